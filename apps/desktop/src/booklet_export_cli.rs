@@ -0,0 +1,111 @@
+use std::path::PathBuf;
+
+use choreo_components::booklet::BookletOptions;
+use choreo_components::booklet::export_booklet;
+
+use crate::floor_export_cli::open_choreography_headless;
+
+pub(crate) const EXPORT_BOOKLET_FLAG: &str = "--export-booklet";
+const USAGE: &str = "usage: rchoreo_desktop --export-booklet <file.choreo> <output.pdf> \
+[--dancer-index] [--locale <code>]";
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct BookletExportCommand {
+    pub(crate) choreo_path: PathBuf,
+    pub(crate) output_path: PathBuf,
+    pub(crate) options: BookletOptions,
+}
+
+/// Returns `None` when the arguments do not ask for a booklet export.
+pub(crate) fn parse_booklet_export_args(
+    args: &[String],
+) -> Option<Result<BookletExportCommand, String>> {
+    let (flag, rest) = args.split_first()?;
+    if flag != EXPORT_BOOKLET_FLAG {
+        return None;
+    }
+    Some(parse_export_command(rest))
+}
+
+/// Writes the PDF booklet without opening a window; the error is ready for stderr.
+pub(crate) fn run_booklet_export(command: &BookletExportCommand) -> Result<PathBuf, String> {
+    let binding = open_choreography_headless(&command.choreo_path)?;
+    let state = binding.state();
+    let state = state.borrow();
+    export_booklet(&state, &command.output_path, &command.options)
+        .map_err(|error| error.to_string())?;
+    Ok(command.output_path.clone())
+}
+
+fn parse_export_command(args: &[String]) -> Result<BookletExportCommand, String> {
+    let [choreo_path, output_path, flags @ ..] = args else {
+        return Err(USAGE.to_string());
+    };
+    let mut options = BookletOptions::default();
+    let mut flags = flags.iter();
+    while let Some(flag) = flags.next() {
+        match flag.as_str() {
+            "--dancer-index" => options.include_dancer_index = true,
+            "--locale" => {
+                let locale = flags.next().ok_or_else(|| USAGE.to_string())?;
+                if choreo_i18n::translation(locale, "BookletPageNumber").is_none() {
+                    return Err(format!("Unsupported locale: {locale}"));
+                }
+                options.locale = locale.clone();
+            }
+            _ => return Err(USAGE.to_string()),
+        }
+    }
+
+    Ok(BookletExportCommand {
+        choreo_path: PathBuf::from(choreo_path),
+        output_path: PathBuf::from(output_path),
+        options,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_booklet_export_args;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| (*value).to_string()).collect()
+    }
+
+    #[test]
+    fn parses_dancer_index_and_locale() {
+        let command = parse_booklet_export_args(&args(&[
+            "--export-booklet",
+            "demo.choreo",
+            "demo.pdf",
+            "--dancer-index",
+            "--locale",
+            "de",
+        ]))
+        .expect("booklet flag should be recognised")
+        .expect("arguments should parse");
+
+        assert_eq!(command.choreo_path.to_str(), Some("demo.choreo"));
+        assert_eq!(command.output_path.to_str(), Some("demo.pdf"));
+        assert!(command.options.include_dancer_index);
+        assert_eq!(command.options.locale, "de");
+    }
+
+    #[test]
+    fn rejects_missing_output_and_unknown_locales() {
+        assert!(parse_booklet_export_args(&args(&["demo.choreo"])).is_none());
+        for invalid in [
+            args(&["--export-booklet", "demo.choreo"]),
+            args(&["--export-booklet", "demo.choreo", "demo.pdf", "--locale"]),
+            args(&[
+                "--export-booklet",
+                "demo.choreo",
+                "demo.pdf",
+                "--locale",
+                "xx",
+            ]),
+        ] {
+            assert!(matches!(parse_booklet_export_args(&invalid), Some(Err(_))));
+        }
+    }
+}
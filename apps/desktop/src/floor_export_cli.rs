@@ -0,0 +1,164 @@
+use std::path::Path;
+use std::path::PathBuf;
+
+use choreo_components::choreo_main::FloorExportOptions;
+use choreo_components::choreo_main::FloorImageFormat;
+use choreo_components::choreo_main::MainPageBinding;
+use choreo_components::choreo_main::MainPageDependencies;
+use choreo_components::choreo_main::actions::ChoreoMainAction;
+use choreo_components::choreo_main::export_scene_floors;
+
+use crate::load_open_choreo_request_from_path;
+
+pub(crate) const EXPORT_FLOORS_FLAG: &str = "--export-floors";
+const USAGE: &str = "usage: rchoreo_desktop --export-floors <file.choreo> <output-dir> \
+[--scenes <first>[-<last>]] [--format svg|png]";
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct FloorExportCommand {
+    pub(crate) choreo_path: PathBuf,
+    pub(crate) output_directory: PathBuf,
+    pub(crate) options: FloorExportOptions,
+}
+
+/// Returns `None` when the arguments do not ask for a floor export, so the app starts normally.
+pub(crate) fn parse_floor_export_args(
+    args: &[String],
+) -> Option<Result<FloorExportCommand, String>> {
+    let (flag, rest) = args.split_first()?;
+    if flag != EXPORT_FLOORS_FLAG {
+        return None;
+    }
+    Some(parse_export_command(rest))
+}
+
+/// Renders the requested scenes without opening a window; the error is ready for stderr.
+pub(crate) fn run_floor_export(command: &FloorExportCommand) -> Result<Vec<PathBuf>, String> {
+    let binding = open_choreography_headless(&command.choreo_path)?;
+    let state = binding.state();
+    let state = state.borrow();
+    export_scene_floors(&state, &command.output_directory, &command.options)
+        .map_err(|error| error.to_string())
+}
+
+/// Loads a choreography through the main page like the app would, without a window.
+pub(crate) fn open_choreography_headless(choreo_path: &Path) -> Result<MainPageBinding, String> {
+    let request = load_open_choreo_request_from_path(choreo_path)
+        .ok_or_else(|| format!("Could not read {}", choreo_path.display()))?;
+    let binding = MainPageBinding::new(MainPageDependencies::default());
+    binding.dispatch(ChoreoMainAction::RequestOpenChoreo(request));
+
+    let dialog_message = {
+        let state = binding.state();
+        let state = state.borrow();
+        state
+            .dialog_content
+            .clone()
+            .filter(|_| state.is_dialog_open)
+    };
+    match dialog_message {
+        Some(message) => Err(message),
+        None => Ok(binding),
+    }
+}
+
+fn parse_export_command(args: &[String]) -> Result<FloorExportCommand, String> {
+    let [choreo_path, output_directory, flags @ ..] = args else {
+        return Err(USAGE.to_string());
+    };
+    let mut options = FloorExportOptions::default();
+    let mut formats = Vec::new();
+    let mut flags = flags.iter();
+    while let Some(flag) = flags.next() {
+        let value = flags.next().ok_or_else(|| USAGE.to_string())?;
+        match flag.as_str() {
+            "--scenes" => options.scenes = Some(parse_scene_range(value)?),
+            "--format" => formats.push(parse_format(value)?),
+            _ => return Err(USAGE.to_string()),
+        }
+    }
+    if !formats.is_empty() {
+        options.formats = formats;
+    }
+
+    Ok(FloorExportCommand {
+        choreo_path: Path::new(choreo_path).to_path_buf(),
+        output_directory: Path::new(output_directory).to_path_buf(),
+        options,
+    })
+}
+
+/// Scene numbers on the command line start at 1, like the scene list.
+fn parse_scene_range(value: &str) -> Result<std::ops::RangeInclusive<usize>, String> {
+    let (first, last) = value.split_once('-').unwrap_or((value, value));
+    let parse = |number: &str| {
+        number
+            .trim()
+            .parse::<usize>()
+            .ok()
+            .filter(|number| *number > 0)
+            .ok_or_else(|| format!("Invalid scene range: {value}"))
+    };
+    let (first, last) = (parse(first)?, parse(last)?);
+    if last < first {
+        return Err(format!("Invalid scene range: {value}"));
+    }
+    Ok(first - 1..=last - 1)
+}
+
+fn parse_format(value: &str) -> Result<FloorImageFormat, String> {
+    match value.to_ascii_lowercase().as_str() {
+        "svg" => Ok(FloorImageFormat::Svg),
+        "png" => Ok(FloorImageFormat::Png),
+        _ => Err(format!("Unsupported image format: {value}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use choreo_components::choreo_main::FloorImageFormat;
+
+    use super::parse_floor_export_args;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| (*value).to_string()).collect()
+    }
+
+    #[test]
+    fn ignores_arguments_without_the_export_flag() {
+        assert!(parse_floor_export_args(&args(&["demo.choreo"])).is_none());
+        assert!(parse_floor_export_args(&[]).is_none());
+    }
+
+    #[test]
+    fn parses_scene_range_and_formats() {
+        let command = parse_floor_export_args(&args(&[
+            "--export-floors",
+            "demo.choreo",
+            "out",
+            "--scenes",
+            "2-4",
+            "--format",
+            "png",
+        ]))
+        .expect("export flag should be recognised")
+        .expect("arguments should parse");
+
+        assert_eq!(command.choreo_path.to_str(), Some("demo.choreo"));
+        assert_eq!(command.output_directory.to_str(), Some("out"));
+        assert_eq!(command.options.scenes, Some(1..=3));
+        assert_eq!(command.options.formats, vec![FloorImageFormat::Png]);
+    }
+
+    #[test]
+    fn rejects_invalid_scene_ranges_and_missing_paths() {
+        for invalid in [
+            args(&["--export-floors", "demo.choreo"]),
+            args(&["--export-floors", "demo.choreo", "out", "--scenes", "0"]),
+            args(&["--export-floors", "demo.choreo", "out", "--scenes", "4-2"]),
+            args(&["--export-floors", "demo.choreo", "out", "--format", "gif"]),
+        ] {
+            assert!(matches!(parse_floor_export_args(&invalid), Some(Err(_))));
+        }
+    }
+}
@@ -7,6 +7,7 @@
 #![deny(clippy::all)]
 
 use choreo_components::AppShellStore;
+use choreo_components::audio_player::audio_format::SUPPORTED_AUDIO_EXTENSIONS;
 use choreo_components::choreo_main::MainPageActionHandlers;
 use choreo_components::choreo_main::MainPageDependencies;
 use choreo_components::choreo_main::actions::OpenChoreoRequested;
@@ -19,6 +20,8 @@ use std::rc::Rc;
 use std::sync::Once;
 
 mod app_icon;
+mod booklet_export_cli;
+mod floor_export_cli;
 
 const APP_ID: &str = "rchoreo_desktop";
 const APP_TITLE: &str = "ChoreoApp";
@@ -58,6 +61,35 @@ impl eframe::App for DesktopEguiApp {
 
 fn main() -> eframe::Result<()> {
     init_logging();
+    let external_paths = env::args().skip(1).collect::<Vec<_>>();
+    if let Some(command) = floor_export_cli::parse_floor_export_args(&external_paths) {
+        let result = command.and_then(|command| floor_export_cli::run_floor_export(&command));
+        match result {
+            Ok(written) => {
+                for path in written {
+                    println!("{}", path.display());
+                }
+                return Ok(());
+            }
+            Err(message) => {
+                eprintln!("{message}");
+                std::process::exit(1);
+            }
+        }
+    }
+    if let Some(command) = booklet_export_cli::parse_booklet_export_args(&external_paths) {
+        match command.and_then(|command| booklet_export_cli::run_booklet_export(&command)) {
+            Ok(written) => {
+                println!("{}", written.display());
+                return Ok(());
+            }
+            Err(message) => {
+                eprintln!("{message}");
+                std::process::exit(1);
+            }
+        }
+    }
+
     log::info!("starting {APP_ID}");
 
     let native_options = eframe::NativeOptions {
@@ -68,8 +100,6 @@ fn main() -> eframe::Result<()> {
         ..Default::default()
     };
 
-    let external_paths = env::args().skip(1).collect::<Vec<_>>();
-
     eframe::run_native(
         APP_TITLE,
         native_options,
@@ -97,6 +127,7 @@ fn desktop_main_page_dependencies() -> MainPageDependencies {
         pick_choreo_file: Some(Rc::new(pick_choreo_file)),
         pick_audio_path: Some(Rc::new(pick_audio_path)),
         pick_image_path: Some(Rc::new(pick_image_path)),
+        pick_save_choreo_path: Some(Rc::new(pick_save_choreo_path)),
         ..MainPageActionHandlers::default()
     };
     dependencies
@@ -105,7 +136,7 @@ fn desktop_main_page_dependencies() -> MainPageDependencies {
 fn pick_audio_path() -> Option<String> {
     FileDialog::new()
         .set_title("Open audio file")
-        .add_filter("Audio", &["mp3"])
+        .add_filter("Audio", SUPPORTED_AUDIO_EXTENSIONS)
         .add_filter("All files", &["*"])
         .pick_file()
         .map(|path| path.to_string_lossy().into_owned())
@@ -120,6 +151,15 @@ fn pick_image_path() -> Option<String> {
         .map(|path| path.to_string_lossy().into_owned())
 }
 
+fn pick_save_choreo_path(suggested_file_name: &str) -> Option<String> {
+    FileDialog::new()
+        .set_title("Save choreography file")
+        .set_file_name(suggested_file_name)
+        .add_filter("Choreo", &["choreo"])
+        .save_file()
+        .map(|path| path.to_string_lossy().into_owned())
+}
+
 fn pick_choreo_file() -> Option<OpenChoreoRequested> {
     let path = FileDialog::new()
         .set_title("Open choreography file")
@@ -166,6 +206,12 @@ mod desktop_open_choreo_spec {
         assert!(dependencies.action_handlers.pick_choreo_file.is_some());
     }
 
+    #[test]
+    fn desktop_host_wires_save_choreo_handler() {
+        let dependencies = desktop_main_page_dependencies();
+        assert!(dependencies.action_handlers.pick_save_choreo_path.is_some());
+    }
+
     #[test]
     fn desktop_host_preserves_preferences_wiring_for_startup_restore() {
         let dependencies = desktop_main_page_dependencies();
@@ -178,6 +178,25 @@ pub fn compute_mid_scene_assignment_with_fraction(
     scene_c: &[Vector2],
     scene_b_fraction: f32,
 ) -> Result<Vec<usize>, AlgorithmError> {
+    compute_constrained_mid_scene_assignment(scene_a, scene_b, scene_c, scene_b_fraction, |_, _| {
+        true
+    })
+}
+
+/// Like [`compute_mid_scene_assignment_with_fraction`], but dancer `i` of `scene_a` may only
+/// be given position `j` of `scene_b` when `is_allowed_pair(i, j)` is `true`.
+///
+/// Returns [`AlgorithmError::NoPerfectAssignment`] when no assignment uses allowed pairs only.
+pub fn compute_constrained_mid_scene_assignment<G>(
+    scene_a: &[Vector2],
+    scene_b: &[Vector2],
+    scene_c: &[Vector2],
+    scene_b_fraction: f32,
+    is_allowed_pair: G,
+) -> Result<Vec<usize>, AlgorithmError>
+where
+    G: Fn(usize, usize) -> bool,
+{
     compute_mid_scene_assignment_with(
         scene_a,
         scene_b,
@@ -189,7 +208,7 @@ pub fn compute_mid_scene_assignment_with_fraction(
             let deviation_energy = (mid - expected_mid).length_squared();
             path_energy + deviation_energy
         },
-        |_, _| true,
+        is_allowed_pair,
     )
 }
 
@@ -238,7 +257,18 @@ where
         }
     }
 
-    compute_sigma_vector(&cost_matrix)
+    let assignment = compute_sigma_vector(&cost_matrix)?;
+    if assignment
+        .iter()
+        .enumerate()
+        .any(|(dancer_index, &candidate_index)| !is_allowed_pair(dancer_index, candidate_index))
+    {
+        return Err(AlgorithmError::NoPerfectAssignment(
+            "No perfect assignment exists with the provided constraints.",
+        ));
+    }
+
+    Ok(assignment)
 }
 
 fn find_minimal_max_distance(distances: &[Vec<f32>], sorted_distances: &[f32]) -> f32 {
@@ -9,6 +9,7 @@
 pub mod error;
 pub mod hungarian;
 pub mod min_cost_max_flow;
+pub mod path_planner;
 mod vector2;
 
 pub use error::AlgorithmError;
@@ -345,6 +345,25 @@ pub fn solve_three_scene_assignment_with_fraction(
     scene_c: &[Vector2],
     scene_b_fraction: f32,
 ) -> Result<Vec<usize>, AlgorithmError> {
+    solve_constrained_three_scene_assignment(scene_a, scene_b, scene_c, scene_b_fraction, |_, _| {
+        true
+    })
+}
+
+/// Like [`solve_three_scene_assignment_with_fraction`], but dancer `i` of `scene_a` may only
+/// be given position `j` of `scene_b` when `is_allowed_pair(i, j)` is `true`.
+///
+/// Returns [`AlgorithmError::NoPerfectAssignment`] when no assignment uses allowed pairs only.
+pub fn solve_constrained_three_scene_assignment<G>(
+    scene_a: &[Vector2],
+    scene_b: &[Vector2],
+    scene_c: &[Vector2],
+    scene_b_fraction: f32,
+    is_allowed_pair: G,
+) -> Result<Vec<usize>, AlgorithmError>
+where
+    G: Fn(usize, usize) -> bool,
+{
     solve_three_scene_assignment_with(
         scene_a,
         scene_b,
@@ -356,7 +375,7 @@ pub fn solve_three_scene_assignment_with_fraction(
             let deviation_energy = (mid - expected_mid).length_squared();
             path_energy + deviation_energy
         },
-        |_, _| true,
+        is_allowed_pair,
     )
 }
 
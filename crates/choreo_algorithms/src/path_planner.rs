@@ -0,0 +1,208 @@
+use crate::{AlgorithmError, Vector2};
+
+const SAMPLE_COUNT: usize = 64;
+const MAX_BEND_ITERATIONS: usize = 32;
+const BEND_MARGIN: f32 = 0.1;
+const MIN_BEND_WEIGHT: f32 = 0.05;
+const EPSILON: f32 = 1e-6;
+
+/// Path of one dancer between two scenes, using the same control point rules as the floor
+/// interpolation: no `curve1` is a straight line, `curve1` alone is a quadratic Bézier and
+/// `curve1` with `curve2` is a cubic Bézier.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TransitionPath {
+    pub start: Vector2,
+    pub end: Vector2,
+    pub curve1: Option<Vector2>,
+    pub curve2: Option<Vector2>,
+}
+
+impl TransitionPath {
+    pub const fn straight(start: Vector2, end: Vector2) -> Self {
+        Self {
+            start,
+            end,
+            curve1: None,
+            curve2: None,
+        }
+    }
+
+    pub fn point_at(&self, progress: f32) -> Vector2 {
+        let Some(curve1) = self.curve1 else {
+            return Vector2::lerp(self.start, self.end, progress);
+        };
+        let inverse = 1.0 - progress;
+        if let Some(curve2) = self.curve2 {
+            return self.start * (inverse * inverse * inverse)
+                + curve1 * (3.0 * inverse * inverse * progress)
+                + curve2 * (3.0 * inverse * progress * progress)
+                + self.end * (progress * progress * progress);
+        }
+        self.start * (inverse * inverse)
+            + curve1 * (2.0 * inverse * progress)
+            + self.end * (progress * progress)
+    }
+
+    fn length(&self) -> f32 {
+        self.start.distance_to(self.end)
+    }
+
+    /// Returns the cubic control points describing the same curve as this path.
+    fn cubic_controls(&self) -> (Vector2, Vector2) {
+        match (self.curve1, self.curve2) {
+            (Some(curve1), Some(curve2)) => (curve1, curve2),
+            (Some(curve1), None) => (
+                Vector2::lerp(self.start, curve1, 2.0 / 3.0),
+                Vector2::lerp(self.end, curve1, 2.0 / 3.0),
+            ),
+            (None, _) => (
+                Vector2::lerp(self.start, self.end, 1.0 / 3.0),
+                Vector2::lerp(self.start, self.end, 2.0 / 3.0),
+            ),
+        }
+    }
+
+    /// Moves the point at `progress` by `displacement` while keeping both end points fixed.
+    fn bend(&mut self, progress: f32, displacement: Vector2) {
+        if displacement.length() <= EPSILON {
+            return;
+        }
+        let weight = 3.0 * progress * (1.0 - progress);
+        let offset = displacement * (1.0 / weight);
+        let (curve1, curve2) = self.cubic_controls();
+        self.curve1 = Some(curve1 + offset);
+        self.curve2 = Some(curve2 + offset);
+    }
+}
+
+/// Two dancers whose paths come closer than the allowed distance.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PathConflict {
+    pub first: usize,
+    pub second: usize,
+    /// Transition progress in `[0,1]` where the dancers are closest.
+    pub progress: f32,
+    pub distance: f32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PathPlan {
+    pub paths: Vec<TransitionPath>,
+    /// Conflicts that bending cannot remove, e.g. dancers already too close in a scene.
+    pub unresolved: Vec<PathConflict>,
+}
+
+pub fn find_path_conflicts(
+    paths: &[TransitionPath],
+    min_distance: f32,
+) -> Result<Vec<PathConflict>, AlgorithmError> {
+    if !min_distance.is_finite() || min_distance <= 0.0 {
+        return Err(AlgorithmError::InvalidParameter(
+            "min_distance must be a positive number.",
+        ));
+    }
+
+    let samples = (0..=SAMPLE_COUNT)
+        .map(|step| {
+            let progress = step as f32 / SAMPLE_COUNT as f32;
+            paths
+                .iter()
+                .map(|path| path.point_at(progress))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let mut conflicts = Vec::new();
+    for first in 0..paths.len() {
+        for second in first + 1..paths.len() {
+            let (step, distance) = samples
+                .iter()
+                .map(|points| points[first].distance_to(points[second]))
+                .enumerate()
+                .fold((0, f32::INFINITY), |closest, (step, distance)| {
+                    if distance < closest.1 {
+                        (step, distance)
+                    } else {
+                        closest
+                    }
+                });
+            if distance < min_distance {
+                conflicts.push(PathConflict {
+                    first,
+                    second,
+                    progress: step as f32 / SAMPLE_COUNT as f32,
+                    distance,
+                });
+            }
+        }
+    }
+
+    Ok(conflicts)
+}
+
+/// Bends conflicting paths apart by filling their control points until every pair keeps at
+/// least `min_distance` during the transition. Dancers that move further take the larger part
+/// of the detour; dancers passing head-on swerve to opposite sides instead of crossing.
+pub fn bend_conflicting_paths(
+    paths: &[TransitionPath],
+    min_distance: f32,
+) -> Result<PathPlan, AlgorithmError> {
+    let mut planned = paths.to_vec();
+
+    for _ in 0..MAX_BEND_ITERATIONS {
+        let conflicts = find_path_conflicts(&planned, min_distance)?;
+        let mut bent = false;
+        for conflict in conflicts {
+            let progress = conflict.progress;
+            if 3.0 * progress * (1.0 - progress) < MIN_BEND_WEIGHT {
+                continue;
+            }
+
+            let first = planned[conflict.first];
+            let second = planned[conflict.second];
+            let direction = separation_direction(&first, &second, progress);
+            let push = min_distance - conflict.distance + min_distance * BEND_MARGIN;
+            let total_length = first.length() + second.length();
+            let first_share = if total_length > EPSILON {
+                first.length() / total_length
+            } else {
+                0.5
+            };
+
+            planned[conflict.first].bend(progress, direction * (push * first_share));
+            planned[conflict.second].bend(progress, direction * (-push * (1.0 - first_share)));
+            bent = true;
+        }
+        if !bent {
+            break;
+        }
+    }
+
+    let unresolved = find_path_conflicts(&planned, min_distance)?;
+    Ok(PathPlan {
+        paths: planned,
+        unresolved,
+    })
+}
+
+/// Unit vector pointing from `second` towards `first` at `progress`. When both dancers meet in
+/// the same spot, the direction is perpendicular to their relative travel so they pass side by
+/// side.
+fn separation_direction(first: &TransitionPath, second: &TransitionPath, progress: f32) -> Vector2 {
+    let offset = first.point_at(progress) - second.point_at(progress);
+    if offset.length() > EPSILON {
+        return offset * (1.0 / offset.length());
+    }
+
+    let relative_travel = (first.end - first.start) - (second.end - second.start);
+    let travel = if relative_travel.length() > EPSILON {
+        relative_travel
+    } else {
+        first.end - first.start
+    };
+    if travel.length() <= EPSILON {
+        return Vector2::new(1.0, 0.0);
+    }
+
+    Vector2::new(-travel.y, travel.x) * (1.0 / travel.length())
+}
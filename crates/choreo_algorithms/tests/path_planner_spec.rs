@@ -0,0 +1,191 @@
+use choreo_algorithms::path_planner::{
+    TransitionPath, bend_conflicting_paths, find_path_conflicts,
+};
+use choreo_algorithms::{AlgorithmError, Vector2};
+use rspec::report::Report;
+use rspec::{ConfigurationBuilder, Logger, Runner};
+use std::io;
+use std::sync::Arc;
+
+macro_rules! check_eq {
+    ($errors:expr, $left:expr, $right:expr) => {
+        if $left != $right {
+            $errors.push(format!(
+                "{} != {} (left = {:?}, right = {:?})",
+                stringify!($left),
+                stringify!($right),
+                $left,
+                $right
+            ));
+        }
+    };
+}
+
+macro_rules! check {
+    ($errors:expr, $condition:expr) => {
+        let passed: bool = $condition;
+        if !passed {
+            $errors.push(format!("condition failed: {}", stringify!($condition)));
+        }
+    };
+}
+
+fn run_suite<T>(suite: &rspec::block::Suite<T>) -> rspec::report::SuiteReport
+where
+    T: Clone + Send + Sync + std::fmt::Debug,
+{
+    let configuration = ConfigurationBuilder::default()
+        .exit_on_failure(false)
+        .build()
+        .expect("rspec configuration should build");
+    let logger = Arc::new(Logger::new(io::stdout()));
+    let runner = Runner::new(configuration, vec![logger]);
+    runner.run(suite)
+}
+
+fn straight(start: (f32, f32), end: (f32, f32)) -> TransitionPath {
+    TransitionPath::straight(Vector2::new(start.0, start.1), Vector2::new(end.0, end.1))
+}
+
+fn min_distance(paths: &[TransitionPath]) -> f32 {
+    let mut closest = f32::INFINITY;
+    for step in 0..=1000 {
+        let progress = step as f32 / 1000.0;
+        for first in 0..paths.len() {
+            for second in first + 1..paths.len() {
+                let distance = paths[first]
+                    .point_at(progress)
+                    .distance_to(paths[second].point_at(progress));
+                closest = closest.min(distance);
+            }
+        }
+    }
+    closest
+}
+
+fn side_of_travel(path: &TransitionPath, progress: f32) -> f32 {
+    let travel = path.end - path.start;
+    let offset = path.point_at(progress) - path.start;
+    travel.x * offset.y - travel.y * offset.x
+}
+
+#[test]
+fn path_planner_spec() {
+    let suite = rspec::describe("collision aware path planner", (), |spec| {
+        spec.it("finds dancers that swap places head-on", |_| {
+            let paths = vec![
+                straight((0.0, 0.0), (4.0, 0.0)),
+                straight((4.0, 0.0), (0.0, 0.0)),
+            ];
+
+            let conflicts = find_path_conflicts(&paths, 1.0).expect("conflicts");
+
+            let mut errors = Vec::new();
+            check_eq!(errors, conflicts.len(), 1);
+            check_eq!(errors, (conflicts[0].first, conflicts[0].second), (0, 1));
+            check_eq!(errors, conflicts[0].progress, 0.5);
+            check_eq!(errors, conflicts[0].distance, 0.0);
+            assert!(
+                errors.is_empty(),
+                "Assertion failures:\n{}",
+                errors.join("\n")
+            );
+        });
+
+        spec.it("ignores dancers that keep their distance", |_| {
+            let paths = vec![
+                straight((0.0, 0.0), (4.0, 0.0)),
+                straight((0.0, 2.0), (4.0, 2.0)),
+            ];
+
+            let conflicts = find_path_conflicts(&paths, 1.0).expect("conflicts");
+            let plan = bend_conflicting_paths(&paths, 1.0).expect("plan");
+
+            let mut errors = Vec::new();
+            check!(errors, conflicts.is_empty());
+            check_eq!(errors, plan.paths, paths);
+            check!(errors, plan.unresolved.is_empty());
+            assert!(
+                errors.is_empty(),
+                "Assertion failures:\n{}",
+                errors.join("\n")
+            );
+        });
+
+        spec.it("bends swapping dancers to opposite sides", |_| {
+            let paths = vec![
+                straight((0.0, 0.0), (4.0, 0.0)),
+                straight((4.0, 0.0), (0.0, 0.0)),
+            ];
+
+            let plan = bend_conflicting_paths(&paths, 1.0).expect("plan");
+
+            let mut errors = Vec::new();
+            check!(errors, plan.unresolved.is_empty());
+            check!(errors, min_distance(&plan.paths) >= 0.95);
+            check!(errors, plan.paths.iter().all(|path| path.curve1.is_some()));
+            check!(errors, plan.paths.iter().all(|path| path.curve2.is_some()));
+            check_eq!(errors, plan.paths[0].start, paths[0].start);
+            check_eq!(errors, plan.paths[0].end, paths[0].end);
+            check_eq!(
+                errors,
+                side_of_travel(&plan.paths[0], 0.5).signum(),
+                side_of_travel(&plan.paths[1], 0.5).signum()
+            );
+            assert!(
+                errors.is_empty(),
+                "Assertion failures:\n{}",
+                errors.join("\n")
+            );
+        });
+
+        spec.it("moves the travelling dancer around a standing one", |_| {
+            let paths = vec![
+                straight((0.0, 0.0), (4.0, 0.0)),
+                straight((2.0, 0.2), (2.0, 0.2)),
+            ];
+
+            let plan = bend_conflicting_paths(&paths, 1.0).expect("plan");
+
+            let mut errors = Vec::new();
+            check!(errors, plan.unresolved.is_empty());
+            check!(errors, min_distance(&plan.paths) >= 0.95);
+            check_eq!(errors, plan.paths[1], paths[1]);
+            check!(errors, plan.paths[0].point_at(0.5).y < 0.0);
+            assert!(
+                errors.is_empty(),
+                "Assertion failures:\n{}",
+                errors.join("\n")
+            );
+        });
+
+        spec.it("keeps conflicts that start in the scene itself", |_| {
+            let paths = vec![
+                straight((0.0, 0.0), (0.0, 4.0)),
+                straight((0.5, 0.0), (4.0, 4.0)),
+            ];
+
+            let plan = bend_conflicting_paths(&paths, 1.0).expect("plan");
+
+            let mut errors = Vec::new();
+            check_eq!(errors, plan.unresolved.len(), 1);
+            check_eq!(errors, plan.unresolved[0].progress, 0.0);
+            assert!(
+                errors.is_empty(),
+                "Assertion failures:\n{}",
+                errors.join("\n")
+            );
+        });
+
+        spec.it("rejects a non-positive minimum distance", |_| {
+            let paths = vec![straight((0.0, 0.0), (4.0, 0.0))];
+
+            let error = find_path_conflicts(&paths, 0.0).expect_err("expected invalid parameter");
+
+            assert!(matches!(error, AlgorithmError::InvalidParameter(_)));
+        });
+    });
+
+    let report = run_suite(&suite);
+    assert!(report.is_success());
+}
@@ -1,5 +1,9 @@
-use choreo_algorithms::hungarian::compute_mid_scene_assignment;
-use choreo_algorithms::min_cost_max_flow::solve_three_scene_assignment;
+use choreo_algorithms::hungarian::{
+    compute_mid_scene_assignment, compute_mid_scene_assignment_with,
+};
+use choreo_algorithms::min_cost_max_flow::{
+    solve_three_scene_assignment, solve_three_scene_assignment_with,
+};
 use choreo_algorithms::{AlgorithmError, Vector2};
 use rspec::report::Report;
 use rspec::{ConfigurationBuilder, Logger, Runner};
@@ -92,6 +96,86 @@ fn three_scene_assignment_spec() {
             );
         });
 
+        spec.it("keeps dancers on allowed spots only", |_| {
+            let scene_a = vec![Vector2::new(0.0, 0.0), Vector2::new(2.0, 0.0)];
+            let scene_b = vec![Vector2::new(2.0, 1.0), Vector2::new(0.0, 1.0)];
+            let scene_c = vec![Vector2::new(0.0, 2.0), Vector2::new(2.0, 2.0)];
+            let is_allowed_pair = |dancer: usize, spot: usize| dancer == spot;
+
+            let hungarian = compute_mid_scene_assignment_with(
+                &scene_a,
+                &scene_b,
+                &scene_c,
+                0.5,
+                |start, mid, end| (mid - start).length_squared() + (end - mid).length_squared(),
+                is_allowed_pair,
+            )
+            .expect("hungarian");
+            let min_cost = solve_three_scene_assignment_with(
+                &scene_a,
+                &scene_b,
+                &scene_c,
+                0.5,
+                |start, mid, end| (mid - start).length_squared() + (end - mid).length_squared(),
+                is_allowed_pair,
+            )
+            .expect("min cost");
+
+            let mut errors = Vec::new();
+
+            check_eq!(errors, hungarian, vec![0, 1]);
+            check_eq!(errors, min_cost, vec![0, 1]);
+
+            assert!(
+                errors.is_empty(),
+                "Assertion failures:\n{}",
+                errors.join("\n")
+            );
+        });
+
+        spec.it("fails when no allowed assignment exists", |_| {
+            let scene_a = vec![Vector2::new(0.0, 0.0), Vector2::new(2.0, 0.0)];
+            let scene_b = vec![Vector2::new(0.0, 1.0), Vector2::new(2.0, 1.0)];
+            let scene_c = vec![Vector2::new(0.0, 2.0), Vector2::new(2.0, 2.0)];
+            let is_allowed_pair = |_: usize, spot: usize| spot == 0;
+
+            let hungarian = compute_mid_scene_assignment_with(
+                &scene_a,
+                &scene_b,
+                &scene_c,
+                0.5,
+                |_, _, _| 0.0,
+                is_allowed_pair,
+            )
+            .expect_err("expected no perfect assignment");
+            let min_cost = solve_three_scene_assignment_with(
+                &scene_a,
+                &scene_b,
+                &scene_c,
+                0.5,
+                |_, _, _| 0.0,
+                is_allowed_pair,
+            )
+            .expect_err("expected no perfect assignment");
+
+            let mut errors = Vec::new();
+
+            check!(
+                errors,
+                matches!(hungarian, AlgorithmError::NoPerfectAssignment(_))
+            );
+            check!(
+                errors,
+                matches!(min_cost, AlgorithmError::NoPerfectAssignment(_))
+            );
+
+            assert!(
+                errors.is_empty(),
+                "Assertion failures:\n{}",
+                errors.join("\n")
+            );
+        });
+
         spec.it(
             "returns valid assignments on real choreography sample",
             |_| {
@@ -3,4 +3,6 @@ pub enum AppShellAction {
     FrameStarted,
     SplashPresented,
     ExternalFilePathReceived { file_path: String },
+    WindowTitleChanged { window_title: String },
+    CloseConfirmed,
 }
@@ -4,4 +4,6 @@ pub enum AppShellEffect {
     InitializeMainPage,
     RequestRepaint,
     RouteExternalFilePath { file_path: String },
+    SetWindowTitle { window_title: String },
+    CloseWindow,
 }
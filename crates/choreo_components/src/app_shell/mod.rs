@@ -1,4 +1,5 @@
 use egui::Context;
+use egui::ViewportCommand;
 use std::time::Duration;
 
 use crate::choreo_main::MainPageDependencies;
@@ -12,6 +13,9 @@ pub mod ui;
 
 pub use state::AppShellState;
 
+use crate::choreo_main::actions::ChoreoMainAction;
+use crate::choreo_main::state::ChoreoMainState;
+
 use actions::AppShellAction;
 use reducer::reduce;
 use runtime::AppShellRuntime;
@@ -66,17 +70,33 @@ impl AppShellStore {
             return;
         }
 
+        let close_requested = context.input(|input| input.viewport().close_requested());
+        if close_requested {
+            self.runtime
+                .dispatch_main_page_actions(vec![ChoreoMainAction::RequestClose]);
+        }
+
         let mut request_audio_repaint = self.runtime.tick_audio_runtime();
+        request_audio_repaint |= self.runtime.tick_rehearsal_loop();
         let main_page_state = self.runtime.snapshot_main_page_state();
         self.runtime
             .apply_main_page_theme(context, &main_page_state);
         let actions = ui::draw_main_page(context, &main_page_state);
+        if !actions.is_empty() {
+            // The snapshot drawn this frame predates these actions.
+            context.request_repaint();
+        }
         self.runtime.dispatch_main_page_actions(actions);
 
         request_audio_repaint |= self.runtime.audio_runtime_is_active();
         if request_audio_repaint {
             context.request_repaint_after(Duration::from_millis(16));
         }
+        if let Some(autosave_delay) = self.runtime.tick_autosave() {
+            context.request_repaint_after(autosave_delay);
+        }
+
+        self.sync_window(context, close_requested, &main_page_state);
     }
 
     pub fn route_external_file_path(&mut self, file_path: &str) {
@@ -85,6 +105,27 @@ impl AppShellStore {
         });
     }
 
+    /// Holds a close request back while the main page asks about unsaved changes and closes
+    /// once it is confirmed.
+    ///
+    /// `main_page_state` is the snapshot this frame was drawn from, taken after the close
+    /// request of this frame reached the main page.
+    fn sync_window(
+        &mut self,
+        context: &Context,
+        close_requested: bool,
+        main_page_state: &ChoreoMainState,
+    ) {
+        if close_requested && !main_page_state.is_close_confirmed {
+            context.send_viewport_cmd(ViewportCommand::CancelClose);
+        } else if !close_requested && main_page_state.is_close_confirmed {
+            self.dispatch_with_context(AppShellAction::CloseConfirmed, context);
+        }
+
+        let window_title = ui::window_title(&self.state.title, main_page_state);
+        self.dispatch_with_context(AppShellAction::WindowTitleChanged { window_title }, context);
+    }
+
     fn dispatch_with_context(&mut self, action: AppShellAction, context: &Context) {
         let effects = reduce(&mut self.state, action);
         self.runtime.apply_effects(Some(context), effects);
@@ -9,6 +9,10 @@ pub fn reduce(state: &mut AppShellState, action: AppShellAction) -> Vec<AppShell
         AppShellAction::ExternalFilePathReceived { file_path } => {
             reduce_external_file_path_received(file_path)
         }
+        AppShellAction::WindowTitleChanged { window_title } => {
+            reduce_window_title_changed(state, window_title)
+        }
+        AppShellAction::CloseConfirmed => reduce_close_confirmed(state),
     }
 }
 
@@ -44,3 +48,24 @@ fn reduce_external_file_path_received(file_path: String) -> Vec<AppShellEffect>
 
     vec![AppShellEffect::RouteExternalFilePath { file_path }]
 }
+
+fn reduce_window_title_changed(
+    state: &mut AppShellState,
+    window_title: String,
+) -> Vec<AppShellEffect> {
+    if state.window_title == window_title {
+        return Vec::new();
+    }
+
+    state.window_title = window_title.clone();
+    vec![AppShellEffect::SetWindowTitle { window_title }]
+}
+
+fn reduce_close_confirmed(state: &mut AppShellState) -> Vec<AppShellEffect> {
+    if state.is_closing {
+        return Vec::new();
+    }
+
+    state.is_closing = true;
+    vec![AppShellEffect::CloseWindow]
+}
@@ -1,4 +1,7 @@
+use std::time::Duration;
+
 use egui::Context;
+use egui::ViewportCommand;
 use egui::Visuals;
 
 use crate::choreo_main::MainPageBinding;
@@ -34,6 +37,18 @@ impl AppShellRuntime {
         self.main_page_binding.tick_audio_runtime()
     }
 
+    #[must_use]
+    pub fn tick_rehearsal_loop(&self) -> bool {
+        self.main_page_binding
+            .tick_rehearsal_loop(crate::time::SystemClock::now_utc())
+    }
+
+    #[must_use]
+    pub fn tick_autosave(&self) -> Option<Duration> {
+        self.main_page_binding
+            .tick_autosave(crate::time::SystemClock::now_utc())
+    }
+
     #[must_use]
     pub fn audio_runtime_is_active(&self) -> bool {
         self.main_page_binding.audio_runtime_is_active()
@@ -78,6 +93,14 @@ impl AppShellRuntime {
             AppShellEffect::RouteExternalFilePath { file_path } => {
                 self.main_page_binding.route_external_file_path(&file_path);
             }
+            AppShellEffect::SetWindowTitle { window_title } => {
+                let context = context.expect("window title effect requires an egui context");
+                context.send_viewport_cmd(ViewportCommand::Title(window_title));
+            }
+            AppShellEffect::CloseWindow => {
+                let context = context.expect("close effect requires an egui context");
+                context.send_viewport_cmd(ViewportCommand::Close);
+            }
         }
     }
 }
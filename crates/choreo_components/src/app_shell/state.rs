@@ -6,6 +6,8 @@ pub struct AppShellState {
     pub is_main_page_initialized: bool,
     pub is_typography_initialized: bool,
     pub show_splash_screen: bool,
+    pub window_title: String,
+    pub is_closing: bool,
     pub splash_screen_state: splash_screen_host::ui::SplashScreenUiState,
 }
 
@@ -16,6 +18,8 @@ impl Default for AppShellState {
             is_main_page_initialized: false,
             is_typography_initialized: false,
             show_splash_screen: true,
+            window_title: String::new(),
+            is_closing: false,
             splash_screen_state: splash_screen_host::ui::SplashScreenUiState::default(),
         }
     }
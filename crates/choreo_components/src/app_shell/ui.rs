@@ -5,6 +5,8 @@ use egui::Margin;
 use crate::choreo_main;
 use crate::choreo_main::actions::ChoreoMainAction;
 use crate::choreo_main::state::ChoreoMainState;
+use crate::choreo_main::state::SavedChoreography;
+use crate::choreo_main::translations::choreography_display_name;
 use crate::material::styling::material_palette::MaterialPalette;
 use crate::material::styling::material_palette::material_palette_for_theme;
 use crate::material::styling::material_palette::with_current_material_palette;
@@ -12,6 +14,35 @@ use crate::settings::system_theme::effective_theme_mode;
 
 use super::state::AppShellState;
 
+const DEFAULT_LOCALE: &str = "en";
+const UNSAVED_CHANGES_MARKER: &str = "*";
+
+/// Names the open choreography in front of the app title, marked while it has unsaved changes.
+#[must_use]
+pub fn window_title(title: &str, main_page_state: &ChoreoMainState) -> String {
+    if main_page_state.saved_choreography == SavedChoreography::None
+        && !main_page_state.has_unsaved_changes()
+    {
+        return title.to_string();
+    }
+
+    let name = choreography_display_name(
+        DEFAULT_LOCALE,
+        &main_page_state.choreography_settings_state.name,
+        main_page_state.last_opened_choreo_file.as_deref(),
+    );
+    let marker = if main_page_state.has_unsaved_changes() {
+        UNSAVED_CHANGES_MARKER
+    } else {
+        ""
+    };
+    if title.is_empty() {
+        format!("{name}{marker}")
+    } else {
+        format!("{name}{marker} - {title}")
+    }
+}
+
 pub fn draw_splash(context: &Context, state: &AppShellState, main_page_state: &ChoreoMainState) {
     let palette = material_palette_for_theme(
         &main_page_state.settings_state.material_scheme,
@@ -19,6 +19,11 @@ pub enum AudioPlayerAction {
     SpeedChanged {
         speed: f64,
     },
+    /// The slider's speed range was changed in the settings.
+    SetSpeedRange {
+        minimum: f64,
+        maximum: f64,
+    },
     SetScenes {
         scenes: Vec<super::state::AudioPlayerScene>,
         selected_scene_id: Option<i32>,
@@ -30,6 +35,46 @@ pub enum AudioPlayerAction {
         file_path: String,
         file_exists: bool,
     },
+    /// The file exists but none of the enabled decoders can read it.
+    OpenAudioFileFailed {
+        file_path: String,
+        error: super::audio_format::AudioFormatError,
+    },
     CloseAudioFile,
+    /// Background beat analysis of `file_path` finished.
+    BeatGridDetected {
+        file_path: String,
+        beat_grid: Option<super::beat_detection::BeatGrid>,
+    },
+    WaveformComputed {
+        file_path: String,
+        waveform: Option<super::waveform::Waveform>,
+    },
+    /// A scene marker was dragged on the waveform and released at `timestamp`.
+    MoveSceneTimestamp {
+        scene_id: i32,
+        timestamp: f64,
+    },
+    UpdateTimestampSnap {
+        snap: super::beat_detection::TimestampSnap,
+    },
+    /// The choreography's musical timeline changed; scene markers are labelled with counts.
+    SetMusicalTimeline {
+        timeline: Option<choreo_master_mobile_json::MusicalTimeline>,
+    },
     PublishPositionIfChanged,
+    StartRehearsalLoop {
+        start_seconds: f64,
+        end_seconds: f64,
+    },
+    StopRehearsalLoop,
+    RehearsalTick {
+        elapsed_seconds: f64,
+    },
+    UpdatePreparationSeconds {
+        seconds: f64,
+    },
+    UpdatePauseSeconds {
+        seconds: f64,
+    },
 }
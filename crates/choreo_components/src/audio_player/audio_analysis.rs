@@ -0,0 +1,177 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::PathBuf;
+use std::sync::mpsc::Receiver;
+use std::sync::mpsc::TryRecvError;
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::mpsc::channel;
+#[cfg(not(target_arch = "wasm32"))]
+use std::thread;
+use std::time::SystemTime;
+
+use symphonia::core::audio::SampleBuffer;
+use symphonia::core::errors::Error as SymphoniaError;
+
+use super::audio_format::AudioFormatError;
+use super::audio_format::open_audio_track;
+use super::beat_detection::BeatGrid;
+use super::beat_detection::OnsetEnvelope;
+use super::waveform::Waveform;
+use super::waveform::WaveformBuilder;
+
+/// What one decoding pass over an audio file yields.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AudioAnalysis {
+    pub beat_grid: Option<BeatGrid>,
+    pub waveform: Option<Waveform>,
+}
+
+/// Decodes the whole file once for its beat grid and waveform.
+pub fn analyze_audio_file(path: &Path) -> Result<AudioAnalysis, AudioFormatError> {
+    let mut reader = open_audio_track(path)?;
+    let sample_rate = reader
+        .sample_rate
+        .ok_or(AudioFormatError::UnsupportedFormat)?;
+    let mut envelope = OnsetEnvelope::new(sample_rate);
+    let mut waveform = WaveformBuilder::new(sample_rate);
+    let mut buffer: Option<SampleBuffer<f32>> = None;
+
+    loop {
+        let packet = match reader.format.next_packet() {
+            Ok(packet) => packet,
+            Err(SymphoniaError::IoError(error)) if error.kind() == ErrorKind::UnexpectedEof => {
+                break;
+            }
+            Err(SymphoniaError::ResetRequired) => break,
+            Err(error) => {
+                return Err(AudioFormatError::Read {
+                    message: error.to_string(),
+                });
+            }
+        };
+        if packet.track_id() != reader.track_id {
+            continue;
+        }
+
+        let decoded = match reader.decoder.decode(&packet) {
+            Ok(decoded) => decoded,
+            Err(SymphoniaError::DecodeError(_)) => continue,
+            Err(error) => {
+                return Err(AudioFormatError::Read {
+                    message: error.to_string(),
+                });
+            }
+        };
+        let spec = *decoded.spec();
+        let channels = spec.channels.count().max(1);
+        let required = decoded.capacity() * channels;
+        let buffer = match &mut buffer {
+            Some(buffer) if buffer.capacity() >= required => buffer,
+            slot => slot.insert(SampleBuffer::new(decoded.capacity() as u64, spec)),
+        };
+        buffer.copy_interleaved_ref(decoded);
+        for frame in buffer.samples().chunks(channels) {
+            let sample = frame.iter().sum::<f32>() / channels as f32;
+            envelope.push(sample);
+            waveform.push(sample);
+        }
+    }
+
+    Ok(AudioAnalysis {
+        beat_grid: envelope.finish().beat_grid(),
+        waveform: waveform.finish(),
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct AnalysisCacheKey {
+    file_path: String,
+    length: u64,
+    modified: Option<SystemTime>,
+}
+
+impl AnalysisCacheKey {
+    fn new(file_path: &str) -> Self {
+        let metadata = fs::metadata(file_path).ok();
+        Self {
+            file_path: file_path.to_string(),
+            length: metadata.as_ref().map_or(0, |metadata| metadata.len()),
+            modified: metadata.and_then(|metadata| metadata.modified().ok()),
+        }
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+struct PendingAnalysis {
+    key: AnalysisCacheKey,
+    receiver: Receiver<AudioAnalysis>,
+}
+
+/// Analyzes audio files off the UI thread once per file version and caches the result.
+#[derive(Default)]
+pub struct AudioAnalyzer {
+    cache: HashMap<AnalysisCacheKey, AudioAnalysis>,
+    pending: Option<PendingAnalysis>,
+    ready: Option<(String, AudioAnalysis)>,
+}
+
+impl AudioAnalyzer {
+    pub fn request(&mut self, file_path: &str) {
+        let key = AnalysisCacheKey::new(file_path);
+        self.pending = None;
+        if let Some(analysis) = self.cache.get(&key) {
+            self.ready = Some((key.file_path, analysis.clone()));
+            return;
+        }
+
+        self.ready = None;
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let (sender, receiver) = channel();
+            let path = PathBuf::from(file_path);
+            let spawned = thread::Builder::new()
+                .name("audio-analysis".to_string())
+                .spawn(move || {
+                    let analysis = analyze_audio_file(&path).unwrap_or_default();
+                    let _ = sender.send(analysis);
+                });
+            if spawned.is_ok() {
+                self.pending = Some(PendingAnalysis { key, receiver });
+                return;
+            }
+        }
+        // Without a worker thread (browser builds) decoding here would stall the UI.
+        self.cache.insert(key.clone(), AudioAnalysis::default());
+        self.ready = Some((key.file_path, AudioAnalysis::default()));
+    }
+
+    /// Returns the file path and analysis once one finishes.
+    pub fn poll(&mut self) -> Option<(String, AudioAnalysis)> {
+        if let Some(ready) = self.ready.take() {
+            return Some(ready);
+        }
+
+        let pending = self.pending.as_ref()?;
+        let analysis = match pending.receiver.try_recv() {
+            Ok(analysis) => analysis,
+            Err(TryRecvError::Empty) => return None,
+            Err(TryRecvError::Disconnected) => AudioAnalysis::default(),
+        };
+        let pending = self.pending.take()?;
+        self.cache.insert(pending.key.clone(), analysis.clone());
+        Some((pending.key.file_path, analysis))
+    }
+
+    #[must_use]
+    pub fn is_pending(&self) -> bool {
+        self.pending.is_some() || self.ready.is_some()
+    }
+
+    pub fn cancel(&mut self) {
+        self.pending = None;
+        self.ready = None;
+    }
+}
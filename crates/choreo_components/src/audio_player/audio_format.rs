@@ -0,0 +1,111 @@
+use std::fmt;
+use std::fs::File;
+use std::path::Path;
+
+use symphonia::core::codecs::CODEC_TYPE_NULL;
+use symphonia::core::codecs::Decoder;
+use symphonia::core::codecs::DecoderOptions;
+use symphonia::core::errors::Error as SymphoniaError;
+use symphonia::core::formats::FormatOptions;
+use symphonia::core::formats::FormatReader;
+use symphonia::core::io::MediaSourceStream;
+use symphonia::core::meta::MetadataOptions;
+use symphonia::core::probe::Hint;
+use symphonia::default::get_codecs;
+use symphonia::default::get_probe;
+
+/// File extensions the native backends can decode; offered by the audio file pickers.
+pub const SUPPORTED_AUDIO_EXTENSIONS: &[&str] =
+    &["mp3", "wav", "flac", "ogg", "oga", "m4a", "mp4", "aac"];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AudioFormatError {
+    Read { message: String },
+    UnsupportedFormat,
+    UnsupportedCodec { codec: String },
+}
+
+impl fmt::Display for AudioFormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AudioFormatError::Read { message } => write!(f, "{message}"),
+            AudioFormatError::UnsupportedFormat => write!(f, "unsupported audio container"),
+            AudioFormatError::UnsupportedCodec { codec } => {
+                write!(f, "unsupported audio codec {codec}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for AudioFormatError {}
+
+#[must_use]
+pub fn is_supported_audio_extension(extension: &str) -> bool {
+    SUPPORTED_AUDIO_EXTENSIONS
+        .iter()
+        .any(|supported| supported.eq_ignore_ascii_case(extension))
+}
+
+/// Checks that the file's container and first audio track can be decoded before a player
+/// is created for it.
+pub fn probe_audio_file(path: &Path) -> Result<(), AudioFormatError> {
+    open_audio_track(path).map(|_| ())
+}
+
+/// An opened audio file positioned at its first decodable track.
+pub(crate) struct AudioTrackReader {
+    pub(crate) format: Box<dyn FormatReader>,
+    pub(crate) decoder: Box<dyn Decoder>,
+    pub(crate) track_id: u32,
+    pub(crate) sample_rate: Option<u32>,
+}
+
+pub(crate) fn open_audio_track(path: &Path) -> Result<AudioTrackReader, AudioFormatError> {
+    let file = File::open(path).map_err(|error| AudioFormatError::Read {
+        message: error.to_string(),
+    })?;
+    let stream = MediaSourceStream::new(Box::new(file), Default::default());
+    let mut hint = Hint::new();
+    if let Some(extension) = path.extension().and_then(|value| value.to_str()) {
+        hint.with_extension(extension);
+    }
+
+    let probed = get_probe()
+        .format(
+            &hint,
+            stream,
+            &FormatOptions::default(),
+            &MetadataOptions::default(),
+        )
+        .map_err(|error| match error {
+            SymphoniaError::IoError(error) => AudioFormatError::Read {
+                message: error.to_string(),
+            },
+            _ => AudioFormatError::UnsupportedFormat,
+        })?;
+    let track = probed
+        .format
+        .tracks()
+        .iter()
+        .find(|track| track.codec_params.codec != CODEC_TYPE_NULL)
+        .ok_or(AudioFormatError::UnsupportedFormat)?;
+
+    let decoder = get_codecs()
+        .make(&track.codec_params, &DecoderOptions::default())
+        .map_err(|_| AudioFormatError::UnsupportedCodec {
+            codec: get_codecs()
+                .get_codec(track.codec_params.codec)
+                .map_or_else(
+                    || track.codec_params.codec.to_string(),
+                    |descriptor| descriptor.short_name.to_string(),
+                ),
+        })?;
+    let track_id = track.id;
+    let sample_rate = track.codec_params.sample_rate;
+    Ok(AudioTrackReader {
+        format: probed.format,
+        decoder,
+        track_id,
+        sample_rate,
+    })
+}
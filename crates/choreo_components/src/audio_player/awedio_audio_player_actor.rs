@@ -2,9 +2,12 @@ use std::sync::Mutex;
 use std::time::Duration;
 use std::time::Instant;
 
+use awedio::NextSample;
 use awedio::Sound;
 use awedio::backends::CpalBackend;
 
+use super::super::time_stretch::TimeStretcher;
+use super::super::time_stretch::clamp_playback_speed;
 use super::super::types::AudioPlayer;
 use super::super::types::AudioPlayerSample;
 use super::rodio_audio_player_actor::read_duration_seconds;
@@ -149,7 +152,7 @@ impl AwedioRuntime {
 
     fn set_speed(&mut self, speed: f64) {
         self.sync_position();
-        self.speed = speed.clamp(0.5, 2.0);
+        self.speed = clamp_playback_speed(speed);
         if self.is_playing {
             let current = self.position;
             if !self.start_sound_at(current) {
@@ -193,7 +196,7 @@ impl AwedioRuntime {
             let _ = sound.skip(Duration::from_secs_f64(start_seconds));
         }
 
-        let sound = sound.with_adjustable_speed_of(self.speed as f32);
+        let sound = TimeStretchSound::new(sound, self.speed);
         let sound = sound.with_adjustable_volume_of(self.volume as f32);
         manager.play(Box::new(sound));
         true
@@ -292,6 +295,65 @@ impl AudioPlayer for AwedioAudioPlayerActor {
     }
 }
 
+/// Plays `inner` at `speed` without changing its pitch; a new sound is started per speed change.
+struct TimeStretchSound<S: Sound> {
+    inner: S,
+    stretcher: TimeStretcher,
+}
+
+impl<S: Sound> TimeStretchSound<S> {
+    fn new(inner: S, speed: f64) -> Self {
+        let stretcher = TimeStretcher::new(inner.channel_count(), inner.sample_rate(), speed);
+        Self { inner, stretcher }
+    }
+}
+
+impl<S: Sound> Sound for TimeStretchSound<S> {
+    fn channel_count(&self) -> u16 {
+        self.inner.channel_count()
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.inner.sample_rate()
+    }
+
+    fn next_sample(&mut self) -> Result<NextSample, awedio::Error> {
+        loop {
+            if let Some(sample) = self.stretcher.pop_sample() {
+                let sample =
+                    (sample * f32::from(i16::MAX)).clamp(f32::from(i16::MIN), f32::from(i16::MAX));
+                return Ok(NextSample::Sample(sample as i16));
+            }
+            if self.stretcher.is_finished() {
+                return Ok(NextSample::Finished);
+            }
+            match self.inner.next_sample()? {
+                NextSample::Sample(sample) => {
+                    self.stretcher
+                        .push_sample(f32::from(sample) / f32::from(i16::MAX));
+                }
+                NextSample::MetadataChanged => {
+                    let position = self.stretcher.position_frames().round() as usize;
+                    let speed = self.stretcher.speed();
+                    self.stretcher = TimeStretcher::new(
+                        self.inner.channel_count(),
+                        self.inner.sample_rate(),
+                        speed,
+                    );
+                    self.stretcher.reset(position);
+                    return Ok(NextSample::MetadataChanged);
+                }
+                NextSample::Paused => return Ok(NextSample::Paused),
+                NextSample::Finished => self.stretcher.end_input(),
+            }
+        }
+    }
+
+    fn on_start_of_batch(&mut self) {
+        self.inner.on_start_of_batch();
+    }
+}
+
 fn clamp_position(position: f64, duration: f64) -> f64 {
     if duration <= 0.0 {
         return position.max(0.0);
@@ -0,0 +1,357 @@
+/// Target onset envelope frames per second; beat times are quantized to about this resolution.
+const ENVELOPE_FRAMES_PER_SECOND: f64 = 100.0;
+const MINIMUM_BPM: f64 = 70.0;
+const MAXIMUM_BPM: f64 = 180.0;
+/// Centre of the tempo prior; it decides between half and double time candidates.
+const PREFERRED_BPM: f64 = 120.0;
+const TEMPO_PRIOR_WIDTH_OCTAVES: f64 = 1.0;
+const MINIMUM_ANALYSIS_SECONDS: f64 = 4.0;
+/// Half-width of the moving average removed from the onset curve, in envelope frames.
+const ONSET_MEAN_RADIUS_FRAMES: usize = 25;
+const BEATS_PER_BAR: usize = 4;
+/// Dancers count music in eights: "5-6-7-8" leads into the next "1".
+pub const BEATS_PER_COUNT: usize = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimestampSnap {
+    #[default]
+    Off,
+    Beat,
+    Count,
+}
+
+impl TimestampSnap {
+    #[must_use]
+    pub fn next(self) -> Self {
+        match self {
+            TimestampSnap::Off => TimestampSnap::Beat,
+            TimestampSnap::Beat => TimestampSnap::Count,
+            TimestampSnap::Count => TimestampSnap::Off,
+        }
+    }
+}
+
+/// Tempo and beat positions detected in an audio file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BeatGrid {
+    pub bpm: f64,
+    /// Beat times in seconds, ascending.
+    pub beats: Vec<f64>,
+    /// Index into `beats` of the first "1" of an eight-count.
+    pub first_count_index: usize,
+}
+
+impl BeatGrid {
+    /// The dance count (1 to 8) of the beat at `index`.
+    #[must_use]
+    pub fn count_of(&self, index: usize) -> usize {
+        let offset = self.first_count_index % BEATS_PER_COUNT;
+        (index + BEATS_PER_COUNT - offset) % BEATS_PER_COUNT + 1
+    }
+
+    /// Times of every "1" of an eight-count.
+    pub fn count_starts(&self) -> impl Iterator<Item = f64> + '_ {
+        self.beats
+            .iter()
+            .skip(self.first_count_index)
+            .step_by(BEATS_PER_COUNT)
+            .copied()
+    }
+
+    #[must_use]
+    pub fn nearest_beat(&self, seconds: f64) -> Option<f64> {
+        nearest(self.beats.iter().copied(), seconds)
+    }
+
+    #[must_use]
+    pub fn nearest_count_start(&self, seconds: f64) -> Option<f64> {
+        nearest(self.count_starts(), seconds)
+    }
+}
+
+/// Rounds a scene timestamp to 100 ms, or moves it onto the detected beat grid.
+#[must_use]
+pub fn snap_timestamp(seconds: f64, snap: TimestampSnap, beat_grid: Option<&BeatGrid>) -> f64 {
+    let snapped = match (snap, beat_grid) {
+        (TimestampSnap::Beat, Some(grid)) => grid.nearest_beat(seconds),
+        (TimestampSnap::Count, Some(grid)) => grid.nearest_count_start(seconds),
+        _ => None,
+    };
+    match snapped {
+        Some(beat) => (beat * 1000.0).round() / 1000.0,
+        None => round_to_100_millis(seconds),
+    }
+}
+
+fn round_to_100_millis(seconds: f64) -> f64 {
+    let milliseconds = seconds * 1000.0;
+    let rounded = (milliseconds / 100.0).round() * 100.0;
+    rounded / 1000.0
+}
+
+fn nearest(values: impl Iterator<Item = f64>, seconds: f64) -> Option<f64> {
+    values.min_by(|left, right| {
+        (left - seconds)
+            .abs()
+            .partial_cmp(&(right - seconds).abs())
+            .unwrap_or(std::cmp::Ordering::Equal)
+    })
+}
+
+/// Detects tempo, beats and the eight-count start in mono samples.
+#[must_use]
+pub fn detect_beat_grid(samples: &[f32], sample_rate: u32) -> Option<BeatGrid> {
+    let mut envelope = OnsetEnvelope::new(sample_rate);
+    for sample in samples {
+        envelope.push(*sample);
+    }
+    envelope.finish().beat_grid()
+}
+
+/// Streams samples into log-energy frames and turns them into an onset strength curve.
+pub(crate) struct OnsetEnvelope {
+    sample_rate: u32,
+    hop: usize,
+    filled: usize,
+    sum_squares: f64,
+    energies: Vec<f64>,
+}
+
+impl OnsetEnvelope {
+    pub(crate) fn new(sample_rate: u32) -> Self {
+        let hop = (f64::from(sample_rate) / ENVELOPE_FRAMES_PER_SECOND).round() as usize;
+        Self {
+            sample_rate,
+            hop: hop.max(1),
+            filled: 0,
+            sum_squares: 0.0,
+            energies: Vec::new(),
+        }
+    }
+
+    pub(crate) fn push(&mut self, sample: f32) {
+        let sample = f64::from(sample);
+        self.sum_squares += sample * sample;
+        self.filled += 1;
+        if self.filled == self.hop {
+            let mean_square = self.sum_squares / self.hop as f64;
+            self.energies.push((1.0 + 1000.0 * mean_square).ln());
+            self.filled = 0;
+            self.sum_squares = 0.0;
+        }
+    }
+
+    pub(crate) fn finish(self) -> OnsetCurve {
+        let rises = std::iter::once(0.0)
+            .chain(
+                self.energies
+                    .windows(2)
+                    .map(|pair| (pair[1] - pair[0]).max(0.0)),
+            )
+            .collect::<Vec<_>>();
+
+        let mut prefix = Vec::with_capacity(rises.len() + 1);
+        prefix.push(0.0);
+        for rise in &rises {
+            prefix.push(prefix.last().copied().unwrap_or(0.0) + rise);
+        }
+        let onsets = rises
+            .iter()
+            .enumerate()
+            .map(|(index, rise)| {
+                let start = index.saturating_sub(ONSET_MEAN_RADIUS_FRAMES);
+                let end = (index + ONSET_MEAN_RADIUS_FRAMES + 1).min(rises.len());
+                let mean = (prefix[end] - prefix[start]) / (end - start) as f64;
+                (rise - mean).max(0.0)
+            })
+            .collect();
+        OnsetCurve {
+            onsets,
+            frames_per_second: f64::from(self.sample_rate) / self.hop as f64,
+        }
+    }
+}
+
+pub(crate) struct OnsetCurve {
+    onsets: Vec<f64>,
+    frames_per_second: f64,
+}
+
+impl OnsetCurve {
+    pub(crate) fn beat_grid(&self) -> Option<BeatGrid> {
+        let onsets = self.onsets.as_slice();
+        if (onsets.len() as f64) < MINIMUM_ANALYSIS_SECONDS * self.frames_per_second {
+            return None;
+        }
+        if onsets.iter().all(|onset| *onset <= 0.0) {
+            return None;
+        }
+
+        let period = estimate_period(onsets, self.frames_per_second)?;
+        let (first_beat, period) = fit_beat_phase(onsets, period);
+        let beats = (0..)
+            .map(|index| first_beat + index as f64 * period)
+            .take_while(|frame| *frame < onsets.len() as f64)
+            .collect::<Vec<_>>();
+        if beats.len() < BEATS_PER_COUNT {
+            return None;
+        }
+
+        Some(BeatGrid {
+            bpm: 60.0 * self.frames_per_second / period,
+            beats: beats
+                .iter()
+                .map(|frame| frame / self.frames_per_second)
+                .collect(),
+            first_count_index: first_count_index(onsets, &beats),
+        })
+    }
+}
+
+/// Beat period in envelope frames from the autocorrelation, weighted towards common tempi.
+fn estimate_period(onsets: &[f64], frames_per_second: f64) -> Option<f64> {
+    let shortest = (60.0 * frames_per_second / MAXIMUM_BPM).floor().max(2.0) as usize;
+    let longest = (60.0 * frames_per_second / MINIMUM_BPM).ceil() as usize;
+    let correlation = (0..=longest + 1)
+        .map(|lag| autocorrelation(onsets, lag))
+        .collect::<Vec<_>>();
+
+    let (best_lag, best_score) = (shortest..=longest)
+        .map(|lag| {
+            let bpm = 60.0 * frames_per_second / lag as f64;
+            let octaves = (bpm / PREFERRED_BPM).log2() / TEMPO_PRIOR_WIDTH_OCTAVES;
+            (lag, correlation[lag] * (-0.5 * octaves * octaves).exp())
+        })
+        .max_by(|left, right| {
+            left.1
+                .partial_cmp(&right.1)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })?;
+    if best_score <= 0.0 {
+        return None;
+    }
+
+    let before = correlation[best_lag - 1];
+    let peak = correlation[best_lag];
+    let after = correlation[best_lag + 1];
+    let curvature = before - 2.0 * peak + after;
+    let offset = if curvature < 0.0 {
+        (0.5 * (before - after) / curvature).clamp(-0.5, 0.5)
+    } else {
+        0.0
+    };
+    Some(best_lag as f64 + offset)
+}
+
+fn autocorrelation(onsets: &[f64], lag: usize) -> f64 {
+    if lag >= onsets.len() {
+        return 0.0;
+    }
+    let sum = onsets
+        .iter()
+        .zip(&onsets[lag..])
+        .map(|(left, right)| left * right)
+        .sum::<f64>();
+    sum / (onsets.len() - lag) as f64
+}
+
+/// Finds the first beat frame and refines the period with a least-squares fit to the onsets.
+fn fit_beat_phase(onsets: &[f64], period: f64) -> (f64, f64) {
+    let phase_score = |phase: f64| {
+        (0..)
+            .map(|index| phase + index as f64 * period)
+            .take_while(|frame| *frame < onsets.len() as f64)
+            .map(|frame| onsets[frame.round() as usize % onsets.len()])
+            .sum::<f64>()
+    };
+    let phase = (0..period.ceil() as usize)
+        .map(|phase| phase as f64)
+        .max_by(|left, right| {
+            phase_score(*left)
+                .partial_cmp(&phase_score(*right))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })
+        .unwrap_or(0.0);
+
+    let radius = (period * 0.1).round().max(1.0) as usize;
+    let peaks = (0..)
+        .map(|index| (index, phase + index as f64 * period))
+        .take_while(|(_, frame)| *frame < onsets.len() as f64)
+        .filter_map(|(index, frame)| {
+            let centre = frame.round() as usize;
+            let start = centre.saturating_sub(radius);
+            let end = (centre + radius + 1).min(onsets.len());
+            let (peak, strength) = (start..end)
+                .map(|position| (position, onsets[position]))
+                .max_by(|left, right| {
+                    left.1
+                        .partial_cmp(&right.1)
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                })?;
+            (strength > 0.0).then_some((index as f64, peak as f64))
+        })
+        .collect::<Vec<_>>();
+    if peaks.len() < BEATS_PER_COUNT {
+        return (phase, period);
+    }
+
+    let count = peaks.len() as f64;
+    let mean_index = peaks.iter().map(|(index, _)| index).sum::<f64>() / count;
+    let mean_frame = peaks.iter().map(|(_, frame)| frame).sum::<f64>() / count;
+    let covariance = peaks
+        .iter()
+        .map(|(index, frame)| (index - mean_index) * (frame - mean_frame))
+        .sum::<f64>();
+    let variance = peaks
+        .iter()
+        .map(|(index, _)| (index - mean_index).powi(2))
+        .sum::<f64>();
+    let fitted_period = covariance / variance;
+    if !fitted_period.is_finite() || (fitted_period - period).abs() > period * 0.05 {
+        return (phase, period);
+    }
+    let fitted_phase = mean_frame - fitted_period * mean_index;
+    let first_beat = fitted_phase.rem_euclid(fitted_period);
+    (first_beat, fitted_period)
+}
+
+/// Picks the strongest beat of the bar as "1", then the stronger of the two bars in an eight.
+fn first_count_index(onsets: &[f64], beats: &[f64]) -> usize {
+    let strengths = beats
+        .iter()
+        .map(|frame| {
+            let centre = frame.round() as usize;
+            let start = centre.saturating_sub(2);
+            let end = (centre + 3).min(onsets.len());
+            onsets[start..end].iter().copied().fold(0.0, f64::max)
+        })
+        .collect::<Vec<_>>();
+    let average_strength = |offset: usize, step: usize| {
+        let values = strengths
+            .iter()
+            .skip(offset)
+            .step_by(step)
+            .collect::<Vec<_>>();
+        if values.is_empty() {
+            0.0
+        } else {
+            values.iter().copied().sum::<f64>() / values.len() as f64
+        }
+    };
+
+    let downbeat = (0..BEATS_PER_BAR)
+        .max_by(|left, right| {
+            average_strength(*left, BEATS_PER_BAR)
+                .partial_cmp(&average_strength(*right, BEATS_PER_BAR))
+                .unwrap_or(std::cmp::Ordering::Equal)
+                // Prefer the earliest offset on ties.
+                .then(right.cmp(left))
+        })
+        .unwrap_or(0);
+    let second_bar = downbeat + BEATS_PER_BAR;
+    if average_strength(second_bar, BEATS_PER_COUNT) > average_strength(downbeat, BEATS_PER_COUNT) {
+        second_bar
+    } else {
+        downbeat
+    }
+}
@@ -1,19 +1,30 @@
 pub mod actions;
+pub mod audio_analysis;
+pub mod audio_format;
 pub mod audio_player_actor;
 pub mod audio_player_backend;
+pub mod beat_detection;
 pub mod messages;
 pub mod pipeline;
 pub mod reducer;
 pub mod runtime;
 pub mod state;
+pub mod time_stretch;
+pub mod timeline;
+pub mod translations;
 pub mod types;
 pub mod ui;
+pub mod waveform;
 
 pub use crate::haptics::HapticFeedback;
 pub use crate::haptics::NoopHapticFeedback;
 pub use crate::haptics::PlatformHapticFeedback;
+pub use audio_analysis::AudioAnalysis;
+pub use audio_format::AudioFormatError;
 pub use audio_player_actor::create_platform_audio_player;
 pub use audio_player_backend::AudioPlayerBackend;
+pub use beat_detection::BeatGrid;
+pub use beat_detection::TimestampSnap;
 pub use messages::AudioPlayerPositionChangedEvent;
 pub use messages::CloseAudioFileCommand;
 pub use messages::LinkSceneToPositionCommand;
@@ -23,4 +34,6 @@ pub use pipeline::AudioPlayerPipeline;
 pub use pipeline::AudioPlayerPipelineDependencies;
 pub use pipeline::build_audio_player_pipeline;
 pub use pipeline::reduce_with_haptics;
+pub use time_stretch::TimeStretcher;
 pub use types::{AudioPlayer, StreamFactory};
+pub use waveform::Waveform;
@@ -15,7 +15,9 @@ use crate::preferences::Preferences;
 use crate::time::format_seconds;
 
 use super::actions::AudioPlayerAction;
+use super::audio_format::probe_audio_file;
 use super::audio_player_backend::AudioPlayerBackend;
+use super::beat_detection::snap_timestamp;
 use super::messages::AudioPlayerPositionChangedEvent;
 use super::messages::CloseAudioFileCommand;
 use super::messages::LinkSceneToPositionCommand;
@@ -111,6 +113,16 @@ impl OpenAudioFileProcessor {
         );
 
         runtime.set_backend(selected_backend);
+        if has_audio_file && let Err(error) = probe_audio_file(Path::new(file_path.as_str())) {
+            runtime.close();
+            state.last_trace_context = command.trace_context;
+            let _ = reduce(
+                state,
+                AudioPlayerAction::OpenAudioFileFailed { file_path, error },
+            );
+            span.set_bool_attribute("choreo.success", false);
+            return;
+        }
         if has_audio_file {
             runtime.open_file(file_path.clone());
         } else {
@@ -224,7 +236,7 @@ impl AudioPlayerSceneLinkProcessor {
             }
 
             let updated = self.global_state.try_update(|global_state| {
-                handle_link_scene_to_position(state.position, global_state);
+                handle_link_scene_to_position(state, global_state);
             });
             if !updated {
                 span.set_bool_attribute("choreo.success", false);
@@ -260,6 +272,7 @@ impl AudioPlayerPositionPublisher {
                         let _ = sender.send(event.clone());
                     }
                 }
+                AudioPlayerEffect::TransportRequested { .. } => {}
             }
         }
     }
@@ -325,7 +338,7 @@ fn map_choreography_scene(scene: &SceneModel) -> AudioPlayerChoreographyScene {
     }
 }
 
-fn handle_link_scene_to_position(position: f64, global_state: &mut GlobalStateModel) {
+fn handle_link_scene_to_position(state: &AudioPlayerState, global_state: &mut GlobalStateModel) {
     let Some(selected_scene_id) = global_state
         .selected_scene
         .as_ref()
@@ -334,7 +347,7 @@ fn handle_link_scene_to_position(position: f64, global_state: &mut GlobalStateMo
         return;
     };
     let Some(linked_timestamp) =
-        try_get_linked_timestamp(position, selected_scene_id, &global_state.scenes)
+        try_get_linked_timestamp(state, selected_scene_id, &global_state.scenes)
     else {
         return;
     };
@@ -364,7 +377,7 @@ fn handle_link_scene_to_position(position: f64, global_state: &mut GlobalStateMo
 }
 
 fn try_get_linked_timestamp(
-    position: f64,
+    state: &AudioPlayerState,
     selected_scene_id: SceneId,
     scenes: &[SceneViewModel],
 ) -> Option<f64> {
@@ -379,25 +392,23 @@ fn try_get_linked_timestamp(
         .get(selected_index + 1)
         .and_then(|scene| scene.timestamp);
 
-    let rounded = round_to_100_millis(position);
+    let snapped = snap_timestamp(
+        state.position,
+        state.timestamp_snap,
+        state.beat_grid.as_ref(),
+    );
 
     if let Some(before) = before_timestamp
-        && rounded <= before
+        && snapped <= before
     {
         return None;
     }
 
     if let Some(after) = after_timestamp
-        && rounded >= after
+        && snapped >= after
     {
         return None;
     }
 
-    Some(rounded)
-}
-
-fn round_to_100_millis(seconds: f64) -> f64 {
-    let milliseconds = seconds * 1000.0;
-    let rounded = (milliseconds / 100.0).round() * 100.0;
-    rounded / 1000.0
+    Some(snapped)
 }
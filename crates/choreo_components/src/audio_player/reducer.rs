@@ -1,31 +1,49 @@
 use super::actions::AudioPlayerAction;
+use super::beat_detection::BeatGrid;
+use super::beat_detection::snap_timestamp;
 use super::state::AudioPlayerScene;
 use super::state::AudioPlayerState;
+use super::state::RehearsalLoop;
+use super::state::RehearsalPhase;
 use super::state::duration_label;
 use super::state::speed_to_percent_text;
+use super::time_stretch::clamp_playback_speed;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum AudioPlayerEffect {
-    PositionChangedPublished { position_seconds: f64 },
+    PositionChangedPublished {
+        position_seconds: f64,
+    },
+    /// The rehearsal loop moves the player; `play: false` pauses it at the position.
+    TransportRequested {
+        position_seconds: f64,
+        play: bool,
+    },
 }
 
+const MAXIMUM_REHEARSAL_GAP_SECONDS: f64 = 60.0;
+/// Lets the player report playback after a seek before a stopped player ends the repetition.
+const REHEARSAL_PLAYBACK_GRACE_SECONDS: f64 = 0.5;
+
 pub fn reduce(state: &mut AudioPlayerState, action: AudioPlayerAction) -> Vec<AudioPlayerEffect> {
     match action {
         AudioPlayerAction::Initialize => {
             state.speed_label = speed_to_percent_text(state.speed);
             state.duration_label = duration_label(state.position, state.duration);
-            state.tick_values = build_tick_values(state.duration, &state.scenes);
-            state.can_link_scene_to_position =
-                can_link_scene(state.position, state.selected_scene_id, &state.scenes);
+            state.tick_values =
+                build_tick_values(state.duration, &state.scenes, state.beat_grid.as_ref());
+            state.can_link_scene_to_position = can_link_scene(state);
             Vec::new()
         }
         AudioPlayerAction::TogglePlayPause => {
+            state.rehearsal_loop = None;
             if state.has_player {
                 state.is_playing = !state.is_playing;
             }
             Vec::new()
         }
         AudioPlayerAction::Stop => {
+            state.rehearsal_loop = None;
             state.is_playing = false;
             state.position = 0.0;
             state.duration_label = duration_label(state.position, state.duration);
@@ -35,13 +53,14 @@ pub fn reduce(state: &mut AudioPlayerState, action: AudioPlayerAction) -> Vec<Au
             if !state.can_seek {
                 return Vec::new();
             }
+            state.rehearsal_loop = None;
             state.position = position;
             state.duration_label = duration_label(state.position, state.duration);
-            state.can_link_scene_to_position =
-                can_link_scene(state.position, state.selected_scene_id, &state.scenes);
+            state.can_link_scene_to_position = can_link_scene(state);
             Vec::new()
         }
         AudioPlayerAction::PositionDragStarted => {
+            state.rehearsal_loop = None;
             state.was_playing_before_drag = state.is_playing;
             state.is_user_dragging = true;
             if state.is_playing {
@@ -67,8 +86,7 @@ pub fn reduce(state: &mut AudioPlayerState, action: AudioPlayerAction) -> Vec<Au
             state.is_playing = state.was_playing_before_drag;
             state.is_user_dragging = false;
             state.duration_label = duration_label(state.position, state.duration);
-            state.can_link_scene_to_position =
-                can_link_scene(state.position, state.selected_scene_id, &state.scenes);
+            state.can_link_scene_to_position = can_link_scene(state);
             Vec::new()
         }
         AudioPlayerAction::PlayerPositionSampled { position } => {
@@ -76,8 +94,7 @@ pub fn reduce(state: &mut AudioPlayerState, action: AudioPlayerAction) -> Vec<Au
                 if !state.is_user_dragging {
                     state.position = position;
                     state.duration_label = duration_label(state.position, state.duration);
-                    state.can_link_scene_to_position =
-                        can_link_scene(state.position, state.selected_scene_id, &state.scenes);
+                    state.can_link_scene_to_position = can_link_scene(state);
                 }
                 return Vec::new();
             };
@@ -87,8 +104,7 @@ pub fn reduce(state: &mut AudioPlayerState, action: AudioPlayerAction) -> Vec<Au
                 if !state.is_user_dragging {
                     state.position = position;
                     state.duration_label = duration_label(state.position, state.duration);
-                    state.can_link_scene_to_position =
-                        can_link_scene(state.position, state.selected_scene_id, &state.scenes);
+                    state.can_link_scene_to_position = can_link_scene(state);
                 }
             }
             Vec::new()
@@ -105,6 +121,15 @@ pub fn reduce(state: &mut AudioPlayerState, action: AudioPlayerAction) -> Vec<Au
             state.is_adjusting_speed = false;
             Vec::new()
         }
+        AudioPlayerAction::SetSpeedRange { minimum, maximum } => {
+            let minimum = clamp_playback_speed(minimum).min(1.0);
+            let maximum = clamp_playback_speed(maximum).max(1.0);
+            state.minimum_speed = minimum;
+            state.maximum_speed = maximum;
+            state.speed = state.speed.clamp(minimum, maximum);
+            state.speed_label = speed_to_percent_text(state.speed);
+            Vec::new()
+        }
         AudioPlayerAction::SetScenes {
             scenes,
             selected_scene_id,
@@ -116,9 +141,9 @@ pub fn reduce(state: &mut AudioPlayerState, action: AudioPlayerAction) -> Vec<Au
             Vec::new()
         }
         AudioPlayerAction::UpdateTicksAndLinkState => {
-            state.tick_values = build_tick_values(state.duration, &state.scenes);
-            state.can_link_scene_to_position =
-                can_link_scene(state.position, state.selected_scene_id, &state.scenes);
+            state.tick_values =
+                build_tick_values(state.duration, &state.scenes, state.beat_grid.as_ref());
+            state.can_link_scene_to_position = can_link_scene(state);
             Vec::new()
         }
         AudioPlayerAction::LinkSceneToPosition => {
@@ -126,31 +151,22 @@ pub fn reduce(state: &mut AudioPlayerState, action: AudioPlayerAction) -> Vec<Au
                 return Vec::new();
             };
 
-            let Some(linked_timestamp) =
-                try_get_linked_timestamp(state.position, selected_scene_id, &state.scenes)
-            else {
+            let Some(linked_timestamp) = try_get_linked_timestamp(state, selected_scene_id) else {
                 return Vec::new();
             };
 
-            if let Some(scene) = state
-                .scenes
-                .iter_mut()
-                .find(|scene| scene.scene_id == selected_scene_id)
-            {
-                scene.timestamp = Some(linked_timestamp);
-            }
-
-            if let Some(scene) = state
-                .choreography_scenes
-                .iter_mut()
-                .find(|scene| scene.scene_id == selected_scene_id)
-            {
-                scene.timestamp = Some(format_seconds(linked_timestamp));
-            }
+            set_scene_timestamp(state, selected_scene_id, linked_timestamp);
+            Vec::new()
+        }
+        AudioPlayerAction::MoveSceneTimestamp {
+            scene_id,
+            timestamp,
+        } => {
+            let Some(timestamp) = try_get_scene_timestamp(state, scene_id, timestamp) else {
+                return Vec::new();
+            };
 
-            state.tick_values = build_tick_values(state.duration, &state.scenes);
-            state.can_link_scene_to_position =
-                can_link_scene(state.position, state.selected_scene_id, &state.scenes);
+            set_scene_timestamp(state, scene_id, timestamp);
             Vec::new()
         }
         AudioPlayerAction::OpenAudioFile {
@@ -161,22 +177,36 @@ pub fn reduce(state: &mut AudioPlayerState, action: AudioPlayerAction) -> Vec<Au
                 return Vec::new();
             }
 
+            state.rehearsal_loop = None;
             state.last_opened_audio_file_path = Some(file_path);
+            state.open_audio_error = None;
             state.has_stream_factory = true;
             state.has_player = file_exists;
+            clear_audio_analysis(state);
 
             if !file_exists {
-                state.can_seek = false;
-                state.can_set_speed = false;
-                state.duration = 0.0;
-                state.position = 0.0;
-                state.is_playing = false;
-                state.duration_label = duration_label(state.position, state.duration);
+                reset_unplayable_audio(state);
             }
 
             Vec::new()
         }
+        AudioPlayerAction::OpenAudioFileFailed { file_path, error } => {
+            if file_path.trim().is_empty() {
+                return Vec::new();
+            }
+
+            state.rehearsal_loop = None;
+            state.last_opened_audio_file_path = Some(file_path);
+            state.open_audio_error = Some(error);
+            state.has_stream_factory = true;
+            state.has_player = false;
+            reset_unplayable_audio(state);
+            clear_audio_analysis(state);
+            Vec::new()
+        }
         AudioPlayerAction::CloseAudioFile => {
+            state.rehearsal_loop = None;
+            state.open_audio_error = None;
             state.has_player = false;
             state.has_stream_factory = false;
             state.position = 0.0;
@@ -187,6 +217,39 @@ pub fn reduce(state: &mut AudioPlayerState, action: AudioPlayerAction) -> Vec<Au
             state.pending_seek_position = None;
             state.duration_label = duration_label(state.position, state.duration);
             state.can_link_scene_to_position = false;
+            clear_audio_analysis(state);
+            Vec::new()
+        }
+        AudioPlayerAction::BeatGridDetected {
+            file_path,
+            beat_grid,
+        } => {
+            if state.last_opened_audio_file_path.as_deref() != Some(file_path.as_str()) {
+                return Vec::new();
+            }
+
+            state.beat_grid = beat_grid;
+            state.tick_values =
+                build_tick_values(state.duration, &state.scenes, state.beat_grid.as_ref());
+            state.can_link_scene_to_position = can_link_scene(state);
+            Vec::new()
+        }
+        AudioPlayerAction::WaveformComputed {
+            file_path,
+            waveform,
+        } => {
+            if state.last_opened_audio_file_path.as_deref() == Some(file_path.as_str()) {
+                state.waveform = waveform;
+            }
+            Vec::new()
+        }
+        AudioPlayerAction::UpdateTimestampSnap { snap } => {
+            state.timestamp_snap = snap;
+            state.can_link_scene_to_position = can_link_scene(state);
+            Vec::new()
+        }
+        AudioPlayerAction::SetMusicalTimeline { timeline } => {
+            state.musical_timeline = timeline;
             Vec::new()
         }
         AudioPlayerAction::PublishPositionIfChanged => {
@@ -203,6 +266,152 @@ pub fn reduce(state: &mut AudioPlayerState, action: AudioPlayerAction) -> Vec<Au
                 position_seconds: state.position,
             }]
         }
+        AudioPlayerAction::StartRehearsalLoop {
+            start_seconds,
+            end_seconds,
+        } => {
+            let end_seconds = if state.duration > 0.0 {
+                end_seconds.min(state.duration)
+            } else {
+                end_seconds
+            };
+            if !state.has_player || !start_seconds.is_finite() || end_seconds <= start_seconds {
+                return Vec::new();
+            }
+
+            state.rehearsal_loop = Some(RehearsalLoop {
+                start_seconds,
+                end_seconds,
+                phase: RehearsalPhase::CountIn {
+                    remaining_seconds: state.preparation_seconds,
+                },
+                repetition: 1,
+            });
+            hold_rehearsal_at(state, start_seconds);
+            Vec::new()
+        }
+        AudioPlayerAction::StopRehearsalLoop => {
+            if state.rehearsal_loop.take().is_some() {
+                state.is_playing = false;
+            }
+            Vec::new()
+        }
+        AudioPlayerAction::RehearsalTick { elapsed_seconds } => {
+            advance_rehearsal_loop(state, elapsed_seconds.max(0.0))
+        }
+        AudioPlayerAction::UpdatePreparationSeconds { seconds } => {
+            state.preparation_seconds = clamp_rehearsal_gap(seconds);
+            Vec::new()
+        }
+        AudioPlayerAction::UpdatePauseSeconds { seconds } => {
+            state.pause_seconds = clamp_rehearsal_gap(seconds);
+            Vec::new()
+        }
+    }
+}
+
+/// Counts in at the loop start, plays to the loop end, pauses there and starts over.
+fn advance_rehearsal_loop(
+    state: &mut AudioPlayerState,
+    elapsed_seconds: f64,
+) -> Vec<AudioPlayerEffect> {
+    let Some(mut rehearsal) = state.rehearsal_loop else {
+        return Vec::new();
+    };
+
+    let mut effects = Vec::new();
+    rehearsal.phase = match rehearsal.phase {
+        RehearsalPhase::CountIn { remaining_seconds } => {
+            let remaining_seconds = remaining_seconds - elapsed_seconds;
+            if remaining_seconds > 0.0 {
+                RehearsalPhase::CountIn { remaining_seconds }
+            } else {
+                state.is_playing = true;
+                effects.push(AudioPlayerEffect::TransportRequested {
+                    position_seconds: rehearsal.start_seconds,
+                    play: true,
+                });
+                RehearsalPhase::Playing {
+                    elapsed_seconds: 0.0,
+                }
+            }
+        }
+        RehearsalPhase::Playing {
+            elapsed_seconds: played_seconds,
+        } => {
+            let played_seconds = played_seconds + elapsed_seconds;
+            if played_seconds > REHEARSAL_PLAYBACK_GRACE_SECONDS {
+                // A seek the player never confirmed exactly must not freeze the position.
+                state.pending_seek_position = None;
+            }
+            let reached_end =
+                state.pending_seek_position.is_none() && state.position >= rehearsal.end_seconds;
+            let player_stopped =
+                !state.is_playing && played_seconds > REHEARSAL_PLAYBACK_GRACE_SECONDS;
+            if reached_end || player_stopped {
+                hold_rehearsal_at(state, rehearsal.end_seconds);
+                effects.push(AudioPlayerEffect::TransportRequested {
+                    position_seconds: rehearsal.end_seconds,
+                    play: false,
+                });
+                RehearsalPhase::Pause {
+                    remaining_seconds: state.pause_seconds,
+                }
+            } else {
+                RehearsalPhase::Playing {
+                    elapsed_seconds: played_seconds,
+                }
+            }
+        }
+        RehearsalPhase::Pause { remaining_seconds } => {
+            let remaining_seconds = remaining_seconds - elapsed_seconds;
+            if remaining_seconds > 0.0 {
+                RehearsalPhase::Pause { remaining_seconds }
+            } else {
+                rehearsal.repetition += 1;
+                hold_rehearsal_at(state, rehearsal.start_seconds);
+                effects.push(AudioPlayerEffect::TransportRequested {
+                    position_seconds: rehearsal.start_seconds,
+                    play: false,
+                });
+                RehearsalPhase::CountIn {
+                    remaining_seconds: state.preparation_seconds,
+                }
+            }
+        }
+    };
+    state.rehearsal_loop = Some(rehearsal);
+    effects
+}
+
+fn reset_unplayable_audio(state: &mut AudioPlayerState) {
+    state.can_seek = false;
+    state.can_set_speed = false;
+    state.duration = 0.0;
+    state.position = 0.0;
+    state.is_playing = false;
+    state.duration_label = duration_label(state.position, state.duration);
+}
+
+fn clear_audio_analysis(state: &mut AudioPlayerState) {
+    state.beat_grid = None;
+    state.waveform = None;
+    state.tick_values = build_tick_values(state.duration, &state.scenes, None);
+}
+
+fn hold_rehearsal_at(state: &mut AudioPlayerState, position: f64) {
+    state.is_playing = false;
+    state.position = position;
+    state.pending_seek_position = Some(position);
+    state.duration_label = duration_label(state.position, state.duration);
+    state.can_link_scene_to_position = can_link_scene(state);
+}
+
+fn clamp_rehearsal_gap(seconds: f64) -> f64 {
+    if seconds.is_finite() {
+        seconds.clamp(0.0, MAXIMUM_REHEARSAL_GAP_SECONDS)
+    } else {
+        0.0
     }
 }
 
@@ -212,10 +421,17 @@ fn snap_speed(value: f64, minimum: f64, maximum: f64, step: f64) -> f64 {
     snapped.clamp(minimum, maximum)
 }
 
-fn build_tick_values(duration: f64, scenes: &[AudioPlayerScene]) -> Vec<f64> {
+/// Scene timestamps plus the start of every eight-count; single beats would crowd the slider.
+fn build_tick_values(
+    duration: f64,
+    scenes: &[AudioPlayerScene],
+    beat_grid: Option<&BeatGrid>,
+) -> Vec<f64> {
+    let count_starts = beat_grid.into_iter().flat_map(BeatGrid::count_starts);
     let mut ticks: Vec<f64> = scenes
         .iter()
         .filter_map(|scene| scene.timestamp)
+        .chain(count_starts)
         .filter(|value| duration <= 0.0 || *value <= duration)
         .collect();
     ticks.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
@@ -223,21 +439,48 @@ fn build_tick_values(duration: f64, scenes: &[AudioPlayerScene]) -> Vec<f64> {
     ticks
 }
 
-fn can_link_scene(
-    position: f64,
-    selected_scene_id: Option<i32>,
-    scenes: &[AudioPlayerScene],
-) -> bool {
-    selected_scene_id
-        .and_then(|scene_id| try_get_linked_timestamp(position, scene_id, scenes))
+fn can_link_scene(state: &AudioPlayerState) -> bool {
+    state
+        .selected_scene_id
+        .and_then(|scene_id| try_get_linked_timestamp(state, scene_id))
         .is_some()
 }
 
-fn try_get_linked_timestamp(
-    position: f64,
+fn set_scene_timestamp(state: &mut AudioPlayerState, scene_id: i32, timestamp: f64) {
+    if let Some(scene) = state
+        .scenes
+        .iter_mut()
+        .find(|scene| scene.scene_id == scene_id)
+    {
+        scene.timestamp = Some(timestamp);
+    }
+
+    if let Some(scene) = state
+        .choreography_scenes
+        .iter_mut()
+        .find(|scene| scene.scene_id == scene_id)
+    {
+        scene.timestamp = Some(format_seconds(timestamp));
+    }
+
+    state.tick_values = build_tick_values(state.duration, &state.scenes, state.beat_grid.as_ref());
+    state.can_link_scene_to_position = can_link_scene(state);
+}
+
+fn try_get_linked_timestamp(state: &AudioPlayerState, selected_scene_id: i32) -> Option<f64> {
+    try_get_scene_timestamp(state, selected_scene_id, state.position)
+}
+
+/// Snaps `position` and keeps it strictly between the neighbouring scenes' timestamps.
+fn try_get_scene_timestamp(
+    state: &AudioPlayerState,
     selected_scene_id: i32,
-    scenes: &[AudioPlayerScene],
+    position: f64,
 ) -> Option<f64> {
+    if !position.is_finite() || position < 0.0 {
+        return None;
+    }
+    let scenes = &state.scenes;
     let selected_index = scenes
         .iter()
         .position(|scene| scene.scene_id == selected_scene_id)?;
@@ -249,27 +492,21 @@ fn try_get_linked_timestamp(
         .get(selected_index + 1)
         .and_then(|scene| scene.timestamp);
 
-    let rounded = round_to_100_millis(position);
+    let snapped = snap_timestamp(position, state.timestamp_snap, state.beat_grid.as_ref());
 
     if let Some(before) = before_timestamp
-        && rounded <= before
+        && snapped <= before
     {
         return None;
     }
 
     if let Some(after) = after_timestamp
-        && rounded >= after
+        && snapped >= after
     {
         return None;
     }
 
-    Some(rounded)
-}
-
-fn round_to_100_millis(seconds: f64) -> f64 {
-    let milliseconds = seconds * 1000.0;
-    let rounded = (milliseconds / 100.0).round() * 100.0;
-    rounded / 1000.0
+    Some(snapped)
 }
 
 fn format_seconds(seconds: f64) -> String {
@@ -278,7 +515,11 @@ fn format_seconds(seconds: f64) -> String {
     } else {
         seconds
     };
-    let mut text = format!("{normalized:.1}");
+    // Beat-snapped timestamps keep milliseconds; 100 ms ones still print with one decimal.
+    let mut text = format!("{normalized:.3}");
+    while text.ends_with('0') && !text.ends_with(".0") {
+        text.pop();
+    }
     if text == "-0.0" {
         text = "0.0".to_string();
     }
@@ -1,6 +1,8 @@
 use std::fs::File;
 use std::sync::Arc;
 use std::sync::Mutex;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
 use std::thread;
 use std::thread::JoinHandle;
 use std::time::Duration;
@@ -10,17 +12,23 @@ use crossbeam_channel::Receiver;
 use crossbeam_channel::Sender;
 use crossbeam_channel::TrySendError;
 use crossbeam_channel::bounded;
+use rodio::ChannelCount;
 use rodio::Decoder;
 use rodio::DeviceSinkBuilder;
 use rodio::MixerDeviceSink;
 use rodio::Player;
+use rodio::Sample;
+use rodio::SampleRate;
 use rodio::Source;
+use rodio::source::SeekError;
 use symphonia::core::formats::FormatOptions;
 use symphonia::core::io::MediaSourceStream;
 use symphonia::core::meta::MetadataOptions;
 use symphonia::core::probe::Hint;
 use symphonia::default::get_probe;
 
+use super::super::time_stretch::TimeStretcher;
+use super::super::time_stretch::clamp_playback_speed;
 use super::super::types::AudioPlayer;
 use super::super::types::AudioPlayerSample;
 
@@ -218,6 +226,7 @@ enum Engine {
     Rodio {
         _device_sink: MixerDeviceSink,
         player: Player,
+        control: Arc<TimeStretchControl>,
     },
     Silent,
 }
@@ -236,8 +245,8 @@ impl Engine {
     }
 
     fn set_speed(&self, speed: f64) {
-        if let Self::Rodio { player, .. } = self {
-            player.set_speed(speed as f32);
+        if let Self::Rodio { control, .. } = self {
+            control.set_speed(speed);
         }
     }
 
@@ -256,7 +265,7 @@ impl Engine {
 
     fn position(&self) -> Option<f64> {
         match self {
-            Self::Rodio { player, .. } => Some(player.get_pos().as_secs_f64()),
+            Self::Rodio { control, .. } => Some(control.position_seconds()),
             Self::Silent => None,
         }
     }
@@ -361,7 +370,7 @@ impl RodioRuntime {
             }
             AudioCommand::SetSpeed(speed) => {
                 self.sync_position();
-                self.speed = speed.clamp(0.5, 2.0);
+                self.speed = clamp_playback_speed(speed);
                 self.engine.set_speed(self.speed);
                 if self.is_playing {
                     self.last_started_at = Some(Instant::now());
@@ -456,14 +465,14 @@ impl RodioRuntime {
         };
 
         let player = Player::connect_new(device_sink.mixer());
-        let loaded = append_source(&player, &self.file_path, start_seconds);
+        let control = Arc::new(TimeStretchControl::new(self.speed, start_seconds));
+        let loaded = append_source(&player, &self.file_path, start_seconds, &control);
         if !loaded {
             self.engine = Engine::Silent;
             return;
         }
 
         player.set_volume(self.volume as f32);
-        player.set_speed(self.speed as f32);
         if should_play {
             player.play();
         } else {
@@ -473,11 +482,17 @@ impl RodioRuntime {
         self.engine = Engine::Rodio {
             _device_sink: device_sink,
             player,
+            control,
         };
     }
 }
 
-fn append_source(player: &Player, file_path: &str, start_seconds: f64) -> bool {
+fn append_source(
+    player: &Player,
+    file_path: &str,
+    start_seconds: f64,
+    control: &Arc<TimeStretchControl>,
+) -> bool {
     let Some(file) = File::open(file_path).ok() else {
         return false;
     };
@@ -485,14 +500,134 @@ fn append_source(player: &Player, file_path: &str, start_seconds: f64) -> bool {
         return false;
     };
 
+    // The player itself stays at 1x; speed is applied by the stretch so pitch is kept.
     if start_seconds > 0.0 {
-        player.append(decoder.skip_duration(Duration::from_secs_f64(start_seconds)));
+        player.append(TimeStretchSource::new(
+            decoder.skip_duration(Duration::from_secs_f64(start_seconds)),
+            start_seconds,
+            Arc::clone(control),
+        ));
     } else {
-        player.append(decoder);
+        player.append(TimeStretchSource::new(decoder, 0.0, Arc::clone(control)));
     }
     true
 }
 
+/// Lets the runtime change the speed of, and read the position from, the playing source.
+struct TimeStretchControl {
+    speed: AtomicU64,
+    position_seconds: AtomicU64,
+}
+
+impl TimeStretchControl {
+    fn new(speed: f64, position_seconds: f64) -> Self {
+        Self {
+            speed: AtomicU64::new(speed.to_bits()),
+            position_seconds: AtomicU64::new(position_seconds.to_bits()),
+        }
+    }
+
+    fn speed(&self) -> f64 {
+        f64::from_bits(self.speed.load(Ordering::Relaxed))
+    }
+
+    fn set_speed(&self, speed: f64) {
+        self.speed.store(speed.to_bits(), Ordering::Relaxed);
+    }
+
+    fn position_seconds(&self) -> f64 {
+        f64::from_bits(self.position_seconds.load(Ordering::Relaxed))
+    }
+
+    fn set_position_seconds(&self, position_seconds: f64) {
+        self.position_seconds
+            .store(position_seconds.to_bits(), Ordering::Relaxed);
+    }
+}
+
+/// Plays `inner` at the control's speed without changing its pitch.
+struct TimeStretchSource<S> {
+    inner: S,
+    channels: ChannelCount,
+    sample_rate: SampleRate,
+    stretcher: TimeStretcher,
+    control: Arc<TimeStretchControl>,
+}
+
+impl<S: Source> TimeStretchSource<S> {
+    fn new(inner: S, start_seconds: f64, control: Arc<TimeStretchControl>) -> Self {
+        let channels = inner.channels();
+        let sample_rate = inner.sample_rate();
+        let mut stretcher = TimeStretcher::new(channels.get(), sample_rate.get(), control.speed());
+        stretcher.reset(frames_for_seconds(start_seconds, sample_rate));
+        Self {
+            inner,
+            channels,
+            sample_rate,
+            stretcher,
+            control,
+        }
+    }
+}
+
+impl<S: Source> Iterator for TimeStretchSource<S> {
+    type Item = Sample;
+
+    fn next(&mut self) -> Option<Sample> {
+        self.stretcher.set_speed(self.control.speed());
+        loop {
+            if let Some(sample) = self.stretcher.pop_sample() {
+                self.control.set_position_seconds(
+                    self.stretcher.position_frames() / f64::from(self.sample_rate.get()),
+                );
+                return Some(sample);
+            }
+            if self.stretcher.is_finished() {
+                return None;
+            }
+            for _ in 0..self.channels.get() {
+                match self.inner.next() {
+                    Some(sample) => self.stretcher.push_sample(sample),
+                    None => {
+                        self.stretcher.end_input();
+                        break;
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<S: Source> Source for TimeStretchSource<S> {
+    fn current_span_len(&self) -> Option<usize> {
+        None
+    }
+
+    fn channels(&self) -> ChannelCount {
+        self.channels
+    }
+
+    fn sample_rate(&self) -> SampleRate {
+        self.sample_rate
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        None
+    }
+
+    fn try_seek(&mut self, position: Duration) -> Result<(), SeekError> {
+        self.inner.try_seek(position)?;
+        self.stretcher
+            .reset(frames_for_seconds(position.as_secs_f64(), self.sample_rate));
+        self.control.set_position_seconds(position.as_secs_f64());
+        Ok(())
+    }
+}
+
+fn frames_for_seconds(seconds: f64, sample_rate: SampleRate) -> usize {
+    (seconds.max(0.0) * f64::from(sample_rate.get())).round() as usize
+}
+
 pub(super) fn read_duration_seconds(file_path: &str) -> f64 {
     let Some(file) = File::open(file_path).ok() else {
         return 0.0;
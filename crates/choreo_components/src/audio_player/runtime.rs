@@ -1,3 +1,5 @@
+use super::audio_analysis::AudioAnalysis;
+use super::audio_analysis::AudioAnalyzer;
 use super::audio_player_actor::create_platform_audio_player;
 use super::audio_player_backend::AudioPlayerBackend;
 use super::state::AudioPlayerState;
@@ -9,6 +11,7 @@ use super::types::AudioPlayerSample;
 pub struct AudioPlayerRuntime {
     backend: AudioPlayerBackend,
     player: Option<Box<dyn AudioPlayer>>,
+    audio_analyzer: AudioAnalyzer,
 }
 
 impl AudioPlayerRuntime {
@@ -17,6 +20,7 @@ impl AudioPlayerRuntime {
         Self {
             backend,
             player: None,
+            audio_analyzer: AudioAnalyzer::default(),
         }
     }
 
@@ -39,6 +43,21 @@ impl AudioPlayerRuntime {
 
     pub fn close(&mut self) {
         self.player = None;
+        self.audio_analyzer.cancel();
+    }
+
+    /// Starts detecting the beat grid and waveform of `file_path`, or reuses the cached result.
+    pub fn request_audio_analysis(&mut self, file_path: &str) {
+        self.audio_analyzer.request(file_path);
+    }
+
+    pub fn poll_audio_analysis(&mut self) -> Option<(String, AudioAnalysis)> {
+        self.audio_analyzer.poll()
+    }
+
+    #[must_use]
+    pub fn is_analyzing_audio(&self) -> bool {
+        self.audio_analyzer.is_pending()
     }
 
     #[must_use]
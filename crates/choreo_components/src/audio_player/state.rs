@@ -1,4 +1,11 @@
+use super::audio_format::AudioFormatError;
+use super::beat_detection::BeatGrid;
+use super::beat_detection::TimestampSnap;
+use super::time_stretch::MAXIMUM_PLAYBACK_SPEED;
+use super::time_stretch::MINIMUM_PLAYBACK_SPEED;
+use super::waveform::Waveform;
 use crate::observability::TraceContext;
+use choreo_master_mobile_json::MusicalTimeline;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct AudioPlayerScene {
@@ -13,6 +20,23 @@ pub struct AudioPlayerChoreographyScene {
     pub timestamp: Option<String>,
 }
 
+/// Where a rehearsal repetition currently is; the count-in and pause run on wall-clock time.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RehearsalPhase {
+    CountIn { remaining_seconds: f64 },
+    Playing { elapsed_seconds: f64 },
+    Pause { remaining_seconds: f64 },
+}
+
+/// Repeats the audio between two scene timestamps until it is stopped.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RehearsalLoop {
+    pub start_seconds: f64,
+    pub end_seconds: f64,
+    pub phase: RehearsalPhase,
+    pub repetition: u32,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct AudioPlayerState {
     pub speed: f64,
@@ -34,11 +58,18 @@ pub struct AudioPlayerState {
     pub has_player: bool,
     pub preparation_seconds: f64,
     pub pause_seconds: f64,
+    pub rehearsal_loop: Option<RehearsalLoop>,
     pub is_user_dragging: bool,
     pub was_playing_before_drag: bool,
     pub is_adjusting_speed: bool,
     pub pending_seek_position: Option<f64>,
     pub last_opened_audio_file_path: Option<String>,
+    pub open_audio_error: Option<AudioFormatError>,
+    /// Detected in the background after a file opens; `None` until then or without a beat.
+    pub beat_grid: Option<BeatGrid>,
+    pub timestamp_snap: TimestampSnap,
+    pub waveform: Option<Waveform>,
+    pub musical_timeline: Option<MusicalTimeline>,
     pub scenes: Vec<AudioPlayerScene>,
     pub selected_scene_id: Option<i32>,
     pub choreography_scenes: Vec<AudioPlayerChoreographyScene>,
@@ -50,8 +81,8 @@ impl Default for AudioPlayerState {
     fn default() -> Self {
         Self {
             speed: 1.0,
-            minimum_speed: 0.8,
-            maximum_speed: 1.1,
+            minimum_speed: MINIMUM_PLAYBACK_SPEED,
+            maximum_speed: MAXIMUM_PLAYBACK_SPEED,
             volume: 1.0,
             balance: 0.0,
             duration: 0.0,
@@ -68,11 +99,17 @@ impl Default for AudioPlayerState {
             has_player: false,
             preparation_seconds: 4.0,
             pause_seconds: 0.0,
+            rehearsal_loop: None,
             is_user_dragging: false,
             was_playing_before_drag: false,
             is_adjusting_speed: false,
             pending_seek_position: None,
             last_opened_audio_file_path: None,
+            open_audio_error: None,
+            beat_grid: None,
+            timestamp_snap: TimestampSnap::Off,
+            waveform: None,
+            musical_timeline: None,
             scenes: Vec::new(),
             selected_scene_id: None,
             choreography_scenes: Vec::new(),
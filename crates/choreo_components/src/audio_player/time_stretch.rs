@@ -0,0 +1,279 @@
+use std::f32::consts::PI;
+
+/// Slowest speed the native players can play at without changing pitch.
+pub const MINIMUM_PLAYBACK_SPEED: f64 = 0.5;
+/// Fastest speed the native players can play at without changing pitch.
+pub const MAXIMUM_PLAYBACK_SPEED: f64 = 1.5;
+
+const WINDOW_SECONDS: f64 = 0.04;
+const SEEK_SECONDS: f64 = 0.012;
+/// Every n-th frame is compared while searching for the best splice point.
+const CORRELATION_STRIDE: usize = 4;
+
+/// Changes playback speed without changing pitch (WSOLA).
+///
+/// Windows of the input are overlap-added half a window apart; each window is taken from near
+/// where the speed says playback should be, shifted to where it best continues the previous one.
+pub struct TimeStretcher {
+    channels: usize,
+    window_frames: usize,
+    hop_frames: usize,
+    seek_frames: usize,
+    window: Vec<f32>,
+    speed: f64,
+    input: Vec<f32>,
+    mono: Vec<f32>,
+    partial_frame: Vec<f32>,
+    input_origin: usize,
+    input_ended: bool,
+    analysis_position: f64,
+    previous_start: Option<usize>,
+    accumulator: Vec<f32>,
+    output: Vec<f32>,
+    output_read: usize,
+    output_media_start: f64,
+    output_media_step: f64,
+    finished: bool,
+}
+
+impl TimeStretcher {
+    #[must_use]
+    pub fn new(channels: u16, sample_rate: u32, speed: f64) -> Self {
+        let channels = usize::from(channels.max(1));
+        let sample_rate = f64::from(sample_rate.max(1));
+        let hop_frames = ((sample_rate * WINDOW_SECONDS / 2.0).round() as usize).max(1);
+        let window_frames = hop_frames * 2;
+        let window = (0..window_frames)
+            .map(|frame| 0.5 - 0.5 * (2.0 * PI * frame as f32 / window_frames as f32).cos())
+            .collect();
+        Self {
+            channels,
+            window_frames,
+            hop_frames,
+            seek_frames: (sample_rate * SEEK_SECONDS).round() as usize,
+            window,
+            speed: clamp_playback_speed(speed),
+            input: Vec::new(),
+            mono: Vec::new(),
+            partial_frame: Vec::with_capacity(channels),
+            input_origin: 0,
+            input_ended: false,
+            analysis_position: 0.0,
+            previous_start: None,
+            accumulator: vec![0.0; window_frames * channels],
+            output: Vec::new(),
+            output_read: 0,
+            output_media_start: 0.0,
+            output_media_step: 1.0,
+            finished: false,
+        }
+    }
+
+    #[must_use]
+    pub fn speed(&self) -> f64 {
+        self.speed
+    }
+
+    /// Takes effect from the next window, so it can change while playing.
+    pub fn set_speed(&mut self, speed: f64) {
+        self.speed = clamp_playback_speed(speed);
+    }
+
+    /// Drops everything buffered and continues from input frame `position_frames`.
+    pub fn reset(&mut self, position_frames: usize) {
+        self.input.clear();
+        self.mono.clear();
+        self.partial_frame.clear();
+        self.input_origin = position_frames;
+        self.input_ended = false;
+        self.analysis_position = position_frames as f64;
+        self.previous_start = None;
+        self.accumulator.fill(0.0);
+        self.output.clear();
+        self.output_read = 0;
+        self.output_media_start = position_frames as f64;
+        self.output_media_step = self.speed;
+        self.finished = false;
+    }
+
+    /// Adds one interleaved input sample.
+    pub fn push_sample(&mut self, sample: f32) {
+        self.partial_frame.push(sample);
+        if self.partial_frame.len() < self.channels {
+            return;
+        }
+        self.mono
+            .push(self.partial_frame.iter().sum::<f32>() / self.channels as f32);
+        self.input.append(&mut self.partial_frame);
+    }
+
+    /// Marks the end of the input; the buffered tail is still played out.
+    pub fn end_input(&mut self) {
+        if !self.partial_frame.is_empty() {
+            self.partial_frame.resize(self.channels, 0.0);
+            let frame = std::mem::take(&mut self.partial_frame);
+            for sample in frame {
+                self.push_sample(sample);
+            }
+        }
+        self.input_ended = true;
+    }
+
+    /// Returns the next interleaved output sample, or `None` when more input is needed.
+    pub fn pop_sample(&mut self) -> Option<f32> {
+        while self.output_read >= self.output.len() {
+            if !self.synthesize_hop() {
+                return None;
+            }
+        }
+        let sample = self.output[self.output_read];
+        self.output_read += 1;
+        Some(sample)
+    }
+
+    /// Whether all input has been played out.
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.finished && self.output_read >= self.output.len()
+    }
+
+    /// Input frame that the next output sample plays.
+    #[must_use]
+    pub fn position_frames(&self) -> f64 {
+        let played_frames = (self.output_read / self.channels) as f64;
+        self.output_media_start + played_frames * self.output_media_step
+    }
+
+    fn input_end(&self) -> usize {
+        self.input_origin + self.mono.len()
+    }
+
+    fn synthesize_hop(&mut self) -> bool {
+        if self.finished {
+            return false;
+        }
+
+        let nominal = self.analysis_position.round() as usize;
+        if !self.input_ended && nominal + self.seek_frames + self.window_frames > self.input_end() {
+            return false;
+        }
+
+        self.output.clear();
+        self.output_read = 0;
+        self.output_media_start = self.analysis_position;
+        self.output_media_step = self.speed;
+
+        if self.input_ended && nominal >= self.input_end() {
+            // Only the second half of the last window is left.
+            self.emit_hop();
+            self.finished = true;
+            return true;
+        }
+
+        let start = self.best_start(nominal);
+        for frame in 0..self.window_frames {
+            let weight = self.window[frame];
+            for channel in 0..self.channels {
+                self.accumulator[frame * self.channels + channel] +=
+                    weight * self.input_sample(start + frame, channel);
+            }
+        }
+        self.emit_hop();
+
+        self.previous_start = Some(start);
+        self.analysis_position += self.hop_frames as f64 * self.speed;
+        self.discard_consumed_input(start);
+        true
+    }
+
+    fn emit_hop(&mut self) {
+        let hop_samples = self.hop_frames * self.channels;
+        self.output
+            .extend_from_slice(&self.accumulator[..hop_samples]);
+        self.accumulator.copy_within(hop_samples.., 0);
+        let length = self.accumulator.len();
+        self.accumulator[length - hop_samples..].fill(0.0);
+    }
+
+    /// Shifts the window by up to the seek range so it lines up with the natural continuation of
+    /// the previous window.
+    fn best_start(&self, nominal: usize) -> usize {
+        let Some(previous_start) = self.previous_start else {
+            return nominal;
+        };
+        let natural = previous_start + self.hop_frames;
+        if natural == nominal {
+            return nominal;
+        }
+
+        let lowest = nominal
+            .saturating_sub(self.seek_frames)
+            .max(self.input_origin);
+        let highest = nominal + self.seek_frames;
+        let coarse = (lowest..=highest)
+            .step_by(CORRELATION_STRIDE)
+            .max_by(|left, right| {
+                self.similarity(*left, natural)
+                    .total_cmp(&self.similarity(*right, natural))
+            })
+            .unwrap_or(nominal);
+        let refine_low = coarse.saturating_sub(CORRELATION_STRIDE).max(lowest);
+        let refine_high = (coarse + CORRELATION_STRIDE).min(highest);
+        (refine_low..=refine_high)
+            .max_by(|left, right| {
+                self.similarity(*left, natural)
+                    .total_cmp(&self.similarity(*right, natural))
+            })
+            .unwrap_or(coarse)
+    }
+
+    fn similarity(&self, candidate: usize, natural: usize) -> f32 {
+        let overlap = self.window_frames - self.hop_frames;
+        let mut correlation = 0.0;
+        let mut energy = 0.0;
+        for offset in (0..overlap).step_by(CORRELATION_STRIDE) {
+            let sample = self.mono_sample(candidate + offset);
+            correlation += sample * self.mono_sample(natural + offset);
+            energy += sample * sample;
+        }
+        correlation / (energy.sqrt() + f32::EPSILON)
+    }
+
+    fn input_sample(&self, frame: usize, channel: usize) -> f32 {
+        frame
+            .checked_sub(self.input_origin)
+            .and_then(|index| self.input.get(index * self.channels + channel))
+            .copied()
+            .unwrap_or(0.0)
+    }
+
+    fn mono_sample(&self, frame: usize) -> f32 {
+        frame
+            .checked_sub(self.input_origin)
+            .and_then(|index| self.mono.get(index))
+            .copied()
+            .unwrap_or(0.0)
+    }
+
+    fn discard_consumed_input(&mut self, start: usize) {
+        let next_nominal = self.analysis_position.round() as usize;
+        let keep_from = (start + self.hop_frames)
+            .min(next_nominal.saturating_sub(self.seek_frames))
+            .max(self.input_origin);
+        let discarded = (keep_from - self.input_origin).min(self.mono.len());
+        if discarded == 0 {
+            return;
+        }
+        self.mono.drain(..discarded);
+        self.input.drain(..discarded * self.channels);
+        self.input_origin += discarded;
+    }
+}
+
+#[must_use]
+pub fn clamp_playback_speed(speed: f64) -> f64 {
+    if !speed.is_finite() {
+        return 1.0;
+    }
+    speed.clamp(MINIMUM_PLAYBACK_SPEED, MAXIMUM_PLAYBACK_SPEED)
+}
@@ -0,0 +1,196 @@
+use egui::Color32;
+use egui::Id;
+use egui::Pos2;
+use egui::Rect;
+use egui::Sense;
+use egui::Shape;
+use egui::Stroke;
+use egui::Ui;
+use egui::pos2;
+use egui::vec2;
+
+use crate::material::styling::material_palette::material_palette_for_visuals;
+use crate::time::counts::format_scene_time;
+
+use super::actions::AudioPlayerAction;
+use super::state::AudioPlayerScene;
+use super::state::AudioPlayerState;
+use super::waveform::Waveform;
+
+const WAVEFORM_BAR_WIDTH_PX: f32 = 2.0;
+const WAVEFORM_ALPHA: f32 = 0.35;
+const MARKER_HANDLE_WIDTH_PX: f32 = 12.0;
+const MARKER_HANDLE_HEIGHT_PX: f32 = 10.0;
+/// Keeps a dragged marker clear of its neighbours so scene order never changes.
+const MINIMUM_SCENE_GAP_SECONDS: f64 = 0.1;
+
+/// Paints the waveform into a shape slot reserved before the slider, so it sits behind it.
+pub(super) fn paint_waveform(
+    ui: &Ui,
+    slot: egui::layers::ShapeIdx,
+    rect: Rect,
+    waveform: &Waveform,
+    duration: f64,
+) {
+    if duration <= 0.0 || rect.width() <= 0.0 {
+        return;
+    }
+
+    let palette = material_palette_for_visuals(ui.visuals());
+    let color = palette.secondary.gamma_multiply(WAVEFORM_ALPHA);
+    let bar_count = (rect.width() / WAVEFORM_BAR_WIDTH_PX).floor().max(1.0) as usize;
+    let seconds_per_bar = duration / bar_count as f64;
+    let half_height = rect.height() / 2.0;
+    let shapes = (0..bar_count)
+        .filter_map(|bar| {
+            let start = bar as f64 * seconds_per_bar;
+            let peak = waveform.peak_between(start, start + seconds_per_bar);
+            if peak <= 0.0 {
+                return None;
+            }
+            let x = rect.left() + (bar as f32 + 0.5) * WAVEFORM_BAR_WIDTH_PX;
+            let extent = (peak * half_height).max(0.5);
+            Some(Shape::line_segment(
+                [
+                    pos2(x, rect.center().y - extent),
+                    pos2(x, rect.center().y + extent),
+                ],
+                Stroke::new(WAVEFORM_BAR_WIDTH_PX * 0.75, color),
+            ))
+        })
+        .collect::<Vec<_>>();
+    ui.painter().set(slot, Shape::Vec(shapes));
+}
+
+/// Draws a handle per timed scene; dropping a dragged handle re-times its scene.
+pub(super) fn draw_scene_markers(
+    ui: &mut Ui,
+    rect: Rect,
+    state: &AudioPlayerState,
+    actions: &mut Vec<AudioPlayerAction>,
+) {
+    if state.duration <= 0.0 || !state.has_player {
+        return;
+    }
+
+    let palette = material_palette_for_visuals(ui.visuals());
+    for scene in &state.scenes {
+        let Some(timestamp) = scene.timestamp else {
+            continue;
+        };
+        if timestamp > state.duration {
+            continue;
+        }
+        let Some((minimum, maximum)) =
+            scene_marker_range(&state.scenes, scene.scene_id, state.duration)
+        else {
+            continue;
+        };
+
+        let id = Id::new(("audio_player_scene_marker", scene.scene_id));
+        let dragged_seconds = ui.memory(|memory| memory.data.get_temp::<f64>(id));
+        let seconds = dragged_seconds.unwrap_or(timestamp);
+        let x = x_for_seconds(rect, seconds, state.duration);
+        let handle = Rect::from_min_size(
+            pos2(x - MARKER_HANDLE_WIDTH_PX / 2.0, rect.top()),
+            vec2(MARKER_HANDLE_WIDTH_PX, MARKER_HANDLE_HEIGHT_PX),
+        );
+        let response = ui
+            .interact(handle, id, Sense::drag())
+            .on_hover_cursor(egui::CursorIcon::ResizeHorizontal)
+            .on_hover_text(scene_marker_hover_text(scene, seconds, state));
+
+        if response.dragged()
+            && let Some(pointer) = response.interact_pointer_pos()
+        {
+            let seconds = seconds_for_x(rect, pointer.x, state.duration).clamp(minimum, maximum);
+            ui.memory_mut(|memory| memory.data.insert_temp(id, seconds));
+        }
+        if response.drag_stopped() {
+            ui.memory_mut(|memory| memory.data.remove::<f64>(id));
+            if let Some(seconds) = dragged_seconds {
+                actions.push(AudioPlayerAction::MoveSceneTimestamp {
+                    scene_id: scene.scene_id,
+                    timestamp: seconds,
+                });
+            }
+        }
+
+        let color = if state.selected_scene_id == Some(scene.scene_id) {
+            palette.primary
+        } else {
+            palette.secondary
+        };
+        let color = if response.hovered() || response.dragged() {
+            color
+        } else {
+            color.gamma_multiply(0.8)
+        };
+        paint_marker(ui, rect, x, color);
+    }
+}
+
+/// The scene name, followed by its count ("8·5") when the choreography has a musical timeline.
+#[must_use]
+pub fn scene_marker_hover_text(
+    scene: &AudioPlayerScene,
+    seconds: f64,
+    state: &AudioPlayerState,
+) -> String {
+    match state.musical_timeline.as_ref() {
+        Some(timeline) => format!(
+            "{} · {}",
+            scene.name,
+            format_scene_time(seconds, Some(timeline))
+        ),
+        None => scene.name.clone(),
+    }
+}
+
+fn paint_marker(ui: &Ui, rect: Rect, x: f32, color: Color32) {
+    let painter = ui.painter();
+    painter.line_segment(
+        [pos2(x, rect.top()), pos2(x, rect.bottom())],
+        Stroke::new(1.0, color),
+    );
+    let half_width = MARKER_HANDLE_WIDTH_PX / 2.0;
+    painter.add(Shape::convex_polygon(
+        vec![
+            pos2(x - half_width, rect.top()),
+            pos2(x + half_width, rect.top()),
+            Pos2::new(x, rect.top() + MARKER_HANDLE_HEIGHT_PX),
+        ],
+        color,
+        Stroke::NONE,
+    ));
+}
+
+fn x_for_seconds(rect: Rect, seconds: f64, duration: f64) -> f32 {
+    egui::lerp(rect.x_range(), (seconds / duration).clamp(0.0, 1.0) as f32)
+}
+
+fn seconds_for_x(rect: Rect, x: f32, duration: f64) -> f64 {
+    let fraction = egui::remap_clamp(x, rect.x_range(), 0.0..=1.0);
+    f64::from(fraction) * duration
+}
+
+/// How far a scene's marker may be dragged: past neither neighbouring scene nor the audio.
+#[must_use]
+pub fn scene_marker_range(
+    scenes: &[AudioPlayerScene],
+    scene_id: i32,
+    duration: f64,
+) -> Option<(f64, f64)> {
+    let index = scenes.iter().position(|scene| scene.scene_id == scene_id)?;
+    let minimum = scenes[..index]
+        .iter()
+        .rev()
+        .find_map(|scene| scene.timestamp)
+        .map_or(0.0, |before| before + MINIMUM_SCENE_GAP_SECONDS);
+    let maximum = scenes[index + 1..]
+        .iter()
+        .find_map(|scene| scene.timestamp)
+        .map_or(duration, |after| after - MINIMUM_SCENE_GAP_SECONDS)
+        .min(duration);
+    (minimum <= maximum).then_some((minimum, maximum))
+}
@@ -0,0 +1,35 @@
+use crate::i18n::t;
+
+use super::audio_format::AudioFormatError;
+use super::beat_detection::BeatGrid;
+use super::beat_detection::TimestampSnap;
+
+#[must_use]
+pub fn open_audio_error_message(locale: &str, file_path: &str, error: &AudioFormatError) -> String {
+    match error {
+        AudioFormatError::Read { message } => t(locale, "OpenAudioReadFailed")
+            .replace("{0}", file_path)
+            .replace("{1}", message),
+        AudioFormatError::UnsupportedFormat | AudioFormatError::UnsupportedCodec { .. } => {
+            t(locale, "OpenAudioUnsupportedFormat").replace("{0}", file_path)
+        }
+    }
+}
+
+/// The detected tempo, e.g. "124 BPM"; empty until a beat grid is known.
+#[must_use]
+pub fn bpm_label_text(locale: &str, beat_grid: Option<&BeatGrid>) -> String {
+    beat_grid.map_or_else(String::new, |grid| {
+        t(locale, "BeatBpmLabel").replace("{0}", &format!("{:.0}", grid.bpm))
+    })
+}
+
+#[must_use]
+pub fn timestamp_snap_text(locale: &str, snap: TimestampSnap) -> String {
+    let key = match snap {
+        TimestampSnap::Off => "TimestampSnapOff",
+        TimestampSnap::Beat => "TimestampSnapBeat",
+        TimestampSnap::Count => "TimestampSnapCount",
+    };
+    t(locale, key)
+}
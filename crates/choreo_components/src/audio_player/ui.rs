@@ -1,6 +1,7 @@
 use egui::Align;
 use egui::Image;
 use egui::Layout;
+use egui::Rect;
 use egui::Ui;
 use egui::vec2;
 use egui_material3::MaterialSlider;
@@ -15,19 +16,26 @@ use crate::slider_with_ticks::ui::SliderWithTicksInteraction;
 use crate::slider_with_ticks::ui::SliderWithTicksUiState;
 
 use super::actions::AudioPlayerAction;
+use super::beat_detection::TimestampSnap;
 use super::state::AudioPlayerState;
 use super::state::PlayPauseGlyph;
 use super::state::play_pause_glyph;
+use super::timeline;
+use super::translations::bpm_label_text;
+use super::translations::timestamp_snap_text;
 
 const GRID_12_PX: f32 = 12.0;
 const ICON_BUTTON_SIZE_PX: f32 = 48.0;
 const SPEED_SLIDER_WIDTH_PX: f32 = 240.0;
 const SPEED_LABEL_WIDTH_PX: f32 = 48.0;
 const DURATION_LABEL_WIDTH_PX: f32 = 72.0;
+const BPM_LABEL_WIDTH_PX: f32 = 72.0;
 const MIN_POSITION_SLIDER_WIDTH_PX: f32 = 120.0;
 const TRANSPORT_CLUSTER_GAP_COUNT: f32 = 2.0;
 const SPEED_CLUSTER_GAP_COUNT: f32 = 1.0;
-const INTER_CLUSTER_GAP_COUNT: f32 = 2.0;
+const BEAT_CLUSTER_GAP_COUNT: f32 = 1.0;
+const INTER_CLUSTER_GAP_COUNT: f32 = 3.0;
+const DEFAULT_LOCALE: &str = "en";
 
 pub fn draw(ui: &mut Ui, state: &AudioPlayerState) -> Vec<AudioPlayerAction> {
     let mut actions: Vec<AudioPlayerAction> = Vec::new();
@@ -71,6 +79,35 @@ pub fn draw(ui: &mut Ui, state: &AudioPlayerState) -> Vec<AudioPlayerAction> {
             },
         );
 
+        ui.allocate_ui_with_layout(
+            vec2(beat_cluster_width_px(), row_height),
+            Layout::right_to_left(Align::Center),
+            |ui| {
+                ui.spacing_mut().item_spacing.x = GRID_12_PX;
+
+                let snap_response = components::top_bar_icon_button(
+                    ui,
+                    snap_image(state.timestamp_snap),
+                    state.timestamp_snap != TimestampSnap::Off,
+                )
+                .on_hover_text(timestamp_snap_text(DEFAULT_LOCALE, state.timestamp_snap));
+                if snap_response.clicked() {
+                    actions.push(AudioPlayerAction::UpdateTimestampSnap {
+                        snap: state.timestamp_snap.next(),
+                    });
+                }
+
+                ui.add_sized(
+                    [BPM_LABEL_WIDTH_PX, ICON_BUTTON_SIZE_PX],
+                    egui::Label::new(typography::rich_text_for_role(
+                        bpm_label_text(DEFAULT_LOCALE, state.beat_grid.as_ref()),
+                        duration_label_role(),
+                    ))
+                    .truncate(),
+                );
+            },
+        );
+
         ui.allocate_ui_with_layout(
             vec2(speed_cluster_width_px(), row_height),
             Layout::right_to_left(Align::Center),
@@ -119,7 +156,10 @@ pub fn position_slider_width_for_panel_width(panel_width: f32) -> f32 {
 
 #[must_use]
 pub fn audio_player_fixed_controls_width_px() -> f32 {
-    transport_cluster_width_px() + speed_cluster_width_px() + (INTER_CLUSTER_GAP_COUNT * GRID_12_PX)
+    transport_cluster_width_px()
+        + beat_cluster_width_px()
+        + speed_cluster_width_px()
+        + (INTER_CLUSTER_GAP_COUNT * GRID_12_PX)
 }
 
 #[must_use]
@@ -175,6 +215,16 @@ fn link_image() -> Image<'static> {
     Image::new(egui::include_image!("../../assets/icons/Link.svg"))
 }
 
+fn snap_image(snap: TimestampSnap) -> Image<'static> {
+    match snap {
+        TimestampSnap::Off => Image::new(egui::include_image!("../../assets/icons/Magnet.svg")),
+        TimestampSnap::Beat => {
+            Image::new(egui::include_image!("../../assets/icons/MetronomeTick.svg"))
+        }
+        TimestampSnap::Count => Image::new(egui::include_image!("../../assets/icons/MagnetOn.svg")),
+    }
+}
+
 fn link_icon_spec() -> ui_icons::UiIconSpec {
     ui_icons::icon(UiIconKey::AudioLink)
 }
@@ -187,7 +237,8 @@ fn draw_position_slider(
 ) {
     let palette = material_palette_for_visuals(ui.visuals());
     let position = state.pending_seek_position.unwrap_or(state.position);
-    let interactions = crate::slider_with_ticks::ui::draw(
+    let waveform_slot = ui.painter().add(egui::Shape::Noop);
+    let slider = crate::slider_with_ticks::ui::draw(
         ui,
         SliderWithTicksUiState {
             enabled: state.can_seek,
@@ -200,7 +251,15 @@ fn draw_position_slider(
         },
     );
 
-    for interaction in interactions {
+    let timeline_rect = Rect::from_center_size(
+        slider.rect.center(),
+        vec2(slider.rect.width(), ICON_BUTTON_SIZE_PX),
+    );
+    if let Some(waveform) = state.waveform.as_ref() {
+        timeline::paint_waveform(ui, waveform_slot, timeline_rect, waveform, state.duration);
+    }
+
+    for interaction in slider.interactions {
         match interaction {
             SliderWithTicksInteraction::DragStarted => {
                 actions.push(AudioPlayerAction::PositionDragStarted);
@@ -217,6 +276,8 @@ fn draw_position_slider(
             }
         }
     }
+
+    timeline::draw_scene_markers(ui, timeline_rect, state, actions);
 }
 
 fn transport_cluster_width_px() -> f32 {
@@ -225,6 +286,10 @@ fn transport_cluster_width_px() -> f32 {
         + (TRANSPORT_CLUSTER_GAP_COUNT * GRID_12_PX)
 }
 
+fn beat_cluster_width_px() -> f32 {
+    BPM_LABEL_WIDTH_PX + ICON_BUTTON_SIZE_PX + (BEAT_CLUSTER_GAP_COUNT * GRID_12_PX)
+}
+
 fn speed_cluster_width_px() -> f32 {
     SPEED_SLIDER_WIDTH_PX + SPEED_LABEL_WIDTH_PX + (SPEED_CLUSTER_GAP_COUNT * GRID_12_PX)
 }
@@ -0,0 +1,85 @@
+/// Peaks kept per second of audio; enough detail for a panel a few thousand pixels wide.
+const PEAKS_PER_SECOND: f64 = 50.0;
+
+/// Overview of an audio file's loudness, normalized so the loudest peak is 1.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Waveform {
+    pub peaks: Vec<f32>,
+    pub peaks_per_second: f64,
+}
+
+impl Waveform {
+    #[must_use]
+    pub fn duration_seconds(&self) -> f64 {
+        self.peaks.len() as f64 / self.peaks_per_second
+    }
+
+    /// The loudest peak between two times, or 0 outside the audio.
+    #[must_use]
+    pub fn peak_between(&self, start_seconds: f64, end_seconds: f64) -> f32 {
+        let start = (start_seconds.max(0.0) * self.peaks_per_second).floor() as usize;
+        let end = (end_seconds.max(0.0) * self.peaks_per_second).ceil() as usize;
+        let end = end.max(start + 1).min(self.peaks.len());
+        self.peaks
+            .get(start..end)
+            .map_or(0.0, |peaks| peaks.iter().copied().fold(0.0, f32::max))
+    }
+}
+
+/// Streams mono samples into absolute peaks.
+pub(crate) struct WaveformBuilder {
+    samples_per_peak: usize,
+    filled: usize,
+    peak: f32,
+    peaks: Vec<f32>,
+    peaks_per_second: f64,
+}
+
+impl WaveformBuilder {
+    pub(crate) fn new(sample_rate: u32) -> Self {
+        let samples_per_peak =
+            ((f64::from(sample_rate) / PEAKS_PER_SECOND).round() as usize).max(1);
+        Self {
+            samples_per_peak,
+            filled: 0,
+            peak: 0.0,
+            peaks: Vec::new(),
+            peaks_per_second: f64::from(sample_rate) / samples_per_peak as f64,
+        }
+    }
+
+    pub(crate) fn push(&mut self, sample: f32) {
+        self.peak = self.peak.max(sample.abs());
+        self.filled += 1;
+        if self.filled == self.samples_per_peak {
+            self.peaks.push(self.peak);
+            self.filled = 0;
+            self.peak = 0.0;
+        }
+    }
+
+    /// `None` for silent or empty audio.
+    pub(crate) fn finish(mut self) -> Option<Waveform> {
+        if self.filled > 0 {
+            self.peaks.push(self.peak);
+        }
+        let loudest = self.peaks.iter().copied().fold(0.0, f32::max);
+        if loudest <= 0.0 {
+            return None;
+        }
+        Some(Waveform {
+            peaks: self.peaks.iter().map(|peak| peak / loudest).collect(),
+            peaks_per_second: self.peaks_per_second,
+        })
+    }
+}
+
+/// Builds the waveform of mono samples.
+#[must_use]
+pub fn build_waveform(samples: &[f32], sample_rate: u32) -> Option<Waveform> {
+    let mut builder = WaveformBuilder::new(sample_rate);
+    for sample in samples {
+        builder.push(*sample);
+    }
+    builder.finish()
+}
@@ -0,0 +1,145 @@
+mod pages;
+mod pdf;
+pub mod translations;
+
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::choreo_main::FloorExportOptions;
+use crate::choreo_main::render_scene_floors;
+use crate::choreo_main::state::ChoreoMainState;
+use crate::floor::FloorImageError;
+use crate::floor::render_svg_pixmap;
+use crate::material::styling::material_palette::material_palette_for_visuals;
+
+use pages::PAGE_HEIGHT_PT;
+use pages::PAGE_WIDTH_PT;
+use pages::PageColors;
+use pdf::PdfImagePage;
+use pdf::PdfInfo;
+
+/// Raster resolution of the booklet pages; enough for crisp printing of the floor diagrams.
+pub const BOOKLET_DPI: f32 = 150.0;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BookletOptions {
+    pub locale: String,
+    /// Appends one "where am I" table per dancer after the scene pages.
+    pub include_dancer_index: bool,
+}
+
+impl Default for BookletOptions {
+    fn default() -> Self {
+        Self {
+            locale: "en".to_string(),
+            include_dancer_index: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BookletError {
+    Image(FloorImageError),
+    Write { path: PathBuf, message: String },
+}
+
+impl fmt::Display for BookletError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BookletError::Image(error) => write!(f, "{error}"),
+            BookletError::Write { path, message } => {
+                write!(f, "Could not write {}: {message}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for BookletError {}
+
+impl From<FloorImageError> for BookletError {
+    fn from(error: FloorImageError) -> Self {
+        BookletError::Image(error)
+    }
+}
+
+/// Lays out the booklet as one SVG document per A4 page: the title page, one page per scene
+/// with its floor diagram, timestamp and notes, and optionally the per-dancer index.
+#[must_use]
+pub fn booklet_page_svgs(state: &ChoreoMainState, options: &BookletOptions) -> Vec<String> {
+    let locale = options.locale.as_str();
+    let palette = material_palette_for_visuals(&egui::Visuals::light());
+    let colors = PageColors {
+        text: palette.on_surface,
+        muted: palette.on_surface_variant,
+        rule: palette.outline_variant,
+    };
+    let choreography = &state.choreography_settings_state.choreography;
+
+    let mut pages = vec![pages::title_page(choreography, locale, colors)];
+    for floor in render_scene_floors(state, &FloorExportOptions::default()) {
+        pages.push(pages::scene_page(
+            floor.scene_index,
+            &state.scene_models[floor.scene_index],
+            &floor.svg,
+            locale,
+            colors,
+        ));
+    }
+    if options.include_dancer_index {
+        pages.extend(pages::dancer_index_pages(
+            &choreography.dancers,
+            &state.scene_models,
+            locale,
+            colors,
+        ));
+    }
+
+    let total = pages.len();
+    pages
+        .into_iter()
+        .enumerate()
+        .map(|(index, page)| page.finish(index + 1, total, locale, colors))
+        .collect()
+}
+
+/// Renders the booklet into PDF bytes without any external tools.
+///
+/// Pages are rasterised with the bundled fonts so every script the app can show prints too.
+pub fn render_booklet_pdf(
+    state: &ChoreoMainState,
+    options: &BookletOptions,
+) -> Result<Vec<u8>, BookletError> {
+    let scale = BOOKLET_DPI / 72.0;
+    let pages = booklet_page_svgs(state, options)
+        .iter()
+        .map(|svg| render_svg_pixmap(svg, scale).map(|pixmap| PdfImagePage::from_pixmap(&pixmap)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let choreography = &state.choreography_settings_state.choreography;
+    let info = PdfInfo {
+        title: choreography.name.clone(),
+        subject: choreography
+            .subtitle
+            .clone()
+            .filter(|value| !value.trim().is_empty()),
+        author: choreography
+            .author
+            .clone()
+            .filter(|value| !value.trim().is_empty()),
+    };
+    Ok(pdf::write_pdf(&pages, &info, PAGE_WIDTH_PT, PAGE_HEIGHT_PT))
+}
+
+pub fn export_booklet(
+    state: &ChoreoMainState,
+    path: &Path,
+    options: &BookletOptions,
+) -> Result<(), BookletError> {
+    let bytes = render_booklet_pdf(state, options)?;
+    fs::write(path, bytes).map_err(|error| BookletError::Write {
+        path: path.to_path_buf(),
+        message: error.to_string(),
+    })
+}
@@ -0,0 +1,396 @@
+use std::fmt::Write as _;
+
+use base64::Engine as _;
+use choreo_models::ChoreographyModel;
+use choreo_models::DancerModel;
+use choreo_models::SceneModel;
+use egui::Color32;
+
+use crate::choreo_info::ui::choreo_date_text;
+use crate::floor::escape_xml;
+use crate::time::format_seconds;
+use crate::time::parse_timestamp_seconds;
+
+use super::translations::BookletTranslations;
+
+/// A4 portrait in PDF points.
+pub(super) const PAGE_WIDTH_PT: f32 = 595.28;
+pub(super) const PAGE_HEIGHT_PT: f32 = 841.89;
+
+const MARGIN_PT: f32 = 56.0;
+const CONTENT_WIDTH_PT: f32 = PAGE_WIDTH_PT - 2.0 * MARGIN_PT;
+const CONTENT_BOTTOM_PT: f32 = PAGE_HEIGHT_PT - MARGIN_PT;
+const FOOTER_BASELINE_PT: f32 = PAGE_HEIGHT_PT - 28.0;
+const FLOOR_HEIGHT_PT: f32 = 420.0;
+const INDEX_ROW_HEIGHT_PT: f32 = 20.0;
+const INDEX_TIMESTAMP_X_PT: f32 = MARGIN_PT + 300.0;
+const INDEX_POSITION_X_PT: f32 = MARGIN_PT + 380.0;
+/// Rough advance of a sans-serif glyph relative to the font size, only used to wrap text.
+const AVERAGE_GLYPH_WIDTH_EM: f32 = 0.52;
+const LINE_HEIGHT_EM: f32 = 1.35;
+
+#[derive(Debug, Clone, Copy)]
+pub(super) struct PageColors {
+    pub(super) text: Color32,
+    pub(super) muted: Color32,
+    pub(super) rule: Color32,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Anchor {
+    Start,
+    Middle,
+    End,
+}
+
+#[derive(Debug, Default)]
+pub(super) struct Page {
+    body: String,
+}
+
+impl Page {
+    /// Wraps the page body into a standalone SVG document sized in points.
+    pub(super) fn finish(
+        mut self,
+        number: usize,
+        total: usize,
+        locale: &str,
+        colors: PageColors,
+    ) -> String {
+        self.anchored_text(
+            PAGE_WIDTH_PT / 2.0,
+            FOOTER_BASELINE_PT,
+            9.0,
+            false,
+            colors.muted,
+            Anchor::Middle,
+            &BookletTranslations::page_number(locale, number, total),
+        );
+
+        format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{PAGE_WIDTH_PT}\" \
+height=\"{PAGE_HEIGHT_PT}\" viewBox=\"0 0 {PAGE_WIDTH_PT} {PAGE_HEIGHT_PT}\" \
+font-family=\"sans-serif\">\n<rect width=\"{PAGE_WIDTH_PT}\" height=\"{PAGE_HEIGHT_PT}\" \
+fill=\"#ffffff\"/>\n{}</svg>\n",
+            self.body
+        )
+    }
+
+    fn text(&mut self, x: f32, y: f32, size: f32, bold: bool, color: Color32, text: &str) {
+        self.anchored_text(x, y, size, bold, color, Anchor::Start, text);
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn anchored_text(
+        &mut self,
+        x: f32,
+        y: f32,
+        size: f32,
+        bold: bool,
+        color: Color32,
+        anchor: Anchor,
+        text: &str,
+    ) {
+        let _ = write!(
+            self.body,
+            r#"<text x="{x:.2}" y="{y:.2}" font-size="{size}" fill="{}""#,
+            hex(color)
+        );
+        if bold {
+            self.body.push_str(r#" font-weight="bold""#);
+        }
+        match anchor {
+            Anchor::Start => {}
+            Anchor::Middle => self.body.push_str(r#" text-anchor="middle""#),
+            Anchor::End => self.body.push_str(r#" text-anchor="end""#),
+        }
+        let _ = writeln!(self.body, ">{}</text>", escape_xml(text));
+    }
+
+    fn rule(&mut self, y: f32, color: Color32) {
+        let _ = writeln!(
+            self.body,
+            r#"<line x1="{MARGIN_PT}" y1="{y:.2}" x2="{:.2}" y2="{y:.2}" stroke="{}" stroke-width="0.75"/>"#,
+            MARGIN_PT + CONTENT_WIDTH_PT,
+            hex(color)
+        );
+    }
+
+    fn svg_image(&mut self, y: f32, height: f32, svg: &str) {
+        let data = base64::engine::general_purpose::STANDARD.encode(svg.as_bytes());
+        let _ = writeln!(
+            self.body,
+            r#"<image x="{MARGIN_PT}" y="{y:.2}" width="{CONTENT_WIDTH_PT:.2}" height="{height:.2}" preserveAspectRatio="xMidYMid meet" href="data:image/svg+xml;base64,{data}"/>"#
+        );
+    }
+
+    /// Writes wrapped text from the baseline `y` and returns the baseline after the last line.
+    /// Lines that would run past the bottom margin are dropped and the last kept line ends in `…`.
+    fn paragraph(&mut self, y: f32, size: f32, color: Color32, text: &str) -> f32 {
+        let line_height = size * LINE_HEIGHT_EM;
+        let lines = wrap_text(text, size, CONTENT_WIDTH_PT);
+        let fitting = (((CONTENT_BOTTOM_PT - y) / line_height).floor().max(0.0) as usize) + 1;
+        let mut baseline = y;
+        for (index, line) in lines.iter().take(fitting).enumerate() {
+            if index + 1 == fitting && lines.len() > fitting {
+                self.text(MARGIN_PT, baseline, size, false, color, &format!("{line}…"));
+            } else {
+                self.text(MARGIN_PT, baseline, size, false, color, line);
+            }
+            baseline += line_height;
+        }
+        baseline
+    }
+}
+
+pub(super) fn title_page(
+    choreography: &ChoreographyModel,
+    locale: &str,
+    colors: PageColors,
+) -> Page {
+    let mut page = Page::default();
+    let mut y = PAGE_HEIGHT_PT * 0.3;
+    for line in wrap_text(&choreography.name, 28.0, CONTENT_WIDTH_PT) {
+        page.text(MARGIN_PT, y, 28.0, true, colors.text, &line);
+        y += 28.0 * LINE_HEIGHT_EM;
+    }
+    if let Some(subtitle) = non_empty(choreography.subtitle.as_deref()) {
+        y = page.paragraph(y, 16.0, colors.muted, subtitle);
+    }
+    y += 12.0;
+    page.rule(y, colors.rule);
+    y += 28.0;
+
+    let date = choreography
+        .date
+        .map(|date| choreo_date_text(date.year(), u8::from(date.month()), date.day()));
+    let details = [
+        (
+            BookletTranslations::author(locale),
+            non_empty(choreography.author.as_deref()).map(str::to_string),
+        ),
+        (BookletTranslations::date(locale), date),
+        (
+            BookletTranslations::variation(locale),
+            non_empty(choreography.variation.as_deref()).map(str::to_string),
+        ),
+    ];
+    for (label, value) in details {
+        let Some(value) = value else {
+            continue;
+        };
+        page.text(MARGIN_PT, y, 11.0, true, colors.muted, &label);
+        page.text(MARGIN_PT + 90.0, y, 11.0, false, colors.text, &value);
+        y += 11.0 * LINE_HEIGHT_EM + 4.0;
+    }
+
+    if let Some(description) = non_empty(choreography.description.as_deref()) {
+        page.paragraph(y + 20.0, 11.0, colors.text, description);
+    }
+    page
+}
+
+pub(super) fn scene_page(
+    scene_index: usize,
+    scene: &SceneModel,
+    floor_svg: &str,
+    locale: &str,
+    colors: PageColors,
+) -> Page {
+    let mut page = Page::default();
+    let mut y = MARGIN_PT + 18.0;
+    page.text(
+        MARGIN_PT,
+        y,
+        18.0,
+        true,
+        colors.text,
+        &scene_heading(scene_index, scene),
+    );
+    if let Some(timestamp) = scene_timestamp_text(scene) {
+        page.anchored_text(
+            MARGIN_PT + CONTENT_WIDTH_PT,
+            y,
+            11.0,
+            false,
+            colors.muted,
+            Anchor::End,
+            &format!("{} {timestamp}", BookletTranslations::timestamp(locale)),
+        );
+    }
+    y += 12.0;
+    page.rule(y, colors.rule);
+    y += 12.0;
+
+    page.svg_image(y, FLOOR_HEIGHT_PT, floor_svg);
+    y += FLOOR_HEIGHT_PT + 28.0;
+
+    if let Some(text) = non_empty(scene.text.as_deref()) {
+        page.paragraph(y, 11.0, colors.text, text);
+    }
+    page
+}
+
+/// One table per dancer listing their position in every scene, continued over as many pages
+/// as the scene count needs.
+pub(super) fn dancer_index_pages(
+    dancers: &[std::rc::Rc<DancerModel>],
+    scenes: &[SceneModel],
+    locale: &str,
+    colors: PageColors,
+) -> Vec<Page> {
+    let header_bottom = MARGIN_PT + 18.0 + 12.0 + 24.0;
+    let rows_per_page =
+        (((CONTENT_BOTTOM_PT - header_bottom) / INDEX_ROW_HEIGHT_PT).floor() as usize).max(1);
+
+    let mut pages = Vec::new();
+    for dancer in dancers {
+        let title = BookletTranslations::dancer_index_title(locale, &dancer.name);
+        for (chunk_index, chunk) in scenes.chunks(rows_per_page).enumerate() {
+            let mut page = Page::default();
+            let mut y = MARGIN_PT + 18.0;
+            page.text(MARGIN_PT, y, 18.0, true, colors.text, &title);
+            y += 24.0;
+            page.text(
+                MARGIN_PT,
+                y,
+                10.0,
+                true,
+                colors.muted,
+                &BookletTranslations::scene(locale),
+            );
+            page.text(
+                INDEX_TIMESTAMP_X_PT,
+                y,
+                10.0,
+                true,
+                colors.muted,
+                &BookletTranslations::timestamp(locale),
+            );
+            page.text(
+                INDEX_POSITION_X_PT,
+                y,
+                10.0,
+                true,
+                colors.muted,
+                &BookletTranslations::position(locale),
+            );
+            y += 6.0;
+            page.rule(y, colors.rule);
+            y += INDEX_ROW_HEIGHT_PT - 4.0;
+
+            for (offset, scene) in chunk.iter().enumerate() {
+                let scene_index = chunk_index * rows_per_page + offset;
+                page.text(
+                    MARGIN_PT,
+                    y,
+                    10.0,
+                    false,
+                    colors.text,
+                    &truncate_to_width(&scene_heading(scene_index, scene), 10.0, 290.0),
+                );
+                page.text(
+                    INDEX_TIMESTAMP_X_PT,
+                    y,
+                    10.0,
+                    false,
+                    colors.text,
+                    &scene_timestamp_text(scene).unwrap_or_else(|| "–".to_string()),
+                );
+                let position = scene
+                    .positions
+                    .iter()
+                    .find(|position| {
+                        position
+                            .dancer
+                            .as_ref()
+                            .is_some_and(|candidate| candidate.dancer_id == dancer.dancer_id)
+                    })
+                    .map_or_else(
+                        || "–".to_string(),
+                        |position| format!("({:.2}, {:.2})", position.x, position.y),
+                    );
+                page.text(INDEX_POSITION_X_PT, y, 10.0, false, colors.text, &position);
+                y += INDEX_ROW_HEIGHT_PT;
+            }
+            pages.push(page);
+        }
+    }
+    pages
+}
+
+fn scene_heading(scene_index: usize, scene: &SceneModel) -> String {
+    format!("{:02}. {}", scene_index + 1, scene.name.trim())
+}
+
+/// Shows timestamps as `m:ss.s`, which reads better on paper than raw seconds.
+fn scene_timestamp_text(scene: &SceneModel) -> Option<String> {
+    let raw = non_empty(scene.timestamp.as_deref())?;
+    let Some(seconds) = parse_timestamp_seconds(raw) else {
+        return Some(raw.to_string());
+    };
+    if seconds < 60.0 {
+        return Some(format!("0:{:04.1}", seconds));
+    }
+    let minutes = (seconds / 60.0).floor();
+    let rest = seconds - minutes * 60.0;
+    Some(format!("{}:{rest:04.1}", format_seconds(minutes)))
+}
+
+fn non_empty(value: Option<&str>) -> Option<&str> {
+    value.map(str::trim).filter(|value| !value.is_empty())
+}
+
+fn hex(color: Color32) -> String {
+    let [red, green, blue, _] = color.to_srgba_unmultiplied();
+    format!("#{red:02x}{green:02x}{blue:02x}")
+}
+
+fn max_chars(size: f32, width: f32) -> usize {
+    ((width / (size * AVERAGE_GLYPH_WIDTH_EM)).floor() as usize).max(1)
+}
+
+fn truncate_to_width(text: &str, size: f32, width: f32) -> String {
+    let limit = max_chars(size, width);
+    if text.chars().count() <= limit {
+        return text.to_string();
+    }
+    let mut truncated = text.chars().take(limit - 1).collect::<String>();
+    truncated.push('…');
+    truncated
+}
+
+/// Greedy word wrap that keeps explicit line breaks and splits words longer than a line.
+pub(super) fn wrap_text(text: &str, size: f32, width: f32) -> Vec<String> {
+    let limit = max_chars(size, width);
+    let mut lines = Vec::new();
+    for paragraph in text.lines() {
+        let mut line = String::new();
+        let mut line_len = 0;
+        for word in paragraph.split_whitespace() {
+            let mut word = word.chars().collect::<Vec<_>>();
+            while word.len() > limit {
+                if line_len > 0 {
+                    lines.push(std::mem::take(&mut line));
+                    line_len = 0;
+                }
+                lines.push(word.drain(..limit).collect());
+            }
+            if word.is_empty() {
+                continue;
+            }
+            if line_len > 0 && line_len + 1 + word.len() > limit {
+                lines.push(std::mem::take(&mut line));
+                line_len = 0;
+            }
+            if line_len > 0 {
+                line.push(' ');
+                line_len += 1;
+            }
+            line_len += word.len();
+            line.extend(word);
+        }
+        lines.push(line);
+    }
+    lines
+}
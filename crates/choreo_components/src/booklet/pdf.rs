@@ -0,0 +1,164 @@
+use std::io::Write as _;
+
+use flate2::Compression;
+use flate2::write::ZlibEncoder;
+use resvg::tiny_skia;
+
+/// A page rendered to an opaque RGB raster that fills the whole media box.
+pub(super) struct PdfImagePage {
+    pub(super) width_px: u32,
+    pub(super) height_px: u32,
+    pub(super) rgb: Vec<u8>,
+}
+
+impl PdfImagePage {
+    /// Flattens a premultiplied RGBA pixmap onto white paper.
+    pub(super) fn from_pixmap(pixmap: &tiny_skia::Pixmap) -> Self {
+        let rgb = pixmap
+            .data()
+            .chunks_exact(4)
+            .flat_map(|pixel| {
+                let paper = u8::MAX - pixel[3];
+                [
+                    pixel[0].saturating_add(paper),
+                    pixel[1].saturating_add(paper),
+                    pixel[2].saturating_add(paper),
+                ]
+            })
+            .collect();
+        Self {
+            width_px: pixmap.width(),
+            height_px: pixmap.height(),
+            rgb,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub(super) struct PdfInfo {
+    pub(super) title: String,
+    pub(super) subject: Option<String>,
+    pub(super) author: Option<String>,
+}
+
+/// Writes a PDF 1.4 file with one full-page image per page.
+///
+/// Object layout: 1 catalog, 2 page tree, 3 document info, then page, content stream and
+/// image XObject for every page in turn.
+pub(super) fn write_pdf(
+    pages: &[PdfImagePage],
+    info: &PdfInfo,
+    page_width_pt: f32,
+    page_height_pt: f32,
+) -> Vec<u8> {
+    let mut writer = PdfWriter::default();
+    writer
+        .bytes
+        .extend_from_slice(b"%PDF-1.4\n%\xe2\xe3\xcf\xd3\n");
+
+    let page_object = |index: usize| 4 + index * 3;
+    writer.object(1, b"<< /Type /Catalog /Pages 2 0 R >>");
+    let kids = (0..pages.len())
+        .map(|index| format!("{} 0 R", page_object(index)))
+        .collect::<Vec<_>>()
+        .join(" ");
+    writer.object(
+        2,
+        format!("<< /Type /Pages /Kids [{kids}] /Count {} >>", pages.len()).as_bytes(),
+    );
+    let mut info_entries = format!(
+        "<< /Title {} /Producer {}",
+        pdf_text(&info.title),
+        pdf_text("RChoreo")
+    );
+    if let Some(subject) = &info.subject {
+        info_entries.push_str(&format!(" /Subject {}", pdf_text(subject)));
+    }
+    if let Some(author) = &info.author {
+        info_entries.push_str(&format!(" /Author {}", pdf_text(author)));
+    }
+    info_entries.push_str(" >>");
+    writer.object(3, info_entries.as_bytes());
+
+    for (index, page) in pages.iter().enumerate() {
+        let page_id = page_object(index);
+        let (contents_id, image_id) = (page_id + 1, page_id + 2);
+        writer.object(
+            page_id,
+            format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {page_width_pt:.2} {page_height_pt:.2}] \
+/Resources << /XObject << /Im0 {image_id} 0 R >> >> /Contents {contents_id} 0 R >>"
+            )
+            .as_bytes(),
+        );
+        let contents =
+            format!("q\n{page_width_pt:.2} 0 0 {page_height_pt:.2} 0 0 cm\n/Im0 Do\nQ\n");
+        writer.stream(contents_id, "", contents.as_bytes());
+        writer.stream(
+            image_id,
+            &format!(
+                "/Type /XObject /Subtype /Image /Width {} /Height {} /ColorSpace /DeviceRGB \
+/BitsPerComponent 8 /Filter /FlateDecode",
+                page.width_px, page.height_px
+            ),
+            &deflate(&page.rgb),
+        );
+    }
+
+    writer.finish()
+}
+
+#[derive(Default)]
+struct PdfWriter {
+    bytes: Vec<u8>,
+    offsets: Vec<(usize, usize)>,
+}
+
+impl PdfWriter {
+    fn object(&mut self, id: usize, body: &[u8]) {
+        self.offsets.push((id, self.bytes.len()));
+        self.bytes
+            .extend_from_slice(format!("{id} 0 obj\n").as_bytes());
+        self.bytes.extend_from_slice(body);
+        self.bytes.extend_from_slice(b"\nendobj\n");
+    }
+
+    fn stream(&mut self, id: usize, dictionary: &str, data: &[u8]) {
+        let mut body = format!("<< {dictionary} /Length {} >>\nstream\n", data.len()).into_bytes();
+        body.extend_from_slice(data);
+        body.extend_from_slice(b"\nendstream");
+        self.object(id, &body);
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        self.offsets.sort_unstable();
+        let size = self.offsets.len() + 1;
+        let xref_offset = self.bytes.len();
+        let mut xref = format!("xref\n0 {size}\n0000000000 65535 f \n");
+        for (_, offset) in &self.offsets {
+            xref.push_str(&format!("{offset:010} 00000 n \n"));
+        }
+        xref.push_str(&format!(
+            "trailer\n<< /Size {size} /Root 1 0 R /Info 3 0 R >>\nstartxref\n{xref_offset}\n%%EOF\n"
+        ));
+        self.bytes.extend_from_slice(xref.as_bytes());
+        self.bytes
+    }
+}
+
+fn deflate(data: &[u8]) -> Vec<u8> {
+    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+    // Writing into a Vec cannot fail.
+    let _ = encoder.write_all(data);
+    encoder.finish().unwrap_or_default()
+}
+
+/// Encodes document info strings as UTF-16BE so names in any script survive.
+fn pdf_text(text: &str) -> String {
+    let mut hex = String::from("<FEFF");
+    for unit in text.encode_utf16() {
+        hex.push_str(&format!("{unit:04X}"));
+    }
+    hex.push('>');
+    hex
+}
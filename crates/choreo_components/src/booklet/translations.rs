@@ -0,0 +1,39 @@
+use crate::i18n;
+
+pub struct BookletTranslations;
+
+impl BookletTranslations {
+    pub fn author(locale: &str) -> String {
+        i18n::t(locale, "ChoreographyAuthorPlaceholder")
+    }
+
+    pub fn date(locale: &str) -> String {
+        i18n::t(locale, "ChoreographyDateLabel")
+    }
+
+    pub fn variation(locale: &str) -> String {
+        i18n::t(locale, "ChoreographyVariationPlaceholder")
+    }
+
+    pub fn scene(locale: &str) -> String {
+        i18n::t(locale, "SceneSectionTitle")
+    }
+
+    pub fn timestamp(locale: &str) -> String {
+        i18n::t(locale, "SceneTimestampLabel")
+    }
+
+    pub fn position(locale: &str) -> String {
+        i18n::t(locale, "BookletPositionColumn")
+    }
+
+    pub fn dancer_index_title(locale: &str, dancer_name: &str) -> String {
+        i18n::t(locale, "BookletDancerIndexTitle").replace("{0}", dancer_name)
+    }
+
+    pub fn page_number(locale: &str, number: usize, total: usize) -> String {
+        i18n::t(locale, "BookletPageNumber")
+            .replace("{0}", &number.to_string())
+            .replace("{1}", &total.to_string())
+    }
+}
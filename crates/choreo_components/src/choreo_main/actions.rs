@@ -5,6 +5,8 @@ use crate::choreography_settings::actions::ChoreographySettingsAction;
 use crate::dancers::actions::DancersAction;
 use crate::floor::actions::FloorAction;
 use crate::observability::TraceContext;
+use crate::scenes::formations::FormationChoice;
+use crate::scenes::formations::FormationParameters;
 use crate::settings::actions::SettingsAction;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -52,6 +54,13 @@ pub enum ChoreoMainAction {
     HideDialog,
     RequestOpenChoreo(OpenChoreoRequested),
     RequestSaveChoreo,
+    RequestSaveChoreoAs,
+    RestoreRecovery,
+    DiscardRecovery,
+    RequestClose,
+    SaveUnsavedChanges,
+    DiscardUnsavedChanges,
+    CancelUnsavedChanges,
     RequestOpenAudio(OpenAudioRequested),
     RequestOpenImage {
         file_path: String,
@@ -73,13 +82,74 @@ pub enum ChoreoMainAction {
         insert_after: bool,
     },
     DeleteSelectedScene,
+    DeleteSelectedPositions,
     SelectScene {
         index: usize,
     },
+    /// Adds a scene to the selection or takes it out again.
+    ToggleSceneSelection {
+        index: usize,
+    },
+    /// Selects every scene from the selected one to `index`.
+    ExtendSceneSelection {
+        index: usize,
+    },
+    /// Drops the scene dragged from `from` at `to`.
+    MoveScene {
+        from: usize,
+        to: usize,
+    },
+    DuplicateScene,
+    /// Moves the timestamps of the selected scenes by `seconds`.
+    ShiftSceneTimestamps {
+        seconds: f64,
+    },
+    SelectFormation {
+        choice: FormationChoice,
+    },
+    UpdateFormationParameters {
+        parameters: FormationParameters,
+    },
+    /// Moves the positions of the selected scene onto the chosen formation.
+    ApplyFormation,
+    /// Saves the positions of the selected scene as a template named after the scene.
+    SaveFormationTemplate,
     UpdateAudioPosition {
         seconds: f64,
     },
     LinkSelectedSceneToAudioPosition,
+    ToggleRehearsalPanel,
+    UpdateRehearsalScenes {
+        start_scene_index: Option<usize>,
+        end_scene_index: Option<usize>,
+    },
+    /// Loops the audio from the first scene's timestamp to the second's.
+    StartRehearsalLoop {
+        start_scene_index: usize,
+        end_scene_index: usize,
+    },
+    AutoAssignDancers,
+    /// Inserts a scene partway to the next one, on the spots of the selected scene or, with a
+    /// `formation`, on the spots of that formation.
+    InsertInBetweenScene {
+        formation: Option<FormationChoice>,
+    },
+    BendCollidingPaths,
+    BranchSceneVariation,
+    SwitchSceneVariation {
+        variation: usize,
+    },
+    PromoteSceneVariation,
+    /// Shows a variation set aside next to the active one on the floor; `None` stops comparing.
+    CompareSceneVariation {
+        variation: Option<usize>,
+    },
+    SetRequiredRole {
+        position_indices: Vec<usize>,
+        role_index: Option<usize>,
+    },
+    Undo,
+    Redo,
     FloorAction(FloorAction),
     AudioPlayerAction(AudioPlayerAction),
     ChoreographySettingsAction(ChoreographySettingsAction),
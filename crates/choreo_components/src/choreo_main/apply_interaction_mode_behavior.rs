@@ -2,6 +2,12 @@ use std::cell::RefCell;
 use std::rc::Rc;
 
 use choreo_state_machine::ApplicationStateMachine;
+use choreo_state_machine::BendPathCompletedTrigger;
+use choreo_state_machine::BendPathDragCompletedTrigger;
+use choreo_state_machine::BendPathDragStartedTrigger;
+use choreo_state_machine::BendPathSelectionCompletedTrigger;
+use choreo_state_machine::BendPathSelectionStartedTrigger;
+use choreo_state_machine::BendPathStartedTrigger;
 use choreo_state_machine::MovePositionsCompletedTrigger;
 use choreo_state_machine::MovePositionsStartedTrigger;
 use choreo_state_machine::RotateAroundCenterCompletedTrigger;
@@ -52,50 +58,95 @@ impl ApplyInteractionModeBehavior {
         let mut state_machine = self.state_machine.borrow_mut();
         match mode {
             InteractionMode::Move => {
+                state_machine.try_apply(&BendPathCompletedTrigger);
                 state_machine.try_apply(&RotateAroundCenterCompletedTrigger);
                 state_machine.try_apply(&ScalePositionsCompletedTrigger);
                 state_machine.try_apply(&ScaleAroundDancerCompletedTrigger);
                 state_machine.try_apply(&MovePositionsStartedTrigger);
             }
             InteractionMode::RotateAroundCenter if selected_positions_count == 0 => {
+                state_machine.try_apply(&BendPathCompletedTrigger);
                 state_machine.try_apply(&ScalePositionsCompletedTrigger);
                 state_machine.try_apply(&ScaleAroundDancerCompletedTrigger);
                 state_machine.try_apply(&RotateAroundCenterStartedTrigger);
             }
             InteractionMode::RotateAroundCenter => {
+                state_machine.try_apply(&BendPathCompletedTrigger);
                 state_machine.try_apply(&ScalePositionsCompletedTrigger);
                 state_machine.try_apply(&ScaleAroundDancerCompletedTrigger);
                 state_machine.try_apply(&RotateAroundCenterStartedTrigger);
                 state_machine.try_apply(&RotateAroundCenterSelectionCompletedTrigger);
             }
             InteractionMode::RotateAroundDancer if selected_positions_count == 0 => {
+                state_machine.try_apply(&BendPathCompletedTrigger);
                 state_machine.try_apply(&RotateAroundCenterCompletedTrigger);
                 state_machine.try_apply(&ScalePositionsCompletedTrigger);
                 state_machine.try_apply(&ScaleAroundDancerStartedTrigger);
             }
             InteractionMode::RotateAroundDancer => {
+                state_machine.try_apply(&BendPathCompletedTrigger);
                 state_machine.try_apply(&RotateAroundCenterCompletedTrigger);
                 state_machine.try_apply(&ScalePositionsCompletedTrigger);
                 state_machine.try_apply(&ScaleAroundDancerStartedTrigger);
                 state_machine.try_apply(&ScaleAroundDancerSelectionCompletedTrigger);
             }
             InteractionMode::Scale if selected_positions_count == 0 => {
+                state_machine.try_apply(&BendPathCompletedTrigger);
                 state_machine.try_apply(&RotateAroundCenterCompletedTrigger);
                 state_machine.try_apply(&ScaleAroundDancerCompletedTrigger);
                 state_machine.try_apply(&ScalePositionsStartedTrigger);
             }
             InteractionMode::Scale => {
+                state_machine.try_apply(&BendPathCompletedTrigger);
                 state_machine.try_apply(&RotateAroundCenterCompletedTrigger);
                 state_machine.try_apply(&ScaleAroundDancerCompletedTrigger);
                 state_machine.try_apply(&ScalePositionsStartedTrigger);
                 state_machine.try_apply(&ScalePositionsSelectionCompletedTrigger);
             }
-            InteractionMode::View | InteractionMode::LineOfSight => {
+            InteractionMode::BendPath if selected_positions_count == 0 => {
                 state_machine.try_apply(&MovePositionsCompletedTrigger);
                 state_machine.try_apply(&RotateAroundCenterCompletedTrigger);
                 state_machine.try_apply(&ScalePositionsCompletedTrigger);
                 state_machine.try_apply(&ScaleAroundDancerCompletedTrigger);
+                state_machine.try_apply(&BendPathStartedTrigger);
             }
+            InteractionMode::BendPath => {
+                state_machine.try_apply(&MovePositionsCompletedTrigger);
+                state_machine.try_apply(&RotateAroundCenterCompletedTrigger);
+                state_machine.try_apply(&ScalePositionsCompletedTrigger);
+                state_machine.try_apply(&ScaleAroundDancerCompletedTrigger);
+                state_machine.try_apply(&BendPathStartedTrigger);
+                state_machine.try_apply(&BendPathSelectionCompletedTrigger);
+            }
+            InteractionMode::View
+            | InteractionMode::LineOfSight
+            | InteractionMode::Face
+            | InteractionMode::EditPath => {
+                state_machine.try_apply(&BendPathCompletedTrigger);
+                state_machine.try_apply(&MovePositionsCompletedTrigger);
+                state_machine.try_apply(&RotateAroundCenterCompletedTrigger);
+                state_machine.try_apply(&ScalePositionsCompletedTrigger);
+                state_machine.try_apply(&ScaleAroundDancerCompletedTrigger);
+            }
+        }
+    }
+
+    /// Grabbing a curve handle picks the path it bends, even when no position is selected.
+    pub fn start_bend_drag(&self) {
+        let mut state_machine = self.state_machine.borrow_mut();
+        state_machine.try_apply(&BendPathSelectionCompletedTrigger);
+        state_machine.try_apply(&BendPathDragStartedTrigger);
+    }
+
+    pub fn complete_bend_drag(&self) {
+        let selected_positions_count = self
+            .global_state_store
+            .try_with_state(|state| state.selected_positions.len())
+            .unwrap_or_default();
+
+        let mut state_machine = self.state_machine.borrow_mut();
+        if state_machine.try_apply(&BendPathDragCompletedTrigger) && selected_positions_count == 0 {
+            state_machine.try_apply(&BendPathSelectionStartedTrigger);
         }
     }
 }
@@ -0,0 +1,174 @@
+use std::cell::RefCell;
+#[cfg(not(target_arch = "wasm32"))]
+use std::fs;
+use std::path::Path;
+use std::rc::Rc;
+
+use choreo_master_mobile_json::export;
+use choreo_master_mobile_json::import;
+use choreo_models::ChoreographyModel;
+use choreo_models::ChoreographyModelMapper;
+use time::Duration;
+use time::OffsetDateTime;
+
+use crate::preferences::RecoverySnapshot;
+use crate::preferences::RecoveryStore;
+
+use super::actions::OpenChoreoRequested;
+use super::open_choreo_file_behavior::request_open_choreo;
+use super::state::ChoreoMainState;
+use super::state::RecoveryOffer;
+use super::state::SavedChoreography;
+
+pub const AUTOSAVE_INTERVAL: Duration = Duration::seconds(30);
+
+#[derive(Default)]
+struct AutosaveProgress {
+    autosaved: Option<ChoreographyModel>,
+    last_autosave_at: Option<OffsetDateTime>,
+    restoring: bool,
+}
+
+/// Periodically snapshots unsaved changes to a [`RecoveryStore`] and offers them back on start.
+#[derive(Clone)]
+pub struct AutosaveBehavior {
+    store: Rc<dyn RecoveryStore>,
+    progress: Rc<RefCell<AutosaveProgress>>,
+}
+
+impl AutosaveBehavior {
+    #[must_use]
+    pub fn new(store: Rc<dyn RecoveryStore>) -> Self {
+        Self {
+            store,
+            progress: Rc::new(RefCell::new(AutosaveProgress::default())),
+        }
+    }
+
+    /// Offers the stored snapshot when it is newer than the `LastSaveDate` of its file.
+    pub fn initialize(&self, state: &mut ChoreoMainState) {
+        let Some(snapshot) = self.store.load() else {
+            return;
+        };
+        let Ok(choreography) = import(&snapshot.contents) else {
+            self.store.clear();
+            return;
+        };
+
+        let last_save_date = snapshot.file_path.as_deref().and_then(saved_last_save_date);
+        if last_save_date.is_some_and(|last_save_date| snapshot.saved_at <= last_save_date) {
+            self.store.clear();
+            return;
+        }
+
+        state.recovery_offer = Some(RecoveryOffer {
+            choreography_name: choreography.name,
+            file_path: snapshot.file_path,
+            saved_at: snapshot.saved_at,
+        });
+    }
+
+    /// Writes a snapshot of unsaved changes once the interval has passed since the last one and
+    /// returns how long until the next snapshot is due while changes are still waiting. A
+    /// choreography that was never opened or saved starts its interval at the first change.
+    pub fn tick(
+        &self,
+        state: &ChoreoMainState,
+        now: OffsetDateTime,
+    ) -> Option<std::time::Duration> {
+        if state.recovery_offer.is_some() || !state.has_unsaved_changes() {
+            return None;
+        }
+
+        let choreography = &state.choreography_settings_state.choreography;
+        let mut progress = self.progress.borrow_mut();
+        if progress.autosaved.as_ref() == Some(choreography) {
+            return None;
+        }
+        let last_autosave_at = *progress.last_autosave_at.get_or_insert(now);
+
+        let elapsed = now - last_autosave_at;
+        if elapsed < AUTOSAVE_INTERVAL {
+            return Some((AUTOSAVE_INTERVAL - elapsed).unsigned_abs());
+        }
+
+        let mapper = ChoreographyModelMapper;
+        let Ok(contents) = export(&mapper.map_to_json(choreography)) else {
+            return None;
+        };
+        self.store.store(&RecoverySnapshot {
+            file_path: state
+                .last_opened_choreo_file
+                .clone()
+                .filter(|path| Path::new(path).is_file()),
+            saved_at: now,
+            contents,
+        });
+        progress.autosaved = Some(choreography.clone());
+        progress.last_autosave_at = Some(now);
+        None
+    }
+
+    /// Starts tracking a freshly opened choreography; a restored one still counts as unsaved.
+    /// Opening any other choreography drops the snapshot of the previous one unless it is still
+    /// being offered for recovery.
+    pub fn sync_opened_choreography(&self, state: &mut ChoreoMainState, now: OffsetDateTime) {
+        let mut progress = self.progress.borrow_mut();
+        if progress.restoring {
+            progress.restoring = false;
+            progress.autosaved = Some(state.choreography_settings_state.choreography.clone());
+            state.saved_choreography = SavedChoreography::Recovered;
+        } else {
+            progress.autosaved = None;
+            if state.recovery_offer.is_none() {
+                self.store.clear();
+            }
+        }
+        progress.last_autosave_at = Some(now);
+    }
+
+    pub fn sync_saved_choreography(&self, now: OffsetDateTime) {
+        let mut progress = self.progress.borrow_mut();
+        progress.autosaved = None;
+        progress.last_autosave_at = Some(now);
+        self.store.clear();
+    }
+
+    pub fn restore(&self, state: &mut ChoreoMainState) {
+        let Some(snapshot) = self.store.load() else {
+            return;
+        };
+
+        self.progress.borrow_mut().restoring = true;
+        let file_name = snapshot
+            .file_path
+            .as_deref()
+            .and_then(|path| Path::new(path).file_name())
+            .map(|name| name.to_string_lossy().into_owned());
+        request_open_choreo(
+            state,
+            OpenChoreoRequested {
+                file_path: snapshot.file_path,
+                file_name,
+                contents: snapshot.contents,
+            },
+        );
+    }
+
+    pub fn discard(&self) {
+        self.store.clear();
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn saved_last_save_date(path: &str) -> Option<OffsetDateTime> {
+    let contents = fs::read_to_string(path).ok()?;
+    import(&contents)
+        .ok()
+        .map(|choreography| choreography.last_save_date)
+}
+
+#[cfg(target_arch = "wasm32")]
+fn saved_last_save_date(_path: &str) -> Option<OffsetDateTime> {
+    None
+}
@@ -9,8 +9,12 @@ use crate::audio_player::OpenAudioFileCommand;
 use crate::floor::DrawFloorCommand;
 use crate::global::GlobalStateActor;
 use crate::preferences::Preferences;
+use crate::preferences::RecoveryStore;
 
 use super::apply_interaction_mode_behavior::ApplyInteractionModeBehavior;
+use super::autosave_behavior::AutosaveBehavior;
+use super::formation_templates_behavior::FormationTemplatesBehavior;
+use super::keyboard_shortcuts_behavior::KeyboardShortcutsBehavior;
 use super::open_audio_behavior::OpenAudioBehavior;
 use super::open_choreo_file_behavior::OpenChoreoFileBehavior;
 use super::open_svg_file_behavior::OpenSvgFileBehavior;
@@ -22,11 +26,15 @@ pub struct ChoreoMainBehaviorDependencies {
     pub open_audio_sender: Option<Sender<OpenAudioFileCommand>>,
     pub preferences: Option<Rc<dyn Preferences>>,
     pub draw_floor_sender: Option<SyncSender<DrawFloorCommand>>,
+    pub recovery_store: Option<Rc<dyn RecoveryStore>>,
 }
 
 #[derive(Clone, Default)]
 pub struct ChoreoMainBehaviors {
     pub apply_interaction_mode: Option<ApplyInteractionModeBehavior>,
+    pub autosave: Option<AutosaveBehavior>,
+    pub formation_templates: Option<FormationTemplatesBehavior>,
+    pub keyboard_shortcuts: Option<KeyboardShortcutsBehavior>,
     pub open_audio: Option<OpenAudioBehavior>,
     pub open_choreo_file: Option<OpenChoreoFileBehavior>,
     pub open_svg_file: Option<OpenSvgFileBehavior>,
@@ -45,6 +53,14 @@ impl ChoreoMainBehaviors {
             .preferences
             .as_ref()
             .map(|preferences| OpenChoreoFileBehavior::new(Rc::clone(preferences)));
+        let formation_templates = deps
+            .preferences
+            .as_ref()
+            .map(|preferences| FormationTemplatesBehavior::new(Rc::clone(preferences)));
+        let keyboard_shortcuts = deps
+            .preferences
+            .as_ref()
+            .map(|preferences| KeyboardShortcutsBehavior::new(Rc::clone(preferences)));
         let open_svg_file = deps.global_state_store.as_ref().and_then(|store| {
             deps.preferences.as_ref().and_then(|preferences| {
                 deps.draw_floor_sender.as_ref().map(|draw_floor_sender| {
@@ -57,8 +73,13 @@ impl ChoreoMainBehaviors {
             })
         });
 
+        let autosave = deps.recovery_store.map(AutosaveBehavior::new);
+
         Self {
             apply_interaction_mode,
+            autosave,
+            formation_templates,
+            keyboard_shortcuts,
             open_audio,
             open_choreo_file,
             open_svg_file,
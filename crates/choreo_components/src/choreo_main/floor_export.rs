@@ -0,0 +1,202 @@
+use std::fmt;
+use std::fs;
+use std::ops::RangeInclusive;
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::floor::FloorImageError;
+use crate::floor::floor_svg_document;
+use crate::floor::rasterize_floor_svg;
+use crate::floor::state::FloorState;
+use crate::material::styling::material_palette::material_palette_for_visuals;
+
+use super::reducer::refresh_floor_projection;
+use super::state::ChoreoMainState;
+
+pub const DEFAULT_FLOOR_EXPORT_WIDTH_PX: f64 = 1920.0;
+pub const DEFAULT_FLOOR_EXPORT_HEIGHT_PX: f64 = 1440.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FloorImageFormat {
+    Svg,
+    Png,
+}
+
+impl FloorImageFormat {
+    #[must_use]
+    pub const fn extension(self) -> &'static str {
+        match self {
+            FloorImageFormat::Svg => "svg",
+            FloorImageFormat::Png => "png",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FloorExportOptions {
+    /// Zero-based scene indices to export; `None` exports every scene.
+    pub scenes: Option<RangeInclusive<usize>>,
+    pub formats: Vec<FloorImageFormat>,
+    pub width_px: f64,
+    pub height_px: f64,
+}
+
+impl Default for FloorExportOptions {
+    fn default() -> Self {
+        Self {
+            scenes: None,
+            formats: vec![FloorImageFormat::Svg, FloorImageFormat::Png],
+            width_px: DEFAULT_FLOOR_EXPORT_WIDTH_PX,
+            height_px: DEFAULT_FLOOR_EXPORT_HEIGHT_PX,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SceneFloorImage {
+    pub scene_index: usize,
+    pub file_stem: String,
+    pub svg: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FloorExportError {
+    NoScenes,
+    Image(FloorImageError),
+    Write { path: PathBuf, message: String },
+}
+
+impl fmt::Display for FloorExportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FloorExportError::NoScenes => write!(f, "There are no scenes to export."),
+            FloorExportError::Image(error) => write!(f, "{error}"),
+            FloorExportError::Write { path, message } => {
+                write!(f, "Could not write {}: {message}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for FloorExportError {}
+
+impl From<FloorImageError> for FloorExportError {
+    fn from(error: FloorImageError) -> Self {
+        FloorExportError::Image(error)
+    }
+}
+
+/// Renders the floor of every requested scene as it would appear selected on the main page.
+///
+/// Handouts always show the legend, the side axis labels and the paths to and from the
+/// neighbouring scenes, whatever the choreography settings say for the live floor.
+#[must_use]
+pub fn render_scene_floors(
+    state: &ChoreoMainState,
+    options: &FloorExportOptions,
+) -> Vec<SceneFloorImage> {
+    let scene_count = state.scene_models.len();
+    let Some(last_index) = scene_count.checked_sub(1) else {
+        return Vec::new();
+    };
+    let indices = options.scenes.clone().map_or(0..=last_index, |range| {
+        *range.start()..=(*range.end()).min(last_index)
+    });
+
+    let style = egui::Style::default();
+    let palette = material_palette_for_visuals(&egui::Visuals::light());
+    let mut export_state = export_main_state(state, options);
+    indices
+        .map(|scene_index| {
+            export_state.selected_scene_index = Some(scene_index);
+            refresh_floor_projection(&mut export_state);
+            SceneFloorImage {
+                scene_index,
+                file_stem: scene_floor_file_stem(
+                    scene_index,
+                    &state.scene_models[scene_index].name,
+                ),
+                svg: floor_svg_document(&export_state.floor_state, &style, palette),
+            }
+        })
+        .collect()
+}
+
+/// Writes one file per scene and format into `directory`, returning the written paths.
+pub fn export_scene_floors(
+    state: &ChoreoMainState,
+    directory: &Path,
+    options: &FloorExportOptions,
+) -> Result<Vec<PathBuf>, FloorExportError> {
+    let images = render_scene_floors(state, options);
+    if images.is_empty() {
+        return Err(FloorExportError::NoScenes);
+    }
+
+    fs::create_dir_all(directory).map_err(|error| FloorExportError::Write {
+        path: directory.to_path_buf(),
+        message: error.to_string(),
+    })?;
+    let mut written = Vec::new();
+    for image in &images {
+        for format in &options.formats {
+            let path = directory.join(format!("{}.{}", image.file_stem, format.extension()));
+            let bytes = match format {
+                FloorImageFormat::Svg => image.svg.clone().into_bytes(),
+                FloorImageFormat::Png => rasterize_floor_svg(&image.svg)?,
+            };
+            fs::write(&path, bytes).map_err(|error| FloorExportError::Write {
+                path: path.clone(),
+                message: error.to_string(),
+            })?;
+            written.push(path);
+        }
+    }
+    Ok(written)
+}
+
+/// Numbers files by scene so they sort in playing order, e.g. `03-chorus`.
+#[must_use]
+pub fn scene_floor_file_stem(scene_index: usize, scene_name: &str) -> String {
+    let mut slug = String::new();
+    for character in scene_name.trim().chars() {
+        if character.is_alphanumeric() {
+            slug.extend(character.to_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    let slug = slug.trim_end_matches('-');
+
+    let number = format!("{:02}", scene_index + 1);
+    if slug.is_empty() {
+        number
+    } else {
+        format!("{number}-{slug}")
+    }
+}
+
+fn export_main_state(state: &ChoreoMainState, options: &FloorExportOptions) -> ChoreoMainState {
+    let mut choreography_settings_state = state.choreography_settings_state.clone();
+    choreography_settings_state.show_legend = true;
+    choreography_settings_state.positions_at_side = true;
+    choreography_settings_state.draw_path_from = true;
+    choreography_settings_state.draw_path_to = true;
+
+    ChoreoMainState {
+        choreography_settings_state,
+        scene_models: state.scene_models.clone(),
+        svg_file_path: state.svg_file_path.clone(),
+        // Scene timestamps would otherwise pick the scene under the audio position.
+        audio_position_seconds: f64::NEG_INFINITY,
+        floor_state: FloorState {
+            layout_width_px: options.width_px,
+            layout_height_px: options.height_px,
+            svg_source_path: state.floor_state.svg_source_path.clone(),
+            svg_source_bytes: state.floor_state.svg_source_bytes.clone(),
+            svg_source_size: state.floor_state.svg_source_size,
+            ..FloorState::default()
+        },
+        ..ChoreoMainState::default()
+    }
+}
@@ -0,0 +1,37 @@
+use std::rc::Rc;
+
+use nject::injectable;
+
+use crate::preferences::Preferences;
+use crate::scenes::formations::FORMATION_TEMPLATES_KEY;
+use crate::scenes::formations::formation_templates_json;
+use crate::scenes::formations::parse_formation_templates;
+
+use super::state::ChoreoMainState;
+
+/// Keeps the formation templates in the preferences.
+#[injectable]
+#[inject(|preferences: Rc<dyn Preferences>| Self::new(preferences))]
+#[derive(Clone)]
+pub struct FormationTemplatesBehavior {
+    preferences: Rc<dyn Preferences>,
+}
+
+impl FormationTemplatesBehavior {
+    #[must_use]
+    pub fn new(preferences: Rc<dyn Preferences>) -> Self {
+        Self { preferences }
+    }
+
+    pub fn initialize(&self, state: &mut ChoreoMainState) {
+        let stored = self.preferences.get_string(FORMATION_TEMPLATES_KEY, "");
+        state.formation_templates = parse_formation_templates(stored.as_str());
+    }
+
+    pub fn remember_templates(&self, state: &ChoreoMainState) {
+        self.preferences.set_string(
+            FORMATION_TEMPLATES_KEY,
+            formation_templates_json(&state.formation_templates),
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use std::rc::Rc;
+
+use crate::choreography_settings::state::ChoreographySettingsState;
+
+const MAX_UNDO_STEPS: usize = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum GestureState {
+    #[default]
+    Idle,
+    Open,
+    Recorded,
+}
+
+/// Undo and redo stacks holding the choreography as it was before each edit.
+///
+/// Edits made between the press and the release of a floor gesture share a single step, so a
+/// whole drag, rotation or scale is undone at once. The steps are shared, so cloning the history
+/// along with the page state does not copy the stored choreographies.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct EditHistory {
+    undo_stack: Vec<Rc<ChoreographySettingsState>>,
+    redo_stack: Vec<Rc<ChoreographySettingsState>>,
+    gesture: GestureState,
+}
+
+impl EditHistory {
+    #[must_use]
+    pub fn can_undo(&self) -> bool {
+        !self.undo_stack.is_empty()
+    }
+
+    #[must_use]
+    pub fn can_redo(&self) -> bool {
+        !self.redo_stack.is_empty()
+    }
+
+    #[must_use]
+    pub fn undo_count(&self) -> usize {
+        self.undo_stack.len()
+    }
+
+    #[must_use]
+    pub fn redo_count(&self) -> usize {
+        self.redo_stack.len()
+    }
+
+    /// Records `before` as a new undo step unless the current gesture already recorded one.
+    pub(crate) fn record(&mut self, before: ChoreographySettingsState) {
+        match self.gesture {
+            GestureState::Recorded => return,
+            GestureState::Open => self.gesture = GestureState::Recorded,
+            GestureState::Idle => {}
+        }
+        self.undo_stack.push(Rc::new(before));
+        if self.undo_stack.len() > MAX_UNDO_STEPS {
+            self.undo_stack.remove(0);
+        }
+        self.redo_stack.clear();
+    }
+
+    pub(crate) fn begin_gesture(&mut self) {
+        self.gesture = GestureState::Open;
+    }
+
+    pub(crate) fn end_gesture(&mut self) {
+        self.gesture = GestureState::Idle;
+    }
+
+    /// Returns the state to restore and keeps `current` for redo.
+    pub(crate) fn undo(
+        &mut self,
+        current: ChoreographySettingsState,
+    ) -> Option<ChoreographySettingsState> {
+        let previous = self.undo_stack.pop()?;
+        self.redo_stack.push(Rc::new(current));
+        self.gesture = GestureState::Idle;
+        Some(Rc::unwrap_or_clone(previous))
+    }
+
+    /// Returns the state to restore and keeps `current` for undo.
+    pub(crate) fn redo(
+        &mut self,
+        current: ChoreographySettingsState,
+    ) -> Option<ChoreographySettingsState> {
+        let next = self.redo_stack.pop()?;
+        self.undo_stack.push(Rc::new(current));
+        self.gesture = GestureState::Idle;
+        Some(Rc::unwrap_or_clone(next))
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+        self.gesture = GestureState::Idle;
+    }
+}
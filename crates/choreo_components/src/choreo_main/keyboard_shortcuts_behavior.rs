@@ -0,0 +1,46 @@
+use std::rc::Rc;
+
+use nject::injectable;
+
+use crate::preferences::Preferences;
+use crate::settings::keyboard_shortcuts::KEYBOARD_SHORTCUTS_KEY;
+use crate::settings::keyboard_shortcuts::KeyboardShortcuts;
+
+use super::state::ChoreoMainState;
+
+/// Keeps the remapped keyboard shortcuts in the preferences.
+#[injectable]
+#[inject(|preferences: Rc<dyn Preferences>| Self::new(preferences))]
+#[derive(Clone)]
+pub struct KeyboardShortcutsBehavior {
+    preferences: Rc<dyn Preferences>,
+}
+
+impl KeyboardShortcutsBehavior {
+    #[must_use]
+    pub fn new(preferences: Rc<dyn Preferences>) -> Self {
+        Self { preferences }
+    }
+
+    pub fn initialize(&self, state: &mut ChoreoMainState) {
+        let stored = self.preferences.get_string(KEYBOARD_SHORTCUTS_KEY, "");
+        if stored.is_empty() {
+            return;
+        }
+        state.settings_state.keyboard_shortcuts = KeyboardShortcuts::from_preference(&stored);
+        state
+            .settings_state
+            .preferences
+            .insert(KEYBOARD_SHORTCUTS_KEY.to_string(), stored);
+    }
+
+    pub fn remember_shortcuts(&self, state: &ChoreoMainState) {
+        let shortcuts = &state.settings_state.keyboard_shortcuts;
+        if shortcuts.is_default() {
+            self.preferences.remove(KEYBOARD_SHORTCUTS_KEY);
+        } else {
+            self.preferences
+                .set_string(KEYBOARD_SHORTCUTS_KEY, shortcuts.to_preference());
+        }
+    }
+}
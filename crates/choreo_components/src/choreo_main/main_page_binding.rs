@@ -1,6 +1,10 @@
+use std::cell::Cell;
 use std::cell::RefCell;
 use std::path::Path;
 use std::rc::Rc;
+use std::time::Duration;
+
+use time::OffsetDateTime;
 
 use super::actions::ChoreoMainAction;
 use super::actions::OpenAudioRequested;
@@ -17,14 +21,20 @@ use super::runtime::apply_audio_action_side_effects;
 use super::runtime::consume_outgoing_commands;
 use super::runtime::enqueue_open_audio_request;
 use super::runtime::poll_audio_runtime;
+use super::runtime::tick_rehearsal_loop;
 use super::state::ChoreoMainState;
+use crate::audio_player::audio_format::is_supported_audio_extension;
 use crate::audio_player::runtime::AudioPlayerRuntime;
 
+/// Picks the target of a save, given a suggested file name; `None` cancels the save.
+pub type PickSaveChoreoPath = dyn Fn(&str) -> Option<String>;
+
 #[derive(Clone, Default)]
 pub struct MainPageActionHandlers {
     pub pick_choreo_file: Option<Rc<dyn Fn() -> Option<OpenChoreoRequested>>>,
     pub pick_audio_path: Option<Rc<dyn Fn() -> Option<String>>>,
     pub pick_image_path: Option<Rc<dyn Fn() -> Option<String>>>,
+    pub pick_save_choreo_path: Option<Rc<PickSaveChoreoPath>>,
     pub request_open_choreo: Option<Rc<dyn Fn(OpenChoreoRequested)>>,
     pub request_open_audio: Option<Rc<dyn Fn(OpenAudioRequested)>>,
     pub request_open_image: Option<Rc<dyn Fn(String)>>,
@@ -41,6 +51,7 @@ pub struct MainPageBinding {
     action_handlers: MainPageActionHandlers,
     behaviors: ChoreoMainBehaviors,
     audio_runtime: RefCell<AudioPlayerRuntime>,
+    last_rehearsal_tick: Cell<Option<OffsetDateTime>>,
 }
 
 impl MainPageBinding {
@@ -57,6 +68,7 @@ impl MainPageBinding {
             action_handlers,
             behaviors,
             audio_runtime: RefCell::new(AudioPlayerRuntime::new(audio_backend)),
+            last_rehearsal_tick: Cell::new(None),
         }
     }
 
@@ -82,6 +94,28 @@ impl MainPageBinding {
         poll_audio_runtime(&mut state, &mut audio_runtime)
     }
 
+    /// Moves a running rehearsal loop on by the time since the previous tick; returns whether it
+    /// still needs ticks.
+    #[must_use]
+    pub fn tick_rehearsal_loop(&self, now: OffsetDateTime) -> bool {
+        let mut state = self.state.borrow_mut();
+        let mut audio_runtime = self.audio_runtime.borrow_mut();
+        let elapsed_seconds = self
+            .last_rehearsal_tick
+            .get()
+            .map_or(0.0, |last| (now - last).as_seconds_f64().max(0.0));
+        let is_running = tick_rehearsal_loop(&mut state, &mut audio_runtime, elapsed_seconds);
+        self.last_rehearsal_tick.set(is_running.then_some(now));
+        is_running
+    }
+
+    /// Returns how long until the next autosave while unsaved changes are waiting for it.
+    #[must_use]
+    pub fn tick_autosave(&self, now: OffsetDateTime) -> Option<Duration> {
+        let behavior = self.behaviors.autosave.as_ref()?;
+        behavior.tick(&self.state.borrow(), now)
+    }
+
     #[must_use]
     pub fn audio_runtime_is_active(&self) -> bool {
         let state = self.state.borrow();
@@ -178,7 +212,7 @@ impl MainPageBinding {
             return;
         }
 
-        if extension == "mp3" {
+        if is_supported_audio_extension(extension.as_str()) {
             self.request_open_audio(OpenAudioRequested {
                 file_path: file_path.to_string(),
                 trace_context: None,
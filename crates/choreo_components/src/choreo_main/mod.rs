@@ -1,7 +1,12 @@
 pub mod actions;
 mod apply_interaction_mode_behavior;
+mod autosave_behavior;
 mod behaviors;
+mod floor_export;
+mod formation_templates_behavior;
 mod hide_dialog_behavior;
+pub mod history;
+mod keyboard_shortcuts_behavior;
 mod main_page_binding;
 mod messages;
 mod open_audio_behavior;
@@ -12,13 +17,25 @@ pub mod reducer;
 mod runtime;
 mod show_dialog_behavior;
 pub mod state;
+pub mod translations;
 pub mod ui;
 
 pub use apply_interaction_mode_behavior::ApplyInteractionModeBehavior;
+pub use autosave_behavior::AUTOSAVE_INTERVAL;
+pub use autosave_behavior::AutosaveBehavior;
 pub use behaviors::ChoreoMainBehaviorDependencies;
 pub use behaviors::ChoreoMainBehaviors;
+pub use floor_export::{
+    DEFAULT_FLOOR_EXPORT_HEIGHT_PX, DEFAULT_FLOOR_EXPORT_WIDTH_PX, FloorExportError,
+    FloorExportOptions, FloorImageFormat, SceneFloorImage, export_scene_floors,
+    render_scene_floors, scene_floor_file_stem,
+};
+pub use formation_templates_behavior::FormationTemplatesBehavior;
 pub use hide_dialog_behavior::HideDialogBehavior;
-pub use main_page_binding::{MainPageActionHandlers, MainPageBinding, MainPageDependencies};
+pub use keyboard_shortcuts_behavior::KeyboardShortcutsBehavior;
+pub use main_page_binding::{
+    MainPageActionHandlers, MainPageBinding, MainPageDependencies, PickSaveChoreoPath,
+};
 pub use messages::{
     CloseDialogCommand, OpenAudioRequested, OpenChoreoRequested, OpenImageRequested,
     OpenSvgFileCommand, ShowDialogCommand,
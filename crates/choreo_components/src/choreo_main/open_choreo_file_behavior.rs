@@ -57,6 +57,10 @@ impl OpenChoreoFileBehavior {
             return;
         }
 
+        self.remember_last_opened_choreo_file(file_path);
+    }
+
+    pub fn remember_last_opened_choreo_file(&self, file_path: &str) {
         self.preferences.set_string(
             choreo_models::SettingsPreferenceKeys::LAST_OPENED_CHOREO_FILE,
             file_path.to_string(),
@@ -3,15 +3,43 @@ use super::state::ChoreoMainState;
 use super::state::InteractionMode;
 use super::state::InteractionStateMachineState;
 use super::state::MainContent;
+use super::state::UnsavedChangesPrompt;
 use crate::audio_player::actions::AudioPlayerAction;
+use crate::audio_player::beat_detection::snap_timestamp;
 use crate::audio_player::reducer::AudioPlayerEffect;
 use crate::choreography_settings::actions::ChoreographySettingsAction;
 use crate::choreography_settings::actions::UpdateSelectedSceneAction;
+use crate::choreography_settings::state::ChoreographySettingsState;
 use crate::choreography_settings::state::SelectedSceneState;
 use crate::dancers::actions::DancersAction;
 use crate::dancers::state as dancers_state;
+use crate::floor::actions::FloorAction;
+use crate::floor::movement_path;
+use crate::floor::orientation::interpolate_orientation;
 use crate::floor::state::FloorPosition;
+use crate::floor::state::InteractionMode as FloorInteractionMode;
+use crate::floor::state::Point;
 use crate::floor::state::SceneRenderPosition;
+use crate::floor::state::TouchAction;
+use crate::floor::state::TouchEventArgs;
+use crate::scenes::arrangement;
+use crate::scenes::auto_assign::AutoAssignError;
+use crate::scenes::auto_assign::assign_unplaced_dancers;
+use crate::scenes::auto_assign::build_in_between_positions;
+use crate::scenes::auto_assign::in_between_timing;
+use crate::scenes::auto_assign::set_required_role;
+use crate::scenes::formations;
+use crate::scenes::formations::FormationChoice;
+use crate::scenes::formations::FormationParameters;
+use crate::scenes::formations::FormationTemplate;
+use crate::scenes::transition_paths::TransitionPathError;
+use crate::scenes::transition_paths::bend_colliding_paths;
+use crate::scenes::translations::auto_assign_error_message;
+use crate::scenes::translations::transition_path_error_message;
+use crate::scenes::translations::unresolved_collisions_message;
+use crate::scenes::translations::variation_error_message;
+use crate::scenes::variations;
+use crate::scenes::variations::VariationError;
 use crate::settings::actions::SettingsAction;
 use choreo_master_mobile_json::Color;
 use choreo_master_mobile_json::DancerId;
@@ -28,11 +56,27 @@ use super::open_choreo_file_behavior;
 use super::open_image_behavior;
 use super::open_svg_file_behavior;
 
+const DEFAULT_LOCALE: &str = "en";
+
 pub fn reduce(state: &mut ChoreoMainState, action: ChoreoMainAction) {
     reduce_with_behaviors(state, action);
 }
 
 pub fn reduce_with_behaviors(state: &mut ChoreoMainState, action: ChoreoMainAction) {
+    track_floor_gesture(state, &action);
+    if !is_undoable_edit(&action) {
+        apply_action(state, action);
+        return;
+    }
+
+    let before = state.choreography_settings_state.clone();
+    apply_action(state, action);
+    if state.choreography_settings_state.choreography != before.choreography {
+        state.history.record(before);
+    }
+}
+
+fn apply_action(state: &mut ChoreoMainState, action: ChoreoMainAction) {
     match action {
         ChoreoMainAction::Initialize => {
             state.content = MainContent::Main;
@@ -59,10 +103,27 @@ pub fn reduce_with_behaviors(state: &mut ChoreoMainState, action: ChoreoMainActi
         ChoreoMainAction::CloseAudioPanel => {
             state.is_audio_player_open = false;
         }
+        ChoreoMainAction::ToggleRehearsalPanel => {
+            state.is_rehearsal_panel_open = !state.is_rehearsal_panel_open;
+            if state.is_rehearsal_panel_open && state.rehearsal_start_scene_index.is_none() {
+                let start = state.selected_scene_index.unwrap_or(0);
+                state.rehearsal_start_scene_index = (start < state.scenes.len()).then_some(start);
+                state.rehearsal_end_scene_index =
+                    (start + 1 < state.scenes.len()).then_some(start + 1);
+            }
+        }
+        ChoreoMainAction::UpdateRehearsalScenes {
+            start_scene_index,
+            end_scene_index,
+        } => {
+            state.rehearsal_start_scene_index = start_scene_index;
+            state.rehearsal_end_scene_index = end_scene_index;
+        }
         ChoreoMainAction::SelectMode { index } => {
             state.selected_mode_index = index;
             if let Some(mode) = interaction_mode_from_index(index) {
                 state.interaction_mode = mode;
+                sync_floor_interaction_mode(state);
             }
         }
         ChoreoMainAction::ResetFloorViewport => {
@@ -95,19 +156,33 @@ pub fn reduce_with_behaviors(state: &mut ChoreoMainState, action: ChoreoMainActi
             state.is_dialog_open = false;
         }
         ChoreoMainAction::RequestOpenChoreo(request) => {
-            open_choreo_file_behavior::request_open_choreo(state, request);
+            if state.has_unsaved_changes() {
+                state.unsaved_changes_prompt = Some(UnsavedChangesPrompt::OpenChoreo(request));
+            } else {
+                open_choreo_file_behavior::request_open_choreo(state, request);
+            }
         }
-        ChoreoMainAction::RequestSaveChoreo => {
-            if let Some(file_path) = state.last_opened_choreo_file.as_ref().filter(|path| {
-                !path.trim().is_empty() && std::path::Path::new(path.as_str()).exists()
-            }) {
-                state
-                    .outgoing_save_choreo_requests
-                    .push(super::actions::SaveChoreoRequested {
-                        file_path: file_path.clone(),
-                    });
+        ChoreoMainAction::RequestSaveChoreo => request_save_choreo(state),
+        ChoreoMainAction::RequestSaveChoreoAs => request_save_choreo_as(state),
+        ChoreoMainAction::RestoreRecovery | ChoreoMainAction::DiscardRecovery => {
+            state.recovery_offer = None;
+        }
+        ChoreoMainAction::RequestClose => {
+            if state.is_close_confirmed || !state.has_unsaved_changes() {
+                state.is_close_confirmed = true;
+            } else {
+                state.unsaved_changes_prompt = Some(UnsavedChangesPrompt::Close);
+            }
+        }
+        ChoreoMainAction::SaveUnsavedChanges => {
+            if state.unsaved_changes_prompt.is_some() {
+                request_save_choreo(state);
             }
         }
+        ChoreoMainAction::DiscardUnsavedChanges => continue_unsaved_changes_prompt(state),
+        ChoreoMainAction::CancelUnsavedChanges => {
+            state.unsaved_changes_prompt = None;
+        }
         ChoreoMainAction::RequestOpenAudio(request) => {
             open_audio_behavior::request_open_audio(state, request);
         }
@@ -131,6 +206,7 @@ pub fn reduce_with_behaviors(state: &mut ChoreoMainState, action: ChoreoMainActi
             state.selected_mode_index = interaction_mode_index(mode);
             state.selected_positions_count = selected_positions_count;
             state.interaction_state_machine = map_interaction_state(mode, selected_positions_count);
+            sync_floor_interaction_mode(state);
         }
         ChoreoMainAction::SetScenes { scenes } => {
             state.scenes = scenes;
@@ -147,16 +223,71 @@ pub fn reduce_with_behaviors(state: &mut ChoreoMainState, action: ChoreoMainActi
             sync_choreography_settings_projection(state);
         }
         ChoreoMainAction::DeleteSelectedScene => {
-            delete_selected_scene_internal(state);
-            sync_choreography_settings_projection(state);
+            delete_selected_scenes_internal(state);
+        }
+        ChoreoMainAction::DeleteSelectedPositions => {
+            delete_selected_positions_internal(state);
         }
         ChoreoMainAction::SelectScene { index } => {
             select_scene_internal(state, index, false);
             sync_choreography_settings_projection(state);
         }
+        ChoreoMainAction::ToggleSceneSelection { index } => {
+            toggle_scene_selection_internal(state, index);
+            sync_choreography_settings_projection(state);
+        }
+        ChoreoMainAction::ExtendSceneSelection { index } => {
+            extend_scene_selection_internal(state, index);
+            sync_choreography_settings_projection(state);
+        }
+        ChoreoMainAction::MoveScene { from, to } => {
+            let scenes = &mut state.choreography_settings_state.choreography.scenes;
+            if let Some(index) = arrangement::move_scene(scenes, from, to) {
+                apply_scene_list_edit(state, index);
+            }
+        }
+        ChoreoMainAction::DuplicateScene => {
+            let scenes = &mut state.choreography_settings_state.choreography.scenes;
+            if let Some(index) = state.selected_scene_index.and_then(|index| {
+                arrangement::duplicate_scene(scenes, index, synthetic_scene_id(scenes.len()))
+            }) {
+                apply_scene_list_edit(state, index);
+            }
+        }
+        ChoreoMainAction::ShiftSceneTimestamps { seconds } => {
+            shift_scene_timestamps_internal(state, seconds);
+        }
+        ChoreoMainAction::SelectFormation { choice } => {
+            state.formation_choice = choice;
+        }
+        ChoreoMainAction::UpdateFormationParameters { parameters } => {
+            state.formation_parameters = parameters;
+        }
+        ChoreoMainAction::ApplyFormation => apply_formation_internal(state),
+        ChoreoMainAction::SaveFormationTemplate => save_formation_template_internal(state),
         ChoreoMainAction::UpdateAudioPosition { seconds } => {
             sync_audio_position_internal(state, seconds);
         }
+        ChoreoMainAction::StartRehearsalLoop {
+            start_scene_index,
+            end_scene_index,
+        } => {
+            let Some((start_seconds, end_seconds)) =
+                rehearsal_loop_bounds(state, start_scene_index, end_scene_index)
+            else {
+                return;
+            };
+            crate::audio_player::reducer::reduce(
+                &mut state.audio_player_state,
+                AudioPlayerAction::StartRehearsalLoop {
+                    start_seconds,
+                    end_seconds,
+                },
+            );
+            if state.audio_player_state.rehearsal_loop.is_some() {
+                sync_audio_position_internal(state, start_seconds);
+            }
+        }
         ChoreoMainAction::LinkSelectedSceneToAudioPosition => {
             let Some(selected_index) = state.selected_scene_index else {
                 return;
@@ -164,14 +295,66 @@ pub fn reduce_with_behaviors(state: &mut ChoreoMainState, action: ChoreoMainActi
             let Some(selected_scene) = state.scenes.get_mut(selected_index) else {
                 return;
             };
-            let linked_timestamp = round_to_100_millis(state.audio_position_seconds);
+            let linked_timestamp = snap_timestamp(
+                state.audio_position_seconds,
+                state.audio_player_state.timestamp_snap,
+                state.audio_player_state.beat_grid.as_ref(),
+            );
             selected_scene.timestamp_seconds = Some(linked_timestamp);
             state.audio_position_seconds = linked_timestamp;
             state.floor_scene_name = Some(selected_scene.name.clone());
             sync_choreography_settings_projection(state);
         }
+        ChoreoMainAction::AutoAssignDancers => {
+            auto_assign_dancers_internal(state);
+        }
+        ChoreoMainAction::InsertInBetweenScene { formation } => {
+            insert_in_between_scene_internal(state, formation);
+        }
+        ChoreoMainAction::BendCollidingPaths => {
+            bend_colliding_paths_internal(state);
+        }
+        ChoreoMainAction::BranchSceneVariation => {
+            branch_scene_variation_internal(state);
+        }
+        ChoreoMainAction::SwitchSceneVariation { variation } => {
+            switch_scene_variation_internal(state, variation);
+        }
+        ChoreoMainAction::PromoteSceneVariation => {
+            promote_scene_variation_internal(state);
+        }
+        ChoreoMainAction::CompareSceneVariation { variation } => {
+            state.compared_scene_variation = variation;
+            refresh_floor_projection(state);
+            state.draw_floor_request_count += 1;
+        }
+        ChoreoMainAction::SetRequiredRole {
+            position_indices,
+            role_index,
+        } => {
+            set_required_role_internal(state, &position_indices, role_index);
+        }
+        ChoreoMainAction::Undo => {
+            let current = state.choreography_settings_state.clone();
+            if let Some(previous) = state.history.undo(current) {
+                restore_choreography(state, previous);
+            }
+        }
+        ChoreoMainAction::Redo => {
+            let current = state.choreography_settings_state.clone();
+            if let Some(next) = state.history.redo(current) {
+                restore_choreography(state, next);
+            }
+        }
         ChoreoMainAction::FloorAction(action) => {
+            let moves_positions = is_position_transform(&action);
+            let bends_path = is_path_bend(&action);
             crate::floor::reducer::reduce(&mut state.floor_state, action);
+            if moves_positions {
+                write_floor_positions_to_scene(state);
+            } else if bends_path {
+                write_floor_curves_to_previous_scene(state);
+            }
         }
         ChoreoMainAction::AudioPlayerAction(action) => {
             let seek_position = match &action {
@@ -179,6 +362,10 @@ pub fn reduce_with_behaviors(state: &mut ChoreoMainState, action: ChoreoMainActi
                 | AudioPlayerAction::PositionDragCompleted { position } => Some(*position),
                 _ => None,
             };
+            let moved_scene_id = match &action {
+                AudioPlayerAction::MoveSceneTimestamp { scene_id, .. } => Some(*scene_id),
+                _ => None,
+            };
 
             let effects =
                 crate::audio_player::reducer::reduce(&mut state.audio_player_state, action);
@@ -186,27 +373,38 @@ pub fn reduce_with_behaviors(state: &mut ChoreoMainState, action: ChoreoMainActi
             if let Some(position) = seek_position {
                 sync_audio_position_internal(state, position);
             }
+            if let Some(scene_id) = moved_scene_id {
+                sync_scene_timestamp_from_audio_player(state, scene_id);
+            }
 
             for effect in effects {
                 match effect {
-                    AudioPlayerEffect::PositionChangedPublished { position_seconds } => {
+                    AudioPlayerEffect::PositionChangedPublished { position_seconds }
+                    | AudioPlayerEffect::TransportRequested {
+                        position_seconds, ..
+                    } => {
                         sync_audio_position_internal(state, position_seconds);
                     }
                 }
             }
         }
         ChoreoMainAction::ChoreographySettingsAction(action) => {
+            if matches!(action, ChoreographySettingsAction::LoadChoreography { .. }) {
+                state.history.clear();
+            }
             crate::choreography_settings::reducer::reduce(
                 &mut state.choreography_settings_state,
                 action,
             );
             sync_main_state_from_choreography_settings(state);
+            sync_audio_musical_timeline_from_choreography_settings(state);
         }
         ChoreoMainAction::SettingsAction(action) => {
             crate::settings::reducer::reduce(&mut state.settings_state, action.clone());
             if matches!(action, SettingsAction::NavigateBack) {
                 state.content = MainContent::Main;
             }
+            sync_audio_speed_range_from_settings(state);
         }
         ChoreoMainAction::DancersAction(action) => {
             if matches!(action, DancersAction::Cancel) {
@@ -228,6 +426,129 @@ pub fn reduce_with_behaviors(state: &mut ChoreoMainState, action: ChoreoMainActi
     }
 }
 
+fn sync_audio_speed_range_from_settings(state: &mut ChoreoMainState) {
+    let settings = &state.settings_state;
+    crate::audio_player::reducer::reduce(
+        &mut state.audio_player_state,
+        AudioPlayerAction::SetSpeedRange {
+            minimum: f64::from(settings.minimum_playback_speed_percent) / 100.0,
+            maximum: f64::from(settings.maximum_playback_speed_percent) / 100.0,
+        },
+    );
+}
+
+fn sync_audio_musical_timeline_from_choreography_settings(state: &mut ChoreoMainState) {
+    let timeline = state.choreography_settings_state.musical_timeline;
+    if state.audio_player_state.musical_timeline == timeline {
+        return;
+    }
+    crate::audio_player::reducer::reduce(
+        &mut state.audio_player_state,
+        AudioPlayerAction::SetMusicalTimeline { timeline },
+    );
+}
+
+fn is_undoable_edit(action: &ChoreoMainAction) -> bool {
+    match action {
+        ChoreoMainAction::InsertScene { .. }
+        | ChoreoMainAction::DeleteSelectedScene
+        | ChoreoMainAction::DeleteSelectedPositions
+        | ChoreoMainAction::MoveScene { .. }
+        | ChoreoMainAction::DuplicateScene
+        | ChoreoMainAction::ShiftSceneTimestamps { .. }
+        | ChoreoMainAction::ApplyFormation
+        | ChoreoMainAction::LinkSelectedSceneToAudioPosition
+        | ChoreoMainAction::AudioPlayerAction(AudioPlayerAction::MoveSceneTimestamp { .. })
+        | ChoreoMainAction::AutoAssignDancers
+        | ChoreoMainAction::InsertInBetweenScene { .. }
+        | ChoreoMainAction::BendCollidingPaths
+        | ChoreoMainAction::BranchSceneVariation
+        | ChoreoMainAction::SwitchSceneVariation { .. }
+        | ChoreoMainAction::PromoteSceneVariation
+        | ChoreoMainAction::SetRequiredRole { .. }
+        | ChoreoMainAction::DancersAction(DancersAction::SaveToGlobal) => true,
+        ChoreoMainAction::FloorAction(action) => {
+            is_position_transform(action) || is_path_bend(action)
+        }
+        ChoreoMainAction::ChoreographySettingsAction(action) => !matches!(
+            action,
+            ChoreographySettingsAction::LoadChoreography { .. }
+                | ChoreographySettingsAction::LoadSettingsPreferences { .. }
+                | ChoreographySettingsAction::InitializeDrawPathFrom(_)
+                | ChoreographySettingsAction::InitializeDrawPathTo(_)
+                | ChoreographySettingsAction::InitializeShowLegend(_)
+                | ChoreographySettingsAction::InitializeShowTimestamps(_)
+                | ChoreographySettingsAction::InitializePositionsAtSide(_)
+                | ChoreographySettingsAction::InitializeSnapToGrid(_)
+                | ChoreographySettingsAction::ClearEphemeralOutputs
+                | ChoreographySettingsAction::UpdateSelectedScene(
+                    UpdateSelectedSceneAction::SyncFromSelected
+                )
+        ),
+        _ => false,
+    }
+}
+
+fn is_position_transform(action: &FloorAction) -> bool {
+    matches!(
+        action,
+        FloorAction::MoveSelectedByDelta { .. }
+            | FloorAction::RotateSelectedAroundCenter { .. }
+            | FloorAction::RotateSelectedAroundPivot { .. }
+            | FloorAction::ScaleSelected { .. }
+            | FloorAction::AlignSelected { .. }
+            | FloorAction::DistributeSelected { .. }
+            | FloorAction::MirrorSelected { .. }
+            | FloorAction::FaceSelectedTowards { .. }
+            | FloorAction::MovePathWaypoint { .. }
+            | FloorAction::ClearPathWaypoint { .. }
+    )
+}
+
+/// Edits of the curve controls, which live on the previous scene's positions.
+fn is_path_bend(action: &FloorAction) -> bool {
+    matches!(
+        action,
+        FloorAction::MoveCurveControl { .. } | FloorAction::StraightenPath { .. }
+    )
+}
+
+/// Groups the position transforms between pressing and releasing the floor into one undo step.
+fn track_floor_gesture(state: &mut ChoreoMainState, action: &ChoreoMainAction) {
+    match action {
+        ChoreoMainAction::FloorAction(
+            FloorAction::PointerPressed { .. } | FloorAction::PointerPressedWithContext { .. },
+        ) => state.history.begin_gesture(),
+        ChoreoMainAction::FloorAction(
+            FloorAction::PointerReleased { .. } | FloorAction::PointerReleasedWithContext { .. },
+        ) => state.history.end_gesture(),
+        ChoreoMainAction::FloorAction(FloorAction::Touch { action, .. })
+        | ChoreoMainAction::FloorAction(FloorAction::TouchWithContext {
+            event_args: TouchEventArgs { action, .. },
+            ..
+        }) => match action {
+            TouchAction::Pressed => state.history.begin_gesture(),
+            TouchAction::Released | TouchAction::Cancelled => state.history.end_gesture(),
+            TouchAction::Moved => {}
+        },
+        // An edit from outside the floor never belongs to a floor gesture, even when the
+        // release that should have ended it got lost.
+        ChoreoMainAction::FloorAction(_) => {}
+        action if is_undoable_edit(action) => state.history.end_gesture(),
+        _ => {}
+    }
+}
+
+fn restore_choreography(
+    state: &mut ChoreoMainState,
+    choreography_settings_state: ChoreographySettingsState,
+) {
+    state.choreography_settings_state = choreography_settings_state;
+    sync_main_state_from_choreography_settings(state);
+    sync_audio_musical_timeline_from_choreography_settings(state);
+    state.draw_floor_request_count += 1;
+}
+
 pub(crate) fn interaction_mode_from_index(index: i32) -> Option<InteractionMode> {
     match index {
         0 => Some(InteractionMode::View),
@@ -236,6 +557,9 @@ pub(crate) fn interaction_mode_from_index(index: i32) -> Option<InteractionMode>
         3 => Some(InteractionMode::RotateAroundDancer),
         4 => Some(InteractionMode::Scale),
         5 => Some(InteractionMode::LineOfSight),
+        6 => Some(InteractionMode::Face),
+        7 => Some(InteractionMode::EditPath),
+        8 => Some(InteractionMode::BendPath),
         _ => None,
     }
 }
@@ -248,6 +572,9 @@ fn interaction_mode_index(mode: InteractionMode) -> i32 {
         InteractionMode::RotateAroundDancer => 3,
         InteractionMode::Scale => 4,
         InteractionMode::LineOfSight => 5,
+        InteractionMode::Face => 6,
+        InteractionMode::EditPath => 7,
+        InteractionMode::BendPath => 8,
     }
 }
 
@@ -259,9 +586,30 @@ pub(crate) fn map_global_interaction_mode(mode: InteractionMode) -> crate::globa
         InteractionMode::RotateAroundDancer => crate::global::InteractionMode::RotateAroundDancer,
         InteractionMode::Scale => crate::global::InteractionMode::Scale,
         InteractionMode::LineOfSight => crate::global::InteractionMode::LineOfSight,
+        InteractionMode::Face => crate::global::InteractionMode::Face,
+        InteractionMode::EditPath => crate::global::InteractionMode::EditPath,
+        InteractionMode::BendPath => crate::global::InteractionMode::BendPath,
     }
 }
 
+fn sync_floor_interaction_mode(state: &mut ChoreoMainState) {
+    let mode = match state.interaction_mode {
+        InteractionMode::View => FloorInteractionMode::None,
+        InteractionMode::Move => FloorInteractionMode::Move,
+        InteractionMode::RotateAroundCenter => FloorInteractionMode::RotateAroundCenter,
+        InteractionMode::RotateAroundDancer => FloorInteractionMode::RotateAroundDancer,
+        InteractionMode::Scale => FloorInteractionMode::Scale,
+        InteractionMode::LineOfSight => FloorInteractionMode::LineOfSight,
+        InteractionMode::Face => FloorInteractionMode::Face,
+        InteractionMode::EditPath => FloorInteractionMode::EditPath,
+        InteractionMode::BendPath => FloorInteractionMode::BendPath,
+    };
+    crate::floor::reducer::reduce(
+        &mut state.floor_state,
+        FloorAction::SetInteractionMode { mode },
+    );
+}
+
 fn map_interaction_state(
     mode: InteractionMode,
     selected_positions_count: usize,
@@ -284,7 +632,14 @@ fn map_interaction_state(
             InteractionStateMachineState::ScalePositions
         }
         InteractionMode::Scale => InteractionStateMachineState::ScalePositionsSelection,
-        InteractionMode::View | InteractionMode::LineOfSight => InteractionStateMachineState::Idle,
+        InteractionMode::BendPath if selected_positions_count == 0 => {
+            InteractionStateMachineState::BendPath
+        }
+        InteractionMode::BendPath => InteractionStateMachineState::BendPathSelection,
+        InteractionMode::View
+        | InteractionMode::LineOfSight
+        | InteractionMode::Face
+        | InteractionMode::EditPath => InteractionStateMachineState::Idle,
     }
 }
 
@@ -292,6 +647,10 @@ fn select_scene_internal(state: &mut ChoreoMainState, index: usize, keep_audio_p
     let Some(scene) = state.scenes.get(index) else {
         return;
     };
+    // Following the audio keeps the scenes picked together while it stays on the selected scene.
+    if !keep_audio_position || state.selected_scene_index != Some(index) {
+        state.selected_scene_indices.clear();
+    }
     state.selected_scene_index = Some(index);
     state.floor_scene_name = Some(scene.name.clone());
     if !keep_audio_position && let Some(timestamp) = scene.timestamp_seconds {
@@ -315,23 +674,457 @@ fn insert_scene_internal(state: &mut ChoreoMainState, insert_after: bool) {
     select_scene_internal(state, insert_index, false);
 }
 
-fn delete_selected_scene_internal(state: &mut ChoreoMainState) {
-    let Some(index) = state.selected_scene_index else {
+/// The selected scene together with every scene picked along with it, in list order.
+fn scene_selection(state: &ChoreoMainState) -> Vec<usize> {
+    if state.selected_scene_indices.is_empty() {
+        state.selected_scene_index.into_iter().collect()
+    } else {
+        state.selected_scene_indices.clone()
+    }
+}
+
+fn toggle_scene_selection_internal(state: &mut ChoreoMainState, index: usize) {
+    if index >= state.scenes.len() {
         return;
+    }
+    let selection = arrangement::toggle_selection(&scene_selection(state), index);
+    let shown = if selection.contains(&index) {
+        index
+    } else {
+        state
+            .selected_scene_index
+            .filter(|selected| selection.contains(selected))
+            .unwrap_or(selection[0])
     };
+    select_scene_internal(state, shown, false);
+    if selection.len() > 1 {
+        state.selected_scene_indices = selection;
+    }
+}
+
+fn extend_scene_selection_internal(state: &mut ChoreoMainState, index: usize) {
     if index >= state.scenes.len() {
         return;
     }
+    let Some(anchor) = state.selected_scene_index else {
+        select_scene_internal(state, index, false);
+        return;
+    };
+    let selection = arrangement::extend_selection(anchor, index);
+    state.selected_scene_indices = if selection.len() > 1 {
+        selection
+    } else {
+        Vec::new()
+    };
+}
 
-    state.scenes.remove(index);
-    if state.scenes.is_empty() {
+fn delete_selected_scenes_internal(state: &mut ChoreoMainState) {
+    let selection = scene_selection(state);
+    let Some(first) = selection.first().copied() else {
+        return;
+    };
+    let scenes = &mut state.choreography_settings_state.choreography.scenes;
+    if arrangement::remove_scenes(scenes, &selection) == 0 {
+        return;
+    }
+    if scenes.is_empty() {
+        state.scenes.clear();
         state.selected_scene_index = None;
+        state.selected_scene_indices.clear();
         state.floor_scene_name = None;
+        sync_choreography_settings_projection(state);
+        return;
+    }
+
+    let next_index = first.min(scenes.len() - 1);
+    apply_scene_list_edit(state, next_index);
+}
+
+fn shift_scene_timestamps_internal(state: &mut ChoreoMainState, seconds: f64) {
+    let selection = scene_selection(state);
+    let selected_indices = state.selected_scene_indices.clone();
+    let Some(selected_index) = state.selected_scene_index else {
+        return;
+    };
+    let scenes = &mut state.choreography_settings_state.choreography.scenes;
+    for (_, scene) in scenes
+        .iter_mut()
+        .enumerate()
+        .filter(|(index, _)| selection.contains(index))
+    {
+        if let Some(timestamp) = parse_scene_timestamp(scene.timestamp.as_deref()) {
+            scene.timestamp = Some(format_scene_timestamp(arrangement::shift_timestamp(
+                timestamp, seconds,
+            )));
+        }
+    }
+    apply_scene_list_edit(state, selected_index);
+    state.selected_scene_indices = selected_indices;
+}
+
+fn apply_formation_internal(state: &mut ChoreoMainState) {
+    let Some(index) = state.selected_scene_index else {
+        return;
+    };
+    let grid_resolution = state.choreography_settings_state.grid_resolution();
+    let choreography = &mut state.choreography_settings_state.choreography;
+    let Some(spots) = formations::formation_spots(
+        state.formation_choice,
+        state.formation_parameters,
+        &state.formation_templates,
+        &choreography.floor,
+        grid_resolution,
+    ) else {
+        return;
+    };
+    let Some(scene) = choreography.scenes.get_mut(index) else {
+        return;
+    };
+    formations::arrange_positions(scene, &spots);
+
+    state.scene_models = choreography.scenes.clone();
+    state.floor_state.selected_positions.clear();
+    refresh_floor_projection(state);
+    state.draw_floor_request_count += 1;
+}
+
+fn save_formation_template_internal(state: &mut ChoreoMainState) {
+    let scenes = &state.choreography_settings_state.choreography.scenes;
+    let Some(scene) = state
+        .selected_scene_index
+        .and_then(|index| scenes.get(index))
+        .filter(|scene| !scene.positions.is_empty())
+    else {
+        return;
+    };
+    let template = FormationTemplate::from_positions(scene.name.clone(), &scene.positions);
+    let index = formations::save_template(&mut state.formation_templates, template);
+    state.formation_choice = FormationChoice::Template(index);
+}
+
+fn auto_assign_dancers_internal(state: &mut ChoreoMainState) {
+    let Some(index) = state.selected_scene_index else {
+        return;
+    };
+    let scenes = &mut state.choreography_settings_state.choreography.scenes;
+    if index >= scenes.len() {
+        return;
+    }
+
+    let (previous_scenes, remaining_scenes) = scenes.split_at_mut(index);
+    let result = match previous_scenes.last() {
+        Some(previous) => assign_unplaced_dancers(previous, &mut remaining_scenes[0]),
+        None => Err(AutoAssignError::NoPreviousScene),
+    };
+
+    match result {
+        Ok(_) => {
+            state.scene_models = state
+                .choreography_settings_state
+                .choreography
+                .scenes
+                .clone();
+            refresh_floor_projection(state);
+            state.draw_floor_request_count += 1;
+        }
+        Err(error) => show_auto_assign_error(state, &error),
+    }
+}
+
+fn insert_in_between_scene_internal(
+    state: &mut ChoreoMainState,
+    formation: Option<FormationChoice>,
+) {
+    let Some(index) = state.selected_scene_index else {
+        return;
+    };
+    let scenes = &state.choreography_settings_state.choreography.scenes;
+    let Some(start) = scenes.get(index) else {
+        return;
+    };
+    let Some(end) = scenes.get(index + 1) else {
+        show_auto_assign_error(state, &AutoAssignError::NoNextScene);
+        return;
+    };
+
+    let (fraction, timestamp_seconds) = in_between_timing(
+        parse_scene_timestamp(start.timestamp.as_deref()),
+        parse_scene_timestamp(end.timestamp.as_deref()),
+        Some(state.audio_position_seconds),
+    );
+    let formation_positions;
+    let spots = match formation {
+        None => &start.positions,
+        Some(choice) => {
+            let parameters = FormationParameters {
+                count: start.positions.len(),
+                ..state.formation_parameters
+            };
+            let Some(spots) = formations::formation_spots(
+                choice,
+                parameters,
+                &state.formation_templates,
+                &state.choreography_settings_state.choreography.floor,
+                state.choreography_settings_state.grid_resolution(),
+            ) else {
+                return;
+            };
+            formation_positions = formations::spot_positions(&spots);
+            &formation_positions
+        }
+    };
+    let positions = match build_in_between_positions(start, end, spots, fraction) {
+        Ok(positions) => positions,
+        Err(error) => {
+            show_auto_assign_error(state, &error);
+            return;
+        }
+    };
+
+    let name = build_new_scene_name(&state.scenes);
+    let scenes = &mut state.choreography_settings_state.choreography.scenes;
+    scenes.insert(
+        index + 1,
+        SceneModel {
+            scene_id: synthetic_scene_id(index + 1),
+            positions,
+            name: name.clone(),
+            text: None,
+            fixed_positions: false,
+            timestamp: timestamp_seconds.map(format_scene_timestamp),
+            variation_depth: 0,
+            variations: Vec::new(),
+            current_variation: Vec::new(),
+            color: Color::transparent(),
+        },
+    );
+    for (scene_index, scene) in scenes.iter_mut().enumerate() {
+        scene.scene_id = synthetic_scene_id(scene_index);
+    }
+    state.scenes.insert(
+        index + 1,
+        super::state::SceneState {
+            name,
+            timestamp_seconds,
+        },
+    );
+    select_scene_internal(state, index + 1, false);
+    sync_choreography_settings_projection(state);
+    state.draw_floor_request_count += 1;
+}
+
+fn bend_colliding_paths_internal(state: &mut ChoreoMainState) {
+    let Some(index) = state.selected_scene_index else {
+        return;
+    };
+    let choreography = &mut state.choreography_settings_state.choreography;
+    let dancer_size = choreography.settings.dancer_size;
+    let Some((current, rest)) = choreography
+        .scenes
+        .get_mut(index..)
+        .and_then(<[SceneModel]>::split_first_mut)
+    else {
+        return;
+    };
+    let result = match rest.first() {
+        Some(next) => bend_colliding_paths(current, next, dancer_size),
+        None => Err(TransitionPathError::NoNextScene),
+    };
+
+    match result {
+        Ok(outcome) => {
+            state.scene_models = choreography.scenes.clone();
+            refresh_floor_projection(state);
+            state.draw_floor_request_count += 1;
+            if outcome.unresolved_pairs > 0 {
+                state.dialog_content = Some(unresolved_collisions_message(
+                    DEFAULT_LOCALE,
+                    outcome.unresolved_pairs,
+                ));
+                state.is_dialog_open = true;
+            }
+        }
+        Err(error) => {
+            state.dialog_content = Some(transition_path_error_message(DEFAULT_LOCALE, &error));
+            state.is_dialog_open = true;
+        }
+    }
+}
+
+fn branch_scene_variation_internal(state: &mut ChoreoMainState) {
+    let Some(index) = state.selected_scene_index else {
         return;
+    };
+    let scenes = &mut state.choreography_settings_state.choreography.scenes;
+    if index >= scenes.len() {
+        return;
+    }
+    let branch_index = variations::branch_variation(scenes, index);
+    apply_scene_list_edit(state, branch_index);
+}
+
+fn switch_scene_variation_internal(state: &mut ChoreoMainState, variation: usize) {
+    let scenes = &mut state.choreography_settings_state.choreography.scenes;
+    let Some(anchor) = state
+        .selected_scene_index
+        .and_then(|index| variations::variation_anchor(scenes, index))
+    else {
+        show_variation_error(state, &VariationError::NoVariations);
+        return;
+    };
+    if let Err(error) = variations::switch_variation(scenes, anchor, variation) {
+        show_variation_error(state, &error);
+        return;
+    }
+    // Show the variation just switched to, or the anchor when it is the empty one.
+    let range = variations::active_variation_range(scenes, anchor);
+    let shown = if range.is_empty() {
+        anchor
+    } else {
+        range.start
+    };
+    apply_scene_list_edit(state, shown);
+}
+
+fn promote_scene_variation_internal(state: &mut ChoreoMainState) {
+    let scenes = &mut state.choreography_settings_state.choreography.scenes;
+    let Some(index) = state.selected_scene_index else {
+        return;
+    };
+    let result = match variations::variation_anchor(scenes, index) {
+        Some(anchor) => variations::promote_variation(scenes, anchor),
+        None => Err(VariationError::NoVariations),
+    };
+    if let Err(error) = result {
+        show_variation_error(state, &error);
+        return;
+    }
+    state.compared_scene_variation = None;
+    apply_scene_list_edit(state, index);
+}
+
+/// Rebuilds the scene list after an edit reshaped the scenes of the choreography.
+fn apply_scene_list_edit(state: &mut ChoreoMainState, selected_index: usize) {
+    let scenes = &mut state.choreography_settings_state.choreography.scenes;
+    for (scene_index, scene) in scenes.iter_mut().enumerate() {
+        scene.scene_id = synthetic_scene_id(scene_index);
+    }
+    state.scenes = scenes
+        .iter()
+        .map(|scene| super::state::SceneState {
+            name: scene.name.clone(),
+            timestamp_seconds: parse_scene_timestamp(scene.timestamp.as_deref()),
+        })
+        .collect();
+    select_scene_internal(state, selected_index, false);
+    sync_choreography_settings_projection(state);
+    state.draw_floor_request_count += 1;
+}
+
+fn show_variation_error(state: &mut ChoreoMainState, error: &VariationError) {
+    state.dialog_content = Some(variation_error_message(DEFAULT_LOCALE, error));
+    state.is_dialog_open = true;
+}
+
+fn set_required_role_internal(
+    state: &mut ChoreoMainState,
+    position_indices: &[usize],
+    role_index: Option<usize>,
+) {
+    let Some(index) = state.selected_scene_index else {
+        return;
+    };
+    let choreography = &mut state.choreography_settings_state.choreography;
+    let role = role_index.and_then(|role_index| choreography.roles.get(role_index).cloned());
+    let Some(scene) = choreography.scenes.get_mut(index) else {
+        return;
+    };
+    set_required_role(scene, position_indices, role.as_ref());
+
+    state.scene_models = choreography.scenes.clone();
+    refresh_floor_projection(state);
+    state.draw_floor_request_count += 1;
+}
+
+/// Removes the positions selected on the floor from the scene shown on it.
+fn delete_selected_positions_internal(state: &mut ChoreoMainState) {
+    let Some(index) = floor_scene_index(
+        &state.scene_models,
+        state.selected_scene_index,
+        state.audio_position_seconds,
+    ) else {
+        return;
+    };
+    let selected = std::mem::take(&mut state.floor_state.selected_positions);
+    let choreography = &mut state.choreography_settings_state.choreography;
+    let Some(scene) = choreography.scenes.get_mut(index) else {
+        return;
+    };
+    let mut position_index = 0;
+    scene.positions.retain(|_| {
+        let is_selected = selected.contains(&position_index);
+        position_index += 1;
+        !is_selected
+    });
+
+    state.scene_models = choreography.scenes.clone();
+    refresh_floor_projection(state);
+    state.draw_floor_request_count += 1;
+}
+
+fn show_auto_assign_error(state: &mut ChoreoMainState, error: &AutoAssignError) {
+    state.dialog_content = Some(auto_assign_error_message(DEFAULT_LOCALE, error));
+    state.is_dialog_open = true;
+}
+
+fn request_save_choreo(state: &mut ChoreoMainState) {
+    if let Some(file_path) = state
+        .last_opened_choreo_file
+        .as_ref()
+        .filter(|path| !path.trim().is_empty() && std::path::Path::new(path.as_str()).exists())
+    {
+        state
+            .outgoing_save_choreo_requests
+            .push(super::actions::SaveChoreoRequested {
+                file_path: file_path.clone(),
+            });
+    } else {
+        request_save_choreo_as(state);
+    }
+}
+
+/// An empty path asks the runtime to pick the target file before saving.
+fn request_save_choreo_as(state: &mut ChoreoMainState) {
+    state
+        .outgoing_save_choreo_requests
+        .push(super::actions::SaveChoreoRequested {
+            file_path: String::new(),
+        });
+}
+
+/// Carries out the work held back by the prompt once its changes are saved or discarded.
+pub(crate) fn continue_unsaved_changes_prompt(state: &mut ChoreoMainState) {
+    match state.unsaved_changes_prompt.take() {
+        Some(UnsavedChangesPrompt::OpenChoreo(request)) => {
+            open_choreo_file_behavior::request_open_choreo(state, request);
+        }
+        Some(UnsavedChangesPrompt::Close) => {
+            state.is_close_confirmed = true;
+        }
+        None => {}
     }
+}
 
-    let next_index = index.min(state.scenes.len() - 1);
-    select_scene_internal(state, next_index, false);
+/// The loop needs both scene timestamps, with the end scene playing after the start scene.
+#[must_use]
+pub fn rehearsal_loop_bounds(
+    state: &ChoreoMainState,
+    start_scene_index: usize,
+    end_scene_index: usize,
+) -> Option<(f64, f64)> {
+    let start_seconds = state.scenes.get(start_scene_index)?.timestamp_seconds?;
+    let end_seconds = state.scenes.get(end_scene_index)?.timestamp_seconds?;
+    (end_seconds > start_seconds).then_some((start_seconds, end_seconds))
 }
 
 pub(crate) fn sync_audio_position_internal(state: &mut ChoreoMainState, seconds: f64) {
@@ -357,6 +1150,35 @@ pub(crate) fn sync_audio_position_internal(state: &mut ChoreoMainState, seconds:
     refresh_floor_projection(state);
 }
 
+/// Copies a timestamp the audio player changed (a dragged scene marker) into the choreography.
+fn sync_scene_timestamp_from_audio_player(state: &mut ChoreoMainState, scene_id: i32) {
+    let Some(timestamp) = state
+        .audio_player_state
+        .scenes
+        .iter()
+        .find(|scene| scene.scene_id == scene_id)
+        .and_then(|scene| scene.timestamp)
+    else {
+        return;
+    };
+    let Some(index) = state
+        .scene_models
+        .iter()
+        .position(|scene| scene.scene_id.0 == scene_id)
+    else {
+        return;
+    };
+    let Some(scene) = state.scenes.get_mut(index) else {
+        return;
+    };
+    if scene.timestamp_seconds == Some(timestamp) {
+        return;
+    }
+
+    scene.timestamp_seconds = Some(timestamp);
+    sync_choreography_settings_projection(state);
+}
+
 fn sync_choreography_settings_projection(state: &mut ChoreoMainState) {
     let existing_scenes = state
         .choreography_settings_state
@@ -398,6 +1220,7 @@ fn sync_main_state_from_choreography_settings(state: &mut ChoreoMainState) {
         })
         .collect();
 
+    state.selected_scene_indices.clear();
     state.selected_scene_index = state
         .choreography_settings_state
         .selected_scene
@@ -541,6 +1364,7 @@ pub(crate) fn refresh_floor_projection(state: &mut ChoreoMainState) {
     state.floor_state.floor_color = color_to_rgba(&state.choreography_settings_state.floor_color);
     state.floor_state.transparency = state.choreography_settings_state.transparency;
     state.floor_state.dancer_size = choreography.settings.dancer_size.max(1.0);
+    state.floor_state.front_position = choreography.settings.front_position;
     state.floor_state.svg_path = state.svg_file_path.clone();
 
     let (previous_scene, current_scene, next_scene) = adjacent_scenes_for_audio_or_selected(
@@ -568,14 +1392,84 @@ pub(crate) fn refresh_floor_projection(state: &mut ChoreoMainState) {
         .map(|position| FloorPosition {
             x: position.x,
             y: position.y,
+            orientation: position.orientation,
+            movement1: optional_point(position.movement1_x, position.movement1_y),
+            movement2: optional_point(position.movement2_x, position.movement2_y),
         })
         .collect();
     state.floor_state.interpolated_positions =
         build_interpolated_positions(current_scene, next_scene, state.audio_position_seconds);
+    let compared_scene = compared_variation_scene(
+        &state.scene_models,
+        state.selected_scene_index,
+        state.audio_position_seconds,
+        state.compared_scene_variation,
+    );
+    state.floor_state.comparison_scene_name = compared_scene.map(|scene| scene.name.clone());
+    state.floor_state.comparison_source_positions = compared_scene
+        .map(map_scene_render_positions)
+        .unwrap_or_default();
 
     crate::floor::reducer::refresh_render_geometry(&mut state.floor_state);
 }
 
+/// Copies the floor coordinates back into the scene shown on the floor.
+fn write_floor_positions_to_scene(state: &mut ChoreoMainState) {
+    let Some(index) = floor_scene_index(
+        &state.scene_models,
+        state.selected_scene_index,
+        state.audio_position_seconds,
+    ) else {
+        return;
+    };
+    let choreography = &mut state.choreography_settings_state.choreography;
+    let Some(scene) = choreography.scenes.get_mut(index) else {
+        return;
+    };
+    for (position, floor_position) in scene.positions.iter_mut().zip(&state.floor_state.positions) {
+        position.x = floor_position.x;
+        position.y = floor_position.y;
+        position.orientation = floor_position.orientation;
+        (position.movement1_x, position.movement1_y) = split_point(floor_position.movement1);
+        (position.movement2_x, position.movement2_y) = split_point(floor_position.movement2);
+    }
+
+    state.scene_models = choreography.scenes.clone();
+    refresh_floor_projection(state);
+}
+
+fn write_floor_curves_to_previous_scene(state: &mut ChoreoMainState) {
+    let Some(index) = floor_scene_index(
+        &state.scene_models,
+        state.selected_scene_index,
+        state.audio_position_seconds,
+    )
+    .and_then(|index| index.checked_sub(1)) else {
+        return;
+    };
+    let choreography = &mut state.choreography_settings_state.choreography;
+    let Some(scene) = choreography.scenes.get_mut(index) else {
+        return;
+    };
+    for (position, source_position) in scene
+        .positions
+        .iter_mut()
+        .zip(&state.floor_state.previous_source_positions)
+    {
+        position.curve1_x = source_position.curve1_x;
+        position.curve1_y = source_position.curve1_y;
+        position.curve2_x = source_position.curve2_x;
+        position.curve2_y = source_position.curve2_y;
+        position.movement1_x = source_position.movement1_x;
+        position.movement1_y = source_position.movement1_y;
+        position.movement2_x = source_position.movement2_x;
+        position.movement2_y = source_position.movement2_y;
+    }
+
+    state.scene_models = choreography.scenes.clone();
+    refresh_floor_projection(state);
+}
+
 fn adjacent_scenes_for_audio_or_selected(
     scenes: &[SceneModel],
     selected_index: Option<usize>,
@@ -585,6 +1479,36 @@ fn adjacent_scenes_for_audio_or_selected(
     Option<&SceneModel>,
     Option<&SceneModel>,
 ) {
+    let Some(index) = floor_scene_index(scenes, selected_index, audio_position_seconds) else {
+        return (None, None, None);
+    };
+    (
+        index
+            .checked_sub(1)
+            .and_then(|previous_index| scenes.get(previous_index)),
+        scenes.get(index),
+        scenes.get(index + 1),
+    )
+}
+
+/// The scene of the compared variation that corresponds to the scene shown on the floor.
+fn compared_variation_scene(
+    scenes: &[SceneModel],
+    selected_index: Option<usize>,
+    audio_position_seconds: f64,
+    compared_variation: Option<usize>,
+) -> Option<&SceneModel> {
+    let variation = compared_variation?;
+    let index = floor_scene_index(scenes, selected_index, audio_position_seconds)?;
+    let anchor = variations::variation_anchor(scenes, index)?;
+    variations::comparison_scene(scenes, anchor, variation, index)
+}
+
+fn floor_scene_index(
+    scenes: &[SceneModel],
+    selected_index: Option<usize>,
+    audio_position_seconds: f64,
+) -> Option<usize> {
     for (index, window) in scenes.windows(2).enumerate() {
         let Some(current_timestamp) = parse_scene_timestamp(window[0].timestamp.as_deref()) else {
             continue;
@@ -596,26 +1520,11 @@ fn adjacent_scenes_for_audio_or_selected(
             continue;
         }
         if audio_position_seconds >= current_timestamp && audio_position_seconds <= next_timestamp {
-            return (
-                index
-                    .checked_sub(1)
-                    .and_then(|previous_index| scenes.get(previous_index)),
-                scenes.get(index),
-                scenes.get(index + 1),
-            );
+            return Some(index);
         }
     }
 
-    let Some(index) = selected_index.filter(|index| *index < scenes.len()) else {
-        return (None, None, None);
-    };
-    (
-        index
-            .checked_sub(1)
-            .and_then(|previous_index| scenes.get(previous_index)),
-        scenes.get(index),
-        scenes.get(index + 1),
-    )
+    selected_index.filter(|index| *index < scenes.len())
 }
 
 fn map_role_state(role: &Rc<RoleModel>) -> dancers_state::RoleState {
@@ -729,12 +1638,16 @@ fn map_scene_render_positions(scene: &SceneModel) -> Vec<SceneRenderPosition> {
                 )
             } else {
                 let fill_color = [224, 224, 224, 255];
+                let border_color = position
+                    .required_role
+                    .as_ref()
+                    .map_or([120, 120, 120, 255], |role| color_to_rgba(&role.color));
                 (
                     None,
                     String::new(),
                     String::new(),
                     fill_color,
-                    [120, 120, 120, 255],
+                    border_color,
                     [0, 0, 0, 255],
                     false,
                 )
@@ -750,6 +1663,11 @@ fn map_scene_render_positions(scene: &SceneModel) -> Vec<SceneRenderPosition> {
                 curve1_y: position.curve1_y,
                 curve2_x: position.curve2_x,
                 curve2_y: position.curve2_y,
+                movement1_x: position.movement1_x,
+                movement1_y: position.movement1_y,
+                movement2_x: position.movement2_x,
+                movement2_y: position.movement2_y,
+                orientation: position.orientation,
                 fill_color,
                 border_color,
                 text_color,
@@ -794,16 +1712,32 @@ fn build_interpolated_positions(
                 return FloorPosition {
                     x: position.x,
                     y: position.y,
+                    orientation: position.orientation,
+                    movement1: None,
+                    movement2: None,
                 };
             };
             let Some(next_position) = get_position_by_dancer_key(&next_positions, dancer) else {
                 return FloorPosition {
                     x: position.x,
                     y: position.y,
+                    orientation: position.orientation,
+                    movement1: None,
+                    movement2: None,
                 };
             };
             let (x, y) = interpolate_position(position, next_position, progress);
-            FloorPosition { x, y }
+            FloorPosition {
+                x,
+                y,
+                orientation: interpolate_orientation(
+                    position.orientation,
+                    next_position.orientation,
+                    progress,
+                ),
+                movement1: None,
+                movement2: None,
+            }
         })
         .collect()
 }
@@ -847,6 +1781,20 @@ fn interpolate_position(
     to_position: &PositionModel,
     progress: f64,
 ) -> (f64, f64) {
+    let waypoints = movement_path::waypoints(
+        optional_point(from_position.movement1_x, from_position.movement1_y),
+        optional_point(from_position.movement2_x, from_position.movement2_y),
+    );
+    if !waypoints.is_empty() {
+        let point = movement_path::point_along_path(
+            Point::new(from_position.x, from_position.y),
+            &waypoints,
+            Point::new(to_position.x, to_position.y),
+            progress,
+        );
+        return (point.x, point.y);
+    }
+
     let Some(curve1_x) = from_position.curve1_x else {
         return (
             lerp(from_position.x, to_position.x, progress),
@@ -873,6 +1821,14 @@ fn interpolate_position(
     )
 }
 
+fn optional_point(x: Option<f64>, y: Option<f64>) -> Option<Point> {
+    Some(Point::new(x?, y?))
+}
+
+fn split_point(point: Option<Point>) -> (Option<f64>, Option<f64>) {
+    point.map_or((None, None), |point| (Some(point.x), Some(point.y)))
+}
+
 fn lerp(start: f64, end: f64, progress: f64) -> f64 {
     start + (end - start) * progress
 }
@@ -981,6 +1937,16 @@ fn make_selected_scene_state(
     })
 }
 
+/// Numbers the scenes of a loaded choreography and writes their timestamps the way the scene
+/// list projects them back, so that projecting an unchanged scene list leaves it equal.
+pub(crate) fn normalize_loaded_scenes(scenes: &mut [SceneModel]) {
+    for (index, scene) in scenes.iter_mut().enumerate() {
+        scene.scene_id = synthetic_scene_id(index);
+        scene.timestamp =
+            parse_scene_timestamp(scene.timestamp.as_deref()).map(format_scene_timestamp);
+    }
+}
+
 fn synthetic_scene_id(index: usize) -> SceneId {
     SceneId(index as i32 + 1)
 }
@@ -1003,12 +1969,6 @@ fn format_scene_timestamp(value: f64) -> String {
     text
 }
 
-fn round_to_100_millis(seconds: f64) -> f64 {
-    let milliseconds = seconds * 1000.0;
-    let rounded = (milliseconds / 100.0).round() * 100.0;
-    rounded / 1000.0
-}
-
 fn build_new_scene_name(scenes: &[super::state::SceneState]) -> String {
     const BASE_NAME: &str = "New Scene";
     if scenes.iter().all(|scene| scene.name != BASE_NAME) {
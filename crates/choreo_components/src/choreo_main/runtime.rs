@@ -1,35 +1,47 @@
 use std::path::Path;
 
+use choreo_master_mobile_json::ChoreoJsonError;
 use choreo_master_mobile_json::export_to_file;
 use choreo_master_mobile_json::import;
 use choreo_models::ChoreographyModel;
 use choreo_models::ChoreographyModelMapper;
 
 use crate::audio_player::actions::AudioPlayerAction;
+use crate::audio_player::audio_format::probe_audio_file;
 use crate::audio_player::runtime::AudioPlayerRuntime;
 use crate::audio_player::runtime::apply_player_sample;
 use crate::audio_player::runtime::apply_player_sample_without_position;
 use crate::audio_player::state::AudioPlayerChoreographyScene;
 use crate::audio_player::state::AudioPlayerScene;
+use crate::audio_player::translations::open_audio_error_message;
 use crate::audio_player::types::AudioPlayerSample;
 use crate::choreography_settings::actions::ChoreographySettingsAction;
 use crate::choreography_settings::state::SelectedSceneState;
+use crate::floor::actions::FloorAction;
 use crate::scenes::state::parse_timestamp_seconds;
+use crate::scenes::translations::save_choreo_error_message;
 use crate::settings::actions::SettingsAction;
 
 use super::actions::ChoreoMainAction;
 use super::actions::OpenAudioRequested;
 use super::actions::OpenChoreoRequested;
 use super::actions::OpenSvgFileCommand;
+use super::actions::SaveChoreoRequested;
 use super::behaviors::ChoreoMainBehaviors;
 use super::main_page_binding::MainPageActionHandlers;
 use super::open_audio_behavior;
+use super::reducer::continue_unsaved_changes_prompt;
 use super::reducer::interaction_mode_from_index;
 use super::reducer::map_global_interaction_mode;
+use super::reducer::normalize_loaded_scenes;
 use super::reducer::reduce;
 use super::reducer::reduce_with_behaviors;
 use super::reducer::sync_audio_position_internal;
 use super::state::ChoreoMainState;
+use super::state::SavedChoreography;
+
+const DEFAULT_LOCALE: &str = "en";
+const DEFAULT_SAVE_FILE_NAME: &str = "choreography.choreo";
 
 pub(crate) fn consume_outgoing_commands(
     state: &mut ChoreoMainState,
@@ -38,10 +50,15 @@ pub(crate) fn consume_outgoing_commands(
     audio_runtime: &mut AudioPlayerRuntime,
 ) {
     let audio_requests = state.outgoing_audio_requests.clone();
-    let choreo_requests = state.outgoing_open_choreo_requests.clone();
     let save_requests = state.outgoing_save_choreo_requests.clone();
     let open_svg_commands = state.outgoing_open_svg_commands.clone();
 
+    for request in save_requests {
+        route_save_choreo_request(state, request, handlers, behaviors);
+    }
+
+    // Saving first lets a save that settles an unsaved-changes prompt queue the open it held back.
+    let choreo_requests = state.outgoing_open_choreo_requests.clone();
     for request in choreo_requests {
         route_open_choreo_request(state, request, handlers, behaviors, audio_runtime);
     }
@@ -50,10 +67,6 @@ pub(crate) fn consume_outgoing_commands(
         route_open_audio_request(state, audio_runtime, request, handlers, behaviors, true);
     }
 
-    for request in save_requests {
-        apply_save_choreo_request(state, request.file_path.as_str());
-    }
-
     for command in open_svg_commands {
         route_open_svg_command(command, state, handlers, behaviors);
     }
@@ -85,16 +98,23 @@ fn route_open_choreo_request(
         request_open_choreo(resolved_request.clone());
     }
 
-    if apply_open_choreo_request(
+    if !apply_open_choreo_request(
         state,
         resolved_request.clone(),
         handlers,
         behaviors,
         audio_runtime,
-    ) && let Some(behavior) = behaviors.open_choreo_file.as_ref()
-    {
+    ) {
+        return;
+    }
+
+    if let Some(behavior) = behaviors.open_choreo_file.as_ref() {
         behavior.sync_last_opened_choreo_preference(&resolved_request);
     }
+    mark_choreography_saved(state);
+    if let Some(behavior) = behaviors.autosave.as_ref() {
+        behavior.sync_opened_choreography(state, crate::time::SystemClock::now_utc());
+    }
 }
 
 fn apply_open_choreo_request(
@@ -109,7 +129,8 @@ fn apply_open_choreo_request(
     };
 
     let mapper = ChoreographyModelMapper;
-    let choreography = mapper.map_to_model(&json_model);
+    let mut choreography = mapper.map_to_model(&json_model);
+    normalize_loaded_scenes(&mut choreography.scenes);
     let selected_scene = choreography.scenes.first().map(map_selected_scene_state);
     let audio_request =
         resolve_audio_request(&choreography, request.file_path.as_deref()).map(|file_path| {
@@ -209,6 +230,37 @@ pub(crate) fn apply_action_behaviors(
             if let Some(behavior) = behaviors.open_svg_file.as_ref() {
                 behavior.initialize(state);
             }
+            if let Some(behavior) = behaviors.autosave.as_ref() {
+                behavior.initialize(state);
+            }
+            if let Some(behavior) = behaviors.formation_templates.as_ref() {
+                behavior.initialize(state);
+            }
+            if let Some(behavior) = behaviors.keyboard_shortcuts.as_ref() {
+                behavior.initialize(state);
+            }
+        }
+        ChoreoMainAction::SaveFormationTemplate => {
+            if let Some(behavior) = behaviors.formation_templates.as_ref() {
+                behavior.remember_templates(state);
+            }
+        }
+        ChoreoMainAction::SettingsAction(
+            SettingsAction::RemapShortcut { .. } | SettingsAction::ResetShortcuts,
+        ) => {
+            if let Some(behavior) = behaviors.keyboard_shortcuts.as_ref() {
+                behavior.remember_shortcuts(state);
+            }
+        }
+        ChoreoMainAction::RestoreRecovery => {
+            if let Some(behavior) = behaviors.autosave.as_ref() {
+                behavior.restore(state);
+            }
+        }
+        ChoreoMainAction::DiscardRecovery | ChoreoMainAction::DiscardUnsavedChanges => {
+            if let Some(behavior) = behaviors.autosave.as_ref() {
+                behavior.discard();
+            }
         }
         ChoreoMainAction::SelectMode { index } => {
             if let Some(mode) = interaction_mode_from_index(*index)
@@ -227,6 +279,18 @@ pub(crate) fn apply_action_behaviors(
                 behavior.apply(map_global_interaction_mode(*mode));
             }
         }
+        ChoreoMainAction::FloorAction(FloorAction::MoveCurveControl { .. }) => {
+            if let Some(behavior) = behaviors.apply_interaction_mode.as_ref() {
+                behavior.start_bend_drag();
+            }
+        }
+        ChoreoMainAction::FloorAction(
+            FloorAction::PointerReleased { .. } | FloorAction::PointerReleasedWithContext { .. },
+        ) => {
+            if let Some(behavior) = behaviors.apply_interaction_mode.as_ref() {
+                behavior.complete_bend_drag();
+            }
+        }
         _ => {}
     }
 }
@@ -286,8 +350,22 @@ fn apply_open_audio_request(
 
     let file_exists = Path::new(file_path).is_file();
     audio_runtime.set_backend(state.settings_state.audio_player_backend);
+    if file_exists && let Err(error) = probe_audio_file(Path::new(file_path)) {
+        audio_runtime.close();
+        state.dialog_content = Some(open_audio_error_message(DEFAULT_LOCALE, file_path, &error));
+        state.is_dialog_open = true;
+        reduce(
+            state,
+            ChoreoMainAction::AudioPlayerAction(AudioPlayerAction::OpenAudioFileFailed {
+                file_path: file_path.to_string(),
+                error,
+            }),
+        );
+        return;
+    }
     if file_exists {
         audio_runtime.open_file(file_path.to_string());
+        audio_runtime.request_audio_analysis(file_path);
     } else {
         audio_runtime.close();
     }
@@ -323,10 +401,10 @@ pub(crate) fn apply_audio_action_side_effects(
         ChoreoMainAction::AudioPlayerAction(AudioPlayerAction::SeekToPosition { position }) => {
             audio_runtime.seek(*position);
         }
-        ChoreoMainAction::AudioPlayerAction(AudioPlayerAction::PositionDragStarted) => {
-            if state.audio_player_state.was_playing_before_drag {
-                audio_runtime.pause();
-            }
+        ChoreoMainAction::AudioPlayerAction(AudioPlayerAction::PositionDragStarted)
+            if state.audio_player_state.was_playing_before_drag =>
+        {
+            audio_runtime.pause();
         }
         ChoreoMainAction::AudioPlayerAction(AudioPlayerAction::PositionDragCompleted {
             position,
@@ -340,6 +418,24 @@ pub(crate) fn apply_audio_action_side_effects(
         ChoreoMainAction::AudioPlayerAction(AudioPlayerAction::SpeedChanged { speed }) => {
             audio_runtime.set_speed(*speed);
         }
+        ChoreoMainAction::SettingsAction(
+            SettingsAction::LoadFromPreferences { .. }
+            | SettingsAction::Reload
+            | SettingsAction::UpdateMinimumPlaybackSpeed { .. }
+            | SettingsAction::UpdateMaximumPlaybackSpeed { .. },
+        ) => {
+            // A narrower range may have pulled the speed in.
+            audio_runtime.set_speed(state.audio_player_state.speed);
+        }
+        ChoreoMainAction::StartRehearsalLoop { .. } => {
+            if let Some(rehearsal) = state.audio_player_state.rehearsal_loop {
+                audio_runtime.pause();
+                audio_runtime.seek(rehearsal.start_seconds);
+            }
+        }
+        ChoreoMainAction::AudioPlayerAction(AudioPlayerAction::StopRehearsalLoop) => {
+            audio_runtime.pause();
+        }
         _ => {}
     }
 }
@@ -398,8 +494,9 @@ pub(crate) fn poll_audio_runtime(
     state: &mut ChoreoMainState,
     audio_runtime: &mut AudioPlayerRuntime,
 ) -> bool {
+    let is_analyzing_audio = poll_audio_analysis(state, audio_runtime);
     let Some(sample) = audio_runtime.sample() else {
-        return false;
+        return is_analyzing_audio;
     };
 
     apply_player_sample_without_position(&mut state.audio_player_state, sample);
@@ -416,6 +513,64 @@ pub(crate) fn poll_audio_runtime(
         &mut state.audio_player_state,
         AudioPlayerAction::PublishPositionIfChanged,
     );
+    apply_audio_player_effects(state, audio_runtime, effects);
+
+    is_analyzing_audio
+        || state.audio_player_state.has_player
+            && (state.audio_player_state.is_playing
+                || state.audio_player_state.pending_seek_position.is_some())
+}
+
+/// Applies a finished audio analysis; returns whether one is still running.
+fn poll_audio_analysis(
+    state: &mut ChoreoMainState,
+    audio_runtime: &mut AudioPlayerRuntime,
+) -> bool {
+    if let Some((file_path, analysis)) = audio_runtime.poll_audio_analysis() {
+        let effects = crate::audio_player::reducer::reduce(
+            &mut state.audio_player_state,
+            AudioPlayerAction::BeatGridDetected {
+                file_path: file_path.clone(),
+                beat_grid: analysis.beat_grid,
+            },
+        );
+        debug_assert!(effects.is_empty());
+        let effects = crate::audio_player::reducer::reduce(
+            &mut state.audio_player_state,
+            AudioPlayerAction::WaveformComputed {
+                file_path,
+                waveform: analysis.waveform,
+            },
+        );
+        debug_assert!(effects.is_empty());
+    }
+    audio_runtime.is_analyzing_audio()
+}
+
+/// Advances the rehearsal loop by the wall-clock time since the last tick; returns whether a
+/// loop is still running and needs further ticks.
+pub(crate) fn tick_rehearsal_loop(
+    state: &mut ChoreoMainState,
+    audio_runtime: &mut AudioPlayerRuntime,
+    elapsed_seconds: f64,
+) -> bool {
+    if state.audio_player_state.rehearsal_loop.is_none() {
+        return false;
+    }
+
+    let effects = crate::audio_player::reducer::reduce(
+        &mut state.audio_player_state,
+        AudioPlayerAction::RehearsalTick { elapsed_seconds },
+    );
+    apply_audio_player_effects(state, audio_runtime, effects);
+    state.audio_player_state.rehearsal_loop.is_some()
+}
+
+fn apply_audio_player_effects(
+    state: &mut ChoreoMainState,
+    audio_runtime: &mut AudioPlayerRuntime,
+    effects: Vec<crate::audio_player::reducer::AudioPlayerEffect>,
+) {
     for effect in effects {
         match effect {
             crate::audio_player::reducer::AudioPlayerEffect::PositionChangedPublished {
@@ -423,12 +578,20 @@ pub(crate) fn poll_audio_runtime(
             } => {
                 sync_audio_position_internal(state, position_seconds);
             }
+            crate::audio_player::reducer::AudioPlayerEffect::TransportRequested {
+                position_seconds,
+                play,
+            } => {
+                if play {
+                    audio_runtime.seek_and_play(position_seconds);
+                } else {
+                    audio_runtime.pause();
+                    audio_runtime.seek(position_seconds);
+                }
+                sync_audio_position_internal(state, position_seconds);
+            }
         }
     }
-
-    state.audio_player_state.has_player
-        && (state.audio_player_state.is_playing
-            || state.audio_player_state.pending_seek_position.is_some())
 }
 
 fn map_selected_scene_state(scene: &choreo_models::SceneModel) -> SelectedSceneState {
@@ -490,28 +653,90 @@ fn resolve_audio_request(
     None
 }
 
-fn apply_save_choreo_request(state: &mut ChoreoMainState, file_path: &str) {
-    if file_path.trim().is_empty() {
+fn route_save_choreo_request(
+    state: &mut ChoreoMainState,
+    request: SaveChoreoRequested,
+    handlers: &MainPageActionHandlers,
+    behaviors: &ChoreoMainBehaviors,
+) {
+    let file_path = if request.file_path.trim().is_empty() {
+        let suggested_file_name = suggested_choreo_file_name(state);
+        handlers
+            .pick_save_choreo_path
+            .as_ref()
+            .and_then(|pick_save_choreo_path| pick_save_choreo_path(&suggested_file_name))
+    } else {
+        Some(request.file_path)
+    };
+
+    let Some(file_path) = file_path.filter(|path| !path.trim().is_empty()) else {
         return;
-    }
+    };
 
-    let path = Path::new(file_path);
-    if !path.exists() {
+    if let Err(error) = apply_save_choreo_request(state, file_path.as_str()) {
+        state.dialog_content = Some(save_choreo_error_message(
+            DEFAULT_LOCALE,
+            file_path.as_str(),
+            &error,
+        ));
+        state.is_dialog_open = true;
         return;
     }
 
+    mark_choreography_saved(state);
+    if let Some(behavior) = behaviors.autosave.as_ref() {
+        behavior.sync_saved_choreography(crate::time::SystemClock::now_utc());
+    }
+
+    if state.last_opened_choreo_file.as_deref() != Some(file_path.as_str()) {
+        if let Some(behavior) = behaviors.open_choreo_file.as_ref() {
+            behavior.remember_last_opened_choreo_file(file_path.as_str());
+        }
+        state.last_opened_choreo_file = Some(file_path);
+    }
+
+    continue_unsaved_changes_prompt(state);
+}
+
+fn mark_choreography_saved(state: &mut ChoreoMainState) {
+    state.saved_choreography = SavedChoreography::Persisted(Box::new(
+        state.choreography_settings_state.choreography.clone(),
+    ));
+}
+
+fn apply_save_choreo_request(
+    state: &mut ChoreoMainState,
+    file_path: &str,
+) -> Result<(), ChoreoJsonError> {
     let mut choreography = state.choreography_settings_state.choreography.clone();
     choreography.last_save_date = crate::time::SystemClock::now_utc();
     let mapper = ChoreographyModelMapper;
     let json_model = mapper.map_to_json(&choreography);
-    if export_to_file(path, &json_model).is_err() {
-        return;
-    }
+    export_to_file(Path::new(file_path), &json_model)?;
 
     state
         .choreography_settings_state
         .choreography
         .last_save_date = choreography.last_save_date;
+    Ok(())
+}
+
+fn suggested_choreo_file_name(state: &ChoreoMainState) -> String {
+    let opened_file_name = state
+        .last_opened_choreo_file
+        .as_deref()
+        .and_then(|path| Path::new(path).file_name())
+        .map(|name| name.to_string_lossy().into_owned());
+    if let Some(file_name) = opened_file_name {
+        return file_name;
+    }
+
+    let name = state.choreography_settings_state.choreography.name.trim();
+    if name.is_empty() {
+        DEFAULT_SAVE_FILE_NAME.to_string()
+    } else {
+        format!("{name}.choreo")
+    }
 }
 
 pub(crate) fn enqueue_open_audio_request(state: &mut ChoreoMainState, request: OpenAudioRequested) {
@@ -567,6 +792,29 @@ mod tests {
         let _ = fs::remove_file(path);
     }
 
+    #[test]
+    fn opening_an_undecodable_audio_file_reports_the_format_instead_of_a_silent_player() {
+        let mut state = ChoreoMainState::default();
+        let mut runtime = AudioPlayerRuntime::new(AudioPlayerBackend::Rodio);
+        let path = unique_temp_file("flac");
+        fs::write(&path, b"not really audio").expect("test file should be written");
+        let file_path = path.to_string_lossy().into_owned();
+
+        apply_open_audio_request(&mut state, &mut runtime, file_path.as_str());
+
+        assert!(!runtime.has_player());
+        assert!(!state.audio_player_state.has_player);
+        assert!(state.is_dialog_open);
+        assert!(
+            state
+                .dialog_content
+                .as_deref()
+                .is_some_and(|content| content.contains("audio format is not supported"))
+        );
+
+        let _ = fs::remove_file(path);
+    }
+
     fn unique_temp_file(extension: &str) -> PathBuf {
         let nanos = SystemTime::now()
             .duration_since(UNIX_EPOCH)
@@ -2,12 +2,18 @@ use super::actions::OpenAudioRequested;
 use super::actions::OpenChoreoRequested;
 use super::actions::OpenSvgFileCommand;
 use super::actions::SaveChoreoRequested;
+use super::history::EditHistory;
 use crate::audio_player::state::AudioPlayerState;
 use crate::choreography_settings::state::ChoreographySettingsState;
 use crate::dancers::state::DancersState;
 use crate::floor::state::FloorState;
+use crate::scenes::formations::FormationChoice;
+use crate::scenes::formations::FormationParameters;
+use crate::scenes::formations::FormationTemplate;
 use crate::settings::state::SettingsState;
+use choreo_models::ChoreographyModel;
 use choreo_models::SceneModel;
+use time::OffsetDateTime;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum MainContent {
@@ -26,6 +32,9 @@ pub enum InteractionMode {
     RotateAroundDancer,
     Scale,
     LineOfSight,
+    Face,
+    EditPath,
+    BendPath,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -39,6 +48,8 @@ pub enum InteractionStateMachineState {
     ScaleAroundDancerSelection,
     ScalePositions,
     ScalePositionsSelection,
+    BendPath,
+    BendPathSelection,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -47,6 +58,32 @@ pub struct SceneState {
     pub timestamp_seconds: Option<f64>,
 }
 
+/// An autosaved choreography found on start that is newer than its last save.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecoveryOffer {
+    pub choreography_name: String,
+    pub file_path: Option<String>,
+    pub saved_at: OffsetDateTime,
+}
+
+/// What the open choreography is compared against to tell whether it has unsaved changes.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum SavedChoreography {
+    /// Nothing has been opened yet, so the blank choreography is unsaved once it has been edited.
+    #[default]
+    None,
+    /// Restored from an autosave, so unsaved until the next save.
+    Recovered,
+    Persisted(Box<ChoreographyModel>),
+}
+
+/// Work held back until the user decides what happens to unsaved changes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UnsavedChangesPrompt {
+    OpenChoreo(OpenChoreoRequested),
+    Close,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct ChoreoMainState {
     pub content: MainContent,
@@ -55,6 +92,9 @@ pub struct ChoreoMainState {
     pub is_nav_open: bool,
     pub is_choreography_settings_open: bool,
     pub is_audio_player_open: bool,
+    pub is_rehearsal_panel_open: bool,
+    pub rehearsal_start_scene_index: Option<usize>,
+    pub rehearsal_end_scene_index: Option<usize>,
     pub is_dialog_open: bool,
     pub dialog_content: Option<String>,
     pub interaction_mode: InteractionMode,
@@ -71,7 +111,16 @@ pub struct ChoreoMainState {
     pub scenes: Vec<SceneState>,
     pub scene_models: Vec<SceneModel>,
     pub selected_scene_index: Option<usize>,
+    /// Scenes picked together with the selected scene, in list order; empty when only that one
+    /// is picked.
+    pub selected_scene_indices: Vec<usize>,
+    /// Variation of the shown scene's anchor that the floor outlines next to the active one.
+    pub compared_scene_variation: Option<usize>,
     pub scene_search_text: String,
+    pub formation_choice: FormationChoice,
+    pub formation_parameters: FormationParameters,
+    /// Formations saved from scenes, kept in the preferences across choreographies.
+    pub formation_templates: Vec<FormationTemplate>,
     pub audio_position_seconds: f64,
     pub floor_scene_name: Option<String>,
     pub floor_state: FloorState,
@@ -79,6 +128,11 @@ pub struct ChoreoMainState {
     pub choreography_settings_state: ChoreographySettingsState,
     pub settings_state: SettingsState,
     pub dancers_state: DancersState,
+    pub history: EditHistory,
+    pub recovery_offer: Option<RecoveryOffer>,
+    pub saved_choreography: SavedChoreography,
+    pub unsaved_changes_prompt: Option<UnsavedChangesPrompt>,
+    pub is_close_confirmed: bool,
 }
 
 impl Default for ChoreoMainState {
@@ -90,6 +144,9 @@ impl Default for ChoreoMainState {
             is_nav_open: false,
             is_choreography_settings_open: false,
             is_audio_player_open: false,
+            is_rehearsal_panel_open: false,
+            rehearsal_start_scene_index: None,
+            rehearsal_end_scene_index: None,
             is_dialog_open: false,
             dialog_content: None,
             interaction_mode: InteractionMode::View,
@@ -106,7 +163,12 @@ impl Default for ChoreoMainState {
             scenes: Vec::new(),
             scene_models: Vec::new(),
             selected_scene_index: None,
+            selected_scene_indices: Vec::new(),
+            compared_scene_variation: None,
             scene_search_text: String::new(),
+            formation_choice: FormationChoice::default(),
+            formation_parameters: FormationParameters::default(),
+            formation_templates: Vec::new(),
             audio_position_seconds: 0.0,
             floor_scene_name: None,
             floor_state: FloorState::default(),
@@ -114,6 +176,24 @@ impl Default for ChoreoMainState {
             choreography_settings_state: ChoreographySettingsState::default(),
             settings_state: SettingsState::default(),
             dancers_state: DancersState::default(),
+            history: EditHistory::default(),
+            recovery_offer: None,
+            saved_choreography: SavedChoreography::None,
+            unsaved_changes_prompt: None,
+            is_close_confirmed: false,
+        }
+    }
+}
+
+impl ChoreoMainState {
+    #[must_use]
+    pub fn has_unsaved_changes(&self) -> bool {
+        match &self.saved_choreography {
+            SavedChoreography::None => self.history.can_undo(),
+            SavedChoreography::Recovered => true,
+            SavedChoreography::Persisted(saved) => {
+                **saved != self.choreography_settings_state.choreography
+            }
         }
     }
 }
@@ -0,0 +1,128 @@
+use std::path::Path;
+
+use time::UtcOffset;
+
+use super::state::ChoreoMainState;
+use super::state::RecoveryOffer;
+use crate::audio_player::state::RehearsalLoop;
+use crate::audio_player::state::RehearsalPhase;
+use crate::i18n::t;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecoveryDialogTranslations {
+    pub title: String,
+    pub restore: String,
+    pub discard: String,
+}
+
+#[must_use]
+pub fn recovery_dialog_translations(locale: &str) -> RecoveryDialogTranslations {
+    RecoveryDialogTranslations {
+        title: t(locale, "RecoveryDialogTitle"),
+        restore: t(locale, "RecoveryDialogRestore"),
+        discard: t(locale, "RecoveryDialogDiscard"),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnsavedChangesDialogTranslations {
+    pub title: String,
+    pub save: String,
+    pub discard: String,
+    pub cancel: String,
+}
+
+#[must_use]
+pub fn unsaved_changes_dialog_translations(locale: &str) -> UnsavedChangesDialogTranslations {
+    UnsavedChangesDialogTranslations {
+        title: t(locale, "UnsavedChangesDialogTitle"),
+        save: t(locale, "UnsavedChangesDialogSave"),
+        discard: t(locale, "UnsavedChangesDialogDiscard"),
+        cancel: t(locale, "UnsavedChangesDialogCancel"),
+    }
+}
+
+/// Names a choreography by its own name, else by its file name.
+#[must_use]
+pub fn choreography_display_name(
+    locale: &str,
+    choreography_name: &str,
+    file_path: Option<&str>,
+) -> String {
+    Some(choreography_name.trim())
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+        .or_else(|| {
+            file_path
+                .and_then(|path| Path::new(path).file_name())
+                .map(|name| name.to_string_lossy().into_owned())
+        })
+        .unwrap_or_else(|| t(locale, "UntitledChoreography"))
+}
+
+#[must_use]
+pub fn unsaved_changes_dialog_message(locale: &str, state: &ChoreoMainState) -> String {
+    let name = choreography_display_name(
+        locale,
+        &state.choreography_settings_state.name,
+        state.last_opened_choreo_file.as_deref(),
+    );
+    t(locale, "UnsavedChangesDialogMessage").replace("{0}", &name)
+}
+
+#[must_use]
+pub fn recovery_dialog_message(locale: &str, offer: &RecoveryOffer) -> String {
+    let name =
+        choreography_display_name(locale, &offer.choreography_name, offer.file_path.as_deref());
+    let saved_at = offer.saved_at.to_offset(UtcOffset::UTC);
+    let saved_at = format!(
+        "{:04}-{:02}-{:02} {:02}:{:02} UTC",
+        saved_at.year(),
+        u8::from(saved_at.month()),
+        saved_at.day(),
+        saved_at.hour(),
+        saved_at.minute()
+    );
+
+    t(locale, "RecoveryDialogMessage")
+        .replace("{0}", &name)
+        .replace("{1}", &saved_at)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RehearsalLoopTranslations {
+    pub title: String,
+    pub from_scene: String,
+    pub to_scene: String,
+    pub count_in_seconds: String,
+    pub pause_seconds: String,
+    pub start_loop: String,
+    pub stop_loop: String,
+    pub missing_timestamps: String,
+}
+
+#[must_use]
+pub fn rehearsal_loop_translations(locale: &str) -> RehearsalLoopTranslations {
+    RehearsalLoopTranslations {
+        title: t(locale, "RehearsalLoopTitle"),
+        from_scene: t(locale, "RehearsalFromScene"),
+        to_scene: t(locale, "RehearsalToScene"),
+        count_in_seconds: t(locale, "RehearsalCountInSeconds"),
+        pause_seconds: t(locale, "RehearsalPauseSeconds"),
+        start_loop: t(locale, "RehearsalStartLoop"),
+        stop_loop: t(locale, "RehearsalStopLoop"),
+        missing_timestamps: t(locale, "RehearsalMissingTimestamps"),
+    }
+}
+
+/// Counts down the count-in and pause in whole seconds; playback needs no status.
+#[must_use]
+pub fn rehearsal_status_text(locale: &str, rehearsal: &RehearsalLoop) -> Option<String> {
+    let (key, remaining_seconds) = match rehearsal.phase {
+        RehearsalPhase::CountIn { remaining_seconds } => ("RehearsalCountIn", remaining_seconds),
+        RehearsalPhase::Pause { remaining_seconds } => ("RehearsalPausing", remaining_seconds),
+        RehearsalPhase::Playing { .. } => return None,
+    };
+    let remaining = remaining_seconds.max(0.0).ceil() as u32;
+    Some(t(locale, key).replace("{0}", &remaining.to_string()))
+}
@@ -1,17 +1,43 @@
+use egui::Event;
+use egui::Key;
+use egui::KeyboardShortcut;
+use egui::Modifiers;
+use egui::RichText;
 use egui::Ui;
+use egui_material3::MaterialButton;
 
+use crate::audio_player::actions::AudioPlayerAction;
 use crate::dancers;
+use crate::floor::actions::FloorAction;
+use crate::floor::state::Alignment;
+use crate::floor::state::Distribution;
+use crate::floor::state::MirrorAxis;
 use crate::main_page;
+use crate::main_page::ui::map_scene_pane_action;
 use crate::material::components::dialog_host::DialogHostProps;
 use crate::material::components::dialog_host::dialog_metrics_tokens;
-use crate::material::components::dialog_host::draw_dialog_host;
+use crate::material::components::dialog_host::draw_dialog_host_with_panel;
 use crate::material::styling::material_palette::material_palette_for_visuals;
 use crate::material::styling::material_style_metrics::material_style_metrics;
+use crate::scenes::actions::ScenesAction;
 use crate::settings;
+use crate::settings::keyboard_shortcuts::LARGE_NUDGE_GRID_STEPS;
+use crate::settings::keyboard_shortcuts::ShortcutCommand;
 
 use super::actions::ChoreoMainAction;
 use super::state::ChoreoMainState;
 use super::state::MainContent;
+use super::state::RecoveryOffer;
+use super::translations::recovery_dialog_message;
+use super::translations::recovery_dialog_translations;
+use super::translations::unsaved_changes_dialog_message;
+use super::translations::unsaved_changes_dialog_translations;
+
+const DEFAULT_LOCALE: &str = "en";
+
+const UNDO_SHORTCUT: KeyboardShortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::Z);
+const REDO_SHORTCUT: KeyboardShortcut =
+    KeyboardShortcut::new(Modifiers::COMMAND.plus(Modifiers::SHIFT), Key::Z);
 
 #[must_use]
 pub const fn content_spacing_token() -> f32 {
@@ -23,12 +49,24 @@ pub fn draw(ui: &mut Ui, state: &ChoreoMainState) -> Vec<ChoreoMainAction> {
     let dialog_metrics = dialog_metrics_tokens();
     let palette = material_palette_for_visuals(ui.visuals());
 
-    let close_requested = draw_dialog_host(
+    let dialog_panel = dialog_panel(state);
+    if dialog_panel.is_none()
+        && state.settings_state.capturing_shortcut.is_none()
+        && let Some(action) = history_shortcut_action(ui.ctx())
+    {
+        actions.push(action);
+    }
+    if dialog_panel.is_none() && state.content == MainContent::Main {
+        actions.extend(floor_shortcut_actions(ui.ctx(), state));
+    }
+
+    let mut dialog_actions = Vec::new();
+    let close_requested = draw_dialog_host_with_panel(
         ui,
         &DialogHostProps {
             id_source: "choreo_main_dialog_host",
-            is_open: state.is_dialog_open,
-            close_on_click_away: true,
+            is_open: dialog_panel.is_some(),
+            close_on_click_away: matches!(dialog_panel, Some(DialogPanel::Message)),
             overlay_color: palette.overlay.background_modal,
             dialog_background: palette.surface_container_low,
             dialog_text_color: palette.on_surface,
@@ -49,7 +87,22 @@ pub fn draw(ui: &mut Ui, state: &ChoreoMainState) -> Vec<ChoreoMainAction> {
                 }
             }
         },
+        |ui| match dialog_panel {
+            Some(DialogPanel::Recovery(offer)) => {
+                draw_recovery_prompt(ui, offer, &mut dialog_actions);
+            }
+            Some(DialogPanel::UnsavedChanges) => {
+                draw_unsaved_changes_prompt(ui, state, &mut dialog_actions);
+            }
+            Some(DialogPanel::Message) | None => {
+                ui.label(
+                    RichText::new(state.dialog_content.as_deref().unwrap_or_default())
+                        .color(palette.on_surface),
+                );
+            }
+        },
     );
+    actions.extend(dialog_actions);
 
     if close_requested {
         actions.push(ChoreoMainAction::HideDialog);
@@ -58,6 +111,222 @@ pub fn draw(ui: &mut Ui, state: &ChoreoMainState) -> Vec<ChoreoMainAction> {
     actions
 }
 
+enum DialogPanel<'a> {
+    Message,
+    Recovery(&'a RecoveryOffer),
+    UnsavedChanges,
+}
+
+/// Messages come first so a failed save stays readable above the prompt that asked for it.
+fn dialog_panel(state: &ChoreoMainState) -> Option<DialogPanel<'_>> {
+    if state.is_dialog_open {
+        Some(DialogPanel::Message)
+    } else if let Some(offer) = state.recovery_offer.as_ref() {
+        Some(DialogPanel::Recovery(offer))
+    } else if state.unsaved_changes_prompt.is_some() {
+        Some(DialogPanel::UnsavedChanges)
+    } else {
+        None
+    }
+}
+
+fn draw_recovery_prompt(ui: &mut Ui, offer: &RecoveryOffer, actions: &mut Vec<ChoreoMainAction>) {
+    let strings = recovery_dialog_translations(DEFAULT_LOCALE);
+    ui.heading(strings.title);
+    ui.label(recovery_dialog_message(DEFAULT_LOCALE, offer));
+    ui.horizontal(|ui| {
+        if ui
+            .add(MaterialButton::new(strings.discard.as_str()))
+            .clicked()
+        {
+            actions.push(ChoreoMainAction::DiscardRecovery);
+        }
+        if ui
+            .add(MaterialButton::new(strings.restore.as_str()))
+            .clicked()
+        {
+            actions.push(ChoreoMainAction::RestoreRecovery);
+        }
+    });
+}
+
+fn draw_unsaved_changes_prompt(
+    ui: &mut Ui,
+    state: &ChoreoMainState,
+    actions: &mut Vec<ChoreoMainAction>,
+) {
+    let strings = unsaved_changes_dialog_translations(DEFAULT_LOCALE);
+    ui.heading(strings.title);
+    ui.label(unsaved_changes_dialog_message(DEFAULT_LOCALE, state));
+    ui.horizontal(|ui| {
+        if ui
+            .add(MaterialButton::new(strings.cancel.as_str()))
+            .clicked()
+        {
+            actions.push(ChoreoMainAction::CancelUnsavedChanges);
+        }
+        if ui
+            .add(MaterialButton::new(strings.discard.as_str()))
+            .clicked()
+        {
+            actions.push(ChoreoMainAction::DiscardUnsavedChanges);
+        }
+        if ui.add(MaterialButton::new(strings.save.as_str())).clicked() {
+            actions.push(ChoreoMainAction::SaveUnsavedChanges);
+        }
+    });
+}
+
+/// Maps Ctrl+Z and Ctrl+Shift+Z to undo and redo unless a text field keeps them for itself.
+fn history_shortcut_action(ctx: &egui::Context) -> Option<ChoreoMainAction> {
+    if ctx.wants_keyboard_input() {
+        return None;
+    }
+    ctx.input_mut(|input| {
+        // Redo first: the undo shortcut also matches when shift is held.
+        if input.consume_shortcut(&REDO_SHORTCUT) {
+            Some(ChoreoMainAction::Redo)
+        } else if input.consume_shortcut(&UNDO_SHORTCUT) {
+            Some(ChoreoMainAction::Undo)
+        } else {
+            None
+        }
+    })
+}
+
+/// Maps key presses on the main page to the remappable floor editor shortcuts unless a text field
+/// keeps them for itself. Holding a key down only repeats nudges.
+fn floor_shortcut_actions(ctx: &egui::Context, state: &ChoreoMainState) -> Vec<ChoreoMainAction> {
+    if ctx.wants_keyboard_input() {
+        return Vec::new();
+    }
+    let shortcuts = &state.settings_state.keyboard_shortcuts;
+    ctx.input_mut(|input| {
+        let presses = input
+            .events
+            .iter()
+            .filter_map(|event| match event {
+                Event::Key {
+                    key,
+                    pressed: true,
+                    repeat,
+                    modifiers,
+                    ..
+                } => Some((*key, *modifiers, *repeat)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        let mut actions = Vec::new();
+        for (key, modifiers, repeat) in presses {
+            let Some(command) = shortcuts.command_for(key, modifiers) else {
+                continue;
+            };
+            input.consume_key(modifiers, key);
+            if repeat && command.nudge_direction().is_none() {
+                continue;
+            }
+            actions.extend(shortcut_action(state, command, modifiers));
+        }
+        actions
+    })
+}
+
+/// What `command` does right now; nudges move the selection by one grid step, or by
+/// [`LARGE_NUDGE_GRID_STEPS`] when Shift is held on top of the bound shortcut.
+#[must_use]
+pub fn shortcut_action(
+    state: &ChoreoMainState,
+    command: ShortcutCommand,
+    modifiers: Modifiers,
+) -> Option<ChoreoMainAction> {
+    if let Some((direction_x, direction_y)) = command.nudge_direction() {
+        if state.floor_state.selected_positions.is_empty() {
+            return None;
+        }
+        let bound = state.settings_state.keyboard_shortcuts.shortcut(command);
+        let steps = if modifiers.shift && !bound.modifiers.shift {
+            LARGE_NUDGE_GRID_STEPS
+        } else {
+            1.0
+        };
+        let step = steps / f64::from(state.choreography_settings_state.grid_resolution());
+        return Some(ChoreoMainAction::FloorAction(
+            FloorAction::MoveSelectedByDelta {
+                delta_x: direction_x * step,
+                delta_y: direction_y * step,
+            },
+        ));
+    }
+    if let Some(index) = command.mode_index() {
+        return Some(ChoreoMainAction::SelectMode { index });
+    }
+
+    if let Some(action) = arrange_action(command) {
+        return (!state.floor_state.selected_positions.is_empty())
+            .then_some(ChoreoMainAction::FloorAction(action));
+    }
+
+    let selected_scene = state.selected_scene_index;
+    match command {
+        ShortcutCommand::SelectAll => Some(ChoreoMainAction::FloorAction(FloorAction::SelectAll)),
+        ShortcutCommand::Delete if !state.floor_state.selected_positions.is_empty() => {
+            Some(ChoreoMainAction::DeleteSelectedPositions)
+        }
+        ShortcutCommand::Delete => {
+            selected_scene.and_then(|_| map_scene_pane_action(ScenesAction::OpenDeleteSceneDialog))
+        }
+        ShortcutCommand::PreviousScene => selected_scene
+            .and_then(|index| index.checked_sub(1))
+            .map(|index| ChoreoMainAction::SelectScene { index }),
+        ShortcutCommand::NextScene => selected_scene
+            .map(|index| index + 1)
+            .filter(|index| *index < state.scenes.len())
+            .map(|index| ChoreoMainAction::SelectScene { index }),
+        ShortcutCommand::TogglePlayback => Some(ChoreoMainAction::AudioPlayerAction(
+            AudioPlayerAction::TogglePlayPause,
+        )),
+        _ => None,
+    }
+}
+
+fn arrange_action(command: ShortcutCommand) -> Option<FloorAction> {
+    let action = match command {
+        ShortcutCommand::AlignLeft => FloorAction::AlignSelected {
+            alignment: Alignment::Left,
+        },
+        ShortcutCommand::AlignRight => FloorAction::AlignSelected {
+            alignment: Alignment::Right,
+        },
+        ShortcutCommand::AlignFront => FloorAction::AlignSelected {
+            alignment: Alignment::Front,
+        },
+        ShortcutCommand::AlignBack => FloorAction::AlignSelected {
+            alignment: Alignment::Back,
+        },
+        ShortcutCommand::AlignStageCenter => FloorAction::AlignSelected {
+            alignment: Alignment::StageCenter,
+        },
+        ShortcutCommand::DistributeAlongX => FloorAction::DistributeSelected {
+            distribution: Distribution::AlongX,
+        },
+        ShortcutCommand::DistributeAlongY => FloorAction::DistributeSelected {
+            distribution: Distribution::AlongY,
+        },
+        ShortcutCommand::DistributeOnCircle => FloorAction::DistributeSelected {
+            distribution: Distribution::Circle,
+        },
+        ShortcutCommand::MirrorLeftRight => FloorAction::MirrorSelected {
+            axis: MirrorAxis::LeftRight,
+        },
+        ShortcutCommand::MirrorFrontBack => FloorAction::MirrorSelected {
+            axis: MirrorAxis::FrontBack,
+        },
+        _ => return None,
+    };
+    Some(action)
+}
+
 fn draw_full_settings_page(
     ui: &mut Ui,
     state: &ChoreoMainState,
@@ -1,4 +1,5 @@
 use choreo_master_mobile_json::Color;
+use choreo_master_mobile_json::MusicalTimeline;
 use choreo_models::ChoreographyModel;
 
 use super::messages::ChoreographySettingsCommand;
@@ -12,7 +13,15 @@ pub enum UpdateSelectedSceneAction {
     SceneText(String),
     SceneFixedPositions(bool),
     SceneColor(Color),
-    SceneTimestamp { has_timestamp: bool, seconds: f64 },
+    SceneTimestamp {
+        has_timestamp: bool,
+        seconds: f64,
+    },
+    /// Places the scene on a count of the musical timeline; ignored without a timeline.
+    SceneCountPosition {
+        eight: u32,
+        count: u32,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -57,6 +66,7 @@ pub enum ChoreographySettingsAction {
     UpdatePositionsAtSide(bool),
     UpdateTransparency(f64),
     UpdateFloorColor(Color),
+    UpdateMusicalTimeline(Option<MusicalTimeline>),
     UpdateSelectedScene(UpdateSelectedSceneAction),
     ClearEphemeralOutputs,
 }
@@ -145,6 +155,9 @@ pub fn from_command(command: ChoreographySettingsCommand) -> ChoreographySetting
         ChoreographySettingsCommand::UpdateFloorColor(value) => {
             ChoreographySettingsAction::UpdateFloorColor(value)
         }
+        ChoreographySettingsCommand::UpdateMusicalTimeline(value) => {
+            ChoreographySettingsAction::UpdateMusicalTimeline(value)
+        }
         ChoreographySettingsCommand::UpdateSelectedScene(command) => {
             ChoreographySettingsAction::UpdateSelectedScene(from_selected_scene_command(command))
         }
@@ -175,5 +188,8 @@ pub fn from_selected_scene_command(
             has_timestamp,
             seconds,
         },
+        UpdateSelectedSceneCommand::SceneCountPosition { eight, count } => {
+            UpdateSelectedSceneAction::SceneCountPosition { eight, count }
+        }
     }
 }
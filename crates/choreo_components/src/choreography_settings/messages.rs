@@ -1,4 +1,5 @@
 use choreo_master_mobile_json::Color;
+use choreo_master_mobile_json::MusicalTimeline;
 use choreo_models::ChoreographyModel;
 
 use super::state::DateParts;
@@ -13,6 +14,7 @@ pub enum UpdateSelectedSceneCommand {
     SceneFixedPositions(bool),
     SceneColor(Color),
     SceneTimestamp { has_timestamp: bool, seconds: f64 },
+    SceneCountPosition { eight: u32, count: u32 },
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -48,6 +50,7 @@ pub enum ChoreographySettingsCommand {
     UpdatePositionsAtSide(bool),
     UpdateTransparency(f64),
     UpdateFloorColor(Color),
+    UpdateMusicalTimeline(Option<MusicalTimeline>),
     UpdateSelectedScene(UpdateSelectedSceneCommand),
     ClearEphemeralOutputs,
 }
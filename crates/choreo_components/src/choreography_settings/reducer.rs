@@ -5,6 +5,8 @@ use super::actions::UpdateSelectedSceneAction;
 use super::state::ChoreographySettingsState;
 use super::state::DateParts;
 use super::state::current_date_parts;
+use crate::time::counts::CountPosition;
+use crate::time::counts::sanitize_musical_timeline;
 use time::Date;
 use time::Month;
 
@@ -181,6 +183,13 @@ pub fn reduce(state: &mut ChoreographySettingsState, action: ChoreographySetting
             state.choreography.settings.floor_color = value;
             state.redraw_requested = true;
         }
+        ChoreographySettingsAction::UpdateMusicalTimeline(value) => {
+            state.musical_timeline = value.map(sanitize_musical_timeline);
+            state.choreography.settings.musical_timeline = state.musical_timeline;
+            let seconds = state.scene_timestamp_seconds;
+            state.set_scene_timestamp_seconds(seconds);
+            state.redraw_requested = true;
+        }
         ChoreographySettingsAction::UpdateSelectedScene(action) => {
             reduce_selected_scene(state, action);
         }
@@ -247,25 +256,40 @@ fn reduce_selected_scene(state: &mut ChoreographySettingsState, action: UpdateSe
             has_timestamp,
             seconds,
         } => {
-            if let Some(selected_scene) = state.selected_scene.as_mut() {
-                let scene_id = selected_scene.scene_id;
-                selected_scene.timestamp = if has_timestamp { Some(seconds) } else { None };
-                if let Some(model_scene) = find_scene_mut(&mut state.choreography.scenes, scene_id)
-                {
-                    model_scene.timestamp = if has_timestamp {
-                        Some(format_seconds(seconds))
-                    } else {
-                        None
-                    };
-                }
-                state.scene_has_timestamp = has_timestamp;
-                state.set_scene_timestamp_seconds(seconds);
-                state.redraw_requested = true;
+            set_selected_scene_timestamp(state, has_timestamp, seconds);
+        }
+        UpdateSelectedSceneAction::SceneCountPosition { eight, count } => {
+            if state.musical_timeline.is_none() || state.selected_scene.is_none() {
+                return;
             }
+            state.set_scene_timestamp_count_position(CountPosition { eight, count });
+            let seconds = state.scene_timestamp_seconds;
+            set_selected_scene_timestamp(state, true, seconds);
         }
     }
 }
 
+fn set_selected_scene_timestamp(
+    state: &mut ChoreographySettingsState,
+    has_timestamp: bool,
+    seconds: f64,
+) {
+    if let Some(selected_scene) = state.selected_scene.as_mut() {
+        let scene_id = selected_scene.scene_id;
+        selected_scene.timestamp = if has_timestamp { Some(seconds) } else { None };
+        if let Some(model_scene) = find_scene_mut(&mut state.choreography.scenes, scene_id) {
+            model_scene.timestamp = if has_timestamp {
+                Some(format_seconds(seconds))
+            } else {
+                None
+            };
+        }
+        state.scene_has_timestamp = has_timestamp;
+        state.set_scene_timestamp_seconds(seconds);
+        state.redraw_requested = true;
+    }
+}
+
 fn reset_state_from_empty_choreography(state: &mut ChoreographySettingsState) {
     state.comment.clear();
     state.name.clear();
@@ -285,6 +309,7 @@ fn reset_state_from_empty_choreography(state: &mut ChoreographySettingsState) {
     state.snap_to_grid = true;
     state.floor_color = choreo_master_mobile_json::Color::transparent();
     state.show_timestamps = false;
+    state.musical_timeline = None;
     state.has_selected_scene = false;
     state.scene_name.clear();
     state.scene_text.clear();
@@ -294,6 +319,7 @@ fn reset_state_from_empty_choreography(state: &mut ChoreographySettingsState) {
     state.scene_timestamp_minutes = 0;
     state.scene_timestamp_seconds_part = 0;
     state.scene_timestamp_millis = 0;
+    state.scene_timestamp_count_position = None;
     state.scene_color = choreo_master_mobile_json::Color::transparent();
 }
 
@@ -324,6 +350,11 @@ fn map_state_from_choreography(state: &mut ChoreographySettingsState) {
     state.snap_to_grid = state.choreography.settings.snap_to_grid;
     state.floor_color = state.choreography.settings.floor_color.clone();
     state.show_timestamps = state.choreography.settings.show_timestamps;
+    state.musical_timeline = state
+        .choreography
+        .settings
+        .musical_timeline
+        .map(sanitize_musical_timeline);
 }
 
 fn sync_state_from_selected_scene(state: &mut ChoreographySettingsState) {
@@ -384,6 +415,7 @@ fn clear_selected_scene_state(state: &mut ChoreographySettingsState) {
     state.scene_timestamp_minutes = 0;
     state.scene_timestamp_seconds_part = 0;
     state.scene_timestamp_millis = 0;
+    state.scene_timestamp_count_position = None;
     state.scene_color = choreo_master_mobile_json::Color::transparent();
 }
 
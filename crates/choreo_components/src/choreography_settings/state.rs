@@ -1,4 +1,8 @@
+use crate::time::counts::CountPosition;
+use crate::time::counts::count_position_for_seconds;
+use crate::time::counts::seconds_for_count_position;
 use choreo_master_mobile_json::Color;
+use choreo_master_mobile_json::MusicalTimeline;
 use choreo_master_mobile_json::SceneId;
 use choreo_models::ChoreographyModel;
 use std::time::SystemTime;
@@ -65,6 +69,7 @@ pub struct ChoreographySettingsState {
     pub show_timestamps: bool,
     pub positions_at_side: bool,
     pub show_legend: bool,
+    pub musical_timeline: Option<MusicalTimeline>,
     pub transparency: f64,
     pub comment: String,
     pub name: String,
@@ -82,6 +87,7 @@ pub struct ChoreographySettingsState {
     pub scene_timestamp_minutes: i32,
     pub scene_timestamp_seconds_part: i32,
     pub scene_timestamp_millis: i32,
+    pub scene_timestamp_count_position: Option<CountPosition>,
     pub scene_color: Color,
     pub redraw_requested: bool,
     pub last_show_timestamps_event: Option<ShowTimestampsChangedEvent>,
@@ -119,6 +125,7 @@ impl Default for ChoreographySettingsState {
             show_timestamps: false,
             positions_at_side: true,
             show_legend: false,
+            musical_timeline: None,
             transparency: 0.0,
             comment: String::new(),
             name: String::new(),
@@ -136,6 +143,7 @@ impl Default for ChoreographySettingsState {
             scene_timestamp_minutes: 0,
             scene_timestamp_seconds_part: 0,
             scene_timestamp_millis: 0,
+            scene_timestamp_count_position: None,
             scene_color: Color::transparent(),
             redraw_requested: false,
             last_show_timestamps_event: None,
@@ -198,6 +206,16 @@ impl ChoreographySettingsState {
         self.scene_timestamp_minutes = minutes;
         self.scene_timestamp_seconds_part = seconds_part;
         self.scene_timestamp_millis = millis;
+        self.scene_timestamp_count_position = self
+            .musical_timeline
+            .map(|timeline| count_position_for_seconds(&timeline, clamped));
+    }
+
+    pub fn set_scene_timestamp_count_position(&mut self, position: CountPosition) {
+        let Some(timeline) = self.musical_timeline else {
+            return;
+        };
+        self.set_scene_timestamp_seconds(seconds_for_count_position(&timeline, position));
     }
 
     pub fn set_scene_timestamp_parts(&mut self, minutes: i32, seconds: i32, millis: i32) {
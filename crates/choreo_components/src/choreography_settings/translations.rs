@@ -115,6 +115,22 @@ impl ChoreographySettingsTranslations {
         i18n::t(locale, "ChoreographyTransparencyLabel")
     }
 
+    pub fn musical_timeline(locale: &str) -> String {
+        i18n::t(locale, "ChoreographyMusicalTimelineLabel")
+    }
+
+    pub fn tempo(locale: &str) -> String {
+        i18n::t(locale, "ChoreographyTempoLabel")
+    }
+
+    pub fn first_count(locale: &str) -> String {
+        i18n::t(locale, "ChoreographyFirstCountLabel")
+    }
+
+    pub fn time_signature(locale: &str) -> String {
+        i18n::t(locale, "ChoreographyTimeSignatureLabel")
+    }
+
     pub fn scene_fixed_positions(locale: &str) -> String {
         i18n::t(locale, "SceneFixedPositionsLabel")
     }
@@ -135,6 +151,14 @@ impl ChoreographySettingsTranslations {
         i18n::t(locale, "SceneTimestampMillisecondsLabel")
     }
 
+    pub fn timestamp_eight(locale: &str) -> String {
+        i18n::t(locale, "SceneTimestampEightLabel")
+    }
+
+    pub fn timestamp_count(locale: &str) -> String {
+        i18n::t(locale, "SceneTimestampCountLabel")
+    }
+
     pub fn scene_color(locale: &str) -> String {
         i18n::t(locale, "SceneColorLabel")
     }
@@ -26,7 +26,13 @@ use crate::material::styling::material_palette::material_palette_for_visuals;
 use crate::material::styling::material_style_metrics::material_style_metrics;
 use crate::material::styling::material_typography as typography;
 use crate::material::styling::material_typography::TypographyRole;
+use crate::time::counts::MAXIMUM_BPM;
+use crate::time::counts::MINIMUM_BPM;
+use crate::time::counts::TIME_SIGNATURE_OPTIONS;
+use crate::time::counts::counts_per_eight;
+use crate::time::counts::time_signature_text;
 use choreo_master_mobile_json::Color;
+use choreo_master_mobile_json::MusicalTimeline;
 
 use super::actions::ChoreographySettingsAction;
 use super::actions::UpdateSelectedSceneAction;
@@ -35,6 +41,9 @@ use super::translations::ChoreographySettingsTranslations;
 
 const DEFAULT_LOCALE: &str = "en";
 pub const GRID_RESOLUTION_DROPDOWN_ID: &str = "choreography_settings_grid_resolution_dropdown";
+pub const TIME_SIGNATURE_DROPDOWN_ID: &str = "choreography_settings_time_signature_dropdown";
+const MAXIMUM_FIRST_COUNT_MILLIS: i32 = 600_000;
+const MAXIMUM_EIGHT: i32 = 999;
 
 #[must_use]
 pub fn choreo_date_text(year: i32, month: u8, day: u8) -> String {
@@ -108,6 +117,20 @@ pub fn scene_timestamp_controls_enabled(state: &ChoreographySettingsState) -> bo
     state.has_selected_scene && state.scene_has_timestamp
 }
 
+/// With a musical timeline the selected scene is placed by eight and count instead of by time.
+#[must_use]
+pub fn uses_count_timestamp_controls(state: &ChoreographySettingsState) -> bool {
+    state.musical_timeline.is_some() && state.scene_timestamp_count_position.is_some()
+}
+
+#[must_use]
+pub fn time_signature_labels() -> Vec<String> {
+    TIME_SIGNATURE_OPTIONS
+        .iter()
+        .map(|(beats_per_bar, beat_unit)| time_signature_text(*beats_per_bar, *beat_unit))
+        .collect()
+}
+
 #[must_use]
 pub fn floor_size_maximum(state: &ChoreographySettingsState) -> i32 {
     state.floor_size_options.last().copied().unwrap_or(100)
@@ -143,6 +166,94 @@ fn draw_choreography_section(
     for action in info_actions {
         actions.push(map_choreo_info_action(action));
     }
+    ui.add_space(material_style_metrics().spacings.spacing_8);
+    draw_musical_timeline(ui, state, locale, actions);
+}
+
+fn draw_musical_timeline(
+    ui: &mut Ui,
+    state: &ChoreographySettingsState,
+    locale: &str,
+    actions: &mut Vec<ChoreographySettingsAction>,
+) {
+    render_toggle_switch(
+        ui,
+        state.musical_timeline.is_some(),
+        ChoreographySettingsTranslations::musical_timeline(locale),
+        |enabled| {
+            ChoreographySettingsAction::UpdateMusicalTimeline(
+                enabled.then(MusicalTimeline::default),
+            )
+        },
+        actions,
+    );
+    let Some(timeline) = state.musical_timeline else {
+        return;
+    };
+
+    if let Some(bpm) = number_picker::draw(
+        ui,
+        NumberPickerUiState {
+            label: &ChoreographySettingsTranslations::tempo(locale),
+            value: timeline.bpm.round() as i32,
+            minimum: MINIMUM_BPM as i32,
+            maximum: MAXIMUM_BPM as i32,
+            step: 1,
+            enabled: true,
+        },
+    ) {
+        actions.push(ChoreographySettingsAction::UpdateMusicalTimeline(Some(
+            MusicalTimeline {
+                bpm: f64::from(bpm),
+                ..timeline
+            },
+        )));
+    }
+    if let Some(first_count_millis) = number_picker::draw(
+        ui,
+        NumberPickerUiState {
+            label: &ChoreographySettingsTranslations::first_count(locale),
+            value: (timeline.offset_seconds * 1000.0).round() as i32,
+            minimum: 0,
+            maximum: MAXIMUM_FIRST_COUNT_MILLIS,
+            step: 10,
+            enabled: true,
+        },
+    ) {
+        actions.push(ChoreographySettingsAction::UpdateMusicalTimeline(Some(
+            MusicalTimeline {
+                offset_seconds: f64::from(first_count_millis) / 1000.0,
+                ..timeline
+            },
+        )));
+    }
+
+    ui.label(ChoreographySettingsTranslations::time_signature(locale));
+    let selected_index = TIME_SIGNATURE_OPTIONS
+        .iter()
+        .position(|option| *option == (timeline.beats_per_bar, timeline.beat_unit));
+    let labels = time_signature_labels();
+    let labels = labels.iter().map(String::as_str).collect::<Vec<_>>();
+    let width = ui.available_width();
+    if let Some(index) = mode_dropdown(
+        ui,
+        Id::new(TIME_SIGNATURE_DROPDOWN_ID),
+        selected_index,
+        labels.as_slice(),
+        true,
+        width,
+        grid_resolution_dropdown_height_token(),
+    ) && selected_index != Some(index)
+        && let Some((beats_per_bar, beat_unit)) = TIME_SIGNATURE_OPTIONS.get(index)
+    {
+        actions.push(ChoreographySettingsAction::UpdateMusicalTimeline(Some(
+            MusicalTimeline {
+                beats_per_bar: *beats_per_bar,
+                beat_unit: *beat_unit,
+                ..timeline
+            },
+        )));
+    }
 }
 
 fn draw_floor_section(
@@ -369,6 +480,10 @@ fn draw_selected_scene_section(
         }
 
         ui.add_enabled_ui(scene_timestamp_controls_enabled(state), |ui| {
+            if uses_count_timestamp_controls(state) {
+                draw_scene_count_pickers(ui, state, locale, actions);
+                return;
+            }
             let mut minutes = state.scene_timestamp_minutes;
             let mut seconds = state.scene_timestamp_seconds_part;
             let mut millis = state.scene_timestamp_millis;
@@ -438,6 +553,57 @@ fn draw_selected_scene_section(
     });
 }
 
+fn draw_scene_count_pickers(
+    ui: &mut Ui,
+    state: &ChoreographySettingsState,
+    locale: &str,
+    actions: &mut Vec<ChoreographySettingsAction>,
+) {
+    let (Some(timeline), Some(position)) =
+        (state.musical_timeline, state.scene_timestamp_count_position)
+    else {
+        return;
+    };
+    let mut eight = position.eight as i32;
+    let mut count = position.count as i32;
+
+    if let Some(next_eight) = number_picker::draw(
+        ui,
+        NumberPickerUiState {
+            label: &ChoreographySettingsTranslations::timestamp_eight(locale),
+            value: eight,
+            minimum: 1,
+            maximum: MAXIMUM_EIGHT,
+            step: 1,
+            enabled: true,
+        },
+    ) {
+        eight = next_eight;
+    }
+    if let Some(next_count) = number_picker::draw(
+        ui,
+        NumberPickerUiState {
+            label: &ChoreographySettingsTranslations::timestamp_count(locale),
+            value: count,
+            minimum: 1,
+            maximum: counts_per_eight(&timeline) as i32,
+            step: 1,
+            enabled: true,
+        },
+    ) {
+        count = next_count;
+    }
+
+    if eight != position.eight as i32 || count != position.count as i32 {
+        actions.push(ChoreographySettingsAction::UpdateSelectedScene(
+            UpdateSelectedSceneAction::SceneCountPosition {
+                eight: eight.max(1) as u32,
+                count: count.max(1) as u32,
+            },
+        ));
+    }
+}
+
 fn draw_settings_card(
     ui: &mut Ui,
     outer_width: f32,
@@ -1,6 +1,11 @@
+use super::state::Alignment;
 use super::state::CanvasViewHandle;
+use super::state::CurveControl;
+use super::state::Distribution;
 use super::state::FloorPosition;
 use super::state::InteractionMode;
+use super::state::MirrorAxis;
+use super::state::PathWaypoint;
 use super::state::Point;
 use super::state::PointerEventArgs;
 use super::state::TouchAction;
@@ -41,9 +46,53 @@ pub enum FloorAction {
         start: Point,
         end: Point,
     },
+    AlignSelected {
+        alignment: Alignment,
+    },
+    DistributeSelected {
+        distribution: Distribution,
+    },
+    /// Mirrors the selected positions about a centerline of the stage.
+    MirrorSelected {
+        axis: MirrorAxis,
+    },
+    /// Turns the selected dancers to face `target`, given in floor coordinates.
+    FaceSelectedTowards {
+        target: Point,
+    },
+    /// Looks from `seat` in floor coordinates in the line-of-sight mode, or from the whole
+    /// front when `None`.
+    SetSightSeat {
+        seat: Option<Point>,
+    },
+    /// Moves a waypoint of the path from `position` to the next scene to `point` in floor
+    /// coordinates, adding it when unset. The handle stays active until the pointer is released.
+    MovePathWaypoint {
+        position: usize,
+        waypoint: PathWaypoint,
+        point: Point,
+    },
+    ClearPathWaypoint {
+        position: usize,
+        waypoint: PathWaypoint,
+    },
+    /// Moves a Bézier control of the path from the previous scene to `position` to `point` in
+    /// floor coordinates. The other control is filled in so the path stays cubic, and waypoints on
+    /// the path are dropped because they would take precedence over the curve.
+    MoveCurveControl {
+        position: usize,
+        control: CurveControl,
+        point: Point,
+    },
+    /// Clears both controls of the path from the previous scene to `position`.
+    StraightenPath {
+        position: usize,
+    },
     PlacePosition {
         point: Point,
     },
+    /// Selects every position on the floor.
+    SelectAll,
     ClearSelection,
     PointerPressed {
         point: Point,
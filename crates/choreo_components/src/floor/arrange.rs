@@ -0,0 +1,159 @@
+//! Aligning, distributing and mirroring the selected positions.
+//!
+//! Coordinates are floor coordinates, where x grows to the right and y towards the front. Selected
+//! indices past the end of the positions are ignored.
+
+use std::f64::consts::PI;
+
+use super::orientation::normalize_orientation;
+use super::state::Alignment;
+use super::state::Distribution;
+use super::state::FloorPosition;
+use super::state::MirrorAxis;
+use super::state::Point;
+
+/// The middle of a floor reaching `left` to the left, `right` to the right and so on.
+#[must_use]
+pub fn stage_center(left: i32, right: i32, front: i32, back: i32) -> Point {
+    Point::new(f64::from(right - left) / 2.0, f64::from(front - back) / 2.0)
+}
+
+/// Lines the selected positions up on the outermost of them, or moves them together so their
+/// middle lands on `center`.
+pub fn align_positions(
+    positions: &mut [FloorPosition],
+    selected: &[usize],
+    alignment: Alignment,
+    center: Point,
+) {
+    let indices = selected_indices(positions, selected);
+    let Some((min, max)) = bounds(positions, &indices) else {
+        return;
+    };
+    for index in indices {
+        let position = &mut positions[index];
+        match alignment {
+            Alignment::Left => position.x = min.x,
+            Alignment::Right => position.x = max.x,
+            Alignment::Front => position.y = max.y,
+            Alignment::Back => position.y = min.y,
+            Alignment::StageCenter => {
+                position.x += center.x - (min.x + max.x) / 2.0;
+                position.y += center.y - (min.y + max.y) / 2.0;
+            }
+        }
+    }
+}
+
+/// Spaces the selected positions evenly. Along an axis the outermost two stay where they are;
+/// on a circle the positions keep their order around their middle and their average distance
+/// from it. Fewer than three positions are left alone.
+pub fn distribute_positions(
+    positions: &mut [FloorPosition],
+    selected: &[usize],
+    distribution: Distribution,
+) {
+    let mut indices = selected_indices(positions, selected);
+    if indices.len() < 3 {
+        return;
+    }
+    let last = (indices.len() - 1) as f64;
+    match distribution {
+        Distribution::AlongX => {
+            indices.sort_by(|left, right| positions[*left].x.total_cmp(&positions[*right].x));
+            let (first_x, last_x) = (
+                positions[indices[0]].x,
+                positions[indices[indices.len() - 1]].x,
+            );
+            for (step, index) in indices.into_iter().enumerate() {
+                positions[index].x = first_x + (last_x - first_x) * step as f64 / last;
+            }
+        }
+        Distribution::AlongY => {
+            indices.sort_by(|left, right| positions[*left].y.total_cmp(&positions[*right].y));
+            let (first_y, last_y) = (
+                positions[indices[0]].y,
+                positions[indices[indices.len() - 1]].y,
+            );
+            for (step, index) in indices.into_iter().enumerate() {
+                positions[index].y = first_y + (last_y - first_y) * step as f64 / last;
+            }
+        }
+        Distribution::Circle => distribute_on_circle(positions, indices),
+    }
+}
+
+/// Mirrors the selected positions about the centerline of the stage through `center`, turning
+/// their facing with them.
+pub fn mirror_positions(
+    positions: &mut [FloorPosition],
+    selected: &[usize],
+    axis: MirrorAxis,
+    center: Point,
+) {
+    for index in selected_indices(positions, selected) {
+        let position = &mut positions[index];
+        match axis {
+            MirrorAxis::LeftRight => {
+                position.x = 2.0 * center.x - position.x;
+                position.orientation = position
+                    .orientation
+                    .map(|orientation| normalize_orientation(-orientation));
+            }
+            MirrorAxis::FrontBack => {
+                position.y = 2.0 * center.y - position.y;
+                position.orientation = position
+                    .orientation
+                    .map(|orientation| normalize_orientation(180.0 - orientation));
+            }
+        }
+    }
+}
+
+fn distribute_on_circle(positions: &mut [FloorPosition], mut indices: Vec<usize>) {
+    let count = indices.len() as f64;
+    let center = Point::new(
+        indices.iter().map(|index| positions[*index].x).sum::<f64>() / count,
+        indices.iter().map(|index| positions[*index].y).sum::<f64>() / count,
+    );
+    let angle = |position: &FloorPosition| (position.y - center.y).atan2(position.x - center.x);
+    let radius = indices
+        .iter()
+        .map(|index| (positions[*index].x - center.x).hypot(positions[*index].y - center.y))
+        .sum::<f64>()
+        / count;
+    if radius <= 0.0001 {
+        return;
+    }
+    indices.sort_by(|left, right| angle(&positions[*left]).total_cmp(&angle(&positions[*right])));
+    let start = angle(&positions[indices[0]]);
+    for (step, index) in indices.into_iter().enumerate() {
+        let angle = start + 2.0 * PI * step as f64 / count;
+        positions[index].x = center.x + radius * angle.cos();
+        positions[index].y = center.y + radius * angle.sin();
+    }
+}
+
+fn selected_indices(positions: &[FloorPosition], selected: &[usize]) -> Vec<usize> {
+    let mut indices = selected
+        .iter()
+        .copied()
+        .filter(|index| *index < positions.len())
+        .collect::<Vec<_>>();
+    indices.sort_unstable();
+    indices.dedup();
+    indices
+}
+
+fn bounds(positions: &[FloorPosition], indices: &[usize]) -> Option<(Point, Point)> {
+    let first = positions.get(*indices.first()?)?;
+    Some(indices.iter().map(|index| positions[*index]).fold(
+        (Point::new(first.x, first.y), Point::new(first.x, first.y)),
+        |(min, max), position| {
+            (
+                Point::new(min.x.min(position.x), min.y.min(position.y)),
+                Point::new(max.x.max(position.x), max.y.max(position.y)),
+            )
+        },
+    ))
+}
@@ -40,6 +40,13 @@ pub(super) fn draw_position_circles(
             radius,
             tokens::color32_from_rgba(position.fill_color),
         );
+        if let Some(orientation) = position.orientation {
+            painter.add(egui::Shape::convex_polygon(
+                geometry::orientation_notch(center, radius, orientation).to_vec(),
+                tokens::color32_from_rgba(position.border_color),
+                egui::Stroke::NONE,
+            ));
+        }
         painter.circle_stroke(
             center,
             radius,
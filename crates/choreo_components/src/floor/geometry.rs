@@ -1,9 +1,12 @@
 use egui::Rect;
 
+use super::orientation::canvas_direction;
 use super::state::FloorState;
 use super::state::Point;
 use super::state::RectPrimitive;
 use super::tokens::MIN_DANCER_RADIUS;
+use super::tokens::ORIENTATION_NOTCH_HALF_WIDTH;
+use super::tokens::ORIENTATION_NOTCH_LENGTH;
 
 #[must_use]
 pub(super) fn to_screen_point(canvas_rect: Rect, point: Point) -> egui::Pos2 {
@@ -57,3 +60,21 @@ pub(super) fn floor_layout_transform_scale(state: &FloorState) -> f32 {
 pub(super) fn floor_visual_scale(state: &FloorState) -> f32 {
     state.zoom as f32 * floor_layout_transform_scale(state)
 }
+
+/// The triangle drawn just outside a dancer circle on the side the dancer faces.
+#[must_use]
+pub(super) fn orientation_notch(
+    center: egui::Pos2,
+    radius: f32,
+    orientation: f64,
+) -> [egui::Pos2; 3] {
+    let (direction_x, direction_y) = canvas_direction(orientation);
+    let direction = egui::vec2(direction_x as f32, direction_y as f32);
+    let across = egui::vec2(-direction.y, direction.x);
+    let base = center + direction * (radius * 0.85);
+    [
+        center + direction * (radius + radius * ORIENTATION_NOTCH_LENGTH),
+        base + across * (radius * ORIENTATION_NOTCH_HALF_WIDTH),
+        base - across * (radius * ORIENTATION_NOTCH_HALF_WIDTH),
+    ]
+}
@@ -0,0 +1,696 @@
+use std::fmt;
+use std::fmt::Write as _;
+use std::sync::Arc;
+use std::sync::OnceLock;
+
+use base64::Engine as _;
+use egui::Align2;
+use egui::Color32;
+use egui::Rect;
+use resvg::tiny_skia;
+use resvg::usvg;
+use resvg::usvg::fontdb;
+
+use crate::material::styling::material_palette::MaterialPalette;
+
+use super::geometry;
+use super::state::FloorLayer;
+use super::state::FloorState;
+use super::state::Point;
+use super::tokens;
+use super::tokens::FloorCanvasColorRoles;
+use super::tokens::floor_canvas_color_roles;
+
+const FONT_FAMILY: &str = "sans-serif";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FloorImageError {
+    InvalidSvg(String),
+    EmptyImage,
+    Encode(String),
+}
+
+impl fmt::Display for FloorImageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FloorImageError::InvalidSvg(message) => {
+                write!(f, "The floor image could not be read: {message}")
+            }
+            FloorImageError::EmptyImage => write!(f, "The floor image has no area to draw."),
+            FloorImageError::Encode(message) => {
+                write!(f, "The floor image could not be encoded: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for FloorImageError {}
+
+/// Writes the floor as a standalone SVG document, following the same layers as `ui::draw`.
+///
+/// Editing aids (selection rectangle, placement hint) are left out. The image is cropped to the
+/// header, floor, axis labels and legend, so nothing hangs off the edge of a printed page.
+#[must_use]
+pub fn floor_svg_document(
+    state: &FloorState,
+    style: &egui::Style,
+    palette: MaterialPalette,
+) -> String {
+    let canvas_rect = Rect::from_min_size(
+        egui::Pos2::ZERO,
+        egui::vec2(state.layout_width_px as f32, state.layout_height_px as f32),
+    );
+    let image_rect = content_bounds(canvas_rect, state, style);
+    let roles = floor_canvas_color_roles(palette);
+
+    let mut svg = SvgWriter::new(image_rect);
+    svg.rect(image_rect, 0.0, Some(roles.canvas_background), None);
+    for layer in &state.layer_order {
+        write_layer(&mut svg, *layer, canvas_rect, state, style, palette, roles);
+    }
+    write_legend(&mut svg, canvas_rect, state, style, palette);
+    svg.finish()
+}
+
+/// Rasterizes a document from [`floor_svg_document`] into PNG bytes using egui's bundled fonts.
+pub fn rasterize_floor_svg(svg: &str) -> Result<Vec<u8>, FloorImageError> {
+    render_svg_pixmap(svg, 1.0)?
+        .encode_png()
+        .map_err(|error| FloorImageError::Encode(error.to_string()))
+}
+
+/// Renders any export document at `scale` pixels per SVG unit with the export fonts.
+pub(crate) fn render_svg_pixmap(
+    svg: &str,
+    scale: f32,
+) -> Result<tiny_skia::Pixmap, FloorImageError> {
+    let fonts = export_fonts();
+    let options = usvg::Options {
+        font_family: fonts.family.clone(),
+        fontdb: Arc::clone(&fonts.database),
+        ..usvg::Options::default()
+    };
+    let tree = usvg::Tree::from_str(svg, &options)
+        .map_err(|error| FloorImageError::InvalidSvg(error.to_string()))?;
+    let size = tree
+        .size()
+        .to_int_size()
+        .scale_by(scale)
+        .ok_or(FloorImageError::EmptyImage)?;
+    let mut pixmap =
+        tiny_skia::Pixmap::new(size.width(), size.height()).ok_or(FloorImageError::EmptyImage)?;
+    resvg::render(
+        &tree,
+        tiny_skia::Transform::from_scale(scale, scale),
+        &mut pixmap.as_mut(),
+    );
+    Ok(pixmap)
+}
+
+fn write_layer(
+    svg: &mut SvgWriter,
+    layer: FloorLayer,
+    canvas_rect: Rect,
+    state: &FloorState,
+    style: &egui::Style,
+    palette: MaterialPalette,
+    roles: FloorCanvasColorRoles,
+) {
+    match layer {
+        FloorLayer::Background => write_background(svg, canvas_rect, state, roles),
+        FloorLayer::GridLines => write_grid(svg, canvas_rect, state, palette, roles),
+        FloorLayer::FloorSvg => write_svg_overlay(svg, canvas_rect, state),
+        FloorLayer::PathSegments => write_paths(svg, canvas_rect, state, palette, roles),
+        FloorLayer::PositionCircles => write_position_circles(svg, canvas_rect, state, palette),
+        FloorLayer::PositionNumbers => {
+            write_position_numbers(svg, canvas_rect, state, style, palette);
+        }
+        FloorLayer::SelectionSegments => {}
+        FloorLayer::HeaderOverlay => {
+            write_axis_labels(svg, canvas_rect, state, style, palette);
+            write_header(svg, canvas_rect, state, style, palette);
+        }
+    }
+}
+
+fn write_background(
+    svg: &mut SvgWriter,
+    canvas_rect: Rect,
+    state: &FloorState,
+    roles: FloorCanvasColorRoles,
+) {
+    if let Some(background) = state.background_rect {
+        svg.rect(
+            geometry::primitive_to_screen_rect(canvas_rect, background),
+            0.0,
+            Some(roles.canvas_background),
+            None,
+        );
+    }
+    svg.rect(
+        floor_rect(canvas_rect, state),
+        0.0,
+        Some(tokens::color32_from_rgba(state.floor_color)),
+        None,
+    );
+}
+
+fn write_grid(
+    svg: &mut SvgWriter,
+    canvas_rect: Rect,
+    state: &FloorState,
+    palette: MaterialPalette,
+    roles: FloorCanvasColorRoles,
+) {
+    for segment in &state.grid_lines {
+        svg.line(
+            geometry::to_screen_point(canvas_rect, segment.from),
+            geometry::to_screen_point(canvas_rect, segment.to),
+            egui::Stroke::new(tokens::GRID_LINE_WIDTH, roles.grid),
+        );
+    }
+
+    let floor_stroke = egui::Stroke::new(tokens::FLOOR_BORDER_WIDTH, roles.floor_border);
+    for segment in &state.center_mark_segments {
+        svg.line(
+            geometry::to_screen_point(canvas_rect, segment.from),
+            geometry::to_screen_point(canvas_rect, segment.to),
+            floor_stroke,
+        );
+    }
+    svg.circle(
+        geometry::to_screen_point(canvas_rect, Point::new(state.center_x, state.center_y)),
+        tokens::CENTER_MARK_RADIUS,
+        Some(palette.secondary),
+        None,
+    );
+    svg.rect(
+        floor_rect(canvas_rect, state),
+        0.0,
+        None,
+        Some(floor_stroke),
+    );
+}
+
+fn write_svg_overlay(svg: &mut SvgWriter, canvas_rect: Rect, state: &FloorState) {
+    let (Some(bounds), Some(bytes)) = (state.svg_overlay_bounds, state.svg_source_bytes.as_ref())
+    else {
+        return;
+    };
+    svg.svg_image(
+        geometry::primitive_to_screen_rect(canvas_rect, bounds),
+        bytes,
+    );
+}
+
+fn write_paths(
+    svg: &mut SvgWriter,
+    canvas_rect: Rect,
+    state: &FloorState,
+    palette: MaterialPalette,
+    roles: FloorCanvasColorRoles,
+) {
+    if state.colored_path_segments.is_empty() {
+        for segment in &state.path_segments {
+            svg.line(
+                geometry::to_screen_point(canvas_rect, segment.from),
+                geometry::to_screen_point(canvas_rect, segment.to),
+                egui::Stroke::new(tokens::FLOOR_BORDER_WIDTH, roles.floor_border),
+            );
+        }
+    } else {
+        for segment in &state.colored_path_segments {
+            svg.line(
+                geometry::to_screen_point(canvas_rect, segment.from),
+                geometry::to_screen_point(canvas_rect, segment.to),
+                egui::Stroke::new(
+                    tokens::FLOOR_BORDER_WIDTH,
+                    tokens::color32_from_rgba(segment.color),
+                ),
+            );
+        }
+    }
+
+    if state.colored_dashed_path_segments.is_empty() {
+        for segment in &state.dashed_path_segments {
+            svg.line(
+                geometry::to_screen_point(canvas_rect, segment.from),
+                geometry::to_screen_point(canvas_rect, segment.to),
+                egui::Stroke::new(tokens::PATH_LINE_WIDTH, palette.secondary),
+            );
+        }
+    } else {
+        for segment in &state.colored_dashed_path_segments {
+            svg.line(
+                geometry::to_screen_point(canvas_rect, segment.from),
+                geometry::to_screen_point(canvas_rect, segment.to),
+                egui::Stroke::new(
+                    tokens::PATH_LINE_WIDTH,
+                    tokens::color32_from_rgba(segment.color),
+                ),
+            );
+        }
+    }
+}
+
+fn write_position_circles(
+    svg: &mut SvgWriter,
+    canvas_rect: Rect,
+    state: &FloorState,
+    palette: MaterialPalette,
+) {
+    if state.rendered_positions.is_empty() {
+        for point in &state.position_circles {
+            svg.circle(
+                geometry::to_screen_point(canvas_rect, *point),
+                tokens::FALLBACK_POSITION_RADIUS,
+                Some(palette.primary_container),
+                None,
+            );
+        }
+        return;
+    }
+
+    let radius = geometry::clamped_floor_position_radius(state);
+    for position in &state.rendered_positions {
+        if let Some(orientation) = position.orientation {
+            svg.polygon(
+                &geometry::orientation_notch(
+                    geometry::to_screen_point(canvas_rect, position.point),
+                    radius,
+                    orientation,
+                ),
+                tokens::color32_from_rgba(position.border_color),
+            );
+        }
+        svg.circle(
+            geometry::to_screen_point(canvas_rect, position.point),
+            radius,
+            Some(tokens::color32_from_rgba(position.fill_color)),
+            Some(egui::Stroke::new(
+                tokens::DANCER_BORDER_WIDTH,
+                tokens::color32_from_rgba(position.border_color),
+            )),
+        );
+    }
+}
+
+fn write_position_numbers(
+    svg: &mut SvgWriter,
+    canvas_rect: Rect,
+    state: &FloorState,
+    style: &egui::Style,
+    palette: MaterialPalette,
+) {
+    if state.rendered_positions.is_empty() {
+        let font_size = egui::TextStyle::Body.resolve(style).size;
+        for label in &state.position_labels {
+            svg.text(
+                geometry::to_screen_point(
+                    canvas_rect,
+                    Point::new(
+                        label.point.x + tokens::POSITION_LABEL_OFFSET_X,
+                        label.point.y + tokens::POSITION_LABEL_OFFSET_Y,
+                    ),
+                ),
+                Align2::LEFT_TOP,
+                &label.text,
+                font_size,
+                palette.on_surface,
+            );
+        }
+        return;
+    }
+
+    let radius = geometry::clamped_floor_position_radius(state);
+    for position in &state.rendered_positions {
+        if position.shortcut.trim().is_empty() {
+            continue;
+        }
+        svg.text(
+            geometry::to_screen_point(canvas_rect, position.point),
+            Align2::CENTER_CENTER,
+            &position.shortcut,
+            (radius * 1.15).max(12.0),
+            tokens::color32_from_rgba(position.text_color),
+        );
+    }
+}
+
+fn write_axis_labels(
+    svg: &mut SvgWriter,
+    canvas_rect: Rect,
+    state: &FloorState,
+    style: &egui::Style,
+    palette: MaterialPalette,
+) {
+    let font_size =
+        egui::TextStyle::Button.resolve(style).size * geometry::floor_visual_scale(state);
+    for axis in &state.axis_labels {
+        svg.text(
+            geometry::to_screen_point(canvas_rect, axis.position),
+            Align2::CENTER_CENTER,
+            &axis.text,
+            font_size,
+            palette.on_surface_variant,
+        );
+    }
+}
+
+fn write_header(
+    svg: &mut SvgWriter,
+    canvas_rect: Rect,
+    state: &FloorState,
+    style: &egui::Style,
+    palette: MaterialPalette,
+) {
+    let Some(header_rect) = state.header_overlay_rect else {
+        return;
+    };
+
+    let overlay = geometry::primitive_to_screen_rect(canvas_rect, header_rect);
+    let visual_scale = geometry::floor_visual_scale(state);
+    svg.rect(overlay, 0.0, Some(palette.surface_container_low), None);
+    if !state.choreography_name.trim().is_empty() {
+        svg.text(
+            egui::pos2(
+                overlay.center().x,
+                overlay.top() + (tokens::HEADER_TITLE_OFFSET_Y * visual_scale),
+            ),
+            Align2::CENTER_TOP,
+            &state.choreography_name,
+            egui::TextStyle::Heading.resolve(style).size * visual_scale,
+            palette.on_surface,
+        );
+    }
+    if !state.scene_name.trim().is_empty() {
+        svg.text(
+            egui::pos2(
+                overlay.center().x,
+                overlay.top() + (tokens::HEADER_SCENE_OFFSET_Y * visual_scale),
+            ),
+            Align2::CENTER_TOP,
+            &state.scene_name,
+            egui::TextStyle::Body.resolve(style).size * visual_scale,
+            palette.on_surface_variant,
+        );
+    }
+}
+
+fn write_legend(
+    svg: &mut SvgWriter,
+    canvas_rect: Rect,
+    state: &FloorState,
+    style: &egui::Style,
+    palette: MaterialPalette,
+) {
+    let Some(legend_panel_rect) = state.legend_panel_rect else {
+        return;
+    };
+
+    let legend_rect = geometry::primitive_to_screen_rect(canvas_rect, legend_panel_rect);
+    let scale = geometry::floor_visual_scale(state);
+    let layout_transform_scale = geometry::floor_layout_transform_scale(state);
+    let corner_radius = tokens::LEGEND_SWATCH_RADIUS * scale;
+    svg.rect(
+        legend_rect,
+        corner_radius,
+        Some(palette.surface_container),
+        Some(egui::Stroke::new(
+            tokens::GRID_LINE_WIDTH * scale,
+            palette.outline_variant,
+        )),
+    );
+
+    let padding_left = state.metrics.legend_content_padding_left as f32 * layout_transform_scale;
+    let padding_top = state.metrics.legend_content_padding_top as f32 * layout_transform_scale;
+    let padding_right = state.metrics.legend_content_padding_right as f32 * layout_transform_scale;
+    let row_height = tokens::LEGEND_ROW_HEIGHT * scale;
+    let swatch_radius = tokens::LEGEND_SWATCH_RADIUS * scale;
+    let body_size = egui::TextStyle::Body.resolve(style).size * scale;
+    let small_size = egui::TextStyle::Small.resolve(style).size * scale;
+
+    let square_x = legend_rect.left() + padding_left;
+    let shortcut_x = square_x + tokens::LEGEND_SHORTCUT_OFFSET_X * scale;
+    let name_x = square_x + tokens::LEGEND_NAME_OFFSET_X * scale;
+    let position_x = legend_rect.right() - padding_right;
+    let start_y = legend_rect.top() + padding_top;
+
+    for (index, entry) in state.legend_entries.iter().enumerate() {
+        let y = start_y + index as f32 * row_height;
+        svg.circle(
+            egui::pos2(square_x, y + swatch_radius),
+            swatch_radius,
+            Some(tokens::color32_from_rgba(entry.color)),
+            None,
+        );
+        if !entry.shortcut.trim().is_empty() {
+            svg.text(
+                egui::pos2(shortcut_x, y),
+                Align2::LEFT_TOP,
+                &entry.shortcut,
+                body_size,
+                palette.on_surface,
+            );
+        }
+        svg.text(
+            egui::pos2(name_x, y),
+            Align2::LEFT_TOP,
+            &entry.name,
+            body_size,
+            palette.on_surface,
+        );
+        if !entry.position_text.trim().is_empty() {
+            svg.text(
+                egui::pos2(position_x, y),
+                Align2::RIGHT_TOP,
+                &entry.position_text,
+                small_size,
+                palette.on_surface_variant,
+            );
+        }
+    }
+}
+
+fn content_bounds(canvas_rect: Rect, state: &FloorState, style: &egui::Style) -> Rect {
+    let visual_scale = geometry::floor_visual_scale(state);
+    let label_extent = egui::TextStyle::Button.resolve(style).size * visual_scale;
+    let mut bounds = floor_rect(canvas_rect, state);
+    for rect in [state.header_overlay_rect, state.legend_panel_rect]
+        .into_iter()
+        .flatten()
+    {
+        bounds = bounds.union(geometry::primitive_to_screen_rect(canvas_rect, rect));
+    }
+    for axis in &state.axis_labels {
+        bounds = bounds.union(Rect::from_center_size(
+            geometry::to_screen_point(canvas_rect, axis.position),
+            egui::vec2(label_extent * 2.0, label_extent),
+        ));
+    }
+    bounds.expand(tokens::LEGEND_ROW_HEIGHT * visual_scale)
+}
+
+fn floor_rect(canvas_rect: Rect, state: &FloorState) -> Rect {
+    geometry::to_screen_rect(
+        canvas_rect,
+        state.floor_x,
+        state.floor_y,
+        state.floor_width,
+        state.floor_height,
+    )
+}
+
+struct SvgWriter {
+    document: String,
+}
+
+impl SvgWriter {
+    fn new(view: Rect) -> Self {
+        let mut document = String::new();
+        let _ = writeln!(
+            document,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="{x} {y} {w} {h}" font-family="{FONT_FAMILY}">"#,
+            x = view.left(),
+            y = view.top(),
+            w = view.width().ceil(),
+            h = view.height().ceil(),
+        );
+        Self { document }
+    }
+
+    fn finish(mut self) -> String {
+        self.document.push_str("</svg>\n");
+        self.document
+    }
+
+    fn rect(
+        &mut self,
+        rect: Rect,
+        corner_radius: f32,
+        fill: Option<Color32>,
+        stroke: Option<egui::Stroke>,
+    ) {
+        let _ = write!(
+            self.document,
+            r#"<rect x="{}" y="{}" width="{}" height="{}""#,
+            rect.left(),
+            rect.top(),
+            rect.width(),
+            rect.height()
+        );
+        if corner_radius > 0.0 {
+            let _ = write!(self.document, r#" rx="{corner_radius}""#);
+        }
+        self.paint(fill, stroke);
+        self.document.push_str("/>\n");
+    }
+
+    fn line(&mut self, from: egui::Pos2, to: egui::Pos2, stroke: egui::Stroke) {
+        let _ = write!(
+            self.document,
+            r#"<line x1="{}" y1="{}" x2="{}" y2="{}""#,
+            from.x, from.y, to.x, to.y
+        );
+        self.paint(None, Some(stroke));
+        self.document.push_str("/>\n");
+    }
+
+    fn circle(
+        &mut self,
+        center: egui::Pos2,
+        radius: f32,
+        fill: Option<Color32>,
+        stroke: Option<egui::Stroke>,
+    ) {
+        let _ = write!(
+            self.document,
+            r#"<circle cx="{}" cy="{}" r="{radius}""#,
+            center.x, center.y
+        );
+        self.paint(fill, stroke);
+        self.document.push_str("/>\n");
+    }
+
+    fn polygon(&mut self, points: &[egui::Pos2], fill: Color32) {
+        let points: Vec<String> = points
+            .iter()
+            .map(|point| format!("{},{}", point.x, point.y))
+            .collect();
+        let _ = write!(self.document, r#"<polygon points="{}""#, points.join(" "));
+        self.paint(Some(fill), None);
+        self.document.push_str("/>\n");
+    }
+
+    fn text(&mut self, position: egui::Pos2, align: Align2, text: &str, size: f32, color: Color32) {
+        let anchor = match align.x() {
+            egui::Align::Min => "start",
+            egui::Align::Center => "middle",
+            egui::Align::Max => "end",
+        };
+        let baseline = match align.y() {
+            egui::Align::Min => "hanging",
+            egui::Align::Center => "central",
+            egui::Align::Max => "text-after-edge",
+        };
+        let _ = write!(
+            self.document,
+            r#"<text x="{}" y="{}" font-size="{size}" text-anchor="{anchor}" dominant-baseline="{baseline}""#,
+            position.x, position.y
+        );
+        self.paint(Some(color), None);
+        let _ = writeln!(self.document, ">{}</text>", escape_xml(text));
+    }
+
+    fn svg_image(&mut self, rect: Rect, bytes: &[u8]) {
+        let _ = writeln!(
+            self.document,
+            r#"<image x="{}" y="{}" width="{}" height="{}" preserveAspectRatio="none" href="data:image/svg+xml;base64,{}"/>"#,
+            rect.left(),
+            rect.top(),
+            rect.width(),
+            rect.height(),
+            base64::engine::general_purpose::STANDARD.encode(bytes)
+        );
+    }
+
+    fn paint(&mut self, fill: Option<Color32>, stroke: Option<egui::Stroke>) {
+        match fill {
+            Some(color) => self.color_attributes("fill", color),
+            None => self.document.push_str(r#" fill="none""#),
+        }
+        if let Some(stroke) = stroke {
+            self.color_attributes("stroke", stroke.color);
+            let _ = write!(self.document, r#" stroke-width="{}""#, stroke.width);
+        }
+    }
+
+    fn color_attributes(&mut self, name: &str, color: Color32) {
+        let [red, green, blue, alpha] = color.to_srgba_unmultiplied();
+        let _ = write!(
+            self.document,
+            r##" {name}="#{red:02x}{green:02x}{blue:02x}""##
+        );
+        if alpha < u8::MAX {
+            let _ = write!(
+                self.document,
+                r#" {name}-opacity="{:.3}""#,
+                f32::from(alpha) / 255.0
+            );
+        }
+    }
+}
+
+pub(crate) fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for character in text.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(character),
+        }
+    }
+    escaped
+}
+
+struct ExportFonts {
+    database: Arc<fontdb::Database>,
+    family: String,
+}
+
+/// Loads the proportional fonts egui ships with so exports look the same on every machine.
+fn export_fonts() -> &'static ExportFonts {
+    static FONTS: OnceLock<ExportFonts> = OnceLock::new();
+    FONTS.get_or_init(|| {
+        let definitions = egui::FontDefinitions::default();
+        let mut database = fontdb::Database::new();
+        let mut family = None;
+        for name in definitions
+            .families
+            .get(&egui::FontFamily::Proportional)
+            .into_iter()
+            .flatten()
+        {
+            let Some(data) = definitions.font_data.get(name) else {
+                continue;
+            };
+            let face_count = database.len();
+            database.load_font_data(data.font.to_vec());
+            if family.is_none() {
+                family = database
+                    .faces()
+                    .nth(face_count)
+                    .and_then(|face| face.families.first())
+                    .map(|(family, _)| family.clone());
+            }
+        }
+        let family = family.unwrap_or_else(|| FONT_FAMILY.to_string());
+        database.set_sans_serif_family(family.clone());
+        ExportFonts {
+            database: Arc::new(database),
+            family,
+        }
+    })
+}
@@ -0,0 +1,170 @@
+//! Sight-line analysis for the line-of-sight mode: which dancers the audience cannot see because
+//! another dancer stands between them and the viewer.
+
+use choreo_master_mobile_json::FrontPosition;
+
+use super::state::Point;
+
+/// Where the audience looks from.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SightSource {
+    /// The whole audience along the front edge, looking straight onto the floor.
+    Front(FrontPosition),
+    /// A single seat, in floor coordinates.
+    Seat(Point),
+}
+
+/// The shadow a blocking dancer casts over a hidden one, as four corners in floor coordinates:
+/// the two sides of the blocker followed by the matching points level with the hidden dancer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BlockingCone {
+    pub blocker: usize,
+    pub hidden: usize,
+    pub corners: [Point; 4],
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SightLineAnalysis {
+    /// Indices of the hidden dancers in ascending order.
+    pub hidden: Vec<usize>,
+    pub cones: Vec<BlockingCone>,
+}
+
+impl SightLineAnalysis {
+    #[must_use]
+    pub fn is_hidden(&self, index: usize) -> bool {
+        self.hidden.binary_search(&index).is_ok()
+    }
+}
+
+/// Direction from the floor towards the audience. The front of the floor is positive y, which
+/// the floor draws at the top.
+#[must_use]
+pub fn audience_direction(front_position: FrontPosition) -> Point {
+    match front_position {
+        FrontPosition::Top => Point::new(0.0, 1.0),
+        FrontPosition::Bottom => Point::new(0.0, -1.0),
+        FrontPosition::Right => Point::new(1.0, 0.0),
+        FrontPosition::Left => Point::new(-1.0, 0.0),
+    }
+}
+
+/// A dancer counts as hidden when its center lies in the shadow of a dancer nearer the audience.
+/// `radius` is the dancer radius in floor units.
+#[must_use]
+pub fn analyze_sight_lines(
+    positions: &[Point],
+    radius: f64,
+    source: SightSource,
+) -> SightLineAnalysis {
+    let mut analysis = SightLineAnalysis::default();
+    if radius <= 0.0 {
+        return analysis;
+    }
+
+    for (hidden, target) in positions.iter().enumerate() {
+        let cones: Vec<BlockingCone> = positions
+            .iter()
+            .enumerate()
+            .filter(|(blocker, _)| *blocker != hidden)
+            .filter_map(|(blocker, position)| {
+                let corners = match source {
+                    SightSource::Front(front_position) => {
+                        front_shadow(*position, *target, radius, front_position)
+                    }
+                    SightSource::Seat(seat) => seat_shadow(seat, *position, *target, radius),
+                }?;
+                Some(BlockingCone {
+                    blocker,
+                    hidden,
+                    corners,
+                })
+            })
+            .collect();
+        if !cones.is_empty() {
+            analysis.hidden.push(hidden);
+            analysis.cones.extend(cones);
+        }
+    }
+    analysis
+}
+
+fn front_shadow(
+    blocker: Point,
+    target: Point,
+    radius: f64,
+    front_position: FrontPosition,
+) -> Option<[Point; 4]> {
+    let towards = audience_direction(front_position);
+    let across = Point::new(-towards.y, towards.x);
+    let depth = dot(sub(blocker, target), towards);
+    if depth <= 0.0 {
+        return None;
+    }
+    if dot(sub(target, blocker), across).abs() > radius {
+        return None;
+    }
+
+    let back = scale(towards, -depth);
+    let left = add(blocker, scale(across, radius));
+    let right = add(blocker, scale(across, -radius));
+    Some([left, right, add(right, back), add(left, back)])
+}
+
+fn seat_shadow(seat: Point, blocker: Point, target: Point, radius: f64) -> Option<[Point; 4]> {
+    let to_blocker = sub(blocker, seat);
+    let to_target = sub(target, seat);
+    let blocker_distance = length(to_blocker);
+    let target_distance = length(to_target);
+    // A seat inside the blocker has no tangent lines to cast a shadow from.
+    if blocker_distance <= radius || target_distance <= blocker_distance {
+        return None;
+    }
+
+    let half_angle = (radius / blocker_distance).asin();
+    let separation = angle_between(to_blocker, to_target);
+    if separation > half_angle {
+        return None;
+    }
+
+    let tangent_distance = (blocker_distance.powi(2) - radius.powi(2)).sqrt();
+    let far_distance = target_distance / half_angle.cos();
+    let left_direction = rotate(scale(to_blocker, 1.0 / blocker_distance), half_angle);
+    let right_direction = rotate(scale(to_blocker, 1.0 / blocker_distance), -half_angle);
+    Some([
+        add(seat, scale(left_direction, tangent_distance)),
+        add(seat, scale(right_direction, tangent_distance)),
+        add(seat, scale(right_direction, far_distance)),
+        add(seat, scale(left_direction, far_distance)),
+    ])
+}
+
+fn add(left: Point, right: Point) -> Point {
+    Point::new(left.x + right.x, left.y + right.y)
+}
+
+fn sub(left: Point, right: Point) -> Point {
+    Point::new(left.x - right.x, left.y - right.y)
+}
+
+fn scale(point: Point, factor: f64) -> Point {
+    Point::new(point.x * factor, point.y * factor)
+}
+
+fn dot(left: Point, right: Point) -> f64 {
+    left.x * right.x + left.y * right.y
+}
+
+fn length(point: Point) -> f64 {
+    point.x.hypot(point.y)
+}
+
+fn rotate(point: Point, radians: f64) -> Point {
+    let (sin, cos) = radians.sin_cos();
+    Point::new(point.x * cos - point.y * sin, point.x * sin + point.y * cos)
+}
+
+fn angle_between(left: Point, right: Point) -> f64 {
+    let cross = left.x * right.y - left.y * right.x;
+    cross.atan2(dot(left, right)).abs()
+}
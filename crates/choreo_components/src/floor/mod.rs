@@ -1,4 +1,5 @@
 pub mod actions;
+pub mod arrange;
 mod axis_label_item;
 mod canvas_item;
 mod dancer_item;
@@ -7,24 +8,36 @@ mod floor_provider;
 mod floor_svg_item;
 mod geometry;
 mod header_item;
+mod image_export;
 mod legend_item;
+pub mod line_of_sight;
 mod messages;
+pub mod movement_path;
+pub mod orientation;
+mod path_handle_item;
 mod path_item;
 mod placement_hint_item;
 pub mod reducer;
 mod runtime;
 mod selection_item;
+mod sight_line_item;
 pub mod state;
 mod tokens;
 mod translations;
 mod types;
 pub mod ui;
+mod variation_item;
 
 pub use floor_adapter::AudioInterpolationInput;
 pub use floor_adapter::FloorAdapter;
 pub use floor_adapter::FloorAdapterInput;
 pub use floor_provider::FloorProvider;
 pub use floor_provider::FloorProviderDependencies;
+pub use image_export::FloorImageError;
+pub(crate) use image_export::escape_xml;
+pub use image_export::floor_svg_document;
+pub use image_export::rasterize_floor_svg;
+pub(crate) use image_export::render_svg_pixmap;
 pub use messages::DrawFloorCommand;
 pub use messages::PanUpdatedCommand;
 pub use messages::PinchUpdatedCommand;
@@ -0,0 +1,96 @@
+//! Transition paths through the `Movement1`/`Movement2` waypoints of a position.
+//!
+//! A position with waypoints travels `start → movement1 → movement2 → end` along straight legs
+//! at an even pace, so each leg takes time in proportion to its length. Waypoints take
+//! precedence over the Bézier `curve1`/`curve2` control points, which the bend-path mode edits
+//! as a cubic curve.
+
+use super::state::PathWaypoint;
+use super::state::Point;
+
+/// The set waypoints in travel order.
+#[must_use]
+pub fn waypoints(movement1: Option<Point>, movement2: Option<Point>) -> Vec<Point> {
+    movement1.into_iter().chain(movement2).collect()
+}
+
+/// The point reached after `progress` (0 to 1) of the way along `start`, `waypoints` and `end`.
+#[must_use]
+pub fn point_along_path(start: Point, waypoints: &[Point], end: Point, progress: f64) -> Point {
+    let points: Vec<Point> = std::iter::once(start)
+        .chain(waypoints.iter().copied())
+        .chain(std::iter::once(end))
+        .collect();
+    let total_length: f64 = points.windows(2).map(|leg| distance(leg[0], leg[1])).sum();
+    if total_length <= f64::EPSILON {
+        return start;
+    }
+
+    let mut remaining = total_length * progress.clamp(0.0, 1.0);
+    for leg in points.windows(2) {
+        let leg_length = distance(leg[0], leg[1]);
+        if remaining <= leg_length && leg_length > 0.0 {
+            let t = remaining / leg_length;
+            return Point::new(
+                leg[0].x + (leg[1].x - leg[0].x) * t,
+                leg[0].y + (leg[1].y - leg[0].y) * t,
+            );
+        }
+        remaining -= leg_length;
+    }
+    end
+}
+
+/// Where the handle of an unset waypoint sits: a third of the way along the straight path, or
+/// halfway to the other waypoint when that one is set, so adding it leaves the path unchanged.
+#[must_use]
+pub fn default_waypoint(
+    start: Point,
+    movement1: Option<Point>,
+    movement2: Option<Point>,
+    end: Point,
+    waypoint: PathWaypoint,
+) -> Point {
+    let (from, to, t) = match waypoint {
+        PathWaypoint::First => match movement2 {
+            Some(movement2) => (start, movement2, 0.5),
+            None => (start, end, 1.0 / 3.0),
+        },
+        PathWaypoint::Second => match movement1 {
+            Some(movement1) => (movement1, end, 0.5),
+            None => (start, end, 2.0 / 3.0),
+        },
+    };
+    Point::new(from.x + (to.x - from.x) * t, from.y + (to.y - from.y) * t)
+}
+
+/// Both controls of the cubic curve from `start` to `end`. A quadratic curve with only
+/// `curve1` is raised to the cubic of the same shape, and missing controls sit a third of the way
+/// from either end so the path stays straight.
+#[must_use]
+pub fn cubic_controls(
+    start: Point,
+    curve1: Option<Point>,
+    curve2: Option<Point>,
+    end: Point,
+) -> (Point, Point) {
+    match (curve1, curve2) {
+        (Some(curve1), Some(curve2)) => (curve1, curve2),
+        (Some(control), None) => (
+            lerp(start, control, 2.0 / 3.0),
+            lerp(end, control, 2.0 / 3.0),
+        ),
+        (None, curve2) => (
+            lerp(start, end, 1.0 / 3.0),
+            curve2.unwrap_or_else(|| lerp(start, end, 2.0 / 3.0)),
+        ),
+    }
+}
+
+fn lerp(from: Point, to: Point, t: f64) -> Point {
+    Point::new(from.x + (to.x - from.x) * t, from.y + (to.y - from.y) * t)
+}
+
+fn distance(from: Point, to: Point) -> f64 {
+    (to.x - from.x).hypot(to.y - from.y)
+}
@@ -0,0 +1,49 @@
+//! Dancer facing directions, stored as degrees clockwise from facing the front of the floor.
+
+/// Facing directions snap to this step while snap to grid is on.
+pub const SNAP_STEP_DEGREES: f64 = 45.0;
+
+#[must_use]
+pub fn normalize_orientation(degrees: f64) -> f64 {
+    let normalized = degrees.rem_euclid(360.0);
+    if normalized >= 360.0 { 0.0 } else { normalized }
+}
+
+/// Turns from `from` towards `to` along the shorter way round, so a turn from 350° to 10° passes
+/// through 0° rather than 180°. A side without a facing keeps the other side's facing.
+#[must_use]
+pub fn interpolate_orientation(from: Option<f64>, to: Option<f64>, progress: f64) -> Option<f64> {
+    match (from, to) {
+        (Some(from), Some(to)) => {
+            let turn = (to - from + 180.0).rem_euclid(360.0) - 180.0;
+            Some(normalize_orientation(
+                from + turn * progress.clamp(0.0, 1.0),
+            ))
+        }
+        (from, to) => from.or(to),
+    }
+}
+
+/// The facing of a dancer at `(x, y)` looking at `(target_x, target_y)` in floor coordinates,
+/// where positive y is towards the front. `None` when the target is on the dancer.
+#[must_use]
+pub fn orientation_towards(x: f64, y: f64, target_x: f64, target_y: f64) -> Option<f64> {
+    let delta_x = target_x - x;
+    let delta_y = target_y - y;
+    if delta_x.hypot(delta_y) <= 0.0001 {
+        return None;
+    }
+    Some(normalize_orientation(delta_x.atan2(delta_y).to_degrees()))
+}
+
+#[must_use]
+pub fn snap_orientation(degrees: f64) -> f64 {
+    normalize_orientation((degrees / SNAP_STEP_DEGREES).round() * SNAP_STEP_DEGREES)
+}
+
+/// The facing as a unit vector on the canvas, where y grows towards the back.
+#[must_use]
+pub fn canvas_direction(degrees: f64) -> (f64, f64) {
+    let radians = degrees.to_radians();
+    (radians.sin(), -radians.cos())
+}
@@ -0,0 +1,131 @@
+use egui::Rect;
+
+use crate::material::styling::material_palette::MaterialPalette;
+
+use super::geometry;
+use super::state::CurveHandle;
+use super::state::FloorState;
+use super::state::InteractionMode;
+use super::state::PathHandle;
+use super::state::Point;
+use super::tokens;
+use super::translations::floor_translations;
+
+pub(super) fn draw_path_handles(
+    painter: &egui::Painter,
+    canvas_rect: Rect,
+    state: &FloorState,
+    palette: MaterialPalette,
+) {
+    let stroke = egui::Stroke::new(tokens::PATH_HANDLE_STROKE_WIDTH, palette.primary);
+    for handle in &state.path_handles {
+        let center = geometry::to_screen_point(canvas_rect, handle.point);
+        if handle.is_set {
+            painter.circle(center, tokens::PATH_HANDLE_RADIUS, palette.primary, stroke);
+        } else {
+            painter.circle(center, tokens::PATH_HANDLE_RADIUS, palette.surface, stroke);
+        }
+    }
+}
+
+/// The handle under `point` in canvas coordinates, preferring the nearest one.
+pub(super) fn path_handle_at(state: &FloorState, point: Point) -> Option<PathHandle> {
+    let hit_radius = f64::from(tokens::PATH_HANDLE_HIT_RADIUS);
+    state
+        .path_handles
+        .iter()
+        .map(|handle| {
+            (
+                (handle.point.x - point.x).hypot(handle.point.y - point.y),
+                handle,
+            )
+        })
+        .filter(|(distance, _)| *distance <= hit_radius)
+        .min_by(|(left, _), (right, _)| left.total_cmp(right))
+        .map(|(_, handle)| *handle)
+}
+
+/// Control handles of the incoming paths, each tied to its path end by a tangent line.
+pub(super) fn draw_curve_handles(
+    painter: &egui::Painter,
+    canvas_rect: Rect,
+    state: &FloorState,
+    palette: MaterialPalette,
+) {
+    let tangent = egui::Stroke::new(tokens::CURVE_HANDLE_TANGENT_WIDTH, palette.primary);
+    let stroke = egui::Stroke::new(tokens::PATH_HANDLE_STROKE_WIDTH, palette.primary);
+    for handle in &state.curve_handles {
+        let center = geometry::to_screen_point(canvas_rect, handle.point);
+        painter.line_segment(
+            [
+                geometry::to_screen_point(canvas_rect, handle.anchor),
+                center,
+            ],
+            tangent,
+        );
+        if handle.is_set {
+            painter.circle(center, tokens::PATH_HANDLE_RADIUS, palette.primary, stroke);
+        } else {
+            painter.circle(center, tokens::PATH_HANDLE_RADIUS, palette.surface, stroke);
+        }
+    }
+}
+
+/// The curve handle under `point` in canvas coordinates, preferring the nearest one.
+pub(super) fn curve_handle_at(state: &FloorState, point: Point) -> Option<CurveHandle> {
+    let hit_radius = f64::from(tokens::PATH_HANDLE_HIT_RADIUS);
+    state
+        .curve_handles
+        .iter()
+        .map(|handle| {
+            (
+                (handle.point.x - point.x).hypot(handle.point.y - point.y),
+                handle,
+            )
+        })
+        .filter(|(distance, _)| *distance <= hit_radius)
+        .min_by(|(left, _), (right, _)| left.total_cmp(right))
+        .map(|(_, handle)| *handle)
+}
+
+pub(super) fn draw_path_hint(
+    painter: &egui::Painter,
+    canvas_rect: Rect,
+    state: &FloorState,
+    style: &egui::Style,
+    palette: MaterialPalette,
+) {
+    let strings = floor_translations("en");
+    let (title, hint) = match state.interaction_mode {
+        InteractionMode::EditPath if state.path_handles.is_empty() => (
+            strings.edit_path_title,
+            strings.edit_path_no_next_scene_hint,
+        ),
+        InteractionMode::EditPath => (strings.edit_path_title, strings.edit_path_hint),
+        InteractionMode::BendPath if state.curve_handles.is_empty() => (
+            strings.bend_path_title,
+            strings.bend_path_no_previous_scene_hint,
+        ),
+        InteractionMode::BendPath => (strings.bend_path_title, strings.bend_path_hint),
+        _ => return,
+    };
+
+    let start = egui::pos2(
+        canvas_rect.left() + tokens::PLACEMENT_TEXT_LEFT,
+        canvas_rect.top() + tokens::PLACEMENT_TEXT_TOP,
+    );
+    painter.text(
+        start,
+        egui::Align2::LEFT_TOP,
+        title,
+        egui::TextStyle::Button.resolve(style),
+        palette.on_surface,
+    );
+    painter.text(
+        egui::pos2(start.x, start.y + tokens::PLACEMENT_TEXT_LINE_HEIGHT),
+        egui::Align2::LEFT_TOP,
+        hint,
+        egui::TextStyle::Body.resolve(style),
+        palette.on_surface_variant,
+    );
+}
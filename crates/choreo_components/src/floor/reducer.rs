@@ -1,13 +1,33 @@
 use super::actions::FloorAction;
+use super::arrange::align_positions;
+use super::arrange::distribute_positions;
+use super::arrange::mirror_positions;
+use super::arrange::stage_center;
+use super::line_of_sight::SightLineAnalysis;
+use super::line_of_sight::SightSource;
+use super::line_of_sight::analyze_sight_lines;
+use super::movement_path;
+use super::orientation::interpolate_orientation;
+use super::orientation::normalize_orientation;
+use super::orientation::orientation_towards;
+use super::orientation::snap_orientation;
+use super::state::Alignment;
 use super::state::AxisLabel;
 use super::state::ColoredLineSegment;
+use super::state::CurveControl;
+use super::state::CurveHandle;
+use super::state::Distribution;
 use super::state::FloorLayer;
 use super::state::FloorLayoutMetrics;
 use super::state::FloorPosition;
 use super::state::FloorState;
+use super::state::InteractionMode;
 use super::state::LabeledPoint;
 use super::state::LegendEntry;
 use super::state::LineSegment;
+use super::state::MirrorAxis;
+use super::state::PathHandle;
+use super::state::PathWaypoint;
 use super::state::Point;
 use super::state::PointerButton;
 use super::state::RectPrimitive;
@@ -42,6 +62,9 @@ pub fn reduce(state: &mut FloorState, action: FloorAction) {
         }
         FloorAction::SetInteractionMode { mode } => {
             state.interaction_mode = mode;
+            state.active_path_handle = None;
+            state.active_curve_handle = None;
+            recompute_geometry(state);
         }
         FloorAction::SetPositions { positions } => {
             state.positions = positions;
@@ -121,10 +144,141 @@ pub fn reduce(state: &mut FloorState, action: FloorAction) {
             }
             recompute_geometry(state);
         }
+        FloorAction::AlignSelected { alignment } => {
+            let center = floor_center(state);
+            align_positions(
+                &mut state.positions,
+                &state.selected_positions,
+                alignment,
+                center,
+            );
+            snap_arranged_axes(
+                state,
+                !matches!(alignment, Alignment::Front | Alignment::Back),
+                !matches!(alignment, Alignment::Left | Alignment::Right),
+            );
+            recompute_geometry(state);
+        }
+        FloorAction::DistributeSelected { distribution } => {
+            distribute_positions(
+                &mut state.positions,
+                &state.selected_positions,
+                distribution,
+            );
+            snap_arranged_axes(
+                state,
+                distribution != Distribution::AlongY,
+                distribution != Distribution::AlongX,
+            );
+            recompute_geometry(state);
+        }
+        FloorAction::MirrorSelected { axis } => {
+            let center = floor_center(state);
+            mirror_positions(
+                &mut state.positions,
+                &state.selected_positions,
+                axis,
+                center,
+            );
+            snap_arranged_axes(
+                state,
+                axis == MirrorAxis::LeftRight,
+                axis == MirrorAxis::FrontBack,
+            );
+            recompute_geometry(state);
+        }
+        FloorAction::FaceSelectedTowards { target } => {
+            let selected = state.selected_positions.clone();
+            for index in selected {
+                if let Some(position) = state.positions.get_mut(index)
+                    && let Some(orientation) =
+                        orientation_towards(position.x, position.y, target.x, target.y)
+                {
+                    position.orientation = Some(if state.snap_to_grid {
+                        snap_orientation(orientation)
+                    } else {
+                        orientation
+                    });
+                }
+            }
+            recompute_geometry(state);
+        }
+        FloorAction::SetSightSeat { seat } => {
+            state.sight_seat = seat;
+            recompute_geometry(state);
+        }
+        FloorAction::MovePathWaypoint {
+            position,
+            waypoint,
+            point,
+        } => {
+            let point = snap_floor_point(state, point);
+            let Some(floor_position) = state.positions.get_mut(position) else {
+                return;
+            };
+            *waypoint_mut(floor_position, waypoint) = Some(point);
+            state.active_path_handle = Some((position, waypoint));
+            recompute_geometry(state);
+        }
+        FloorAction::ClearPathWaypoint { position, waypoint } => {
+            let Some(floor_position) = state.positions.get_mut(position) else {
+                return;
+            };
+            *waypoint_mut(floor_position, waypoint) = None;
+            recompute_geometry(state);
+        }
+        FloorAction::MoveCurveControl {
+            position,
+            control,
+            point,
+        } => {
+            let point = snap_floor_point(state, point);
+            let Some(end) = state
+                .source_positions
+                .get(position)
+                .map(|current| Point::new(current.x, current.y))
+            else {
+                return;
+            };
+            let Some(previous) = previous_source_position_mut(state, position) else {
+                return;
+            };
+            let (control1, control2) = movement_path::cubic_controls(
+                Point::new(previous.x, previous.y),
+                optional_point(previous.curve1_x, previous.curve1_y),
+                optional_point(previous.curve2_x, previous.curve2_y),
+                end,
+            );
+            let (control1, control2) = match control {
+                CurveControl::First => (point, control2),
+                CurveControl::Second => (control1, point),
+            };
+            (previous.curve1_x, previous.curve1_y) = (Some(control1.x), Some(control1.y));
+            (previous.curve2_x, previous.curve2_y) = (Some(control2.x), Some(control2.y));
+            (previous.movement1_x, previous.movement1_y) = (None, None);
+            (previous.movement2_x, previous.movement2_y) = (None, None);
+            state.active_curve_handle = Some((position, control));
+            recompute_geometry(state);
+        }
+        FloorAction::StraightenPath { position } => {
+            let Some(previous) = previous_source_position_mut(state, position) else {
+                return;
+            };
+            (previous.curve1_x, previous.curve1_y) = (None, None);
+            (previous.curve2_x, previous.curve2_y) = (None, None);
+            (previous.movement1_x, previous.movement1_y) = (None, None);
+            (previous.movement2_x, previous.movement2_y) = (None, None);
+            recompute_geometry(state);
+        }
         FloorAction::PlacePosition { point } => {
             state.positions.push(FloorPosition::new(point.x, point.y));
             recompute_geometry(state);
         }
+        FloorAction::SelectAll => {
+            state.selected_positions = (0..state.positions.len()).collect();
+            state.selection_rectangle = None;
+            recompute_geometry(state);
+        }
         FloorAction::ClearSelection => {
             state.selected_positions.clear();
             state.selection_rectangle = None;
@@ -187,6 +341,8 @@ pub fn reduce(state: &mut FloorState, action: FloorAction) {
             }
             state.last_tap_point = Some(point);
             state.pointer_anchor = None;
+            state.active_path_handle = None;
+            state.active_curve_handle = None;
         }
         FloorAction::PointerReleasedWithContext {
             canvas_view,
@@ -387,6 +543,13 @@ pub fn reduce(state: &mut FloorState, action: FloorAction) {
                 .map(|(from_position, to_position)| FloorPosition {
                     x: from_position.x + (to_position.x - from_position.x) * clamped_progress,
                     y: from_position.y + (to_position.y - from_position.y) * clamped_progress,
+                    orientation: interpolate_orientation(
+                        from_position.orientation,
+                        to_position.orientation,
+                        clamped_progress,
+                    ),
+                    movement1: None,
+                    movement2: None,
                 })
                 .collect();
             recompute_geometry(state);
@@ -439,10 +602,42 @@ fn rotate_selected(state: &mut FloorState, center: Point, start: Point, end: Poi
             let rotated_y = translated_x * rotation.sin() + translated_y * rotation.cos();
             position.x = center.x + rotated_x;
             position.y = center.y + rotated_y;
+            // Facing is measured clockwise, the rotation counterclockwise.
+            position.orientation = position
+                .orientation
+                .map(|orientation| normalize_orientation(orientation - rotation.to_degrees()));
+        }
+    }
+}
+
+/// Snaps the axes an align, distribute or mirror moved the selection along; the other axis keeps
+/// whatever the positions had.
+fn snap_arranged_axes(state: &mut FloorState, snap_x: bool, snap_y: bool) {
+    if !state.snap_to_grid {
+        return;
+    }
+    let selected = state.selected_positions.clone();
+    for index in selected {
+        if let Some(position) = state.positions.get_mut(index) {
+            if snap_x {
+                position.x = snap_to_grid(position.x, state.grid_resolution);
+            }
+            if snap_y {
+                position.y = snap_to_grid(position.y, state.grid_resolution);
+            }
         }
     }
 }
 
+fn floor_center(state: &FloorState) -> Point {
+    stage_center(
+        state.floor_left,
+        state.floor_right,
+        state.floor_front,
+        state.floor_back,
+    )
+}
+
 fn snap_to_grid(value: f64, resolution: i32) -> f64 {
     let step = 1.0 / f64::from(resolution.max(1));
     (value / step).round() * step
@@ -625,6 +820,11 @@ fn recompute_geometry(state: &mut FloorState) {
     state.colored_path_segments.clear();
     state.colored_dashed_path_segments.clear();
     state.rendered_positions.clear();
+    state.sight_lines = SightLineAnalysis::default();
+    state.blocking_cone_polygons.clear();
+    state.path_handles.clear();
+    state.curve_handles.clear();
+    state.comparison_positions.clear();
     if state.source_positions.is_empty() {
         let active_positions = if state.interpolated_positions.is_empty() {
             state.positions.clone()
@@ -657,7 +857,9 @@ fn recompute_geometry(state: &mut FloorState) {
     } else {
         state.position_circles.clear();
         state.position_labels.clear();
+        recompute_sight_lines(state);
         state.rendered_positions = build_rendered_positions(state);
+        state.comparison_positions = build_comparison_positions(state);
         state.position_circles = state
             .rendered_positions
             .iter()
@@ -688,7 +890,8 @@ fn recompute_geometry(state: &mut FloorState) {
             );
             state.path_segments = plain_line_segments(&state.colored_path_segments);
         }
-        if state.draw_path_from {
+        // The bend-path mode needs the incoming paths to show what the handles bend.
+        if state.draw_path_from || state.interaction_mode == InteractionMode::BendPath {
             state.colored_dashed_path_segments = build_scene_path_segments(
                 &state.previous_source_positions,
                 &state.source_positions,
@@ -697,6 +900,8 @@ fn recompute_geometry(state: &mut FloorState) {
             );
             state.dashed_path_segments = plain_line_segments(&state.colored_dashed_path_segments);
         }
+        state.path_handles = build_path_handles(state);
+        state.curve_handles = build_curve_handles(state);
         if state.positions_at_side {
             state.axis_labels = build_side_axis_labels(state);
         } else {
@@ -760,7 +965,7 @@ fn transform_point(state: &FloorState, point: Point) -> Point {
     )
 }
 
-fn map_floor_coordinate_to_canvas(state: &FloorState, x: f64, y: f64) -> Point {
+pub(super) fn map_floor_coordinate_to_canvas(state: &FloorState, x: f64, y: f64) -> Point {
     let width_meters = f64::from((state.floor_left + state.floor_right).max(1));
     let height_meters = f64::from((state.floor_front + state.floor_back).max(1));
     let scale_x = state.floor_width / width_meters;
@@ -769,21 +974,197 @@ fn map_floor_coordinate_to_canvas(state: &FloorState, x: f64, y: f64) -> Point {
     Point::new(state.center_x + x * scale, state.center_y - y * scale)
 }
 
+/// Inverse of the mapping used to draw dancers, for turning pointer positions into floor
+/// coordinates.
+#[must_use]
+pub fn map_canvas_point_to_floor_coordinate(state: &FloorState, point: Point) -> Point {
+    let width_meters = f64::from((state.floor_left + state.floor_right).max(1));
+    let height_meters = f64::from((state.floor_front + state.floor_back).max(1));
+    let scale_x = state.floor_width / width_meters;
+    let scale_y = state.floor_height / height_meters;
+    let scale = scale_x.min(scale_y).max(0.0001);
+    Point::new(
+        (point.x - state.center_x) / scale,
+        (state.center_y - point.y) / scale,
+    )
+}
+
+/// Where a scene position is drawn: its place in the scene, or along its path during playback.
+fn active_floor_position(
+    state: &FloorState,
+    index: usize,
+    position: &SceneRenderPosition,
+) -> FloorPosition {
+    state
+        .interpolated_positions
+        .get(index)
+        .copied()
+        .unwrap_or(FloorPosition {
+            x: position.x,
+            y: position.y,
+            orientation: position.orientation,
+            movement1: optional_point(position.movement1_x, position.movement1_y),
+            movement2: optional_point(position.movement2_x, position.movement2_y),
+        })
+}
+
+fn optional_point(x: Option<f64>, y: Option<f64>) -> Option<Point> {
+    Some(Point::new(x?, y?))
+}
+
+fn waypoint_mut(position: &mut FloorPosition, waypoint: PathWaypoint) -> &mut Option<Point> {
+    match waypoint {
+        PathWaypoint::First => &mut position.movement1,
+        PathWaypoint::Second => &mut position.movement2,
+    }
+}
+
+/// Handles for both waypoints of every selected dancer that also appears in the next scene, or
+/// of every such dancer when nothing is selected.
+fn build_path_handles(state: &FloorState) -> Vec<PathHandle> {
+    if state.interaction_mode != InteractionMode::EditPath {
+        return Vec::new();
+    }
+    let mut handles = Vec::new();
+    for (index, position) in state.source_positions.iter().enumerate() {
+        if !state.selected_positions.is_empty() && !state.selected_positions.contains(&index) {
+            continue;
+        }
+        let Some(dancer_key) = position.dancer_key.as_deref() else {
+            continue;
+        };
+        let Some(next_position) = state
+            .next_source_positions
+            .iter()
+            .find(|candidate| candidate.dancer_key.as_deref() == Some(dancer_key))
+        else {
+            continue;
+        };
+
+        let start = Point::new(position.x, position.y);
+        let end = Point::new(next_position.x, next_position.y);
+        let movement1 = optional_point(position.movement1_x, position.movement1_y);
+        let movement2 = optional_point(position.movement2_x, position.movement2_y);
+        for (waypoint, value) in [
+            (PathWaypoint::First, movement1),
+            (PathWaypoint::Second, movement2),
+        ] {
+            let point = value.unwrap_or_else(|| {
+                movement_path::default_waypoint(start, movement1, movement2, end, waypoint)
+            });
+            handles.push(PathHandle {
+                position: index,
+                waypoint,
+                point: map_floor_coordinate_to_canvas(state, point.x, point.y),
+                is_set: value.is_some(),
+            });
+        }
+    }
+    handles
+}
+
+fn snap_floor_point(state: &FloorState, point: Point) -> Point {
+    if !state.snap_to_grid {
+        return point;
+    }
+    Point::new(
+        snap_to_grid(point.x, state.grid_resolution),
+        snap_to_grid(point.y, state.grid_resolution),
+    )
+}
+
+/// The previous scene's position of the dancer at `position`, where the path into the current
+/// scene and its curve controls start.
+fn previous_source_position_mut(
+    state: &mut FloorState,
+    position: usize,
+) -> Option<&mut SceneRenderPosition> {
+    let dancer_key = state.source_positions.get(position)?.dancer_key.clone()?;
+    state
+        .previous_source_positions
+        .iter_mut()
+        .find(|candidate| candidate.dancer_key.as_deref() == Some(dancer_key.as_str()))
+}
+
+/// Handles for both curve controls of the path into every selected dancer that also appears in
+/// the previous scene, or of every such dancer when nothing is selected.
+fn build_curve_handles(state: &FloorState) -> Vec<CurveHandle> {
+    if state.interaction_mode != InteractionMode::BendPath {
+        return Vec::new();
+    }
+    let mut handles = Vec::new();
+    for (index, position) in state.source_positions.iter().enumerate() {
+        if !state.selected_positions.is_empty() && !state.selected_positions.contains(&index) {
+            continue;
+        }
+        let Some(dancer_key) = position.dancer_key.as_deref() else {
+            continue;
+        };
+        let Some(previous_position) = state
+            .previous_source_positions
+            .iter()
+            .find(|candidate| candidate.dancer_key.as_deref() == Some(dancer_key))
+        else {
+            continue;
+        };
+
+        let start = Point::new(previous_position.x, previous_position.y);
+        let end = Point::new(position.x, position.y);
+        let curve1 = optional_point(previous_position.curve1_x, previous_position.curve1_y);
+        let curve2 = optional_point(previous_position.curve2_x, previous_position.curve2_y);
+        let (control1, control2) = movement_path::cubic_controls(start, curve1, curve2, end);
+        for (control, point, anchor, is_set) in [
+            (CurveControl::First, control1, start, curve1.is_some()),
+            (CurveControl::Second, control2, end, curve2.is_some()),
+        ] {
+            handles.push(CurveHandle {
+                position: index,
+                control,
+                point: map_floor_coordinate_to_canvas(state, point.x, point.y),
+                anchor: map_floor_coordinate_to_canvas(state, anchor.x, anchor.y),
+                is_set,
+            });
+        }
+    }
+    handles
+}
+
+fn recompute_sight_lines(state: &mut FloorState) {
+    if state.interaction_mode != InteractionMode::LineOfSight {
+        return;
+    }
+    let points: Vec<Point> = state
+        .source_positions
+        .iter()
+        .enumerate()
+        .map(|(index, position)| {
+            let active = active_floor_position(state, index, position);
+            Point::new(active.x, active.y)
+        })
+        .collect();
+    let source = match state.sight_seat {
+        Some(seat) => SightSource::Seat(seat),
+        None => SightSource::Front(state.front_position),
+    };
+    state.sight_lines = analyze_sight_lines(&points, state.dancer_size.max(1.0) / 2.0, source);
+    state.blocking_cone_polygons = state
+        .sight_lines
+        .cones
+        .iter()
+        .map(|cone| {
+            cone.corners
+                .map(|corner| map_floor_coordinate_to_canvas(state, corner.x, corner.y))
+        })
+        .collect();
+}
+
 fn build_rendered_positions(state: &FloorState) -> Vec<RenderedFloorPosition> {
     state
         .source_positions
         .iter()
         .enumerate()
         .map(|(index, position)| {
-            let active =
-                state
-                    .interpolated_positions
-                    .get(index)
-                    .copied()
-                    .unwrap_or(FloorPosition {
-                        x: position.x,
-                        y: position.y,
-                    });
+            let active = active_floor_position(state, index, position);
             let fill_color = visible_fill_color(
                 position.fill_color,
                 apply_transparency(position.fill_color, state.transparency),
@@ -794,17 +1175,37 @@ fn build_rendered_positions(state: &FloorState) -> Vec<RenderedFloorPosition> {
             );
             RenderedFloorPosition {
                 point: map_floor_coordinate_to_canvas(state, active.x, active.y),
+                orientation: active.orientation,
                 fill_color,
                 border_color,
                 text_color: pick_black_or_white(fill_color),
                 shortcut: position.shortcut.clone(),
                 is_selected: state.selected_positions.contains(&index),
+                is_hidden: state.sight_lines.is_hidden(index),
                 has_dancer: position.has_dancer,
             }
         })
         .collect()
 }
 
+fn build_comparison_positions(state: &FloorState) -> Vec<RenderedFloorPosition> {
+    state
+        .comparison_source_positions
+        .iter()
+        .map(|position| RenderedFloorPosition {
+            point: map_floor_coordinate_to_canvas(state, position.x, position.y),
+            orientation: position.orientation,
+            fill_color: position.fill_color,
+            border_color: position.border_color,
+            text_color: position.border_color,
+            shortcut: position.shortcut.clone(),
+            is_selected: false,
+            is_hidden: false,
+            has_dancer: position.has_dancer,
+        })
+        .collect()
+}
+
 fn build_side_axis_labels(state: &FloorState) -> Vec<AxisLabel> {
     let mut x_values: Vec<f64> = state
         .source_positions
@@ -870,15 +1271,7 @@ fn build_legend_entries(state: &FloorState) -> Vec<LegendEntry> {
         .enumerate()
         .filter(|(_, position)| position.has_dancer)
         .map(|(index, position)| {
-            let active =
-                state
-                    .interpolated_positions
-                    .get(index)
-                    .copied()
-                    .unwrap_or(FloorPosition {
-                        x: position.x,
-                        y: position.y,
-                    });
+            let active = active_floor_position(state, index, position);
             LegendEntry {
                 shortcut: position.shortcut.clone(),
                 name: position.dancer_name.clone(),
@@ -970,6 +1363,16 @@ fn build_curve_points(
 ) -> Vec<Point> {
     let start = Point::new(from_position.x, from_position.y);
     let end = Point::new(to_position.x, to_position.y);
+    let waypoints = movement_path::waypoints(
+        optional_point(from_position.movement1_x, from_position.movement1_y),
+        optional_point(from_position.movement2_x, from_position.movement2_y),
+    );
+    if !waypoints.is_empty() {
+        return std::iter::once(start)
+            .chain(waypoints)
+            .chain(std::iter::once(end))
+            .collect();
+    }
     let Some(control1_x) = from_position.curve1_x else {
         return vec![start, end];
     };
@@ -0,0 +1,130 @@
+use egui::Rect;
+
+use crate::material::styling::material_palette::MaterialPalette;
+
+use super::geometry;
+use super::reducer::map_floor_coordinate_to_canvas;
+use super::state::FloorState;
+use super::state::InteractionMode;
+use super::state::Point;
+use super::tokens;
+use super::translations::floor_translations;
+
+pub(super) fn draw_blocking_cones(
+    painter: &egui::Painter,
+    canvas_rect: Rect,
+    state: &FloorState,
+    palette: MaterialPalette,
+) {
+    if state.interaction_mode != InteractionMode::LineOfSight {
+        return;
+    }
+
+    for polygon in &state.blocking_cone_polygons {
+        painter.add(egui::Shape::convex_polygon(
+            polygon
+                .iter()
+                .map(|corner| geometry::to_screen_point(canvas_rect, *corner))
+                .collect(),
+            palette.error.gamma_multiply(tokens::BLOCKING_CONE_OPACITY),
+            egui::Stroke::NONE,
+        ));
+    }
+
+    if let Some(seat) = state.sight_seat {
+        painter.circle_filled(
+            geometry::to_screen_point(
+                canvas_rect,
+                map_floor_coordinate_to_canvas(state, seat.x, seat.y),
+            ),
+            tokens::CENTER_MARK_RADIUS,
+            palette.error,
+        );
+    }
+}
+
+/// Whether `point` on the canvas lands on the chosen seat.
+pub(super) fn is_sight_seat_at(state: &FloorState, point: Point) -> bool {
+    state.sight_seat.is_some_and(|seat| {
+        let seat = map_floor_coordinate_to_canvas(state, seat.x, seat.y);
+        (seat.x - point.x).hypot(seat.y - point.y) <= f64::from(tokens::SIGHT_SEAT_HIT_RADIUS)
+    })
+}
+
+pub(super) fn draw_hidden_markers(
+    painter: &egui::Painter,
+    canvas_rect: Rect,
+    state: &FloorState,
+    palette: MaterialPalette,
+) {
+    if state.interaction_mode != InteractionMode::LineOfSight {
+        return;
+    }
+
+    let radius = geometry::clamped_floor_position_radius(state);
+    for position in state
+        .rendered_positions
+        .iter()
+        .filter(|position| position.is_hidden)
+    {
+        painter.circle_stroke(
+            geometry::to_screen_point(canvas_rect, position.point),
+            radius + tokens::DANCER_SELECTION_RADIUS_OFFSET,
+            egui::Stroke::new(tokens::DANCER_SELECTION_WIDTH, palette.error),
+        );
+    }
+}
+
+pub(super) fn draw_line_of_sight_hint(
+    painter: &egui::Painter,
+    canvas_rect: Rect,
+    state: &FloorState,
+    style: &egui::Style,
+    palette: MaterialPalette,
+) {
+    if state.interaction_mode != InteractionMode::LineOfSight {
+        return;
+    }
+
+    let strings = floor_translations("en");
+    let start = egui::pos2(
+        canvas_rect.left() + tokens::PLACEMENT_TEXT_LEFT,
+        canvas_rect.top() + tokens::PLACEMENT_TEXT_TOP,
+    );
+    painter.text(
+        start,
+        egui::Align2::LEFT_TOP,
+        strings.line_of_sight_title,
+        egui::TextStyle::Button.resolve(style),
+        palette.on_surface,
+    );
+    painter.text(
+        egui::pos2(start.x, start.y + tokens::PLACEMENT_TEXT_LINE_HEIGHT),
+        egui::Align2::LEFT_TOP,
+        if state.sight_seat.is_some() {
+            strings.line_of_sight_seat_hint
+        } else {
+            strings.line_of_sight_front_hint
+        },
+        egui::TextStyle::Body.resolve(style),
+        palette.on_surface_variant,
+    );
+    painter.text(
+        egui::pos2(
+            start.x,
+            start.y + (tokens::PLACEMENT_TEXT_LINE_HEIGHT * 2.0),
+        ),
+        egui::Align2::LEFT_TOP,
+        format!(
+            "{}{}",
+            strings.line_of_sight_hidden_prefix,
+            state.sight_lines.hidden.len()
+        ),
+        egui::TextStyle::Body.resolve(style),
+        if state.sight_lines.hidden.is_empty() {
+            palette.secondary
+        } else {
+            palette.error
+        },
+    );
+}
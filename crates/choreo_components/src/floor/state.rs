@@ -1,5 +1,9 @@
 use std::collections::BTreeMap;
 
+use choreo_master_mobile_json::FrontPosition;
+
+use super::line_of_sight::SightLineAnalysis;
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Point {
     pub x: f64,
@@ -67,6 +71,10 @@ pub enum InteractionMode {
     RotateAroundDancer,
     Scale,
     Place,
+    Face,
+    LineOfSight,
+    EditPath,
+    BendPath,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -180,15 +188,90 @@ pub enum FloorLayer {
 pub struct FloorPosition {
     pub x: f64,
     pub y: f64,
+    /// Facing direction in degrees clockwise from the front; `None` when not set.
+    pub orientation: Option<f64>,
+    /// Waypoints the path to the next scene passes through, in floor coordinates.
+    pub movement1: Option<Point>,
+    pub movement2: Option<Point>,
 }
 
 impl FloorPosition {
     #[must_use]
     pub fn new(x: f64, y: f64) -> Self {
-        Self { x, y }
+        Self {
+            x,
+            y,
+            orientation: None,
+            movement1: None,
+            movement2: None,
+        }
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathWaypoint {
+    First,
+    Second,
+}
+
+/// A draggable waypoint of the path from the current to the next scene.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PathHandle {
+    pub position: usize,
+    pub waypoint: PathWaypoint,
+    /// Canvas point of the handle.
+    pub point: Point,
+    /// `false` while the waypoint is unset and the handle only offers a place to add it.
+    pub is_set: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CurveControl {
+    First,
+    Second,
+}
+
+/// Where the selected positions are lined up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Alignment {
+    Left,
+    Right,
+    Front,
+    Back,
+    /// Moves the selection as a whole so its middle is the middle of the stage.
+    StageCenter,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Distribution {
+    AlongX,
+    AlongY,
+    Circle,
+}
+
+/// The stage centerline the selected positions are mirrored about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MirrorAxis {
+    /// Swaps left and right about the line running from the front to the back.
+    LeftRight,
+    /// Swaps front and back about the line running from left to right.
+    FrontBack,
+}
+
+/// A draggable Bézier control of the path from the previous to the current scene.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CurveHandle {
+    pub position: usize,
+    pub control: CurveControl,
+    /// Canvas point of the handle.
+    pub point: Point,
+    /// Canvas point of the path end the control pulls on: the previous position for the first
+    /// control, the current one for the second.
+    pub anchor: Point,
+    /// `false` while the control is unset and the handle sits on the straight path.
+    pub is_set: bool,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct SceneRenderPosition {
     pub dancer_key: Option<String>,
@@ -200,6 +283,11 @@ pub struct SceneRenderPosition {
     pub curve1_y: Option<f64>,
     pub curve2_x: Option<f64>,
     pub curve2_y: Option<f64>,
+    pub movement1_x: Option<f64>,
+    pub movement1_y: Option<f64>,
+    pub movement2_x: Option<f64>,
+    pub movement2_y: Option<f64>,
+    pub orientation: Option<f64>,
     pub fill_color: [u8; 4],
     pub border_color: [u8; 4],
     pub text_color: [u8; 4],
@@ -209,12 +297,14 @@ pub struct SceneRenderPosition {
 #[derive(Debug, Clone, PartialEq)]
 pub struct RenderedFloorPosition {
     pub point: Point,
+    pub orientation: Option<f64>,
     pub fill_color: [u8; 4],
     pub border_color: [u8; 4],
     pub text_color: [u8; 4],
     pub shortcut: String,
     pub is_selected: bool,
     pub has_dancer: bool,
+    pub is_hidden: bool,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -320,6 +410,22 @@ pub struct FloorState {
     pub axis_labels: Vec<AxisLabel>,
     pub legend_entries: Vec<LegendEntry>,
     pub placement_remaining: Option<u32>,
+    /// Side of the floor the audience sits on.
+    pub front_position: FrontPosition,
+    /// Seat the line-of-sight mode looks from, in floor coordinates; `None` means the whole front.
+    pub sight_seat: Option<Point>,
+    pub sight_lines: SightLineAnalysis,
+    pub blocking_cone_polygons: Vec<[Point; 4]>,
+    pub path_handles: Vec<PathHandle>,
+    /// Handle being dragged in the edit-path mode, released with the pointer.
+    pub active_path_handle: Option<(usize, PathWaypoint)>,
+    pub curve_handles: Vec<CurveHandle>,
+    /// Handle being dragged in the bend-path mode, released with the pointer.
+    pub active_curve_handle: Option<(usize, CurveControl)>,
+    /// Scene of another variation outlined next to the shown scene.
+    pub comparison_scene_name: Option<String>,
+    pub comparison_source_positions: Vec<SceneRenderPosition>,
+    pub comparison_positions: Vec<RenderedFloorPosition>,
     pub svg_path: Option<String>,
     pub svg_source_path: Option<String>,
     pub svg_source_bytes: Option<Vec<u8>>,
@@ -405,6 +511,17 @@ impl Default for FloorState {
             axis_labels: Vec::new(),
             legend_entries: Vec::new(),
             placement_remaining: None,
+            front_position: FrontPosition::Top,
+            sight_seat: None,
+            sight_lines: SightLineAnalysis::default(),
+            blocking_cone_polygons: Vec::new(),
+            path_handles: Vec::new(),
+            active_path_handle: None,
+            curve_handles: Vec::new(),
+            active_curve_handle: None,
+            comparison_scene_name: None,
+            comparison_source_positions: Vec::new(),
+            comparison_positions: Vec::new(),
             svg_path: None,
             svg_source_path: None,
             svg_source_bytes: None,
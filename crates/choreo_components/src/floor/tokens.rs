@@ -33,6 +33,17 @@ pub(super) const CENTER_MARK_RADIUS: f32 = 4.0;
 pub(super) const MIN_DANCER_RADIUS: f32 = 6.0;
 pub(super) const DANCER_SELECTION_RADIUS_OFFSET: f32 = 4.0;
 pub(super) const FALLBACK_POSITION_RADIUS: f32 = 6.0;
+// Facing notch size as a fraction of the dancer radius.
+pub(super) const ORIENTATION_NOTCH_LENGTH: f32 = 0.55;
+pub(super) const ORIENTATION_NOTCH_HALF_WIDTH: f32 = 0.45;
+pub(super) const BLOCKING_CONE_OPACITY: f32 = 0.2;
+pub(super) const SIGHT_SEAT_HIT_RADIUS: f32 = 12.0;
+pub(super) const PATH_HANDLE_RADIUS: f32 = 5.0;
+pub(super) const PATH_HANDLE_HIT_RADIUS: f32 = 12.0;
+pub(super) const PATH_HANDLE_STROKE_WIDTH: f32 = 2.0;
+pub(super) const CURVE_HANDLE_TANGENT_WIDTH: f32 = 1.0;
+pub(super) const COMPARISON_OUTLINE_WIDTH: f32 = 2.0;
+pub(super) const COMPARISON_OPACITY: f32 = 0.6;
 pub(super) const LEGEND_SWATCH_RADIUS: f32 = 6.0;
 
 pub(super) const POSITION_LABEL_OFFSET_X: f64 = 12.0;
@@ -2,6 +2,17 @@ pub(super) struct FloorTranslations {
     pub placement_title: &'static str,
     pub placement_hint: &'static str,
     pub placement_remaining_prefix: &'static str,
+    pub line_of_sight_title: &'static str,
+    pub line_of_sight_front_hint: &'static str,
+    pub line_of_sight_seat_hint: &'static str,
+    pub line_of_sight_hidden_prefix: &'static str,
+    pub edit_path_title: &'static str,
+    pub edit_path_hint: &'static str,
+    pub edit_path_no_next_scene_hint: &'static str,
+    pub bend_path_title: &'static str,
+    pub bend_path_hint: &'static str,
+    pub bend_path_no_previous_scene_hint: &'static str,
+    pub comparison_prefix: &'static str,
 }
 
 #[must_use]
@@ -11,11 +22,33 @@ pub(super) fn floor_translations(locale: &str) -> FloorTranslations {
             placement_title: "Positionieren",
             placement_hint: "Tippen, um eine Position zu setzen",
             placement_remaining_prefix: "Verbleibend: ",
+            line_of_sight_title: "Sichtlinie",
+            line_of_sight_front_hint: "Vom ganzen Publikum aus; tippen, um einen Sitzplatz zu wählen",
+            line_of_sight_seat_hint: "Vom gewählten Sitzplatz aus; erneut darauf tippen für das ganze Publikum",
+            line_of_sight_hidden_prefix: "Verdeckt: ",
+            edit_path_title: "Weg bearbeiten",
+            edit_path_hint: "Griffe ziehen, um den Weg umzuleiten; Rechtsklick entfernt einen Griff",
+            edit_path_no_next_scene_hint: "Wege führen zur nächsten Szene; keine folgende Szene",
+            bend_path_title: "Weg biegen",
+            bend_path_hint: "Griffe ziehen, um den Weg zu biegen; Rechtsklick macht ihn gerade",
+            bend_path_no_previous_scene_hint: "Wege kommen aus der vorigen Szene; keine vorige Szene",
+            comparison_prefix: "Verglichen mit: ",
         },
         _ => FloorTranslations {
             placement_title: "Placement",
             placement_hint: "Tap to place a position",
             placement_remaining_prefix: "Remaining: ",
+            line_of_sight_title: "Line of sight",
+            line_of_sight_front_hint: "From the whole audience; tap to pick a seat",
+            line_of_sight_seat_hint: "From the chosen seat; tap it again for the whole audience",
+            line_of_sight_hidden_prefix: "Hidden: ",
+            edit_path_title: "Edit path",
+            edit_path_hint: "Drag a handle to route the path through it; right-click to remove it",
+            edit_path_no_next_scene_hint: "Paths lead to the next scene; there is none to edit",
+            bend_path_title: "Bend path",
+            bend_path_hint: "Drag a handle to bend the path; right-click to straighten it",
+            bend_path_no_previous_scene_hint: "Paths come from the previous scene; there is none to bend",
+            comparison_prefix: "Compared with: ",
         },
     }
 }
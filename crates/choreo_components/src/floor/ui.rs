@@ -13,12 +13,16 @@ use super::dancer_item;
 use super::floor_svg_item;
 use super::header_item;
 use super::legend_item;
+use super::path_handle_item;
 use super::path_item;
 use super::placement_hint_item;
+use super::reducer::map_canvas_point_to_floor_coordinate;
 use super::selection_item;
+use super::sight_line_item;
 use super::state::CanvasViewHandle;
 use super::state::FloorLayer;
 use super::state::FloorState;
+use super::state::InteractionMode;
 use super::state::Point;
 use super::state::PointerButton;
 use super::state::PointerEventArgs;
@@ -27,6 +31,7 @@ use super::state::TouchDeviceType;
 use super::state::TouchEventArgs;
 pub use super::tokens::FloorCanvasColorRoles;
 pub use super::tokens::floor_canvas_color_roles;
+use super::variation_item;
 
 pub fn draw(ui: &mut Ui, state: &FloorState) -> Vec<FloorAction> {
     let mut actions: Vec<FloorAction> = Vec::new();
@@ -39,7 +44,7 @@ pub fn draw(ui: &mut Ui, state: &FloorState) -> Vec<FloorAction> {
 
     let (rect, response) = ui.allocate_exact_size(available, egui::Sense::drag());
     let painter = ui.painter_at(rect);
-    collect_interactions(ui, rect, response.hovered(), &mut actions);
+    collect_interactions(ui, rect, response.hovered(), state, &mut actions);
 
     let palette = material_palette_for_visuals(ui.visuals());
     let color_roles = floor_canvas_color_roles(palette);
@@ -59,6 +64,9 @@ pub fn draw(ui: &mut Ui, state: &FloorState) -> Vec<FloorAction> {
 
     legend_item::draw_legend(&painter, rect, state, style, palette);
     placement_hint_item::draw_placement_hint(&painter, rect, state, style, palette);
+    sight_line_item::draw_line_of_sight_hint(&painter, rect, state, style, palette);
+    path_handle_item::draw_path_hint(&painter, rect, state, style, palette);
+    variation_item::draw_comparison_hint(&painter, rect, state, style, palette);
 
     actions
 }
@@ -88,14 +96,39 @@ fn draw_layer(layer: FloorLayer, state: &FloorState, context: &LayerDrawContext<
                 context.palette,
                 context.color_roles,
             );
+            path_handle_item::draw_path_handles(
+                context.painter,
+                context.rect,
+                state,
+                context.palette,
+            );
+            path_handle_item::draw_curve_handles(
+                context.painter,
+                context.rect,
+                state,
+                context.palette,
+            );
         }
         FloorLayer::PositionCircles => {
+            variation_item::draw_comparison_positions(context.painter, context.rect, state);
+            sight_line_item::draw_blocking_cones(
+                context.painter,
+                context.rect,
+                state,
+                context.palette,
+            );
             dancer_item::draw_position_circles(
                 context.painter,
                 context.rect,
                 state,
                 context.palette,
             );
+            sight_line_item::draw_hidden_markers(
+                context.painter,
+                context.rect,
+                state,
+                context.palette,
+            );
         }
         FloorLayer::PositionNumbers => {
             dancer_item::draw_position_numbers(
@@ -137,10 +170,25 @@ struct LayerDrawContext<'a> {
     color_roles: FloorCanvasColorRoles,
 }
 
-fn collect_interactions(ui: &Ui, rect: Rect, is_hovered: bool, actions: &mut Vec<FloorAction>) {
+fn collect_interactions(
+    ui: &Ui,
+    rect: Rect,
+    is_hovered: bool,
+    state: &FloorState,
+    actions: &mut Vec<FloorAction>,
+) {
     let canvas_view = CanvasViewHandle::default();
+    let turns_selection =
+        state.interaction_mode == InteractionMode::Face && !state.selected_positions.is_empty();
+    let edits_paths = state.interaction_mode == InteractionMode::EditPath;
+    let bends_paths = state.interaction_mode == InteractionMode::BendPath;
     ui.input(|input| {
         let mut last_canvas_point: Option<Point> = None;
+        let mut dragged_path_handle = state.active_path_handle;
+        let mut dragged_curve_handle = state.active_curve_handle;
+        let mut is_pressing_floor = state.pointer_anchor.is_some()
+            || state.active_path_handle.is_some()
+            || state.active_curve_handle.is_some();
         for event in &input.events {
             match event {
                 Event::PointerButton {
@@ -157,20 +205,119 @@ fn collect_interactions(ui: &Ui, rect: Rect, is_hovered: bool, actions: &mut Vec
                         is_in_contact: *pressed,
                     };
                     if *pressed {
+                        is_pressing_floor |= event_args.button == PointerButton::Primary;
                         actions.push(FloorAction::PointerPressedWithContext {
                             canvas_view,
                             event_args,
                         });
+                        if turns_selection && event_args.button == PointerButton::Primary {
+                            actions.push(FloorAction::FaceSelectedTowards {
+                                target: map_canvas_point_to_floor_coordinate(state, point),
+                            });
+                        }
+                        if state.interaction_mode == InteractionMode::LineOfSight {
+                            // Tapping the chosen seat again looks from the whole audience, which
+                            // also works where there is no secondary button.
+                            let seat = (event_args.button == PointerButton::Primary
+                                && !sight_line_item::is_sight_seat_at(state, point))
+                            .then(|| map_canvas_point_to_floor_coordinate(state, point));
+                            actions.push(FloorAction::SetSightSeat { seat });
+                        }
+                        if edits_paths
+                            && let Some(handle) = path_handle_item::path_handle_at(state, point)
+                        {
+                            if event_args.button == PointerButton::Primary {
+                                dragged_path_handle = Some((handle.position, handle.waypoint));
+                                actions.push(FloorAction::MovePathWaypoint {
+                                    position: handle.position,
+                                    waypoint: handle.waypoint,
+                                    point: map_canvas_point_to_floor_coordinate(state, point),
+                                });
+                            } else if handle.is_set {
+                                actions.push(FloorAction::ClearPathWaypoint {
+                                    position: handle.position,
+                                    waypoint: handle.waypoint,
+                                });
+                            }
+                        }
+                        if bends_paths
+                            && let Some(handle) = path_handle_item::curve_handle_at(state, point)
+                        {
+                            if event_args.button == PointerButton::Primary {
+                                dragged_curve_handle = Some((handle.position, handle.control));
+                                actions.push(FloorAction::MoveCurveControl {
+                                    position: handle.position,
+                                    control: handle.control,
+                                    point: map_canvas_point_to_floor_coordinate(state, point),
+                                });
+                            } else {
+                                actions.push(FloorAction::StraightenPath {
+                                    position: handle.position,
+                                });
+                            }
+                        }
                     } else {
+                        is_pressing_floor &= event_args.button != PointerButton::Primary;
+                        dragged_path_handle = None;
+                        dragged_curve_handle = None;
                         actions.push(FloorAction::PointerReleasedWithContext {
                             canvas_view,
                             event_args,
                         });
                     }
                 }
+                // A drag that started on the floor still ends there when the button is released
+                // outside of it.
+                Event::PointerButton {
+                    pos,
+                    button: egui::PointerButton::Primary,
+                    pressed: false,
+                    ..
+                } if is_pressing_floor => {
+                    is_pressing_floor = false;
+                    dragged_path_handle = None;
+                    dragged_curve_handle = None;
+                    actions.push(FloorAction::PointerReleasedWithContext {
+                        canvas_view,
+                        event_args: PointerEventArgs {
+                            position: to_canvas_point(rect, *pos),
+                            button: PointerButton::Primary,
+                            is_in_contact: false,
+                        },
+                    });
+                }
                 Event::PointerMoved(pos) if rect.contains(*pos) => {
                     let point = to_canvas_point(rect, *pos);
                     last_canvas_point = Some(point);
+                    // Dragging turns the selected dancers instead of panning the floor.
+                    if turns_selection && input.pointer.primary_down() {
+                        actions.push(FloorAction::FaceSelectedTowards {
+                            target: map_canvas_point_to_floor_coordinate(state, point),
+                        });
+                        continue;
+                    }
+                    // Dragging a path handle moves its waypoint instead of panning the floor.
+                    if let Some((position, waypoint)) = dragged_path_handle
+                        && input.pointer.primary_down()
+                    {
+                        actions.push(FloorAction::MovePathWaypoint {
+                            position,
+                            waypoint,
+                            point: map_canvas_point_to_floor_coordinate(state, point),
+                        });
+                        continue;
+                    }
+                    // Dragging a curve handle bends the incoming path instead of panning the floor.
+                    if let Some((position, control)) = dragged_curve_handle
+                        && input.pointer.primary_down()
+                    {
+                        actions.push(FloorAction::MoveCurveControl {
+                            position,
+                            control,
+                            point: map_canvas_point_to_floor_coordinate(state, point),
+                        });
+                        continue;
+                    }
                     actions.push(FloorAction::PointerMovedWithContext {
                         canvas_view,
                         event_args: PointerEventArgs {
@@ -213,6 +360,21 @@ fn collect_interactions(ui: &Ui, rect: Rect, is_hovered: bool, actions: &mut Vec
                         },
                     });
                 }
+                Event::Touch { id, phase, pos, .. }
+                    if matches!(phase, TouchPhase::End | TouchPhase::Cancel)
+                        && state.active_touches.contains_key(&(id.0 as i64)) =>
+                {
+                    actions.push(FloorAction::TouchWithContext {
+                        canvas_view,
+                        event_args: TouchEventArgs {
+                            id: id.0 as i64,
+                            action: map_touch_phase(*phase),
+                            device_type: TouchDeviceType::Touch,
+                            location: to_canvas_point(rect, *pos),
+                            in_contact: false,
+                        },
+                    });
+                }
                 _ => {}
             }
         }
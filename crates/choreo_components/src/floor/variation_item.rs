@@ -0,0 +1,60 @@
+use egui::Rect;
+
+use crate::material::styling::material_palette::MaterialPalette;
+
+use super::geometry;
+use super::state::FloorState;
+use super::tokens;
+use super::translations::floor_translations;
+
+/// Outlines the dancers of the compared variation so both formations show on one floor.
+pub(super) fn draw_comparison_positions(
+    painter: &egui::Painter,
+    canvas_rect: Rect,
+    state: &FloorState,
+) {
+    let radius = geometry::clamped_floor_position_radius(state);
+    for position in &state.comparison_positions {
+        let center = geometry::to_screen_point(canvas_rect, position.point);
+        let color = tokens::color32_from_rgba(position.border_color)
+            .gamma_multiply(tokens::COMPARISON_OPACITY);
+        painter.circle_stroke(
+            center,
+            radius,
+            egui::Stroke::new(tokens::COMPARISON_OUTLINE_WIDTH, color),
+        );
+        if !position.shortcut.trim().is_empty() {
+            painter.text(
+                center,
+                egui::Align2::CENTER_CENTER,
+                &position.shortcut,
+                egui::FontId::proportional((radius * 1.15).max(12.0)),
+                color,
+            );
+        }
+    }
+}
+
+pub(super) fn draw_comparison_hint(
+    painter: &egui::Painter,
+    canvas_rect: Rect,
+    state: &FloorState,
+    style: &egui::Style,
+    palette: MaterialPalette,
+) {
+    let Some(scene_name) = state.comparison_scene_name.as_deref() else {
+        return;
+    };
+
+    let strings = floor_translations("en");
+    painter.text(
+        egui::pos2(
+            canvas_rect.left() + tokens::PLACEMENT_TEXT_LEFT,
+            canvas_rect.bottom() - tokens::PLACEMENT_TEXT_TOP,
+        ),
+        egui::Align2::LEFT_BOTTOM,
+        format!("{}{}", strings.comparison_prefix, scene_name),
+        egui::TextStyle::Body.resolve(style),
+        palette.on_surface_variant,
+    );
+}
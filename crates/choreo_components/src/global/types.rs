@@ -30,6 +30,9 @@ pub enum InteractionMode {
     RotateAroundDancer,
     Scale,
     LineOfSight,
+    Face,
+    EditPath,
+    BendPath,
 }
 
 #[derive(Debug, Default)]
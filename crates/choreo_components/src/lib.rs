@@ -9,6 +9,7 @@
 pub mod app_shell;
 pub mod audio_player;
 pub mod behavior;
+pub mod booklet;
 pub mod choreo_info;
 pub mod choreo_main;
 pub mod choreography_settings;
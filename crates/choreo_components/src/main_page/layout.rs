@@ -11,6 +11,7 @@ use crate::material::components::drawer_host::state::DrawerHostState;
 pub(super) const TOP_BAR_HEIGHT_PX: f32 = 84.0;
 pub(super) const DRAWER_WIDTH_LEFT_PX: f32 = 324.0;
 pub(super) const AUDIO_PANEL_HEIGHT_PX: f32 = 84.0;
+pub(super) const REHEARSAL_PANEL_HEIGHT_PX: f32 = 64.0;
 pub(super) const GRID_12_PX: f32 = 12.0;
 
 #[must_use]
@@ -59,6 +60,16 @@ pub fn audio_panel_height_px(is_audio_player_open: bool) -> f32 {
     }
 }
 
+/// Extra height below the audio player while the rehearsal loop row is shown.
+#[must_use]
+pub fn rehearsal_panel_height_px(is_audio_player_open: bool, is_rehearsal_panel_open: bool) -> f32 {
+    if is_audio_player_open && is_rehearsal_panel_open {
+        REHEARSAL_PANEL_HEIGHT_PX
+    } else {
+        0.0
+    }
+}
+
 #[must_use]
 pub fn audio_panel_rect(page_rect: Rect, audio_panel_height: f32) -> Rect {
     let panel_top = (page_rect.max.y - audio_panel_height).max(page_rect.min.y);
@@ -69,6 +69,7 @@ pub fn map_scene_pane_action(action: ScenesAction) -> Option<ChoreoMainAction> {
             }))
         }
         ScenesAction::RequestSaveChoreography => Some(ChoreoMainAction::RequestSaveChoreo),
+        ScenesAction::RequestSaveChoreographyAs => Some(ChoreoMainAction::RequestSaveChoreoAs),
         ScenesAction::NavigateToSettings => Some(ChoreoMainAction::NavigateToSettings),
         ScenesAction::NavigateToDancerSettings => Some(ChoreoMainAction::NavigateToDancers),
         ScenesAction::UpdateSearchText(value) => {
@@ -79,6 +80,45 @@ pub fn map_scene_pane_action(action: ScenesAction) -> Option<ChoreoMainAction> {
         }
         ScenesAction::OpenDeleteSceneDialog => Some(ChoreoMainAction::DeleteSelectedScene),
         ScenesAction::SelectScene { index } => Some(ChoreoMainAction::SelectScene { index }),
+        ScenesAction::ToggleSceneSelection { index } => {
+            Some(ChoreoMainAction::ToggleSceneSelection { index })
+        }
+        ScenesAction::ExtendSceneSelection { index } => {
+            Some(ChoreoMainAction::ExtendSceneSelection { index })
+        }
+        ScenesAction::MoveScene { from, to } => Some(ChoreoMainAction::MoveScene { from, to }),
+        ScenesAction::DuplicateScene => Some(ChoreoMainAction::DuplicateScene),
+        ScenesAction::ShiftSceneTimestamps { seconds } => {
+            Some(ChoreoMainAction::ShiftSceneTimestamps { seconds })
+        }
+        ScenesAction::SelectFormation { choice } => {
+            Some(ChoreoMainAction::SelectFormation { choice })
+        }
+        ScenesAction::UpdateFormationParameters { parameters } => {
+            Some(ChoreoMainAction::UpdateFormationParameters { parameters })
+        }
+        ScenesAction::ApplyFormation => Some(ChoreoMainAction::ApplyFormation),
+        ScenesAction::SaveFormationTemplate => Some(ChoreoMainAction::SaveFormationTemplate),
+        ScenesAction::AutoAssignDancers => Some(ChoreoMainAction::AutoAssignDancers),
+        ScenesAction::InsertInBetweenScene { formation } => {
+            Some(ChoreoMainAction::InsertInBetweenScene { formation })
+        }
+        ScenesAction::BendCollidingPaths => Some(ChoreoMainAction::BendCollidingPaths),
+        ScenesAction::BranchSceneVariation => Some(ChoreoMainAction::BranchSceneVariation),
+        ScenesAction::SwitchSceneVariation { variation } => {
+            Some(ChoreoMainAction::SwitchSceneVariation { variation })
+        }
+        ScenesAction::PromoteSceneVariation => Some(ChoreoMainAction::PromoteSceneVariation),
+        ScenesAction::CompareSceneVariation { variation } => {
+            Some(ChoreoMainAction::CompareSceneVariation { variation })
+        }
+        ScenesAction::SetRequiredRole {
+            position_indices,
+            role_index,
+        } => Some(ChoreoMainAction::SetRequiredRole {
+            position_indices,
+            role_index,
+        }),
         ScenesAction::LoadScenes { .. }
         | ScenesAction::ReloadScenes
         | ScenesAction::SelectSceneFromAudioPosition { .. }
@@ -1,5 +1,6 @@
 mod layout;
 mod mappings;
+mod rehearsal;
 mod scene_pane;
 mod top_bar;
 
@@ -0,0 +1,170 @@
+use egui::Image;
+use egui::Response;
+use egui::Ui;
+use egui::Vec2b;
+use egui::vec2;
+use egui_material3::MaterialButton;
+
+use crate::audio_player::actions::AudioPlayerAction;
+use crate::choreo_main::actions::ChoreoMainAction;
+use crate::choreo_main::reducer::rehearsal_loop_bounds;
+use crate::choreo_main::state::ChoreoMainState;
+use crate::choreo_main::translations::rehearsal_loop_translations;
+use crate::choreo_main::translations::rehearsal_status_text;
+use crate::material::components;
+use crate::material::components::MaterialScrollArea;
+use crate::material::components::number_picker;
+use crate::material::components::number_picker::NumberPickerUiState;
+
+use super::layout::GRID_12_PX;
+
+const DEFAULT_LOCALE: &str = "en";
+const SCENE_DROPDOWN_WIDTH_PX: f32 = 180.0;
+const SCENE_DROPDOWN_ITEM_HEIGHT_PX: f32 = 40.0;
+const SECONDS_PICKER_WIDTH_PX: f32 = 320.0;
+const MAXIMUM_GAP_SECONDS: i32 = 60;
+
+pub(super) fn draw_toggle_button(ui: &mut Ui, is_open: bool) -> Response {
+    let strings = rehearsal_loop_translations(DEFAULT_LOCALE);
+    components::top_bar_icon_button(ui, repeat_image(), is_open).on_hover_text(strings.title)
+}
+
+pub(super) fn draw(ui: &mut Ui, state: &ChoreoMainState) -> Vec<ChoreoMainAction> {
+    let mut actions = Vec::new();
+    let strings = rehearsal_loop_translations(DEFAULT_LOCALE);
+    let audio = &state.audio_player_state;
+    let scene_names = state
+        .scenes
+        .iter()
+        .map(|scene| scene.name.as_str())
+        .collect::<Vec<_>>();
+    let start_index = state
+        .rehearsal_start_scene_index
+        .filter(|index| *index < scene_names.len());
+    let end_index = state
+        .rehearsal_end_scene_index
+        .filter(|index| *index < scene_names.len());
+    let is_running = audio.rehearsal_loop.is_some();
+
+    MaterialScrollArea::new(Vec2b::new(true, false))
+        .id_salt("main_page_rehearsal_row")
+        .show(ui, |ui| {
+            ui.horizontal_centered(|ui| {
+                ui.spacing_mut().item_spacing.x = GRID_12_PX;
+                ui.add_space(GRID_12_PX);
+
+                let _ = ui.label(strings.from_scene.as_str());
+                if let Some(index) = components::mode_dropdown(
+                    ui,
+                    egui::Id::new("main_page_rehearsal_start_scene"),
+                    start_index,
+                    &scene_names,
+                    !is_running,
+                    SCENE_DROPDOWN_WIDTH_PX,
+                    SCENE_DROPDOWN_ITEM_HEIGHT_PX,
+                ) && Some(index) != start_index
+                {
+                    actions.push(ChoreoMainAction::UpdateRehearsalScenes {
+                        start_scene_index: Some(index),
+                        end_scene_index: end_index,
+                    });
+                }
+
+                let _ = ui.label(strings.to_scene.as_str());
+                if let Some(index) = components::mode_dropdown(
+                    ui,
+                    egui::Id::new("main_page_rehearsal_end_scene"),
+                    end_index,
+                    &scene_names,
+                    !is_running,
+                    SCENE_DROPDOWN_WIDTH_PX,
+                    SCENE_DROPDOWN_ITEM_HEIGHT_PX,
+                ) && Some(index) != end_index
+                {
+                    actions.push(ChoreoMainAction::UpdateRehearsalScenes {
+                        start_scene_index: start_index,
+                        end_scene_index: Some(index),
+                    });
+                }
+
+                if let Some(seconds) =
+                    seconds_picker(ui, &strings.count_in_seconds, audio.preparation_seconds)
+                {
+                    actions.push(ChoreoMainAction::AudioPlayerAction(
+                        AudioPlayerAction::UpdatePreparationSeconds {
+                            seconds: f64::from(seconds),
+                        },
+                    ));
+                }
+                if let Some(seconds) =
+                    seconds_picker(ui, &strings.pause_seconds, audio.pause_seconds)
+                {
+                    actions.push(ChoreoMainAction::AudioPlayerAction(
+                        AudioPlayerAction::UpdatePauseSeconds {
+                            seconds: f64::from(seconds),
+                        },
+                    ));
+                }
+
+                if is_running {
+                    if ui
+                        .add(MaterialButton::new(strings.stop_loop.as_str()))
+                        .clicked()
+                    {
+                        actions.push(ChoreoMainAction::AudioPlayerAction(
+                            AudioPlayerAction::StopRehearsalLoop,
+                        ));
+                    }
+                } else {
+                    let range = start_index.zip(end_index).filter(|(start, end)| {
+                        audio.has_player && rehearsal_loop_bounds(state, *start, *end).is_some()
+                    });
+                    let response = ui
+                        .add_enabled(
+                            range.is_some(),
+                            MaterialButton::new(strings.start_loop.as_str()),
+                        )
+                        .on_disabled_hover_text(strings.missing_timestamps.as_str());
+                    if let Some((start_scene_index, end_scene_index)) = range
+                        && response.clicked()
+                    {
+                        actions.push(ChoreoMainAction::StartRehearsalLoop {
+                            start_scene_index,
+                            end_scene_index,
+                        });
+                    }
+                }
+
+                if let Some(status) = audio
+                    .rehearsal_loop
+                    .as_ref()
+                    .and_then(|rehearsal| rehearsal_status_text(DEFAULT_LOCALE, rehearsal))
+                {
+                    let _ = ui.strong(status);
+                }
+                ui.add_space(GRID_12_PX);
+            });
+        });
+    actions
+}
+
+fn seconds_picker(ui: &mut Ui, label: &str, seconds: f64) -> Option<i32> {
+    ui.allocate_ui(vec2(SECONDS_PICKER_WIDTH_PX, ui.available_height()), |ui| {
+        number_picker::draw(
+            ui,
+            NumberPickerUiState {
+                label,
+                value: seconds.round() as i32,
+                minimum: 0,
+                maximum: MAXIMUM_GAP_SECONDS,
+                step: 1,
+                enabled: true,
+            },
+        )
+    })
+    .inner
+}
+
+fn repeat_image() -> Image<'static> {
+    Image::new(egui::include_image!("../../assets/icons/Repeat.svg"))
+}
@@ -10,9 +10,13 @@ use crate::choreo_main::state::InteractionMode;
 use crate::material::styling::material_palette::material_palette_for_visuals;
 use crate::scene_list_item::SceneItemState;
 use crate::scenes;
+use crate::scenes::auto_assign::shared_required_role;
 use crate::scenes::state::ScenesState;
-use crate::scenes::state::format_seconds;
 use crate::scenes::state::parse_timestamp_seconds;
+use crate::scenes::transition_paths::count_colliding_pairs;
+use crate::scenes::variations::variation_anchor;
+use crate::scenes::variations::variation_names;
+use crate::time::counts::format_scene_time;
 use choreo_master_mobile_json::Color;
 use choreo_master_mobile_json::SceneId;
 
@@ -74,7 +78,7 @@ pub fn scene_pane_state(state: &ChoreoMainState) -> ScenesState {
                 text: String::new(),
                 fixed_positions: false,
                 timestamp: scene.timestamp_seconds,
-                is_selected: state.selected_scene_index == Some(index),
+                is_selected: is_scene_selected(state, index),
                 positions: Vec::new(),
                 variation_depth: 0,
                 variations: Vec::new(),
@@ -96,11 +100,23 @@ pub fn scene_pane_state(state: &ChoreoMainState) -> ScenesState {
     let selected_scene = state
         .selected_scene_index
         .and_then(|index| scenes.get(index).cloned());
+    let scene_variations = selected_scene_variations(state);
+    let selected_scene_ids = state
+        .selected_scene_indices
+        .iter()
+        .filter_map(|index| scenes.get(*index))
+        .map(|scene| scene.scene_id)
+        .collect();
+    let can_shift_scene_timestamps = scenes
+        .iter()
+        .enumerate()
+        .any(|(index, scene)| scene.timestamp.is_some() && is_scene_selected(state, index));
     let mut pane_state = ScenesState {
         choreography: choreo_models::ChoreographyModel::default(),
         scenes,
         visible_scenes,
         selected_scene: selected_scene.clone(),
+        selected_scene_ids,
         search_text: state.scene_search_text.clone(),
         show_timestamps: state.choreography_settings_state.show_timestamps
             || state
@@ -108,12 +124,52 @@ pub fn scene_pane_state(state: &ChoreoMainState) -> ScenesState {
                 .choreography
                 .settings
                 .show_timestamps,
+        musical_timeline: state.choreography_settings_state.musical_timeline,
         is_place_mode: state.interaction_mode != InteractionMode::View,
         can_save_choreo: can_save_choreo(state),
+        can_save_choreo_as: has_choreography(state),
         can_delete_scene: selected_scene.is_some(),
+        can_duplicate_scene: selected_scene.is_some(),
+        can_reorder_scenes: state.scene_search_text.trim().is_empty(),
+        can_shift_scene_timestamps,
+        can_apply_formation: selected_scene.is_some(),
+        can_save_formation_template: selected_scene
+            .as_ref()
+            .is_some_and(|scene| !scene.positions.is_empty()),
+        formation_choice: state.formation_choice,
+        formation_parameters: state.formation_parameters,
+        formation_templates: state.formation_templates.clone(),
+        grid_resolution: state.choreography_settings_state.grid_resolution(),
+        can_auto_assign_dancers: can_auto_assign_dancers(state),
+        can_insert_in_between_scene: state
+            .selected_scene_index
+            .is_some_and(|index| index + 1 < state.scenes.len()),
+        can_set_required_role: selected_scene.is_some()
+            && !state.floor_state.selected_positions.is_empty()
+            && !state
+                .choreography_settings_state
+                .choreography
+                .roles
+                .is_empty(),
+        can_bend_colliding_paths: can_bend_colliding_paths(state),
+        can_branch_scene_variation: selected_scene.is_some(),
+        can_promote_scene_variation: !scene_variations.is_empty(),
+        compared_scene_variation: state
+            .compared_scene_variation
+            .filter(|variation| variation + 1 < scene_variations.len()),
+        scene_variations,
         can_navigate_to_settings: true,
         can_navigate_to_dancer_settings: true,
         has_selected_scene: selected_scene.is_some(),
+        selected_position_indices: state.floor_state.selected_positions.clone(),
+        required_role_names: state
+            .choreography_settings_state
+            .choreography
+            .roles
+            .iter()
+            .map(|role| role.name.clone())
+            .collect(),
+        selected_required_role: selected_required_role(state),
         ..ScenesState::default()
     };
     if let Some(selected_scene) = selected_scene {
@@ -122,21 +178,75 @@ pub fn scene_pane_state(state: &ChoreoMainState) -> ScenesState {
         pane_state.selected_scene_fixed_positions = selected_scene.fixed_positions;
         pane_state.selected_scene_timestamp_text = selected_scene
             .timestamp
-            .map(format_seconds)
+            .map(|seconds| format_scene_time(seconds, pane_state.musical_timeline.as_ref()))
             .unwrap_or_default();
         pane_state.selected_scene_color = selected_scene.color;
     }
     pane_state
 }
 
+fn is_scene_selected(state: &ChoreoMainState, index: usize) -> bool {
+    state.selected_scene_index == Some(index) || state.selected_scene_indices.contains(&index)
+}
+
 fn can_save_choreo(state: &ChoreoMainState) -> bool {
     let has_file = state
         .last_opened_choreo_file
         .as_ref()
         .is_some_and(|path| !path.trim().is_empty() && std::path::Path::new(path).exists());
+    has_file && has_choreography(state)
+}
+
+fn has_choreography(state: &ChoreoMainState) -> bool {
+    let choreography = &state.choreography_settings_state.choreography;
+    !choreography.name.is_empty() || !choreography.scenes.is_empty()
+}
+
+fn can_auto_assign_dancers(state: &ChoreoMainState) -> bool {
+    let scenes = &state.choreography_settings_state.choreography.scenes;
+    state
+        .selected_scene_index
+        .filter(|index| *index > 0)
+        .and_then(|index| scenes.get(index))
+        .is_some_and(|scene| {
+            scene
+                .positions
+                .iter()
+                .any(|position| position.dancer.is_none())
+        })
+}
+
+fn can_bend_colliding_paths(state: &ChoreoMainState) -> bool {
+    let choreography = &state.choreography_settings_state.choreography;
+    let Some(index) = state.selected_scene_index else {
+        return false;
+    };
+    let (Some(current), Some(next)) = (
+        choreography.scenes.get(index),
+        choreography.scenes.get(index + 1),
+    ) else {
+        return false;
+    };
+    count_colliding_pairs(current, next, choreography.settings.dancer_size) > 0
+}
+
+fn selected_scene_variations(state: &ChoreoMainState) -> Vec<Option<String>> {
+    let scenes = &state.choreography_settings_state.choreography.scenes;
+    state
+        .selected_scene_index
+        .and_then(|index| variation_anchor(scenes, index))
+        .map(|anchor| variation_names(scenes, anchor))
+        .unwrap_or_default()
+}
+
+fn selected_required_role(state: &ChoreoMainState) -> Option<usize> {
     let choreography = &state.choreography_settings_state.choreography;
-    let has_choreo = !choreography.name.is_empty() || !choreography.scenes.is_empty();
-    has_file && has_choreo
+    let scene = choreography.scenes.get(state.selected_scene_index?)?;
+    shared_required_role(
+        &choreography.roles,
+        scene,
+        &state.floor_state.selected_positions,
+    )
 }
 
 fn filter_scene_items(scenes: &[SceneItemState], search_text: &str) -> Vec<SceneItemState> {
@@ -163,9 +273,9 @@ fn project_scene_list_item(
         text: scene.text.clone().unwrap_or_default(),
         fixed_positions: scene.fixed_positions,
         timestamp: scene.timestamp.as_deref().and_then(parse_timestamp_seconds),
-        is_selected: state.selected_scene_index == Some(index),
+        is_selected: is_scene_selected(state, index),
         positions: Vec::new(),
-        variation_depth: 0,
+        variation_depth: scene.variation_depth,
         variations: Vec::new(),
         current_variation: Vec::new(),
         color: scene.color.clone(),
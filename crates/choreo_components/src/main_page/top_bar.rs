@@ -1,15 +1,23 @@
 use egui::Layout;
+use egui::RichText;
 use egui::Ui;
 use egui::vec2;
 
 use crate::choreo_main::actions::ChoreoMainAction;
 use crate::choreo_main::state::ChoreoMainState;
 use crate::choreo_main::state::InteractionMode;
+use crate::floor::actions::FloorAction;
+use crate::floor::state::Alignment;
+use crate::floor::state::Distribution;
+use crate::floor::state::MirrorAxis;
 use crate::material::components;
 use crate::material::components::hamburger_toggle_button;
+use crate::material::components::top_bar_icon::top_bar_icon_button_enabled;
 use crate::material::icons as ui_icons;
 use crate::material::icons::UiIconKey;
+use crate::material::styling::material_palette::material_palette_for_visuals;
 use crate::material::styling::material_typography::TypographyRole;
+use crate::nav_bar::translations::NavBarTranslations;
 use crate::nav_bar::translations::mode_text;
 use crate::nav_bar::translations::nav_bar_translations;
 
@@ -22,6 +30,8 @@ use super::mappings::top_bar_settings_action;
 const DEFAULT_LOCALE: &str = "en";
 const MODE_SELECTOR_WIDTH_PX: f32 = 180.0;
 const MODE_SELECTOR_HEIGHT_PX: f32 = 56.0;
+const UNSAVED_CHANGES_MARKER: &str = "●";
+const UNSAVED_CHANGES_MARKER_SIZE_PX: f32 = 12.0;
 
 pub(super) fn draw_top_bar(
     ui: &mut Ui,
@@ -43,6 +53,16 @@ pub(super) fn draw_top_bar(
         if nav_response.clicked() {
             actions.push(top_bar_nav_action(state.is_nav_open));
         }
+        if state.has_unsaved_changes() {
+            let palette = material_palette_for_visuals(ui.visuals());
+            let _ = ui
+                .label(
+                    RichText::new(UNSAVED_CHANGES_MARKER)
+                        .size(UNSAVED_CHANGES_MARKER_SIZE_PX)
+                        .color(palette.primary),
+                )
+                .on_hover_text(strings.unsaved_changes_tooltip.as_str());
+        }
         ui.with_layout(Layout::right_to_left(egui::Align::Center), |ui| {
             ui.add_space(8.0);
 
@@ -88,6 +108,42 @@ pub(super) fn draw_top_bar(
             }
             let _ = settings_response.on_hover_text(strings.open_settings_tooltip.as_str());
 
+            let redo_response = top_bar_icon_button_enabled(
+                ui,
+                components::icon_image(components::TopBarIcon::Redo),
+                false,
+                state.history.can_redo(),
+            );
+            if redo_response.clicked() {
+                actions.push(ChoreoMainAction::Redo);
+            }
+            let _ = redo_response.on_hover_text(strings.redo_tooltip.as_str());
+
+            let undo_response = top_bar_icon_button_enabled(
+                ui,
+                components::icon_image(components::TopBarIcon::Undo),
+                false,
+                state.history.can_undo(),
+            );
+            if undo_response.clicked() {
+                actions.push(ChoreoMainAction::Undo);
+            }
+            let _ = undo_response.on_hover_text(strings.undo_tooltip.as_str());
+
+            let arrange_response = ui
+                .add_enabled_ui(!state.floor_state.selected_positions.is_empty(), |ui| {
+                    ui.menu_button(strings.arrange_label.as_str(), |ui| {
+                        for (label, action) in arrange_menu_entries(&strings) {
+                            if ui.button(label).clicked() {
+                                actions.push(ChoreoMainAction::FloorAction(action));
+                            }
+                        }
+                    })
+                })
+                .inner
+                .response;
+            let _ = arrange_response.on_hover_text(strings.arrange_tooltip.as_str());
+
             let previous_mode_index =
                 effective_mode_index(state).clamp(0, mode_count() - 1) as usize;
             let selected_mode_index = components::mode_dropdown(
@@ -120,6 +176,16 @@ pub fn top_bar_settings_icon_name() -> &'static str {
     top_bar_settings_icon_spec().token
 }
 
+#[must_use]
+pub fn undo_icon_name() -> &'static str {
+    undo_icon_spec().token
+}
+
+#[must_use]
+pub fn redo_icon_name() -> &'static str {
+    redo_icon_spec().token
+}
+
 #[must_use]
 pub fn home_icon_name() -> &'static str {
     home_icon_spec().token
@@ -189,13 +255,15 @@ pub const fn mode_label_role() -> TypographyRole {
 
 #[must_use]
 pub fn top_bar_action_count() -> usize {
-    6
+    8
 }
 
 #[must_use]
-pub fn top_bar_action_icon_tokens(is_nav_open: bool) -> [&'static str; 5] {
+pub fn top_bar_action_icon_tokens(is_nav_open: bool) -> [&'static str; 7] {
     [
         nav_icon_name(is_nav_open),
+        undo_icon_name(),
+        redo_icon_name(),
         top_bar_settings_icon_name(),
         home_icon_name(),
         open_image_icon_name(),
@@ -204,8 +272,10 @@ pub fn top_bar_action_icon_tokens(is_nav_open: bool) -> [&'static str; 5] {
 }
 
 #[must_use]
-pub const fn top_bar_action_icon_uris() -> [&'static str; 4] {
+pub const fn top_bar_action_icon_uris() -> [&'static str; 6] {
     [
+        components::icon_uri(components::TopBarIcon::Undo),
+        components::icon_uri(components::TopBarIcon::Redo),
         components::icon_uri(components::TopBarIcon::Settings),
         components::icon_uri(components::TopBarIcon::Home),
         components::icon_uri(components::TopBarIcon::Image),
@@ -213,10 +283,75 @@ pub const fn top_bar_action_icon_uris() -> [&'static str; 4] {
     ]
 }
 
+/// The align, distribute and mirror entries of the arrange menu, in the order they are listed.
 #[must_use]
-pub fn translated_mode_labels(
-    strings: &crate::nav_bar::translations::NavBarTranslations,
-) -> [&str; 6] {
+pub fn arrange_menu_entries(strings: &NavBarTranslations) -> [(&str, FloorAction); 10] {
+    [
+        (
+            strings.arrange_align_left.as_str(),
+            FloorAction::AlignSelected {
+                alignment: Alignment::Left,
+            },
+        ),
+        (
+            strings.arrange_align_right.as_str(),
+            FloorAction::AlignSelected {
+                alignment: Alignment::Right,
+            },
+        ),
+        (
+            strings.arrange_align_front.as_str(),
+            FloorAction::AlignSelected {
+                alignment: Alignment::Front,
+            },
+        ),
+        (
+            strings.arrange_align_back.as_str(),
+            FloorAction::AlignSelected {
+                alignment: Alignment::Back,
+            },
+        ),
+        (
+            strings.arrange_align_stage_center.as_str(),
+            FloorAction::AlignSelected {
+                alignment: Alignment::StageCenter,
+            },
+        ),
+        (
+            strings.arrange_distribute_along_x.as_str(),
+            FloorAction::DistributeSelected {
+                distribution: Distribution::AlongX,
+            },
+        ),
+        (
+            strings.arrange_distribute_along_y.as_str(),
+            FloorAction::DistributeSelected {
+                distribution: Distribution::AlongY,
+            },
+        ),
+        (
+            strings.arrange_distribute_on_circle.as_str(),
+            FloorAction::DistributeSelected {
+                distribution: Distribution::Circle,
+            },
+        ),
+        (
+            strings.arrange_mirror_left_right.as_str(),
+            FloorAction::MirrorSelected {
+                axis: MirrorAxis::LeftRight,
+            },
+        ),
+        (
+            strings.arrange_mirror_front_back.as_str(),
+            FloorAction::MirrorSelected {
+                axis: MirrorAxis::FrontBack,
+            },
+        ),
+    ]
+}
+
+#[must_use]
+pub fn translated_mode_labels(strings: &NavBarTranslations) -> [&str; 9] {
     [
         mode_text(strings, crate::nav_bar::state::InteractionMode::View),
         mode_text(strings, crate::nav_bar::state::InteractionMode::Move),
@@ -230,6 +365,9 @@ pub fn translated_mode_labels(
         ),
         mode_text(strings, crate::nav_bar::state::InteractionMode::Scale),
         mode_text(strings, crate::nav_bar::state::InteractionMode::LineOfSight),
+        mode_text(strings, crate::nav_bar::state::InteractionMode::Face),
+        mode_text(strings, crate::nav_bar::state::InteractionMode::EditPath),
+        mode_text(strings, crate::nav_bar::state::InteractionMode::BendPath),
     ]
 }
 
@@ -245,6 +383,14 @@ fn top_bar_settings_icon_spec() -> ui_icons::UiIconSpec {
     ui_icons::icon(UiIconKey::NavSettings)
 }
 
+fn undo_icon_spec() -> ui_icons::UiIconSpec {
+    ui_icons::icon(UiIconKey::NavUndo)
+}
+
+fn redo_icon_spec() -> ui_icons::UiIconSpec {
+    ui_icons::icon(UiIconKey::NavRedo)
+}
+
 fn home_icon_spec() -> ui_icons::UiIconSpec {
     ui_icons::icon(UiIconKey::FloorResetViewport)
 }
@@ -269,5 +415,8 @@ fn effective_mode_index(state: &ChoreoMainState) -> i32 {
         InteractionMode::RotateAroundDancer => 3,
         InteractionMode::Scale => 4,
         InteractionMode::LineOfSight => 5,
+        InteractionMode::Face => 6,
+        InteractionMode::EditPath => 7,
+        InteractionMode::BendPath => 8,
     }
 }
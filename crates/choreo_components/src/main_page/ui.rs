@@ -18,6 +18,7 @@ pub use super::layout::audio_panel_height_px;
 pub use super::layout::audio_panel_rect;
 pub use super::layout::drawer_host_rect;
 pub use super::layout::drawer_host_state;
+pub use super::layout::rehearsal_panel_height_px;
 pub use super::layout::shell_rect;
 pub use super::layout::top_bar_rect;
 pub use super::mappings::map_audio_host_action;
@@ -29,6 +30,7 @@ pub use super::mappings::top_bar_nav_action;
 pub use super::mappings::top_bar_open_audio_action;
 pub use super::mappings::top_bar_settings_action;
 pub use super::scene_pane::scene_pane_state;
+pub use super::top_bar::arrange_menu_entries;
 pub use super::top_bar::home_icon_name;
 pub use super::top_bar::home_icon_svg;
 pub use super::top_bar::mode_count;
@@ -40,15 +42,19 @@ pub use super::top_bar::open_audio_icon_name;
 pub use super::top_bar::open_audio_icon_svg;
 pub use super::top_bar::open_image_icon_name;
 pub use super::top_bar::open_image_icon_svg;
+pub use super::top_bar::redo_icon_name;
 pub use super::top_bar::top_bar_action_count;
 pub use super::top_bar::top_bar_action_icon_tokens;
 pub use super::top_bar::top_bar_action_icon_uris;
 pub use super::top_bar::top_bar_settings_icon_name;
 pub use super::top_bar::top_bar_settings_icon_svg;
 pub use super::top_bar::translated_mode_labels;
+pub use super::top_bar::undo_icon_name;
 
 use super::layout::AUDIO_PANEL_HEIGHT_PX;
 use super::layout::GRID_12_PX;
+use super::layout::REHEARSAL_PANEL_HEIGHT_PX;
+use super::rehearsal;
 use super::scene_pane::draw_scenes_drawer;
 use super::top_bar::draw_top_bar;
 
@@ -75,7 +81,8 @@ pub fn draw(ui: &mut Ui, state: &ChoreoMainState) -> Vec<ChoreoMainAction> {
     let palette = material_palette_for_visuals(ui.visuals());
     ui.spacing_mut().item_spacing = vec2(GRID_12_PX, GRID_12_PX);
     let page_rect = shell_rect(ui);
-    let audio_panel_height = audio_panel_height_px(state.is_audio_player_open);
+    let audio_panel_height = audio_panel_height_px(state.is_audio_player_open)
+        + rehearsal_panel_height_px(state.is_audio_player_open, state.is_rehearsal_panel_open);
     let top_bar_rect = top_bar_rect(page_rect);
     let drawer_host_rect = drawer_host_rect(page_rect, audio_panel_height);
     egui::Area::new(egui::Id::new("main_page_top_bar"))
@@ -270,10 +277,24 @@ fn draw_audio_host(ui: &mut Ui, state: &ChoreoMainState) -> Vec<ChoreoMainAction
             ui.set_width(host_width);
             ui.set_min_width(host_width);
             ui.set_min_height(AUDIO_PANEL_HEIGHT_PX);
+            if rehearsal::draw_toggle_button(ui, state.is_rehearsal_panel_open).clicked() {
+                actions.push(ChoreoMainAction::ToggleRehearsalPanel);
+            }
             for action in audio_player::ui::draw(ui, &state.audio_player_state) {
                 actions.extend(map_audio_host_action(action));
             }
         },
     );
+    if state.is_rehearsal_panel_open {
+        ui.allocate_ui_with_layout(
+            vec2(host_width, REHEARSAL_PANEL_HEIGHT_PX),
+            Layout::left_to_right(egui::Align::Center),
+            |ui| {
+                ui.set_width(host_width);
+                ui.set_min_height(REHEARSAL_PANEL_HEIGHT_PX);
+                actions.extend(rehearsal::draw(ui, state));
+            },
+        );
+    }
     actions
 }
@@ -6,15 +6,21 @@ pub enum InteractionMode {
     RotateAroundDancer,
     Scale,
     LineOfSight,
+    Face,
+    EditPath,
+    BendPath,
 }
 
-pub const MODE_OPTIONS: [InteractionMode; 6] = [
+pub const MODE_OPTIONS: [InteractionMode; 9] = [
     InteractionMode::View,
     InteractionMode::Move,
     InteractionMode::RotateAroundCenter,
     InteractionMode::RotateAroundDancer,
     InteractionMode::Scale,
     InteractionMode::LineOfSight,
+    InteractionMode::Face,
+    InteractionMode::EditPath,
+    InteractionMode::BendPath,
 ];
 
 #[must_use]
@@ -9,6 +9,21 @@ pub struct NavBarTranslations {
     pub reset_floor_viewport_tooltip: String,
     pub open_image_tooltip: String,
     pub open_audio_tooltip: String,
+    pub undo_tooltip: String,
+    pub redo_tooltip: String,
+    pub unsaved_changes_tooltip: String,
+    pub arrange_label: String,
+    pub arrange_tooltip: String,
+    pub arrange_align_left: String,
+    pub arrange_align_right: String,
+    pub arrange_align_front: String,
+    pub arrange_align_back: String,
+    pub arrange_align_stage_center: String,
+    pub arrange_distribute_along_x: String,
+    pub arrange_distribute_along_y: String,
+    pub arrange_distribute_on_circle: String,
+    pub arrange_mirror_left_right: String,
+    pub arrange_mirror_front_back: String,
     pub mode_label: String,
     pub mode_view: String,
     pub mode_move: String,
@@ -16,6 +31,9 @@ pub struct NavBarTranslations {
     pub mode_rotate_around_dancer: String,
     pub mode_scale: String,
     pub mode_line_of_sight: String,
+    pub mode_face: String,
+    pub mode_edit_path: String,
+    pub mode_bend_path: String,
 }
 
 #[must_use]
@@ -26,6 +44,21 @@ pub fn nav_bar_translations(locale: &str) -> NavBarTranslations {
         reset_floor_viewport_tooltip: t(locale, "MainHomeTooltip"),
         open_image_tooltip: t(locale, "MainOpenImageTooltip"),
         open_audio_tooltip: t(locale, "MainOpenAudioTooltip"),
+        undo_tooltip: t(locale, "MainUndoTooltip"),
+        redo_tooltip: t(locale, "MainRedoTooltip"),
+        unsaved_changes_tooltip: t(locale, "UnsavedChangesTooltip"),
+        arrange_label: t(locale, "MainArrangeLabel"),
+        arrange_tooltip: t(locale, "MainArrangeTooltip"),
+        arrange_align_left: t(locale, "ArrangeAlignLeft"),
+        arrange_align_right: t(locale, "ArrangeAlignRight"),
+        arrange_align_front: t(locale, "ArrangeAlignFront"),
+        arrange_align_back: t(locale, "ArrangeAlignBack"),
+        arrange_align_stage_center: t(locale, "ArrangeAlignStageCenter"),
+        arrange_distribute_along_x: t(locale, "ArrangeDistributeAlongX"),
+        arrange_distribute_along_y: t(locale, "ArrangeDistributeAlongY"),
+        arrange_distribute_on_circle: t(locale, "ArrangeDistributeOnCircle"),
+        arrange_mirror_left_right: t(locale, "ArrangeMirrorLeftRight"),
+        arrange_mirror_front_back: t(locale, "ArrangeMirrorFrontBack"),
         mode_label: t(locale, "ModeLabel"),
         mode_view: t(locale, "ModeView"),
         mode_move: t(locale, "ModeMove"),
@@ -33,6 +66,9 @@ pub fn nav_bar_translations(locale: &str) -> NavBarTranslations {
         mode_rotate_around_dancer: t(locale, "ModeRotateAroundDancer"),
         mode_scale: t(locale, "ModeScale"),
         mode_line_of_sight: t(locale, "ModeLineOfSight"),
+        mode_face: t(locale, "ModeFace"),
+        mode_edit_path: t(locale, "ModeEditPath"),
+        mode_bend_path: t(locale, "ModeBendPath"),
     }
 }
 
@@ -45,5 +81,8 @@ pub fn mode_text(strings: &NavBarTranslations, mode: InteractionMode) -> &str {
         InteractionMode::RotateAroundDancer => strings.mode_rotate_around_dancer.as_str(),
         InteractionMode::Scale => strings.mode_scale.as_str(),
         InteractionMode::LineOfSight => strings.mode_line_of_sight.as_str(),
+        InteractionMode::Face => strings.mode_face.as_str(),
+        InteractionMode::EditPath => strings.mode_edit_path.as_str(),
+        InteractionMode::BendPath => strings.mode_bend_path.as_str(),
     }
 }
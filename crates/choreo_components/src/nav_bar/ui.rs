@@ -43,6 +43,9 @@ pub fn mode_label(mode: InteractionMode) -> &'static str {
         InteractionMode::RotateAroundDancer => "ModeRotateAroundDancer",
         InteractionMode::Scale => "ModeScale",
         InteractionMode::LineOfSight => "ModeLineOfSight",
+        InteractionMode::Face => "ModeFace",
+        InteractionMode::EditPath => "ModeEditPath",
+        InteractionMode::BendPath => "ModeBendPath",
     }
 }
 
@@ -73,7 +76,7 @@ pub const fn top_bar_action_count() -> usize {
 }
 
 #[must_use]
-pub fn mode_option_labels(strings: &super::translations::NavBarTranslations) -> [&str; 6] {
+pub fn mode_option_labels(strings: &super::translations::NavBarTranslations) -> [&str; 9] {
     [
         mode_text(strings, InteractionMode::View),
         mode_text(strings, InteractionMode::Move),
@@ -81,6 +84,9 @@ pub fn mode_option_labels(strings: &super::translations::NavBarTranslations) ->
         mode_text(strings, InteractionMode::RotateAroundDancer),
         mode_text(strings, InteractionMode::Scale),
         mode_text(strings, InteractionMode::LineOfSight),
+        mode_text(strings, InteractionMode::Face),
+        mode_text(strings, InteractionMode::EditPath),
+        mode_text(strings, InteractionMode::BendPath),
     ]
 }
 
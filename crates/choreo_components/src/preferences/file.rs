@@ -82,12 +82,16 @@ impl Preferences for FilePreferences {
     }
 }
 
-fn resolve_preferences_path(app_name: &str) -> PathBuf {
+pub(super) fn preferences_directory(app_name: &str) -> PathBuf {
     let base_dir = dirs::config_dir()
         .or_else(|| std::env::current_dir().ok())
         .unwrap_or_else(|| PathBuf::from("."));
 
-    base_dir.join(app_name).join("preferences.json")
+    base_dir.join(app_name)
+}
+
+fn resolve_preferences_path(app_name: &str) -> PathBuf {
+    preferences_directory(app_name).join("preferences.json")
 }
 
 fn load_state(path: &Path) -> Option<PreferencesState> {
@@ -96,13 +100,18 @@ fn load_state(path: &Path) -> Option<PreferencesState> {
 }
 
 fn save_state(path: &Path, state: &PreferencesState) -> std::io::Result<()> {
+    let json = serde_json::to_string_pretty(state).map_err(std::io::Error::other)?;
+    write_replacing(path, &json)
+}
+
+/// Writes through a temporary file so a crash never leaves `path` half written.
+pub(super) fn write_replacing(path: &Path, contents: &str) -> std::io::Result<()> {
     if let Some(parent) = path.parent() {
         fs::create_dir_all(parent)?;
     }
 
-    let json = serde_json::to_string_pretty(state).map_err(std::io::Error::other)?;
     let temp_path = path.with_extension("tmp");
-    fs::write(&temp_path, json)?;
+    fs::write(&temp_path, contents)?;
 
     if path.exists() {
         fs::remove_file(path)?;
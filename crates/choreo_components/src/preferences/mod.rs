@@ -1,6 +1,7 @@
 #[cfg(not(target_arch = "wasm32"))]
 mod file;
 mod in_memory;
+mod recovery;
 mod shared;
 mod types;
 #[cfg(target_arch = "wasm32")]
@@ -11,6 +12,9 @@ pub use file::FilePreferences;
 pub use in_memory::InMemoryPreferences;
 #[cfg(not(target_arch = "wasm32"))]
 pub type PlatformPreferences = FilePreferences;
+#[cfg(not(target_arch = "wasm32"))]
+pub use recovery::FileRecoveryStore;
+pub use recovery::{InMemoryRecoveryStore, RecoverySnapshot, RecoveryStore};
 pub use shared::SharedPreferences;
 pub use types::Preferences;
 #[cfg(target_arch = "wasm32")]
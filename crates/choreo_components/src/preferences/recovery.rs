@@ -0,0 +1,110 @@
+use std::cell::RefCell;
+#[cfg(not(target_arch = "wasm32"))]
+use std::fs;
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::Path;
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::PathBuf;
+use std::rc::Rc;
+
+#[cfg(not(target_arch = "wasm32"))]
+use log::warn;
+use serde::Deserialize;
+use serde::Serialize;
+use time::OffsetDateTime;
+
+#[cfg(not(target_arch = "wasm32"))]
+use super::file::preferences_directory;
+#[cfg(not(target_arch = "wasm32"))]
+use super::file::write_replacing;
+
+#[cfg(not(target_arch = "wasm32"))]
+const RECOVERY_FILE_NAME: &str = "recovery.json";
+
+/// Autosaved copy of the open choreography, kept until it is saved or discarded.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecoverySnapshot {
+    pub file_path: Option<String>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub saved_at: OffsetDateTime,
+    pub contents: String,
+}
+
+pub trait RecoveryStore {
+    fn load(&self) -> Option<RecoverySnapshot>;
+    fn store(&self, snapshot: &RecoverySnapshot);
+    fn clear(&self);
+}
+
+#[derive(Clone, Default)]
+pub struct InMemoryRecoveryStore {
+    snapshot: Rc<RefCell<Option<RecoverySnapshot>>>,
+}
+
+impl InMemoryRecoveryStore {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl RecoveryStore for InMemoryRecoveryStore {
+    fn load(&self) -> Option<RecoverySnapshot> {
+        self.snapshot.borrow().clone()
+    }
+
+    fn store(&self, snapshot: &RecoverySnapshot) {
+        *self.snapshot.borrow_mut() = Some(snapshot.clone());
+    }
+
+    fn clear(&self) {
+        *self.snapshot.borrow_mut() = None;
+    }
+}
+
+/// Keeps the snapshot in `recovery.json` next to the [`super::FilePreferences`] file.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Clone)]
+pub struct FileRecoveryStore {
+    path: PathBuf,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl FileRecoveryStore {
+    #[must_use]
+    pub fn new(app_name: &str) -> Self {
+        Self {
+            path: preferences_directory(app_name).join(RECOVERY_FILE_NAME),
+        }
+    }
+
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl RecoveryStore for FileRecoveryStore {
+    fn load(&self) -> Option<RecoverySnapshot> {
+        let content = fs::read_to_string(&self.path).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    fn store(&self, snapshot: &RecoverySnapshot) {
+        let result = serde_json::to_string(snapshot)
+            .map_err(std::io::Error::other)
+            .and_then(|json| write_replacing(&self.path, &json));
+        if let Err(err) = result {
+            warn!("Failed to write recovery snapshot: {err}");
+        }
+    }
+
+    fn clear(&self) {
+        if let Err(err) = fs::remove_file(&self.path)
+            && err.kind() != std::io::ErrorKind::NotFound
+        {
+            warn!("Failed to remove recovery snapshot: {err}");
+        }
+    }
+}
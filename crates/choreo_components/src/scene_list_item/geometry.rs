@@ -13,6 +13,7 @@ const SCENE_ROW_TEXT_LEFT_PX: f32 = 26.0;
 const SCENE_ROW_TITLE_Y_PX: f32 = 8.0;
 const SCENE_ROW_TIMESTAMP_Y_PX: f32 = 30.0;
 const SCENE_ROW_ACCENT_WIDTH_PX: f32 = 4.0;
+const SCENE_ROW_VARIATION_INDENT_PX: f32 = 16.0;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct SceneListItemLayout {
@@ -32,6 +33,12 @@ pub fn row_height_px(show_timestamps: bool) -> f32 {
     }
 }
 
+/// Scenes of a variation are indented one step per level below the main line.
+#[must_use]
+pub(super) fn variation_indent_px(variation_depth: i32) -> f32 {
+    variation_depth.max(0) as f32 * SCENE_ROW_VARIATION_INDENT_PX
+}
+
 #[must_use]
 pub fn layout_for_row_rect(row_rect: Rect, show_timestamps: bool) -> SceneListItemLayout {
     let content_rect = row_rect.shrink2(vec2(0.0, SCENE_ROW_VERTICAL_GAP_PX));
@@ -14,8 +14,10 @@ use crate::material::styling::material_typography as typography;
 use crate::material::styling::material_typography::TypographyRole;
 use crate::scene_list_item::geometry::layout_for_row_rect;
 use crate::scene_list_item::geometry::row_height_px;
+use crate::scene_list_item::geometry::variation_indent_px;
 use crate::scene_list_item::state::SceneItemState;
-use crate::time::format_seconds;
+use crate::time::counts::format_scene_time;
+use choreo_master_mobile_json::MusicalTimeline;
 
 const SCENE_ROW_SWATCH_CORNER_RADIUS_PX: u8 = 4;
 
@@ -64,16 +66,23 @@ pub fn colors_for_selection(palette: MaterialPalette, is_selected: bool) -> Scen
 }
 
 #[must_use]
-pub fn draw(ui: &mut Ui, scene: &SceneItemState, show_timestamps: bool) -> Response {
+pub fn draw(
+    ui: &mut Ui,
+    scene: &SceneItemState,
+    show_timestamps: bool,
+    musical_timeline: Option<&MusicalTimeline>,
+) -> Response {
     let (row_rect, response) = ui.allocate_exact_size(
         vec2(ui.available_width(), row_height_px(show_timestamps)),
-        Sense::click(),
+        Sense::click_and_drag(),
     );
     if !ui.is_rect_visible(row_rect) {
         return response;
     }
 
-    let layout = layout_for_row_rect(row_rect, show_timestamps);
+    let indented_rect =
+        row_rect.with_min_x(row_rect.min.x + variation_indent_px(scene.variation_depth));
+    let layout = layout_for_row_rect(indented_rect, show_timestamps);
     let palette = material_palette_for_visuals(&ui.style().visuals);
     let colors = colors_for_selection(palette, scene.is_selected);
 
@@ -108,7 +117,10 @@ pub fn draw(ui: &mut Ui, scene: &SceneItemState, show_timestamps: bool) -> Respo
     );
 
     if show_timestamps {
-        let timestamp_text = scene.timestamp.map(format_seconds).unwrap_or_default();
+        let timestamp_text = scene
+            .timestamp
+            .map(|seconds| format_scene_time(seconds, musical_timeline))
+            .unwrap_or_default();
         ui.painter().text(
             layout.timestamp_position,
             Align2::LEFT_TOP,
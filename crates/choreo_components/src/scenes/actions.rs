@@ -1,9 +1,13 @@
 use choreo_models::ChoreographyModel;
 
+use super::formations::FormationChoice;
+use super::formations::FormationParameters;
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum ScenesAction {
     RequestOpenChoreography,
     RequestSaveChoreography,
+    RequestSaveChoreographyAs,
     NavigateToSettings,
     NavigateToDancerSettings,
     LoadScenes {
@@ -17,10 +21,57 @@ pub enum ScenesAction {
     SelectScene {
         index: usize,
     },
+    /// Adds a scene to the selection or takes it out again.
+    ToggleSceneSelection {
+        index: usize,
+    },
+    /// Selects every scene from the selected one to `index`.
+    ExtendSceneSelection {
+        index: usize,
+    },
+    /// Drops the scene dragged from `from` at `to`.
+    MoveScene {
+        from: usize,
+        to: usize,
+    },
+    DuplicateScene,
+    /// Moves the timestamps of the selected scenes by `seconds`.
+    ShiftSceneTimestamps {
+        seconds: f64,
+    },
+    SelectFormation {
+        choice: FormationChoice,
+    },
+    UpdateFormationParameters {
+        parameters: FormationParameters,
+    },
+    /// Moves the positions of the selected scene onto the chosen formation.
+    ApplyFormation,
+    /// Saves the positions of the selected scene as a template named after the scene.
+    SaveFormationTemplate,
     SelectSceneFromAudioPosition {
         position_seconds: f64,
     },
     ApplyPlacementModeForSelected,
+    AutoAssignDancers,
+    /// Inserts a scene partway to the next one, on the spots of the selected scene or, with a
+    /// `formation`, on the spots of that formation.
+    InsertInBetweenScene {
+        formation: Option<FormationChoice>,
+    },
+    BendCollidingPaths,
+    BranchSceneVariation,
+    SwitchSceneVariation {
+        variation: usize,
+    },
+    PromoteSceneVariation,
+    CompareSceneVariation {
+        variation: Option<usize>,
+    },
+    SetRequiredRole {
+        position_indices: Vec<usize>,
+        role_index: Option<usize>,
+    },
     SyncShowTimestampsFromChoreography,
     UpdateShowTimestamps(bool),
     OpenDeleteSceneDialog,
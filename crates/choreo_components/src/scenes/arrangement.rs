@@ -0,0 +1,89 @@
+//! Reordering, duplicating and removing scenes, and the multi-selection these work on.
+//!
+//! Selections are scene indices in list order. The scene the selection was started from stays
+//! the selected scene shown on the floor, so extending the selection again starts from it.
+
+use choreo_master_mobile_json::SceneId;
+use choreo_models::CloneMode;
+use choreo_models::SceneModel;
+
+/// Moves the scene at `from` so it ends up at `to`, clamped to the end of the list. Returns the
+/// index the scene ends up at.
+pub fn move_scene(scenes: &mut Vec<SceneModel>, from: usize, to: usize) -> Option<usize> {
+    if from >= scenes.len() {
+        return None;
+    }
+    let scene = scenes.remove(from);
+    let to = to.min(scenes.len());
+    scenes.insert(to, scene);
+    Some(to)
+}
+
+/// Inserts a deep copy of the scene at `index` right after it and returns the copy's index.
+///
+/// The copy gets its own dancers and roles, a name of its own and no timestamp, so it does not
+/// start at the same moment as the original. Variations branched from the original stay with it.
+pub fn duplicate_scene(
+    scenes: &mut Vec<SceneModel>,
+    index: usize,
+    scene_id: SceneId,
+) -> Option<usize> {
+    let original = scenes.get(index)?;
+    let mut copy = original.clone_with(CloneMode::Deep);
+    copy.scene_id = scene_id;
+    copy.name = duplicate_scene_name(scenes, &original.name);
+    copy.timestamp = None;
+    copy.variations.clear();
+    copy.current_variation.clear();
+    scenes.insert(index + 1, copy);
+    Some(index + 1)
+}
+
+/// Removes the scenes at `indices`, ignoring indices past the end. Returns how many were removed.
+pub fn remove_scenes(scenes: &mut Vec<SceneModel>, indices: &[usize]) -> usize {
+    let mut indices = indices
+        .iter()
+        .copied()
+        .filter(|index| *index < scenes.len())
+        .collect::<Vec<_>>();
+    indices.sort_unstable();
+    indices.dedup();
+    for index in indices.iter().rev() {
+        scenes.remove(*index);
+    }
+    indices.len()
+}
+
+/// A timestamp moved by `delta_seconds`, never before the start of the music.
+#[must_use]
+pub fn shift_timestamp(seconds: f64, delta_seconds: f64) -> f64 {
+    (seconds + delta_seconds).max(0.0)
+}
+
+/// Adds `index` to the selection or takes it out again. The last scene is never taken out.
+#[must_use]
+pub fn toggle_selection(selection: &[usize], index: usize) -> Vec<usize> {
+    let mut toggled = selection.to_vec();
+    if let Some(position) = toggled.iter().position(|selected| *selected == index) {
+        if toggled.len() > 1 {
+            toggled.remove(position);
+        }
+    } else {
+        toggled.push(index);
+        toggled.sort_unstable();
+    }
+    toggled
+}
+
+/// Every index from `anchor` to `index`, both included.
+#[must_use]
+pub fn extend_selection(anchor: usize, index: usize) -> Vec<usize> {
+    (anchor.min(index)..=anchor.max(index)).collect()
+}
+
+fn duplicate_scene_name(scenes: &[SceneModel], name: &str) -> String {
+    (2..)
+        .map(|suffix| format!("{name} {suffix}"))
+        .find(|candidate| scenes.iter().all(|scene| scene.name != *candidate))
+        .unwrap_or_else(|| name.to_string())
+}
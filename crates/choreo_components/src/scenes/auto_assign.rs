@@ -0,0 +1,282 @@
+use std::fmt;
+use std::rc::Rc;
+
+use choreo_algorithms::AlgorithmError;
+use choreo_algorithms::Vector2;
+use choreo_algorithms::min_cost_max_flow;
+use choreo_models::DancerModel;
+use choreo_models::PositionModel;
+use choreo_models::RoleModel;
+use choreo_models::SceneModel;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AutoAssignError {
+    NoPreviousScene,
+    NoNextScene,
+    NoUnassignedPositions,
+    CountMismatch { dancers: usize, positions: usize },
+    NoRoleCompatibleAssignment,
+    Algorithm(AlgorithmError),
+}
+
+impl fmt::Display for AutoAssignError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AutoAssignError::NoPreviousScene => {
+                write!(f, "There is no previous scene to assign dancers from.")
+            }
+            AutoAssignError::NoNextScene => {
+                write!(f, "There is no next scene to transition to.")
+            }
+            AutoAssignError::NoUnassignedPositions => {
+                write!(f, "The scene has no unassigned positions.")
+            }
+            AutoAssignError::CountMismatch { dancers, positions } => write!(
+                f,
+                "{dancers} dancer(s) need a position but the scene has {positions} unassigned position(s)."
+            ),
+            AutoAssignError::NoRoleCompatibleAssignment => write!(
+                f,
+                "No assignment places every dancer on a position that allows their role."
+            ),
+            AutoAssignError::Algorithm(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for AutoAssignError {}
+
+impl AutoAssignError {
+    /// Blames the required roles for a failed assignment only when they ruled out at least one
+    /// pairing of a dancer and a position.
+    #[must_use]
+    pub fn from_algorithm(error: AlgorithmError, role_constrained: bool) -> Self {
+        match error {
+            AlgorithmError::NoPerfectAssignment(_) if role_constrained => {
+                AutoAssignError::NoRoleCompatibleAssignment
+            }
+            error => AutoAssignError::Algorithm(error),
+        }
+    }
+}
+
+/// Assigns the dancers of `previous` that are not yet placed in `current` to the
+/// unassigned positions of `current`, minimizing the total travel distance.
+///
+/// Positions with a required role only accept dancers of that role.
+/// `current` is only modified when a complete assignment was found.
+/// Returns the number of positions that received a dancer.
+pub fn assign_unplaced_dancers(
+    previous: &SceneModel,
+    current: &mut SceneModel,
+) -> Result<usize, AutoAssignError> {
+    let open_positions = current
+        .positions
+        .iter()
+        .enumerate()
+        .filter(|(_, position)| position.dancer.is_none())
+        .map(|(index, _)| index)
+        .collect::<Vec<_>>();
+    if open_positions.is_empty() {
+        return Err(AutoAssignError::NoUnassignedPositions);
+    }
+
+    let unplaced = previous
+        .positions
+        .iter()
+        .filter_map(|position| {
+            let dancer = position.dancer.as_ref()?;
+            (!is_placed(current, dancer)).then_some((dancer.clone(), position))
+        })
+        .collect::<Vec<_>>();
+    if unplaced.len() != open_positions.len() {
+        return Err(AutoAssignError::CountMismatch {
+            dancers: unplaced.len(),
+            positions: open_positions.len(),
+        });
+    }
+
+    let initial_points = unplaced
+        .iter()
+        .map(|(_, position)| to_vector(position))
+        .collect::<Vec<_>>();
+    let target_points = open_positions
+        .iter()
+        .map(|index| to_vector(&current.positions[*index]))
+        .collect::<Vec<_>>();
+    let allowed = |dancer_index: usize, target_index: usize| {
+        allows_role(
+            &current.positions[open_positions[target_index]],
+            &unplaced[dancer_index].0,
+        )
+    };
+    let role_constrained = (0..unplaced.len())
+        .any(|dancer_index| (0..open_positions.len()).any(|target| !allowed(dancer_index, target)));
+    let assignment = min_cost_max_flow::solve_assignment_with(
+        &initial_points,
+        &target_points,
+        |distance| distance,
+        allowed,
+    )
+    .map_err(|error| AutoAssignError::from_algorithm(error, role_constrained))?;
+
+    for ((dancer, _), target_index) in unplaced.into_iter().zip(assignment) {
+        current.positions[open_positions[target_index]].dancer = Some(dancer);
+    }
+
+    Ok(open_positions.len())
+}
+
+/// Builds the positions of a scene placed at `fraction` of the transition from `start` to
+/// `end`, using the coordinates of `spots`.
+///
+/// Every dancer placed in both scenes takes the spot closest to where its straight path
+/// would be at `fraction`, with the assignment solved over all dancers at once. Spots with a
+/// required role only accept dancers of that role.
+pub fn build_in_between_positions(
+    start: &SceneModel,
+    end: &SceneModel,
+    spots: &[PositionModel],
+    fraction: f64,
+) -> Result<Vec<PositionModel>, AutoAssignError> {
+    let travelling = start
+        .positions
+        .iter()
+        .filter_map(|from| {
+            let dancer = from.dancer.as_ref()?;
+            let to = end.positions.iter().find(|position| {
+                position
+                    .dancer
+                    .as_ref()
+                    .is_some_and(|other| other.dancer_id == dancer.dancer_id)
+            })?;
+            Some((dancer.clone(), from, to))
+        })
+        .collect::<Vec<_>>();
+    if travelling.len() != spots.len() {
+        return Err(AutoAssignError::CountMismatch {
+            dancers: travelling.len(),
+            positions: spots.len(),
+        });
+    }
+
+    let start_points = travelling
+        .iter()
+        .map(|(_, from, _)| to_vector(from))
+        .collect::<Vec<_>>();
+    let spot_points = spots.iter().map(to_vector).collect::<Vec<_>>();
+    let end_points = travelling
+        .iter()
+        .map(|(_, _, to)| to_vector(to))
+        .collect::<Vec<_>>();
+    let allowed = |dancer_index: usize, spot_index: usize| {
+        allows_role(&spots[spot_index], &travelling[dancer_index].0)
+    };
+    let role_constrained = (0..travelling.len())
+        .any(|dancer_index| (0..spots.len()).any(|spot| !allowed(dancer_index, spot)));
+    let assignment = min_cost_max_flow::solve_constrained_three_scene_assignment(
+        &start_points,
+        &spot_points,
+        &end_points,
+        fraction.clamp(0.0, 1.0) as f32,
+        allowed,
+    )
+    .map_err(|error| AutoAssignError::from_algorithm(error, role_constrained))?;
+
+    let mut positions = spots.iter().map(empty_spot).collect::<Vec<_>>();
+    for ((dancer, _, _), spot_index) in travelling.into_iter().zip(assignment) {
+        positions[spot_index].dancer = Some(dancer);
+    }
+    Ok(positions)
+}
+
+/// Returns the transition fraction and timestamp for a scene inserted between two scenes.
+///
+/// When both scenes have timestamps, `requested_seconds` is used if it lies strictly between
+/// them; otherwise the new scene is placed halfway.
+#[must_use]
+pub fn in_between_timing(
+    start_seconds: Option<f64>,
+    end_seconds: Option<f64>,
+    requested_seconds: Option<f64>,
+) -> (f64, Option<f64>) {
+    let (Some(start_seconds), Some(end_seconds)) = (start_seconds, end_seconds) else {
+        return (0.5, None);
+    };
+    let duration = end_seconds - start_seconds;
+    if duration <= 0.0 {
+        return (0.5, None);
+    }
+
+    match requested_seconds.filter(|seconds| *seconds > start_seconds && *seconds < end_seconds) {
+        Some(seconds) => ((seconds - start_seconds) / duration, Some(seconds)),
+        None => (0.5, Some(start_seconds + duration * 0.5)),
+    }
+}
+
+fn empty_spot(spot: &PositionModel) -> PositionModel {
+    PositionModel {
+        dancer: None,
+        required_role: spot.required_role.clone(),
+        orientation: None,
+        x: spot.x,
+        y: spot.y,
+        curve1_x: None,
+        curve1_y: None,
+        curve2_x: None,
+        curve2_y: None,
+        movement1_x: None,
+        movement1_y: None,
+        movement2_x: None,
+        movement2_y: None,
+    }
+}
+
+/// Returns whether `dancer` may take `position`, honoring the position's required role.
+#[must_use]
+pub fn allows_role(position: &PositionModel, dancer: &DancerModel) -> bool {
+    position
+        .required_role
+        .as_ref()
+        .is_none_or(|role| Rc::ptr_eq(role, &dancer.role) || **role == *dancer.role)
+}
+
+/// Sets the required role of the positions at `indices`; `None` accepts dancers of any role.
+pub fn set_required_role(scene: &mut SceneModel, indices: &[usize], role: Option<&Rc<RoleModel>>) {
+    for index in indices {
+        if let Some(position) = scene.positions.get_mut(*index) {
+            position.required_role = role.cloned();
+        }
+    }
+}
+
+/// Returns the index in `roles` required by every position at `indices`, if they all agree.
+#[must_use]
+pub fn shared_required_role(
+    roles: &[Rc<RoleModel>],
+    scene: &SceneModel,
+    indices: &[usize],
+) -> Option<usize> {
+    let mut required = indices
+        .iter()
+        .map(|index| scene.positions.get(*index)?.required_role.as_ref());
+    let first = required.next()??;
+    if !required.all(|role| role.is_some_and(|role| role == first)) {
+        return None;
+    }
+    roles
+        .iter()
+        .position(|role| Rc::ptr_eq(role, first) || role == first)
+}
+
+fn is_placed(scene: &SceneModel, dancer: &Rc<DancerModel>) -> bool {
+    scene.positions.iter().any(|position| {
+        position.dancer.as_ref().is_some_and(|placed| {
+            Rc::ptr_eq(placed, dancer) || placed.dancer_id == dancer.dancer_id
+        })
+    })
+}
+
+fn to_vector(position: &PositionModel) -> Vector2 {
+    Vector2::new(position.x as f32, position.y as f32)
+}
@@ -0,0 +1,386 @@
+//! Formations generated from a few parameters, and formation templates saved from scenes.
+//!
+//! Generated formations are laid out with a spacing of whole grid steps. Like templates, they are
+//! centered on the floor, shrunk when they do not fit within its bounds and snapped to the grid.
+//! Floor coordinates grow to the right and to the front.
+
+use std::f64::consts::PI;
+
+use choreo_models::FloorModel;
+use choreo_models::PositionModel;
+use choreo_models::SceneModel;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Preference key the saved templates are stored under as JSON.
+pub const FORMATION_TEMPLATES_KEY: &str = "formation_templates";
+pub const MAXIMUM_FORMATION_COUNT: usize = 64;
+pub const MAXIMUM_FORMATION_SPACING_STEPS: u32 = 16;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormationKind {
+    Line,
+    DoubleLine,
+    Wedge,
+    Circle,
+    /// Rows where every other row stands in the gaps of the row in front of it.
+    Windows,
+    Diagonal,
+    Checkerboard,
+}
+
+impl FormationKind {
+    pub const ALL: [FormationKind; 7] = [
+        FormationKind::Line,
+        FormationKind::DoubleLine,
+        FormationKind::Wedge,
+        FormationKind::Circle,
+        FormationKind::Windows,
+        FormationKind::Diagonal,
+        FormationKind::Checkerboard,
+    ];
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FormationParameters {
+    pub count: usize,
+    /// Distance between neighbouring spots in grid steps.
+    pub spacing_steps: u32,
+}
+
+impl Default for FormationParameters {
+    fn default() -> Self {
+        Self {
+            count: 8,
+            spacing_steps: 2,
+        }
+    }
+}
+
+/// A generated formation, or one of the saved templates by index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormationChoice {
+    Generated(FormationKind),
+    Template(usize),
+}
+
+impl Default for FormationChoice {
+    fn default() -> Self {
+        FormationChoice::Generated(FormationKind::Line)
+    }
+}
+
+/// Spots saved from a scene, relative to their center.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FormationTemplate {
+    pub name: String,
+    pub spots: Vec<[f64; 2]>,
+}
+
+impl FormationTemplate {
+    /// A template of the spots `positions` stand on.
+    #[must_use]
+    pub fn from_positions(name: impl Into<String>, positions: &[PositionModel]) -> Self {
+        let spots = positions
+            .iter()
+            .map(|position| (position.x, position.y))
+            .collect::<Vec<_>>();
+        let (center_x, center_y) = SpotBounds::of(&spots).map_or((0.0, 0.0), |bounds| {
+            (
+                (bounds.min_x + bounds.max_x) / 2.0,
+                (bounds.min_y + bounds.max_y) / 2.0,
+            )
+        });
+        Self {
+            name: name.into(),
+            spots: spots
+                .into_iter()
+                .map(|(x, y)| [x - center_x, y - center_y])
+                .collect(),
+        }
+    }
+}
+
+/// The spots of a `kind` formation, fitted on `floor` and snapped to the grid.
+#[must_use]
+pub fn generate_formation(
+    kind: FormationKind,
+    parameters: FormationParameters,
+    floor: &FloorModel,
+    grid_resolution: i32,
+) -> Vec<(f64, f64)> {
+    let count = parameters.count.min(MAXIMUM_FORMATION_COUNT);
+    let spacing = f64::from(parameters.spacing_steps.max(1)) * grid_step(grid_resolution);
+    let spots = match kind {
+        FormationKind::Line => (0..count)
+            .map(|index| (column(index, spacing), 0.0))
+            .collect(),
+        FormationKind::DoubleLine => rows(count, count.div_ceil(2), spacing, spacing, 0.0),
+        FormationKind::Wedge => wedge(count, spacing),
+        FormationKind::Circle => circle(count, spacing),
+        FormationKind::Windows => rows(count, block_columns(count), spacing, spacing, 0.5),
+        FormationKind::Diagonal => (0..count)
+            .map(|index| (column(index, spacing), -column(index, spacing)))
+            .collect(),
+        FormationKind::Checkerboard => {
+            rows(count, block_columns(count), spacing * 2.0, spacing, 0.5)
+        }
+    };
+    fit_to_floor(&spots, floor, grid_resolution)
+}
+
+/// The spots of `template`, fitted on `floor` and snapped to the grid.
+#[must_use]
+pub fn template_formation(
+    template: &FormationTemplate,
+    floor: &FloorModel,
+    grid_resolution: i32,
+) -> Vec<(f64, f64)> {
+    let spots = template
+        .spots
+        .iter()
+        .map(|[x, y]| (*x, *y))
+        .collect::<Vec<_>>();
+    fit_to_floor(&spots, floor, grid_resolution)
+}
+
+/// The spots of `choice`, fitted on `floor`; `None` when it names a template that is not saved.
+#[must_use]
+pub fn formation_spots(
+    choice: FormationChoice,
+    parameters: FormationParameters,
+    templates: &[FormationTemplate],
+    floor: &FloorModel,
+    grid_resolution: i32,
+) -> Option<Vec<(f64, f64)>> {
+    match choice {
+        FormationChoice::Generated(kind) => {
+            Some(generate_formation(kind, parameters, floor, grid_resolution))
+        }
+        FormationChoice::Template(index) => templates
+            .get(index)
+            .map(|template| template_formation(template, floor, grid_resolution)),
+    }
+}
+
+/// Positions without a dancer on each of `spots`.
+#[must_use]
+pub fn spot_positions(spots: &[(f64, f64)]) -> Vec<PositionModel> {
+    spots.iter().map(|(x, y)| empty_position(*x, *y)).collect()
+}
+
+/// Moves the positions of `scene` onto `spots` in order, keeping their dancers and roles.
+///
+/// Spots left over get new positions without a dancer and positions left over are removed.
+/// Movement waypoints and path bends are cleared, since they were laid out for the old spots.
+pub fn arrange_positions(scene: &mut SceneModel, spots: &[(f64, f64)]) {
+    scene.positions.truncate(spots.len());
+    for (index, (x, y)) in spots.iter().copied().enumerate() {
+        match scene.positions.get_mut(index) {
+            Some(position) => {
+                position.x = x;
+                position.y = y;
+                (position.curve1_x, position.curve1_y) = (None, None);
+                (position.curve2_x, position.curve2_y) = (None, None);
+                (position.movement1_x, position.movement1_y) = (None, None);
+                (position.movement2_x, position.movement2_y) = (None, None);
+            }
+            None => scene.positions.push(empty_position(x, y)),
+        }
+    }
+}
+
+/// Adds `template`, replacing a saved template of the same name, and returns its index.
+pub fn save_template(templates: &mut Vec<FormationTemplate>, template: FormationTemplate) -> usize {
+    if let Some(index) = templates
+        .iter()
+        .position(|saved| saved.name == template.name)
+    {
+        templates[index] = template;
+        return index;
+    }
+    templates.push(template);
+    templates.len() - 1
+}
+
+/// The templates stored in a preference value; an unreadable value holds none.
+#[must_use]
+pub fn parse_formation_templates(value: &str) -> Vec<FormationTemplate> {
+    serde_json::from_str(value).unwrap_or_default()
+}
+
+#[must_use]
+pub fn formation_templates_json(templates: &[FormationTemplate]) -> String {
+    serde_json::to_string(templates).unwrap_or_default()
+}
+
+/// Lays out the spots in rows of `columns` from the front, every other row shifted sideways by
+/// `stagger` times the spacing within a row.
+fn rows(
+    count: usize,
+    columns: usize,
+    column_spacing: f64,
+    row_spacing: f64,
+    stagger: f64,
+) -> Vec<(f64, f64)> {
+    let columns = columns.max(1);
+    (0..count)
+        .map(|index| {
+            let row = index / columns;
+            let shift = if row % 2 == 1 {
+                stagger * column_spacing
+            } else {
+                0.0
+            };
+            (
+                column(index % columns, column_spacing) + shift,
+                -column(row, row_spacing),
+            )
+        })
+        .collect()
+}
+
+/// The tip stands at the front; the arms step one spacing sideways and one back per spot.
+fn wedge(count: usize, spacing: f64) -> Vec<(f64, f64)> {
+    (0..count)
+        .map(|index| {
+            let step = column(index.div_ceil(2), spacing);
+            let side = if index % 2 == 1 { -1.0 } else { 1.0 };
+            (side * step, -step)
+        })
+        .collect()
+}
+
+/// Neighbours on the circle are one spacing apart, starting at the front.
+fn circle(count: usize, spacing: f64) -> Vec<(f64, f64)> {
+    if count < 2 {
+        return vec![(0.0, 0.0); count];
+    }
+    let spot_count = count as f64;
+    let radius = spacing / (2.0 * (PI / spot_count).sin());
+    (0..count)
+        .map(|index| {
+            let angle = PI / 2.0 - 2.0 * PI * index as f64 / spot_count;
+            (radius * angle.cos(), radius * angle.sin())
+        })
+        .collect()
+}
+
+fn block_columns(count: usize) -> usize {
+    (1..=count)
+        .find(|columns| columns * columns >= count)
+        .unwrap_or(1)
+}
+
+fn column(index: usize, spacing: f64) -> f64 {
+    index as f64 * spacing
+}
+
+#[derive(Debug, Clone, Copy)]
+struct SpotBounds {
+    min_x: f64,
+    max_x: f64,
+    min_y: f64,
+    max_y: f64,
+}
+
+impl SpotBounds {
+    fn of(spots: &[(f64, f64)]) -> Option<Self> {
+        let (first_x, first_y) = *spots.first()?;
+        Some(spots.iter().fold(
+            Self {
+                min_x: first_x,
+                max_x: first_x,
+                min_y: first_y,
+                max_y: first_y,
+            },
+            |bounds, (x, y)| Self {
+                min_x: bounds.min_x.min(*x),
+                max_x: bounds.max_x.max(*x),
+                min_y: bounds.min_y.min(*y),
+                max_y: bounds.max_y.max(*y),
+            },
+        ))
+    }
+}
+
+/// Centers `spots` on the floor, shrinking them evenly when they are wider or deeper than it.
+///
+/// The left back corner of the formation is snapped first so formations laid out in whole grid
+/// steps keep their spacing. A floor without a size on an axis does not bound that axis.
+fn fit_to_floor(spots: &[(f64, f64)], floor: &FloorModel, grid_resolution: i32) -> Vec<(f64, f64)> {
+    let Some(bounds) = SpotBounds::of(spots) else {
+        return Vec::new();
+    };
+    let (left, right) = (-f64::from(floor.size_left), f64::from(floor.size_right));
+    let (back, front) = (-f64::from(floor.size_back), f64::from(floor.size_front));
+    let width = bounds.max_x - bounds.min_x;
+    let depth = bounds.max_y - bounds.min_y;
+    let scale = fit_scale(width, right - left).min(fit_scale(depth, front - back));
+    let origin_x = snap_to_grid((left + right - width * scale) / 2.0, grid_resolution);
+    let origin_y = snap_to_grid((back + front - depth * scale) / 2.0, grid_resolution);
+
+    spots
+        .iter()
+        .map(|(x, y)| {
+            (
+                fit_axis(
+                    origin_x + (x - bounds.min_x) * scale,
+                    left,
+                    right,
+                    grid_resolution,
+                ),
+                fit_axis(
+                    origin_y + (y - bounds.min_y) * scale,
+                    back,
+                    front,
+                    grid_resolution,
+                ),
+            )
+        })
+        .collect()
+}
+
+fn fit_scale(extent: f64, available: f64) -> f64 {
+    if available > 0.0 && extent > available {
+        available / extent
+    } else {
+        1.0
+    }
+}
+
+fn fit_axis(value: f64, minimum: f64, maximum: f64, grid_resolution: i32) -> f64 {
+    let value = snap_to_grid(value, grid_resolution);
+    if maximum > minimum {
+        value.clamp(minimum, maximum)
+    } else {
+        value
+    }
+}
+
+fn grid_step(grid_resolution: i32) -> f64 {
+    1.0 / f64::from(grid_resolution.max(1))
+}
+
+fn snap_to_grid(value: f64, grid_resolution: i32) -> f64 {
+    let step = grid_step(grid_resolution);
+    (value / step).round() * step
+}
+
+fn empty_position(x: f64, y: f64) -> PositionModel {
+    PositionModel {
+        dancer: None,
+        required_role: None,
+        orientation: None,
+        x,
+        y,
+        curve1_x: None,
+        curve1_y: None,
+        curve2_x: None,
+        curve2_y: None,
+        movement1_x: None,
+        movement1_y: None,
+        movement2_x: None,
+        movement2_y: None,
+    }
+}
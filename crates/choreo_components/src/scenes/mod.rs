@@ -1,8 +1,13 @@
 pub mod actions;
+pub mod arrangement;
+pub mod auto_assign;
+pub mod formations;
 pub mod provider;
 pub mod reducer;
 pub mod state;
+pub mod transition_paths;
 pub mod translations;
 pub mod ui;
+pub mod variations;
 
 pub use crate::material::icons as ui_icons;
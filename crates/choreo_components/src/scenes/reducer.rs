@@ -1,13 +1,38 @@
 use choreo_models::SceneModel;
 
 use super::actions::ScenesAction;
+use super::arrangement;
+use super::auto_assign::AutoAssignError;
+use super::auto_assign::assign_unplaced_dancers;
+use super::auto_assign::build_in_between_positions;
+use super::auto_assign::in_between_timing;
+use super::auto_assign::set_required_role;
+use super::auto_assign::shared_required_role;
+use super::formations::FormationChoice;
+use super::formations::FormationParameters;
+use super::formations::FormationTemplate;
+use super::formations::arrange_positions;
+use super::formations::formation_spots;
+use super::formations::save_template;
+use super::formations::spot_positions;
 use super::state::ScenesState;
 use super::state::build_scene_name;
 use super::state::format_seconds;
 use super::state::next_scene_id;
 use super::state::normalize_text;
 use super::state::parse_timestamp_seconds;
+use super::transition_paths::TransitionPathError;
+use super::transition_paths::bend_colliding_paths;
+use super::transition_paths::count_colliding_pairs;
+use super::variations::VariationError;
+use super::variations::active_variation_range;
+use super::variations::branch_variation;
+use super::variations::promote_variation;
+use super::variations::switch_variation;
+use super::variations::variation_anchor;
+use super::variations::variation_names;
 use crate::scene_list_item::SceneItemState;
+use crate::time::counts::format_scene_time;
 
 pub fn reduce(state: &mut ScenesState, action: ScenesAction) {
     match action {
@@ -17,6 +42,9 @@ pub fn reduce(state: &mut ScenesState, action: ScenesAction) {
         ScenesAction::RequestSaveChoreography => {
             state.request_save_choreo = state.can_save_choreo;
         }
+        ScenesAction::RequestSaveChoreographyAs => {
+            state.request_save_choreo_as = state.can_save_choreo_as;
+        }
         ScenesAction::NavigateToSettings => {
             state.navigate_to_settings_requested = state.can_navigate_to_settings;
         }
@@ -28,6 +56,7 @@ pub fn reduce(state: &mut ScenesState, action: ScenesAction) {
             map_scenes_from_choreography(state);
             select_first_scene(state);
             state.show_timestamps = state.choreography.settings.show_timestamps;
+            state.musical_timeline = state.choreography.settings.musical_timeline;
             state.selected_scene_changed = state.selected_scene.is_some();
             update_caps_and_projection(state);
         }
@@ -60,6 +89,7 @@ pub fn reduce(state: &mut ScenesState, action: ScenesAction) {
                 name.clone(),
                 choreo_master_mobile_json::Color::transparent(),
             );
+            state.selected_scene_ids.clear();
             state.scenes.insert(insert_index, new_scene.clone());
             state.choreography.scenes.insert(
                 insert_index.min(state.choreography.scenes.len()),
@@ -85,12 +115,141 @@ pub fn reduce(state: &mut ScenesState, action: ScenesAction) {
             let Some(scene_id) = state.visible_scenes.get(index).map(|scene| scene.scene_id) else {
                 return;
             };
+            state.selected_scene_ids.clear();
             set_selected_scene_by_id(state, scene_id);
             state.selected_scene_changed = true;
             state.redraw_floor_requested = true;
             reduce(state, ScenesAction::ApplyPlacementModeForSelected);
             update_caps_and_projection(state);
         }
+        ScenesAction::ToggleSceneSelection { index } => {
+            let Some(index) = scene_list_index(state, index) else {
+                return;
+            };
+            let selection =
+                arrangement::toggle_selection(&selected_scene_list_indices(state), index);
+            let shown = if selection.contains(&index) {
+                index
+            } else {
+                selected_scene_list_index(state)
+                    .filter(|selected| selection.contains(selected))
+                    .unwrap_or(selection[0])
+            };
+            select_scene_list(state, &selection, shown);
+        }
+        ScenesAction::ExtendSceneSelection { index } => {
+            let Some(index) = scene_list_index(state, index) else {
+                return;
+            };
+            let Some(anchor) = selected_scene_list_index(state) else {
+                select_scene_list(state, &[index], index);
+                return;
+            };
+            select_scene_list(state, &arrangement::extend_selection(anchor, index), anchor);
+        }
+        ScenesAction::MoveScene { from, to } => {
+            if !state.search_text.trim().is_empty() {
+                return;
+            }
+            let Some(scene_id) = state
+                .choreography
+                .scenes
+                .get(from)
+                .map(|scene| scene.scene_id)
+            else {
+                return;
+            };
+            arrangement::move_scene(&mut state.choreography.scenes, from, to);
+            apply_scene_list_edit(state, scene_id);
+        }
+        ScenesAction::DuplicateScene => {
+            let Some(index) = selected_choreography_index(state) else {
+                return;
+            };
+            let scene_id = next_scene_id(&state.scenes);
+            if arrangement::duplicate_scene(&mut state.choreography.scenes, index, scene_id)
+                .is_some()
+            {
+                apply_scene_list_edit(state, scene_id);
+            }
+        }
+        ScenesAction::ShiftSceneTimestamps { seconds } => {
+            let Some(selected_id) = state.selected_scene.as_ref().map(|scene| scene.scene_id)
+            else {
+                return;
+            };
+            let shifted_ids = selected_scene_ids(state);
+            for scene in state
+                .choreography
+                .scenes
+                .iter_mut()
+                .filter(|scene| shifted_ids.contains(&scene.scene_id))
+            {
+                if let Some(timestamp) =
+                    scene.timestamp.as_deref().and_then(parse_timestamp_seconds)
+                {
+                    scene.timestamp = Some(format_seconds(arrangement::shift_timestamp(
+                        timestamp, seconds,
+                    )));
+                }
+            }
+            apply_scene_list_edit(state, selected_id);
+        }
+        ScenesAction::SelectFormation { choice } => {
+            state.formation_choice = choice;
+        }
+        ScenesAction::UpdateFormationParameters { parameters } => {
+            state.formation_parameters = parameters;
+        }
+        ScenesAction::ApplyFormation => {
+            let Some(selected_id) = state.selected_scene.as_ref().map(|scene| scene.scene_id)
+            else {
+                return;
+            };
+            let Some(spots) = formation_spots(
+                state.formation_choice,
+                state.formation_parameters,
+                &state.formation_templates,
+                &state.choreography.floor,
+                state.grid_resolution,
+            ) else {
+                return;
+            };
+            let Some(scene) = state
+                .choreography
+                .scenes
+                .iter_mut()
+                .find(|scene| scene.scene_id == selected_id)
+            else {
+                return;
+            };
+            arrange_positions(scene, &spots);
+            let positions = scene.positions.clone();
+            for item in state
+                .scenes
+                .iter_mut()
+                .chain(state.visible_scenes.iter_mut())
+                .chain(state.selected_scene.iter_mut())
+                .filter(|item| item.scene_id == selected_id)
+            {
+                item.positions = positions.clone();
+            }
+            state.selected_position_indices.clear();
+            state.redraw_floor_requested = true;
+            update_caps_and_projection(state);
+        }
+        ScenesAction::SaveFormationTemplate => {
+            let Some(selected) = state
+                .selected_scene
+                .as_ref()
+                .filter(|scene| !scene.positions.is_empty())
+            else {
+                return;
+            };
+            let template = FormationTemplate::from_positions(&selected.name, &selected.positions);
+            let index = save_template(&mut state.formation_templates, template);
+            state.formation_choice = FormationChoice::Template(index);
+        }
         ScenesAction::SelectSceneFromAudioPosition { position_seconds } => {
             let previous_id = state.selected_scene.as_ref().map(|scene| scene.scene_id);
             if state.scenes.len() < 2 {
@@ -115,8 +274,11 @@ pub fn reduce(state: &mut ScenesState, action: ScenesAction) {
                     continue;
                 }
                 if position_seconds >= current_timestamp && position_seconds <= next_timestamp {
-                    set_selected_scene_by_id(state, current_scene_id);
                     let changed = previous_id != Some(current_scene_id);
+                    if changed {
+                        state.selected_scene_ids.clear();
+                    }
+                    set_selected_scene_by_id(state, current_scene_id);
                     if changed {
                         state.selected_scene_changed = true;
                         state.redraw_floor_requested = true;
@@ -142,6 +304,264 @@ pub fn reduce(state: &mut ScenesState, action: ScenesAction) {
                 .unwrap_or_default();
             state.is_place_mode = dancer_count > 0 && position_count < dancer_count;
         }
+        ScenesAction::AutoAssignDancers => {
+            let Some(selected_id) = state.selected_scene.as_ref().map(|scene| scene.scene_id)
+            else {
+                return;
+            };
+            let Some(index) = state
+                .choreography
+                .scenes
+                .iter()
+                .position(|scene| scene.scene_id == selected_id)
+            else {
+                return;
+            };
+            let Some(previous) = index
+                .checked_sub(1)
+                .and_then(|previous_index| state.choreography.scenes.get(previous_index))
+            else {
+                state.auto_assign_error = Some(AutoAssignError::NoPreviousScene);
+                return;
+            };
+
+            let mut current = state.choreography.scenes[index].clone();
+            match assign_unplaced_dancers(previous, &mut current) {
+                Ok(_) => {
+                    state.auto_assign_error = None;
+                    for scene in state
+                        .scenes
+                        .iter_mut()
+                        .chain(state.visible_scenes.iter_mut())
+                        .chain(state.selected_scene.iter_mut())
+                        .filter(|scene| scene.scene_id == selected_id)
+                    {
+                        scene.positions = current.positions.clone();
+                    }
+                    state.choreography.scenes[index] = current;
+                    state.redraw_floor_requested = true;
+                    reduce(state, ScenesAction::ApplyPlacementModeForSelected);
+                    update_caps_and_projection(state);
+                }
+                Err(error) => {
+                    state.auto_assign_error = Some(error);
+                }
+            }
+        }
+        ScenesAction::SetRequiredRole {
+            position_indices,
+            role_index,
+        } => {
+            let Some(selected_id) = state.selected_scene.as_ref().map(|scene| scene.scene_id)
+            else {
+                return;
+            };
+            let role = role_index.and_then(|index| state.choreography.roles.get(index).cloned());
+            let Some(scene) = state
+                .choreography
+                .scenes
+                .iter_mut()
+                .find(|scene| scene.scene_id == selected_id)
+            else {
+                return;
+            };
+            set_required_role(scene, &position_indices, role.as_ref());
+            let positions = scene.positions.clone();
+            for item in state
+                .scenes
+                .iter_mut()
+                .chain(state.visible_scenes.iter_mut())
+                .chain(state.selected_scene.iter_mut())
+                .filter(|item| item.scene_id == selected_id)
+            {
+                item.positions = positions.clone();
+            }
+            state.selected_position_indices = position_indices;
+            state.redraw_floor_requested = true;
+            update_caps_and_projection(state);
+        }
+        ScenesAction::BendCollidingPaths => {
+            let Some(selected_id) = state.selected_scene.as_ref().map(|scene| scene.scene_id)
+            else {
+                return;
+            };
+            let Some(index) = state
+                .choreography
+                .scenes
+                .iter()
+                .position(|scene| scene.scene_id == selected_id)
+            else {
+                return;
+            };
+            let dancer_size = state.choreography.settings.dancer_size;
+            let Some((current, rest)) = state.choreography.scenes[index..].split_first_mut() else {
+                return;
+            };
+            let Some(next) = rest.first() else {
+                state.transition_path_error = Some(TransitionPathError::NoNextScene);
+                return;
+            };
+            let outcome = match bend_colliding_paths(current, next, dancer_size) {
+                Ok(outcome) => outcome,
+                Err(error) => {
+                    state.transition_path_error = Some(error);
+                    return;
+                }
+            };
+
+            state.transition_path_error = None;
+            state.unresolved_collision_pairs = outcome.unresolved_pairs;
+            let positions = current.positions.clone();
+            for item in state
+                .scenes
+                .iter_mut()
+                .chain(state.visible_scenes.iter_mut())
+                .chain(state.selected_scene.iter_mut())
+                .filter(|item| item.scene_id == selected_id)
+            {
+                item.positions = positions.clone();
+            }
+            state.redraw_floor_requested = true;
+            update_caps_and_projection(state);
+        }
+        ScenesAction::BranchSceneVariation => {
+            let Some(index) = selected_choreography_index(state) else {
+                return;
+            };
+            let branch_index = branch_variation(&mut state.choreography.scenes, index);
+            let scene_id = state.choreography.scenes[branch_index].scene_id;
+            state.variation_error = None;
+            apply_scene_list_edit(state, scene_id);
+        }
+        ScenesAction::SwitchSceneVariation { variation } => {
+            let Some(anchor) = selected_choreography_index(state)
+                .and_then(|index| variation_anchor(&state.choreography.scenes, index))
+            else {
+                state.variation_error = Some(VariationError::NoVariations);
+                return;
+            };
+            if let Err(error) = switch_variation(&mut state.choreography.scenes, anchor, variation)
+            {
+                state.variation_error = Some(error);
+                return;
+            }
+            // Show the variation just switched to, or the anchor when it is the empty one.
+            let range = active_variation_range(&state.choreography.scenes, anchor);
+            let shown = if range.is_empty() {
+                anchor
+            } else {
+                range.start
+            };
+            let scene_id = state.choreography.scenes[shown].scene_id;
+            state.variation_error = None;
+            apply_scene_list_edit(state, scene_id);
+        }
+        ScenesAction::PromoteSceneVariation => {
+            let Some(selected_id) = state.selected_scene.as_ref().map(|scene| scene.scene_id)
+            else {
+                return;
+            };
+            let Some(anchor) = selected_choreography_index(state)
+                .and_then(|index| variation_anchor(&state.choreography.scenes, index))
+            else {
+                state.variation_error = Some(VariationError::NoVariations);
+                return;
+            };
+            if let Err(error) = promote_variation(&mut state.choreography.scenes, anchor) {
+                state.variation_error = Some(error);
+                return;
+            }
+            state.variation_error = None;
+            state.compared_scene_variation = None;
+            apply_scene_list_edit(state, selected_id);
+        }
+        ScenesAction::CompareSceneVariation { variation } => {
+            state.compared_scene_variation = variation;
+            state.redraw_floor_requested = true;
+            update_caps_and_projection(state);
+        }
+        ScenesAction::InsertInBetweenScene { formation } => {
+            let Some(selected_id) = state.selected_scene.as_ref().map(|scene| scene.scene_id)
+            else {
+                return;
+            };
+            let Some(index) = state
+                .choreography
+                .scenes
+                .iter()
+                .position(|scene| scene.scene_id == selected_id)
+            else {
+                return;
+            };
+            let Some(end) = state.choreography.scenes.get(index + 1) else {
+                state.auto_assign_error = Some(AutoAssignError::NoNextScene);
+                return;
+            };
+            let start = &state.choreography.scenes[index];
+            let (fraction, timestamp) = in_between_timing(
+                start.timestamp.as_deref().and_then(parse_timestamp_seconds),
+                end.timestamp.as_deref().and_then(parse_timestamp_seconds),
+                None,
+            );
+            let formation_positions;
+            let spots = match formation {
+                None => &start.positions,
+                Some(choice) => {
+                    let parameters = FormationParameters {
+                        count: start.positions.len(),
+                        ..state.formation_parameters
+                    };
+                    let Some(spots) = formation_spots(
+                        choice,
+                        parameters,
+                        &state.formation_templates,
+                        &state.choreography.floor,
+                        state.grid_resolution,
+                    ) else {
+                        return;
+                    };
+                    formation_positions = spot_positions(&spots);
+                    &formation_positions
+                }
+            };
+            let positions = match build_in_between_positions(start, end, spots, fraction) {
+                Ok(positions) => positions,
+                Err(error) => {
+                    state.auto_assign_error = Some(error);
+                    return;
+                }
+            };
+
+            state.auto_assign_error = None;
+            let scene = SceneModel {
+                scene_id: next_scene_id(&state.scenes),
+                positions,
+                name: build_scene_name(&state.scenes),
+                text: None,
+                fixed_positions: false,
+                timestamp: timestamp.map(format_seconds),
+                variation_depth: 0,
+                variations: Vec::new(),
+                current_variation: Vec::new(),
+                color: choreo_master_mobile_json::Color::transparent(),
+            };
+            let scene_id = scene.scene_id;
+            let scene_index = state
+                .scenes
+                .iter()
+                .position(|item| item.scene_id == selected_id)
+                .map_or(state.scenes.len(), |item_index| item_index + 1);
+            state
+                .scenes
+                .insert(scene_index, map_model_to_scene_item(&scene));
+            state.choreography.scenes.insert(index + 1, scene);
+            state.selected_scene_ids.clear();
+            set_selected_scene_by_id(state, scene_id);
+            refresh_visible_scenes(state);
+            state.selected_scene_changed = true;
+            state.redraw_floor_requested = true;
+            update_caps_and_projection(state);
+        }
         ScenesAction::SyncShowTimestampsFromChoreography => {
             state.show_timestamps = state.choreography.settings.show_timestamps;
         }
@@ -193,6 +613,7 @@ pub fn reduce(state: &mut ScenesState, action: ScenesAction) {
             state.reload_requested = true;
             state.selected_scene_changed = state.selected_scene.is_some();
             state.show_timestamps = state.choreography.settings.show_timestamps;
+            state.musical_timeline = state.choreography.settings.musical_timeline;
             state.last_opened_choreo_file = file_path.or(file_name);
             state.pending_open_audio = audio_path;
             state.close_audio_requested = state.pending_open_audio.is_none();
@@ -215,6 +636,7 @@ pub fn reduce(state: &mut ScenesState, action: ScenesAction) {
 }
 
 fn map_scenes_from_choreography(state: &mut ScenesState) {
+    state.selected_scene_ids.clear();
     state.scenes = state
         .choreography
         .scenes
@@ -224,7 +646,93 @@ fn map_scenes_from_choreography(state: &mut ScenesState) {
     refresh_visible_scenes(state);
 }
 
+fn selected_choreography_index(state: &ScenesState) -> Option<usize> {
+    let selected_id = state.selected_scene.as_ref()?.scene_id;
+    state
+        .choreography
+        .scenes
+        .iter()
+        .position(|scene| scene.scene_id == selected_id)
+}
+
+/// Rebuilds the scene list after an edit reshaped the scenes of the choreography, keeping the
+/// scenes picked together with the selected one.
+fn apply_scene_list_edit(state: &mut ScenesState, selected_id: choreo_master_mobile_json::SceneId) {
+    let selected_ids = std::mem::take(&mut state.selected_scene_ids);
+    map_scenes_from_choreography(state);
+    state.selected_scene_ids = selected_ids
+        .into_iter()
+        .filter(|scene_id| state.scenes.iter().any(|scene| scene.scene_id == *scene_id))
+        .collect();
+    set_selected_scene_by_id(state, selected_id);
+    state.selected_scene_changed = true;
+    state.redraw_floor_requested = true;
+    update_caps_and_projection(state);
+}
+
+/// Index in the scene list of the scene at `visible_index` in the filtered list.
+fn scene_list_index(state: &ScenesState, visible_index: usize) -> Option<usize> {
+    let scene_id = state.visible_scenes.get(visible_index)?.scene_id;
+    state
+        .scenes
+        .iter()
+        .position(|scene| scene.scene_id == scene_id)
+}
+
+/// The selected scene together with every scene picked along with it.
+fn selected_scene_ids(state: &ScenesState) -> Vec<choreo_master_mobile_json::SceneId> {
+    if state.selected_scene_ids.is_empty() {
+        state
+            .selected_scene
+            .iter()
+            .map(|scene| scene.scene_id)
+            .collect()
+    } else {
+        state.selected_scene_ids.clone()
+    }
+}
+
+fn selected_scene_list_index(state: &ScenesState) -> Option<usize> {
+    let selected_id = state.selected_scene.as_ref()?.scene_id;
+    state
+        .scenes
+        .iter()
+        .position(|scene| scene.scene_id == selected_id)
+}
+
+fn selected_scene_list_indices(state: &ScenesState) -> Vec<usize> {
+    let selected_ids = selected_scene_ids(state);
+    state
+        .scenes
+        .iter()
+        .enumerate()
+        .filter(|(_, scene)| selected_ids.contains(&scene.scene_id))
+        .map(|(index, _)| index)
+        .collect()
+}
+
+fn select_scene_list(state: &mut ScenesState, selection: &[usize], shown: usize) {
+    let Some(shown_id) = state.scenes.get(shown).map(|scene| scene.scene_id) else {
+        return;
+    };
+    state.selected_scene_ids = if selection.len() > 1 {
+        selection
+            .iter()
+            .filter_map(|index| state.scenes.get(*index))
+            .map(|scene| scene.scene_id)
+            .collect()
+    } else {
+        Vec::new()
+    };
+    set_selected_scene_by_id(state, shown_id);
+    state.selected_scene_changed = true;
+    state.redraw_floor_requested = true;
+    reduce(state, ScenesAction::ApplyPlacementModeForSelected);
+    update_caps_and_projection(state);
+}
+
 fn refresh_visible_scenes(state: &mut ScenesState) {
+    state.can_reorder_scenes = state.search_text.trim().is_empty();
     if state.search_text.trim().is_empty() {
         state.visible_scenes = state.scenes.clone();
         return;
@@ -248,11 +756,13 @@ fn select_first_scene(state: &mut ScenesState) {
 }
 
 fn set_selected_scene_by_id(state: &mut ScenesState, scene_id: choreo_master_mobile_json::SceneId) {
-    for scene in &mut state.scenes {
-        scene.is_selected = scene.scene_id == scene_id;
-    }
-    for scene in &mut state.visible_scenes {
-        scene.is_selected = scene.scene_id == scene_id;
+    for scene in state
+        .scenes
+        .iter_mut()
+        .chain(state.visible_scenes.iter_mut())
+    {
+        scene.is_selected =
+            scene.scene_id == scene_id || state.selected_scene_ids.contains(&scene.scene_id);
     }
     state.selected_scene = state
         .scenes
@@ -269,10 +779,84 @@ fn update_can_save(state: &mut ScenesState) {
         .is_some_and(|path| !path.trim().is_empty() && std::path::Path::new(path).exists());
     let has_choreo = !state.choreography.name.is_empty() || !state.choreography.scenes.is_empty();
     state.can_save_choreo = has_file && has_choreo;
+    state.can_save_choreo_as = has_choreo;
 }
 
 fn update_caps_and_projection(state: &mut ScenesState) {
     state.can_delete_scene = state.selected_scene.is_some();
+    state.can_duplicate_scene = state.selected_scene.is_some();
+    state.can_apply_formation = state.selected_scene.is_some();
+    state.can_save_formation_template = state
+        .selected_scene
+        .as_ref()
+        .is_some_and(|selected| !selected.positions.is_empty());
+    let shifted_ids = selected_scene_ids(state);
+    state.can_shift_scene_timestamps = state
+        .scenes
+        .iter()
+        .any(|scene| scene.timestamp.is_some() && shifted_ids.contains(&scene.scene_id));
+    state.can_auto_assign_dancers = state.selected_scene.as_ref().is_some_and(|selected| {
+        state
+            .scenes
+            .iter()
+            .position(|scene| scene.scene_id == selected.scene_id)
+            .is_some_and(|index| index > 0)
+            && selected
+                .positions
+                .iter()
+                .any(|position| position.dancer.is_none())
+    });
+    state.can_insert_in_between_scene = state.selected_scene.as_ref().is_some_and(|selected| {
+        state
+            .scenes
+            .iter()
+            .position(|scene| scene.scene_id == selected.scene_id)
+            .is_some_and(|index| index + 1 < state.scenes.len())
+    });
+    state.required_role_names = state
+        .choreography
+        .roles
+        .iter()
+        .map(|role| role.name.clone())
+        .collect();
+    state.selected_required_role = state.selected_scene.as_ref().and_then(|selected| {
+        state
+            .choreography
+            .scenes
+            .iter()
+            .find(|scene| scene.scene_id == selected.scene_id)
+            .and_then(|scene| {
+                shared_required_role(
+                    &state.choreography.roles,
+                    scene,
+                    &state.selected_position_indices,
+                )
+            })
+    });
+    state.can_set_required_role = state.selected_scene.is_some()
+        && !state.selected_position_indices.is_empty()
+        && !state.required_role_names.is_empty();
+    state.can_bend_colliding_paths = state.selected_scene.as_ref().is_some_and(|selected| {
+        let scenes = &state.choreography.scenes;
+        scenes
+            .iter()
+            .position(|scene| scene.scene_id == selected.scene_id)
+            .and_then(|index| Some((&scenes[index], scenes.get(index + 1)?)))
+            .is_some_and(|(current, next)| {
+                let dancer_size = state.choreography.settings.dancer_size;
+                count_colliding_pairs(current, next, dancer_size) > 0
+            })
+    });
+    let variation_anchor_index = selected_choreography_index(state)
+        .and_then(|index| variation_anchor(&state.choreography.scenes, index));
+    state.can_branch_scene_variation = state.selected_scene.is_some();
+    state.scene_variations = variation_anchor_index
+        .map(|anchor| variation_names(&state.choreography.scenes, anchor))
+        .unwrap_or_default();
+    state.can_promote_scene_variation = variation_anchor_index.is_some();
+    state.compared_scene_variation = state
+        .compared_scene_variation
+        .filter(|variation| variation + 1 < state.scene_variations.len());
     state.can_navigate_to_settings = true;
     state.can_navigate_to_dancer_settings = true;
     update_can_save(state);
@@ -294,8 +878,10 @@ fn update_selected_scene_projection(state: &mut ScenesState) {
     state.selected_scene_name = selected.name.clone();
     state.selected_scene_text = selected.text.clone();
     state.selected_scene_fixed_positions = selected.fixed_positions;
-    state.selected_scene_timestamp_text =
-        selected.timestamp.map(format_seconds).unwrap_or_default();
+    state.selected_scene_timestamp_text = selected
+        .timestamp
+        .map(|seconds| format_scene_time(seconds, state.musical_timeline.as_ref()))
+        .unwrap_or_default();
     state.selected_scene_color = selected.color.clone();
 }
 
@@ -1,7 +1,15 @@
 use choreo_master_mobile_json::Color;
+use choreo_master_mobile_json::MusicalTimeline;
 use choreo_master_mobile_json::SceneId;
 use choreo_models::ChoreographyModel;
 
+use super::auto_assign::AutoAssignError;
+use super::formations::FormationChoice;
+use super::formations::FormationParameters;
+use super::formations::FormationTemplate;
+use super::transition_paths::TransitionPathError;
+use super::variations::VariationError;
+
 pub use crate::scene_list_item::SceneItemState;
 
 #[derive(Debug, Clone, PartialEq, Default)]
@@ -10,11 +18,38 @@ pub struct ScenesState {
     pub scenes: Vec<SceneItemState>,
     pub visible_scenes: Vec<SceneItemState>,
     pub selected_scene: Option<SceneItemState>,
+    /// Scenes picked together with the selected scene; empty when only that one is picked.
+    pub selected_scene_ids: Vec<SceneId>,
     pub search_text: String,
     pub show_timestamps: bool,
+    /// Scene times are shown as counts ("8·5") when the choreography has a musical timeline.
+    pub musical_timeline: Option<MusicalTimeline>,
     pub is_place_mode: bool,
     pub can_save_choreo: bool,
+    pub can_save_choreo_as: bool,
     pub can_delete_scene: bool,
+    pub can_duplicate_scene: bool,
+    /// Scenes can be dragged to a new place while the list is not filtered by a search.
+    pub can_reorder_scenes: bool,
+    pub can_shift_scene_timestamps: bool,
+    pub can_apply_formation: bool,
+    pub can_save_formation_template: bool,
+    pub formation_choice: FormationChoice,
+    pub formation_parameters: FormationParameters,
+    pub formation_templates: Vec<FormationTemplate>,
+    /// Grid steps per meter that formations snap to.
+    pub grid_resolution: i32,
+    pub can_auto_assign_dancers: bool,
+    pub can_insert_in_between_scene: bool,
+    pub can_set_required_role: bool,
+    pub can_bend_colliding_paths: bool,
+    pub can_branch_scene_variation: bool,
+    pub can_promote_scene_variation: bool,
+    /// Names of the variations the selected scene belongs to, the active one first; `None`
+    /// names the empty variation. Empty when the scene is not part of a variation.
+    pub scene_variations: Vec<Option<String>>,
+    /// Variation set aside that the floor shows next to the active one.
+    pub compared_scene_variation: Option<usize>,
     pub can_navigate_to_settings: bool,
     pub can_navigate_to_dancer_settings: bool,
     pub show_delete_scene_dialog: bool,
@@ -22,6 +57,7 @@ pub struct ScenesState {
     pub copy_scene_positions_decision: Option<bool>,
     pub request_open_choreo_dialog: bool,
     pub request_save_choreo: bool,
+    pub request_save_choreo_as: bool,
     pub navigate_to_settings_requested: bool,
     pub navigate_to_dancer_settings_requested: bool,
     pub last_opened_choreo_file: Option<String>,
@@ -39,6 +75,13 @@ pub struct ScenesState {
     pub selected_scene_timestamp_text: String,
     pub selected_scene_color: Color,
     pub delete_scene_dialog_scene: Option<SceneItemState>,
+    pub auto_assign_error: Option<AutoAssignError>,
+    pub transition_path_error: Option<TransitionPathError>,
+    pub variation_error: Option<VariationError>,
+    pub unresolved_collision_pairs: usize,
+    pub selected_position_indices: Vec<usize>,
+    pub required_role_names: Vec<String>,
+    pub selected_required_role: Option<usize>,
 }
 
 impl ScenesState {
@@ -53,6 +96,7 @@ impl ScenesState {
         self.copy_scene_positions_decision = None;
         self.request_open_choreo_dialog = false;
         self.request_save_choreo = false;
+        self.request_save_choreo_as = false;
         self.navigate_to_settings_requested = false;
         self.navigate_to_dancer_settings_requested = false;
     }
@@ -0,0 +1,134 @@
+use std::fmt;
+
+use choreo_algorithms::AlgorithmError;
+use choreo_algorithms::Vector2;
+use choreo_algorithms::path_planner;
+use choreo_algorithms::path_planner::TransitionPath;
+use choreo_models::PositionModel;
+use choreo_models::SceneModel;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransitionPathError {
+    NoNextScene,
+    NoCollisions,
+    Algorithm(AlgorithmError),
+}
+
+impl fmt::Display for TransitionPathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TransitionPathError::NoNextScene => {
+                write!(f, "There is no next scene to transition to.")
+            }
+            TransitionPathError::NoCollisions => write!(
+                f,
+                "No dancers come closer than the dancer size during this transition."
+            ),
+            TransitionPathError::Algorithm(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for TransitionPathError {}
+
+impl From<AlgorithmError> for TransitionPathError {
+    fn from(error: AlgorithmError) -> Self {
+        TransitionPathError::Algorithm(error)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PathBendOutcome {
+    pub bent_paths: usize,
+    /// Pairs that stay too close because they already start or end too close together.
+    pub unresolved_pairs: usize,
+}
+
+/// Returns the number of dancer pairs whose paths from `current` to `next` come closer than
+/// `dancer_size`.
+#[must_use]
+pub fn count_colliding_pairs(current: &SceneModel, next: &SceneModel, dancer_size: f64) -> usize {
+    let (_, paths) = transition_paths(current, next);
+    path_planner::find_path_conflicts(&paths, dancer_size as f32)
+        .map_or(0, |conflicts| conflicts.len())
+}
+
+/// Bends the paths from `current` to `next` so that no two dancers come closer than
+/// `dancer_size`, writing the control points into the `curve1`/`curve2` fields of `current`.
+///
+/// Only the positions whose path changed are modified. Their movement waypoints are cleared,
+/// since waypoints take precedence over the curve when the path is walked.
+pub fn bend_colliding_paths(
+    current: &mut SceneModel,
+    next: &SceneModel,
+    dancer_size: f64,
+) -> Result<PathBendOutcome, TransitionPathError> {
+    let (indices, paths) = transition_paths(current, next);
+    let min_distance = dancer_size as f32;
+    if path_planner::find_path_conflicts(&paths, min_distance)?.is_empty() {
+        return Err(TransitionPathError::NoCollisions);
+    }
+
+    let plan = path_planner::bend_conflicting_paths(&paths, min_distance)?;
+    let mut bent_paths = 0;
+    for ((index, before), after) in indices.into_iter().zip(&paths).zip(&plan.paths) {
+        if before == after {
+            continue;
+        }
+        let position = &mut current.positions[index];
+        (position.curve1_x, position.curve1_y) = split(after.curve1);
+        (position.curve2_x, position.curve2_y) = split(after.curve2);
+        (position.movement1_x, position.movement1_y) = (None, None);
+        (position.movement2_x, position.movement2_y) = (None, None);
+        bent_paths += 1;
+    }
+
+    Ok(PathBendOutcome {
+        bent_paths,
+        unresolved_pairs: plan.unresolved.len(),
+    })
+}
+
+/// Pairs every dancer of `current` with its position in `next`. Dancers missing from `next`
+/// stay where they are, matching the floor interpolation.
+fn transition_paths(current: &SceneModel, next: &SceneModel) -> (Vec<usize>, Vec<TransitionPath>) {
+    current
+        .positions
+        .iter()
+        .enumerate()
+        .filter_map(|(index, from)| {
+            let dancer = from.dancer.as_ref()?;
+            let start = to_vector(from);
+            let to = next.positions.iter().find(|position| {
+                position
+                    .dancer
+                    .as_ref()
+                    .is_some_and(|other| other.dancer_id == dancer.dancer_id)
+            });
+            let path = match to {
+                Some(to) => TransitionPath {
+                    start,
+                    end: to_vector(to),
+                    curve1: join(from.curve1_x, from.curve1_y),
+                    curve2: join(from.curve2_x, from.curve2_y),
+                },
+                None => TransitionPath::straight(start, start),
+            };
+            Some((index, path))
+        })
+        .unzip()
+}
+
+fn to_vector(position: &PositionModel) -> Vector2 {
+    Vector2::new(position.x as f32, position.y as f32)
+}
+
+fn join(x: Option<f64>, y: Option<f64>) -> Option<Vector2> {
+    Some(Vector2::new(x? as f32, y? as f32))
+}
+
+fn split(point: Option<Vector2>) -> (Option<f64>, Option<f64>) {
+    point.map_or((None, None), |point| {
+        (Some(f64::from(point.x)), Some(f64::from(point.y)))
+    })
+}
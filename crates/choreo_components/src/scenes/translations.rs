@@ -1,3 +1,9 @@
+use choreo_master_mobile_json::ChoreoJsonError;
+
+use super::auto_assign::AutoAssignError;
+use super::formations::FormationKind;
+use super::transition_paths::TransitionPathError;
+use super::variations::VariationError;
 use crate::i18n::t;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -6,8 +12,30 @@ pub struct ScenesTranslations {
     pub add_before: String,
     pub add_after: String,
     pub delete_scene_title: String,
+    pub auto_assign_dancers: String,
+    pub insert_in_between_scene: String,
+    pub bend_colliding_paths: String,
+    pub branch_scene_variation: String,
+    pub promote_scene_variation: String,
+    pub scene_variation: String,
+    pub scene_variation_empty: String,
+    pub compare_scene_variation: String,
+    pub compare_scene_variation_off: String,
+    pub duplicate_scene: String,
+    pub shift_scene_time: String,
+    pub shift_scene_earlier: String,
+    pub shift_scene_later: String,
+    pub formation: String,
+    pub formation_count: String,
+    pub formation_spacing: String,
+    pub apply_formation: String,
+    pub insert_in_between_formation: String,
+    pub save_formation_template: String,
+    pub required_role: String,
+    pub required_role_any: String,
     pub open: String,
     pub save: String,
+    pub save_as: String,
     pub settings: String,
     pub dancers: String,
     pub delete_scene_dialog_title: String,
@@ -29,8 +57,30 @@ pub fn scenes_translations(locale: &str) -> ScenesTranslations {
         add_before: t(locale, "ScenesAddBefore"),
         add_after: t(locale, "ScenesAddAfter"),
         delete_scene_title: t(locale, "DeleteSceneDialogTitle"),
+        auto_assign_dancers: t(locale, "ScenesAutoAssignDancers"),
+        insert_in_between_scene: t(locale, "ScenesInsertInBetween"),
+        bend_colliding_paths: t(locale, "ScenesBendCollidingPaths"),
+        branch_scene_variation: t(locale, "ScenesBranchVariation"),
+        promote_scene_variation: t(locale, "ScenesPromoteVariation"),
+        scene_variation: t(locale, "ScenesVariation"),
+        scene_variation_empty: t(locale, "ScenesVariationEmpty"),
+        compare_scene_variation: t(locale, "ScenesCompareVariation"),
+        compare_scene_variation_off: t(locale, "ScenesCompareVariationOff"),
+        duplicate_scene: t(locale, "ScenesDuplicate"),
+        shift_scene_time: t(locale, "ScenesShiftTime"),
+        shift_scene_earlier: t(locale, "ScenesShiftEarlier"),
+        shift_scene_later: t(locale, "ScenesShiftLater"),
+        formation: t(locale, "ScenesFormation"),
+        formation_count: t(locale, "ScenesFormationCount"),
+        formation_spacing: t(locale, "ScenesFormationSpacing"),
+        apply_formation: t(locale, "ScenesApplyFormation"),
+        insert_in_between_formation: t(locale, "ScenesInsertInBetweenFormation"),
+        save_formation_template: t(locale, "ScenesSaveFormationTemplate"),
+        required_role: t(locale, "ScenesRequiredRole"),
+        required_role_any: t(locale, "ScenesRequiredRoleAny"),
         open: t(locale, "ScenesOpen"),
         save: t(locale, "ScenesSave"),
+        save_as: t(locale, "ScenesSaveAs"),
         settings: t(locale, "SettingsTitle"),
         dancers: t(locale, "DancersTitle"),
         delete_scene_dialog_title: t(locale, "DeleteSceneDialogTitle"),
@@ -45,3 +95,65 @@ pub fn scenes_translations(locale: &str) -> ScenesTranslations {
         common_cancel: t(locale, "CommonCancel"),
     }
 }
+
+#[must_use]
+pub fn formation_kind_name(locale: &str, kind: FormationKind) -> String {
+    match kind {
+        FormationKind::Line => t(locale, "ScenesFormationLine"),
+        FormationKind::DoubleLine => t(locale, "ScenesFormationDoubleLine"),
+        FormationKind::Wedge => t(locale, "ScenesFormationWedge"),
+        FormationKind::Circle => t(locale, "ScenesFormationCircle"),
+        FormationKind::Windows => t(locale, "ScenesFormationWindows"),
+        FormationKind::Diagonal => t(locale, "ScenesFormationDiagonal"),
+        FormationKind::Checkerboard => t(locale, "ScenesFormationCheckerboard"),
+    }
+}
+
+#[must_use]
+pub fn auto_assign_error_message(locale: &str, error: &AutoAssignError) -> String {
+    match error {
+        AutoAssignError::NoPreviousScene => t(locale, "AutoAssignNoPreviousScene"),
+        AutoAssignError::NoNextScene => t(locale, "AutoAssignNoNextScene"),
+        AutoAssignError::NoUnassignedPositions => t(locale, "AutoAssignNoUnassignedPositions"),
+        AutoAssignError::CountMismatch { dancers, positions } => {
+            t(locale, "AutoAssignCountMismatch")
+                .replace("{0}", &dancers.to_string())
+                .replace("{1}", &positions.to_string())
+        }
+        AutoAssignError::NoRoleCompatibleAssignment => {
+            t(locale, "AutoAssignNoRoleCompatibleAssignment")
+        }
+        AutoAssignError::Algorithm(_) => t(locale, "AutoAssignFailed"),
+    }
+}
+
+#[must_use]
+pub fn transition_path_error_message(locale: &str, error: &TransitionPathError) -> String {
+    match error {
+        TransitionPathError::NoNextScene => t(locale, "AutoAssignNoNextScene"),
+        TransitionPathError::NoCollisions => t(locale, "TransitionPathsNoCollisions"),
+        TransitionPathError::Algorithm(_) => t(locale, "TransitionPathsFailed"),
+    }
+}
+
+#[must_use]
+pub fn variation_error_message(locale: &str, error: &VariationError) -> String {
+    match error {
+        VariationError::NoVariations => t(locale, "ScenesVariationNone"),
+        VariationError::UnknownVariation(variation) => {
+            t(locale, "ScenesVariationUnknown").replace("{0}", &(variation + 1).to_string())
+        }
+    }
+}
+
+#[must_use]
+pub fn unresolved_collisions_message(locale: &str, pairs: usize) -> String {
+    t(locale, "TransitionPathsUnresolved").replace("{0}", &pairs.to_string())
+}
+
+#[must_use]
+pub fn save_choreo_error_message(locale: &str, file_path: &str, error: &ChoreoJsonError) -> String {
+    t(locale, "SaveChoreoFailed")
+        .replace("{0}", file_path)
+        .replace("{1}", &error.to_string())
+}
@@ -11,8 +11,11 @@ use egui::vec2;
 
 use crate::delete_scene_dialog::ui::DeleteSceneDialogAction;
 use crate::delete_scene_dialog::ui::draw_delete_scene_dialog;
+use crate::material::components;
 use crate::material::components::MaterialScrollArea;
 use crate::material::components::centered_icon_rect;
+use crate::material::components::number_picker;
+use crate::material::components::number_picker::NumberPickerUiState;
 use crate::material::components::paint_icon;
 use crate::material::components::top_bar_icon::top_bar_icon_button_enabled;
 use crate::material::styling::material_palette::MaterialPalette;
@@ -20,9 +23,16 @@ use crate::material::styling::material_palette::material_palette_for_visuals;
 use crate::material::styling::material_style_metrics::material_style_metrics;
 use crate::scene_list_item;
 use crate::scene_list_item::SceneItemState;
+use crate::time::counts::seconds_per_count;
 
 use super::actions::ScenesAction;
+use super::formations::FormationChoice;
+use super::formations::FormationKind;
+use super::formations::FormationParameters;
+use super::formations::MAXIMUM_FORMATION_COUNT;
+use super::formations::MAXIMUM_FORMATION_SPACING_STEPS;
 use super::state::ScenesState;
+use super::translations::formation_kind_name;
 use super::translations::scenes_translations;
 use super::ui_icons;
 use super::ui_icons::UiIconKey;
@@ -32,6 +42,9 @@ const SEARCH_BAR_HEIGHT_PX: f32 = 44.0;
 const SEARCH_BAR_ICON_BUTTON_SIZE_PX: f32 = 24.0;
 const TOOLBAR_ROW_HEIGHT_PX: f32 = 48.0;
 const TOOLBAR_ICON_GLYPH_SIZE_PX: f32 = 24.0;
+const DROP_INDICATOR_WIDTH_PX: f32 = 2.0;
+/// Scene times move by this much per step when the choreography has no musical timeline.
+const SHIFT_STEP_SECONDS: f64 = 1.0;
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SceneSearchBarViewModel {
     pub placeholder_text: String,
@@ -72,10 +85,47 @@ pub fn scene_pane_action_flow(state: &ScenesState) -> Vec<ScenesAction> {
     if state.can_delete_scene {
         actions.push(ScenesAction::OpenDeleteSceneDialog);
     }
+    if state.can_auto_assign_dancers {
+        actions.push(ScenesAction::AutoAssignDancers);
+    }
+    if state.can_insert_in_between_scene {
+        actions.push(ScenesAction::InsertInBetweenScene { formation: None });
+    }
+    if state.can_bend_colliding_paths {
+        actions.push(ScenesAction::BendCollidingPaths);
+    }
+    if state.can_branch_scene_variation {
+        actions.push(ScenesAction::BranchSceneVariation);
+    }
+    if state.can_promote_scene_variation {
+        actions.push(ScenesAction::PromoteSceneVariation);
+    }
+    if state.can_duplicate_scene {
+        actions.push(ScenesAction::DuplicateScene);
+    }
+    if state.can_shift_scene_timestamps {
+        let step = scene_time_shift_step_seconds(state);
+        actions.push(ScenesAction::ShiftSceneTimestamps { seconds: -step });
+        actions.push(ScenesAction::ShiftSceneTimestamps { seconds: step });
+    }
+    if state.can_apply_formation {
+        actions.push(ScenesAction::ApplyFormation);
+    }
+    if state.can_apply_formation && state.can_insert_in_between_scene {
+        actions.push(ScenesAction::InsertInBetweenScene {
+            formation: Some(state.formation_choice),
+        });
+    }
+    if state.can_save_formation_template {
+        actions.push(ScenesAction::SaveFormationTemplate);
+    }
     actions.push(ScenesAction::RequestOpenChoreography);
     if state.can_save_choreo {
         actions.push(ScenesAction::RequestSaveChoreography);
     }
+    if state.can_save_choreo_as {
+        actions.push(ScenesAction::RequestSaveChoreographyAs);
+    }
     if state.can_navigate_to_settings {
         actions.push(ScenesAction::NavigateToSettings);
     }
@@ -100,6 +150,37 @@ pub fn draw(ui: &mut Ui, state: &ScenesState) -> Vec<ScenesAction> {
         draw_fixed_height_section(ui, panel_width, TOOLBAR_ROW_HEIGHT_PX, |ui| {
             draw_edit_toolbar_row(ui, state, &mut actions);
         });
+        if state.can_duplicate_scene {
+            draw_fixed_height_section(ui, panel_width, TOOLBAR_ROW_HEIGHT_PX, |ui| {
+                draw_arrange_row(ui, state, &mut actions);
+            });
+        }
+        if state.can_apply_formation {
+            draw_fixed_height_section(ui, panel_width, TOOLBAR_ROW_HEIGHT_PX, |ui| {
+                draw_formation_spacing_row(ui, state, &mut actions);
+            });
+            draw_fixed_height_section(ui, panel_width, TOOLBAR_ROW_HEIGHT_PX, |ui| {
+                draw_formation_count_row(ui, state, &mut actions);
+            });
+            draw_fixed_height_section(ui, panel_width, TOOLBAR_ROW_HEIGHT_PX, |ui| {
+                draw_formation_row(ui, panel_width, state, &mut actions);
+            });
+        }
+        if state.can_branch_scene_variation {
+            draw_fixed_height_section(ui, panel_width, TOOLBAR_ROW_HEIGHT_PX, |ui| {
+                draw_variation_row(ui, panel_width, state, &mut actions);
+            });
+        }
+        if state.scene_variations.len() > 1 {
+            draw_fixed_height_section(ui, panel_width, TOOLBAR_ROW_HEIGHT_PX, |ui| {
+                draw_compare_variation_row(ui, panel_width, state, &mut actions);
+            });
+        }
+        if state.can_set_required_role {
+            draw_fixed_height_section(ui, panel_width, TOOLBAR_ROW_HEIGHT_PX, |ui| {
+                draw_required_role_row(ui, panel_width, state, &mut actions);
+            });
+        }
         draw_fixed_height_section(ui, panel_width, SEARCH_BAR_HEIGHT_PX, |ui| {
             draw_search_bar(ui, panel_width, state, &mut actions, DEFAULT_LOCALE);
         });
@@ -125,10 +206,28 @@ pub fn draw(ui: &mut Ui, state: &ScenesState) -> Vec<ScenesAction> {
                             .auto_shrink([false, false])
                             .show(ui, |ui| {
                                 for (index, scene) in state.visible_scenes.iter().enumerate() {
-                                    if scene_list_item::draw(ui, scene, state.show_timestamps)
-                                        .clicked()
-                                    {
-                                        actions.push(ScenesAction::SelectScene { index });
+                                    let response = scene_list_item::draw(
+                                        ui,
+                                        scene,
+                                        state.show_timestamps,
+                                        state.musical_timeline.as_ref(),
+                                    );
+                                    if response.clicked() {
+                                        let modifiers = ui.input(|input| input.modifiers);
+                                        actions.push(scene_click_action(
+                                            index,
+                                            modifiers.command,
+                                            modifiers.shift,
+                                        ));
+                                    }
+                                    if state.can_reorder_scenes {
+                                        draw_scene_drag_and_drop(
+                                            ui,
+                                            &response,
+                                            index,
+                                            palette.primary,
+                                            &mut actions,
+                                        );
                                     }
                                 }
                             });
@@ -208,6 +307,56 @@ pub fn scene_delete_icon() -> ui_icons::UiIconSpec {
     ui_icons::icon(UiIconKey::ScenesDelete)
 }
 
+#[must_use]
+pub fn scene_auto_assign_icon() -> ui_icons::UiIconSpec {
+    ui_icons::icon(UiIconKey::ScenesAutoAssignDancers)
+}
+
+#[must_use]
+pub fn scene_insert_in_between_icon() -> ui_icons::UiIconSpec {
+    ui_icons::icon(UiIconKey::ScenesInsertInBetween)
+}
+
+#[must_use]
+pub fn scene_bend_colliding_paths_icon() -> ui_icons::UiIconSpec {
+    ui_icons::icon(UiIconKey::ScenesBendCollidingPaths)
+}
+
+#[must_use]
+pub fn scene_branch_variation_icon() -> ui_icons::UiIconSpec {
+    ui_icons::icon(UiIconKey::ScenesBranchVariation)
+}
+
+#[must_use]
+pub fn scene_promote_variation_icon() -> ui_icons::UiIconSpec {
+    ui_icons::icon(UiIconKey::ScenesPromoteVariation)
+}
+
+#[must_use]
+pub fn scene_duplicate_icon() -> ui_icons::UiIconSpec {
+    ui_icons::icon(UiIconKey::ScenesDuplicate)
+}
+
+#[must_use]
+pub fn scene_shift_earlier_icon() -> ui_icons::UiIconSpec {
+    ui_icons::icon(UiIconKey::ScenesShiftEarlier)
+}
+
+#[must_use]
+pub fn scene_shift_later_icon() -> ui_icons::UiIconSpec {
+    ui_icons::icon(UiIconKey::ScenesShiftLater)
+}
+
+#[must_use]
+pub fn scene_apply_formation_icon() -> ui_icons::UiIconSpec {
+    ui_icons::icon(UiIconKey::ScenesApplyFormation)
+}
+
+#[must_use]
+pub fn scene_save_formation_template_icon() -> ui_icons::UiIconSpec {
+    ui_icons::icon(UiIconKey::ScenesSaveFormationTemplate)
+}
+
 #[must_use]
 pub fn open_choreography_icon() -> ui_icons::UiIconSpec {
     ui_icons::icon(UiIconKey::ScenesOpenChoreography)
@@ -218,6 +367,11 @@ pub fn save_choreography_icon() -> ui_icons::UiIconSpec {
     ui_icons::icon(UiIconKey::ScenesSaveChoreography)
 }
 
+#[must_use]
+pub fn save_choreography_as_icon() -> ui_icons::UiIconSpec {
+    ui_icons::icon(UiIconKey::ScenesSaveChoreographyAs)
+}
+
 #[must_use]
 pub fn navigate_settings_icon() -> ui_icons::UiIconSpec {
     ui_icons::icon(UiIconKey::ScenesNavigateSettings)
@@ -393,6 +547,422 @@ fn draw_edit_toolbar_row(ui: &mut Ui, state: &ScenesState, actions: &mut Vec<Sce
         {
             actions.push(ScenesAction::OpenDeleteSceneDialog);
         }
+        let auto_assign = scene_auto_assign_icon();
+        if add_scene_icon_button(ui, auto_assign, state.can_auto_assign_dancers)
+            .on_hover_text(strings.auto_assign_dancers.as_str())
+            .clicked()
+        {
+            actions.push(ScenesAction::AutoAssignDancers);
+        }
+        let insert_in_between = scene_insert_in_between_icon();
+        if add_scene_icon_button(ui, insert_in_between, state.can_insert_in_between_scene)
+            .on_hover_text(strings.insert_in_between_scene.as_str())
+            .clicked()
+        {
+            actions.push(ScenesAction::InsertInBetweenScene { formation: None });
+        }
+        let bend_paths = scene_bend_colliding_paths_icon();
+        if add_scene_icon_button(ui, bend_paths, state.can_bend_colliding_paths)
+            .on_hover_text(strings.bend_colliding_paths.as_str())
+            .clicked()
+        {
+            actions.push(ScenesAction::BendCollidingPaths);
+        }
+    });
+}
+
+/// Clicking a scene selects it; with Ctrl (Cmd) it is added to the selection or taken out, and
+/// with Shift every scene up to it is selected.
+#[must_use]
+pub fn scene_click_action(index: usize, command: bool, shift: bool) -> ScenesAction {
+    if command {
+        ScenesAction::ToggleSceneSelection { index }
+    } else if shift {
+        ScenesAction::ExtendSceneSelection { index }
+    } else {
+        ScenesAction::SelectScene { index }
+    }
+}
+
+/// The move for a scene dragged from `from` and dropped on the row at `row`, above or below its
+/// middle. `None` when the scene would stay where it is.
+#[must_use]
+pub fn move_scene_action(from: usize, row: usize, drop_below: bool) -> Option<ScenesAction> {
+    let target = if drop_below { row + 1 } else { row };
+    let to = if target > from { target - 1 } else { target };
+    (to != from).then_some(ScenesAction::MoveScene { from, to })
+}
+
+/// One count when the choreography has a musical timeline, otherwise one second.
+#[must_use]
+pub fn scene_time_shift_step_seconds(state: &ScenesState) -> f64 {
+    state
+        .musical_timeline
+        .as_ref()
+        .map_or(SHIFT_STEP_SECONDS, seconds_per_count)
+}
+
+fn draw_scene_drag_and_drop(
+    ui: &Ui,
+    response: &Response,
+    index: usize,
+    indicator_color: egui::Color32,
+    actions: &mut Vec<ScenesAction>,
+) {
+    response.dnd_set_drag_payload(index);
+    let Some(pointer) = ui.input(|input| input.pointer.interact_pos()) else {
+        return;
+    };
+    let drop_below = pointer.y > response.rect.center().y;
+    if response.dnd_hover_payload::<usize>().is_some() {
+        let y = if drop_below {
+            response.rect.bottom()
+        } else {
+            response.rect.top()
+        };
+        ui.painter().hline(
+            response.rect.x_range(),
+            y,
+            Stroke::new(DROP_INDICATOR_WIDTH_PX, indicator_color),
+        );
+    }
+    if let Some(from) = response.dnd_release_payload::<usize>()
+        && let Some(action) = move_scene_action(*from, index, drop_below)
+    {
+        actions.push(action);
+    }
+}
+
+fn draw_arrange_row(ui: &mut Ui, state: &ScenesState, actions: &mut Vec<ScenesAction>) {
+    let strings = scenes_translations(DEFAULT_LOCALE);
+    ui.horizontal_centered(|ui| {
+        ui.spacing_mut().item_spacing.x = material_style_metrics().spacings.spacing_12;
+        let duplicate = scene_duplicate_icon();
+        if add_scene_icon_button(ui, duplicate, state.can_duplicate_scene)
+            .on_hover_text(strings.duplicate_scene.as_str())
+            .clicked()
+        {
+            actions.push(ScenesAction::DuplicateScene);
+        }
+        ui.label(strings.shift_scene_time.as_str());
+        let step = scene_time_shift_step_seconds(state);
+        let earlier = scene_shift_earlier_icon();
+        if add_scene_icon_button(ui, earlier, state.can_shift_scene_timestamps)
+            .on_hover_text(strings.shift_scene_earlier.as_str())
+            .clicked()
+        {
+            actions.push(ScenesAction::ShiftSceneTimestamps { seconds: -step });
+        }
+        let later = scene_shift_later_icon();
+        if add_scene_icon_button(ui, later, state.can_shift_scene_timestamps)
+            .on_hover_text(strings.shift_scene_later.as_str())
+            .clicked()
+        {
+            actions.push(ScenesAction::ShiftSceneTimestamps { seconds: step });
+        }
+    });
+}
+
+/// The generated formations followed by the saved templates.
+#[must_use]
+pub fn formation_labels(state: &ScenesState, locale: &str) -> Vec<String> {
+    FormationKind::ALL
+        .iter()
+        .map(|kind| formation_kind_name(locale, *kind))
+        .chain(
+            state
+                .formation_templates
+                .iter()
+                .map(|template| template.name.clone()),
+        )
+        .collect()
+}
+
+#[must_use]
+pub fn formation_label_index(choice: FormationChoice) -> usize {
+    match choice {
+        FormationChoice::Generated(kind) => FormationKind::ALL
+            .iter()
+            .position(|candidate| *candidate == kind)
+            .unwrap_or_default(),
+        FormationChoice::Template(index) => FormationKind::ALL.len() + index,
+    }
+}
+
+#[must_use]
+pub fn formation_choice_for_label(label_index: usize) -> FormationChoice {
+    FormationKind::ALL.get(label_index).map_or_else(
+        || FormationChoice::Template(label_index - FormationKind::ALL.len()),
+        |kind| FormationChoice::Generated(*kind),
+    )
+}
+
+fn draw_formation_row(
+    ui: &mut Ui,
+    width_px: f32,
+    state: &ScenesState,
+    actions: &mut Vec<ScenesAction>,
+) {
+    let strings = scenes_translations(DEFAULT_LOCALE);
+    let labels = formation_labels(state, DEFAULT_LOCALE);
+    let label_refs = labels.iter().map(String::as_str).collect::<Vec<_>>();
+    let current_index = formation_label_index(state.formation_choice);
+    ui.horizontal_centered(|ui| {
+        ui.spacing_mut().item_spacing.x = material_style_metrics().spacings.spacing_12;
+        let apply = scene_apply_formation_icon();
+        if add_scene_icon_button(ui, apply, state.can_apply_formation)
+            .on_hover_text(strings.apply_formation.as_str())
+            .clicked()
+        {
+            actions.push(ScenesAction::ApplyFormation);
+        }
+        let insert_in_between = scene_insert_in_between_icon();
+        if add_scene_icon_button(ui, insert_in_between, state.can_insert_in_between_scene)
+            .on_hover_text(strings.insert_in_between_formation.as_str())
+            .clicked()
+        {
+            actions.push(ScenesAction::InsertInBetweenScene {
+                formation: Some(state.formation_choice),
+            });
+        }
+        let save = scene_save_formation_template_icon();
+        if add_scene_icon_button(ui, save, state.can_save_formation_template)
+            .on_hover_text(strings.save_formation_template.as_str())
+            .clicked()
+        {
+            actions.push(ScenesAction::SaveFormationTemplate);
+        }
+        ui.label(strings.formation.as_str());
+        let used_width = ui.min_rect().width() + ui.spacing().item_spacing.x;
+        let dropdown_width = (width_px - used_width).max(0.0);
+        if let Some(selected_index) = components::mode_dropdown(
+            ui,
+            egui::Id::new("scenes_formation_dropdown"),
+            Some(current_index),
+            &label_refs,
+            true,
+            dropdown_width,
+            TOOLBAR_ROW_HEIGHT_PX,
+        ) && selected_index != current_index
+        {
+            actions.push(ScenesAction::SelectFormation {
+                choice: formation_choice_for_label(selected_index),
+            });
+        }
+    });
+}
+
+/// Templates bring their own positions, so the parameters only apply to generated formations.
+fn draw_formation_count_row(ui: &mut Ui, state: &ScenesState, actions: &mut Vec<ScenesAction>) {
+    let strings = scenes_translations(DEFAULT_LOCALE);
+    let parameters = state.formation_parameters;
+    if let Some(count) = number_picker::draw(
+        ui,
+        NumberPickerUiState {
+            label: strings.formation_count.as_str(),
+            value: parameters.count as i32,
+            minimum: 1,
+            maximum: MAXIMUM_FORMATION_COUNT as i32,
+            step: 1,
+            enabled: matches!(state.formation_choice, FormationChoice::Generated(_)),
+        },
+    ) {
+        actions.push(ScenesAction::UpdateFormationParameters {
+            parameters: FormationParameters {
+                count: count.max(1) as usize,
+                ..parameters
+            },
+        });
+    }
+}
+
+fn draw_formation_spacing_row(ui: &mut Ui, state: &ScenesState, actions: &mut Vec<ScenesAction>) {
+    let strings = scenes_translations(DEFAULT_LOCALE);
+    let parameters = state.formation_parameters;
+    if let Some(spacing_steps) = number_picker::draw(
+        ui,
+        NumberPickerUiState {
+            label: strings.formation_spacing.as_str(),
+            value: parameters.spacing_steps as i32,
+            minimum: 1,
+            maximum: MAXIMUM_FORMATION_SPACING_STEPS as i32,
+            step: 1,
+            enabled: matches!(state.formation_choice, FormationChoice::Generated(_)),
+        },
+    ) {
+        actions.push(ScenesAction::UpdateFormationParameters {
+            parameters: FormationParameters {
+                spacing_steps: spacing_steps.max(1) as u32,
+                ..parameters
+            },
+        });
+    }
+}
+
+#[must_use]
+pub fn required_role_labels(state: &ScenesState, any_role_label: &str) -> Vec<String> {
+    std::iter::once(any_role_label.to_string())
+        .chain(state.required_role_names.iter().cloned())
+        .collect()
+}
+
+#[must_use]
+pub fn required_role_action(state: &ScenesState, label_index: usize) -> ScenesAction {
+    ScenesAction::SetRequiredRole {
+        position_indices: state.selected_position_indices.clone(),
+        role_index: label_index.checked_sub(1),
+    }
+}
+
+fn draw_required_role_row(
+    ui: &mut Ui,
+    width_px: f32,
+    state: &ScenesState,
+    actions: &mut Vec<ScenesAction>,
+) {
+    let strings = scenes_translations(DEFAULT_LOCALE);
+    let labels = required_role_labels(state, strings.required_role_any.as_str());
+    let label_refs = labels.iter().map(String::as_str).collect::<Vec<_>>();
+    let current_index = state.selected_required_role.map_or(0, |index| index + 1);
+    ui.horizontal_centered(|ui| {
+        ui.spacing_mut().item_spacing.x = material_style_metrics().spacings.spacing_12;
+        let label_width = ui.label(strings.required_role.as_str()).rect.width();
+        let dropdown_width = (width_px - label_width - ui.spacing().item_spacing.x).max(0.0);
+        if let Some(selected_index) = components::mode_dropdown(
+            ui,
+            egui::Id::new("scenes_required_role_dropdown"),
+            Some(current_index),
+            &label_refs,
+            true,
+            dropdown_width,
+            TOOLBAR_ROW_HEIGHT_PX,
+        ) && selected_index != current_index
+        {
+            actions.push(required_role_action(state, selected_index));
+        }
+    });
+}
+
+#[must_use]
+pub fn scene_variation_labels(state: &ScenesState, empty_label: &str) -> Vec<String> {
+    state
+        .scene_variations
+        .iter()
+        .map(|name| name.clone().unwrap_or_else(|| empty_label.to_string()))
+        .collect()
+}
+
+/// Switching picks one of the variations set aside; the first label is the active variation.
+#[must_use]
+pub fn switch_scene_variation_action(label_index: usize) -> Option<ScenesAction> {
+    label_index
+        .checked_sub(1)
+        .map(|variation| ScenesAction::SwitchSceneVariation { variation })
+}
+
+#[must_use]
+pub fn compare_scene_variation_labels(
+    state: &ScenesState,
+    off_label: &str,
+    empty_label: &str,
+) -> Vec<String> {
+    std::iter::once(off_label.to_string())
+        .chain(
+            scene_variation_labels(state, empty_label)
+                .into_iter()
+                .skip(1),
+        )
+        .collect()
+}
+
+#[must_use]
+pub fn compare_scene_variation_action(label_index: usize) -> ScenesAction {
+    ScenesAction::CompareSceneVariation {
+        variation: label_index.checked_sub(1),
+    }
+}
+
+fn draw_variation_row(
+    ui: &mut Ui,
+    width_px: f32,
+    state: &ScenesState,
+    actions: &mut Vec<ScenesAction>,
+) {
+    let strings = scenes_translations(DEFAULT_LOCALE);
+    let labels = scene_variation_labels(state, strings.scene_variation_empty.as_str());
+    let label_refs = labels.iter().map(String::as_str).collect::<Vec<_>>();
+    ui.horizontal_centered(|ui| {
+        ui.spacing_mut().item_spacing.x = material_style_metrics().spacings.spacing_12;
+        let branch = scene_branch_variation_icon();
+        if add_scene_icon_button(ui, branch, state.can_branch_scene_variation)
+            .on_hover_text(strings.branch_scene_variation.as_str())
+            .clicked()
+        {
+            actions.push(ScenesAction::BranchSceneVariation);
+        }
+        let promote = scene_promote_variation_icon();
+        if add_scene_icon_button(ui, promote, state.can_promote_scene_variation)
+            .on_hover_text(strings.promote_scene_variation.as_str())
+            .clicked()
+        {
+            actions.push(ScenesAction::PromoteSceneVariation);
+        }
+        if labels.is_empty() {
+            return;
+        }
+        ui.label(strings.scene_variation.as_str());
+        let used_width = ui.min_rect().width() + ui.spacing().item_spacing.x;
+        let dropdown_width = (width_px - used_width).max(0.0);
+        if let Some(selected_index) = components::mode_dropdown(
+            ui,
+            egui::Id::new("scenes_variation_dropdown"),
+            Some(0),
+            &label_refs,
+            labels.len() > 1,
+            dropdown_width,
+            TOOLBAR_ROW_HEIGHT_PX,
+        ) && let Some(action) = switch_scene_variation_action(selected_index)
+        {
+            actions.push(action);
+        }
+    });
+}
+
+fn draw_compare_variation_row(
+    ui: &mut Ui,
+    width_px: f32,
+    state: &ScenesState,
+    actions: &mut Vec<ScenesAction>,
+) {
+    let strings = scenes_translations(DEFAULT_LOCALE);
+    let labels = compare_scene_variation_labels(
+        state,
+        strings.compare_scene_variation_off.as_str(),
+        strings.scene_variation_empty.as_str(),
+    );
+    let label_refs = labels.iter().map(String::as_str).collect::<Vec<_>>();
+    let current_index = state
+        .compared_scene_variation
+        .map_or(0, |variation| variation + 1);
+    ui.horizontal_centered(|ui| {
+        ui.spacing_mut().item_spacing.x = material_style_metrics().spacings.spacing_12;
+        let label_width = ui
+            .label(strings.compare_scene_variation.as_str())
+            .rect
+            .width();
+        let dropdown_width = (width_px - label_width - ui.spacing().item_spacing.x).max(0.0);
+        if let Some(selected_index) = components::mode_dropdown(
+            ui,
+            egui::Id::new("scenes_compare_variation_dropdown"),
+            Some(current_index),
+            &label_refs,
+            true,
+            dropdown_width,
+            TOOLBAR_ROW_HEIGHT_PX,
+        ) && selected_index != current_index
+        {
+            actions.push(compare_scene_variation_action(selected_index));
+        }
     });
 }
 
@@ -414,6 +984,13 @@ fn draw_navigation_toolbar_row(ui: &mut Ui, state: &ScenesState, actions: &mut V
         {
             actions.push(ScenesAction::RequestSaveChoreography);
         }
+        let save_as = save_choreography_as_icon();
+        if add_scene_icon_button(ui, save_as, state.can_save_choreo_as)
+            .on_hover_text(strings.save_as.as_str())
+            .clicked()
+        {
+            actions.push(ScenesAction::RequestSaveChoreographyAs);
+        }
         let settings = navigate_settings_icon();
         if add_scene_icon_button(ui, settings, state.can_navigate_to_settings)
             .on_hover_text(strings.settings.as_str())
@@ -0,0 +1,183 @@
+//! Scene variations.
+//!
+//! A scene that has been branched is the anchor of its variations. The active variation stays
+//! in the scene list as the run of scenes right after the anchor with a deeper
+//! `variation_depth`, so playback, the floor and every scene command work on it unchanged. The
+//! other variations wait in the anchor's `variations`; an empty one is the main line without
+//! extra scenes. `current_variation` is kept as loaded and never switched to. The
+//! `choreo_master_mobile_json` README describes how this maps onto `.choreo` files.
+
+use std::fmt;
+use std::ops::Range;
+
+use choreo_master_mobile_json::SceneId;
+use choreo_models::SceneModel;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VariationError {
+    NoVariations,
+    UnknownVariation(usize),
+}
+
+impl fmt::Display for VariationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VariationError::NoVariations => {
+                write!(f, "The selected scene is not part of a variation.")
+            }
+            VariationError::UnknownVariation(variation) => {
+                write!(f, "There is no variation {}.", variation + 1)
+            }
+        }
+    }
+}
+
+impl std::error::Error for VariationError {}
+
+/// Returns the index of the anchor whose variation the scene at `index` belongs to.
+///
+/// A scene with variations of its own, or followed by deeper scenes, is its own anchor.
+#[must_use]
+pub fn variation_anchor(scenes: &[SceneModel], index: usize) -> Option<usize> {
+    let scene = scenes.get(index)?;
+    let has_branch = scenes
+        .get(index + 1)
+        .is_some_and(|next| next.variation_depth > scene.variation_depth);
+    if has_branch || !scene.variations.is_empty() {
+        return Some(index);
+    }
+    (0..index)
+        .rev()
+        .find(|&candidate| scenes[candidate].variation_depth < scene.variation_depth)
+}
+
+/// Range of the scenes that make up the active variation of `anchor`.
+#[must_use]
+pub fn active_variation_range(scenes: &[SceneModel], anchor: usize) -> Range<usize> {
+    let Some(depth) = scenes.get(anchor).map(|scene| scene.variation_depth) else {
+        return 0..0;
+    };
+    let start = anchor + 1;
+    let end = scenes[start..]
+        .iter()
+        .position(|scene| scene.variation_depth <= depth)
+        .map_or(scenes.len(), |offset| start + offset);
+    start..end
+}
+
+/// Names of the variations of `anchor`, the active one first. A variation is named after its
+/// first scene; the empty variation has no name.
+#[must_use]
+pub fn variation_names(scenes: &[SceneModel], anchor: usize) -> Vec<Option<String>> {
+    let Some(scene) = scenes.get(anchor) else {
+        return Vec::new();
+    };
+    std::iter::once(&scenes[active_variation_range(scenes, anchor)])
+        .chain(scene.variations.iter().map(Vec::as_slice))
+        .map(|variation| variation.first().map(|scene| scene.name.clone()))
+        .collect()
+}
+
+/// Branches a new variation from the scene at `index`, which becomes (or stays) its anchor.
+///
+/// The active variation is set aside and replaced by a single copy of the anchor, so the new
+/// variation starts from the anchor's formation. Returns the index of that copy.
+pub fn branch_variation(scenes: &mut Vec<SceneModel>, index: usize) -> usize {
+    let scene_id = next_variation_scene_id(scenes);
+    let range = active_variation_range(scenes, index);
+    let active = scenes.drain(range).collect::<Vec<_>>();
+    let anchor = &mut scenes[index];
+    anchor.variations.push(active);
+
+    let scene = SceneModel {
+        scene_id,
+        positions: anchor.positions.clone(),
+        name: format!("{} ({})", anchor.name, anchor.variations.len() + 1),
+        text: None,
+        fixed_positions: anchor.fixed_positions,
+        timestamp: None,
+        variation_depth: anchor.variation_depth + 1,
+        variations: Vec::new(),
+        current_variation: Vec::new(),
+        color: anchor.color.clone(),
+    };
+    scenes.insert(index + 1, scene);
+    index + 1
+}
+
+/// Makes `variation` of `anchor` the active one, setting the active variation aside in its slot.
+pub fn switch_variation(
+    scenes: &mut Vec<SceneModel>,
+    anchor: usize,
+    variation: usize,
+) -> Result<(), VariationError> {
+    let range = active_variation_range(scenes, anchor);
+    let alternative = scenes
+        .get_mut(anchor)
+        .and_then(|scene| scene.variations.get_mut(variation))
+        .ok_or(VariationError::UnknownVariation(variation))?;
+    let alternative = std::mem::take(alternative);
+    let active = scenes.splice(range, alternative).collect::<Vec<_>>();
+    scenes[anchor].variations[variation] = active;
+    Ok(())
+}
+
+/// Moves the active variation of `anchor` into the main line and drops the other variations.
+pub fn promote_variation(scenes: &mut [SceneModel], anchor: usize) -> Result<(), VariationError> {
+    let range = active_variation_range(scenes, anchor);
+    let Some(scene) = scenes.get_mut(anchor) else {
+        return Err(VariationError::NoVariations);
+    };
+    if range.is_empty() && scene.variations.is_empty() {
+        return Err(VariationError::NoVariations);
+    }
+    scene.variations.clear();
+    for scene in &mut scenes[range] {
+        lift_variation_depth(scene);
+    }
+    Ok(())
+}
+
+/// The scene of `variation` of `anchor` that corresponds to the scene at `index`: the one at the
+/// same place in the variation, or its last scene when the variation is shorter. The empty
+/// variation corresponds to the anchor itself.
+#[must_use]
+pub fn comparison_scene(
+    scenes: &[SceneModel],
+    anchor: usize,
+    variation: usize,
+    index: usize,
+) -> Option<&SceneModel> {
+    let alternative = scenes.get(anchor)?.variations.get(variation)?;
+    let offset = index.saturating_sub(anchor + 1);
+    alternative
+        .get(offset.min(alternative.len().saturating_sub(1)))
+        .or_else(|| scenes.get(anchor))
+}
+
+/// A scene id unused by the scene list and by every variation set aside in it.
+fn next_variation_scene_id(scenes: &[SceneModel]) -> SceneId {
+    fn max_scene_id(scenes: &[SceneModel]) -> i32 {
+        scenes
+            .iter()
+            .map(|scene| {
+                scene
+                    .variations
+                    .iter()
+                    .map(|variation| max_scene_id(variation))
+                    .fold(scene.scene_id.0, i32::max)
+            })
+            .max()
+            .unwrap_or(0)
+    }
+    SceneId(max_scene_id(scenes).saturating_add(1))
+}
+
+fn lift_variation_depth(scene: &mut SceneModel) {
+    scene.variation_depth = (scene.variation_depth - 1).max(0);
+    for variation in &mut scene.variations {
+        for nested in variation {
+            lift_variation_depth(nested);
+        }
+    }
+}
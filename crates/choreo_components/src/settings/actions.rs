@@ -1,5 +1,8 @@
 use std::collections::BTreeMap;
 
+use egui::KeyboardShortcut;
+
+use super::keyboard_shortcuts::ShortcutCommand;
 use crate::audio_player::AudioPlayerBackend;
 use crate::material::styling::material_schemes::MaterialThemeVariant;
 
@@ -7,16 +10,53 @@ use crate::material::styling::material_schemes::MaterialThemeVariant;
 pub enum SettingsAction {
     Initialize,
     NavigateBack,
-    LoadFromPreferences { entries: BTreeMap<String, String> },
+    LoadFromPreferences {
+        entries: BTreeMap<String, String>,
+    },
     Reload,
-    UpdateUseSystemTheme { enabled: bool },
-    UpdateIsDarkMode { enabled: bool },
-    UpdateUsePrimaryColor { enabled: bool },
-    UpdateUseSecondaryColor { enabled: bool },
-    UpdateUseTertiaryColor { enabled: bool },
-    UpdatePrimaryColorHex { value: String },
-    UpdateSecondaryColorHex { value: String },
-    UpdateTertiaryColorHex { value: String },
-    UpdateMaterialThemeVariant { variant: MaterialThemeVariant },
-    UpdateAudioPlayerBackend { backend: AudioPlayerBackend },
+    UpdateUseSystemTheme {
+        enabled: bool,
+    },
+    UpdateIsDarkMode {
+        enabled: bool,
+    },
+    UpdateUsePrimaryColor {
+        enabled: bool,
+    },
+    UpdateUseSecondaryColor {
+        enabled: bool,
+    },
+    UpdateUseTertiaryColor {
+        enabled: bool,
+    },
+    UpdatePrimaryColorHex {
+        value: String,
+    },
+    UpdateSecondaryColorHex {
+        value: String,
+    },
+    UpdateTertiaryColorHex {
+        value: String,
+    },
+    UpdateMaterialThemeVariant {
+        variant: MaterialThemeVariant,
+    },
+    UpdateAudioPlayerBackend {
+        backend: AudioPlayerBackend,
+    },
+    UpdateMinimumPlaybackSpeed {
+        percent: u32,
+    },
+    UpdateMaximumPlaybackSpeed {
+        percent: u32,
+    },
+    /// Waits for the next key press to bind to `command`; `None` stops waiting.
+    CaptureShortcut {
+        command: Option<ShortcutCommand>,
+    },
+    RemapShortcut {
+        command: ShortcutCommand,
+        shortcut: KeyboardShortcut,
+    },
+    ResetShortcuts,
 }
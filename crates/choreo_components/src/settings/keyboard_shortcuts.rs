@@ -0,0 +1,335 @@
+use std::collections::BTreeMap;
+
+use egui::Key;
+use egui::KeyboardShortcut;
+use egui::Modifiers;
+
+pub const KEYBOARD_SHORTCUTS_KEY: &str = "keyboard_shortcuts";
+
+/// Grid steps a nudge moves the selection by while Shift is held.
+pub const LARGE_NUDGE_GRID_STEPS: f64 = 4.0;
+
+/// Something the floor editor does on a key press.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ShortcutCommand {
+    NudgeLeft,
+    NudgeRight,
+    NudgeFront,
+    NudgeBack,
+    SelectAll,
+    Delete,
+    PreviousScene,
+    NextScene,
+    TogglePlayback,
+    AlignLeft,
+    AlignRight,
+    AlignFront,
+    AlignBack,
+    AlignStageCenter,
+    DistributeAlongX,
+    DistributeAlongY,
+    DistributeOnCircle,
+    MirrorLeftRight,
+    MirrorFrontBack,
+    ViewMode,
+    MoveMode,
+    RotateAroundCenterMode,
+    RotateAroundDancerMode,
+    ScaleMode,
+    LineOfSightMode,
+    FaceMode,
+    EditPathMode,
+    BendPathMode,
+}
+
+impl ShortcutCommand {
+    pub const ALL: [Self; 28] = [
+        Self::NudgeLeft,
+        Self::NudgeRight,
+        Self::NudgeFront,
+        Self::NudgeBack,
+        Self::SelectAll,
+        Self::Delete,
+        Self::PreviousScene,
+        Self::NextScene,
+        Self::TogglePlayback,
+        Self::AlignLeft,
+        Self::AlignRight,
+        Self::AlignFront,
+        Self::AlignBack,
+        Self::AlignStageCenter,
+        Self::DistributeAlongX,
+        Self::DistributeAlongY,
+        Self::DistributeOnCircle,
+        Self::MirrorLeftRight,
+        Self::MirrorFrontBack,
+        Self::ViewMode,
+        Self::MoveMode,
+        Self::RotateAroundCenterMode,
+        Self::RotateAroundDancerMode,
+        Self::ScaleMode,
+        Self::LineOfSightMode,
+        Self::FaceMode,
+        Self::EditPathMode,
+        Self::BendPathMode,
+    ];
+
+    #[must_use]
+    pub fn as_preference(self) -> &'static str {
+        match self {
+            Self::NudgeLeft => "nudge_left",
+            Self::NudgeRight => "nudge_right",
+            Self::NudgeFront => "nudge_front",
+            Self::NudgeBack => "nudge_back",
+            Self::SelectAll => "select_all",
+            Self::Delete => "delete",
+            Self::PreviousScene => "previous_scene",
+            Self::NextScene => "next_scene",
+            Self::TogglePlayback => "toggle_playback",
+            Self::AlignLeft => "align_left",
+            Self::AlignRight => "align_right",
+            Self::AlignFront => "align_front",
+            Self::AlignBack => "align_back",
+            Self::AlignStageCenter => "align_stage_center",
+            Self::DistributeAlongX => "distribute_along_x",
+            Self::DistributeAlongY => "distribute_along_y",
+            Self::DistributeOnCircle => "distribute_on_circle",
+            Self::MirrorLeftRight => "mirror_left_right",
+            Self::MirrorFrontBack => "mirror_front_back",
+            Self::ViewMode => "view_mode",
+            Self::MoveMode => "move_mode",
+            Self::RotateAroundCenterMode => "rotate_around_center_mode",
+            Self::RotateAroundDancerMode => "rotate_around_dancer_mode",
+            Self::ScaleMode => "scale_mode",
+            Self::LineOfSightMode => "line_of_sight_mode",
+            Self::FaceMode => "face_mode",
+            Self::EditPathMode => "edit_path_mode",
+            Self::BendPathMode => "bend_path_mode",
+        }
+    }
+
+    #[must_use]
+    pub fn from_preference(value: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|command| command.as_preference() == value)
+    }
+
+    /// The `SelectMode` index the command switches to.
+    #[must_use]
+    pub fn mode_index(self) -> Option<i32> {
+        match self {
+            Self::ViewMode => Some(0),
+            Self::MoveMode => Some(1),
+            Self::RotateAroundCenterMode => Some(2),
+            Self::RotateAroundDancerMode => Some(3),
+            Self::ScaleMode => Some(4),
+            Self::LineOfSightMode => Some(5),
+            Self::FaceMode => Some(6),
+            Self::EditPathMode => Some(7),
+            Self::BendPathMode => Some(8),
+            _ => None,
+        }
+    }
+
+    /// One grid step in floor coordinates, where the front is positive y.
+    #[must_use]
+    pub fn nudge_direction(self) -> Option<(f64, f64)> {
+        match self {
+            Self::NudgeLeft => Some((-1.0, 0.0)),
+            Self::NudgeRight => Some((1.0, 0.0)),
+            Self::NudgeFront => Some((0.0, 1.0)),
+            Self::NudgeBack => Some((0.0, -1.0)),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn default_shortcut(self) -> KeyboardShortcut {
+        let (modifiers, key) = match self {
+            Self::NudgeLeft => (Modifiers::NONE, Key::ArrowLeft),
+            Self::NudgeRight => (Modifiers::NONE, Key::ArrowRight),
+            Self::NudgeFront => (Modifiers::NONE, Key::ArrowUp),
+            Self::NudgeBack => (Modifiers::NONE, Key::ArrowDown),
+            Self::SelectAll => (Modifiers::COMMAND, Key::A),
+            Self::Delete => (Modifiers::NONE, Key::Delete),
+            Self::PreviousScene => (Modifiers::NONE, Key::PageUp),
+            Self::NextScene => (Modifiers::NONE, Key::PageDown),
+            Self::TogglePlayback => (Modifiers::NONE, Key::Space),
+            Self::AlignLeft => (Modifiers::ALT, Key::ArrowLeft),
+            Self::AlignRight => (Modifiers::ALT, Key::ArrowRight),
+            Self::AlignFront => (Modifiers::ALT, Key::ArrowUp),
+            Self::AlignBack => (Modifiers::ALT, Key::ArrowDown),
+            Self::AlignStageCenter => (Modifiers::ALT, Key::C),
+            Self::DistributeAlongX => (Modifiers::ALT, Key::H),
+            Self::DistributeAlongY => (Modifiers::ALT, Key::V),
+            Self::DistributeOnCircle => (Modifiers::ALT, Key::O),
+            Self::MirrorLeftRight => (Modifiers::ALT, Key::M),
+            Self::MirrorFrontBack => (Modifiers::ALT | Modifiers::SHIFT, Key::M),
+            Self::ViewMode => (Modifiers::NONE, Key::Num1),
+            Self::MoveMode => (Modifiers::NONE, Key::Num2),
+            Self::RotateAroundCenterMode => (Modifiers::NONE, Key::Num3),
+            Self::RotateAroundDancerMode => (Modifiers::NONE, Key::Num4),
+            Self::ScaleMode => (Modifiers::NONE, Key::Num5),
+            Self::LineOfSightMode => (Modifiers::NONE, Key::Num6),
+            Self::FaceMode => (Modifiers::NONE, Key::Num7),
+            Self::EditPathMode => (Modifiers::NONE, Key::Num8),
+            Self::BendPathMode => (Modifiers::NONE, Key::Num9),
+        };
+        KeyboardShortcut::new(modifiers, key)
+    }
+}
+
+/// The key bound to every [`ShortcutCommand`]; no two commands share a shortcut.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyboardShortcuts {
+    bindings: BTreeMap<ShortcutCommand, KeyboardShortcut>,
+}
+
+impl Default for KeyboardShortcuts {
+    fn default() -> Self {
+        Self {
+            bindings: ShortcutCommand::ALL
+                .into_iter()
+                .map(|command| (command, command.default_shortcut()))
+                .collect(),
+        }
+    }
+}
+
+impl KeyboardShortcuts {
+    #[must_use]
+    pub fn shortcut(&self, command: ShortcutCommand) -> KeyboardShortcut {
+        self.bindings
+            .get(&command)
+            .copied()
+            .unwrap_or_else(|| command.default_shortcut())
+    }
+
+    /// Binds `shortcut` to `command`; a command that had it before takes over the old shortcut
+    /// of `command`.
+    pub fn remap(&mut self, command: ShortcutCommand, shortcut: KeyboardShortcut) {
+        let shortcut = normalized(shortcut);
+        let previous = self.shortcut(command);
+        if let Some(other) = self
+            .bindings
+            .iter()
+            .find(|(other, bound)| **other != command && **bound == shortcut)
+            .map(|(other, _)| *other)
+        {
+            self.bindings.insert(other, previous);
+        }
+        self.bindings.insert(command, shortcut);
+    }
+
+    /// The command bound to the key press. Nudges also fire with an extra Shift, which the
+    /// caller turns into a larger step.
+    #[must_use]
+    pub fn command_for(&self, key: Key, modifiers: Modifiers) -> Option<ShortcutCommand> {
+        let exact = self.bindings.iter().find(|(_, shortcut)| {
+            shortcut.logical_key == key && modifiers.matches_exact(shortcut.modifiers)
+        });
+        if let Some((command, _)) = exact {
+            return Some(*command);
+        }
+        if !modifiers.shift {
+            return None;
+        }
+        let without_shift = Modifiers {
+            shift: false,
+            ..modifiers
+        };
+        self.bindings
+            .iter()
+            .find(|(command, shortcut)| {
+                command.nudge_direction().is_some()
+                    && shortcut.logical_key == key
+                    && without_shift.matches_exact(shortcut.modifiers)
+            })
+            .map(|(command, _)| *command)
+    }
+
+    #[must_use]
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Reads the JSON object written by [`Self::to_preference`]. Commands that are missing or
+    /// cannot be read keep their default shortcut.
+    #[must_use]
+    pub fn from_preference(value: &str) -> Self {
+        let mut shortcuts = Self::default();
+        let Ok(entries) = serde_json::from_str::<BTreeMap<String, String>>(value) else {
+            return shortcuts;
+        };
+        for (name, text) in entries {
+            if let Some(command) = ShortcutCommand::from_preference(&name)
+                && let Some(shortcut) = parse_shortcut(&text)
+            {
+                shortcuts.remap(command, shortcut);
+            }
+        }
+        shortcuts
+    }
+
+    #[must_use]
+    pub fn to_preference(&self) -> String {
+        let entries = self
+            .bindings
+            .iter()
+            .map(|(command, shortcut)| (command.as_preference(), shortcut_text(*shortcut)))
+            .collect::<BTreeMap<_, _>>();
+        serde_json::to_string(&entries).unwrap_or_default()
+    }
+}
+
+/// Text such as `Ctrl+Shift+A`; `Ctrl` stands for the command key on macOS.
+#[must_use]
+pub fn shortcut_text(shortcut: KeyboardShortcut) -> String {
+    let modifiers = shortcut.modifiers;
+    let mut parts = Vec::new();
+    if modifiers.command || modifiers.ctrl || modifiers.mac_cmd {
+        parts.push("Ctrl");
+    }
+    if modifiers.alt {
+        parts.push("Alt");
+    }
+    if modifiers.shift {
+        parts.push("Shift");
+    }
+    parts.push(shortcut.logical_key.name());
+    parts.join("+")
+}
+
+#[must_use]
+pub fn parse_shortcut(text: &str) -> Option<KeyboardShortcut> {
+    let mut modifiers = Modifiers::NONE;
+    let mut key = None;
+    for part in text.split('+').map(str::trim) {
+        match part {
+            "Ctrl" | "Cmd" => modifiers = modifiers.plus(Modifiers::COMMAND),
+            "Alt" => modifiers = modifiers.plus(Modifiers::ALT),
+            "Shift" => modifiers = modifiers.plus(Modifiers::SHIFT),
+            _ if key.is_none() => key = Some(Key::from_name(part)?),
+            _ => return None,
+        }
+    }
+    key.map(|key| KeyboardShortcut::new(modifiers, key))
+}
+
+/// Folds Ctrl and the macOS command key into [`Modifiers::COMMAND`] so a captured shortcut
+/// matches on every platform.
+fn normalized(shortcut: KeyboardShortcut) -> KeyboardShortcut {
+    let modifiers = shortcut.modifiers;
+    let mut normalized = Modifiers::NONE;
+    if modifiers.command || modifiers.ctrl || modifiers.mac_cmd {
+        normalized = normalized.plus(Modifiers::COMMAND);
+    }
+    if modifiers.alt {
+        normalized = normalized.plus(Modifiers::ALT);
+    }
+    if modifiers.shift {
+        normalized = normalized.plus(Modifiers::SHIFT);
+    }
+    KeyboardShortcut::new(normalized, shortcut.logical_key)
+}
@@ -1,4 +1,5 @@
 pub mod actions;
+pub mod keyboard_shortcuts;
 pub mod messages;
 pub mod provider;
 pub mod reducer;
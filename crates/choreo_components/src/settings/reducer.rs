@@ -1,10 +1,18 @@
 use super::actions::SettingsAction;
+use super::keyboard_shortcuts::KEYBOARD_SHORTCUTS_KEY;
+use super::keyboard_shortcuts::KeyboardShortcuts;
 use super::state::AUDIO_PLAYER_BACKEND_KEY;
 use super::state::AudioPlayerBackend;
+use super::state::DEFAULT_MAXIMUM_PLAYBACK_SPEED_PERCENT;
+use super::state::DEFAULT_MINIMUM_PLAYBACK_SPEED_PERCENT;
 use super::state::DEFAULT_PRIMARY_COLOR_HEX;
 use super::state::DEFAULT_SECONDARY_COLOR_HEX;
 use super::state::DEFAULT_TERTIARY_COLOR_HEX;
 use super::state::MATERIAL_THEME_VARIANT_KEY;
+use super::state::MAXIMUM_PLAYBACK_SPEED_KEY;
+use super::state::MAXIMUM_PLAYBACK_SPEED_PERCENT_OPTIONS;
+use super::state::MINIMUM_PLAYBACK_SPEED_KEY;
+use super::state::MINIMUM_PLAYBACK_SPEED_PERCENT_OPTIONS;
 use super::state::PRIMARY_COLOR_KEY;
 use super::state::SECONDARY_COLOR_KEY;
 use super::state::SettingsState;
@@ -31,7 +39,9 @@ pub fn reduce(state: &mut SettingsState, action: SettingsAction) {
                 state.theme_mode = system_mode;
             }
         }
-        SettingsAction::NavigateBack => {}
+        SettingsAction::NavigateBack => {
+            state.capturing_shortcut = None;
+        }
         SettingsAction::LoadFromPreferences { entries } => {
             state.preferences = entries;
             apply_preferences(state);
@@ -162,6 +172,40 @@ pub fn reduce(state: &mut SettingsState, action: SettingsAction) {
                 backend.as_preference().to_ascii_lowercase(),
             );
         }
+        SettingsAction::UpdateMinimumPlaybackSpeed { percent } => {
+            if !MINIMUM_PLAYBACK_SPEED_PERCENT_OPTIONS.contains(&percent) {
+                return;
+            }
+            state.minimum_playback_speed_percent = percent;
+            state
+                .preferences
+                .insert(MINIMUM_PLAYBACK_SPEED_KEY.to_string(), percent.to_string());
+        }
+        SettingsAction::UpdateMaximumPlaybackSpeed { percent } => {
+            if !MAXIMUM_PLAYBACK_SPEED_PERCENT_OPTIONS.contains(&percent) {
+                return;
+            }
+            state.maximum_playback_speed_percent = percent;
+            state
+                .preferences
+                .insert(MAXIMUM_PLAYBACK_SPEED_KEY.to_string(), percent.to_string());
+        }
+        SettingsAction::CaptureShortcut { command } => {
+            state.capturing_shortcut = command;
+        }
+        SettingsAction::RemapShortcut { command, shortcut } => {
+            state.capturing_shortcut = None;
+            state.keyboard_shortcuts.remap(command, shortcut);
+            state.preferences.insert(
+                KEYBOARD_SHORTCUTS_KEY.to_string(),
+                state.keyboard_shortcuts.to_preference(),
+            );
+        }
+        SettingsAction::ResetShortcuts => {
+            state.capturing_shortcut = None;
+            state.keyboard_shortcuts = KeyboardShortcuts::default();
+            state.preferences.remove(KEYBOARD_SHORTCUTS_KEY);
+        }
     }
 }
 
@@ -212,6 +256,24 @@ fn apply_preferences(state: &mut SettingsState) {
             .map(String::as_str)
             .unwrap_or(AudioPlayerBackend::RODIO_KEY),
     );
+    state.minimum_playback_speed_percent = percent_pref(
+        &state.preferences,
+        MINIMUM_PLAYBACK_SPEED_KEY,
+        &MINIMUM_PLAYBACK_SPEED_PERCENT_OPTIONS,
+        DEFAULT_MINIMUM_PLAYBACK_SPEED_PERCENT,
+    );
+    state.maximum_playback_speed_percent = percent_pref(
+        &state.preferences,
+        MAXIMUM_PLAYBACK_SPEED_KEY,
+        &MAXIMUM_PLAYBACK_SPEED_PERCENT_OPTIONS,
+        DEFAULT_MAXIMUM_PLAYBACK_SPEED_PERCENT,
+    );
+    state.keyboard_shortcuts = state
+        .preferences
+        .get(KEYBOARD_SHORTCUTS_KEY)
+        .map(String::as_str)
+        .map(KeyboardShortcuts::from_preference)
+        .unwrap_or_default();
     state.can_use_system_theme = supports_system_theme_toggle();
     if state.can_use_system_theme
         && state.use_system_theme
@@ -234,6 +296,19 @@ fn bool_pref(
         .unwrap_or(fallback)
 }
 
+fn percent_pref(
+    prefs: &std::collections::BTreeMap<String, String>,
+    key: &str,
+    options: &[u32],
+    fallback: u32,
+) -> u32 {
+    prefs
+        .get(key)
+        .and_then(|value| value.trim().parse::<u32>().ok())
+        .filter(|percent| options.contains(percent))
+        .unwrap_or(fallback)
+}
+
 fn set_bool_pref(state: &mut SettingsState, key: &str, value: bool) {
     state.preferences.insert(key.to_string(), value.to_string());
 }
@@ -4,6 +4,8 @@ use crate::material::styling::material_schemes::MaterialSchemes;
 use crate::material::styling::material_schemes::MaterialThemeVariant;
 pub use material3::ThemeMode;
 
+pub use super::keyboard_shortcuts::KeyboardShortcuts;
+pub use super::keyboard_shortcuts::ShortcutCommand;
 pub use crate::audio_player::AudioPlayerBackend;
 
 pub const THEME_KEY: &str = "theme";
@@ -16,11 +18,20 @@ pub const SECONDARY_COLOR_KEY: &str = "secondary_color";
 pub const TERTIARY_COLOR_KEY: &str = "tertiary_color";
 pub const AUDIO_PLAYER_BACKEND_KEY: &str = "audio_player_backend";
 pub const MATERIAL_THEME_VARIANT_KEY: &str = "material_theme_variant";
+pub const MINIMUM_PLAYBACK_SPEED_KEY: &str = "minimum_playback_speed";
+pub const MAXIMUM_PLAYBACK_SPEED_KEY: &str = "maximum_playback_speed";
 
 pub const DEFAULT_PRIMARY_COLOR_HEX: &str = "#FF1976D2";
 pub const DEFAULT_SECONDARY_COLOR_HEX: &str = "#FF675A84";
 pub const DEFAULT_TERTIARY_COLOR_HEX: &str = "#FF825A2C";
 
+/// Choices for the ends of the audio speed slider, in percent; both stay within what the
+/// time stretch supports.
+pub const MINIMUM_PLAYBACK_SPEED_PERCENT_OPTIONS: [u32; 5] = [50, 60, 70, 80, 90];
+pub const MAXIMUM_PLAYBACK_SPEED_PERCENT_OPTIONS: [u32; 5] = [110, 120, 130, 140, 150];
+pub const DEFAULT_MINIMUM_PLAYBACK_SPEED_PERCENT: u32 = 50;
+pub const DEFAULT_MAXIMUM_PLAYBACK_SPEED_PERCENT: u32 = 150;
+
 pub type MaterialSchemeState = MaterialSchemes;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -36,6 +47,11 @@ pub struct SettingsState {
     pub tertiary_color_hex: String,
     pub material_theme_variant: MaterialThemeVariant,
     pub audio_player_backend: AudioPlayerBackend,
+    pub minimum_playback_speed_percent: u32,
+    pub maximum_playback_speed_percent: u32,
+    pub keyboard_shortcuts: KeyboardShortcuts,
+    /// The command whose shortcut the next key press replaces.
+    pub capturing_shortcut: Option<ShortcutCommand>,
     pub preferences: BTreeMap<String, String>,
     pub material_scheme: MaterialSchemeState,
     pub material_update_count: usize,
@@ -57,6 +73,10 @@ impl Default for SettingsState {
             audio_player_backend: AudioPlayerBackend::from_preference(
                 AudioPlayerBackend::RODIO_KEY,
             ),
+            minimum_playback_speed_percent: DEFAULT_MINIMUM_PLAYBACK_SPEED_PERCENT,
+            maximum_playback_speed_percent: DEFAULT_MAXIMUM_PLAYBACK_SPEED_PERCENT,
+            keyboard_shortcuts: KeyboardShortcuts::default(),
+            capturing_shortcut: None,
             preferences: BTreeMap::new(),
             material_scheme: MaterialSchemeState::default(),
             material_update_count: 0,
@@ -1,3 +1,4 @@
+use super::keyboard_shortcuts::ShortcutCommand;
 use crate::i18n::t;
 
 #[derive(Debug, Clone)]
@@ -22,6 +23,12 @@ pub struct SettingsTranslations {
     pub backend_rodio: String,
     pub backend_awedio: String,
     pub backend_browser: String,
+    pub playback_speed: String,
+    pub minimum_playback_speed: String,
+    pub maximum_playback_speed: String,
+    pub keyboard_shortcuts: String,
+    pub shortcut_capture_prompt: String,
+    pub reset_shortcuts: String,
     pub colors: String,
     pub primary_color: String,
     pub secondary_color: String,
@@ -57,6 +64,12 @@ pub fn settings_translations(locale: &str) -> SettingsTranslations {
         backend_rodio: t(locale, "SettingsAudioBackendRodioLabel"),
         backend_awedio: t(locale, "SettingsAudioBackendAwedioLabel"),
         backend_browser: t(locale, "SettingsAudioBackendBrowserLabel"),
+        playback_speed: t(locale, "SettingsPlaybackSpeedLabel"),
+        minimum_playback_speed: t(locale, "SettingsMinimumPlaybackSpeedLabel"),
+        maximum_playback_speed: t(locale, "SettingsMaximumPlaybackSpeedLabel"),
+        keyboard_shortcuts: t(locale, "SettingsKeyboardShortcutsLabel"),
+        shortcut_capture_prompt: t(locale, "SettingsShortcutCapturePrompt"),
+        reset_shortcuts: t(locale, "SettingsResetShortcuts"),
         colors: t(locale, "SettingsColorsLabel"),
         primary_color: t(locale, "SettingsPrimaryColorLabel"),
         secondary_color: t(locale, "SettingsSecondaryColorLabel"),
@@ -69,3 +82,38 @@ pub fn settings_translations(locale: &str) -> SettingsTranslations {
         dock_bottom: t(locale, "ColorPickerDockBottom"),
     }
 }
+
+#[must_use]
+pub fn shortcut_command_label(locale: &str, command: ShortcutCommand) -> String {
+    let key = match command {
+        ShortcutCommand::NudgeLeft => "ShortcutNudgeLeft",
+        ShortcutCommand::NudgeRight => "ShortcutNudgeRight",
+        ShortcutCommand::NudgeFront => "ShortcutNudgeFront",
+        ShortcutCommand::NudgeBack => "ShortcutNudgeBack",
+        ShortcutCommand::SelectAll => "ShortcutSelectAll",
+        ShortcutCommand::Delete => "ShortcutDelete",
+        ShortcutCommand::PreviousScene => "ShortcutPreviousScene",
+        ShortcutCommand::NextScene => "ShortcutNextScene",
+        ShortcutCommand::TogglePlayback => "ShortcutTogglePlayback",
+        ShortcutCommand::AlignLeft => "ArrangeAlignLeft",
+        ShortcutCommand::AlignRight => "ArrangeAlignRight",
+        ShortcutCommand::AlignFront => "ArrangeAlignFront",
+        ShortcutCommand::AlignBack => "ArrangeAlignBack",
+        ShortcutCommand::AlignStageCenter => "ArrangeAlignStageCenter",
+        ShortcutCommand::DistributeAlongX => "ArrangeDistributeAlongX",
+        ShortcutCommand::DistributeAlongY => "ArrangeDistributeAlongY",
+        ShortcutCommand::DistributeOnCircle => "ArrangeDistributeOnCircle",
+        ShortcutCommand::MirrorLeftRight => "ArrangeMirrorLeftRight",
+        ShortcutCommand::MirrorFrontBack => "ArrangeMirrorFrontBack",
+        ShortcutCommand::ViewMode => "ModeView",
+        ShortcutCommand::MoveMode => "ModeMove",
+        ShortcutCommand::RotateAroundCenterMode => "ModeRotateAroundCenter",
+        ShortcutCommand::RotateAroundDancerMode => "ModeRotateAroundDancer",
+        ShortcutCommand::ScaleMode => "ModeScale",
+        ShortcutCommand::LineOfSightMode => "ModeLineOfSight",
+        ShortcutCommand::FaceMode => "ModeFace",
+        ShortcutCommand::EditPathMode => "ModeEditPath",
+        ShortcutCommand::BendPathMode => "ModeBendPath",
+    };
+    t(locale, key)
+}
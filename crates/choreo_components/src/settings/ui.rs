@@ -3,12 +3,15 @@ use std::borrow::Cow;
 use egui::Color32;
 use egui::CornerRadius;
 use egui::Frame;
+use egui::Key;
+use egui::KeyboardShortcut;
 use egui::Layout;
 use egui::Margin;
 use egui::Sense;
 use egui::Stroke;
 use egui::Ui;
 use egui::vec2;
+use egui_material3::MaterialButton;
 
 use crate::material::components;
 use crate::material::components::color_picker::state::ColorPickerDock;
@@ -22,10 +25,15 @@ use crate::material::styling::material_typography as typography;
 use crate::material::styling::material_typography::TypographyRole;
 
 use super::actions::SettingsAction;
+use super::keyboard_shortcuts::ShortcutCommand;
+use super::keyboard_shortcuts::shortcut_text;
 use super::state::AudioPlayerBackend;
+use super::state::MAXIMUM_PLAYBACK_SPEED_PERCENT_OPTIONS;
+use super::state::MINIMUM_PLAYBACK_SPEED_PERCENT_OPTIONS;
 use super::state::SettingsState;
 use super::state::ThemeMode;
 use super::translations::settings_translations;
+use super::translations::shortcut_command_label;
 use crate::material::styling::material_schemes::MaterialThemeVariant;
 
 const DEFAULT_LOCALE: &str = "en";
@@ -116,6 +124,12 @@ pub fn draw(ui: &mut Ui, state: &SettingsState) -> Vec<SettingsAction> {
                     ui.add_space(card_spacing_token());
                     draw_audio_backend_card(ui, state, &strings, &mut actions);
                 }
+
+                ui.add_space(card_spacing_token());
+                draw_playback_speed_card(ui, state, &strings, &mut actions);
+
+                ui.add_space(card_spacing_token());
+                draw_keyboard_shortcuts_card(ui, state, &strings, &mut actions);
             });
         });
 
@@ -240,6 +254,8 @@ pub fn visible_settings_card_headers(
     if shows_audio_backend_card() {
         headers.push(strings.audio_backend.clone());
     }
+    headers.push(strings.playback_speed.clone());
+    headers.push(strings.keyboard_shortcuts.clone());
 
     let _ = state;
     headers
@@ -428,6 +444,159 @@ fn draw_audio_backend_card(
     });
 }
 
+fn draw_playback_speed_card(
+    ui: &mut Ui,
+    state: &SettingsState,
+    strings: &super::translations::SettingsTranslations,
+    actions: &mut Vec<SettingsAction>,
+) {
+    draw_settings_card(ui, |ui| {
+        draw_card_header(ui, strings.playback_speed.as_str());
+        ui.add_space(row_spacing_token());
+
+        draw_card_header(ui, strings.minimum_playback_speed.as_str());
+        if let Some(percent) = draw_playback_speed_dropdown(
+            ui,
+            "settings_minimum_playback_speed_dropdown",
+            &MINIMUM_PLAYBACK_SPEED_PERCENT_OPTIONS,
+            state.minimum_playback_speed_percent,
+        ) {
+            actions.push(SettingsAction::UpdateMinimumPlaybackSpeed { percent });
+        }
+
+        ui.add_space(row_spacing_token());
+        draw_card_header(ui, strings.maximum_playback_speed.as_str());
+        if let Some(percent) = draw_playback_speed_dropdown(
+            ui,
+            "settings_maximum_playback_speed_dropdown",
+            &MAXIMUM_PLAYBACK_SPEED_PERCENT_OPTIONS,
+            state.maximum_playback_speed_percent,
+        ) {
+            actions.push(SettingsAction::UpdateMaximumPlaybackSpeed { percent });
+        }
+    });
+}
+
+fn draw_keyboard_shortcuts_card(
+    ui: &mut Ui,
+    state: &SettingsState,
+    strings: &super::translations::SettingsTranslations,
+    actions: &mut Vec<SettingsAction>,
+) {
+    if let Some(command) = state.capturing_shortcut
+        && let Some(action) = captured_shortcut_action(ui.ctx(), command)
+    {
+        actions.push(action);
+    }
+
+    draw_settings_card(ui, |ui| {
+        draw_card_header(ui, strings.keyboard_shortcuts.as_str());
+        ui.add_space(row_spacing_token());
+
+        let palette = material_palette_for_visuals(ui.visuals());
+        for command in ShortcutCommand::ALL {
+            ui.allocate_ui_with_layout(
+                vec2(ui.available_width(), toggle_row_height_token()),
+                Layout::left_to_right(egui::Align::Center),
+                |ui| {
+                    ui.label(
+                        typography::rich_text_for_role(
+                            shortcut_command_label(DEFAULT_LOCALE, command),
+                            toggle_label_role(),
+                        )
+                        .color(palette.on_surface),
+                    );
+                    ui.with_layout(Layout::right_to_left(egui::Align::Center), |ui| {
+                        let text = if state.capturing_shortcut == Some(command) {
+                            strings.shortcut_capture_prompt.clone()
+                        } else {
+                            shortcut_text(state.keyboard_shortcuts.shortcut(command))
+                        };
+                        if ui.add(MaterialButton::new(text.as_str())).clicked() {
+                            actions.push(SettingsAction::CaptureShortcut {
+                                command: Some(command),
+                            });
+                        }
+                    });
+                },
+            );
+        }
+
+        ui.add_space(row_spacing_token());
+        if ui
+            .add(MaterialButton::new(strings.reset_shortcuts.as_str()))
+            .clicked()
+        {
+            actions.push(SettingsAction::ResetShortcuts);
+        }
+    });
+}
+
+/// Turns the next key press into the new shortcut of `command`; Escape stops waiting instead.
+#[must_use]
+pub fn captured_shortcut_action(
+    ctx: &egui::Context,
+    command: ShortcutCommand,
+) -> Option<SettingsAction> {
+    ctx.input_mut(|input| {
+        let (key, modifiers) = input.events.iter().find_map(|event| match event {
+            egui::Event::Key {
+                key,
+                pressed: true,
+                repeat: false,
+                modifiers,
+                ..
+            } => Some((*key, *modifiers)),
+            _ => None,
+        })?;
+        input.consume_key(modifiers, key);
+        Some(if key == Key::Escape && modifiers.is_none() {
+            SettingsAction::CaptureShortcut { command: None }
+        } else {
+            SettingsAction::RemapShortcut {
+                command,
+                shortcut: KeyboardShortcut::new(modifiers, key),
+            }
+        })
+    })
+}
+
+/// Returns the newly picked percentage, if any.
+fn draw_playback_speed_dropdown(
+    ui: &mut Ui,
+    id: &str,
+    options: &[u32],
+    selected_percent: u32,
+) -> Option<u32> {
+    let labels = playback_speed_dropdown_labels(options);
+    let label_refs = labels.iter().map(String::as_str).collect::<Vec<_>>();
+    let selected_index = options
+        .iter()
+        .position(|percent| *percent == selected_percent)
+        .unwrap_or(0);
+    let response = components::mode_dropdown(
+        ui,
+        egui::Id::new(id),
+        Some(selected_index),
+        label_refs.as_slice(),
+        true,
+        ui.available_width(),
+        dropdown_height_token(),
+    );
+
+    response
+        .filter(|next_index| *next_index != selected_index)
+        .and_then(|next_index| options.get(next_index).copied())
+}
+
+#[must_use]
+pub fn playback_speed_dropdown_labels(options: &[u32]) -> Vec<String> {
+    options
+        .iter()
+        .map(|percent| format!("{percent}%"))
+        .collect()
+}
+
 fn draw_color_card(ui: &mut Ui, spec: ColorCardSpec<'_>, actions: &mut Vec<SettingsAction>) {
     draw_settings_card(ui, |ui| {
         draw_card_header(ui, spec.header);
@@ -10,6 +10,7 @@ use crate::choreo_main::ChoreoMainBehaviorDependencies;
 use crate::choreo_main::MainPageDependencies;
 use crate::preferences::PlatformPreferences;
 use crate::preferences::Preferences;
+use crate::preferences::RecoveryStore;
 use std::rc::Rc;
 
 pub fn app_title() -> &'static str {
@@ -39,8 +40,22 @@ pub fn default_main_page_dependencies() -> MainPageDependencies {
     MainPageDependencies {
         behavior_dependencies: ChoreoMainBehaviorDependencies {
             preferences: Some(preferences),
+            recovery_store: platform_recovery_store(),
             ..ChoreoMainBehaviorDependencies::default()
         },
         ..MainPageDependencies::default()
     }
 }
+
+#[cfg(not(target_arch = "wasm32"))]
+fn platform_recovery_store() -> Option<Rc<dyn RecoveryStore>> {
+    Some(Rc::new(crate::preferences::FileRecoveryStore::new(
+        app_title(),
+    )))
+}
+
+/// Browser builds have no crash-recovery storage yet.
+#[cfg(target_arch = "wasm32")]
+fn platform_recovery_store() -> Option<Rc<dyn RecoveryStore>> {
+    None
+}
@@ -22,7 +22,14 @@ pub enum SliderWithTicksInteraction {
     DragCompleted { value: f64 },
 }
 
-pub fn draw(ui: &mut Ui, state: SliderWithTicksUiState<'_>) -> Vec<SliderWithTicksInteraction> {
+#[derive(Debug, Clone, PartialEq)]
+pub struct SliderWithTicksResponse {
+    /// Where the slider was laid out; the ticks are spread across its width.
+    pub rect: egui::Rect,
+    pub interactions: Vec<SliderWithTicksInteraction>,
+}
+
+pub fn draw(ui: &mut Ui, state: SliderWithTicksUiState<'_>) -> SliderWithTicksResponse {
     let mut interactions = Vec::new();
     let span = state.maximum - state.minimum;
     let has_valid_range = span > 0.0;
@@ -88,7 +95,10 @@ pub fn draw(ui: &mut Ui, state: SliderWithTicksUiState<'_>) -> Vec<SliderWithTic
         }
     });
 
-    interactions
+    SliderWithTicksResponse {
+        rect: response.rect,
+        interactions,
+    }
 }
 
 fn draw_tick_marks(
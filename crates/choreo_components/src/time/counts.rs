@@ -0,0 +1,102 @@
+use choreo_master_mobile_json::MusicalTimeline;
+
+pub const MINIMUM_BPM: f64 = 20.0;
+pub const MAXIMUM_BPM: f64 = 400.0;
+
+/// Time signatures offered for the musical timeline, as beats per bar and beat unit.
+pub const TIME_SIGNATURE_OPTIONS: [(u32, u32); 7] =
+    [(2, 4), (3, 4), (4, 4), (5, 4), (6, 8), (7, 8), (12, 8)];
+
+/// A count on the musical timeline; `eight` and `count` both start at 1, so "8·5" is the fifth
+/// count of the eighth eight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CountPosition {
+    pub eight: u32,
+    pub count: u32,
+}
+
+/// Clamps a timeline loaded from a file or edited in the settings to values the conversions
+/// can work with.
+#[must_use]
+pub fn sanitize_musical_timeline(timeline: MusicalTimeline) -> MusicalTimeline {
+    let bpm = if timeline.bpm.is_finite() {
+        timeline.bpm.clamp(MINIMUM_BPM, MAXIMUM_BPM)
+    } else {
+        MusicalTimeline::default().bpm
+    };
+    let offset_seconds = if timeline.offset_seconds.is_finite() {
+        timeline.offset_seconds.max(0.0)
+    } else {
+        0.0
+    };
+    MusicalTimeline {
+        bpm,
+        offset_seconds,
+        beats_per_bar: timeline.beats_per_bar.clamp(1, 16),
+        beat_unit: timeline.beat_unit.clamp(1, 32),
+    }
+}
+
+/// An eight spans two bars, which is eight counts in 4/4 and six in a 3/4 waltz. Meters counted
+/// in eighth notes or shorter, such as 6/8 and 12/8, keep a plain eight of eight counts instead,
+/// because two of their bars would hold twelve or more.
+#[must_use]
+pub fn counts_per_eight(timeline: &MusicalTimeline) -> u32 {
+    let timeline = sanitize_musical_timeline(*timeline);
+    if timeline.beat_unit >= 8 {
+        8
+    } else {
+        timeline.beats_per_bar * 2
+    }
+}
+
+#[must_use]
+pub fn seconds_per_count(timeline: &MusicalTimeline) -> f64 {
+    60.0 / sanitize_musical_timeline(*timeline).bpm
+}
+
+#[must_use]
+pub fn seconds_for_count_position(timeline: &MusicalTimeline, position: CountPosition) -> f64 {
+    let counts_per_eight = counts_per_eight(timeline);
+    let eight = position.eight.max(1) - 1;
+    let count = position.count.clamp(1, counts_per_eight) - 1;
+    let total_counts = f64::from(eight) * f64::from(counts_per_eight) + f64::from(count);
+    sanitize_musical_timeline(*timeline).offset_seconds + total_counts * seconds_per_count(timeline)
+}
+
+/// The count nearest to `seconds`; anything before the offset is the first count.
+#[must_use]
+pub fn count_position_for_seconds(timeline: &MusicalTimeline, seconds: f64) -> CountPosition {
+    let offset_seconds = sanitize_musical_timeline(*timeline).offset_seconds;
+    let elapsed = if seconds.is_finite() {
+        (seconds - offset_seconds).max(0.0)
+    } else {
+        0.0
+    };
+    let total_counts = (elapsed / seconds_per_count(timeline)).round() as u32;
+    let counts_per_eight = counts_per_eight(timeline);
+    CountPosition {
+        eight: total_counts / counts_per_eight + 1,
+        count: total_counts % counts_per_eight + 1,
+    }
+}
+
+#[must_use]
+pub fn format_count_position(position: CountPosition) -> String {
+    format!("{}·{}", position.eight, position.count)
+}
+
+/// Formats a scene timestamp as counts when the choreography has a musical timeline, otherwise
+/// as seconds.
+#[must_use]
+pub fn format_scene_time(seconds: f64, timeline: Option<&MusicalTimeline>) -> String {
+    match timeline {
+        Some(timeline) => format_count_position(count_position_for_seconds(timeline, seconds)),
+        None => super::format_seconds(seconds),
+    }
+}
+
+#[must_use]
+pub fn time_signature_text(beats_per_bar: u32, beat_unit: u32) -> String {
+    format!("{beats_per_bar}/{beat_unit}")
+}
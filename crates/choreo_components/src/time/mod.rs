@@ -1,5 +1,7 @@
 use time::OffsetDateTime;
 
+pub mod counts;
+
 pub struct SystemClock;
 
 impl SystemClock {
@@ -9,6 +9,7 @@ pub use choreo_components::app_shell::actions;
 pub use choreo_components::app_shell::effects;
 pub use choreo_components::app_shell::reducer;
 pub use choreo_components::app_shell::state;
+pub use choreo_components::app_shell::ui;
 
 pub use rspec::report::Report;
 
@@ -28,3 +29,4 @@ where
 
 pub mod external_file_routing_spec;
 pub mod frame_lifecycle_spec;
+pub mod window_spec;
@@ -0,0 +1,94 @@
+use choreo_components::choreo_main::actions::ChoreoMainAction;
+use choreo_components::choreo_main::reducer::reduce as reduce_main_page;
+use choreo_components::choreo_main::state::ChoreoMainState;
+use choreo_components::choreo_main::state::SavedChoreography;
+use choreo_components::choreography_settings::actions::ChoreographySettingsAction;
+
+use super::Report;
+use super::actions::AppShellAction;
+use super::effects::AppShellEffect;
+use super::reducer::reduce;
+use super::state::AppShellState;
+use super::ui::window_title;
+
+#[test]
+fn window_spec() {
+    let suite = rspec::describe("app shell window", (), |spec| {
+        spec.it("sets the window title only when it changes", |_| {
+            let mut state = AppShellState::default();
+
+            let first = reduce(
+                &mut state,
+                AppShellAction::WindowTitleChanged {
+                    window_title: "Demo - ChoreoApp".to_string(),
+                },
+            );
+            let repeated = reduce(
+                &mut state,
+                AppShellAction::WindowTitleChanged {
+                    window_title: "Demo - ChoreoApp".to_string(),
+                },
+            );
+
+            assert_eq!(
+                first,
+                vec![AppShellEffect::SetWindowTitle {
+                    window_title: "Demo - ChoreoApp".to_string(),
+                }]
+            );
+            assert!(repeated.is_empty());
+        });
+
+        spec.it(
+            "closes the window once after the close is confirmed",
+            |_| {
+                let mut state = AppShellState::default();
+
+                let first = reduce(&mut state, AppShellAction::CloseConfirmed);
+                let repeated = reduce(&mut state, AppShellAction::CloseConfirmed);
+
+                assert_eq!(first, vec![AppShellEffect::CloseWindow]);
+                assert!(repeated.is_empty());
+            },
+        );
+
+        spec.it("marks the window title while changes are unsaved", |_| {
+            let mut main_page_state = ChoreoMainState::default();
+            let untracked_title = window_title("ChoreoApp", &main_page_state);
+
+            main_page_state.choreography_settings_state.name = "Demo".to_string();
+            main_page_state.saved_choreography = SavedChoreography::Persisted(Box::new(
+                main_page_state
+                    .choreography_settings_state
+                    .choreography
+                    .clone(),
+            ));
+            let saved_title = window_title("ChoreoApp", &main_page_state);
+
+            main_page_state.saved_choreography = SavedChoreography::Recovered;
+            let unsaved_title = window_title("ChoreoApp", &main_page_state);
+
+            assert_eq!(untracked_title, "ChoreoApp");
+            assert_eq!(saved_title, "Demo - ChoreoApp");
+            assert_eq!(unsaved_title, "Demo* - ChoreoApp");
+        });
+
+        spec.it("marks a blank choreography once it has been edited", |_| {
+            let mut main_page_state = ChoreoMainState::default();
+            reduce_main_page(
+                &mut main_page_state,
+                ChoreoMainAction::ChoreographySettingsAction(
+                    ChoreographySettingsAction::UpdateName("Draft".to_string()),
+                ),
+            );
+
+            assert_eq!(
+                window_title("ChoreoApp", &main_page_state),
+                "Draft* - ChoreoApp"
+            );
+        });
+    });
+
+    let report = super::run_suite(&suite);
+    assert!(report.is_success());
+}
@@ -0,0 +1,251 @@
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+use std::rc::Rc;
+use std::sync::mpsc::channel;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use choreo_models::SettingsPreferenceKeys;
+
+use choreo_components::audio_player::AudioFormatError;
+use choreo_components::audio_player::AudioPlayerBackend;
+use choreo_components::audio_player::AudioPlayerPipelineDependencies;
+use choreo_components::audio_player::OpenAudioFileCommand;
+use choreo_components::audio_player::audio_format::is_supported_audio_extension;
+use choreo_components::audio_player::audio_format::probe_audio_file;
+use choreo_components::audio_player::build_audio_player_pipeline;
+use choreo_components::audio_player::runtime::AudioPlayerRuntime;
+use choreo_components::audio_player::state::AudioPlayerState;
+use choreo_components::audio_player::translations::open_audio_error_message;
+use choreo_components::global::GlobalStateActor;
+use choreo_components::preferences::InMemoryPreferences;
+use choreo_components::preferences::Preferences;
+
+macro_rules! check_eq {
+    ($errors:expr, $left:expr, $right:expr) => {
+        if $left != $right {
+            $errors.push(format!(
+                "{} != {} (left = {:?}, right = {:?})",
+                stringify!($left),
+                stringify!($right),
+                $left,
+                $right
+            ));
+        }
+    };
+}
+
+macro_rules! check {
+    ($errors:expr, $condition:expr) => {
+        if !$condition {
+            $errors.push(format!("condition failed: {}", stringify!($condition)));
+        }
+    };
+}
+
+fn assert_no_errors(errors: Vec<String>) {
+    assert!(
+        errors.is_empty(),
+        "Assertion failures:\n{}",
+        errors.join("\n")
+    );
+}
+
+#[test]
+fn probe_accepts_wav_and_flac_files() {
+    let wav = unique_temp_file("wav");
+    write_test_wav(&wav);
+    let flac = unique_temp_file("flac");
+    write_test_flac(&flac);
+
+    let mut errors = Vec::new();
+
+    check!(errors, probe_audio_file(&wav).is_ok());
+    check!(errors, probe_audio_file(&flac).is_ok());
+
+    assert_no_errors(errors);
+
+    let _ = fs::remove_file(wav);
+    let _ = fs::remove_file(flac);
+}
+
+#[test]
+fn probe_rejects_files_no_decoder_understands() {
+    let path = unique_temp_file("mp3");
+    fs::write(&path, b"not really audio").expect("temp audio file should be written");
+    let missing = unique_temp_file("ogg");
+
+    let mut errors = Vec::new();
+
+    check_eq!(
+        errors,
+        probe_audio_file(&path).err(),
+        Some(AudioFormatError::UnsupportedFormat)
+    );
+    check!(
+        errors,
+        matches!(
+            probe_audio_file(&missing),
+            Err(AudioFormatError::Read { .. })
+        )
+    );
+
+    assert_no_errors(errors);
+
+    let _ = fs::remove_file(path);
+}
+
+#[test]
+fn supported_extensions_cover_flac_ogg_and_aac() {
+    let mut errors = Vec::new();
+
+    for extension in ["mp3", "WAV", "flac", "ogg", "m4a", "aac"] {
+        check!(errors, is_supported_audio_extension(extension));
+    }
+    check!(errors, !is_supported_audio_extension("mid"));
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn open_behavior_reports_unsupported_format_instead_of_creating_a_player() {
+    let (open_tx, open_rx) = channel();
+    let (_close_tx, close_rx) = channel();
+    let (_link_tx, link_rx) = channel();
+    let (position_tx, _position_rx) = channel();
+    let preferences = Rc::new(InMemoryPreferences::new());
+    let pipeline = build_audio_player_pipeline(AudioPlayerPipelineDependencies {
+        global_state_store: GlobalStateActor::new(),
+        open_audio_receiver: open_rx,
+        close_audio_receiver: close_rx,
+        position_changed_senders: vec![position_tx],
+        link_scene_receiver: link_rx,
+        preferences: preferences.clone(),
+        haptic_feedback: None,
+    });
+
+    let path = unique_temp_file("m4a");
+    fs::write(&path, b"not really audio").expect("temp audio file should be written");
+    let file_path = path.to_string_lossy().into_owned();
+    open_tx
+        .send(OpenAudioFileCommand {
+            file_path: file_path.clone(),
+            trace_context: None,
+        })
+        .expect("open command should send");
+
+    let mut state = AudioPlayerState::default();
+    let mut runtime = AudioPlayerRuntime::new(AudioPlayerBackend::Rodio);
+    pipeline.open_audio_file.poll(&mut state, &mut runtime);
+
+    let mut errors = Vec::new();
+
+    check!(errors, !state.has_player);
+    check!(errors, !runtime.has_player());
+    check_eq!(
+        errors,
+        state.open_audio_error,
+        Some(AudioFormatError::UnsupportedFormat)
+    );
+    check_eq!(
+        errors,
+        preferences.get_string(SettingsPreferenceKeys::LAST_OPENED_AUDIO_FILE, ""),
+        ""
+    );
+    check_eq!(
+        errors,
+        open_audio_error_message("en", "song.m4a", &AudioFormatError::UnsupportedFormat),
+        "Could not open song.m4a: the audio format is not supported. Use MP3, WAV, FLAC, OGG Vorbis or AAC/M4A."
+    );
+
+    assert_no_errors(errors);
+
+    let _ = fs::remove_file(path);
+}
+
+fn unique_temp_file(extension: &str) -> PathBuf {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system time should be after unix epoch")
+        .as_nanos();
+    std::env::temp_dir().join(format!("rchoreo_audio_format_{nanos}.{extension}"))
+}
+
+fn write_test_wav(path: &Path) {
+    let sample_rate = 8_000_u32;
+    let sample_count = 800_usize;
+    let data_size = (sample_count * std::mem::size_of::<i16>()) as u32;
+    let mut bytes = Vec::with_capacity(44 + data_size as usize);
+    bytes.extend_from_slice(b"RIFF");
+    bytes.extend_from_slice(&(36 + data_size).to_le_bytes());
+    bytes.extend_from_slice(b"WAVE");
+    bytes.extend_from_slice(b"fmt ");
+    bytes.extend_from_slice(&16_u32.to_le_bytes());
+    bytes.extend_from_slice(&1_u16.to_le_bytes());
+    bytes.extend_from_slice(&1_u16.to_le_bytes());
+    bytes.extend_from_slice(&sample_rate.to_le_bytes());
+    bytes.extend_from_slice(&(sample_rate * 2).to_le_bytes());
+    bytes.extend_from_slice(&2_u16.to_le_bytes());
+    bytes.extend_from_slice(&16_u16.to_le_bytes());
+    bytes.extend_from_slice(b"data");
+    bytes.extend_from_slice(&data_size.to_le_bytes());
+    bytes.resize(44 + data_size as usize, 0);
+    fs::write(path, bytes).expect("test wav file should be written");
+}
+
+/// A FLAC stream (mono, 8 kHz, 16 bit) with one frame of silence.
+fn write_test_flac(path: &Path) {
+    let block_size = 16_u16;
+    let sample_rate = 8_000_u64;
+    let channels = 1_u64;
+    let bits_per_sample = 16_u64;
+    let packed = (sample_rate << 44)
+        | ((channels - 1) << 41)
+        | ((bits_per_sample - 1) << 36)
+        | u64::from(block_size);
+
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(b"fLaC");
+    // Last metadata block, STREAMINFO, 34 bytes long.
+    bytes.extend_from_slice(&[0x80, 0x00, 0x00, 34]);
+    bytes.extend_from_slice(&block_size.to_be_bytes());
+    bytes.extend_from_slice(&block_size.to_be_bytes());
+    bytes.extend_from_slice(&[0; 6]);
+    bytes.extend_from_slice(&packed.to_be_bytes());
+    bytes.extend_from_slice(&[0; 16]);
+
+    // Fixed block size, 8-bit block size at the end, rate from STREAMINFO, mono 16 bit, frame 0.
+    let mut frame = vec![0xFF, 0xF8, 0x60, 0x08, 0x00, (block_size - 1) as u8];
+    frame.push(crc8(&frame));
+    // One CONSTANT subframe holding zero.
+    frame.extend_from_slice(&[0x00, 0x00, 0x00]);
+    let footer = crc16(&frame);
+    frame.extend_from_slice(&footer.to_be_bytes());
+    bytes.extend_from_slice(&frame);
+    fs::write(path, bytes).expect("test flac file should be written");
+}
+
+fn crc8(bytes: &[u8]) -> u8 {
+    bytes.iter().fold(0_u8, |crc, byte| {
+        (0..8).fold(crc ^ byte, |crc, _| {
+            if crc & 0x80 == 0 {
+                crc << 1
+            } else {
+                (crc << 1) ^ 0x07
+            }
+        })
+    })
+}
+
+fn crc16(bytes: &[u8]) -> u16 {
+    bytes.iter().fold(0_u16, |crc, byte| {
+        (0..8).fold(crc ^ (u16::from(*byte) << 8), |crc, _| {
+            if crc & 0x8000 == 0 {
+                crc << 1
+            } else {
+                (crc << 1) ^ 0x8005
+            }
+        })
+    })
+}
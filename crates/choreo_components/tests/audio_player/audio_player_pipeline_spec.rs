@@ -218,7 +218,28 @@ fn create_temp_audio_file_path() -> String {
         .duration_since(std::time::UNIX_EPOCH)
         .expect("system time should be after unix epoch")
         .as_nanos();
-    let path = std::env::temp_dir().join(format!("rchoreo-audio-pipeline-{unique}.mp3"));
-    fs::write(&path, b"audio-test").expect("temp audio file should be writable");
+    let path = std::env::temp_dir().join(format!("rchoreo-audio-pipeline-{unique}.wav"));
+    fs::write(&path, silent_wav_bytes()).expect("temp audio file should be writable");
     path.to_string_lossy().into_owned()
 }
+
+fn silent_wav_bytes() -> Vec<u8> {
+    let sample_rate = 8_000_u32;
+    let data_size = 1_600_u32;
+    let mut bytes = Vec::with_capacity(44 + data_size as usize);
+    bytes.extend_from_slice(b"RIFF");
+    bytes.extend_from_slice(&(36 + data_size).to_le_bytes());
+    bytes.extend_from_slice(b"WAVE");
+    bytes.extend_from_slice(b"fmt ");
+    bytes.extend_from_slice(&16_u32.to_le_bytes());
+    bytes.extend_from_slice(&1_u16.to_le_bytes());
+    bytes.extend_from_slice(&1_u16.to_le_bytes());
+    bytes.extend_from_slice(&sample_rate.to_le_bytes());
+    bytes.extend_from_slice(&(sample_rate * 2).to_le_bytes());
+    bytes.extend_from_slice(&2_u16.to_le_bytes());
+    bytes.extend_from_slice(&16_u16.to_le_bytes());
+    bytes.extend_from_slice(b"data");
+    bytes.extend_from_slice(&data_size.to_le_bytes());
+    bytes.resize(44 + data_size as usize, 0);
+    bytes
+}
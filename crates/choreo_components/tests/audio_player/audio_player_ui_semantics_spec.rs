@@ -23,7 +23,8 @@ macro_rules! check_eq {
 
 macro_rules! check {
     ($errors:expr, $condition:expr) => {
-        if !$condition {
+        let passed: bool = $condition;
+        if !passed {
             $errors.push(format!("condition failed: {}", stringify!($condition)));
         }
     };
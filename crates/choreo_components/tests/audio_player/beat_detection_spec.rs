@@ -0,0 +1,304 @@
+use std::fs;
+use std::path::PathBuf;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use choreo_components::audio_player::actions::AudioPlayerAction;
+use choreo_components::audio_player::audio_analysis::analyze_audio_file;
+use choreo_components::audio_player::beat_detection::BeatGrid;
+use choreo_components::audio_player::beat_detection::TimestampSnap;
+use choreo_components::audio_player::beat_detection::detect_beat_grid;
+use choreo_components::audio_player::beat_detection::snap_timestamp;
+use choreo_components::audio_player::reducer::reduce;
+use choreo_components::audio_player::state::AudioPlayerChoreographyScene;
+use choreo_components::audio_player::state::AudioPlayerScene;
+use choreo_components::audio_player::state::AudioPlayerState;
+use choreo_components::audio_player::translations::bpm_label_text;
+
+macro_rules! check_eq {
+    ($errors:expr, $left:expr, $right:expr) => {
+        if $left != $right {
+            $errors.push(format!(
+                "{} != {} (left = {:?}, right = {:?})",
+                stringify!($left),
+                stringify!($right),
+                $left,
+                $right
+            ));
+        }
+    };
+}
+
+macro_rules! check {
+    ($errors:expr, $condition:expr) => {
+        let passed: bool = $condition;
+        if !passed {
+            $errors.push(format!("condition failed: {}", stringify!($condition)));
+        }
+    };
+}
+
+fn assert_no_errors(errors: Vec<String>) {
+    assert!(
+        errors.is_empty(),
+        "Assertion failures:\n{}",
+        errors.join("\n")
+    );
+}
+
+const SAMPLE_RATE: u32 = 22_050;
+
+/// Clicks every beat from `first_beat_seconds`; the "1" of each eight-count is loudest and
+/// the "5" louder than the rest.
+fn click_track(
+    bpm: f64,
+    first_beat_seconds: f64,
+    first_count_beat: usize,
+    seconds: f64,
+) -> Vec<f32> {
+    let mut samples = vec![0.0_f32; (seconds * f64::from(SAMPLE_RATE)) as usize];
+    let beat_seconds = 60.0 / bpm;
+    let click_length = (0.02 * f64::from(SAMPLE_RATE)) as usize;
+    let mut beat = 0;
+    loop {
+        let start =
+            ((first_beat_seconds + beat as f64 * beat_seconds) * f64::from(SAMPLE_RATE)) as usize;
+        if start >= samples.len() {
+            break;
+        }
+        let amplitude = match (beat + 8 - first_count_beat) % 8 {
+            0 => 0.9,
+            4 => 0.5,
+            _ => 0.2,
+        };
+        for (offset, sample) in samples[start..].iter_mut().take(click_length).enumerate() {
+            let time = offset as f64 / f64::from(SAMPLE_RATE);
+            let decay = 1.0 - offset as f64 / click_length as f64;
+            *sample =
+                (amplitude * decay * (2.0 * std::f64::consts::PI * 1_000.0 * time).sin()) as f32;
+        }
+        beat += 1;
+    }
+    samples
+}
+
+fn grid() -> BeatGrid {
+    BeatGrid {
+        bpm: 120.0,
+        beats: (0..24).map(|beat| 0.25 + f64::from(beat) * 0.5).collect(),
+        first_count_index: 2,
+    }
+}
+
+#[test]
+fn detects_tempo_beats_and_the_eight_count_of_a_click_track() {
+    let samples = click_track(120.0, 0.25, 2, 20.0);
+
+    let beat_grid = detect_beat_grid(&samples, SAMPLE_RATE).expect("click track has a beat");
+
+    let mut errors = Vec::new();
+
+    check!(errors, (beat_grid.bpm - 120.0).abs() < 1.0);
+    check!(errors, (beat_grid.beats[0] - 0.25).abs() < 0.02);
+    check!(errors, beat_grid.beats.len() >= 38);
+    check_eq!(errors, beat_grid.first_count_index, 2);
+    check_eq!(errors, beat_grid.count_of(2), 1);
+    check_eq!(errors, beat_grid.count_of(1), 8);
+    check_eq!(errors, bpm_label_text("en", Some(&beat_grid)), "120 BPM");
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn finds_a_slower_tempo_without_halving_or_doubling_it() {
+    let samples = click_track(96.0, 0.5, 0, 30.0);
+
+    let beat_grid = detect_beat_grid(&samples, SAMPLE_RATE).expect("click track has a beat");
+
+    assert!(
+        (beat_grid.bpm - 96.0).abs() < 1.0,
+        "detected {} BPM",
+        beat_grid.bpm
+    );
+}
+
+#[test]
+fn silence_and_short_clips_have_no_beat_grid() {
+    let mut errors = Vec::new();
+
+    check!(
+        errors,
+        detect_beat_grid(&vec![0.0; SAMPLE_RATE as usize * 10], SAMPLE_RATE).is_none()
+    );
+    check!(
+        errors,
+        detect_beat_grid(&click_track(120.0, 0.25, 0, 2.0), SAMPLE_RATE).is_none()
+    );
+    check_eq!(errors, bpm_label_text("en", None), "");
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn analyzes_a_decoded_wav_file() {
+    let samples = click_track(120.0, 0.25, 2, 12.0);
+    let path = unique_temp_file("wav");
+    write_wav(&path, &samples);
+
+    let beat_grid = analyze_audio_file(&path)
+        .expect("wav should decode")
+        .beat_grid
+        .expect("click track has a beat");
+
+    assert!(
+        (beat_grid.bpm - 120.0).abs() < 1.0,
+        "detected {} BPM",
+        beat_grid.bpm
+    );
+
+    let _ = fs::remove_file(path);
+}
+
+#[test]
+fn snaps_timestamps_to_beats_or_eight_counts() {
+    let grid = grid();
+    let mut errors = Vec::new();
+
+    check_eq!(
+        errors,
+        snap_timestamp(3.04, TimestampSnap::Off, Some(&grid)),
+        3.0
+    );
+    check_eq!(errors, snap_timestamp(3.04, TimestampSnap::Beat, None), 3.0);
+    check_eq!(
+        errors,
+        snap_timestamp(3.04, TimestampSnap::Beat, Some(&grid)),
+        3.25
+    );
+    check_eq!(
+        errors,
+        snap_timestamp(3.04, TimestampSnap::Count, Some(&grid)),
+        1.25
+    );
+    check_eq!(
+        errors,
+        snap_timestamp(4.0, TimestampSnap::Count, Some(&grid)),
+        5.25
+    );
+    check_eq!(
+        errors,
+        grid.count_starts().collect::<Vec<_>>(),
+        vec![1.25, 5.25, 9.25]
+    );
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn detected_grid_adds_count_ticks_and_snaps_linked_scenes() {
+    let mut state = AudioPlayerState {
+        duration: 8.0,
+        position: 3.04,
+        last_opened_audio_file_path: Some("song.mp3".to_string()),
+        ..AudioPlayerState::default()
+    };
+    reduce(
+        &mut state,
+        AudioPlayerAction::SetScenes {
+            scenes: vec![
+                AudioPlayerScene {
+                    scene_id: 1,
+                    name: "A".to_string(),
+                    timestamp: Some(0.5),
+                },
+                AudioPlayerScene {
+                    scene_id: 2,
+                    name: "B".to_string(),
+                    timestamp: None,
+                },
+            ],
+            selected_scene_id: Some(2),
+            choreography_scenes: vec![
+                AudioPlayerChoreographyScene {
+                    scene_id: 1,
+                    timestamp: Some("0.5".to_string()),
+                },
+                AudioPlayerChoreographyScene {
+                    scene_id: 2,
+                    timestamp: None,
+                },
+            ],
+        },
+    );
+
+    reduce(
+        &mut state,
+        AudioPlayerAction::BeatGridDetected {
+            file_path: "other.mp3".to_string(),
+            beat_grid: Some(grid()),
+        },
+    );
+
+    let mut errors = Vec::new();
+    check!(errors, state.beat_grid.is_none());
+
+    reduce(
+        &mut state,
+        AudioPlayerAction::BeatGridDetected {
+            file_path: "song.mp3".to_string(),
+            beat_grid: Some(grid()),
+        },
+    );
+    check_eq!(errors, state.tick_values, vec![0.5, 1.25, 5.25]);
+
+    reduce(
+        &mut state,
+        AudioPlayerAction::UpdateTimestampSnap {
+            snap: TimestampSnap::Beat,
+        },
+    );
+    reduce(&mut state, AudioPlayerAction::LinkSceneToPosition);
+    check_eq!(errors, state.scenes[1].timestamp, Some(3.25));
+    check_eq!(
+        errors,
+        state.choreography_scenes[1].timestamp.as_deref(),
+        Some("3.25")
+    );
+
+    reduce(&mut state, AudioPlayerAction::CloseAudioFile);
+    check!(errors, state.beat_grid.is_none());
+    check!(errors, state.tick_values.iter().all(|tick| *tick != 1.25));
+
+    assert_no_errors(errors);
+}
+
+fn unique_temp_file(extension: &str) -> PathBuf {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system time should be after unix epoch")
+        .as_nanos();
+    std::env::temp_dir().join(format!("rchoreo_beat_detection_{nanos}.{extension}"))
+}
+
+fn write_wav(path: &PathBuf, samples: &[f32]) {
+    let data_size = (samples.len() * std::mem::size_of::<i16>()) as u32;
+    let mut bytes = Vec::with_capacity(44 + data_size as usize);
+    bytes.extend_from_slice(b"RIFF");
+    bytes.extend_from_slice(&(36 + data_size).to_le_bytes());
+    bytes.extend_from_slice(b"WAVE");
+    bytes.extend_from_slice(b"fmt ");
+    bytes.extend_from_slice(&16_u32.to_le_bytes());
+    bytes.extend_from_slice(&1_u16.to_le_bytes());
+    bytes.extend_from_slice(&1_u16.to_le_bytes());
+    bytes.extend_from_slice(&SAMPLE_RATE.to_le_bytes());
+    bytes.extend_from_slice(&(SAMPLE_RATE * 2).to_le_bytes());
+    bytes.extend_from_slice(&2_u16.to_le_bytes());
+    bytes.extend_from_slice(&16_u16.to_le_bytes());
+    bytes.extend_from_slice(b"data");
+    bytes.extend_from_slice(&data_size.to_le_bytes());
+    for sample in samples {
+        let value = (sample.clamp(-1.0, 1.0) * f32::from(i16::MAX)) as i16;
+        bytes.extend_from_slice(&value.to_le_bytes());
+    }
+    fs::write(path, bytes).expect("test wav file should be written");
+}
@@ -1,3 +1,4 @@
+pub mod audio_format_spec;
 pub mod audio_player_link_scene_behavior_spec;
 pub mod audio_player_observability_and_haptics_spec;
 pub mod audio_player_pipeline_spec;
@@ -6,5 +7,8 @@ pub mod audio_player_runtime_spec;
 pub mod audio_player_ticks_behavior_spec;
 pub mod audio_player_ui_semantics_spec;
 pub mod audio_player_view_state_spec;
+pub mod beat_detection_spec;
 pub mod close_audio_file_behavior_spec;
 pub mod open_audio_file_behavior_spec;
+pub mod time_stretch_spec;
+pub mod waveform_spec;
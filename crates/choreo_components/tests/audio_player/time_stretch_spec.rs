@@ -0,0 +1,189 @@
+use choreo_components::audio_player::actions::AudioPlayerAction;
+use choreo_components::audio_player::reducer::reduce;
+use choreo_components::audio_player::state::AudioPlayerState;
+use choreo_components::audio_player::time_stretch::TimeStretcher;
+use choreo_components::audio_player::time_stretch::clamp_playback_speed;
+
+macro_rules! check_eq {
+    ($errors:expr, $left:expr, $right:expr) => {
+        if $left != $right {
+            $errors.push(format!(
+                "{} != {} (left = {:?}, right = {:?})",
+                stringify!($left),
+                stringify!($right),
+                $left,
+                $right
+            ));
+        }
+    };
+}
+
+macro_rules! check {
+    ($errors:expr, $condition:expr) => {
+        let passed: bool = $condition;
+        if !passed {
+            $errors.push(format!("condition failed: {}", stringify!($condition)));
+        }
+    };
+}
+
+fn assert_no_errors(errors: Vec<String>) {
+    assert!(
+        errors.is_empty(),
+        "Assertion failures:\n{}",
+        errors.join("\n")
+    );
+}
+
+const SAMPLE_RATE: u32 = 16_000;
+
+fn stereo_sine(frequency: f64, seconds: f64) -> Vec<f32> {
+    let frames = (seconds * f64::from(SAMPLE_RATE)) as usize;
+    (0..frames)
+        .flat_map(|frame| {
+            let time = frame as f64 / f64::from(SAMPLE_RATE);
+            let sample = (0.5 * (2.0 * std::f64::consts::PI * frequency * time).sin()) as f32;
+            [sample, sample]
+        })
+        .collect()
+}
+
+/// Feeds the whole input the way the player sources do and collects the output.
+fn stretch(input: &[f32], speed: f64) -> Vec<f32> {
+    let mut stretcher = TimeStretcher::new(2, SAMPLE_RATE, speed);
+    let mut input = input.iter();
+    let mut output = Vec::new();
+    loop {
+        if let Some(sample) = stretcher.pop_sample() {
+            output.push(sample);
+            continue;
+        }
+        if stretcher.is_finished() {
+            return output;
+        }
+        match input.next() {
+            Some(sample) => stretcher.push_sample(*sample),
+            None => stretcher.end_input(),
+        }
+    }
+}
+
+/// Counts rising zero crossings of the left channel per second, away from the edges.
+fn left_channel_frequency(samples: &[f32]) -> f64 {
+    let left = samples.iter().step_by(2).copied().collect::<Vec<_>>();
+    let middle = &left[left.len() / 4..left.len() * 3 / 4];
+    let crossings = middle
+        .windows(2)
+        .filter(|pair| pair[0] < 0.0 && pair[1] >= 0.0)
+        .count();
+    crossings as f64 * f64::from(SAMPLE_RATE) / middle.len() as f64
+}
+
+#[test]
+fn normal_speed_reproduces_the_input_after_the_first_window() {
+    let input = stereo_sine(440.0, 1.0);
+
+    let output = stretch(&input, 1.0);
+
+    let mut errors = Vec::new();
+    let fade_in_samples = (SAMPLE_RATE as usize / 50) * 2;
+    check!(errors, output.len() >= input.len());
+    check!(
+        errors,
+        input[fade_in_samples..]
+            .iter()
+            .zip(&output[fade_in_samples..])
+            .all(|(expected, actual)| (expected - actual).abs() < 1e-4)
+    );
+    assert_no_errors(errors);
+}
+
+#[test]
+fn slower_and_faster_speeds_change_length_but_not_pitch() {
+    let input = stereo_sine(440.0, 2.0);
+
+    let mut errors = Vec::new();
+    for speed in [0.5, 0.75, 1.25, 1.5] {
+        let output = stretch(&input, speed);
+        let expected_length = input.len() as f64 / speed;
+        let frequency = left_channel_frequency(&output);
+        check!(
+            errors,
+            (output.len() as f64 - expected_length).abs() < expected_length * 0.03
+        );
+        check!(errors, (frequency - 440.0).abs() < 10.0);
+    }
+    assert_no_errors(errors);
+}
+
+#[test]
+fn position_follows_the_input_at_the_playback_speed() {
+    let input = stereo_sine(220.0, 1.0);
+    let mut stretcher = TimeStretcher::new(2, SAMPLE_RATE, 0.5);
+    stretcher.reset(8_000);
+    let mut popped_samples = 0;
+    let mut input = input.iter();
+    while popped_samples < 8_000 {
+        if stretcher.pop_sample().is_some() {
+            popped_samples += 1;
+            continue;
+        }
+        match input.next() {
+            Some(sample) => stretcher.push_sample(*sample),
+            None => break,
+        }
+    }
+
+    let position = stretcher.position_frames();
+    assert!(
+        (position - 10_000.0).abs() < 400.0,
+        "position after 4000 frames at half speed was {position}"
+    );
+}
+
+#[test]
+fn speeds_are_limited_to_what_the_stretch_supports() {
+    let mut errors = Vec::new();
+
+    check_eq!(errors, clamp_playback_speed(0.2), 0.5);
+    check_eq!(errors, clamp_playback_speed(2.0), 1.5);
+    check_eq!(errors, clamp_playback_speed(f64::NAN), 1.0);
+    check_eq!(errors, TimeStretcher::new(1, SAMPLE_RATE, 3.0).speed(), 1.5);
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn speed_range_from_settings_narrows_the_slider_and_the_current_speed() {
+    let mut state = AudioPlayerState {
+        speed: 0.6,
+        ..AudioPlayerState::default()
+    };
+
+    reduce(
+        &mut state,
+        AudioPlayerAction::SetSpeedRange {
+            minimum: 0.8,
+            maximum: 1.2,
+        },
+    );
+
+    let mut errors = Vec::new();
+
+    check_eq!(errors, state.minimum_speed, 0.8);
+    check_eq!(errors, state.maximum_speed, 1.2);
+    check_eq!(errors, state.speed, 0.8);
+    check_eq!(errors, state.speed_label, "80%");
+
+    reduce(
+        &mut state,
+        AudioPlayerAction::SetSpeedRange {
+            minimum: 0.1,
+            maximum: 4.0,
+        },
+    );
+    check_eq!(errors, state.minimum_speed, 0.5);
+    check_eq!(errors, state.maximum_speed, 1.5);
+
+    assert_no_errors(errors);
+}
@@ -0,0 +1,249 @@
+use std::fs;
+use std::path::PathBuf;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use choreo_components::audio_player::actions::AudioPlayerAction;
+use choreo_components::audio_player::audio_analysis::analyze_audio_file;
+use choreo_components::audio_player::reducer::reduce;
+use choreo_components::audio_player::state::AudioPlayerChoreographyScene;
+use choreo_components::audio_player::state::AudioPlayerScene;
+use choreo_components::audio_player::state::AudioPlayerState;
+use choreo_components::audio_player::timeline::scene_marker_range;
+use choreo_components::audio_player::waveform::build_waveform;
+
+macro_rules! check_eq {
+    ($errors:expr, $left:expr, $right:expr) => {
+        if $left != $right {
+            $errors.push(format!(
+                "{} != {} (left = {:?}, right = {:?})",
+                stringify!($left),
+                stringify!($right),
+                $left,
+                $right
+            ));
+        }
+    };
+}
+
+macro_rules! check {
+    ($errors:expr, $condition:expr) => {
+        let passed: bool = $condition;
+        if !passed {
+            $errors.push(format!("condition failed: {}", stringify!($condition)));
+        }
+    };
+}
+
+fn assert_no_errors(errors: Vec<String>) {
+    assert!(
+        errors.is_empty(),
+        "Assertion failures:\n{}",
+        errors.join("\n")
+    );
+}
+
+const SAMPLE_RATE: u32 = 8_000;
+
+/// One second quiet, one second loud.
+fn quiet_then_loud() -> Vec<f32> {
+    (0..SAMPLE_RATE * 2)
+        .map(|index| {
+            let amplitude = if index < SAMPLE_RATE { 0.1 } else { 0.5 };
+            if index % 2 == 0 {
+                amplitude
+            } else {
+                -amplitude
+            }
+        })
+        .collect()
+}
+
+fn scene(scene_id: i32, timestamp: Option<f64>) -> AudioPlayerScene {
+    AudioPlayerScene {
+        scene_id,
+        name: format!("Scene {scene_id}"),
+        timestamp,
+    }
+}
+
+fn timed_state() -> AudioPlayerState {
+    let mut state = AudioPlayerState {
+        duration: 30.0,
+        has_player: true,
+        last_opened_audio_file_path: Some("song.mp3".to_string()),
+        ..AudioPlayerState::default()
+    };
+    reduce(
+        &mut state,
+        AudioPlayerAction::SetScenes {
+            scenes: vec![
+                scene(1, Some(2.0)),
+                scene(2, Some(10.0)),
+                scene(3, None),
+                scene(4, Some(20.0)),
+            ],
+            selected_scene_id: Some(1),
+            choreography_scenes: (1..=4)
+                .map(|scene_id| AudioPlayerChoreographyScene {
+                    scene_id,
+                    timestamp: None,
+                })
+                .collect(),
+        },
+    );
+    state
+}
+
+#[test]
+fn waveform_peaks_are_normalized_to_the_loudest_part() {
+    let waveform = build_waveform(&quiet_then_loud(), SAMPLE_RATE).expect("audio is not silent");
+
+    let mut errors = Vec::new();
+
+    check!(errors, (waveform.duration_seconds() - 2.0).abs() < 0.05);
+    check!(
+        errors,
+        (waveform.peak_between(0.2, 0.8) - 0.2).abs() < 0.001
+    );
+    check_eq!(errors, waveform.peak_between(1.2, 1.8), 1.0);
+    check_eq!(errors, waveform.peak_between(0.5, 1.5), 1.0);
+    check_eq!(errors, waveform.peak_between(5.0, 6.0), 0.0);
+    check!(errors, build_waveform(&[0.0; 800], SAMPLE_RATE).is_none());
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn analysis_of_a_file_includes_its_waveform() {
+    let path = unique_temp_file("wav");
+    write_wav(&path, &quiet_then_loud());
+
+    let analysis = analyze_audio_file(&path).expect("wav should decode");
+    let waveform = analysis.waveform.expect("audio is not silent");
+
+    assert!((waveform.duration_seconds() - 2.0).abs() < 0.05);
+
+    let _ = fs::remove_file(path);
+}
+
+#[test]
+fn waveform_is_kept_for_the_open_file_only() {
+    let mut state = timed_state();
+    let waveform = build_waveform(&quiet_then_loud(), SAMPLE_RATE);
+    let mut errors = Vec::new();
+
+    reduce(
+        &mut state,
+        AudioPlayerAction::WaveformComputed {
+            file_path: "previous.mp3".to_string(),
+            waveform: waveform.clone(),
+        },
+    );
+    check!(errors, state.waveform.is_none());
+
+    reduce(
+        &mut state,
+        AudioPlayerAction::WaveformComputed {
+            file_path: "song.mp3".to_string(),
+            waveform: waveform.clone(),
+        },
+    );
+    check_eq!(errors, state.waveform, waveform);
+
+    reduce(&mut state, AudioPlayerAction::CloseAudioFile);
+    check!(errors, state.waveform.is_none());
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn scene_markers_stay_between_their_timed_neighbours() {
+    let state = timed_state();
+    let mut errors = Vec::new();
+
+    check_eq!(
+        errors,
+        scene_marker_range(&state.scenes, 1, state.duration),
+        Some((0.0, 9.9))
+    );
+    check_eq!(
+        errors,
+        scene_marker_range(&state.scenes, 2, state.duration),
+        Some((2.1, 19.9))
+    );
+    check_eq!(
+        errors,
+        scene_marker_range(&state.scenes, 4, state.duration),
+        Some((10.1, 30.0))
+    );
+    check!(
+        errors,
+        scene_marker_range(&state.scenes, 9, state.duration).is_none()
+    );
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn dropping_a_scene_marker_retimes_the_scene() {
+    let mut state = timed_state();
+    let mut errors = Vec::new();
+
+    reduce(
+        &mut state,
+        AudioPlayerAction::MoveSceneTimestamp {
+            scene_id: 2,
+            timestamp: 12.34,
+        },
+    );
+    check_eq!(errors, state.scenes[1].timestamp, Some(12.3));
+    check_eq!(
+        errors,
+        state.choreography_scenes[1].timestamp.as_deref(),
+        Some("12.3")
+    );
+    check_eq!(errors, state.tick_values, vec![2.0, 12.3, 20.0]);
+
+    reduce(
+        &mut state,
+        AudioPlayerAction::MoveSceneTimestamp {
+            scene_id: 1,
+            timestamp: 15.0,
+        },
+    );
+    check_eq!(errors, state.scenes[0].timestamp, Some(2.0));
+
+    assert_no_errors(errors);
+}
+
+fn unique_temp_file(extension: &str) -> PathBuf {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system time should be after unix epoch")
+        .as_nanos();
+    std::env::temp_dir().join(format!("rchoreo_waveform_{nanos}.{extension}"))
+}
+
+fn write_wav(path: &PathBuf, samples: &[f32]) {
+    let data_size = (samples.len() * std::mem::size_of::<i16>()) as u32;
+    let mut bytes = Vec::with_capacity(44 + data_size as usize);
+    bytes.extend_from_slice(b"RIFF");
+    bytes.extend_from_slice(&(36 + data_size).to_le_bytes());
+    bytes.extend_from_slice(b"WAVE");
+    bytes.extend_from_slice(b"fmt ");
+    bytes.extend_from_slice(&16_u32.to_le_bytes());
+    bytes.extend_from_slice(&1_u16.to_le_bytes());
+    bytes.extend_from_slice(&1_u16.to_le_bytes());
+    bytes.extend_from_slice(&SAMPLE_RATE.to_le_bytes());
+    bytes.extend_from_slice(&(SAMPLE_RATE * 2).to_le_bytes());
+    bytes.extend_from_slice(&2_u16.to_le_bytes());
+    bytes.extend_from_slice(&16_u16.to_le_bytes());
+    bytes.extend_from_slice(b"data");
+    bytes.extend_from_slice(&data_size.to_le_bytes());
+    for sample in samples {
+        let value = (sample.clamp(-1.0, 1.0) * f32::from(i16::MAX)) as i16;
+        bytes.extend_from_slice(&value.to_le_bytes());
+    }
+    fs::write(path, bytes).expect("test wav file should be written");
+}
@@ -0,0 +1,214 @@
+use std::rc::Rc;
+
+use choreo_components::booklet::BookletOptions;
+use choreo_components::booklet::booklet_page_svgs;
+use choreo_components::booklet::render_booklet_pdf;
+use choreo_components::choreo_main::actions::ChoreoMainAction;
+use choreo_components::choreo_main::reducer::reduce;
+use choreo_components::choreo_main::state::ChoreoMainState;
+use choreo_components::choreography_settings::actions::ChoreographySettingsAction;
+use choreo_master_mobile_json::Color;
+use choreo_models::ChoreographyModel;
+use choreo_models::DancerModel;
+use choreo_models::FloorModel;
+use choreo_models::PositionModel;
+use choreo_models::RoleModel;
+use choreo_models::SceneModel;
+
+use crate::booklet::build_dancer;
+use crate::booklet::dancer_position;
+use crate::booklet::scene_model;
+
+macro_rules! check_eq {
+    ($errors:expr, $left:expr, $right:expr) => {
+        if $left != $right {
+            $errors.push(format!(
+                "{} != {} (left = {:?}, right = {:?})",
+                stringify!($left),
+                stringify!($right),
+                $left,
+                $right
+            ));
+        }
+    };
+}
+
+macro_rules! check {
+    ($errors:expr, $condition:expr) => {
+        let passed: bool = $condition;
+        if !passed {
+            $errors.push(format!("condition failed: {}", stringify!($condition)));
+        }
+    };
+}
+
+fn assert_no_errors(errors: Vec<String>) {
+    assert!(
+        errors.is_empty(),
+        "Assertion failures:\n{}",
+        errors.join("\n")
+    );
+}
+
+#[test]
+fn lays_out_a_title_page_and_one_page_per_scene() {
+    let state = booklet_state();
+
+    let pages = booklet_page_svgs(&state, &BookletOptions::default());
+
+    let mut errors = Vec::new();
+
+    check_eq!(errors, pages.len(), 3);
+    let title = &pages[0];
+    check!(errors, title.contains(">Viennese Waltz</text>"));
+    check!(errors, title.contains(">Spring showcase</text>"));
+    check!(errors, title.contains(">Author</text>"));
+    check!(errors, title.contains(">Ada &amp; Bo</text>"));
+    check!(errors, title.contains(">2026-04-18</text>"));
+    check!(errors, title.contains(">Page 1 of 3</text>"));
+    let chorus = &pages[2];
+    check!(errors, chorus.contains(">02. Chorus</text>"));
+    check!(errors, chorus.contains(">Timestamp 1:15.5</text>"));
+    check!(
+        errors,
+        chorus.contains(">Lead turns under &lt;arm&gt;</text>")
+    );
+    check!(errors, chorus.contains("href=\"data:image/svg+xml;base64,"));
+    check!(errors, chorus.contains(">Page 3 of 3</text>"));
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn dancer_index_lists_each_dancers_position_per_scene() {
+    let state = booklet_state();
+    let options = BookletOptions {
+        include_dancer_index: true,
+        ..BookletOptions::default()
+    };
+
+    let pages = booklet_page_svgs(&state, &options);
+
+    let mut errors = Vec::new();
+
+    // Title, two scenes and one index page per dancer.
+    check_eq!(errors, pages.len(), 5);
+    let lead = &pages[3];
+    check!(errors, lead.contains(">Where am I? – Lead</text>"));
+    check!(errors, lead.contains(">Position</text>"));
+    check!(errors, lead.contains(">(-1.00, 1.00)</text>"));
+    check!(errors, lead.contains(">(0.00, 1.00)</text>"));
+    let follow = &pages[4];
+    check!(errors, follow.contains(">Where am I? – Follow</text>"));
+    check!(errors, follow.contains(">(2.50, -0.50)</text>"));
+    check!(errors, follow.contains(">–</text>"));
+    check!(errors, follow.contains(">Page 5 of 5</text>"));
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn renders_a_pdf_with_one_page_per_booklet_page() {
+    let state = booklet_state();
+
+    let pdf = render_booklet_pdf(&state, &BookletOptions::default())
+        .expect("booklet should render without external tools");
+    let text = String::from_utf8_lossy(&pdf);
+
+    let mut errors = Vec::new();
+
+    check!(errors, pdf.starts_with(b"%PDF-1.4"));
+    check!(
+        errors,
+        text.contains("/Type /Pages /Kids [4 0 R 7 0 R 10 0 R] /Count 3")
+    );
+    check!(
+        errors,
+        text.contains("/Subtype /Image /Width 1240 /Height 1754")
+    );
+    check!(errors, text.trim_end().ends_with("%%EOF"));
+
+    assert_no_errors(errors);
+}
+
+fn booklet_state() -> ChoreoMainState {
+    let role = Rc::new(RoleModel {
+        z_index: 1,
+        name: "Lead".to_string(),
+        color: rgba(255, 120, 0, 0),
+    });
+    let lead = dancer(1, "Lead", "L", role.clone());
+    let follow = dancer(2, "Follow", "F", role);
+    let scenes = vec![
+        scene(
+            1,
+            "Opening",
+            "3",
+            None,
+            vec![dancer_position(&lead, -1.0, 1.0)],
+        ),
+        scene(
+            2,
+            "Chorus",
+            "1:15.5",
+            Some("Lead turns under <arm>"),
+            vec![
+                dancer_position(&lead, 0.0, 1.0),
+                dancer_position(&follow, 2.5, -0.5),
+            ],
+        ),
+    ];
+    let choreography = ChoreographyModel {
+        name: "Viennese Waltz".to_string(),
+        subtitle: Some("Spring showcase".to_string()),
+        author: Some("Ada & Bo".to_string()),
+        date: time::Date::from_calendar_date(2026, time::Month::April, 18).ok(),
+        floor: FloorModel {
+            size_front: 4,
+            size_back: 4,
+            size_left: 5,
+            size_right: 5,
+        },
+        dancers: vec![lead, follow],
+        scenes,
+        ..ChoreographyModel::default()
+    };
+
+    let mut state = ChoreoMainState::default();
+    reduce(
+        &mut state,
+        ChoreoMainAction::ChoreographySettingsAction(
+            ChoreographySettingsAction::LoadChoreography {
+                choreography: Box::new(choreography),
+                selected_scene: None,
+            },
+        ),
+    );
+    state
+}
+
+fn dancer(id: i32, name: &str, shortcut: &str, role: Rc<RoleModel>) -> Rc<DancerModel> {
+    Rc::new(DancerModel {
+        role,
+        shortcut: shortcut.to_string(),
+        color: rgba(255, 220, 40, 40),
+        ..Rc::unwrap_or_clone(build_dancer(id, name))
+    })
+}
+
+fn scene(
+    id: i32,
+    name: &str,
+    timestamp: &str,
+    text: Option<&str>,
+    positions: Vec<PositionModel>,
+) -> SceneModel {
+    SceneModel {
+        text: text.map(str::to_string),
+        ..scene_model(id, name, Some(timestamp), positions)
+    }
+}
+
+fn rgba(a: u8, r: u8, g: u8, b: u8) -> Color {
+    Color { a, r, g, b }
+}
@@ -0,0 +1,8 @@
+#[path = "../shared/models.rs"]
+mod models;
+
+pub use models::build_dancer;
+pub use models::dancer_position;
+pub use models::scene_model;
+
+pub mod booklet_export_spec;
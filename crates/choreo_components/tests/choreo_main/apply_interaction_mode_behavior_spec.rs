@@ -54,7 +54,7 @@ fn apply_interaction_mode_behavior_spec() {
         });
 
         spec.it(
-            "maps rotate and scale modes with and without selection",
+            "maps rotate, scale and bend-path modes with and without selection",
             |_| {
                 let mut state = ChoreoMainState::default();
 
@@ -100,6 +100,19 @@ fn apply_interaction_mode_behavior_spec() {
                     InteractionStateMachineState::ScalePositions
                 );
 
+                reduce(
+                    &mut state,
+                    ChoreoMainAction::ApplyInteractionMode {
+                        mode: InteractionMode::BendPath,
+                        selected_positions_count: 1,
+                    },
+                );
+                check_eq!(
+                    errors,
+                    state.interaction_state_machine,
+                    InteractionStateMachineState::BendPathSelection
+                );
+
                 assert_no_errors(errors);
             },
         );
@@ -0,0 +1,133 @@
+use choreo_components::choreography_settings::actions::ChoreographySettingsAction;
+use choreo_components::choreography_settings::state::SelectedSceneState;
+use choreo_master_mobile_json::Color;
+use choreo_models::ChoreographyModel;
+use choreo_models::SceneModel;
+
+use crate::choreo_main::Report;
+use crate::choreo_main::actions::ChoreoMainAction;
+use crate::choreo_main::assert_no_errors;
+use crate::choreo_main::build_dancer;
+use crate::choreo_main::build_position;
+use crate::choreo_main::dancer_position;
+use crate::choreo_main::reducer::reduce;
+use crate::choreo_main::scene_model;
+use crate::choreo_main::state::ChoreoMainState;
+
+fn load_state(scenes: Vec<SceneModel>, selected: usize) -> ChoreoMainState {
+    let selected_scene = SelectedSceneState {
+        scene_id: scenes[selected].scene_id,
+        name: scenes[selected].name.clone(),
+        text: String::new(),
+        fixed_positions: false,
+        timestamp: None,
+        color: Color::transparent(),
+    };
+    let mut state = ChoreoMainState::default();
+    reduce(
+        &mut state,
+        ChoreoMainAction::ChoreographySettingsAction(
+            ChoreographySettingsAction::LoadChoreography {
+                choreography: Box::new(ChoreographyModel {
+                    name: "Auto Assign".to_string(),
+                    scenes,
+                    ..ChoreographyModel::default()
+                }),
+                selected_scene: Some(selected_scene),
+            },
+        ),
+    );
+    state
+}
+
+#[test]
+fn auto_assign_dancers_spec() {
+    let suite = rspec::describe("auto assign dancers in main reducer", (), |spec| {
+        spec.it(
+            "writes the assignment back into the selected scene model",
+            |_| {
+                let lead = build_dancer(1, "Lead");
+                let follow = build_dancer(2, "Follow");
+                let mut state = load_state(
+                    vec![
+                        scene_model(
+                            1,
+                            "Opening",
+                            None,
+                            vec![
+                                dancer_position(&lead, -2.0, 0.0),
+                                dancer_position(&follow, 2.0, 0.0),
+                            ],
+                        ),
+                        scene_model(
+                            2,
+                            "Travel",
+                            None,
+                            vec![build_position(3.0, 1.0), build_position(-3.0, 1.0)],
+                        ),
+                    ],
+                    1,
+                );
+                let draw_count = state.draw_floor_request_count;
+
+                reduce(&mut state, ChoreoMainAction::AutoAssignDancers);
+
+                let mut errors = Vec::new();
+                let assigned = state.choreography_settings_state.choreography.scenes[1]
+                    .positions
+                    .iter()
+                    .map(|position| position.dancer.as_ref().map(|dancer| dancer.name.clone()))
+                    .collect::<Vec<_>>();
+                check_eq!(
+                    errors,
+                    assigned,
+                    vec![Some("Follow".to_string()), Some("Lead".to_string())]
+                );
+                check_eq!(
+                    errors,
+                    state.scene_models[1].positions[0]
+                        .dancer
+                        .as_ref()
+                        .map(|dancer| dancer.name.clone()),
+                    Some("Follow".to_string())
+                );
+                check_eq!(
+                    errors,
+                    state.floor_state.source_positions[0].shortcut,
+                    "Follow"
+                );
+                check_eq!(errors, state.draw_floor_request_count, draw_count + 1);
+                check_eq!(errors, state.is_dialog_open, false);
+                assert_no_errors(errors);
+            },
+        );
+
+        spec.it(
+            "shows a dialog when the selected scene has no open spots",
+            |_| {
+                let lead = build_dancer(1, "Lead");
+                let mut state = load_state(
+                    vec![
+                        scene_model(1, "Opening", None, vec![dancer_position(&lead, 0.0, 0.0)]),
+                        scene_model(2, "Travel", None, vec![dancer_position(&lead, 1.0, 0.0)]),
+                    ],
+                    1,
+                );
+
+                reduce(&mut state, ChoreoMainAction::AutoAssignDancers);
+
+                let mut errors = Vec::new();
+                check_eq!(errors, state.is_dialog_open, true);
+                check_eq!(
+                    errors,
+                    state.dialog_content.as_deref(),
+                    Some("The selected scene has no unassigned positions.")
+                );
+                assert_no_errors(errors);
+            },
+        );
+    });
+
+    let report = crate::choreo_main::run_suite(&suite);
+    assert!(report.is_success());
+}
@@ -0,0 +1,369 @@
+use std::fs;
+use std::rc::Rc;
+
+use choreo_components::choreo_main::AUTOSAVE_INTERVAL;
+use choreo_components::choreo_main::ChoreoMainBehaviorDependencies;
+use choreo_components::choreo_main::MainPageActionHandlers;
+use choreo_components::choreo_main::MainPageBinding;
+use choreo_components::choreo_main::MainPageDependencies;
+use choreo_components::choreo_main::actions::ChoreoMainAction;
+use choreo_components::choreo_main::actions::OpenChoreoRequested;
+use choreo_components::choreo_main::translations::recovery_dialog_message;
+use choreo_components::choreography_settings::actions::ChoreographySettingsAction;
+use choreo_components::preferences::InMemoryRecoveryStore;
+use choreo_components::preferences::RecoverySnapshot;
+use choreo_components::preferences::RecoveryStore;
+use choreo_master_mobile_json::export;
+use choreo_master_mobile_json::import;
+use choreo_models::ChoreographyModel;
+use choreo_models::ChoreographyModelMapper;
+use time::Duration;
+use time::OffsetDateTime;
+
+use crate::choreo_main::assert_no_errors;
+use crate::choreo_main::unique_temp_path;
+
+fn choreography_contents(name: &str, last_save_date: OffsetDateTime) -> String {
+    let choreography = ChoreographyModel {
+        name: name.to_string(),
+        last_save_date,
+        ..ChoreographyModel::default()
+    };
+    let mapper = ChoreographyModelMapper;
+    export(&mapper.map_to_json(&choreography)).expect("test choreography should serialize to json")
+}
+
+fn binding_with_store(store: &InMemoryRecoveryStore) -> MainPageBinding {
+    MainPageBinding::new(MainPageDependencies {
+        behavior_dependencies: ChoreoMainBehaviorDependencies {
+            recovery_store: Some(Rc::new(store.clone())),
+            ..ChoreoMainBehaviorDependencies::default()
+        },
+        ..MainPageDependencies::default()
+    })
+}
+
+fn open_choreography(binding: &MainPageBinding, file_path: Option<String>, contents: String) {
+    binding.dispatch(ChoreoMainAction::RequestOpenChoreo(OpenChoreoRequested {
+        file_path,
+        file_name: None,
+        contents,
+    }));
+}
+
+fn rename(binding: &MainPageBinding, name: &str) {
+    binding.dispatch(ChoreoMainAction::ChoreographySettingsAction(
+        ChoreographySettingsAction::UpdateName(name.to_string()),
+    ));
+}
+
+#[test]
+fn autosave_waits_for_the_interval_before_writing_a_snapshot() {
+    let store = InMemoryRecoveryStore::new();
+    let binding = binding_with_store(&store);
+    open_choreography(
+        &binding,
+        None,
+        choreography_contents("Draft", OffsetDateTime::now_utc()),
+    );
+    rename(&binding, "Draft edited");
+
+    let now = OffsetDateTime::now_utc();
+    let early_delay = binding.tick_autosave(now);
+    let early_snapshot = store.load();
+    let late_delay = binding.tick_autosave(now + AUTOSAVE_INTERVAL);
+    let snapshot = store.load();
+
+    let mut errors = Vec::new();
+
+    check!(
+        errors,
+        early_delay.is_some_and(|delay| delay <= AUTOSAVE_INTERVAL.unsigned_abs())
+    );
+    check!(errors, early_snapshot.is_none());
+    check_eq!(errors, late_delay, None::<std::time::Duration>);
+    check_eq!(
+        errors,
+        snapshot
+            .as_ref()
+            .and_then(|snapshot| import(&snapshot.contents).ok())
+            .map(|choreography| choreography.name),
+        Some("Draft edited".to_string())
+    );
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn autosave_covers_a_choreography_that_was_never_opened() {
+    let store = InMemoryRecoveryStore::new();
+    let binding = binding_with_store(&store);
+    binding.dispatch(ChoreoMainAction::Initialize);
+
+    let now = OffsetDateTime::now_utc();
+    let untouched_delay = binding.tick_autosave(now);
+    rename(&binding, "Draft");
+    let first_delay = binding.tick_autosave(now);
+    let early_snapshot = store.load();
+    let _ = binding.tick_autosave(now + AUTOSAVE_INTERVAL);
+    let snapshot = store.load();
+
+    let mut errors = Vec::new();
+
+    check_eq!(errors, untouched_delay, None::<std::time::Duration>);
+    check_eq!(errors, first_delay, Some(AUTOSAVE_INTERVAL.unsigned_abs()));
+    check!(errors, early_snapshot.is_none());
+    check_eq!(
+        errors,
+        snapshot
+            .as_ref()
+            .and_then(|snapshot| import(&snapshot.contents).ok())
+            .map(|choreography| choreography.name),
+        Some("Draft".to_string())
+    );
+    check_eq!(
+        errors,
+        snapshot
+            .as_ref()
+            .and_then(|snapshot| snapshot.file_path.clone()),
+        None::<String>
+    );
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn autosave_skips_unchanged_choreography() {
+    let store = InMemoryRecoveryStore::new();
+    let binding = binding_with_store(&store);
+    open_choreography(
+        &binding,
+        None,
+        choreography_contents("Untouched", OffsetDateTime::now_utc()),
+    );
+
+    let delay = binding.tick_autosave(OffsetDateTime::now_utc() + AUTOSAVE_INTERVAL);
+
+    let mut errors = Vec::new();
+
+    check_eq!(errors, delay, None::<std::time::Duration>);
+    check!(errors, store.load().is_none());
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn opening_another_choreography_drops_the_previous_snapshot() {
+    let store = InMemoryRecoveryStore::new();
+    let binding = binding_with_store(&store);
+    open_choreography(
+        &binding,
+        None,
+        choreography_contents("First", OffsetDateTime::now_utc()),
+    );
+    rename(&binding, "First edited");
+    let _ = binding.tick_autosave(OffsetDateTime::now_utc() + AUTOSAVE_INTERVAL);
+    binding.dispatch(ChoreoMainAction::Undo);
+
+    let mut errors = Vec::new();
+
+    check!(errors, store.load().is_some());
+    check!(errors, !binding.state().borrow().has_unsaved_changes());
+
+    open_choreography(
+        &binding,
+        None,
+        choreography_contents("Second", OffsetDateTime::now_utc()),
+    );
+    check_eq!(
+        errors,
+        binding.state().borrow().choreography_settings_state.name,
+        "Second"
+    );
+    check!(errors, store.load().is_none());
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn saving_clears_the_recovery_snapshot() {
+    let temp_file = unique_temp_path("autosave").with_extension("choreo");
+    let file_path = temp_file.to_string_lossy().into_owned();
+    let file_path_for_picker = file_path.clone();
+    let store = InMemoryRecoveryStore::new();
+    let binding = MainPageBinding::new(MainPageDependencies {
+        behavior_dependencies: ChoreoMainBehaviorDependencies {
+            recovery_store: Some(Rc::new(store.clone())),
+            ..ChoreoMainBehaviorDependencies::default()
+        },
+        action_handlers: MainPageActionHandlers {
+            pick_save_choreo_path: Some(Rc::new(move |_: &str| Some(file_path_for_picker.clone()))),
+            ..MainPageActionHandlers::default()
+        },
+    });
+    open_choreography(
+        &binding,
+        None,
+        choreography_contents("Draft", OffsetDateTime::now_utc()),
+    );
+    rename(&binding, "Draft edited");
+    let _ = binding.tick_autosave(OffsetDateTime::now_utc() + AUTOSAVE_INTERVAL);
+    let had_snapshot = store.load().is_some();
+
+    binding.dispatch(ChoreoMainAction::RequestSaveChoreo);
+    let delay = binding.tick_autosave(OffsetDateTime::now_utc() + AUTOSAVE_INTERVAL * 2);
+
+    let mut errors = Vec::new();
+
+    check!(errors, had_snapshot);
+    check!(errors, store.load().is_none());
+    check_eq!(errors, delay, None::<std::time::Duration>);
+
+    assert_no_errors(errors);
+
+    let _ = fs::remove_file(temp_file);
+}
+
+#[test]
+fn initialize_offers_a_snapshot_newer_than_the_last_save() {
+    let temp_file = unique_temp_path("autosave").with_extension("choreo");
+    let file_path = temp_file.to_string_lossy().into_owned();
+    let last_save_date = OffsetDateTime::now_utc() - Duration::hours(1);
+    fs::write(&temp_file, choreography_contents("On disk", last_save_date))
+        .expect("test choreography should be written");
+    let store = InMemoryRecoveryStore::new();
+    store.store(&RecoverySnapshot {
+        file_path: Some(file_path.clone()),
+        saved_at: last_save_date + Duration::minutes(5),
+        contents: choreography_contents("Recovered", last_save_date),
+    });
+    let binding = binding_with_store(&store);
+
+    binding.dispatch(ChoreoMainAction::Initialize);
+
+    let state = binding.state();
+    let state = state.borrow();
+    let offer = state.recovery_offer.clone();
+
+    let mut errors = Vec::new();
+
+    check_eq!(
+        errors,
+        offer.as_ref().map(|offer| offer.choreography_name.as_str()),
+        Some("Recovered")
+    );
+    check_eq!(
+        errors,
+        offer.as_ref().and_then(|offer| offer.file_path.clone()),
+        Some(file_path.clone())
+    );
+    check!(
+        errors,
+        offer
+            .as_ref()
+            .is_some_and(|offer| recovery_dialog_message("en", offer)
+                .starts_with("An autosaved copy of \"Recovered\" from "))
+    );
+    check!(errors, store.load().is_some());
+
+    assert_no_errors(errors);
+
+    let _ = fs::remove_file(temp_file);
+}
+
+#[test]
+fn initialize_drops_a_snapshot_older_than_the_last_save() {
+    let temp_file = unique_temp_path("autosave").with_extension("choreo");
+    let file_path = temp_file.to_string_lossy().into_owned();
+    let last_save_date = OffsetDateTime::now_utc() - Duration::hours(1);
+    fs::write(&temp_file, choreography_contents("On disk", last_save_date))
+        .expect("test choreography should be written");
+    let store = InMemoryRecoveryStore::new();
+    store.store(&RecoverySnapshot {
+        file_path: Some(file_path),
+        saved_at: last_save_date - Duration::minutes(5),
+        contents: choreography_contents("Stale", last_save_date),
+    });
+    let binding = binding_with_store(&store);
+
+    binding.dispatch(ChoreoMainAction::Initialize);
+
+    let state = binding.state();
+    let state = state.borrow();
+
+    let mut errors = Vec::new();
+
+    check!(errors, state.recovery_offer.is_none());
+    check!(errors, store.load().is_none());
+
+    assert_no_errors(errors);
+
+    let _ = fs::remove_file(temp_file);
+}
+
+#[test]
+fn restoring_opens_the_snapshot_as_unsaved_changes() {
+    let store = InMemoryRecoveryStore::new();
+    store.store(&RecoverySnapshot {
+        file_path: None,
+        saved_at: OffsetDateTime::now_utc(),
+        contents: choreography_contents("Recovered", OffsetDateTime::now_utc()),
+    });
+    let binding = binding_with_store(&store);
+
+    binding.dispatch(ChoreoMainAction::Initialize);
+    binding.dispatch(ChoreoMainAction::RestoreRecovery);
+    rename(&binding, "Recovered edited");
+    let _ = binding.tick_autosave(OffsetDateTime::now_utc() + AUTOSAVE_INTERVAL);
+
+    let state = binding.state();
+    let state = state.borrow();
+
+    let mut errors = Vec::new();
+
+    check!(errors, state.recovery_offer.is_none());
+    check!(errors, state.has_unsaved_changes());
+    check_eq!(
+        errors,
+        state.choreography_settings_state.name,
+        "Recovered edited"
+    );
+    check_eq!(
+        errors,
+        store
+            .load()
+            .and_then(|snapshot| import(&snapshot.contents).ok())
+            .map(|choreography| choreography.name),
+        Some("Recovered edited".to_string())
+    );
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn discarding_clears_the_offer_and_the_snapshot() {
+    let store = InMemoryRecoveryStore::new();
+    store.store(&RecoverySnapshot {
+        file_path: None,
+        saved_at: OffsetDateTime::now_utc(),
+        contents: choreography_contents("Recovered", OffsetDateTime::now_utc()),
+    });
+    let binding = binding_with_store(&store);
+
+    binding.dispatch(ChoreoMainAction::Initialize);
+    binding.dispatch(ChoreoMainAction::DiscardRecovery);
+
+    let state = binding.state();
+    let state = state.borrow();
+
+    let mut errors = Vec::new();
+
+    check!(errors, state.recovery_offer.is_none());
+    check!(errors, store.load().is_none());
+    check!(
+        errors,
+        state.choreography_settings_state.name != "Recovered"
+    );
+
+    assert_no_errors(errors);
+}
@@ -0,0 +1,190 @@
+use choreo_components::choreography_settings::actions::ChoreographySettingsAction;
+use choreo_components::choreography_settings::state::SelectedSceneState;
+use choreo_master_mobile_json::Color;
+use choreo_models::ChoreographyModel;
+use choreo_models::SceneModel;
+use choreo_models::SettingsModel;
+
+use crate::choreo_main::Report;
+use crate::choreo_main::actions::ChoreoMainAction;
+use crate::choreo_main::assert_no_errors;
+use crate::choreo_main::build_dancer;
+use crate::choreo_main::dancer_position;
+use crate::choreo_main::reducer::reduce;
+use crate::choreo_main::scene_model;
+use crate::choreo_main::state::ChoreoMainState;
+
+fn load_state(scenes: Vec<SceneModel>, selected: usize) -> ChoreoMainState {
+    let selected_scene = SelectedSceneState {
+        scene_id: scenes[selected].scene_id,
+        name: scenes[selected].name.clone(),
+        text: String::new(),
+        fixed_positions: false,
+        timestamp: None,
+        color: Color::transparent(),
+    };
+    let mut state = ChoreoMainState::default();
+    reduce(
+        &mut state,
+        ChoreoMainAction::ChoreographySettingsAction(
+            ChoreographySettingsAction::LoadChoreography {
+                choreography: Box::new(ChoreographyModel {
+                    name: "Bend Paths".to_string(),
+                    settings: SettingsModel {
+                        dancer_size: 1.0,
+                        ..SettingsModel::default()
+                    },
+                    scenes,
+                    ..ChoreographyModel::default()
+                }),
+                selected_scene: Some(selected_scene),
+            },
+        ),
+    );
+    state
+}
+
+#[test]
+fn bend_colliding_paths_spec() {
+    let suite = rspec::describe("bend colliding paths in main reducer", (), |spec| {
+        spec.it(
+            "writes bent control points into the selected scene model",
+            |_| {
+                let lead = build_dancer(1, "Lead");
+                let follow = build_dancer(2, "Follow");
+                let mut state = load_state(
+                    vec![
+                        scene_model(
+                            1,
+                            "Opening",
+                            None,
+                            vec![
+                                dancer_position(&lead, -2.0, 0.0),
+                                dancer_position(&follow, 2.0, 0.0),
+                            ],
+                        ),
+                        scene_model(
+                            2,
+                            "Swap",
+                            None,
+                            vec![
+                                dancer_position(&lead, 2.0, 0.0),
+                                dancer_position(&follow, -2.0, 0.0),
+                            ],
+                        ),
+                    ],
+                    0,
+                );
+                let draw_count = state.draw_floor_request_count;
+
+                reduce(&mut state, ChoreoMainAction::BendCollidingPaths);
+
+                let mut errors = Vec::new();
+                let positions = &state.choreography_settings_state.choreography.scenes[0].positions;
+                check_eq!(
+                    errors,
+                    positions
+                        .iter()
+                        .all(|position| position.curve1_x.is_some() && position.curve2_y.is_some()),
+                    true
+                );
+                check_eq!(
+                    errors,
+                    state.scene_models[0].positions[0].curve1_x,
+                    positions[0].curve1_x
+                );
+                check_eq!(
+                    errors,
+                    state.floor_state.source_positions[0].curve1_x,
+                    positions[0].curve1_x
+                );
+                check_eq!(errors, state.draw_floor_request_count, draw_count + 1);
+                check_eq!(errors, state.is_dialog_open, false);
+                assert_no_errors(errors);
+            },
+        );
+
+        spec.it("shows a dialog when no dancers collide", |_| {
+            let lead = build_dancer(1, "Lead");
+            let follow = build_dancer(2, "Follow");
+            let mut state = load_state(
+                vec![
+                    scene_model(
+                        1,
+                        "Opening",
+                        None,
+                        vec![
+                            dancer_position(&lead, 0.0, 0.0),
+                            dancer_position(&follow, 0.0, 3.0),
+                        ],
+                    ),
+                    scene_model(
+                        2,
+                        "Travel",
+                        None,
+                        vec![
+                            dancer_position(&lead, 4.0, 0.0),
+                            dancer_position(&follow, 4.0, 3.0),
+                        ],
+                    ),
+                ],
+                0,
+            );
+
+            reduce(&mut state, ChoreoMainAction::BendCollidingPaths);
+
+            let mut errors = Vec::new();
+            check_eq!(errors, state.is_dialog_open, true);
+            check_eq!(
+                errors,
+                state.dialog_content.as_deref(),
+                Some("No dancers come closer than the dancer size during this transition.")
+            );
+            assert_no_errors(errors);
+        });
+
+        spec.it("reports pairs that stay too close", |_| {
+            let lead = build_dancer(1, "Lead");
+            let follow = build_dancer(2, "Follow");
+            let mut state = load_state(
+                vec![
+                    scene_model(
+                        1,
+                        "Opening",
+                        None,
+                        vec![
+                            dancer_position(&lead, 0.0, 0.0),
+                            dancer_position(&follow, 0.5, 0.0),
+                        ],
+                    ),
+                    scene_model(
+                        2,
+                        "Travel",
+                        None,
+                        vec![
+                            dancer_position(&lead, 0.0, 4.0),
+                            dancer_position(&follow, 4.0, 4.0),
+                        ],
+                    ),
+                ],
+                0,
+            );
+
+            reduce(&mut state, ChoreoMainAction::BendCollidingPaths);
+
+            let mut errors = Vec::new();
+            check_eq!(errors, state.is_dialog_open, true);
+            check_eq!(
+                errors,
+                state.dialog_content.as_deref(),
+                Some(
+                    "1 pair(s) of dancers still come too close because they start or end too close together."
+                )
+            );
+            assert_no_errors(errors);
+        });
+    });
+
+    let report = crate::choreo_main::run_suite(&suite);
+    assert!(report.is_success());
+}
@@ -0,0 +1,162 @@
+use choreo_components::choreography_settings::actions::ChoreographySettingsAction;
+use choreo_components::choreography_settings::state::SelectedSceneState;
+use choreo_components::floor::actions::FloorAction;
+use choreo_components::floor::state::InteractionMode as FloorInteractionMode;
+use choreo_components::floor::state::Point;
+use choreo_master_mobile_json::Color;
+use choreo_master_mobile_json::SceneId;
+use choreo_models::ChoreographyModel;
+use choreo_models::PositionModel;
+
+use crate::choreo_main::actions::ChoreoMainAction;
+use crate::choreo_main::assert_no_errors;
+use crate::choreo_main::build_dancer;
+use crate::choreo_main::dancer_position;
+use crate::choreo_main::reducer::reduce;
+use crate::choreo_main::scene_model;
+use crate::choreo_main::state::ChoreoMainState;
+use crate::choreo_main::state::InteractionMode;
+
+fn is_close(actual: Option<f64>, expected: f64) -> bool {
+    actual.is_some_and(|actual| (actual - expected).abs() < 0.001)
+}
+
+fn load_state(first_orientation: Option<f64>, second_orientation: Option<f64>) -> ChoreoMainState {
+    let dancer = build_dancer(1, "Alex");
+    let facing = |x: f64, orientation: Option<f64>| PositionModel {
+        orientation,
+        ..dancer_position(&dancer, x, 0.0)
+    };
+    let scenes = vec![
+        scene_model(
+            1,
+            "Scene 1",
+            Some("0"),
+            vec![facing(0.0, first_orientation)],
+        ),
+        scene_model(
+            2,
+            "Scene 2",
+            Some("4"),
+            vec![facing(4.0, second_orientation)],
+        ),
+    ];
+    let mut state = ChoreoMainState::default();
+    reduce(
+        &mut state,
+        ChoreoMainAction::ChoreographySettingsAction(
+            ChoreographySettingsAction::LoadChoreography {
+                choreography: Box::new(ChoreographyModel {
+                    name: "Facing".to_string(),
+                    scenes,
+                    ..ChoreographyModel::default()
+                }),
+                selected_scene: Some(SelectedSceneState {
+                    scene_id: SceneId(1),
+                    name: "Scene 1".to_string(),
+                    text: String::new(),
+                    fixed_positions: false,
+                    timestamp: Some(0.0),
+                    color: Color::transparent(),
+                }),
+            },
+        ),
+    );
+    state
+}
+
+#[test]
+fn floor_draws_the_scene_orientation_and_turns_the_short_way_during_playback() {
+    let mut state = load_state(Some(350.0), Some(30.0));
+
+    let mut errors = Vec::new();
+
+    check!(
+        errors,
+        is_close(state.floor_state.source_positions[0].orientation, 350.0)
+    );
+    check!(
+        errors,
+        is_close(state.floor_state.rendered_positions[0].orientation, 350.0)
+    );
+
+    reduce(
+        &mut state,
+        ChoreoMainAction::UpdateAudioPosition { seconds: 1.0 },
+    );
+
+    check!(
+        errors,
+        (state.floor_state.interpolated_positions[0].x - 1.0).abs() < 0.001
+    );
+    check!(
+        errors,
+        is_close(state.floor_state.interpolated_positions[0].orientation, 0.0)
+    );
+    check!(
+        errors,
+        is_close(state.floor_state.rendered_positions[0].orientation, 0.0)
+    );
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn face_mode_turns_selected_dancers_in_the_scene_as_one_undo_step() {
+    let mut state = load_state(None, None);
+
+    reduce(&mut state, ChoreoMainAction::SelectMode { index: 6 });
+    reduce(
+        &mut state,
+        ChoreoMainAction::FloorAction(FloorAction::SelectRectangle {
+            start: Point::new(-1.0, -1.0),
+            end: Point::new(1.0, 1.0),
+        }),
+    );
+    reduce(
+        &mut state,
+        ChoreoMainAction::FloorAction(FloorAction::PointerPressed {
+            point: Point::new(0.0, 0.0),
+        }),
+    );
+    for target in [Point::new(0.0, -3.0), Point::new(3.0, 0.0)] {
+        reduce(
+            &mut state,
+            ChoreoMainAction::FloorAction(FloorAction::FaceSelectedTowards { target }),
+        );
+    }
+    reduce(
+        &mut state,
+        ChoreoMainAction::FloorAction(FloorAction::PointerReleased {
+            point: Point::new(0.0, 0.0),
+        }),
+    );
+
+    let mut errors = Vec::new();
+
+    check_eq!(errors, state.interaction_mode, InteractionMode::Face);
+    check_eq!(
+        errors,
+        state.floor_state.interaction_mode,
+        FloorInteractionMode::Face
+    );
+    check!(
+        errors,
+        is_close(
+            state.choreography_settings_state.choreography.scenes[0].positions[0].orientation,
+            90.0
+        )
+    );
+
+    reduce(&mut state, ChoreoMainAction::Undo);
+
+    check!(
+        errors,
+        state.choreography_settings_state.choreography.scenes[0].positions[0]
+            .orientation
+            .is_none()
+    );
+    check!(errors, !state.history.can_undo());
+
+    assert_no_errors(errors);
+}
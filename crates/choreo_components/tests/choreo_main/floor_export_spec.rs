@@ -0,0 +1,180 @@
+use std::fs;
+use std::rc::Rc;
+
+use choreo_components::choreo_main::FloorExportError;
+use choreo_components::choreo_main::FloorExportOptions;
+use choreo_components::choreo_main::FloorImageFormat;
+use choreo_components::choreo_main::export_scene_floors;
+use choreo_components::choreo_main::render_scene_floors;
+use choreo_components::choreo_main::scene_floor_file_stem;
+use choreo_components::choreography_settings::actions::ChoreographySettingsAction;
+use choreo_master_mobile_json::Color;
+use choreo_master_mobile_json::DancerId;
+use choreo_models::ChoreographyModel;
+use choreo_models::DancerModel;
+use choreo_models::FloorModel;
+use choreo_models::RoleModel;
+
+use crate::choreo_main::actions::ChoreoMainAction;
+use crate::choreo_main::assert_no_errors;
+use crate::choreo_main::dancer_position;
+use crate::choreo_main::reducer::reduce;
+use crate::choreo_main::scene_model;
+use crate::choreo_main::state::ChoreoMainState;
+use crate::choreo_main::unique_temp_path;
+
+const PATH_TO_STROKE: &str = r##"stroke="#dc2828" stroke-width="2""##;
+const PATH_FROM_STROKE: &str = r##"stroke="#dc2828" stroke-width="1""##;
+
+#[test]
+fn renders_the_requested_scene_range_with_header_legend_axis_labels_and_paths() {
+    let state = state_with_scenes(&["Opening", "Travel", "Big Finale!"]);
+    let options = FloorExportOptions {
+        scenes: Some(1..=5),
+        ..FloorExportOptions::default()
+    };
+
+    let images = render_scene_floors(&state, &options);
+
+    let mut errors = Vec::new();
+
+    check_eq!(
+        errors,
+        images
+            .iter()
+            .map(|image| image.file_stem.as_str())
+            .collect::<Vec<_>>(),
+        vec!["02-travel", "03-big-finale"]
+    );
+    let travel = &images[0].svg;
+    check!(errors, travel.contains(">Viennese Waltz</text>"));
+    check!(errors, travel.contains(">Travel</text>"));
+    check!(errors, travel.contains(">Lead</text>"));
+    check!(errors, travel.contains(">(0.00, 1.00)</text>"));
+    check!(errors, travel.contains(">0</text>"));
+    check!(errors, travel.contains(PATH_TO_STROKE));
+    check!(errors, travel.contains(PATH_FROM_STROKE));
+    let finale = &images[1].svg;
+    check!(errors, finale.contains(PATH_FROM_STROKE));
+    check!(errors, !finale.contains(PATH_TO_STROKE));
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn export_writes_svg_and_png_files_for_every_scene() {
+    let state = state_with_scenes(&["Opening", "Travel"]);
+    let directory = unique_temp_path("floor_export");
+
+    let written = export_scene_floors(&state, &directory, &FloorExportOptions::default())
+        .expect("floor export should write every scene");
+
+    let mut errors = Vec::new();
+
+    check_eq!(
+        errors,
+        written,
+        vec![
+            directory.join("01-opening.svg"),
+            directory.join("01-opening.png"),
+            directory.join("02-travel.svg"),
+            directory.join("02-travel.png"),
+        ]
+    );
+    let svg = fs::read_to_string(directory.join("01-opening.svg")).unwrap_or_default();
+    check!(errors, svg.starts_with("<svg"));
+    check!(errors, svg.contains(">Opening</text>"));
+    let png = fs::read(directory.join("02-travel.png")).unwrap_or_default();
+    check!(errors, png.starts_with(b"\x89PNG\r\n\x1a\n"));
+
+    assert_no_errors(errors);
+
+    let _ = fs::remove_dir_all(directory);
+}
+
+#[test]
+fn export_reports_when_there_is_nothing_to_export() {
+    let state = ChoreoMainState::default();
+    let options = FloorExportOptions {
+        formats: vec![FloorImageFormat::Svg],
+        ..FloorExportOptions::default()
+    };
+
+    let result = export_scene_floors(&state, &unique_temp_path("floor_export"), &options);
+
+    assert_eq!(result, Err(FloorExportError::NoScenes));
+}
+
+#[test]
+fn file_stems_sort_in_scene_order() {
+    let mut errors = Vec::new();
+
+    check_eq!(
+        errors,
+        scene_floor_file_stem(2, " Big  Finale! "),
+        "03-big-finale"
+    );
+    check_eq!(
+        errors,
+        scene_floor_file_stem(11, "Ünïcode Walk"),
+        "12-ünïcode-walk"
+    );
+    check_eq!(errors, scene_floor_file_stem(0, "  "), "01");
+
+    assert_no_errors(errors);
+}
+
+fn state_with_scenes(names: &[&str]) -> ChoreoMainState {
+    let role = Rc::new(RoleModel {
+        z_index: 1,
+        name: "Lead".to_string(),
+        color: rgba(255, 120, 0, 0),
+    });
+    let lead = Rc::new(DancerModel {
+        dancer_id: DancerId(1),
+        role,
+        name: "Lead".to_string(),
+        shortcut: "L".to_string(),
+        color: rgba(255, 220, 40, 40),
+        icon: None,
+    });
+    let scenes = names
+        .iter()
+        .enumerate()
+        .map(|(index, name)| {
+            scene_model(
+                index as i32 + 1,
+                name,
+                Some(&format!("{}.0", index * 4)),
+                vec![dancer_position(&lead, index as f64 - 1.0, 1.0)],
+            )
+        })
+        .collect();
+    let choreography = ChoreographyModel {
+        name: "Viennese Waltz".to_string(),
+        floor: FloorModel {
+            size_front: 4,
+            size_back: 4,
+            size_left: 5,
+            size_right: 5,
+        },
+        scenes,
+        ..ChoreographyModel::default()
+    };
+
+    let mut state = ChoreoMainState::default();
+    reduce(
+        &mut state,
+        ChoreoMainAction::ChoreographySettingsAction(
+            ChoreographySettingsAction::LoadChoreography {
+                choreography: Box::new(choreography),
+                selected_scene: None,
+            },
+        ),
+    );
+    state
+}
+
+fn rgba(a: u8, r: u8, g: u8, b: u8) -> Color {
+    Color { a, r, g, b }
+}
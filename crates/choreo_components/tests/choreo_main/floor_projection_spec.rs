@@ -33,7 +33,8 @@ macro_rules! check_eq {
 
 macro_rules! check {
     ($errors:expr, $condition:expr) => {
-        if !$condition {
+        let passed: bool = $condition;
+        if !passed {
             $errors.push(format!("condition failed: {}", stringify!($condition)));
         }
     };
@@ -81,6 +82,7 @@ fn load_choreography_projects_floor_renderer_state_from_scene_models() {
         positions: vec![
             PositionModel {
                 dancer: Some(lead.clone()),
+                required_role: None,
                 orientation: None,
                 x: -1.0,
                 y: 1.0,
@@ -95,6 +97,7 @@ fn load_choreography_projects_floor_renderer_state_from_scene_models() {
             },
             PositionModel {
                 dancer: Some(follow.clone()),
+                required_role: None,
                 orientation: None,
                 x: 1.0,
                 y: -1.0,
@@ -122,6 +125,7 @@ fn load_choreography_projects_floor_renderer_state_from_scene_models() {
         positions: vec![
             PositionModel {
                 dancer: Some(lead),
+                required_role: None,
                 orientation: None,
                 x: 1.0,
                 y: 2.0,
@@ -136,6 +140,7 @@ fn load_choreography_projects_floor_renderer_state_from_scene_models() {
             },
             PositionModel {
                 dancer: Some(follow),
+                required_role: None,
                 orientation: None,
                 x: 2.0,
                 y: 0.0,
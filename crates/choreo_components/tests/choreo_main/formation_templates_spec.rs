@@ -0,0 +1,164 @@
+use std::rc::Rc;
+
+use choreo_components::choreo_main::ChoreoMainBehaviorDependencies;
+use choreo_components::choreo_main::MainPageBinding;
+use choreo_components::choreo_main::MainPageDependencies;
+use choreo_components::choreography_settings::actions::ChoreographySettingsAction;
+use choreo_components::choreography_settings::state::SelectedSceneState;
+use choreo_components::main_page::ui::scene_pane_state;
+use choreo_components::preferences::InMemoryPreferences;
+use choreo_components::preferences::Preferences;
+use choreo_components::scenes::formations::FORMATION_TEMPLATES_KEY;
+use choreo_components::scenes::formations::FormationChoice;
+use choreo_components::scenes::formations::FormationKind;
+use choreo_components::scenes::formations::FormationParameters;
+use choreo_components::scenes::formations::FormationTemplate;
+use choreo_master_mobile_json::Color;
+use choreo_master_mobile_json::SceneId;
+use choreo_models::ChoreographyModel;
+use choreo_models::FloorModel;
+
+use crate::choreo_main::actions::ChoreoMainAction;
+use crate::choreo_main::assert_no_errors;
+use crate::choreo_main::build_dancer;
+use crate::choreo_main::dancer_position;
+use crate::choreo_main::reducer::reduce;
+use crate::choreo_main::scene_model;
+use crate::choreo_main::state::ChoreoMainState;
+
+fn load_action() -> ChoreoMainAction {
+    ChoreoMainAction::ChoreographySettingsAction(ChoreographySettingsAction::LoadChoreography {
+        choreography: Box::new(ChoreographyModel {
+            name: "Formations".to_string(),
+            floor: FloorModel {
+                size_front: 5,
+                size_back: 5,
+                size_left: 5,
+                size_right: 5,
+            },
+            scenes: vec![scene_model(
+                1,
+                "Opening",
+                Some("0"),
+                vec![
+                    dancer_position(&build_dancer(1, "Alex"), 3.0, 1.0),
+                    dancer_position(&build_dancer(2, "Sam"), 1.0, -1.0),
+                ],
+            )],
+            ..ChoreographyModel::default()
+        }),
+        selected_scene: Some(SelectedSceneState {
+            scene_id: SceneId(1),
+            name: "Opening".to_string(),
+            text: String::new(),
+            fixed_positions: false,
+            timestamp: Some(0.0),
+            color: Color::transparent(),
+        }),
+    })
+}
+
+fn spots(state: &ChoreoMainState) -> Vec<(f64, f64)> {
+    state.choreography_settings_state.choreography.scenes[0]
+        .positions
+        .iter()
+        .map(|position| (position.x, position.y))
+        .collect()
+}
+
+fn dancer_names(state: &ChoreoMainState) -> Vec<Option<String>> {
+    state.choreography_settings_state.choreography.scenes[0]
+        .positions
+        .iter()
+        .map(|position| position.dancer.as_ref().map(|dancer| dancer.name.clone()))
+        .collect()
+}
+
+#[test]
+fn applying_a_formation_rearranges_the_scene_as_one_undo_step() {
+    let mut state = ChoreoMainState::default();
+    reduce(&mut state, load_action());
+    reduce(
+        &mut state,
+        ChoreoMainAction::SelectFormation {
+            choice: FormationChoice::Generated(FormationKind::Line),
+        },
+    );
+    reduce(
+        &mut state,
+        ChoreoMainAction::UpdateFormationParameters {
+            parameters: FormationParameters {
+                count: 3,
+                spacing_steps: 2,
+            },
+        },
+    );
+
+    let mut errors = Vec::new();
+    check!(errors, scene_pane_state(&state).can_apply_formation);
+
+    reduce(&mut state, ChoreoMainAction::ApplyFormation);
+
+    check_eq!(
+        errors,
+        spots(&state),
+        vec![(-2.0, 0.0), (0.0, 0.0), (2.0, 0.0)]
+    );
+    check_eq!(
+        errors,
+        dancer_names(&state),
+        vec![Some("Alex".to_string()), Some("Sam".to_string()), None]
+    );
+    check_eq!(errors, state.scene_models[0].positions.len(), 3);
+
+    reduce(&mut state, ChoreoMainAction::Undo);
+
+    check_eq!(errors, spots(&state), vec![(3.0, 1.0), (1.0, -1.0)]);
+    check!(errors, !state.history.can_undo());
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn saved_formation_templates_are_kept_in_the_preferences() {
+    let preferences: Rc<dyn Preferences> = Rc::new(InMemoryPreferences::new());
+    let dependencies = || MainPageDependencies {
+        behavior_dependencies: ChoreoMainBehaviorDependencies {
+            preferences: Some(Rc::clone(&preferences)),
+            ..ChoreoMainBehaviorDependencies::default()
+        },
+        ..MainPageDependencies::default()
+    };
+
+    let binding = MainPageBinding::new(dependencies());
+    binding.dispatch(ChoreoMainAction::Initialize);
+    binding.dispatch(load_action());
+    binding.dispatch(ChoreoMainAction::SaveFormationTemplate);
+
+    let template = FormationTemplate {
+        name: "Opening".to_string(),
+        spots: vec![[1.0, 1.0], [-1.0, -1.0]],
+    };
+    let mut errors = Vec::new();
+    {
+        let state = binding.state();
+        let state = state.borrow();
+        check_eq!(errors, state.formation_templates, vec![template.clone()]);
+        check_eq!(errors, state.formation_choice, FormationChoice::Template(0));
+    }
+    check!(
+        errors,
+        !preferences
+            .get_string(FORMATION_TEMPLATES_KEY, "")
+            .is_empty()
+    );
+
+    let reopened = MainPageBinding::new(dependencies());
+    reopened.dispatch(ChoreoMainAction::Initialize);
+
+    let state = reopened.state();
+    let state = state.borrow();
+    check_eq!(errors, state.formation_templates, vec![template.clone()]);
+
+    assert_no_errors(errors);
+}
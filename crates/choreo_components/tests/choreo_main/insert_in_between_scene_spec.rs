@@ -0,0 +1,144 @@
+use choreo_components::choreography_settings::actions::ChoreographySettingsAction;
+use choreo_components::choreography_settings::state::SelectedSceneState;
+use choreo_master_mobile_json::Color;
+use choreo_master_mobile_json::SceneId;
+use choreo_models::ChoreographyModel;
+use choreo_models::SceneModel;
+
+use crate::choreo_main::Report;
+use crate::choreo_main::actions::ChoreoMainAction;
+use crate::choreo_main::assert_no_errors;
+use crate::choreo_main::build_dancer;
+use crate::choreo_main::dancer_position;
+use crate::choreo_main::reducer::reduce;
+use crate::choreo_main::scene_model;
+use crate::choreo_main::state::ChoreoMainState;
+
+fn load_state(scenes: Vec<SceneModel>, selected: usize) -> ChoreoMainState {
+    let selected_scene = SelectedSceneState {
+        scene_id: scenes[selected].scene_id,
+        name: scenes[selected].name.clone(),
+        text: String::new(),
+        fixed_positions: false,
+        timestamp: None,
+        color: Color::transparent(),
+    };
+    let mut state = ChoreoMainState::default();
+    reduce(
+        &mut state,
+        ChoreoMainAction::ChoreographySettingsAction(
+            ChoreographySettingsAction::LoadChoreography {
+                choreography: Box::new(ChoreographyModel {
+                    name: "In Between".to_string(),
+                    scenes,
+                    ..ChoreographyModel::default()
+                }),
+                selected_scene: Some(selected_scene),
+            },
+        ),
+    );
+    state
+}
+
+#[test]
+fn insert_in_between_scene_spec() {
+    let suite = rspec::describe("insert in-between scene in main reducer", (), |spec| {
+        spec.it(
+            "inserts the scene at the audio position and renumbers scene ids",
+            |_| {
+                let lead = build_dancer(1, "Lead");
+                let follow = build_dancer(2, "Follow");
+                let mut state = load_state(
+                    vec![
+                        scene_model(
+                            1,
+                            "Opening",
+                            Some("10"),
+                            vec![
+                                dancer_position(&lead, 0.0, 0.0),
+                                dancer_position(&follow, 2.0, 0.0),
+                            ],
+                        ),
+                        scene_model(
+                            2,
+                            "Finale",
+                            Some("20"),
+                            vec![
+                                dancer_position(&lead, 10.0, 0.0),
+                                dancer_position(&follow, -10.0, 0.0),
+                            ],
+                        ),
+                    ],
+                    0,
+                );
+                state.audio_position_seconds = 15.0;
+                let draw_count = state.draw_floor_request_count;
+
+                reduce(
+                    &mut state,
+                    ChoreoMainAction::InsertInBetweenScene { formation: None },
+                );
+
+                let mut errors = Vec::new();
+                let scenes = &state.choreography_settings_state.choreography.scenes;
+                check_eq!(
+                    errors,
+                    scenes
+                        .iter()
+                        .map(|scene| scene.scene_id)
+                        .collect::<Vec<_>>(),
+                    vec![SceneId(1), SceneId(2), SceneId(3)]
+                );
+                check_eq!(errors, scenes[1].timestamp.as_deref(), Some("15"));
+                check_eq!(
+                    errors,
+                    scenes[1]
+                        .positions
+                        .iter()
+                        .map(|position| position.dancer.as_ref().map(|dancer| dancer.name.clone()))
+                        .collect::<Vec<_>>(),
+                    vec![Some("Follow".to_string()), Some("Lead".to_string())]
+                );
+                check_eq!(errors, state.scenes.len(), 3);
+                check_eq!(errors, state.scenes[1].timestamp_seconds, Some(15.0));
+                check_eq!(errors, state.selected_scene_index, Some(1));
+                check_eq!(errors, state.draw_floor_request_count, draw_count + 1);
+                check_eq!(errors, state.is_dialog_open, false);
+                assert_no_errors(errors);
+            },
+        );
+
+        spec.it("shows a dialog when the last scene is selected", |_| {
+            let lead = build_dancer(1, "Lead");
+            let mut state = load_state(
+                vec![
+                    scene_model(1, "Opening", None, vec![dancer_position(&lead, 0.0, 0.0)]),
+                    scene_model(2, "Finale", None, vec![dancer_position(&lead, 1.0, 0.0)]),
+                ],
+                1,
+            );
+
+            reduce(
+                &mut state,
+                ChoreoMainAction::InsertInBetweenScene { formation: None },
+            );
+
+            let mut errors = Vec::new();
+            check_eq!(errors, state.is_dialog_open, true);
+            check_eq!(
+                errors,
+                state.dialog_content.as_deref(),
+                Some("There is no next scene to transition to.")
+            );
+            check_eq!(
+                errors,
+                state.choreography_settings_state.choreography.scenes.len(),
+                2
+            );
+            assert_no_errors(errors);
+        });
+    });
+
+    let report = crate::choreo_main::run_suite(&suite);
+    assert!(report.is_success());
+}
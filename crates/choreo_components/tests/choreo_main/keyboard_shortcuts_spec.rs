@@ -0,0 +1,321 @@
+use std::rc::Rc;
+
+use choreo_components::audio_player::actions::AudioPlayerAction;
+use choreo_components::choreo_main::ChoreoMainBehaviorDependencies;
+use choreo_components::choreo_main::MainPageBinding;
+use choreo_components::choreo_main::MainPageDependencies;
+use choreo_components::choreo_main::ui::shortcut_action;
+use choreo_components::choreography_settings::actions::ChoreographySettingsAction;
+use choreo_components::choreography_settings::state::SelectedSceneState;
+use choreo_components::floor::actions::FloorAction;
+use choreo_components::floor::state::Distribution;
+use choreo_components::floor::state::Point;
+use choreo_components::preferences::InMemoryPreferences;
+use choreo_components::preferences::Preferences;
+use choreo_components::settings::actions::SettingsAction;
+use choreo_components::settings::keyboard_shortcuts::KEYBOARD_SHORTCUTS_KEY;
+use choreo_components::settings::keyboard_shortcuts::LARGE_NUDGE_GRID_STEPS;
+use choreo_components::settings::keyboard_shortcuts::ShortcutCommand;
+use choreo_master_mobile_json::Color;
+use choreo_master_mobile_json::SceneId;
+use choreo_models::ChoreographyModel;
+use choreo_models::FloorModel;
+use egui::Key;
+use egui::KeyboardShortcut;
+use egui::Modifiers;
+
+use crate::choreo_main::actions::ChoreoMainAction;
+use crate::choreo_main::assert_no_errors;
+use crate::choreo_main::build_position;
+use crate::choreo_main::reducer::reduce;
+use crate::choreo_main::scene_model;
+use crate::choreo_main::state::ChoreoMainState;
+
+fn load_action() -> ChoreoMainAction {
+    ChoreoMainAction::ChoreographySettingsAction(ChoreographySettingsAction::LoadChoreography {
+        choreography: Box::new(ChoreographyModel {
+            name: "Shortcuts".to_string(),
+            floor: FloorModel {
+                size_front: 5,
+                size_back: 5,
+                size_left: 5,
+                size_right: 5,
+            },
+            scenes: vec![
+                scene_model(
+                    1,
+                    "Opening",
+                    None,
+                    vec![build_position(0.0, 0.0), build_position(2.0, 1.0)],
+                ),
+                scene_model(2, "Finale", None, vec![build_position(1.0, 1.0)]),
+            ],
+            ..ChoreographyModel::default()
+        }),
+        selected_scene: Some(SelectedSceneState {
+            scene_id: SceneId(1),
+            name: "Opening".to_string(),
+            text: String::new(),
+            fixed_positions: false,
+            timestamp: None,
+            color: Color::transparent(),
+        }),
+    })
+}
+
+fn loaded_state() -> ChoreoMainState {
+    let mut state = ChoreoMainState::default();
+    reduce(&mut state, load_action());
+    state
+}
+
+/// The actions the main page emits for one key press, leaving out the floor's own layout
+/// updates.
+fn key_actions(state: &ChoreoMainState, key: Key, modifiers: Modifiers) -> Vec<ChoreoMainAction> {
+    let context = egui::Context::default();
+    let mut actions = Vec::new();
+    let input = egui::RawInput {
+        modifiers,
+        events: vec![egui::Event::Key {
+            key,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers,
+        }],
+        ..egui::RawInput::default()
+    };
+    let _ = context.run(input, |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            actions = crate::choreo_main::ui::draw(ui, state);
+        });
+    });
+    actions
+        .into_iter()
+        .filter(|action| {
+            matches!(
+                action,
+                ChoreoMainAction::FloorAction(
+                    FloorAction::MoveSelectedByDelta { .. }
+                        | FloorAction::SelectAll
+                        | FloorAction::AlignSelected { .. }
+                        | FloorAction::DistributeSelected { .. }
+                        | FloorAction::MirrorSelected { .. }
+                ) | ChoreoMainAction::SelectMode { .. }
+                    | ChoreoMainAction::SelectScene { .. }
+                    | ChoreoMainAction::DeleteSelectedScene
+                    | ChoreoMainAction::DeleteSelectedPositions
+                    | ChoreoMainAction::AudioPlayerAction(AudioPlayerAction::TogglePlayPause)
+            )
+        })
+        .collect()
+}
+
+fn press(state: &mut ChoreoMainState, key: Key, modifiers: Modifiers) {
+    for action in key_actions(state, key, modifiers) {
+        reduce(state, action);
+    }
+}
+
+fn spots(state: &ChoreoMainState) -> Vec<(f64, f64)> {
+    state.choreography_settings_state.choreography.scenes[0]
+        .positions
+        .iter()
+        .map(|position| (position.x, position.y))
+        .collect()
+}
+
+#[test]
+fn arrow_keys_nudge_the_selection_by_grid_steps() {
+    let mut state = loaded_state();
+    let step = 1.0 / f64::from(state.choreography_settings_state.grid_resolution());
+
+    let mut errors = Vec::new();
+    check!(
+        errors,
+        key_actions(&state, Key::ArrowRight, Modifiers::NONE).is_empty()
+    );
+
+    reduce(
+        &mut state,
+        ChoreoMainAction::FloorAction(FloorAction::SelectRectangle {
+            start: Point::new(-1.0, -1.0),
+            end: Point::new(1.0, 1.0),
+        }),
+    );
+    press(&mut state, Key::ArrowRight, Modifiers::NONE);
+    press(&mut state, Key::ArrowUp, Modifiers::SHIFT);
+
+    check_eq!(
+        errors,
+        spots(&state),
+        vec![(step, LARGE_NUDGE_GRID_STEPS * step), (2.0, 1.0)]
+    );
+    check_eq!(errors, state.history.undo_count(), 2);
+
+    reduce(&mut state, ChoreoMainAction::Undo);
+
+    check_eq!(errors, spots(&state), vec![(step, 0.0), (2.0, 1.0)]);
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn shortcuts_select_all_switch_modes_scenes_and_playback() {
+    let mut state = loaded_state();
+
+    let mut errors = Vec::new();
+    press(&mut state, Key::A, Modifiers::COMMAND);
+    check_eq!(errors, state.floor_state.selected_positions, vec![0, 1]);
+
+    check_eq!(
+        errors,
+        key_actions(&state, Key::Num3, Modifiers::NONE),
+        vec![ChoreoMainAction::SelectMode { index: 2 }]
+    );
+    check_eq!(
+        errors,
+        key_actions(&state, Key::Space, Modifiers::NONE),
+        vec![ChoreoMainAction::AudioPlayerAction(
+            AudioPlayerAction::TogglePlayPause
+        )]
+    );
+    check_eq!(
+        errors,
+        key_actions(&state, Key::PageUp, Modifiers::NONE),
+        Vec::<ChoreoMainAction>::new()
+    );
+
+    press(&mut state, Key::PageDown, Modifiers::NONE);
+    check_eq!(errors, state.selected_scene_index, Some(1));
+    check_eq!(
+        errors,
+        shortcut_action(&state, ShortcutCommand::NextScene, Modifiers::NONE),
+        None::<ChoreoMainAction>
+    );
+
+    check_eq!(
+        errors,
+        shortcut_action(&state, ShortcutCommand::Delete, Modifiers::NONE),
+        Some(ChoreoMainAction::DeleteSelectedPositions)
+    );
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn alt_shortcuts_align_and_mirror_the_selection() {
+    let mut state = loaded_state();
+
+    let mut errors = Vec::new();
+    check!(
+        errors,
+        key_actions(&state, Key::ArrowLeft, Modifiers::ALT).is_empty()
+    );
+
+    press(&mut state, Key::A, Modifiers::COMMAND);
+    press(&mut state, Key::ArrowLeft, Modifiers::ALT);
+    check_eq!(errors, spots(&state), vec![(0.0, 0.0), (0.0, 1.0)]);
+
+    press(&mut state, Key::M, Modifiers::ALT | Modifiers::SHIFT);
+    check_eq!(errors, spots(&state), vec![(0.0, 0.0), (0.0, -1.0)]);
+    check_eq!(errors, state.history.undo_count(), 2);
+
+    check_eq!(
+        errors,
+        shortcut_action(&state, ShortcutCommand::DistributeOnCircle, Modifiers::NONE),
+        Some(ChoreoMainAction::FloorAction(
+            FloorAction::DistributeSelected {
+                distribution: Distribution::Circle,
+            }
+        ))
+    );
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn delete_removes_the_selected_positions_before_the_scene() {
+    let mut state = loaded_state();
+
+    let mut errors = Vec::new();
+    reduce(
+        &mut state,
+        ChoreoMainAction::FloorAction(FloorAction::SelectRectangle {
+            start: Point::new(1.0, 0.0),
+            end: Point::new(3.0, 2.0),
+        }),
+    );
+    press(&mut state, Key::Delete, Modifiers::NONE);
+
+    check_eq!(errors, spots(&state), vec![(0.0, 0.0)]);
+    check_eq!(errors, state.floor_state.positions.len(), 1);
+    check!(errors, state.floor_state.selected_positions.is_empty());
+    check_eq!(errors, state.scenes.len(), 2);
+
+    reduce(&mut state, ChoreoMainAction::Undo);
+    check_eq!(errors, spots(&state), vec![(0.0, 0.0), (2.0, 1.0)]);
+
+    press(&mut state, Key::Delete, Modifiers::NONE);
+    check_eq!(errors, state.scenes.len(), 1);
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn remapped_shortcuts_are_kept_in_the_preferences() {
+    let preferences: Rc<dyn Preferences> = Rc::new(InMemoryPreferences::new());
+    let dependencies = || MainPageDependencies {
+        behavior_dependencies: ChoreoMainBehaviorDependencies {
+            preferences: Some(Rc::clone(&preferences)),
+            ..ChoreoMainBehaviorDependencies::default()
+        },
+        ..MainPageDependencies::default()
+    };
+
+    let binding = MainPageBinding::new(dependencies());
+    binding.dispatch(ChoreoMainAction::Initialize);
+    binding.dispatch(ChoreoMainAction::SettingsAction(
+        SettingsAction::RemapShortcut {
+            command: ShortcutCommand::TogglePlayback,
+            shortcut: KeyboardShortcut::new(Modifiers::NONE, Key::P),
+        },
+    ));
+
+    let mut errors = Vec::new();
+    check!(
+        errors,
+        !preferences
+            .get_string(KEYBOARD_SHORTCUTS_KEY, "")
+            .is_empty()
+    );
+
+    let reopened = MainPageBinding::new(dependencies());
+    reopened.dispatch(ChoreoMainAction::Initialize);
+    {
+        let state = reopened.state();
+        let state = state.borrow();
+        check_eq!(
+            errors,
+            key_actions(&state, Key::P, Modifiers::NONE),
+            vec![ChoreoMainAction::AudioPlayerAction(
+                AudioPlayerAction::TogglePlayPause
+            )]
+        );
+        check!(
+            errors,
+            key_actions(&state, Key::Space, Modifiers::NONE).is_empty()
+        );
+    }
+
+    reopened.dispatch(ChoreoMainAction::SettingsAction(
+        SettingsAction::ResetShortcuts,
+    ));
+    check_eq!(
+        errors,
+        preferences.get_string(KEYBOARD_SHORTCUTS_KEY, ""),
+        ""
+    );
+
+    assert_no_errors(errors);
+}
@@ -0,0 +1,78 @@
+use choreo_components::choreography_settings::actions::ChoreographySettingsAction;
+use choreo_components::choreography_settings::state::SelectedSceneState;
+use choreo_components::floor::state::InteractionMode as FloorInteractionMode;
+use choreo_master_mobile_json::Color;
+use choreo_master_mobile_json::FrontPosition;
+use choreo_master_mobile_json::SceneId;
+use choreo_models::ChoreographyModel;
+use choreo_models::SettingsModel;
+
+use crate::choreo_main::actions::ChoreoMainAction;
+use crate::choreo_main::assert_no_errors;
+use crate::choreo_main::build_dancer;
+use crate::choreo_main::dancer_position;
+use crate::choreo_main::reducer::reduce;
+use crate::choreo_main::scene_model;
+use crate::choreo_main::state::ChoreoMainState;
+
+fn load_state(front_position: FrontPosition) -> ChoreoMainState {
+    let scene = scene_model(
+        1,
+        "Opening",
+        None,
+        vec![
+            dancer_position(&build_dancer(1, "Dancer 1"), 0.0, 2.0),
+            dancer_position(&build_dancer(2, "Dancer 2"), 0.0, 0.0),
+        ],
+    );
+    let mut state = ChoreoMainState::default();
+    reduce(
+        &mut state,
+        ChoreoMainAction::ChoreographySettingsAction(
+            ChoreographySettingsAction::LoadChoreography {
+                choreography: Box::new(ChoreographyModel {
+                    name: "Sight lines".to_string(),
+                    settings: SettingsModel {
+                        front_position,
+                        ..SettingsModel::default()
+                    },
+                    scenes: vec![scene],
+                    ..ChoreographyModel::default()
+                }),
+                selected_scene: Some(SelectedSceneState {
+                    scene_id: SceneId(1),
+                    name: "Opening".to_string(),
+                    text: String::new(),
+                    fixed_positions: false,
+                    timestamp: None,
+                    color: Color::transparent(),
+                }),
+            },
+        ),
+    );
+    state
+}
+
+#[test]
+fn line_of_sight_mode_analyses_from_the_choreography_front() {
+    let mut errors = Vec::new();
+
+    let mut top = load_state(FrontPosition::Top);
+    check_eq!(errors, top.floor_state.sight_lines.hidden.len(), 0);
+    reduce(&mut top, ChoreoMainAction::SelectMode { index: 5 });
+    check_eq!(
+        errors,
+        top.floor_state.interaction_mode,
+        FloorInteractionMode::LineOfSight
+    );
+    check_eq!(errors, top.floor_state.sight_lines.hidden, vec![1]);
+
+    let mut bottom = load_state(FrontPosition::Bottom);
+    reduce(&mut bottom, ChoreoMainAction::SelectMode { index: 5 });
+    check_eq!(errors, bottom.floor_state.sight_lines.hidden, vec![0]);
+
+    reduce(&mut bottom, ChoreoMainAction::SelectMode { index: 0 });
+    check_eq!(errors, bottom.floor_state.sight_lines.hidden.len(), 0);
+
+    assert_no_errors(errors);
+}
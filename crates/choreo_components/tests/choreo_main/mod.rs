@@ -1,5 +1,8 @@
 use std::io;
+use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
 
 use rspec::ConfigurationBuilder;
 use rspec::Logger;
@@ -26,12 +29,70 @@ where
     runner.run(suite)
 }
 
+macro_rules! check_eq {
+    ($errors:expr, $left:expr, $right:expr) => {
+        if $left != $right {
+            $errors.push(format!(
+                "{} != {} (left = {:?}, right = {:?})",
+                stringify!($left),
+                stringify!($right),
+                $left,
+                $right
+            ));
+        }
+    };
+}
+
+macro_rules! check {
+    ($errors:expr, $condition:expr) => {
+        let passed: bool = $condition;
+        if !passed {
+            $errors.push(format!("condition failed: {}", stringify!($condition)));
+        }
+    };
+}
+
+pub fn assert_no_errors(errors: Vec<String>) {
+    assert!(
+        errors.is_empty(),
+        "Assertion failures:\n{}",
+        errors.join("\n")
+    );
+}
+
+/// A path in the temp directory that no other spec run uses, named after `name`.
+pub fn unique_temp_path(name: &str) -> PathBuf {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system time should be after unix epoch")
+        .as_nanos();
+    std::env::temp_dir().join(format!("rchoreo_{name}_{nanos}"))
+}
+
+#[path = "../shared/models.rs"]
+mod models;
+
+pub use models::build_dancer;
+pub use models::build_position;
+pub use models::dancer_position;
+pub use models::scene_model;
+
 pub mod apply_interaction_mode_behavior_spec;
+pub mod auto_assign_dancers_spec;
+pub mod autosave_spec;
+pub mod bend_colliding_paths_spec;
+pub mod dancer_orientation_spec;
 pub mod dialog_host_ui_spec;
 pub mod external_file_routing_spec;
+pub mod floor_export_spec;
 pub mod floor_projection_spec;
+pub mod formation_templates_spec;
 pub mod hide_dialog_behavior_spec;
+pub mod insert_in_between_scene_spec;
+pub mod keyboard_shortcuts_spec;
+pub mod line_of_sight_spec;
 pub mod main_page_host_forwarding_spec;
+pub mod movement_waypoints_spec;
 pub mod navigate_dancers_to_main_spec;
 pub mod navigate_main_to_dancers_spec;
 pub mod navigate_main_to_settings_spec;
@@ -40,7 +101,15 @@ pub mod non_ui_behavior_parity_spec;
 pub mod open_audio_behavior_spec;
 pub mod open_image_behavior_spec;
 pub mod open_svg_file_behavior_spec;
+pub mod path_bending_spec;
+pub mod rehearsal_loop_spec;
+pub mod required_role_spec;
+pub mod scene_arrangement_spec;
+pub mod scene_variations_spec;
 pub mod show_dialog_behavior_spec;
 pub mod startup_open_choreo_behavior_spec;
 pub mod timestamp_sync_spec;
 pub mod ui_main_page_spec;
+pub mod undo_redo_spec;
+pub mod unsaved_changes_spec;
+pub mod waveform_marker_spec;
@@ -0,0 +1,144 @@
+use choreo_components::choreography_settings::actions::ChoreographySettingsAction;
+use choreo_components::choreography_settings::state::SelectedSceneState;
+use choreo_components::floor::actions::FloorAction;
+use choreo_components::floor::state::InteractionMode as FloorInteractionMode;
+use choreo_components::floor::state::PathWaypoint;
+use choreo_components::floor::state::Point;
+use choreo_master_mobile_json::Color;
+use choreo_master_mobile_json::SceneId;
+use choreo_models::ChoreographyModel;
+use choreo_models::PositionModel;
+
+use crate::choreo_main::actions::ChoreoMainAction;
+use crate::choreo_main::assert_no_errors;
+use crate::choreo_main::build_dancer;
+use crate::choreo_main::dancer_position;
+use crate::choreo_main::reducer::reduce;
+use crate::choreo_main::scene_model;
+use crate::choreo_main::state::ChoreoMainState;
+use crate::choreo_main::state::InteractionMode;
+
+fn is_near(actual: f64, expected: f64) -> bool {
+    (actual - expected).abs() < 0.001
+}
+
+fn load_state(movement1: Option<(f64, f64)>, movement2: Option<(f64, f64)>) -> ChoreoMainState {
+    let dancer = build_dancer(1, "Alex");
+    let waypoints = PositionModel {
+        movement1_x: movement1.map(|(x, _)| x),
+        movement1_y: movement1.map(|(_, y)| y),
+        movement2_x: movement2.map(|(x, _)| x),
+        movement2_y: movement2.map(|(_, y)| y),
+        ..dancer_position(&dancer, 0.0, 0.0)
+    };
+    let scenes = vec![
+        scene_model(1, "Scene 1", Some("0"), vec![waypoints]),
+        scene_model(
+            2,
+            "Scene 2",
+            Some("10"),
+            vec![dancer_position(&dancer, 4.0, 0.0)],
+        ),
+    ];
+    let mut state = ChoreoMainState::default();
+    reduce(
+        &mut state,
+        ChoreoMainAction::ChoreographySettingsAction(
+            ChoreographySettingsAction::LoadChoreography {
+                choreography: Box::new(ChoreographyModel {
+                    name: "Waypoints".to_string(),
+                    scenes,
+                    ..ChoreographyModel::default()
+                }),
+                selected_scene: Some(SelectedSceneState {
+                    scene_id: SceneId(1),
+                    name: "Scene 1".to_string(),
+                    text: String::new(),
+                    fixed_positions: false,
+                    timestamp: Some(0.0),
+                    color: Color::transparent(),
+                }),
+            },
+        ),
+    );
+    state
+}
+
+#[test]
+fn playback_follows_the_waypoints_at_an_even_pace() {
+    // Legs of 3, 4 and 3 metres over ten seconds.
+    let mut state = load_state(Some((0.0, 3.0)), Some((4.0, 3.0)));
+
+    let mut errors = Vec::new();
+
+    check_eq!(errors, state.floor_state.colored_path_segments.len(), 3);
+
+    for (seconds, expected_x, expected_y) in [(3.0, 0.0, 3.0), (5.0, 2.0, 3.0), (8.5, 4.0, 1.5)] {
+        reduce(
+            &mut state,
+            ChoreoMainAction::UpdateAudioPosition { seconds },
+        );
+        let position = state.floor_state.interpolated_positions[0];
+        check!(
+            errors,
+            is_near(position.x, expected_x) && is_near(position.y, expected_y)
+        );
+    }
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn edit_path_mode_writes_dragged_waypoints_to_the_scene_as_one_undo_step() {
+    let mut state = load_state(None, None);
+
+    reduce(&mut state, ChoreoMainAction::SelectMode { index: 7 });
+
+    let mut errors = Vec::new();
+
+    check_eq!(errors, state.interaction_mode, InteractionMode::EditPath);
+    check_eq!(
+        errors,
+        state.floor_state.interaction_mode,
+        FloorInteractionMode::EditPath
+    );
+    check_eq!(errors, state.floor_state.path_handles.len(), 2);
+
+    reduce(
+        &mut state,
+        ChoreoMainAction::FloorAction(FloorAction::PointerPressed {
+            point: Point::new(0.0, 0.0),
+        }),
+    );
+    for point in [Point::new(1.0, 1.0), Point::new(2.0, 3.0)] {
+        reduce(
+            &mut state,
+            ChoreoMainAction::FloorAction(FloorAction::MovePathWaypoint {
+                position: 0,
+                waypoint: PathWaypoint::First,
+                point,
+            }),
+        );
+    }
+    reduce(
+        &mut state,
+        ChoreoMainAction::FloorAction(FloorAction::PointerReleased {
+            point: Point::new(0.0, 0.0),
+        }),
+    );
+
+    let scene_position = &state.choreography_settings_state.choreography.scenes[0].positions[0];
+    check_eq!(errors, scene_position.movement1_x, Some(2.0));
+    check_eq!(errors, scene_position.movement1_y, Some(3.0));
+    check_eq!(errors, scene_position.movement2_x, None::<f64>);
+    check!(errors, state.floor_state.path_handles[0].is_set);
+    check_eq!(errors, state.floor_state.colored_path_segments.len(), 2);
+
+    reduce(&mut state, ChoreoMainAction::Undo);
+
+    let scene_position = &state.choreography_settings_state.choreography.scenes[0].positions[0];
+    check_eq!(errors, scene_position.movement1_x, None::<f64>);
+    check!(errors, !state.history.can_undo());
+
+    assert_no_errors(errors);
+}
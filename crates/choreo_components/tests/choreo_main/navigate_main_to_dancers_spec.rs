@@ -46,7 +46,7 @@ fn navigate_main_to_dancers_spec() {
             |_| {
                 let mut state = ChoreoMainState::default();
                 let mut errors = Vec::new();
-                let initial_content = state.content.clone();
+                let initial_content = state.content;
 
                 check_eq!(errors, initial_content, MainContent::Main);
                 reduce(&mut state, ChoreoMainAction::NavigateToDancers);
@@ -152,6 +152,7 @@ fn state_with_loaded_choreography() -> ChoreoMainState {
         positions: vec![
             PositionModel {
                 dancer: Some(lead.clone()),
+                required_role: None,
                 orientation: None,
                 x: 0.0,
                 y: 0.0,
@@ -166,6 +167,7 @@ fn state_with_loaded_choreography() -> ChoreoMainState {
             },
             PositionModel {
                 dancer: Some(follow.clone()),
+                required_role: None,
                 orientation: None,
                 x: 1.0,
                 y: 0.0,
@@ -11,6 +11,9 @@ use choreo_components::choreo_main::OpenAudioRequested;
 use choreo_components::choreo_main::OpenImageRequested;
 use choreo_components::choreo_main::OpenSvgFileCommand;
 use choreo_components::choreo_main::actions::ChoreoMainAction;
+use choreo_components::floor::actions::FloorAction;
+use choreo_components::floor::state::CurveControl;
+use choreo_components::floor::state::Point;
 use choreo_components::global::GlobalStateActor;
 use choreo_components::global::GlobalStateModel;
 use choreo_components::preferences::InMemoryPreferences;
@@ -224,6 +227,77 @@ fn non_ui_behavior_parity_spec() {
                 );
             },
         );
+
+        spec.it(
+            "enters and leaves the bend-path states through the state machine",
+            |_| {
+                let global_state_store = GlobalStateActor::new();
+                let state_machine = Rc::new(RefCell::new(
+                    ApplicationStateMachine::with_default_transitions(Box::new(
+                        GlobalStateModel::default(),
+                    )),
+                ));
+                let binding = MainPageBinding::new(MainPageDependencies {
+                    behavior_dependencies: ChoreoMainBehaviorDependencies {
+                        global_state_store: Some(global_state_store),
+                        state_machine: Some(Rc::clone(&state_machine)),
+                        ..ChoreoMainBehaviorDependencies::default()
+                    },
+                    ..MainPageDependencies::default()
+                });
+
+                let mut errors = Vec::new();
+
+                binding.dispatch(ChoreoMainAction::SelectMode { index: 1 });
+                binding.dispatch(ChoreoMainAction::SelectMode { index: 8 });
+                check_eq!(
+                    errors,
+                    state_machine.borrow().state().kind(),
+                    StateKind::BendPathState
+                );
+
+                binding.dispatch(ChoreoMainAction::FloorAction(
+                    FloorAction::MoveCurveControl {
+                        position: 0,
+                        control: CurveControl::First,
+                        point: Point::new(1.0, 0.0),
+                    },
+                ));
+                check_eq!(
+                    errors,
+                    state_machine.borrow().state().kind(),
+                    StateKind::BendPathDragState
+                );
+
+                binding.dispatch(ChoreoMainAction::FloorAction(
+                    FloorAction::PointerReleased {
+                        point: Point::new(1.0, 0.0),
+                    },
+                ));
+                check_eq!(
+                    errors,
+                    state_machine.borrow().state().kind(),
+                    StateKind::BendPathState
+                );
+
+                binding.dispatch(ChoreoMainAction::SelectMode { index: 4 });
+                check_eq!(
+                    errors,
+                    state_machine.borrow().state().kind(),
+                    StateKind::ScalePositionsState
+                );
+
+                binding.dispatch(ChoreoMainAction::SelectMode { index: 8 });
+                binding.dispatch(ChoreoMainAction::SelectMode { index: 0 });
+                check_eq!(
+                    errors,
+                    state_machine.borrow().state().kind(),
+                    StateKind::ViewSceneState
+                );
+
+                assert_no_errors(errors);
+            },
+        );
     });
 
     let report = crate::choreo_main::run_suite(&suite);
@@ -0,0 +1,149 @@
+use choreo_components::choreography_settings::actions::ChoreographySettingsAction;
+use choreo_components::choreography_settings::state::SelectedSceneState;
+use choreo_components::floor::actions::FloorAction;
+use choreo_components::floor::state::CurveControl;
+use choreo_components::floor::state::InteractionMode as FloorInteractionMode;
+use choreo_components::floor::state::Point;
+use choreo_master_mobile_json::Color;
+use choreo_master_mobile_json::SceneId;
+use choreo_master_mobile_json::export;
+use choreo_master_mobile_json::import;
+use choreo_models::ChoreographyModel;
+use choreo_models::ChoreographyModelMapper;
+
+use crate::choreo_main::actions::ChoreoMainAction;
+use crate::choreo_main::assert_no_errors;
+use crate::choreo_main::build_dancer;
+use crate::choreo_main::dancer_position;
+use crate::choreo_main::reducer::reduce;
+use crate::choreo_main::scene_model;
+use crate::choreo_main::state::ChoreoMainState;
+use crate::choreo_main::state::InteractionMode;
+
+/// Two scenes with the second one selected, so the path to bend comes from the first.
+fn load_state() -> ChoreoMainState {
+    let dancer = build_dancer(1, "Alex");
+    let scenes = vec![
+        scene_model(
+            1,
+            "Scene 1",
+            None,
+            vec![dancer_position(&dancer, -3.0, 0.0)],
+        ),
+        scene_model(2, "Scene 2", None, vec![dancer_position(&dancer, 3.0, 0.0)]),
+    ];
+    let mut state = ChoreoMainState::default();
+    reduce(
+        &mut state,
+        ChoreoMainAction::ChoreographySettingsAction(
+            ChoreographySettingsAction::LoadChoreography {
+                choreography: Box::new(ChoreographyModel {
+                    name: "Curves".to_string(),
+                    scenes,
+                    ..ChoreographyModel::default()
+                }),
+                selected_scene: Some(SelectedSceneState {
+                    scene_id: SceneId(2),
+                    name: "Scene 2".to_string(),
+                    text: String::new(),
+                    fixed_positions: false,
+                    timestamp: None,
+                    color: Color::transparent(),
+                }),
+            },
+        ),
+    );
+    state
+}
+
+fn drag_first_control(state: &mut ChoreoMainState, points: &[Point]) {
+    reduce(
+        state,
+        ChoreoMainAction::FloorAction(FloorAction::PointerPressed {
+            point: Point::new(0.0, 0.0),
+        }),
+    );
+    for point in points {
+        reduce(
+            state,
+            ChoreoMainAction::FloorAction(FloorAction::MoveCurveControl {
+                position: 0,
+                control: CurveControl::First,
+                point: *point,
+            }),
+        );
+    }
+    reduce(
+        state,
+        ChoreoMainAction::FloorAction(FloorAction::PointerReleased {
+            point: Point::new(0.0, 0.0),
+        }),
+    );
+}
+
+#[test]
+fn bend_path_mode_writes_the_curve_to_the_previous_scene_as_one_undo_step() {
+    let mut state = load_state();
+
+    reduce(&mut state, ChoreoMainAction::SelectMode { index: 8 });
+
+    let mut errors = Vec::new();
+
+    check_eq!(errors, state.interaction_mode, InteractionMode::BendPath);
+    check_eq!(
+        errors,
+        state.floor_state.interaction_mode,
+        FloorInteractionMode::BendPath
+    );
+    check_eq!(errors, state.floor_state.curve_handles.len(), 2);
+
+    drag_first_control(&mut state, &[Point::new(-1.0, 1.0), Point::new(-2.0, 3.0)]);
+
+    let scenes = &state.choreography_settings_state.choreography.scenes;
+    check_eq!(errors, scenes[0].positions[0].curve1_x, Some(-2.0));
+    check_eq!(errors, scenes[0].positions[0].curve1_y, Some(3.0));
+    check_eq!(errors, scenes[0].positions[0].curve2_x, Some(1.0));
+    check_eq!(errors, scenes[0].positions[0].curve2_y, Some(0.0));
+    check_eq!(errors, scenes[1].positions[0].curve1_x, None::<f64>);
+    check!(
+        errors,
+        state
+            .floor_state
+            .curve_handles
+            .iter()
+            .all(|handle| handle.is_set)
+    );
+
+    reduce(&mut state, ChoreoMainAction::Undo);
+
+    let scenes = &state.choreography_settings_state.choreography.scenes;
+    check_eq!(errors, scenes[0].positions[0].curve1_x, None::<f64>);
+    check_eq!(errors, scenes[0].positions[0].curve2_x, None::<f64>);
+    check!(errors, !state.history.can_undo());
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn bent_paths_round_trip_through_the_json_export() {
+    let mut state = load_state();
+    reduce(&mut state, ChoreoMainAction::SelectMode { index: 8 });
+    drag_first_control(&mut state, &[Point::new(-2.0, 3.0)]);
+
+    let mapper = ChoreographyModelMapper;
+    let json = export(&mapper.map_to_json(&state.choreography_settings_state.choreography))
+        .expect("bent choreography should serialize to json");
+    let reloaded = mapper.map_to_model(&import(&json).expect("exported json should import"));
+
+    let mut errors = Vec::new();
+
+    let original = &state.choreography_settings_state.choreography.scenes[0].positions[0];
+    let restored = &reloaded.scenes[0].positions[0];
+    check_eq!(errors, restored.curve1_x, original.curve1_x);
+    check_eq!(errors, restored.curve1_y, original.curve1_y);
+    check_eq!(errors, restored.curve2_x, original.curve2_x);
+    check_eq!(errors, restored.curve2_y, original.curve2_y);
+    check_eq!(errors, restored.curve1_x, Some(-2.0));
+
+    assert_no_errors(errors);
+}
@@ -0,0 +1,266 @@
+use crate::choreo_main::Report;
+use crate::choreo_main::actions::ChoreoMainAction;
+use crate::choreo_main::assert_no_errors;
+use crate::choreo_main::reducer::reduce;
+use crate::choreo_main::state::ChoreoMainState;
+use crate::choreo_main::state::SceneState;
+use choreo_components::audio_player::actions::AudioPlayerAction;
+use choreo_components::audio_player::reducer::AudioPlayerEffect;
+use choreo_components::audio_player::reducer::reduce as reduce_audio;
+use choreo_components::audio_player::state::AudioPlayerState;
+use choreo_components::audio_player::state::RehearsalPhase;
+use choreo_components::choreo_main::translations::rehearsal_status_text;
+
+fn audio_state() -> AudioPlayerState {
+    AudioPlayerState {
+        has_player: true,
+        duration: 120.0,
+        preparation_seconds: 4.0,
+        pause_seconds: 2.0,
+        ..AudioPlayerState::default()
+    }
+}
+
+fn main_state() -> ChoreoMainState {
+    let mut state = ChoreoMainState {
+        audio_player_state: audio_state(),
+        ..ChoreoMainState::default()
+    };
+    reduce(
+        &mut state,
+        ChoreoMainAction::SetScenes {
+            scenes: vec![
+                SceneState {
+                    name: "Intro".to_string(),
+                    timestamp_seconds: Some(5.0),
+                },
+                SceneState {
+                    name: "Verse".to_string(),
+                    timestamp_seconds: Some(20.0),
+                },
+                SceneState {
+                    name: "Bridge".to_string(),
+                    timestamp_seconds: None,
+                },
+            ],
+        },
+    );
+    state
+}
+
+#[test]
+fn rehearsal_loop_spec() {
+    let suite = rspec::describe("rehearsal loop", (), |spec| {
+        spec.it(
+            "counts in, plays to the end scene, pauses and starts over",
+            |_| {
+                let mut state = audio_state();
+                let mut errors = Vec::new();
+
+                reduce_audio(
+                    &mut state,
+                    AudioPlayerAction::StartRehearsalLoop {
+                        start_seconds: 10.0,
+                        end_seconds: 30.0,
+                    },
+                );
+                let rehearsal = state.rehearsal_loop.expect("loop should start");
+                check_eq!(
+                    errors,
+                    rehearsal.phase,
+                    RehearsalPhase::CountIn {
+                        remaining_seconds: 4.0
+                    }
+                );
+                check_eq!(errors, state.position, 10.0);
+                check!(errors, !state.is_playing);
+
+                let effects = reduce_audio(
+                    &mut state,
+                    AudioPlayerAction::RehearsalTick {
+                        elapsed_seconds: 4.0,
+                    },
+                );
+                check_eq!(
+                    errors,
+                    effects,
+                    vec![AudioPlayerEffect::TransportRequested {
+                        position_seconds: 10.0,
+                        play: true,
+                    }]
+                );
+                check!(errors, state.is_playing);
+
+                reduce_audio(
+                    &mut state,
+                    AudioPlayerAction::PlayerPositionSampled { position: 10.0 },
+                );
+                reduce_audio(
+                    &mut state,
+                    AudioPlayerAction::PlayerPositionSampled { position: 30.2 },
+                );
+                let effects = reduce_audio(
+                    &mut state,
+                    AudioPlayerAction::RehearsalTick {
+                        elapsed_seconds: 20.2,
+                    },
+                );
+                check_eq!(
+                    errors,
+                    effects,
+                    vec![AudioPlayerEffect::TransportRequested {
+                        position_seconds: 30.0,
+                        play: false,
+                    }]
+                );
+                check_eq!(errors, state.position, 30.0);
+                check!(errors, !state.is_playing);
+                check_eq!(
+                    errors,
+                    state.rehearsal_loop.map(|rehearsal| rehearsal.phase),
+                    Some(RehearsalPhase::Pause {
+                        remaining_seconds: 2.0
+                    })
+                );
+
+                let effects = reduce_audio(
+                    &mut state,
+                    AudioPlayerAction::RehearsalTick {
+                        elapsed_seconds: 2.0,
+                    },
+                );
+                check_eq!(
+                    errors,
+                    effects,
+                    vec![AudioPlayerEffect::TransportRequested {
+                        position_seconds: 10.0,
+                        play: false,
+                    }]
+                );
+                let rehearsal = state.rehearsal_loop.expect("loop should keep running");
+                check_eq!(errors, rehearsal.repetition, 2);
+                check_eq!(
+                    errors,
+                    rehearsal.phase,
+                    RehearsalPhase::CountIn {
+                        remaining_seconds: 4.0
+                    }
+                );
+                check_eq!(errors, state.position, 10.0);
+
+                assert_no_errors(errors);
+            },
+        );
+
+        spec.it("ignores a loop whose end is not after its start", |_| {
+            let mut state = audio_state();
+
+            reduce_audio(
+                &mut state,
+                AudioPlayerAction::StartRehearsalLoop {
+                    start_seconds: 30.0,
+                    end_seconds: 30.0,
+                },
+            );
+
+            assert!(state.rehearsal_loop.is_none());
+        });
+
+        spec.it("cancels the loop on manual play or pause", |_| {
+            let mut state = audio_state();
+            reduce_audio(
+                &mut state,
+                AudioPlayerAction::StartRehearsalLoop {
+                    start_seconds: 10.0,
+                    end_seconds: 30.0,
+                },
+            );
+
+            reduce_audio(&mut state, AudioPlayerAction::TogglePlayPause);
+
+            assert!(state.rehearsal_loop.is_none());
+        });
+
+        spec.it("clamps the count-in and pause lengths", |_| {
+            let mut state = audio_state();
+            let mut errors = Vec::new();
+
+            reduce_audio(
+                &mut state,
+                AudioPlayerAction::UpdatePreparationSeconds { seconds: -3.0 },
+            );
+            reduce_audio(
+                &mut state,
+                AudioPlayerAction::UpdatePauseSeconds { seconds: 600.0 },
+            );
+
+            check_eq!(errors, state.preparation_seconds, 0.0);
+            check_eq!(errors, state.pause_seconds, 60.0);
+
+            assert_no_errors(errors);
+        });
+
+        spec.it("loops between the timestamps of the chosen scenes", |_| {
+            let mut state = main_state();
+            let mut errors = Vec::new();
+
+            reduce(
+                &mut state,
+                ChoreoMainAction::StartRehearsalLoop {
+                    start_scene_index: 0,
+                    end_scene_index: 1,
+                },
+            );
+
+            let rehearsal = state
+                .audio_player_state
+                .rehearsal_loop
+                .expect("loop should start");
+            check_eq!(errors, rehearsal.start_seconds, 5.0);
+            check_eq!(errors, rehearsal.end_seconds, 20.0);
+            check_eq!(errors, state.audio_position_seconds, 5.0);
+            check_eq!(errors, state.selected_scene_index, Some(0));
+            check_eq!(
+                errors,
+                rehearsal_status_text("en", &rehearsal).as_deref(),
+                Some("Get ready… 4")
+            );
+
+            assert_no_errors(errors);
+        });
+
+        spec.it("needs timestamps on both scenes", |_| {
+            let mut state = main_state();
+
+            reduce(
+                &mut state,
+                ChoreoMainAction::StartRehearsalLoop {
+                    start_scene_index: 1,
+                    end_scene_index: 2,
+                },
+            );
+
+            assert!(state.audio_player_state.rehearsal_loop.is_none());
+        });
+
+        spec.it(
+            "preselects the current and next scene when the panel opens",
+            |_| {
+                let mut state = main_state();
+                let mut errors = Vec::new();
+                reduce(&mut state, ChoreoMainAction::SelectScene { index: 1 });
+
+                reduce(&mut state, ChoreoMainAction::ToggleRehearsalPanel);
+
+                check!(errors, state.is_rehearsal_panel_open);
+                check_eq!(errors, state.rehearsal_start_scene_index, Some(1));
+                check_eq!(errors, state.rehearsal_end_scene_index, Some(2));
+
+                assert_no_errors(errors);
+            },
+        );
+    });
+
+    let report = crate::choreo_main::run_suite(&suite);
+    assert!(report.is_success());
+}
@@ -0,0 +1,169 @@
+use std::rc::Rc;
+
+use choreo_components::choreography_settings::actions::ChoreographySettingsAction;
+use choreo_components::choreography_settings::state::SelectedSceneState;
+use choreo_master_mobile_json::Color;
+use choreo_models::ChoreographyModel;
+use choreo_models::PositionModel;
+use choreo_models::RoleModel;
+use choreo_models::SceneModel;
+
+use crate::choreo_main::Report;
+use crate::choreo_main::actions::ChoreoMainAction;
+use crate::choreo_main::assert_no_errors;
+use crate::choreo_main::build_dancer;
+use crate::choreo_main::build_position;
+use crate::choreo_main::dancer_position;
+use crate::choreo_main::reducer::reduce;
+use crate::choreo_main::scene_model;
+use crate::choreo_main::state::ChoreoMainState;
+
+fn role(name: &str, color: Color) -> Rc<RoleModel> {
+    Rc::new(RoleModel {
+        z_index: 0,
+        name: name.to_string(),
+        color,
+    })
+}
+
+fn load_state(
+    roles: Vec<Rc<RoleModel>>,
+    scenes: Vec<SceneModel>,
+    selected: usize,
+) -> ChoreoMainState {
+    let selected_scene = SelectedSceneState {
+        scene_id: scenes[selected].scene_id,
+        name: scenes[selected].name.clone(),
+        text: String::new(),
+        fixed_positions: false,
+        timestamp: None,
+        color: Color::transparent(),
+    };
+    let mut state = ChoreoMainState::default();
+    reduce(
+        &mut state,
+        ChoreoMainAction::ChoreographySettingsAction(
+            ChoreographySettingsAction::LoadChoreography {
+                choreography: Box::new(ChoreographyModel {
+                    name: "Required Roles".to_string(),
+                    roles,
+                    scenes,
+                    ..ChoreographyModel::default()
+                }),
+                selected_scene: Some(selected_scene),
+            },
+        ),
+    );
+    state
+}
+
+#[test]
+fn required_role_spec() {
+    let suite =
+        rspec::describe("required position roles in main reducer", (), |spec| {
+            spec.it(
+                "tags positions of the selected scene and colors their floor spots",
+                |_| {
+                    let red = Color {
+                        a: 255,
+                        r: 200,
+                        g: 10,
+                        b: 20,
+                    };
+                    let lead = role("Lead", red);
+                    let mut state = load_state(
+                        vec![lead.clone()],
+                        vec![scene_model(
+                            1,
+                            "Opening",
+                            None,
+                            vec![build_position(0.0, 0.0), build_position(1.0, 0.0)],
+                        )],
+                        0,
+                    );
+                    let draw_count = state.draw_floor_request_count;
+
+                    reduce(
+                        &mut state,
+                        ChoreoMainAction::SetRequiredRole {
+                            position_indices: vec![1],
+                            role_index: Some(0),
+                        },
+                    );
+
+                    let mut errors = Vec::new();
+                    let positions =
+                        &state.choreography_settings_state.choreography.scenes[0].positions;
+                    check_eq!(errors, positions[0].required_role.is_none(), true);
+                    check_eq!(
+                        errors,
+                        positions[1]
+                            .required_role
+                            .as_ref()
+                            .is_some_and(|required| Rc::ptr_eq(required, &lead)),
+                        true
+                    );
+                    check_eq!(
+                        errors,
+                        state.scene_models[0].positions[1].required_role.is_some(),
+                        true
+                    );
+                    check_eq!(
+                        errors,
+                        state.floor_state.source_positions[0].border_color,
+                        [120, 120, 120, 255]
+                    );
+                    check_eq!(
+                        errors,
+                        state.floor_state.source_positions[1].border_color,
+                        [200, 10, 20, 255]
+                    );
+                    check_eq!(errors, state.draw_floor_request_count, draw_count + 1);
+                    assert_no_errors(errors);
+                },
+            );
+
+            spec.it(
+            "shows a dialog when no assignment satisfies the required roles",
+            |_| {
+                let lead = role("Lead", Color::transparent());
+                let follow = role("Follow", Color::transparent());
+                let mut a = (*build_dancer(1, "A")).clone();
+                a.role = lead.clone();
+                let a = Rc::new(a);
+                let mut state = load_state(
+                    vec![lead.clone(), follow.clone()],
+                    vec![
+                        scene_model(1, "Opening", None, vec![dancer_position(&a, 0.0, 0.0)]),
+                        scene_model(2, "Travel", None, vec![PositionModel {
+                            required_role: Some(follow.clone()),
+                            ..build_position(1.0, 0.0)
+                        }]),
+                    ],
+                    1,
+                );
+
+                reduce(&mut state, ChoreoMainAction::AutoAssignDancers);
+
+                let mut errors = Vec::new();
+                check_eq!(errors, state.is_dialog_open, true);
+                check_eq!(
+                    errors,
+                    state.dialog_content.as_deref(),
+                    Some("No assignment places every dancer on a position that allows their role.")
+                );
+                check_eq!(
+                    errors,
+                    state.choreography_settings_state.choreography.scenes[1].positions[0]
+                        .dancer
+                        .is_none(),
+                    true
+                );
+                assert_no_errors(errors);
+            },
+        );
+        });
+
+    let report = crate::choreo_main::run_suite(&suite);
+    assert!(report.is_success());
+}
@@ -0,0 +1,267 @@
+use std::rc::Rc;
+
+use choreo_components::choreography_settings::actions::ChoreographySettingsAction;
+use choreo_components::choreography_settings::state::SelectedSceneState;
+use choreo_components::main_page::ui::scene_pane_state;
+use choreo_master_mobile_json::Color;
+use choreo_master_mobile_json::SceneId;
+use choreo_models::ChoreographyModel;
+
+use crate::choreo_main::actions::ChoreoMainAction;
+use crate::choreo_main::assert_no_errors;
+use crate::choreo_main::build_dancer;
+use crate::choreo_main::dancer_position;
+use crate::choreo_main::reducer::reduce;
+use crate::choreo_main::scene_model;
+use crate::choreo_main::state::ChoreoMainState;
+
+/// Three scenes with the middle one selected; the last one has no timestamp.
+fn load_state() -> ChoreoMainState {
+    let dancer = build_dancer(1, "Alex");
+    let scenes = vec![
+        scene_model(
+            1,
+            "Scene 1",
+            Some("2"),
+            vec![dancer_position(&dancer, -3.0, 0.0)],
+        ),
+        scene_model(
+            2,
+            "Scene 2",
+            Some("6"),
+            vec![dancer_position(&dancer, 0.0, 0.0)],
+        ),
+        scene_model(3, "Scene 3", None, vec![dancer_position(&dancer, 3.0, 0.0)]),
+    ];
+    let mut state = ChoreoMainState::default();
+    reduce(
+        &mut state,
+        ChoreoMainAction::ChoreographySettingsAction(
+            ChoreographySettingsAction::LoadChoreography {
+                choreography: Box::new(ChoreographyModel {
+                    name: "Arrangement".to_string(),
+                    scenes,
+                    ..ChoreographyModel::default()
+                }),
+                selected_scene: Some(SelectedSceneState {
+                    scene_id: SceneId(2),
+                    name: "Scene 2".to_string(),
+                    text: String::new(),
+                    fixed_positions: false,
+                    timestamp: Some(6.0),
+                    color: Color::transparent(),
+                }),
+            },
+        ),
+    );
+    state
+}
+
+fn scene_names(state: &ChoreoMainState) -> Vec<String> {
+    state
+        .choreography_settings_state
+        .choreography
+        .scenes
+        .iter()
+        .map(|scene| scene.name.clone())
+        .collect()
+}
+
+fn scene_xs(state: &ChoreoMainState) -> Vec<f64> {
+    state
+        .choreography_settings_state
+        .choreography
+        .scenes
+        .iter()
+        .map(|scene| scene.positions[0].x)
+        .collect()
+}
+
+fn scene_timestamps(state: &ChoreoMainState) -> Vec<Option<String>> {
+    state
+        .choreography_settings_state
+        .choreography
+        .scenes
+        .iter()
+        .map(|scene| scene.timestamp.clone())
+        .collect()
+}
+
+#[test]
+fn dragging_a_scene_reorders_the_list_as_one_undo_step() {
+    let mut state = load_state();
+
+    reduce(&mut state, ChoreoMainAction::MoveScene { from: 0, to: 2 });
+
+    let mut errors = Vec::new();
+
+    check_eq!(
+        errors,
+        scene_names(&state),
+        vec!["Scene 2", "Scene 3", "Scene 1"]
+    );
+    check_eq!(errors, scene_xs(&state), vec![0.0, 3.0, -3.0]);
+    check_eq!(
+        errors,
+        state
+            .choreography_settings_state
+            .choreography
+            .scenes
+            .iter()
+            .map(|scene| scene.scene_id)
+            .collect::<Vec<_>>(),
+        vec![SceneId(1), SceneId(2), SceneId(3)]
+    );
+    check_eq!(errors, state.selected_scene_index, Some(2));
+    check_eq!(errors, state.scenes[2].name, "Scene 1");
+
+    reduce(&mut state, ChoreoMainAction::Undo);
+
+    check_eq!(
+        errors,
+        scene_names(&state),
+        vec!["Scene 1", "Scene 2", "Scene 3"]
+    );
+    check!(errors, !state.history.can_undo());
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn duplicating_a_scene_deep_clones_its_positions() {
+    let mut state = load_state();
+
+    reduce(&mut state, ChoreoMainAction::DuplicateScene);
+
+    let mut errors = Vec::new();
+
+    check_eq!(
+        errors,
+        scene_names(&state),
+        vec!["Scene 1", "Scene 2", "Scene 2 2", "Scene 3"]
+    );
+    check_eq!(errors, scene_xs(&state), vec![-3.0, 0.0, 0.0, 3.0]);
+    check_eq!(
+        errors,
+        scene_timestamps(&state),
+        vec![Some("2".to_string()), Some("6".to_string()), None, None]
+    );
+    let scenes = &state.choreography_settings_state.choreography.scenes;
+    check!(
+        errors,
+        !Rc::ptr_eq(
+            scenes[1].positions[0]
+                .dancer
+                .as_ref()
+                .expect("original has a dancer"),
+            scenes[2].positions[0]
+                .dancer
+                .as_ref()
+                .expect("copy has a dancer"),
+        )
+    );
+    check_eq!(errors, state.selected_scene_index, Some(2));
+    check!(errors, state.history.can_undo());
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn picked_scenes_are_deleted_together() {
+    let mut state = load_state();
+
+    reduce(
+        &mut state,
+        ChoreoMainAction::ToggleSceneSelection { index: 0 },
+    );
+
+    let mut errors = Vec::new();
+
+    check_eq!(errors, state.selected_scene_indices, vec![0, 1]);
+    check_eq!(errors, state.selected_scene_index, Some(0));
+    let pane_state = scene_pane_state(&state);
+    check_eq!(
+        errors,
+        pane_state
+            .scenes
+            .iter()
+            .map(|scene| scene.is_selected)
+            .collect::<Vec<_>>(),
+        vec![true, true, false]
+    );
+    check_eq!(
+        errors,
+        pane_state.selected_scene_ids,
+        vec![SceneId(1), SceneId(2)]
+    );
+
+    reduce(&mut state, ChoreoMainAction::DeleteSelectedScene);
+
+    check_eq!(errors, scene_names(&state), vec!["Scene 3"]);
+    check_eq!(errors, scene_xs(&state), vec![3.0]);
+    check_eq!(errors, state.selected_scene_index, Some(0));
+    check!(errors, state.selected_scene_indices.is_empty());
+
+    reduce(&mut state, ChoreoMainAction::Undo);
+
+    check_eq!(
+        errors,
+        scene_names(&state),
+        vec!["Scene 1", "Scene 2", "Scene 3"]
+    );
+    check_eq!(errors, scene_xs(&state), vec![-3.0, 0.0, 3.0]);
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn picked_scenes_shift_in_time_together() {
+    let mut state = load_state();
+    reduce(
+        &mut state,
+        ChoreoMainAction::ExtendSceneSelection { index: 0 },
+    );
+
+    reduce(
+        &mut state,
+        ChoreoMainAction::ShiftSceneTimestamps { seconds: 1.5 },
+    );
+
+    let mut errors = Vec::new();
+
+    check_eq!(
+        errors,
+        scene_timestamps(&state),
+        vec![Some("3.5".to_string()), Some("7.5".to_string()), None]
+    );
+    check_eq!(
+        errors,
+        state
+            .scenes
+            .iter()
+            .map(|scene| scene.timestamp_seconds)
+            .collect::<Vec<_>>(),
+        vec![Some(3.5), Some(7.5), None]
+    );
+    check_eq!(errors, state.selected_scene_indices, vec![0, 1]);
+    check_eq!(errors, state.selected_scene_index, Some(1));
+    check!(errors, scene_pane_state(&state).can_shift_scene_timestamps);
+
+    reduce(
+        &mut state,
+        ChoreoMainAction::ShiftSceneTimestamps { seconds: -5.0 },
+    );
+
+    check_eq!(
+        errors,
+        scene_timestamps(&state),
+        vec![Some("0".to_string()), Some("2.5".to_string()), None]
+    );
+
+    reduce(&mut state, ChoreoMainAction::SelectScene { index: 2 });
+
+    check!(errors, state.selected_scene_indices.is_empty());
+    check!(errors, !scene_pane_state(&state).can_shift_scene_timestamps);
+
+    assert_no_errors(errors);
+}
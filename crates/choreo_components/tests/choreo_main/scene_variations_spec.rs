@@ -0,0 +1,207 @@
+use choreo_components::choreography_settings::actions::ChoreographySettingsAction;
+use choreo_components::choreography_settings::state::SelectedSceneState;
+use choreo_components::main_page::ui::scene_pane_state;
+use choreo_master_mobile_json::Color;
+use choreo_master_mobile_json::SceneId;
+use choreo_master_mobile_json::export;
+use choreo_master_mobile_json::import;
+use choreo_models::ChoreographyModel;
+use choreo_models::ChoreographyModelMapper;
+use choreo_models::SceneModel;
+
+use crate::choreo_main::actions::ChoreoMainAction;
+use crate::choreo_main::assert_no_errors;
+use crate::choreo_main::build_dancer;
+use crate::choreo_main::dancer_position;
+use crate::choreo_main::reducer::reduce;
+use crate::choreo_main::scene_model;
+use crate::choreo_main::state::ChoreoMainState;
+
+/// Three scenes with the middle one selected.
+fn load_state() -> ChoreoMainState {
+    let dancer = build_dancer(1, "Alex");
+    let scenes = vec![
+        scene_model(
+            1,
+            "Scene 1",
+            None,
+            vec![dancer_position(&dancer, -3.0, 0.0)],
+        ),
+        scene_model(2, "Scene 2", None, vec![dancer_position(&dancer, 0.0, 0.0)]),
+        scene_model(3, "Scene 3", None, vec![dancer_position(&dancer, 3.0, 0.0)]),
+    ];
+    let mut state = ChoreoMainState::default();
+    reduce(
+        &mut state,
+        ChoreoMainAction::ChoreographySettingsAction(
+            ChoreographySettingsAction::LoadChoreography {
+                choreography: Box::new(ChoreographyModel {
+                    name: "Variations".to_string(),
+                    scenes,
+                    ..ChoreographyModel::default()
+                }),
+                selected_scene: Some(SelectedSceneState {
+                    scene_id: SceneId(2),
+                    name: "Scene 2".to_string(),
+                    text: String::new(),
+                    fixed_positions: false,
+                    timestamp: None,
+                    color: Color::transparent(),
+                }),
+            },
+        ),
+    );
+    state
+}
+
+fn scene_names(state: &ChoreoMainState) -> Vec<String> {
+    state
+        .choreography_settings_state
+        .choreography
+        .scenes
+        .iter()
+        .map(|scene| scene.name.clone())
+        .collect()
+}
+
+#[test]
+fn branching_switching_and_promoting_reshape_the_scene_list() {
+    let mut state = load_state();
+
+    reduce(&mut state, ChoreoMainAction::BranchSceneVariation);
+
+    let mut errors = Vec::new();
+
+    check_eq!(
+        errors,
+        scene_names(&state),
+        vec!["Scene 1", "Scene 2", "Scene 2 (2)", "Scene 3"]
+    );
+    check_eq!(errors, state.scenes.len(), 4);
+    check_eq!(errors, state.selected_scene_index, Some(2));
+    let scenes = &state.choreography_settings_state.choreography.scenes;
+    check_eq!(errors, scenes[2].variation_depth, 1);
+    check_eq!(errors, scenes[2].positions[0].x, 0.0);
+    check_eq!(errors, scenes[1].variations, vec![Vec::<SceneModel>::new()]);
+    let pane = scene_pane_state(&state);
+    check_eq!(
+        errors,
+        pane.scene_variations,
+        vec![Some("Scene 2 (2)".to_string()), None]
+    );
+    check!(errors, pane.can_promote_scene_variation);
+    check_eq!(errors, pane.visible_scenes[2].variation_depth, 1);
+
+    reduce(
+        &mut state,
+        ChoreoMainAction::SwitchSceneVariation { variation: 0 },
+    );
+
+    check_eq!(
+        errors,
+        scene_names(&state),
+        vec!["Scene 1", "Scene 2", "Scene 3"]
+    );
+    check_eq!(errors, state.selected_scene_index, Some(1));
+    let stashed = &state.choreography_settings_state.choreography.scenes[1].variations;
+    check_eq!(errors, stashed.len(), 1);
+    check_eq!(errors, stashed[0][0].name, "Scene 2 (2)");
+
+    reduce(
+        &mut state,
+        ChoreoMainAction::SwitchSceneVariation { variation: 0 },
+    );
+    reduce(&mut state, ChoreoMainAction::PromoteSceneVariation);
+
+    let scenes = &state.choreography_settings_state.choreography.scenes;
+    check_eq!(
+        errors,
+        scene_names(&state),
+        vec!["Scene 1", "Scene 2", "Scene 2 (2)", "Scene 3"]
+    );
+    check!(
+        errors,
+        scenes.iter().all(|scene| scene.variation_depth == 0)
+    );
+    check!(errors, scenes[1].variations.is_empty());
+    check!(errors, scene_pane_state(&state).scene_variations.is_empty());
+
+    reduce(&mut state, ChoreoMainAction::Undo);
+
+    let scenes = &state.choreography_settings_state.choreography.scenes;
+    check_eq!(errors, scenes[2].variation_depth, 1);
+    check_eq!(errors, scenes[1].variations.len(), 1);
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn comparing_a_variation_outlines_its_scene_on_the_floor() {
+    let mut state = load_state();
+    reduce(&mut state, ChoreoMainAction::BranchSceneVariation);
+
+    reduce(
+        &mut state,
+        ChoreoMainAction::CompareSceneVariation { variation: Some(0) },
+    );
+
+    let mut errors = Vec::new();
+
+    // The variation set aside is empty, so the branch compares with its anchor.
+    check_eq!(
+        errors,
+        state.floor_state.comparison_scene_name.as_deref(),
+        Some("Scene 2")
+    );
+    check_eq!(errors, state.floor_state.comparison_positions.len(), 1);
+
+    reduce(
+        &mut state,
+        ChoreoMainAction::SwitchSceneVariation { variation: 0 },
+    );
+
+    check_eq!(
+        errors,
+        state.floor_state.comparison_scene_name.as_deref(),
+        Some("Scene 2 (2)")
+    );
+    check_eq!(
+        errors,
+        scene_pane_state(&state).compared_scene_variation,
+        Some(0)
+    );
+
+    reduce(
+        &mut state,
+        ChoreoMainAction::CompareSceneVariation { variation: None },
+    );
+
+    check_eq!(
+        errors,
+        state.floor_state.comparison_scene_name,
+        None::<String>
+    );
+    check!(errors, state.floor_state.comparison_positions.is_empty());
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn variations_round_trip_through_the_json_export() {
+    let mut state = load_state();
+    reduce(&mut state, ChoreoMainAction::BranchSceneVariation);
+
+    let mapper = ChoreographyModelMapper;
+    let json = export(&mapper.map_to_json(&state.choreography_settings_state.choreography))
+        .expect("branched choreography should serialize to json");
+    let reloaded = mapper.map_to_model(&import(&json).expect("exported json should import"));
+
+    let mut errors = Vec::new();
+
+    check_eq!(errors, reloaded.scenes.len(), 4);
+    check_eq!(errors, reloaded.scenes[2].variation_depth, 1);
+    check_eq!(errors, reloaded.scenes[1].variations.len(), 1);
+    check!(errors, reloaded.scenes[1].variations[0].is_empty());
+
+    assert_no_errors(errors);
+}
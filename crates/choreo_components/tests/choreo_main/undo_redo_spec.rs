@@ -0,0 +1,357 @@
+use choreo_components::choreography_settings::actions::ChoreographySettingsAction;
+use choreo_components::choreography_settings::state::SelectedSceneState;
+use choreo_components::floor::actions::FloorAction;
+use choreo_components::floor::state::Alignment;
+use choreo_components::floor::state::CanvasViewHandle;
+use choreo_components::floor::state::MirrorAxis;
+use choreo_components::floor::state::Point;
+use choreo_components::floor::state::TouchAction;
+use choreo_components::floor::state::TouchDeviceType;
+use choreo_components::floor::state::TouchEventArgs;
+use choreo_master_mobile_json::Color;
+use choreo_models::ChoreographyModel;
+use choreo_models::SceneModel;
+
+use crate::choreo_main::Report;
+use crate::choreo_main::actions::ChoreoMainAction;
+use crate::choreo_main::assert_no_errors;
+use crate::choreo_main::build_position;
+use crate::choreo_main::reducer::reduce;
+use crate::choreo_main::scene_model;
+use crate::choreo_main::state::ChoreoMainState;
+
+fn load_choreography(state: &mut ChoreoMainState, scenes: Vec<SceneModel>) {
+    let selected_scene = SelectedSceneState {
+        scene_id: scenes[0].scene_id,
+        name: scenes[0].name.clone(),
+        text: String::new(),
+        fixed_positions: false,
+        timestamp: None,
+        color: Color::transparent(),
+    };
+    reduce(
+        state,
+        ChoreoMainAction::ChoreographySettingsAction(
+            ChoreographySettingsAction::LoadChoreography {
+                choreography: Box::new(ChoreographyModel {
+                    name: "Undo".to_string(),
+                    scenes,
+                    ..ChoreographyModel::default()
+                }),
+                selected_scene: Some(selected_scene),
+            },
+        ),
+    );
+}
+
+fn load_state(scenes: Vec<SceneModel>) -> ChoreoMainState {
+    let mut state = ChoreoMainState::default();
+    load_choreography(&mut state, scenes);
+    state
+}
+
+fn two_position_state() -> ChoreoMainState {
+    let mut state = load_state(vec![scene_model(
+        1,
+        "Opening",
+        None,
+        vec![build_position(0.0, 0.0), build_position(4.0, 0.0)],
+    )]);
+    reduce(
+        &mut state,
+        ChoreoMainAction::FloorAction(FloorAction::SelectRectangle {
+            start: Point::new(-1.0, -1.0),
+            end: Point::new(1.0, 1.0),
+        }),
+    );
+    state
+}
+
+fn move_selected(state: &mut ChoreoMainState, delta_x: f64) {
+    reduce(
+        state,
+        ChoreoMainAction::FloorAction(FloorAction::MoveSelectedByDelta {
+            delta_x,
+            delta_y: 0.0,
+        }),
+    );
+}
+
+fn model_x(state: &ChoreoMainState, index: usize) -> f64 {
+    state.choreography_settings_state.choreography.scenes[0].positions[index].x
+}
+
+fn shortcut_actions(modifiers: egui::Modifiers) -> Vec<ChoreoMainAction> {
+    let state = ChoreoMainState::default();
+    let context = egui::Context::default();
+    let mut actions = Vec::new();
+    let input = egui::RawInput {
+        modifiers,
+        events: vec![egui::Event::Key {
+            key: egui::Key::Z,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers,
+        }],
+        ..egui::RawInput::default()
+    };
+    let _ = context.run(input, |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            actions = crate::choreo_main::ui::draw(ui, &state);
+        });
+    });
+    actions
+        .into_iter()
+        .filter(|action| matches!(action, ChoreoMainAction::Undo | ChoreoMainAction::Redo))
+        .collect()
+}
+
+#[test]
+fn undo_redo_spec() {
+    let suite = rspec::describe("undo and redo in main reducer", (), |spec| {
+        spec.it(
+            "restores a deleted scene and deletes it again on redo",
+            |_| {
+                let mut state = load_state(vec![
+                    scene_model(1, "Opening", None, vec![build_position(0.0, 0.0)]),
+                    scene_model(2, "Finale", None, vec![build_position(2.0, 2.0)]),
+                ]);
+
+                reduce(&mut state, ChoreoMainAction::DeleteSelectedScene);
+                let mut errors = Vec::new();
+                check_eq!(errors, state.scenes.len(), 1);
+                check_eq!(errors, state.history.can_undo(), true);
+
+                reduce(&mut state, ChoreoMainAction::Undo);
+                check_eq!(errors, state.scenes.len(), 2);
+                check_eq!(errors, state.scenes[0].name, "Opening");
+                check_eq!(errors, state.selected_scene_index, Some(0));
+                check_eq!(
+                    errors,
+                    state.choreography_settings_state.choreography.scenes[1].positions[0].x,
+                    2.0
+                );
+                check_eq!(errors, state.history.can_undo(), false);
+                check_eq!(errors, state.history.can_redo(), true);
+
+                reduce(&mut state, ChoreoMainAction::Redo);
+                check_eq!(errors, state.scenes.len(), 1);
+                check_eq!(errors, state.history.can_undo(), true);
+                check_eq!(errors, state.history.can_redo(), false);
+                assert_no_errors(errors);
+            },
+        );
+
+        spec.it(
+            "writes floor moves into the scene and undoes a whole drag at once",
+            |_| {
+                let mut state = two_position_state();
+                let draw_count = state.draw_floor_request_count;
+
+                reduce(
+                    &mut state,
+                    ChoreoMainAction::FloorAction(FloorAction::PointerPressed {
+                        point: Point::new(0.0, 0.0),
+                    }),
+                );
+                move_selected(&mut state, 1.0);
+                move_selected(&mut state, 1.0);
+                move_selected(&mut state, 1.0);
+                reduce(
+                    &mut state,
+                    ChoreoMainAction::FloorAction(FloorAction::PointerReleased {
+                        point: Point::new(3.0, 0.0),
+                    }),
+                );
+
+                let mut errors = Vec::new();
+                check_eq!(errors, model_x(&state, 0), 3.0);
+                check_eq!(errors, model_x(&state, 1), 4.0);
+                check_eq!(errors, state.scene_models[0].positions[0].x, 3.0);
+                check_eq!(errors, state.history.undo_count(), 1);
+
+                reduce(&mut state, ChoreoMainAction::Undo);
+                check_eq!(errors, model_x(&state, 0), 0.0);
+                check_eq!(errors, state.floor_state.positions[0].x, 0.0);
+                check_eq!(errors, state.history.undo_count(), 0);
+                check_eq!(errors, state.draw_floor_request_count, draw_count + 1);
+                assert_no_errors(errors);
+            },
+        );
+
+        spec.it(
+            "ends a gesture whose release never arrived once a scene edit comes in",
+            |_| {
+                let mut state = two_position_state();
+
+                reduce(
+                    &mut state,
+                    ChoreoMainAction::FloorAction(FloorAction::PointerPressed {
+                        point: Point::new(0.0, 0.0),
+                    }),
+                );
+                move_selected(&mut state, 1.0);
+                reduce(&mut state, ChoreoMainAction::DuplicateScene);
+
+                let mut errors = Vec::new();
+                check_eq!(errors, state.history.undo_count(), 2);
+                check_eq!(errors, state.scenes.len(), 2);
+
+                reduce(&mut state, ChoreoMainAction::Undo);
+                check_eq!(errors, state.scenes.len(), 1);
+                check_eq!(errors, model_x(&state, 0), 1.0);
+                assert_no_errors(errors);
+            },
+        );
+
+        spec.it("undoes a whole touch drag at once", |_| {
+            let mut state = two_position_state();
+            let touch = |action| {
+                ChoreoMainAction::FloorAction(FloorAction::TouchWithContext {
+                    canvas_view: CanvasViewHandle::default(),
+                    event_args: TouchEventArgs {
+                        id: 1,
+                        action,
+                        device_type: TouchDeviceType::Touch,
+                        location: Point::new(0.0, 0.0),
+                        in_contact: action != TouchAction::Released,
+                    },
+                })
+            };
+
+            reduce(&mut state, touch(TouchAction::Pressed));
+            move_selected(&mut state, 1.0);
+            move_selected(&mut state, 1.0);
+            reduce(&mut state, touch(TouchAction::Released));
+            move_selected(&mut state, 1.0);
+
+            let mut errors = Vec::new();
+            check_eq!(errors, model_x(&state, 0), 3.0);
+            check_eq!(errors, state.history.undo_count(), 2);
+
+            reduce(&mut state, ChoreoMainAction::Undo);
+            check_eq!(errors, model_x(&state, 0), 2.0);
+            reduce(&mut state, ChoreoMainAction::Undo);
+            check_eq!(errors, model_x(&state, 0), 0.0);
+            assert_no_errors(errors);
+        });
+
+        spec.it(
+            "keeps separate steps outside a gesture and drops redo after a new edit",
+            |_| {
+                let mut state = two_position_state();
+
+                move_selected(&mut state, 1.0);
+                move_selected(&mut state, 1.0);
+                let mut errors = Vec::new();
+                check_eq!(errors, state.history.undo_count(), 2);
+
+                reduce(&mut state, ChoreoMainAction::Undo);
+                check_eq!(errors, model_x(&state, 0), 1.0);
+                check_eq!(errors, state.history.redo_count(), 1);
+
+                reduce(
+                    &mut state,
+                    ChoreoMainAction::ChoreographySettingsAction(
+                        ChoreographySettingsAction::UpdateName("Renamed".to_string()),
+                    ),
+                );
+                check_eq!(errors, state.history.undo_count(), 2);
+                check_eq!(errors, state.history.redo_count(), 0);
+
+                reduce(&mut state, ChoreoMainAction::Undo);
+                check_eq!(
+                    errors,
+                    state.choreography_settings_state.choreography.name,
+                    "Undo"
+                );
+                assert_no_errors(errors);
+            },
+        );
+
+        spec.it(
+            "skips edits without changes and forgets history when loading",
+            |_| {
+                let mut state = two_position_state();
+
+                reduce(&mut state, ChoreoMainAction::AutoAssignDancers);
+                let mut errors = Vec::new();
+                check_eq!(errors, state.is_dialog_open, true);
+                check_eq!(errors, state.history.can_undo(), false);
+
+                move_selected(&mut state, 1.0);
+                check_eq!(errors, state.history.can_undo(), true);
+                load_choreography(&mut state, vec![scene_model(1, "Other", None, Vec::new())]);
+                check_eq!(errors, state.history.can_undo(), false);
+
+                reduce(&mut state, ChoreoMainAction::Undo);
+                check_eq!(errors, state.scenes[0].name, "Other");
+                assert_no_errors(errors);
+            },
+        );
+
+        spec.it(
+            "writes aligned and mirrored positions back to the scene as separate steps",
+            |_| {
+                let mut state = two_position_state();
+                reduce(
+                    &mut state,
+                    ChoreoMainAction::FloorAction(FloorAction::SelectRectangle {
+                        start: Point::new(-1.0, -1.0),
+                        end: Point::new(5.0, 1.0),
+                    }),
+                );
+
+                reduce(
+                    &mut state,
+                    ChoreoMainAction::FloorAction(FloorAction::MirrorSelected {
+                        axis: MirrorAxis::LeftRight,
+                    }),
+                );
+                reduce(
+                    &mut state,
+                    ChoreoMainAction::FloorAction(FloorAction::AlignSelected {
+                        alignment: Alignment::Left,
+                    }),
+                );
+
+                let mut errors = Vec::new();
+                check_eq!(errors, model_x(&state, 0), -4.0);
+                check_eq!(errors, model_x(&state, 1), -4.0);
+                check_eq!(errors, state.history.undo_count(), 2);
+
+                reduce(&mut state, ChoreoMainAction::Undo);
+                check_eq!(errors, model_x(&state, 0), 0.0);
+                check_eq!(errors, model_x(&state, 1), -4.0);
+
+                reduce(&mut state, ChoreoMainAction::Undo);
+                check_eq!(errors, model_x(&state, 1), 4.0);
+                assert_no_errors(errors);
+            },
+        );
+
+        spec.it("maps ctrl+z to undo and ctrl+shift+z to redo", |_| {
+            let mut errors = Vec::new();
+            check_eq!(
+                errors,
+                shortcut_actions(egui::Modifiers::COMMAND),
+                vec![ChoreoMainAction::Undo]
+            );
+            check_eq!(
+                errors,
+                shortcut_actions(egui::Modifiers::COMMAND | egui::Modifiers::SHIFT),
+                vec![ChoreoMainAction::Redo]
+            );
+            check_eq!(
+                errors,
+                shortcut_actions(egui::Modifiers::NONE),
+                Vec::<ChoreoMainAction>::new()
+            );
+            assert_no_errors(errors);
+        });
+    });
+
+    let report = crate::choreo_main::run_suite(&suite);
+    assert!(report.is_success());
+}
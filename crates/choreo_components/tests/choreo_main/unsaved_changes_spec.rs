@@ -0,0 +1,335 @@
+use std::fs;
+use std::rc::Rc;
+
+use choreo_components::choreo_main::AUTOSAVE_INTERVAL;
+use choreo_components::choreo_main::ChoreoMainBehaviorDependencies;
+use choreo_components::choreo_main::MainPageActionHandlers;
+use choreo_components::choreo_main::MainPageBinding;
+use choreo_components::choreo_main::MainPageDependencies;
+use choreo_components::choreo_main::actions::ChoreoMainAction;
+use choreo_components::choreo_main::actions::OpenChoreoRequested;
+use choreo_components::choreo_main::state::UnsavedChangesPrompt;
+use choreo_components::choreo_main::translations::unsaved_changes_dialog_message;
+use choreo_components::choreography_settings::actions::ChoreographySettingsAction;
+use choreo_components::preferences::InMemoryRecoveryStore;
+use choreo_components::preferences::RecoveryStore;
+use choreo_master_mobile_json::export;
+use choreo_master_mobile_json::import;
+use choreo_models::ChoreographyModel;
+use choreo_models::ChoreographyModelMapper;
+use time::OffsetDateTime;
+
+use crate::choreo_main::assert_no_errors;
+use crate::choreo_main::build_dancer;
+use crate::choreo_main::dancer_position;
+use crate::choreo_main::scene_model;
+use crate::choreo_main::unique_temp_path;
+
+fn open_request(name: &str) -> OpenChoreoRequested {
+    let choreography = ChoreographyModel {
+        name: name.to_string(),
+        ..ChoreographyModel::default()
+    };
+    let mapper = ChoreographyModelMapper;
+    OpenChoreoRequested {
+        file_path: None,
+        file_name: None,
+        contents: export(&mapper.map_to_json(&choreography))
+            .expect("test choreography should serialize to json"),
+    }
+}
+
+fn open_request_with_scenes(choreography: ChoreographyModel) -> OpenChoreoRequested {
+    let mapper = ChoreographyModelMapper;
+    OpenChoreoRequested {
+        file_path: None,
+        file_name: None,
+        contents: export(&mapper.map_to_json(&choreography))
+            .expect("test choreography should serialize to json"),
+    }
+}
+
+fn rename(binding: &MainPageBinding, name: &str) {
+    binding.dispatch(ChoreoMainAction::ChoreographySettingsAction(
+        ChoreographySettingsAction::UpdateName(name.to_string()),
+    ));
+}
+
+fn binding_saving_to(file_path: String) -> MainPageBinding {
+    MainPageBinding::new(MainPageDependencies {
+        action_handlers: MainPageActionHandlers {
+            pick_save_choreo_path: Some(Rc::new(move |_: &str| Some(file_path.clone()))),
+            ..MainPageActionHandlers::default()
+        },
+        ..MainPageDependencies::default()
+    })
+}
+
+#[test]
+fn edits_mark_the_choreography_unsaved_until_it_is_saved() {
+    let temp_file = unique_temp_path("unsaved_changes").with_extension("choreo");
+    let binding = binding_saving_to(temp_file.to_string_lossy().into_owned());
+    let state = binding.state();
+    let mut errors = Vec::new();
+
+    check!(errors, !state.borrow().has_unsaved_changes());
+
+    binding.dispatch(ChoreoMainAction::RequestOpenChoreo(open_request("Draft")));
+    check!(errors, !state.borrow().has_unsaved_changes());
+
+    rename(&binding, "Draft edited");
+    check!(errors, state.borrow().has_unsaved_changes());
+
+    rename(&binding, "Draft");
+    check!(errors, !state.borrow().has_unsaved_changes());
+
+    rename(&binding, "Draft edited");
+    binding.dispatch(ChoreoMainAction::RequestSaveChoreo);
+    check!(errors, !state.borrow().has_unsaved_changes());
+
+    assert_no_errors(errors);
+
+    let _ = fs::remove_file(temp_file);
+}
+
+#[test]
+fn selecting_a_scene_of_a_freshly_opened_file_keeps_it_saved() {
+    let dancer = build_dancer(1, "Alex");
+    let choreography = ChoreographyModel {
+        name: "Draft".to_string(),
+        roles: vec![dancer.role.clone()],
+        dancers: vec![dancer.clone()],
+        scenes: vec![
+            scene_model(
+                10,
+                "Intro",
+                Some("1.50"),
+                vec![dancer_position(&dancer, 1.0, 2.0)],
+            ),
+            scene_model(
+                20,
+                "Verse",
+                Some("2.0"),
+                vec![dancer_position(&dancer, 3.0, 4.0)],
+            ),
+        ],
+        ..ChoreographyModel::default()
+    };
+    let binding = MainPageBinding::new(MainPageDependencies::default());
+    binding.dispatch(ChoreoMainAction::RequestOpenChoreo(
+        open_request_with_scenes(choreography),
+    ));
+    let state = binding.state();
+    let mut errors = Vec::new();
+
+    check!(errors, !state.borrow().has_unsaved_changes());
+
+    binding.dispatch(ChoreoMainAction::SelectScene { index: 1 });
+    binding.dispatch(ChoreoMainAction::SelectScene { index: 0 });
+
+    check!(errors, !state.borrow().has_unsaved_changes());
+    check_eq!(
+        errors,
+        state
+            .borrow()
+            .choreography_settings_state
+            .choreography
+            .scenes[1]
+            .positions[0]
+            .x,
+        3.0
+    );
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn opening_over_unsaved_changes_waits_for_the_prompt() {
+    let binding = MainPageBinding::new(MainPageDependencies::default());
+    binding.dispatch(ChoreoMainAction::RequestOpenChoreo(open_request("Draft")));
+    rename(&binding, "Draft edited");
+    let other = open_request("Other");
+
+    binding.dispatch(ChoreoMainAction::RequestOpenChoreo(other.clone()));
+
+    let state = binding.state();
+    let mut errors = Vec::new();
+
+    check_eq!(
+        errors,
+        state.borrow().choreography_settings_state.name,
+        "Draft edited"
+    );
+    check_eq!(
+        errors,
+        state.borrow().unsaved_changes_prompt,
+        Some(UnsavedChangesPrompt::OpenChoreo(other.clone()))
+    );
+    check_eq!(
+        errors,
+        unsaved_changes_dialog_message("en", &state.borrow()),
+        "\"Draft edited\" has unsaved changes. Save them before continuing?"
+    );
+
+    binding.dispatch(ChoreoMainAction::CancelUnsavedChanges);
+    check!(errors, state.borrow().unsaved_changes_prompt.is_none());
+    check_eq!(
+        errors,
+        state.borrow().choreography_settings_state.name,
+        "Draft edited"
+    );
+
+    binding.dispatch(ChoreoMainAction::RequestOpenChoreo(other));
+    binding.dispatch(ChoreoMainAction::DiscardUnsavedChanges);
+    check!(errors, state.borrow().unsaved_changes_prompt.is_none());
+    check_eq!(
+        errors,
+        state.borrow().choreography_settings_state.name,
+        "Other"
+    );
+    check!(errors, !state.borrow().has_unsaved_changes());
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn saving_from_the_prompt_saves_before_opening_the_next_file() {
+    let temp_file = unique_temp_path("unsaved_changes").with_extension("choreo");
+    let binding = binding_saving_to(temp_file.to_string_lossy().into_owned());
+    binding.dispatch(ChoreoMainAction::RequestOpenChoreo(open_request("Draft")));
+    rename(&binding, "Draft edited");
+
+    binding.dispatch(ChoreoMainAction::RequestOpenChoreo(open_request("Other")));
+    binding.dispatch(ChoreoMainAction::SaveUnsavedChanges);
+
+    let saved_contents =
+        fs::read_to_string(&temp_file).expect("saved .choreo file should be readable");
+    let saved_json = import(&saved_contents).expect("saved .choreo contents should import");
+    let state = binding.state();
+    let state = state.borrow();
+    let mut errors = Vec::new();
+
+    check_eq!(errors, saved_json.name, "Draft edited");
+    check_eq!(errors, state.choreography_settings_state.name, "Other");
+    check!(errors, state.unsaved_changes_prompt.is_none());
+
+    assert_no_errors(errors);
+
+    let _ = fs::remove_file(temp_file);
+}
+
+#[test]
+fn cancelling_the_save_picker_keeps_the_prompt_open() {
+    let binding = MainPageBinding::new(MainPageDependencies {
+        action_handlers: MainPageActionHandlers {
+            pick_save_choreo_path: Some(Rc::new(|_: &str| None)),
+            ..MainPageActionHandlers::default()
+        },
+        ..MainPageDependencies::default()
+    });
+    binding.dispatch(ChoreoMainAction::RequestOpenChoreo(open_request("Draft")));
+    rename(&binding, "Draft edited");
+
+    binding.dispatch(ChoreoMainAction::RequestClose);
+    binding.dispatch(ChoreoMainAction::SaveUnsavedChanges);
+
+    let state = binding.state();
+    let state = state.borrow();
+    let mut errors = Vec::new();
+
+    check_eq!(
+        errors,
+        state.unsaved_changes_prompt,
+        Some(UnsavedChangesPrompt::Close)
+    );
+    check!(errors, !state.is_close_confirmed);
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn closing_asks_only_while_changes_are_unsaved() {
+    let clean = MainPageBinding::new(MainPageDependencies::default());
+    clean.dispatch(ChoreoMainAction::RequestOpenChoreo(open_request("Draft")));
+    clean.dispatch(ChoreoMainAction::RequestClose);
+
+    let dirty = MainPageBinding::new(MainPageDependencies::default());
+    dirty.dispatch(ChoreoMainAction::RequestOpenChoreo(open_request("Draft")));
+    rename(&dirty, "Draft edited");
+    dirty.dispatch(ChoreoMainAction::RequestClose);
+
+    let mut errors = Vec::new();
+
+    check!(errors, clean.state().borrow().is_close_confirmed);
+    check!(
+        errors,
+        clean.state().borrow().unsaved_changes_prompt.is_none()
+    );
+    check!(errors, !dirty.state().borrow().is_close_confirmed);
+    check_eq!(
+        errors,
+        dirty.state().borrow().unsaved_changes_prompt,
+        Some(UnsavedChangesPrompt::Close)
+    );
+
+    dirty.dispatch(ChoreoMainAction::DiscardUnsavedChanges);
+    check!(errors, dirty.state().borrow().is_close_confirmed);
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn discarding_unsaved_changes_drops_the_recovery_snapshot() {
+    let store = InMemoryRecoveryStore::new();
+    let binding = MainPageBinding::new(MainPageDependencies {
+        behavior_dependencies: ChoreoMainBehaviorDependencies {
+            recovery_store: Some(Rc::new(store.clone())),
+            ..ChoreoMainBehaviorDependencies::default()
+        },
+        ..MainPageDependencies::default()
+    });
+    binding.dispatch(ChoreoMainAction::RequestOpenChoreo(open_request("Draft")));
+    rename(&binding, "Draft edited");
+    let _ = binding.tick_autosave(OffsetDateTime::now_utc() + AUTOSAVE_INTERVAL);
+
+    let mut errors = Vec::new();
+
+    check!(errors, store.load().is_some());
+
+    binding.dispatch(ChoreoMainAction::RequestClose);
+    binding.dispatch(ChoreoMainAction::DiscardUnsavedChanges);
+    check!(errors, binding.state().borrow().is_close_confirmed);
+    check!(errors, store.load().is_none());
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn closing_a_blank_choreography_asks_once_it_has_been_edited() {
+    let untouched = MainPageBinding::new(MainPageDependencies::default());
+    untouched.dispatch(ChoreoMainAction::Initialize);
+    untouched.dispatch(ChoreoMainAction::RequestClose);
+
+    let edited = MainPageBinding::new(MainPageDependencies::default());
+    edited.dispatch(ChoreoMainAction::Initialize);
+    rename(&edited, "Draft");
+
+    let mut errors = Vec::new();
+
+    check!(errors, untouched.state().borrow().is_close_confirmed);
+    check!(errors, edited.state().borrow().has_unsaved_changes());
+
+    edited.dispatch(ChoreoMainAction::RequestClose);
+    check!(errors, !edited.state().borrow().is_close_confirmed);
+    check_eq!(
+        errors,
+        edited.state().borrow().unsaved_changes_prompt,
+        Some(UnsavedChangesPrompt::Close)
+    );
+
+    edited.dispatch(ChoreoMainAction::CancelUnsavedChanges);
+    edited.dispatch(ChoreoMainAction::Undo);
+    check!(errors, !edited.state().borrow().has_unsaved_changes());
+
+    assert_no_errors(errors);
+}
@@ -0,0 +1,103 @@
+use crate::choreo_main::Report;
+use crate::choreo_main::actions::ChoreoMainAction;
+use crate::choreo_main::reducer::reduce;
+use crate::choreo_main::state::ChoreoMainState;
+use crate::choreo_main::state::SceneState;
+use choreo_components::audio_player::actions::AudioPlayerAction;
+use choreo_components::audio_player::state::AudioPlayerScene;
+
+macro_rules! check_eq {
+    ($errors:expr, $left:expr, $right:expr) => {
+        if $left != $right {
+            $errors.push(format!(
+                "{} != {} (left = {:?}, right = {:?})",
+                stringify!($left),
+                stringify!($right),
+                $left,
+                $right
+            ));
+        }
+    };
+}
+
+fn assert_no_errors(errors: Vec<String>) {
+    assert!(
+        errors.is_empty(),
+        "Assertion failures:\n{}",
+        errors.join("\n")
+    );
+}
+
+#[test]
+fn waveform_marker_timestamp_spec() {
+    let suite = rspec::describe("waveform marker timestamps", (), |spec| {
+        spec.it(
+            "retimes a scene when its waveform marker is dropped",
+            |_| {
+                let mut state = ChoreoMainState::default();
+                reduce(
+                    &mut state,
+                    ChoreoMainAction::SetScenes {
+                        scenes: vec![
+                            SceneState {
+                                name: "Scene 1".to_string(),
+                                timestamp_seconds: Some(5.0),
+                            },
+                            SceneState {
+                                name: "Scene 2".to_string(),
+                                timestamp_seconds: Some(10.0),
+                            },
+                        ],
+                    },
+                );
+                reduce(
+                    &mut state,
+                    ChoreoMainAction::AudioPlayerAction(AudioPlayerAction::SetScenes {
+                        scenes: vec![
+                            AudioPlayerScene {
+                                scene_id: 1,
+                                name: "Scene 1".to_string(),
+                                timestamp: Some(5.0),
+                            },
+                            AudioPlayerScene {
+                                scene_id: 2,
+                                name: "Scene 2".to_string(),
+                                timestamp: Some(10.0),
+                            },
+                        ],
+                        selected_scene_id: Some(1),
+                        choreography_scenes: Vec::new(),
+                    }),
+                );
+
+                reduce(
+                    &mut state,
+                    ChoreoMainAction::AudioPlayerAction(AudioPlayerAction::MoveSceneTimestamp {
+                        scene_id: 2,
+                        timestamp: 12.5,
+                    }),
+                );
+
+                let mut errors = Vec::new();
+
+                check_eq!(errors, state.scenes[1].timestamp_seconds, Some(12.5));
+                check_eq!(
+                    errors,
+                    state.choreography_settings_state.choreography.scenes[1]
+                        .timestamp
+                        .as_deref(),
+                    Some("12.5")
+                );
+                check_eq!(errors, state.history.can_undo(), true);
+
+                reduce(&mut state, ChoreoMainAction::Undo);
+                check_eq!(errors, state.scenes[1].timestamp_seconds, Some(10.0));
+
+                assert_no_errors(errors);
+            },
+        );
+    });
+
+    let report = crate::choreo_main::run_suite(&suite);
+    assert!(report.is_success());
+}
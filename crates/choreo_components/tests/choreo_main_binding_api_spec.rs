@@ -277,6 +277,139 @@ fn binding_saves_current_choreography_back_to_last_opened_file() {
     let _ = fs::remove_file(temp_file);
 }
 
+fn open_unsaved_choreography(binding: &MainPageBinding, name: &str) {
+    let choreography = ChoreographyModel {
+        name: name.to_string(),
+        ..ChoreographyModel::default()
+    };
+    let mapper = ChoreographyModelMapper;
+    let contents = export(&mapper.map_to_json(&choreography))
+        .expect("test choreography should serialize to json");
+    binding.dispatch(ChoreoMainAction::RequestOpenChoreo(OpenChoreoRequested {
+        file_path: None,
+        file_name: None,
+        contents,
+    }));
+}
+
+#[test]
+fn binding_saves_new_choreography_through_save_as_picker() {
+    let temp_file = unique_temp_file("choreo");
+    let file_path = temp_file.to_string_lossy().into_owned();
+    let suggested_names: Rc<RefCell<Vec<String>>> = Rc::new(RefCell::new(Vec::new()));
+    let suggested_names_for_picker = Rc::clone(&suggested_names);
+    let file_path_for_picker = file_path.clone();
+
+    let binding = MainPageBinding::new(MainPageDependencies {
+        action_handlers: MainPageActionHandlers {
+            pick_save_choreo_path: Some(Rc::new(move |suggested_name: &str| {
+                suggested_names_for_picker
+                    .borrow_mut()
+                    .push(suggested_name.to_string());
+                Some(file_path_for_picker.clone())
+            })),
+            ..MainPageActionHandlers::default()
+        },
+        ..MainPageDependencies::default()
+    });
+
+    open_unsaved_choreography(&binding, "Fresh");
+    binding.dispatch(ChoreoMainAction::RequestSaveChoreo);
+    binding.dispatch(ChoreoMainAction::ChoreographySettingsAction(
+        ChoreographySettingsAction::UpdateName("Fresh again".to_string()),
+    ));
+    binding.dispatch(ChoreoMainAction::RequestSaveChoreo);
+
+    let saved_contents =
+        fs::read_to_string(&temp_file).expect("saved .choreo file should be readable");
+    let saved_json = import(&saved_contents).expect("saved .choreo contents should import");
+    let state = binding.state();
+    let state = state.borrow();
+
+    let mut errors = Vec::new();
+
+    check_eq!(errors, saved_json.name, "Fresh again");
+    check_eq!(
+        errors,
+        *suggested_names.borrow(),
+        vec!["Fresh.choreo".to_string()]
+    );
+    check_eq!(
+        errors,
+        state.last_opened_choreo_file,
+        Some(file_path.clone())
+    );
+    check!(errors, !state.is_dialog_open);
+
+    assert_no_errors(errors);
+
+    let _ = fs::remove_file(temp_file);
+}
+
+#[test]
+fn binding_keeps_state_when_save_as_picker_is_cancelled() {
+    let binding = MainPageBinding::new(MainPageDependencies {
+        action_handlers: MainPageActionHandlers {
+            pick_save_choreo_path: Some(Rc::new(|_: &str| None)),
+            ..MainPageActionHandlers::default()
+        },
+        ..MainPageDependencies::default()
+    });
+
+    open_unsaved_choreography(&binding, "Cancelled");
+    binding.dispatch(ChoreoMainAction::RequestSaveChoreoAs);
+
+    let state = binding.state();
+    let state = state.borrow();
+
+    let mut errors = Vec::new();
+
+    check_eq!(errors, state.last_opened_choreo_file, None::<String>);
+    check!(errors, !state.is_dialog_open);
+    check!(errors, state.outgoing_save_choreo_requests.is_empty());
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn binding_shows_dialog_with_error_details_when_save_fails() {
+    let missing_directory = unique_temp_file("missing");
+    let file_path = missing_directory
+        .join("target.choreo")
+        .to_string_lossy()
+        .into_owned();
+    let file_path_for_picker = file_path.clone();
+
+    let binding = MainPageBinding::new(MainPageDependencies {
+        action_handlers: MainPageActionHandlers {
+            pick_save_choreo_path: Some(Rc::new(move |_: &str| Some(file_path_for_picker.clone()))),
+            ..MainPageActionHandlers::default()
+        },
+        ..MainPageDependencies::default()
+    });
+
+    open_unsaved_choreography(&binding, "Unsaved");
+    binding.dispatch(ChoreoMainAction::RequestSaveChoreoAs);
+
+    let state = binding.state();
+    let state = state.borrow();
+    let dialog_content = state.dialog_content.clone().unwrap_or_default();
+
+    let mut errors = Vec::new();
+
+    check!(errors, state.is_dialog_open);
+    check!(
+        errors,
+        dialog_content.starts_with(&format!(
+            "Could not save the choreography to {file_path}: io error:"
+        ))
+    );
+    check_eq!(errors, state.last_opened_choreo_file, None::<String>);
+    check!(errors, !Path::new(&file_path).exists());
+
+    assert_no_errors(errors);
+}
+
 #[test]
 fn binding_uses_pick_audio_handler_to_open_selected_file_and_show_audio_panel() {
     let (open_audio_sender, open_audio_receiver) = bounded::<OpenAudioFileCommand>(8);
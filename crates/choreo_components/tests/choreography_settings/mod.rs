@@ -24,6 +24,7 @@ pub mod update_floor_left_behavior_spec;
 pub mod update_floor_right_behavior_spec;
 pub mod update_grid_lines_behavior_spec;
 pub mod update_grid_resolution_behavior_spec;
+pub mod update_musical_timeline_behavior_spec;
 pub mod update_name_behavior_spec;
 pub mod update_positions_at_side_behavior_spec;
 pub mod update_selected_scene_behavior_spec;
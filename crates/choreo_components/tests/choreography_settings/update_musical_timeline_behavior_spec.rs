@@ -0,0 +1,173 @@
+use super::actions::ChoreographySettingsAction;
+use super::actions::UpdateSelectedSceneAction;
+use super::create_state;
+use super::reducer::reduce;
+use super::scene_model;
+use super::selected_scene;
+use super::state::ChoreographySettingsState;
+use super::state::SelectedSceneState;
+use choreo_components::time::counts::CountPosition;
+use choreo_master_mobile_json::MusicalTimeline;
+
+macro_rules! check_eq {
+    ($errors:expr, $left:expr, $right:expr) => {
+        if $left != $right {
+            $errors.push(format!(
+                "{} != {} (left = {:?}, right = {:?})",
+                stringify!($left),
+                stringify!($right),
+                $left,
+                $right
+            ));
+        }
+    };
+}
+
+macro_rules! check {
+    ($errors:expr, $condition:expr) => {
+        if !$condition {
+            $errors.push(format!("condition failed: {}", stringify!($condition)));
+        }
+    };
+}
+
+fn assert_no_errors(errors: Vec<String>) {
+    assert!(
+        errors.is_empty(),
+        "Assertion failures:\n{}",
+        errors.join("\n")
+    );
+}
+
+fn timeline(bpm: f64, offset_seconds: f64, beats_per_bar: u32) -> MusicalTimeline {
+    MusicalTimeline {
+        bpm,
+        offset_seconds,
+        beats_per_bar,
+        beat_unit: 4,
+    }
+}
+
+fn state_with_scene_at(seconds: f64) -> ChoreographySettingsState {
+    let mut state = create_state();
+    let mut choreography = super::choreography_with_name("Test");
+    choreography.scenes = vec![scene_model(10, "Chorus", None, Some(&seconds.to_string()))];
+    reduce(
+        &mut state,
+        ChoreographySettingsAction::LoadChoreography {
+            choreography: Box::new(choreography),
+            selected_scene: Some(SelectedSceneState {
+                timestamp: Some(seconds),
+                ..selected_scene(10, "Chorus")
+            }),
+        },
+    );
+    state
+}
+
+#[test]
+fn update_musical_timeline_stores_it_and_shows_the_selected_scene_in_counts() {
+    let mut state = state_with_scene_at(16.5);
+
+    reduce(
+        &mut state,
+        ChoreographySettingsAction::UpdateMusicalTimeline(Some(timeline(120.0, 0.5, 4))),
+    );
+
+    let mut errors = Vec::new();
+
+    check_eq!(
+        errors,
+        state.choreography.settings.musical_timeline,
+        Some(timeline(120.0, 0.5, 4))
+    );
+    check_eq!(
+        errors,
+        state.scene_timestamp_count_position,
+        Some(CountPosition { eight: 5, count: 1 })
+    );
+    check!(errors, state.redraw_requested);
+
+    reduce(
+        &mut state,
+        ChoreographySettingsAction::UpdateMusicalTimeline(None),
+    );
+    check!(
+        errors,
+        state.choreography.settings.musical_timeline.is_none()
+    );
+    check!(errors, state.scene_timestamp_count_position.is_none());
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn scene_count_position_places_the_scene_in_seconds() {
+    let mut state = state_with_scene_at(3.0);
+    reduce(
+        &mut state,
+        ChoreographySettingsAction::UpdateMusicalTimeline(Some(timeline(120.0, 0.0, 4))),
+    );
+
+    reduce(
+        &mut state,
+        ChoreographySettingsAction::UpdateSelectedScene(
+            UpdateSelectedSceneAction::SceneCountPosition { eight: 8, count: 5 },
+        ),
+    );
+
+    let mut errors = Vec::new();
+
+    check_eq!(
+        errors,
+        state.choreography.scenes[0].timestamp.as_deref(),
+        Some("30")
+    );
+    check_eq!(errors, state.scene_timestamp_seconds, 30.0);
+    check_eq!(
+        errors,
+        state.scene_timestamp_count_position,
+        Some(CountPosition { eight: 8, count: 5 })
+    );
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn scene_count_position_is_ignored_without_a_musical_timeline() {
+    let mut state = state_with_scene_at(3.0);
+
+    reduce(
+        &mut state,
+        ChoreographySettingsAction::UpdateSelectedScene(
+            UpdateSelectedSceneAction::SceneCountPosition { eight: 8, count: 5 },
+        ),
+    );
+
+    let mut errors = Vec::new();
+
+    check_eq!(
+        errors,
+        state.choreography.scenes[0].timestamp.as_deref(),
+        Some("3")
+    );
+    check_eq!(errors, state.scene_timestamp_seconds, 3.0);
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn musical_timeline_is_kept_within_usable_values() {
+    let mut state = state_with_scene_at(3.0);
+
+    reduce(
+        &mut state,
+        ChoreographySettingsAction::UpdateMusicalTimeline(Some(timeline(0.0, -2.0, 0))),
+    );
+
+    let mut errors = Vec::new();
+
+    check_eq!(errors, state.musical_timeline, Some(timeline(20.0, 0.0, 1)));
+
+    assert_no_errors(errors);
+}
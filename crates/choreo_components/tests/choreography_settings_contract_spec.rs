@@ -18,14 +18,6 @@ macro_rules! check_eq {
     };
 }
 
-macro_rules! check {
-    ($errors:expr, $condition:expr) => {
-        if !$condition {
-            $errors.push(format!("condition failed: {}", stringify!($condition)));
-        }
-    };
-}
-
 fn assert_no_errors(errors: Vec<String>) {
     assert!(
         errors.is_empty(),
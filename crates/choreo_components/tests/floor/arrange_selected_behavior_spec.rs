@@ -0,0 +1,412 @@
+use crate::floor::floor_component::actions::FloorAction;
+use crate::floor::floor_component::arrange::stage_center;
+use crate::floor::floor_component::reducer::reduce;
+use crate::floor::floor_component::state::Alignment;
+use crate::floor::floor_component::state::Distribution;
+use crate::floor::floor_component::state::FloorPosition;
+use crate::floor::floor_component::state::FloorState;
+use crate::floor::floor_component::state::MirrorAxis;
+use crate::floor::floor_component::state::Point;
+
+macro_rules! check_eq {
+    ($errors:expr, $left:expr, $right:expr) => {
+        if $left != $right {
+            $errors.push(format!(
+                "{} != {} (left = {:?}, right = {:?})",
+                stringify!($left),
+                stringify!($right),
+                $left,
+                $right
+            ));
+        }
+    };
+}
+
+macro_rules! check {
+    ($errors:expr, $condition:expr) => {
+        let passed: bool = $condition;
+        if !passed {
+            $errors.push(format!("condition failed: {}", stringify!($condition)));
+        }
+    };
+}
+
+fn assert_no_errors(errors: Vec<String>) {
+    assert!(
+        errors.is_empty(),
+        "Assertion failures:\n{}",
+        errors.join("\n")
+    );
+}
+
+fn is_close(actual: f64, expected: f64) -> bool {
+    (actual - expected).abs() < 0.0001
+}
+
+fn coordinates(state: &FloorState) -> Vec<(f64, f64)> {
+    state
+        .positions
+        .iter()
+        .map(|position| (position.x, position.y))
+        .collect()
+}
+
+/// A floor reaching 4 m to the left and the back and 6 m to the right and the front, with all
+/// but the last position selected.
+fn state_with_selection(positions: Vec<FloorPosition>) -> FloorState {
+    let mut state = FloorState {
+        floor_left: 4,
+        floor_right: 6,
+        floor_front: 6,
+        floor_back: 4,
+        ..FloorState::default()
+    };
+    let unselected = positions.len() - 1;
+    reduce(&mut state, FloorAction::SetPositions { positions });
+    state.selected_positions = (0..unselected).collect();
+    state
+}
+
+fn scattered() -> FloorState {
+    state_with_selection(vec![
+        FloorPosition::new(-2.0, 1.0),
+        FloorPosition::new(3.0, -1.0),
+        FloorPosition::new(0.0, 2.0),
+        FloorPosition::new(-3.0, -3.0),
+    ])
+}
+
+#[test]
+fn align_selected_lines_positions_up_on_the_outermost_one() {
+    let mut errors = Vec::new();
+
+    let mut state = scattered();
+    reduce(
+        &mut state,
+        FloorAction::AlignSelected {
+            alignment: Alignment::Left,
+        },
+    );
+    check_eq!(
+        errors,
+        coordinates(&state),
+        vec![(-2.0, 1.0), (-2.0, -1.0), (-2.0, 2.0), (-3.0, -3.0)]
+    );
+
+    let mut state = scattered();
+    reduce(
+        &mut state,
+        FloorAction::AlignSelected {
+            alignment: Alignment::Right,
+        },
+    );
+    check_eq!(
+        errors,
+        coordinates(&state),
+        vec![(3.0, 1.0), (3.0, -1.0), (3.0, 2.0), (-3.0, -3.0)]
+    );
+
+    let mut state = scattered();
+    reduce(
+        &mut state,
+        FloorAction::AlignSelected {
+            alignment: Alignment::Front,
+        },
+    );
+    check_eq!(
+        errors,
+        coordinates(&state),
+        vec![(-2.0, 2.0), (3.0, 2.0), (0.0, 2.0), (-3.0, -3.0)]
+    );
+
+    let mut state = scattered();
+    reduce(
+        &mut state,
+        FloorAction::AlignSelected {
+            alignment: Alignment::Back,
+        },
+    );
+    check_eq!(
+        errors,
+        coordinates(&state),
+        vec![(-2.0, -1.0), (3.0, -1.0), (0.0, -1.0), (-3.0, -3.0)]
+    );
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn align_selected_to_the_stage_center_keeps_the_shape() {
+    let mut state = scattered();
+
+    reduce(
+        &mut state,
+        FloorAction::AlignSelected {
+            alignment: Alignment::StageCenter,
+        },
+    );
+
+    let mut errors = Vec::new();
+
+    check_eq!(errors, stage_center(4, 6, 6, 4), Point::new(1.0, 1.0));
+    check_eq!(
+        errors,
+        coordinates(&state),
+        vec![(-1.5, 1.5), (3.5, -0.5), (0.5, 2.5), (-3.0, -3.0)]
+    );
+
+    state.snap_to_grid = true;
+    state.grid_resolution = 1;
+    reduce(
+        &mut state,
+        FloorAction::AlignSelected {
+            alignment: Alignment::StageCenter,
+        },
+    );
+
+    // Snapping rounds half a grid step away from zero.
+    check_eq!(
+        errors,
+        coordinates(&state),
+        vec![(-2.0, 2.0), (4.0, -1.0), (1.0, 3.0), (-3.0, -3.0)]
+    );
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn distribute_selected_spaces_positions_evenly_between_the_outermost_ones() {
+    let positions = vec![
+        FloorPosition::new(-3.0, 0.0),
+        FloorPosition::new(3.0, 2.0),
+        FloorPosition::new(-2.0, -4.0),
+        FloorPosition::new(1.0, 1.0),
+        FloorPosition::new(5.0, 5.0),
+    ];
+    let mut errors = Vec::new();
+
+    let mut state = state_with_selection(positions.clone());
+    reduce(
+        &mut state,
+        FloorAction::DistributeSelected {
+            distribution: Distribution::AlongX,
+        },
+    );
+    check_eq!(
+        errors,
+        coordinates(&state),
+        vec![
+            (-3.0, 0.0),
+            (3.0, 2.0),
+            (-1.0, -4.0),
+            (1.0, 1.0),
+            (5.0, 5.0)
+        ]
+    );
+
+    let mut state = state_with_selection(positions);
+    reduce(
+        &mut state,
+        FloorAction::DistributeSelected {
+            distribution: Distribution::AlongY,
+        },
+    );
+    check_eq!(
+        errors,
+        coordinates(&state),
+        vec![
+            (-3.0, -2.0),
+            (3.0, 2.0),
+            (-2.0, -4.0),
+            (1.0, 0.0),
+            (5.0, 5.0)
+        ]
+    );
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn distribute_selected_along_a_circle_keeps_the_order_around_the_middle() {
+    let mut state = state_with_selection(vec![
+        FloorPosition::new(2.0, 0.0),
+        FloorPosition::new(0.0, 1.0),
+        FloorPosition::new(-3.0, 0.0),
+        FloorPosition::new(0.0, -2.0),
+        FloorPosition::new(5.0, 5.0),
+    ]);
+
+    reduce(
+        &mut state,
+        FloorAction::DistributeSelected {
+            distribution: Distribution::Circle,
+        },
+    );
+
+    let mut errors = Vec::new();
+
+    let center = (-0.25, -0.25);
+    let radii = state.positions[..4]
+        .iter()
+        .map(|position| (position.x - center.0).hypot(position.y - center.1))
+        .collect::<Vec<_>>();
+    check!(
+        errors,
+        radii.iter().all(|radius| is_close(*radius, radii[0]))
+    );
+    let angles = state.positions[..4]
+        .iter()
+        .map(|position| (position.y - center.1).atan2(position.x - center.0))
+        .collect::<Vec<_>>();
+    // Neighbours around the circle stay neighbours, a quarter turn apart.
+    for (first, second) in [(3, 0), (0, 1), (1, 2)] {
+        check!(
+            errors,
+            is_close(
+                (angles[second] - angles[first]).rem_euclid(std::f64::consts::TAU),
+                std::f64::consts::FRAC_PI_2
+            )
+        );
+    }
+    check_eq!(errors, coordinates(&state)[4], (5.0, 5.0));
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn distribute_selected_leaves_fewer_than_three_positions_alone() {
+    let mut state = state_with_selection(vec![
+        FloorPosition::new(-4.0, 0.0),
+        FloorPosition::new(3.0, 2.0),
+        FloorPosition::new(5.0, 5.0),
+    ]);
+
+    reduce(
+        &mut state,
+        FloorAction::DistributeSelected {
+            distribution: Distribution::Circle,
+        },
+    );
+
+    let mut errors = Vec::new();
+
+    check_eq!(
+        errors,
+        coordinates(&state),
+        vec![(-4.0, 0.0), (3.0, 2.0), (5.0, 5.0)]
+    );
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn mirror_selected_flips_positions_and_facing_about_the_stage_centerline() {
+    let mut turned = FloorPosition::new(-2.0, 3.0);
+    turned.orientation = Some(90.0);
+    let positions = vec![
+        turned,
+        FloorPosition::new(1.0, -1.0),
+        FloorPosition::new(5.0, 5.0),
+    ];
+    let mut errors = Vec::new();
+
+    let mut state = state_with_selection(positions.clone());
+    reduce(
+        &mut state,
+        FloorAction::MirrorSelected {
+            axis: MirrorAxis::LeftRight,
+        },
+    );
+    check_eq!(
+        errors,
+        coordinates(&state),
+        vec![(4.0, 3.0), (1.0, -1.0), (5.0, 5.0)]
+    );
+    check_eq!(errors, state.positions[0].orientation, Some(270.0));
+    check!(errors, state.positions[1].orientation.is_none());
+
+    let mut state = state_with_selection(positions);
+    reduce(
+        &mut state,
+        FloorAction::MirrorSelected {
+            axis: MirrorAxis::FrontBack,
+        },
+    );
+    check_eq!(
+        errors,
+        coordinates(&state),
+        vec![(-2.0, -1.0), (1.0, 3.0), (5.0, 5.0)]
+    );
+    check_eq!(errors, state.positions[0].orientation, Some(90.0));
+
+    reduce(
+        &mut state,
+        FloorAction::MirrorSelected {
+            axis: MirrorAxis::FrontBack,
+        },
+    );
+    check_eq!(
+        errors,
+        coordinates(&state),
+        vec![(-2.0, 3.0), (1.0, -1.0), (5.0, 5.0)]
+    );
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn snapping_rounds_only_the_axis_an_arrangement_moves() {
+    let off_grid = || {
+        let mut state = state_with_selection(vec![
+            FloorPosition::new(-2.3, 1.4),
+            FloorPosition::new(0.6, -1.2),
+            FloorPosition::new(2.2, 0.3),
+            FloorPosition::new(-3.0, -3.0),
+        ]);
+        state.snap_to_grid = true;
+        state.grid_resolution = 1;
+        state
+    };
+    let mut errors = Vec::new();
+
+    let mut state = off_grid();
+    reduce(
+        &mut state,
+        FloorAction::AlignSelected {
+            alignment: Alignment::Left,
+        },
+    );
+    check_eq!(
+        errors,
+        coordinates(&state),
+        vec![(-2.0, 1.4), (-2.0, -1.2), (-2.0, 0.3), (-3.0, -3.0)]
+    );
+
+    let mut state = off_grid();
+    reduce(
+        &mut state,
+        FloorAction::DistributeSelected {
+            distribution: Distribution::AlongY,
+        },
+    );
+    check_eq!(
+        errors,
+        coordinates(&state),
+        vec![(-2.3, 1.0), (0.6, -1.0), (2.2, 0.0), (-3.0, -3.0)]
+    );
+
+    let mut state = off_grid();
+    reduce(
+        &mut state,
+        FloorAction::MirrorSelected {
+            axis: MirrorAxis::FrontBack,
+        },
+    );
+    check_eq!(
+        errors,
+        coordinates(&state),
+        vec![(-2.3, 1.0), (0.6, 3.0), (2.2, 2.0), (-3.0, -3.0)]
+    );
+
+    assert_no_errors(errors);
+}
@@ -0,0 +1,278 @@
+use crate::floor::assert_near;
+use crate::floor::canvas_point;
+use crate::floor::dancer;
+use crate::floor::floor_component::actions::FloorAction;
+use crate::floor::floor_component::movement_path::cubic_controls;
+use crate::floor::floor_component::reducer::reduce;
+use crate::floor::floor_component::state::CurveControl;
+use crate::floor::floor_component::state::FloorPosition;
+use crate::floor::floor_component::state::FloorState;
+use crate::floor::floor_component::state::InteractionMode;
+use crate::floor::floor_component::state::Point;
+
+macro_rules! check_eq {
+    ($errors:expr, $left:expr, $right:expr) => {
+        if $left != $right {
+            $errors.push(format!(
+                "{} != {} (left = {:?}, right = {:?})",
+                stringify!($left),
+                stringify!($right),
+                $left,
+                $right
+            ));
+        }
+    };
+}
+
+macro_rules! check {
+    ($errors:expr, $condition:expr) => {
+        let passed: bool = $condition;
+        if !passed {
+            $errors.push(format!("condition failed: {}", stringify!($condition)));
+        }
+    };
+}
+
+fn assert_no_errors(errors: Vec<String>) {
+    assert!(
+        errors.is_empty(),
+        "Assertion failures:\n{}",
+        errors.join("\n")
+    );
+}
+
+fn bend_path_state() -> FloorState {
+    let source_positions = vec![dancer("A", 3.0, 0.0), dancer("B", 0.0, 2.0)];
+    let mut state = FloorState {
+        positions: source_positions
+            .iter()
+            .map(|position| FloorPosition::new(position.x, position.y))
+            .collect(),
+        source_positions,
+        previous_source_positions: vec![dancer("A", -3.0, 0.0)],
+        ..FloorState::default()
+    };
+    reduce(&mut state, FloorAction::DrawFloor);
+    reduce(
+        &mut state,
+        FloorAction::SetInteractionMode {
+            mode: InteractionMode::BendPath,
+        },
+    );
+    state
+}
+
+/// Whether the incoming path runs along the straight line between A's two positions.
+fn is_straight(state: &FloorState) -> bool {
+    state
+        .colored_dashed_path_segments
+        .iter()
+        .all(|segment| (segment.from.y - state.center_y).abs() < 1e-9)
+}
+
+#[test]
+fn quadratic_curves_are_raised_to_the_same_cubic() {
+    let start = Point::new(0.0, 0.0);
+    let end = Point::new(3.0, 0.0);
+
+    let mut errors = Vec::new();
+
+    let (control1, control2) = cubic_controls(start, Some(Point::new(1.5, 3.0)), None, end);
+    assert_near(&mut errors, control1, Point::new(1.0, 2.0));
+    assert_near(&mut errors, control2, Point::new(2.0, 2.0));
+
+    let (control1, control2) = cubic_controls(start, None, None, end);
+    assert_near(&mut errors, control1, Point::new(1.0, 0.0));
+    assert_near(&mut errors, control2, Point::new(2.0, 0.0));
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn bend_path_mode_offers_handles_for_dancers_arriving_from_the_previous_scene() {
+    let mut state = bend_path_state();
+
+    let mut errors = Vec::new();
+
+    // Only A appears in the previous scene; its controls sit on the straight path, each tied to
+    // its own end of the path.
+    check_eq!(errors, state.curve_handles.len(), 2);
+    check!(
+        errors,
+        state.curve_handles.iter().all(|handle| !handle.is_set)
+    );
+    check_eq!(errors, state.curve_handles[0].control, CurveControl::First);
+    assert_near(
+        &mut errors,
+        state.curve_handles[0].point,
+        canvas_point(&state, -1.0, 0.0),
+    );
+    assert_near(
+        &mut errors,
+        state.curve_handles[0].anchor,
+        canvas_point(&state, -3.0, 0.0),
+    );
+    assert_near(
+        &mut errors,
+        state.curve_handles[1].point,
+        canvas_point(&state, 1.0, 0.0),
+    );
+    assert_near(
+        &mut errors,
+        state.curve_handles[1].anchor,
+        canvas_point(&state, 3.0, 0.0),
+    );
+    check!(errors, !state.colored_dashed_path_segments.is_empty());
+
+    reduce(
+        &mut state,
+        FloorAction::SelectRectangle {
+            start: Point::new(-1.0, 1.0),
+            end: Point::new(1.0, 3.0),
+        },
+    );
+    check!(errors, state.curve_handles.is_empty());
+
+    reduce(&mut state, FloorAction::ClearSelection);
+    reduce(
+        &mut state,
+        FloorAction::SetInteractionMode {
+            mode: InteractionMode::EditPath,
+        },
+    );
+    check!(errors, state.curve_handles.is_empty());
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn dragging_a_handle_bends_the_incoming_path_until_released() {
+    let mut state = bend_path_state();
+    state.previous_source_positions[0].movement1_x = Some(0.0);
+    state.previous_source_positions[0].movement1_y = Some(-2.0);
+    state.snap_to_grid = true;
+    state.grid_resolution = 2;
+
+    let mut errors = Vec::new();
+
+    reduce(
+        &mut state,
+        FloorAction::MoveCurveControl {
+            position: 0,
+            control: CurveControl::First,
+            point: Point::new(-1.1, 2.8),
+        },
+    );
+    let previous = &state.previous_source_positions[0];
+    check_eq!(errors, previous.curve1_x, Some(-1.0));
+    check_eq!(errors, previous.curve1_y, Some(3.0));
+    // The second control keeps its place on the straight path so the curve stays cubic, and the
+    // waypoint gives way to the curve.
+    check_eq!(errors, previous.curve2_x, Some(1.0));
+    check_eq!(errors, previous.curve2_y, Some(0.0));
+    check_eq!(errors, previous.movement1_x, None::<f64>);
+    check!(
+        errors,
+        state.curve_handles.iter().all(|handle| handle.is_set)
+    );
+    check_eq!(
+        errors,
+        state.active_curve_handle,
+        Some((0, CurveControl::First))
+    );
+    check!(errors, !is_straight(&state));
+
+    reduce(
+        &mut state,
+        FloorAction::PointerReleased {
+            point: Point::new(0.0, 0.0),
+        },
+    );
+    check!(errors, state.active_curve_handle.is_none());
+
+    reduce(&mut state, FloorAction::StraightenPath { position: 0 });
+    let previous = &state.previous_source_positions[0];
+    check_eq!(errors, previous.curve1_x, None::<f64>);
+    check_eq!(errors, previous.curve2_y, None::<f64>);
+    check!(errors, is_straight(&state));
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn straightening_a_waypoint_path_drops_the_waypoints() {
+    let mut state = bend_path_state();
+    let previous = &mut state.previous_source_positions[0];
+    (previous.movement1_x, previous.movement1_y) = (Some(-1.0), Some(2.0));
+    (previous.movement2_x, previous.movement2_y) = (Some(1.0), Some(2.0));
+    reduce(&mut state, FloorAction::DrawFloor);
+
+    let mut errors = Vec::new();
+
+    check!(errors, !is_straight(&state));
+
+    reduce(&mut state, FloorAction::StraightenPath { position: 0 });
+    let previous = &state.previous_source_positions[0];
+    check_eq!(errors, previous.movement1_x, None::<f64>);
+    check_eq!(errors, previous.movement2_y, None::<f64>);
+    check!(errors, is_straight(&state));
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn releasing_a_handle_outside_the_canvas_lets_go_of_it() {
+    let state = bend_path_state();
+    let handle = state.curve_handles[0].point;
+    let pos = |point: Point| egui::pos2(point.x as f32, point.y as f32);
+    let press = |point: Point| egui::Event::PointerButton {
+        pos: pos(point),
+        button: egui::PointerButton::Primary,
+        pressed: true,
+        modifiers: egui::Modifiers::NONE,
+    };
+    let elsewhere = canvas_point(&state, 0.0, 1.0);
+    let raw_input = egui::RawInput {
+        events: vec![
+            press(handle),
+            egui::Event::PointerButton {
+                pos: egui::pos2(-20.0, -20.0),
+                button: egui::PointerButton::Primary,
+                pressed: false,
+                modifiers: egui::Modifiers::NONE,
+            },
+            press(elsewhere),
+            egui::Event::PointerMoved(pos(Point::new(elsewhere.x + 30.0, elsewhere.y))),
+        ],
+        ..egui::RawInput::default()
+    };
+
+    let context = egui::Context::default();
+    let mut actions = Vec::new();
+    let _ = context.run(raw_input, |ctx| {
+        actions = egui::Area::new("bend-path-release-outside".into())
+            .fixed_pos(egui::pos2(0.0, 0.0))
+            .show(ctx, |ui| {
+                ui.set_min_size(egui::vec2(640.0, 480.0));
+                crate::floor::floor_component::ui::draw(ui, &state)
+            })
+            .inner;
+    });
+
+    let control_moves = actions
+        .iter()
+        .filter(|action| matches!(action, FloorAction::MoveCurveControl { .. }))
+        .count();
+
+    let mut errors = Vec::new();
+
+    check_eq!(errors, control_moves, 1);
+    check!(
+        errors,
+        actions
+            .iter()
+            .any(|action| matches!(action, FloorAction::PointerReleasedWithContext { .. }))
+    );
+
+    assert_no_errors(errors);
+}
@@ -0,0 +1,275 @@
+use crate::floor::assert_near;
+use crate::floor::canvas_point;
+use crate::floor::dancer;
+use crate::floor::floor_component::actions::FloorAction;
+use crate::floor::floor_component::movement_path::point_along_path;
+use crate::floor::floor_component::reducer::reduce;
+use crate::floor::floor_component::state::FloorPosition;
+use crate::floor::floor_component::state::FloorState;
+use crate::floor::floor_component::state::InteractionMode;
+use crate::floor::floor_component::state::PathWaypoint;
+use crate::floor::floor_component::state::Point;
+
+macro_rules! check_eq {
+    ($errors:expr, $left:expr, $right:expr) => {
+        if $left != $right {
+            $errors.push(format!(
+                "{} != {} (left = {:?}, right = {:?})",
+                stringify!($left),
+                stringify!($right),
+                $left,
+                $right
+            ));
+        }
+    };
+}
+
+macro_rules! check {
+    ($errors:expr, $condition:expr) => {
+        let passed: bool = $condition;
+        if !passed {
+            $errors.push(format!("condition failed: {}", stringify!($condition)));
+        }
+    };
+}
+
+fn assert_no_errors(errors: Vec<String>) {
+    assert!(
+        errors.is_empty(),
+        "Assertion failures:\n{}",
+        errors.join("\n")
+    );
+}
+
+fn edit_path_state() -> FloorState {
+    let source_positions = vec![dancer("A", -3.0, 0.0), dancer("B", 0.0, 2.0)];
+    let mut state = FloorState {
+        positions: source_positions
+            .iter()
+            .map(|position| FloorPosition::new(position.x, position.y))
+            .collect(),
+        source_positions,
+        next_source_positions: vec![dancer("A", 3.0, 0.0)],
+        ..FloorState::default()
+    };
+    reduce(&mut state, FloorAction::DrawFloor);
+    reduce(
+        &mut state,
+        FloorAction::SetInteractionMode {
+            mode: InteractionMode::EditPath,
+        },
+    );
+    state
+}
+
+#[test]
+fn waypoint_paths_are_travelled_at_an_even_pace() {
+    let start = Point::new(0.0, 0.0);
+    let waypoints = [Point::new(3.0, 0.0), Point::new(3.0, 1.0)];
+    let end = Point::new(0.0, 1.0);
+
+    let mut errors = Vec::new();
+
+    // The legs are 3, 1 and 3 long, so the middle leg takes a seventh of the time.
+    assert_near(
+        &mut errors,
+        point_along_path(start, &waypoints, end, 3.0 / 7.0),
+        Point::new(3.0, 0.0),
+    );
+    assert_near(
+        &mut errors,
+        point_along_path(start, &waypoints, end, 3.5 / 7.0),
+        Point::new(3.0, 0.5),
+    );
+    assert_near(
+        &mut errors,
+        point_along_path(start, &waypoints, end, 1.0),
+        end,
+    );
+    assert_near(
+        &mut errors,
+        point_along_path(start, &[], Point::new(2.0, 0.0), 0.25),
+        Point::new(0.5, 0.0),
+    );
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn edit_path_mode_offers_handles_for_dancers_moving_on() {
+    let mut state = edit_path_state();
+
+    let mut errors = Vec::new();
+
+    // Only A appears in the next scene; its unset waypoints sit on the straight path.
+    check_eq!(errors, state.path_handles.len(), 2);
+    check!(
+        errors,
+        state.path_handles.iter().all(|handle| !handle.is_set)
+    );
+    assert_near(
+        &mut errors,
+        state.path_handles[0].point,
+        canvas_point(&state, -1.0, 0.0),
+    );
+    assert_near(
+        &mut errors,
+        state.path_handles[1].point,
+        canvas_point(&state, 1.0, 0.0),
+    );
+
+    reduce(
+        &mut state,
+        FloorAction::SelectRectangle {
+            start: Point::new(-1.0, 1.0),
+            end: Point::new(1.0, 3.0),
+        },
+    );
+    check!(errors, state.path_handles.is_empty());
+
+    reduce(&mut state, FloorAction::ClearSelection);
+    reduce(
+        &mut state,
+        FloorAction::SetInteractionMode {
+            mode: InteractionMode::Move,
+        },
+    );
+    check!(errors, state.path_handles.is_empty());
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn dragging_a_handle_sets_the_waypoint_until_released() {
+    let mut state = edit_path_state();
+    state.snap_to_grid = true;
+    state.grid_resolution = 2;
+
+    let mut errors = Vec::new();
+
+    reduce(
+        &mut state,
+        FloorAction::MovePathWaypoint {
+            position: 0,
+            waypoint: PathWaypoint::Second,
+            point: Point::new(1.1, 2.8),
+        },
+    );
+    check_eq!(
+        errors,
+        state.positions[0].movement2,
+        Some(Point::new(1.0, 3.0))
+    );
+    check_eq!(errors, state.positions[0].movement1, None::<Point>);
+    check_eq!(
+        errors,
+        state.active_path_handle,
+        Some((0, PathWaypoint::Second))
+    );
+
+    reduce(
+        &mut state,
+        FloorAction::PointerReleased {
+            point: Point::new(0.0, 0.0),
+        },
+    );
+    check!(errors, state.active_path_handle.is_none());
+
+    reduce(
+        &mut state,
+        FloorAction::ClearPathWaypoint {
+            position: 0,
+            waypoint: PathWaypoint::Second,
+        },
+    );
+    check_eq!(errors, state.positions[0].movement2, None::<Point>);
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn paths_pass_through_the_waypoints() {
+    let mut state = edit_path_state();
+    state.source_positions[0].movement1_x = Some(-3.0);
+    state.source_positions[0].movement1_y = Some(3.0);
+    state.source_positions[0].movement2_x = Some(3.0);
+    state.source_positions[0].movement2_y = Some(3.0);
+    reduce(&mut state, FloorAction::RedrawFloor);
+
+    let mut errors = Vec::new();
+
+    check_eq!(errors, state.colored_path_segments.len(), 3);
+    assert_near(
+        &mut errors,
+        state.colored_path_segments[1].from,
+        canvas_point(&state, -3.0, 3.0),
+    );
+    assert_near(
+        &mut errors,
+        state.colored_path_segments[1].to,
+        canvas_point(&state, 3.0, 3.0),
+    );
+    check!(
+        errors,
+        state.path_handles.iter().all(|handle| handle.is_set)
+    );
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn releasing_a_handle_outside_the_canvas_lets_go_of_it() {
+    let state = edit_path_state();
+    let handle = state.path_handles[0].point;
+    let pos = |point: Point| egui::pos2(point.x as f32, point.y as f32);
+    let press = |point: Point| egui::Event::PointerButton {
+        pos: pos(point),
+        button: egui::PointerButton::Primary,
+        pressed: true,
+        modifiers: egui::Modifiers::NONE,
+    };
+    let elsewhere = canvas_point(&state, 0.0, 1.0);
+    let raw_input = egui::RawInput {
+        events: vec![
+            press(handle),
+            egui::Event::PointerButton {
+                pos: egui::pos2(-20.0, -20.0),
+                button: egui::PointerButton::Primary,
+                pressed: false,
+                modifiers: egui::Modifiers::NONE,
+            },
+            press(elsewhere),
+            egui::Event::PointerMoved(pos(Point::new(elsewhere.x + 30.0, elsewhere.y))),
+        ],
+        ..egui::RawInput::default()
+    };
+
+    let context = egui::Context::default();
+    let mut actions = Vec::new();
+    let _ = context.run(raw_input, |ctx| {
+        actions = egui::Area::new("edit-path-release-outside".into())
+            .fixed_pos(egui::pos2(0.0, 0.0))
+            .show(ctx, |ui| {
+                ui.set_min_size(egui::vec2(640.0, 480.0));
+                crate::floor::floor_component::ui::draw(ui, &state)
+            })
+            .inner;
+    });
+
+    let waypoint_moves = actions
+        .iter()
+        .filter(|action| matches!(action, FloorAction::MovePathWaypoint { .. }))
+        .count();
+
+    let mut errors = Vec::new();
+
+    check_eq!(errors, waypoint_moves, 1);
+    check!(
+        errors,
+        actions
+            .iter()
+            .any(|action| matches!(action, FloorAction::PointerReleasedWithContext { .. }))
+    );
+
+    assert_no_errors(errors);
+}
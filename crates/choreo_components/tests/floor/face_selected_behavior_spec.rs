@@ -0,0 +1,172 @@
+use crate::floor::floor_component::actions::FloorAction;
+use crate::floor::floor_component::orientation::interpolate_orientation;
+use crate::floor::floor_component::reducer::reduce;
+use crate::floor::floor_component::state::FloorPosition;
+use crate::floor::floor_component::state::FloorState;
+use crate::floor::floor_component::state::Point;
+
+macro_rules! check {
+    ($errors:expr, $condition:expr) => {
+        let passed: bool = $condition;
+        if !passed {
+            $errors.push(format!("condition failed: {}", stringify!($condition)));
+        }
+    };
+}
+
+fn assert_no_errors(errors: Vec<String>) {
+    assert!(
+        errors.is_empty(),
+        "Assertion failures:\n{}",
+        errors.join("\n")
+    );
+}
+
+fn is_close(actual: Option<f64>, expected: f64) -> bool {
+    actual.is_some_and(|actual| (actual - expected).abs() < 0.0001)
+}
+
+fn state_with_first_two_selected() -> FloorState {
+    let mut state = FloorState::default();
+    reduce(
+        &mut state,
+        FloorAction::SetPositions {
+            positions: vec![
+                FloorPosition::new(-1.0, 0.0),
+                FloorPosition::new(1.0, 0.0),
+                FloorPosition::new(3.0, -2.0),
+            ],
+        },
+    );
+    reduce(
+        &mut state,
+        FloorAction::SelectRectangle {
+            start: Point::new(-2.0, 1.0),
+            end: Point::new(2.0, -1.0),
+        },
+    );
+    state
+}
+
+#[test]
+fn face_selected_towards_turns_only_the_selected_dancers() {
+    let mut state = state_with_first_two_selected();
+
+    reduce(
+        &mut state,
+        FloorAction::FaceSelectedTowards {
+            target: Point::new(1.0, 4.0),
+        },
+    );
+
+    let mut errors = Vec::new();
+
+    check!(errors, is_close(state.positions[0].orientation, 26.5651));
+    check!(errors, is_close(state.positions[1].orientation, 0.0));
+    check!(errors, state.positions[2].orientation.is_none());
+
+    reduce(
+        &mut state,
+        FloorAction::FaceSelectedTowards {
+            target: Point::new(1.0, 0.0),
+        },
+    );
+
+    // The second dancer stands on the target, so it keeps its facing.
+    check!(errors, is_close(state.positions[0].orientation, 90.0));
+    check!(errors, is_close(state.positions[1].orientation, 0.0));
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn face_selected_towards_snaps_to_eighth_turns_with_snap_to_grid() {
+    let mut state = state_with_first_two_selected();
+    reduce(
+        &mut state,
+        FloorAction::SetSnapToGrid {
+            enabled: true,
+            resolution: 2,
+        },
+    );
+
+    reduce(
+        &mut state,
+        FloorAction::FaceSelectedTowards {
+            target: Point::new(1.0, 4.0),
+        },
+    );
+
+    let mut errors = Vec::new();
+
+    check!(errors, is_close(state.positions[0].orientation, 45.0));
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn rotating_the_selection_turns_the_dancers_with_it() {
+    let mut state = state_with_first_two_selected();
+    reduce(
+        &mut state,
+        FloorAction::FaceSelectedTowards {
+            target: Point::new(0.0, 10.0),
+        },
+    );
+
+    // A quarter turn counterclockwise around the selection center.
+    reduce(
+        &mut state,
+        FloorAction::RotateSelectedAroundCenter {
+            start: Point::new(1.0, 0.0),
+            end: Point::new(0.0, 1.0),
+        },
+    );
+
+    let mut errors = Vec::new();
+
+    check!(errors, (state.positions[1].y - 1.0).abs() < 0.0001);
+    check!(
+        errors,
+        is_close(state.positions[1].orientation, 270.0 - 5.7106)
+    );
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn audio_interpolation_turns_along_the_shorter_way_round() {
+    let mut state = FloorState::default();
+    reduce(
+        &mut state,
+        FloorAction::InterpolateAudioPosition {
+            from: vec![FloorPosition {
+                orientation: Some(350.0),
+                ..FloorPosition::new(0.0, 0.0)
+            }],
+            to: vec![FloorPosition {
+                orientation: Some(30.0),
+                ..FloorPosition::new(0.0, 0.0)
+            }],
+            progress: 0.25,
+        },
+    );
+
+    let mut errors = Vec::new();
+
+    check!(
+        errors,
+        is_close(state.interpolated_positions[0].orientation, 0.0)
+    );
+    check!(
+        errors,
+        is_close(interpolate_orientation(Some(90.0), Some(260.0), 0.5), 175.0)
+    );
+    check!(
+        errors,
+        is_close(interpolate_orientation(None, Some(90.0), 0.5), 90.0)
+    );
+    check!(errors, interpolate_orientation(None, None, 0.5).is_none());
+
+    assert_no_errors(errors);
+}
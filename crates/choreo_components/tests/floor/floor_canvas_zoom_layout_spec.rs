@@ -60,6 +60,7 @@ fn check_zoom_scaled_measurement(
     );
 }
 
+#[allow(clippy::too_many_arguments)]
 fn check_measurement_scales_like_panel_dimension_between(
     errors: &mut Vec<String>,
     state: &mut FloorState,
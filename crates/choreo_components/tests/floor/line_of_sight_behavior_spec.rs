@@ -0,0 +1,221 @@
+use choreo_master_mobile_json::FrontPosition;
+
+use crate::floor::dancer;
+use crate::floor::floor_component::actions::FloorAction;
+use crate::floor::floor_component::line_of_sight::SightSource;
+use crate::floor::floor_component::line_of_sight::analyze_sight_lines;
+use crate::floor::floor_component::reducer::reduce;
+use crate::floor::floor_component::state::FloorState;
+use crate::floor::floor_component::state::InteractionMode;
+use crate::floor::floor_component::state::Point;
+
+macro_rules! check_eq {
+    ($errors:expr, $left:expr, $right:expr) => {
+        if $left != $right {
+            $errors.push(format!(
+                "{} != {} (left = {:?}, right = {:?})",
+                stringify!($left),
+                stringify!($right),
+                $left,
+                $right
+            ));
+        }
+    };
+}
+
+macro_rules! check {
+    ($errors:expr, $condition:expr) => {
+        let passed: bool = $condition;
+        if !passed {
+            $errors.push(format!("condition failed: {}", stringify!($condition)));
+        }
+    };
+}
+
+fn assert_no_errors(errors: Vec<String>) {
+    assert!(
+        errors.is_empty(),
+        "Assertion failures:\n{}",
+        errors.join("\n")
+    );
+}
+
+// Front dancer, the dancer straight behind, and one off to the side.
+fn line_up() -> Vec<Point> {
+    vec![
+        Point::new(0.0, 2.0),
+        Point::new(0.3, 0.0),
+        Point::new(2.0, 0.0),
+    ]
+}
+
+#[test]
+fn dancers_behind_others_are_hidden_from_the_front() {
+    let analysis = analyze_sight_lines(&line_up(), 0.5, SightSource::Front(FrontPosition::Top));
+
+    let mut errors = Vec::new();
+
+    check_eq!(errors, analysis.hidden, vec![1]);
+    check_eq!(errors, analysis.cones.len(), 1);
+    check_eq!(errors, analysis.cones[0].blocker, 0);
+    check_eq!(
+        errors,
+        analysis.cones[0].corners,
+        [
+            Point::new(-0.5, 2.0),
+            Point::new(0.5, 2.0),
+            Point::new(0.5, 0.0),
+            Point::new(-0.5, 0.0),
+        ]
+    );
+
+    let from_back = analyze_sight_lines(&line_up(), 0.5, SightSource::Front(FrontPosition::Bottom));
+    check_eq!(errors, from_back.hidden, vec![0]);
+
+    let from_side = analyze_sight_lines(&line_up(), 0.5, SightSource::Front(FrontPosition::Right));
+    check_eq!(errors, from_side.hidden, vec![1]);
+    check_eq!(errors, from_side.cones[0].blocker, 2);
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn a_seat_looks_along_rays_instead_of_straight_lines() {
+    let positions = vec![Point::new(0.0, 5.0), Point::new(0.0, 0.0)];
+
+    let mut errors = Vec::new();
+
+    let in_line = analyze_sight_lines(&positions, 0.5, SightSource::Seat(Point::new(0.0, 10.0)));
+    check_eq!(errors, in_line.hidden, vec![1]);
+    // The shadow widens towards the hidden dancer.
+    let corners = in_line.cones[0].corners;
+    check!(
+        errors,
+        (corners[2].x - corners[3].x).abs() > (corners[0].x - corners[1].x).abs()
+    );
+
+    let from_the_side =
+        analyze_sight_lines(&positions, 0.5, SightSource::Seat(Point::new(10.0, 0.0)));
+    check!(errors, from_the_side.hidden.is_empty());
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn line_of_sight_mode_marks_hidden_dancers_and_follows_the_seat() {
+    let mut state = FloorState {
+        source_positions: vec![
+            dancer("A", 0.0, 2.0),
+            dancer("B", 0.3, 0.0),
+            dancer("C", 2.0, 0.0),
+        ],
+        dancer_size: 1.0,
+        ..FloorState::default()
+    };
+    reduce(&mut state, FloorAction::DrawFloor);
+
+    let mut errors = Vec::new();
+
+    check!(errors, state.sight_lines.hidden.is_empty());
+
+    reduce(
+        &mut state,
+        FloorAction::SetInteractionMode {
+            mode: InteractionMode::LineOfSight,
+        },
+    );
+    let hidden: Vec<bool> = state
+        .rendered_positions
+        .iter()
+        .map(|position| position.is_hidden)
+        .collect();
+    check_eq!(errors, hidden, vec![false, true, false]);
+    check_eq!(errors, state.blocking_cone_polygons.len(), 1);
+
+    // From a seat off to the right, C now stands in front of B.
+    reduce(
+        &mut state,
+        FloorAction::SetSightSeat {
+            seat: Some(Point::new(8.0, 0.0)),
+        },
+    );
+    check_eq!(errors, state.sight_lines.hidden, vec![1]);
+    check_eq!(errors, state.sight_lines.cones[0].blocker, 2);
+
+    reduce(
+        &mut state,
+        FloorAction::SetInteractionMode {
+            mode: InteractionMode::None,
+        },
+    );
+    check!(errors, state.blocking_cone_polygons.is_empty());
+    check!(
+        errors,
+        state
+            .rendered_positions
+            .iter()
+            .all(|position| !position.is_hidden)
+    );
+
+    assert_no_errors(errors);
+}
+
+#[test]
+fn tapping_the_chosen_seat_again_looks_from_the_whole_audience() {
+    let mut state = FloorState {
+        source_positions: vec![dancer("A", 0.0, 2.0)],
+        ..FloorState::default()
+    };
+    reduce(&mut state, FloorAction::DrawFloor);
+    reduce(
+        &mut state,
+        FloorAction::SetInteractionMode {
+            mode: InteractionMode::LineOfSight,
+        },
+    );
+    reduce(
+        &mut state,
+        FloorAction::SetSightSeat {
+            seat: Some(Point::new(1.0, 1.0)),
+        },
+    );
+    let scale = (state.floor_width / 10.0).min(state.floor_height / 10.0);
+    let seat = Point::new(state.center_x + scale, state.center_y - scale);
+
+    let seat_after_tap = |point: Point| {
+        let raw_input = egui::RawInput {
+            events: vec![egui::Event::PointerButton {
+                pos: egui::pos2(point.x as f32, point.y as f32),
+                button: egui::PointerButton::Primary,
+                pressed: true,
+                modifiers: egui::Modifiers::NONE,
+            }],
+            ..egui::RawInput::default()
+        };
+        let context = egui::Context::default();
+        let mut actions = Vec::new();
+        let _ = context.run(raw_input, |ctx| {
+            actions = egui::Area::new("line-of-sight-seat-tap".into())
+                .fixed_pos(egui::pos2(0.0, 0.0))
+                .show(ctx, |ui| {
+                    ui.set_min_size(egui::vec2(640.0, 480.0));
+                    crate::floor::floor_component::ui::draw(ui, &state)
+                })
+                .inner;
+        });
+        actions.into_iter().find_map(|action| match action {
+            FloorAction::SetSightSeat { seat } => Some(seat),
+            _ => None,
+        })
+    };
+
+    let mut errors = Vec::new();
+
+    check_eq!(errors, seat_after_tap(seat), Some(None::<Point>));
+    check!(
+        errors,
+        seat_after_tap(Point::new(seat.x + 60.0, seat.y)).is_some_and(|seat| seat.is_some())
+    );
+
+    assert_no_errors(errors);
+}
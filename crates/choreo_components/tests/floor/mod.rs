@@ -11,6 +11,10 @@ use rspec::Runner;
 
 pub use rspec::report::Report;
 
+use floor_component::state::FloorState;
+use floor_component::state::Point;
+use floor_component::state::SceneRenderPosition;
+
 pub fn run_suite<T>(suite: &rspec::block::Suite<T>) -> rspec::report::SuiteReport
 where
     T: Clone + Send + Sync + std::fmt::Debug,
@@ -25,11 +29,16 @@ where
     runner.run(suite)
 }
 
+pub mod arrange_selected_behavior_spec;
 pub mod audio_position_interpolation_spec;
+pub mod bend_path_behavior_spec;
 pub mod draw_floor_behavior_spec;
+pub mod edit_path_behavior_spec;
+pub mod face_selected_behavior_spec;
 pub mod floor_canvas_zoom_layout_spec;
 pub mod floor_public_api_parity_spec;
 pub mod gesture_handling_behavior_spec;
+pub mod line_of_sight_behavior_spec;
 pub mod move_positions_behavior_spec;
 pub mod move_positions_feature_spec;
 pub mod place_position_behavior_spec;
@@ -41,3 +50,37 @@ pub mod scale_around_dancer_behavior_spec;
 pub mod scale_positions_behavior_spec;
 pub mod test_pointer_event_args_spec;
 pub mod ui_interaction_parity_spec;
+
+pub fn dancer(shortcut: &str, x: f64, y: f64) -> SceneRenderPosition {
+    SceneRenderPosition {
+        dancer_key: Some(format!("shortcut:{shortcut}")),
+        dancer_name: shortcut.to_string(),
+        shortcut: shortcut.to_string(),
+        x,
+        y,
+        curve1_x: None,
+        curve1_y: None,
+        curve2_x: None,
+        curve2_y: None,
+        movement1_x: None,
+        movement1_y: None,
+        movement2_x: None,
+        movement2_y: None,
+        orientation: None,
+        fill_color: [220, 20, 60, 255],
+        border_color: [128, 0, 0, 255],
+        text_color: [255, 255, 255, 255],
+        has_dancer: true,
+    }
+}
+
+pub fn canvas_point(state: &FloorState, x: f64, y: f64) -> Point {
+    let scale = (state.floor_width / 10.0).min(state.floor_height / 10.0);
+    Point::new(state.center_x + x * scale, state.center_y - y * scale)
+}
+
+pub fn assert_near(errors: &mut Vec<String>, actual: Point, expected: Point) {
+    if (actual.x - expected.x).abs() > 1e-9 || (actual.y - expected.y).abs() > 1e-9 {
+        errors.push(format!("{actual:?} is not near {expected:?}"));
+    }
+}
@@ -99,6 +99,24 @@ fn move_positions_feature_spec() {
             assert_no_errors(errors);
         });
 
+        spec.it("selects every position at once", |_| {
+            let mut state = setup_state();
+
+            select_rectangle(&mut state, Point::new(-2.0, 2.0), Point::new(2.0, 0.0));
+            reduce(&mut state, FloorAction::SelectAll);
+
+            let mut errors = Vec::new();
+
+            check_eq!(
+                errors,
+                state.selected_positions,
+                (0..state.positions.len()).collect::<Vec<_>>()
+            );
+            check!(errors, state.selection_rectangle.is_none());
+
+            assert_no_errors(errors);
+        });
+
         spec.it("moves a single position when dragging", |_| {
             let mut state = setup_state();
             state.selected_positions = vec![0];
@@ -29,7 +29,8 @@ macro_rules! check_eq {
 
 macro_rules! check {
     ($errors:expr, $condition:expr) => {
-        if !$condition {
+        let passed: bool = $condition;
+        if !passed {
             $errors.push(format!("condition failed: {}", stringify!($condition)));
         }
     };
@@ -378,6 +379,11 @@ fn legend_panel_height_fits_scene_derived_rows() {
                 curve1_y: None,
                 curve2_x: None,
                 curve2_y: None,
+                movement1_x: None,
+                movement1_y: None,
+                movement2_x: None,
+                movement2_y: None,
+                orientation: None,
                 fill_color: [30, 144, 255, 255],
                 border_color: [0, 64, 128, 255],
                 text_color: [255, 255, 255, 255],
@@ -425,6 +431,11 @@ fn side_position_labels_scale_with_floor_transform() {
                 curve1_y: None,
                 curve2_x: None,
                 curve2_y: None,
+                movement1_x: None,
+                movement1_y: None,
+                movement2_x: None,
+                movement2_y: None,
+                orientation: None,
                 fill_color: [220, 20, 60, 255],
                 border_color: [128, 0, 0, 255],
                 text_color: [255, 255, 255, 255],
@@ -440,6 +451,11 @@ fn side_position_labels_scale_with_floor_transform() {
                 curve1_y: None,
                 curve2_x: None,
                 curve2_y: None,
+                movement1_x: None,
+                movement1_y: None,
+                movement2_x: None,
+                movement2_y: None,
+                orientation: None,
                 fill_color: [30, 144, 255, 255],
                 border_color: [0, 64, 128, 255],
                 text_color: [255, 255, 255, 255],
@@ -623,6 +639,11 @@ fn resize_overlay_state(svg_path: &str) -> FloorState {
                 curve1_y: None,
                 curve2_x: None,
                 curve2_y: None,
+                movement1_x: None,
+                movement1_y: None,
+                movement2_x: None,
+                movement2_y: None,
+                orientation: None,
                 fill_color: [220, 20, 60, 255],
                 border_color: [128, 0, 0, 255],
                 text_color: [255, 255, 255, 255],
@@ -638,6 +659,11 @@ fn resize_overlay_state(svg_path: &str) -> FloorState {
                 curve1_y: None,
                 curve2_x: None,
                 curve2_y: None,
+                movement1_x: None,
+                movement1_y: None,
+                movement2_x: None,
+                movement2_y: None,
+                orientation: None,
                 fill_color: [30, 144, 255, 255],
                 border_color: [0, 64, 128, 255],
                 text_color: [255, 255, 255, 255],
@@ -675,6 +701,11 @@ fn header_overlay_sits_above_top_side_position_labels() {
                 curve1_y: None,
                 curve2_x: None,
                 curve2_y: None,
+                movement1_x: None,
+                movement1_y: None,
+                movement2_x: None,
+                movement2_y: None,
+                orientation: None,
                 fill_color: [220, 20, 60, 255],
                 border_color: [128, 0, 0, 255],
                 text_color: [255, 255, 255, 255],
@@ -690,6 +721,11 @@ fn header_overlay_sits_above_top_side_position_labels() {
                 curve1_y: None,
                 curve2_x: None,
                 curve2_y: None,
+                movement1_x: None,
+                movement1_y: None,
+                movement2_x: None,
+                movement2_y: None,
+                orientation: None,
                 fill_color: [30, 144, 255, 255],
                 border_color: [0, 64, 128, 255],
                 text_color: [255, 255, 255, 255],
@@ -737,6 +773,11 @@ fn draw_floor_projects_scene_render_positions_into_labels_legend_and_paths() {
                 curve1_y: None,
                 curve2_x: None,
                 curve2_y: None,
+                movement1_x: None,
+                movement1_y: None,
+                movement2_x: None,
+                movement2_y: None,
+                orientation: None,
                 fill_color: [220, 20, 60, 255],
                 border_color: [128, 0, 0, 255],
                 text_color: [255, 255, 255, 255],
@@ -752,6 +793,11 @@ fn draw_floor_projects_scene_render_positions_into_labels_legend_and_paths() {
                 curve1_y: None,
                 curve2_x: None,
                 curve2_y: None,
+                movement1_x: None,
+                movement1_y: None,
+                movement2_x: None,
+                movement2_y: None,
+                orientation: None,
                 fill_color: [30, 144, 255, 255],
                 border_color: [0, 64, 128, 255],
                 text_color: [255, 255, 255, 255],
@@ -769,6 +815,11 @@ fn draw_floor_projects_scene_render_positions_into_labels_legend_and_paths() {
                 curve1_y: None,
                 curve2_x: None,
                 curve2_y: None,
+                movement1_x: None,
+                movement1_y: None,
+                movement2_x: None,
+                movement2_y: None,
+                orientation: None,
                 fill_color: [220, 20, 60, 255],
                 border_color: [128, 0, 0, 255],
                 text_color: [255, 255, 255, 255],
@@ -784,6 +835,11 @@ fn draw_floor_projects_scene_render_positions_into_labels_legend_and_paths() {
                 curve1_y: None,
                 curve2_x: None,
                 curve2_y: None,
+                movement1_x: None,
+                movement1_y: None,
+                movement2_x: None,
+                movement2_y: None,
+                orientation: None,
                 fill_color: [30, 144, 255, 255],
                 border_color: [0, 64, 128, 255],
                 text_color: [255, 255, 255, 255],
@@ -801,6 +857,11 @@ fn draw_floor_projects_scene_render_positions_into_labels_legend_and_paths() {
                 curve1_y: Some(1.5),
                 curve2_x: None,
                 curve2_y: None,
+                movement1_x: None,
+                movement1_y: None,
+                movement2_x: None,
+                movement2_y: None,
+                orientation: None,
                 fill_color: [220, 20, 60, 255],
                 border_color: [128, 0, 0, 255],
                 text_color: [255, 255, 255, 255],
@@ -816,6 +877,11 @@ fn draw_floor_projects_scene_render_positions_into_labels_legend_and_paths() {
                 curve1_y: None,
                 curve2_x: None,
                 curve2_y: None,
+                movement1_x: None,
+                movement1_y: None,
+                movement2_x: None,
+                movement2_y: None,
+                orientation: None,
                 fill_color: [30, 144, 255, 255],
                 border_color: [0, 64, 128, 255],
                 text_color: [255, 255, 255, 255],
@@ -894,6 +960,11 @@ fn draw_floor_renders_dancer_fills_and_contrast_text_colors() {
                 curve1_y: None,
                 curve2_x: None,
                 curve2_y: None,
+                movement1_x: None,
+                movement1_y: None,
+                movement2_x: None,
+                movement2_y: None,
+                orientation: None,
                 fill_color: [250, 250, 250, 255],
                 border_color: [20, 20, 20, 255],
                 text_color: [255, 255, 255, 255],
@@ -909,6 +980,11 @@ fn draw_floor_renders_dancer_fills_and_contrast_text_colors() {
                 curve1_y: None,
                 curve2_x: None,
                 curve2_y: None,
+                movement1_x: None,
+                movement1_y: None,
+                movement2_x: None,
+                movement2_y: None,
+                orientation: None,
                 fill_color: [16, 24, 32, 255],
                 border_color: [240, 240, 240, 255],
                 text_color: [0, 0, 0, 255],
@@ -924,6 +1000,11 @@ fn draw_floor_renders_dancer_fills_and_contrast_text_colors() {
                 curve1_y: None,
                 curve2_x: None,
                 curve2_y: None,
+                movement1_x: None,
+                movement1_y: None,
+                movement2_x: None,
+                movement2_y: None,
+                orientation: None,
                 fill_color: [255, 0, 0, 0],
                 border_color: [120, 0, 0, 255],
                 text_color: [0, 0, 0, 255],
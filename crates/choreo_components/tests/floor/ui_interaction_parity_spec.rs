@@ -5,7 +5,8 @@ use crate::floor::floor_component::state::Point;
 
 macro_rules! check {
     ($errors:expr, $condition:expr) => {
-        if !$condition {
+        let passed: bool = $condition;
+        if !passed {
             $errors.push(format!("condition failed: {}", stringify!($condition)));
         }
     };
@@ -242,3 +243,43 @@ fn secondary_pointer_input_keeps_button_context_and_does_not_start_pan() {
 
     assert_no_errors(errors);
 }
+
+#[test]
+fn primary_release_outside_the_canvas_ends_a_press_that_started_on_it() {
+    let release_outside = |state: &FloorState| {
+        draw_actions(
+            state,
+            egui::RawInput {
+                screen_rect: Some(egui::Rect::from_min_size(
+                    egui::pos2(0.0, 0.0),
+                    egui::vec2(640.0, 480.0),
+                )),
+                events: vec![egui::Event::PointerButton {
+                    pos: egui::pos2(-20.0, -20.0),
+                    button: egui::PointerButton::Primary,
+                    pressed: false,
+                    modifiers: egui::Modifiers::NONE,
+                }],
+                ..egui::RawInput::default()
+            },
+        )
+    };
+    let is_release =
+        |action: &FloorAction| matches!(action, FloorAction::PointerReleasedWithContext { .. });
+
+    let idle = FloorState::default();
+    let mut pressed = FloorState::default();
+    reduce(
+        &mut pressed,
+        FloorAction::PointerPressed {
+            point: Point::new(200.0, 150.0),
+        },
+    );
+
+    let mut errors = Vec::new();
+
+    check!(errors, !release_outside(&idle).iter().any(is_release));
+    check!(errors, release_outside(&pressed).iter().any(is_release));
+
+    assert_no_errors(errors);
+}
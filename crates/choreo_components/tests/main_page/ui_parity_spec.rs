@@ -3,6 +3,7 @@ use crate::main_page::actions::ChoreoMainAction;
 use crate::main_page::reducer::reduce;
 use crate::main_page::state::ChoreoMainState;
 use crate::main_page::state::InteractionMode;
+use crate::main_page::ui::arrange_menu_entries;
 use crate::main_page::ui::audio_panel_rect;
 use crate::main_page::ui::drawer_host_rect;
 use crate::main_page::ui::drawer_host_state;
@@ -31,6 +32,9 @@ use choreo_components::choreo_main::actions::OpenAudioRequested;
 use choreo_components::choreo_main::actions::OpenChoreoRequested;
 use choreo_components::choreography_settings::actions::ChoreographySettingsAction;
 use choreo_components::choreography_settings::ui::drawer_width_token as settings_drawer_width_token;
+use choreo_components::floor::actions::FloorAction;
+use choreo_components::floor::state::Alignment;
+use choreo_components::floor::state::MirrorAxis;
 use choreo_components::nav_bar::translations::nav_bar_translations;
 use choreo_components::scenes::actions::ScenesAction;
 use choreo_master_mobile_json::Color;
@@ -149,16 +153,32 @@ fn ui_parity_spec() {
         spec.it("keeps the expected top bar action order", |_| {
             let mut errors = Vec::new();
 
-            check_eq!(errors, top_bar_action_count(), 6);
+            check_eq!(errors, top_bar_action_count(), 8);
             check_eq!(
                 errors,
                 top_bar_action_icon_tokens(false),
-                ["menu", "edit", "home", "image", "play_circle"]
+                [
+                    "menu",
+                    "undo",
+                    "redo",
+                    "edit",
+                    "home",
+                    "image",
+                    "play_circle"
+                ]
             );
             check_eq!(
                 errors,
                 top_bar_action_icon_tokens(true),
-                ["close", "edit", "home", "image", "play_circle"]
+                [
+                    "close",
+                    "undo",
+                    "redo",
+                    "edit",
+                    "home",
+                    "image",
+                    "play_circle"
+                ]
             );
 
             assert_no_errors(errors);
@@ -171,6 +191,8 @@ fn ui_parity_spec() {
                 errors,
                 top_bar_action_icon_uris(),
                 [
+                    "bytes://top_bar/undo.svg",
+                    "bytes://top_bar/redo.svg",
                     "bytes://top_bar/settings.svg",
                     "bytes://top_bar/home.svg",
                     "bytes://top_bar/image.svg",
@@ -195,8 +217,50 @@ fn ui_parity_spec() {
                     "Rotate around dancer",
                     "Scale",
                     "Line of sight",
+                    "Set facing",
+                    "Edit path",
+                    "Bend path",
+                ]
+            );
+
+            assert_no_errors(errors);
+        });
+
+        spec.it("lists the arrange menu entries in order", |_| {
+            let strings = nav_bar_translations("en");
+            let entries = arrange_menu_entries(&strings);
+            let mut errors = Vec::new();
+
+            check_eq!(
+                errors,
+                entries.iter().map(|(label, _)| *label).collect::<Vec<_>>(),
+                vec![
+                    "Align left",
+                    "Align right",
+                    "Align to the front",
+                    "Align to the back",
+                    "Center on the stage",
+                    "Space evenly from left to right",
+                    "Space evenly from front to back",
+                    "Arrange on a circle",
+                    "Mirror left and right",
+                    "Mirror front and back",
                 ]
             );
+            check_eq!(
+                errors,
+                entries[0].1,
+                FloorAction::AlignSelected {
+                    alignment: Alignment::Left,
+                }
+            );
+            check_eq!(
+                errors,
+                entries[9].1,
+                FloorAction::MirrorSelected {
+                    axis: MirrorAxis::FrontBack,
+                }
+            );
 
             assert_no_errors(errors);
         });
@@ -538,6 +602,7 @@ fn ui_parity_spec() {
                 check!(errors, pane_state.scenes[0].variations.is_empty());
                 check!(errors, pane_state.scenes[0].current_variation.is_empty());
                 check!(errors, !pane_state.can_save_choreo);
+                check!(errors, pane_state.can_save_choreo_as);
                 check!(errors, pane_state.can_navigate_to_settings);
                 check!(errors, pane_state.can_navigate_to_dancer_settings);
 
@@ -606,6 +671,11 @@ fn ui_parity_spec() {
                     map_scene_pane_action(ScenesAction::RequestSaveChoreography),
                     Some(ChoreoMainAction::RequestSaveChoreo)
                 );
+                check_eq!(
+                    errors,
+                    map_scene_pane_action(ScenesAction::RequestSaveChoreographyAs),
+                    Some(ChoreoMainAction::RequestSaveChoreoAs)
+                );
                 check_eq!(
                     errors,
                     map_scene_pane_action(ScenesAction::InsertScene {
@@ -21,7 +21,8 @@ fn unchecked_geometry_keeps_three_parallel_bars() {
 
     macro_rules! check {
         ($errors:expr, $condition:expr) => {
-            if !$condition {
+            let passed: bool = $condition;
+            if !passed {
                 $errors.push(format!("{} is false", stringify!($condition)));
             }
         };
@@ -79,7 +80,8 @@ fn checked_geometry_collapses_top_and_bottom_to_middle_with_rotations() {
 
     macro_rules! check {
         ($errors:expr, $condition:expr) => {
-            if !$condition {
+            let passed: bool = $condition;
+            if !passed {
                 $errors.push(format!("{} is false", stringify!($condition)));
             }
         };
@@ -114,7 +116,8 @@ fn partial_transition_geometry_interpolates_between_unchecked_and_checked() {
 
     macro_rules! check {
         ($errors:expr, $condition:expr) => {
-            if !$condition {
+            let passed: bool = $condition;
+            if !passed {
                 $errors.push(format!("{} is false", stringify!($condition)));
             }
         };
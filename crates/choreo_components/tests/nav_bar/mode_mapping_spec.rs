@@ -41,6 +41,9 @@ fn mode_mapping_round_trips_all_modes() {
         InteractionMode::RotateAroundDancer,
         InteractionMode::Scale,
         InteractionMode::LineOfSight,
+        InteractionMode::Face,
+        InteractionMode::EditPath,
+        InteractionMode::BendPath,
     ];
 
     let mut errors = Vec::new();
@@ -47,6 +47,15 @@ fn nav_bar_translations_bind_user_facing_strings() {
     );
     check_eq!(errors, strings.open_image_tooltip, "Open floor SVG");
     check_eq!(errors, strings.open_audio_tooltip, "Open audio file");
+    check_eq!(errors, strings.undo_tooltip, "Undo (Ctrl+Z)");
+    check_eq!(errors, strings.redo_tooltip, "Redo (Ctrl+Shift+Z)");
+    check_eq!(errors, strings.unsaved_changes_tooltip, "Unsaved changes");
+    check_eq!(errors, strings.arrange_label, "Arrange");
+    check_eq!(
+        errors,
+        strings.arrange_tooltip,
+        "Align, distribute or mirror the selected positions"
+    );
     check_eq!(errors, strings.mode_label, "Mode");
 
     assert_no_errors(errors);
@@ -76,6 +85,21 @@ fn nav_bar_mode_translations_preserve_slint_mapping_intent() {
         mode_text(&strings, InteractionMode::LineOfSight),
         "Line of sight"
     );
+    check_eq!(
+        errors,
+        mode_text(&strings, InteractionMode::Face),
+        "Set facing"
+    );
+    check_eq!(
+        errors,
+        mode_text(&strings, InteractionMode::EditPath),
+        "Edit path"
+    );
+    check_eq!(
+        errors,
+        mode_text(&strings, InteractionMode::BendPath),
+        "Bend path"
+    );
 
     assert_no_errors(errors);
 }
@@ -172,6 +172,17 @@ fn mode_labels_map_to_translation_keys() {
         mode_label(InteractionMode::LineOfSight),
         "ModeLineOfSight"
     );
+    check_eq!(errors, mode_label(InteractionMode::Face), "ModeFace");
+    check_eq!(
+        errors,
+        mode_label(InteractionMode::EditPath),
+        "ModeEditPath"
+    );
+    check_eq!(
+        errors,
+        mode_label(InteractionMode::BendPath),
+        "ModeBendPath"
+    );
 
     assert!(
         errors.is_empty(),
@@ -219,6 +230,9 @@ fn nav_bar_exposes_all_mode_options_in_order() {
             "Rotate around dancer",
             "Scale",
             "Line of sight",
+            "Set facing",
+            "Edit path",
+            "Bend path",
         ]
     );
 }
@@ -0,0 +1,53 @@
+use serial_test::serial;
+use time::OffsetDateTime;
+
+use crate::preferences::preferences::FileRecoveryStore;
+use crate::preferences::preferences::RecoverySnapshot;
+use crate::preferences::preferences::RecoveryStore;
+
+fn test_app_name(test_case: &str) -> String {
+    let pid = std::process::id();
+    format!("rchoreo-egui-recovery-{test_case}-{pid}")
+}
+
+fn clear_recovery_directory(store: &FileRecoveryStore) {
+    if let Some(parent) = store.path().parent() {
+        let _ = std::fs::remove_dir_all(parent);
+    }
+}
+
+#[test]
+#[serial]
+fn file_recovery_store_round_trips_snapshot_across_instances() {
+    let app_name = test_app_name("round-trip");
+    let first = FileRecoveryStore::new(&app_name);
+    clear_recovery_directory(&first);
+
+    let snapshot = RecoverySnapshot {
+        file_path: Some("demo.choreo".to_string()),
+        saved_at: OffsetDateTime::from_unix_timestamp(1_700_000_000)
+            .expect("timestamp should be valid"),
+        contents: "{\"Name\":\"Demo\"}".to_string(),
+    };
+    first.store(&snapshot);
+
+    let second = FileRecoveryStore::new(&app_name);
+    assert_eq!(second.load(), Some(snapshot));
+
+    second.clear();
+    assert_eq!(first.load(), None);
+
+    clear_recovery_directory(&first);
+}
+
+#[test]
+#[serial]
+fn file_recovery_store_clear_without_snapshot_is_a_no_op() {
+    let app_name = test_app_name("empty");
+    let store = FileRecoveryStore::new(&app_name);
+    clear_recovery_directory(&store);
+
+    store.clear();
+
+    assert_eq!(store.load(), None);
+}
@@ -0,0 +1,204 @@
+use choreo_models::PositionModel;
+
+use super::actions::ScenesAction;
+use super::auto_assign::AutoAssignError;
+use super::build_dancer;
+use super::build_position;
+use super::choreography_with_scenes;
+use super::create_state;
+use super::dancer_names;
+use super::dancer_position;
+use super::reducer::reduce;
+use super::scene_model;
+use super::state::ScenesState;
+use super::translations::auto_assign_error_message;
+use crate::scenes::Report;
+
+macro_rules! check_eq {
+    ($errors:expr, $left:expr, $right:expr) => {
+        if $left != $right {
+            $errors.push(format!(
+                "{} != {} (left = {:?}, right = {:?})",
+                stringify!($left),
+                stringify!($right),
+                $left,
+                $right
+            ));
+        }
+    };
+}
+
+fn assert_no_errors(errors: Vec<String>) {
+    assert!(
+        errors.is_empty(),
+        "Assertion failures:\n{}",
+        errors.join("\n")
+    );
+}
+
+fn load_and_select_second(
+    previous: Vec<PositionModel>,
+    current: Vec<PositionModel>,
+) -> ScenesState {
+    let mut state = create_state();
+    let mut choreography = choreography_with_scenes(
+        "Test",
+        vec![
+            scene_model(1, "First", None, previous),
+            scene_model(2, "Second", None, current),
+        ],
+    );
+    choreography.dancers = vec![
+        build_dancer(1, "A"),
+        build_dancer(2, "B"),
+        build_dancer(3, "C"),
+    ];
+    reduce(
+        &mut state,
+        ScenesAction::LoadScenes {
+            choreography: Box::new(choreography),
+        },
+    );
+    reduce(&mut state, ScenesAction::SelectScene { index: 1 });
+    state
+}
+
+#[test]
+fn auto_assign_dancers_behavior_spec() {
+    let suite = rspec::describe("auto assign dancers", (), |spec| {
+        spec.it(
+            "assigns unplaced dancers to open spots with the shortest total distance",
+            |_| {
+                let a = build_dancer(1, "A");
+                let b = build_dancer(2, "B");
+                let c = build_dancer(3, "C");
+                let mut state = load_and_select_second(
+                    vec![
+                        dancer_position(&a, 0.0, 0.0),
+                        dancer_position(&b, 10.0, 0.0),
+                        dancer_position(&c, 5.0, 5.0),
+                    ],
+                    vec![
+                        build_position(9.0, 1.0),
+                        dancer_position(&c, 5.0, 4.0),
+                        build_position(1.0, 1.0),
+                    ],
+                );
+
+                reduce(&mut state, ScenesAction::AutoAssignDancers);
+
+                let mut errors = Vec::new();
+                check_eq!(errors, state.auto_assign_error, None::<AutoAssignError>);
+                check_eq!(
+                    errors,
+                    dancer_names(&state.choreography.scenes[1].positions),
+                    vec![
+                        Some("B".to_string()),
+                        Some("C".to_string()),
+                        Some("A".to_string())
+                    ]
+                );
+                check_eq!(
+                    errors,
+                    state
+                        .selected_scene
+                        .as_ref()
+                        .map(|scene| dancer_names(&scene.positions)),
+                    Some(dancer_names(&state.choreography.scenes[1].positions))
+                );
+                check_eq!(errors, state.redraw_floor_requested, true);
+                check_eq!(errors, state.can_auto_assign_dancers, false);
+                assert_no_errors(errors);
+            },
+        );
+
+        spec.it("keeps spot coordinates unchanged", |_| {
+            let a = build_dancer(1, "A");
+            let b = build_dancer(2, "B");
+            let mut state = load_and_select_second(
+                vec![dancer_position(&a, 0.0, 0.0), dancer_position(&b, 4.0, 0.0)],
+                vec![build_position(4.0, 2.0), build_position(0.0, 2.0)],
+            );
+
+            reduce(&mut state, ScenesAction::AutoAssignDancers);
+
+            let mut errors = Vec::new();
+            let positions = &state.choreography.scenes[1].positions;
+            check_eq!(errors, (positions[0].x, positions[0].y), (4.0, 2.0));
+            check_eq!(errors, (positions[1].x, positions[1].y), (0.0, 2.0));
+            check_eq!(
+                errors,
+                dancer_names(positions),
+                vec![Some("B".to_string()), Some("A".to_string())]
+            );
+            assert_no_errors(errors);
+        });
+
+        spec.it(
+            "reports a count mismatch without touching the scene",
+            |_| {
+                let a = build_dancer(1, "A");
+                let b = build_dancer(2, "B");
+                let mut state = load_and_select_second(
+                    vec![dancer_position(&a, 0.0, 0.0), dancer_position(&b, 4.0, 0.0)],
+                    vec![build_position(4.0, 2.0)],
+                );
+
+                reduce(&mut state, ScenesAction::AutoAssignDancers);
+
+                let mut errors = Vec::new();
+                check_eq!(
+                    errors,
+                    state.auto_assign_error,
+                    Some(AutoAssignError::CountMismatch {
+                        dancers: 2,
+                        positions: 1
+                    })
+                );
+                check_eq!(
+                    errors,
+                    dancer_names(&state.choreography.scenes[1].positions),
+                    vec![None::<String>]
+                );
+                assert_no_errors(errors);
+            },
+        );
+
+        spec.it(
+            "reports a missing previous scene for the first scene",
+            |_| {
+                let a = build_dancer(1, "A");
+                let mut state = load_and_select_second(
+                    vec![build_position(0.0, 0.0)],
+                    vec![dancer_position(&a, 0.0, 0.0)],
+                );
+                reduce(&mut state, ScenesAction::SelectScene { index: 0 });
+
+                reduce(&mut state, ScenesAction::AutoAssignDancers);
+
+                assert_eq!(
+                    state.auto_assign_error,
+                    Some(AutoAssignError::NoPreviousScene)
+                );
+            },
+        );
+
+        spec.it("formats count mismatches with both counts", |_| {
+            let message = auto_assign_error_message(
+                "en",
+                &AutoAssignError::CountMismatch {
+                    dancers: 3,
+                    positions: 2,
+                },
+            );
+
+            assert_eq!(
+                message,
+                "3 dancers need a position, but the scene has 2 unassigned positions."
+            );
+        });
+    });
+
+    let report = crate::scenes::run_suite(&suite);
+    assert!(report.is_success());
+}
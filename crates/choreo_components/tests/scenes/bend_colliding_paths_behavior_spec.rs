@@ -0,0 +1,272 @@
+use choreo_models::PositionModel;
+
+use super::actions::ScenesAction;
+use super::build_dancer;
+use super::choreography_with_scenes;
+use super::create_state;
+use super::dancer_position;
+use super::reducer::reduce;
+use super::scene_model;
+use super::state::ScenesState;
+use super::transition_paths::TransitionPathError;
+use super::translations::transition_path_error_message;
+use super::translations::unresolved_collisions_message;
+use crate::scenes::Report;
+
+macro_rules! check_eq {
+    ($errors:expr, $left:expr, $right:expr) => {
+        if $left != $right {
+            $errors.push(format!(
+                "{} != {} (left = {:?}, right = {:?})",
+                stringify!($left),
+                stringify!($right),
+                $left,
+                $right
+            ));
+        }
+    };
+}
+
+fn assert_no_errors(errors: Vec<String>) {
+    assert!(
+        errors.is_empty(),
+        "Assertion failures:\n{}",
+        errors.join("\n")
+    );
+}
+
+fn curves(position: &PositionModel) -> [Option<f64>; 4] {
+    [
+        position.curve1_x,
+        position.curve1_y,
+        position.curve2_x,
+        position.curve2_y,
+    ]
+}
+
+fn load_and_select_first(current: Vec<PositionModel>, next: Vec<PositionModel>) -> ScenesState {
+    load_and_select_first_with_dancer_size(current, next, 1.0)
+}
+
+fn load_and_select_first_with_dancer_size(
+    current: Vec<PositionModel>,
+    next: Vec<PositionModel>,
+    dancer_size: f64,
+) -> ScenesState {
+    let mut state = create_state();
+    let mut choreography = choreography_with_scenes(
+        "Test",
+        vec![
+            scene_model(1, "First", None, current),
+            scene_model(2, "Second", None, next),
+        ],
+    );
+    choreography.settings.dancer_size = dancer_size;
+    reduce(
+        &mut state,
+        ScenesAction::LoadScenes {
+            choreography: Box::new(choreography),
+        },
+    );
+    state
+}
+
+#[test]
+fn bend_colliding_paths_behavior_spec() {
+    let suite = rspec::describe("bend colliding paths", (), |spec| {
+        spec.it("offers bending only when paths come too close", |_| {
+            let a = build_dancer(1, "A");
+            let b = build_dancer(2, "B");
+            let swapping = load_and_select_first(
+                vec![dancer_position(&a, 0.0, 0.0), dancer_position(&b, 4.0, 0.0)],
+                vec![dancer_position(&a, 4.0, 0.0), dancer_position(&b, 0.0, 0.0)],
+            );
+            let parallel = load_and_select_first(
+                vec![dancer_position(&a, 0.0, 0.0), dancer_position(&b, 0.0, 2.0)],
+                vec![dancer_position(&a, 4.0, 0.0), dancer_position(&b, 4.0, 2.0)],
+            );
+
+            let mut errors = Vec::new();
+            check_eq!(errors, swapping.can_bend_colliding_paths, true);
+            check_eq!(errors, parallel.can_bend_colliding_paths, false);
+            assert_no_errors(errors);
+        });
+
+        spec.it(
+            "compares paths with a dancer size below one as given",
+            |_| {
+                let a = build_dancer(1, "A");
+                let b = build_dancer(2, "B");
+                let current = vec![dancer_position(&a, 0.0, 0.0), dancer_position(&b, 0.0, 0.6)];
+                let next = vec![dancer_position(&a, 4.0, 0.0), dancer_position(&b, 4.0, 0.6)];
+                let small =
+                    load_and_select_first_with_dancer_size(current.clone(), next.clone(), 0.5);
+                let large = load_and_select_first_with_dancer_size(current, next, 1.0);
+
+                let mut errors = Vec::new();
+                check_eq!(errors, small.can_bend_colliding_paths, false);
+                check_eq!(errors, large.can_bend_colliding_paths, true);
+                assert_no_errors(errors);
+            },
+        );
+
+        spec.it("fills control points of the colliding paths", |_| {
+            let a = build_dancer(1, "A");
+            let b = build_dancer(2, "B");
+            let c = build_dancer(3, "C");
+            let mut state = load_and_select_first(
+                vec![
+                    dancer_position(&a, 0.0, 0.0),
+                    dancer_position(&b, 4.0, 0.0),
+                    dancer_position(&c, 0.0, 5.0),
+                ],
+                vec![
+                    dancer_position(&a, 4.0, 0.0),
+                    dancer_position(&b, 0.0, 0.0),
+                    dancer_position(&c, 4.0, 5.0),
+                ],
+            );
+
+            reduce(&mut state, ScenesAction::BendCollidingPaths);
+
+            let mut errors = Vec::new();
+            let positions = &state.choreography.scenes[0].positions;
+            check_eq!(
+                errors,
+                state.transition_path_error,
+                None::<TransitionPathError>
+            );
+            check_eq!(errors, state.unresolved_collision_pairs, 0);
+            check_eq!(
+                errors,
+                curves(&positions[0]).iter().all(Option::is_some),
+                true
+            );
+            check_eq!(
+                errors,
+                curves(&positions[1]).iter().all(Option::is_some),
+                true
+            );
+            check_eq!(errors, curves(&positions[2]), [None::<f64>; 4]);
+            check_eq!(errors, (positions[0].x, positions[0].y), (0.0, 0.0));
+            check_eq!(
+                errors,
+                state
+                    .selected_scene
+                    .as_ref()
+                    .map(|scene| curves(&scene.positions[0])),
+                Some(curves(&positions[0]))
+            );
+            check_eq!(errors, state.can_bend_colliding_paths, false);
+            check_eq!(errors, state.redraw_floor_requested, true);
+            assert_no_errors(errors);
+        });
+
+        spec.it(
+            "replaces the waypoints of a bent path with the curve",
+            |_| {
+                let a = build_dancer(1, "A");
+                let b = build_dancer(2, "B");
+                let waypoint_path = PositionModel {
+                    movement1_x: Some(2.0),
+                    movement1_y: Some(0.0),
+                    movement2_x: Some(3.0),
+                    movement2_y: Some(0.0),
+                    ..dancer_position(&a, 0.0, 0.0)
+                };
+                let mut state = load_and_select_first(
+                    vec![waypoint_path, dancer_position(&b, 4.0, 0.0)],
+                    vec![dancer_position(&a, 4.0, 0.0), dancer_position(&b, 0.0, 0.0)],
+                );
+
+                let mut errors = Vec::new();
+                check_eq!(errors, state.can_bend_colliding_paths, true);
+
+                reduce(&mut state, ScenesAction::BendCollidingPaths);
+
+                let bent = &state.choreography.scenes[0].positions[0];
+                check_eq!(errors, curves(bent).iter().all(Option::is_some), true);
+                check_eq!(
+                    errors,
+                    [
+                        bent.movement1_x,
+                        bent.movement1_y,
+                        bent.movement2_x,
+                        bent.movement2_y
+                    ],
+                    [None::<f64>; 4]
+                );
+                assert_no_errors(errors);
+            },
+        );
+
+        spec.it("reports when no dancers collide", |_| {
+            let a = build_dancer(1, "A");
+            let b = build_dancer(2, "B");
+            let mut state = load_and_select_first(
+                vec![dancer_position(&a, 0.0, 0.0), dancer_position(&b, 0.0, 2.0)],
+                vec![dancer_position(&a, 4.0, 0.0), dancer_position(&b, 4.0, 2.0)],
+            );
+
+            reduce(&mut state, ScenesAction::BendCollidingPaths);
+
+            let mut errors = Vec::new();
+            check_eq!(
+                errors,
+                state.transition_path_error,
+                Some(TransitionPathError::NoCollisions)
+            );
+            check_eq!(
+                errors,
+                transition_path_error_message("en", &TransitionPathError::NoCollisions),
+                "No dancers come closer than the dancer size during this transition."
+            );
+            check_eq!(
+                errors,
+                curves(&state.choreography.scenes[0].positions[0]),
+                [None::<f64>; 4]
+            );
+            assert_no_errors(errors);
+        });
+
+        spec.it("reports a missing next scene for the last scene", |_| {
+            let a = build_dancer(1, "A");
+            let mut state = load_and_select_first(
+                vec![dancer_position(&a, 0.0, 0.0)],
+                vec![dancer_position(&a, 1.0, 0.0)],
+            );
+            reduce(&mut state, ScenesAction::SelectScene { index: 1 });
+
+            reduce(&mut state, ScenesAction::BendCollidingPaths);
+
+            assert_eq!(
+                state.transition_path_error,
+                Some(TransitionPathError::NoNextScene)
+            );
+        });
+
+        spec.it("keeps pairs that start too close as unresolved", |_| {
+            let a = build_dancer(1, "A");
+            let b = build_dancer(2, "B");
+            let mut state = load_and_select_first(
+                vec![dancer_position(&a, 0.0, 0.0), dancer_position(&b, 0.5, 0.0)],
+                vec![dancer_position(&a, 0.0, 4.0), dancer_position(&b, 4.0, 4.0)],
+            );
+
+            reduce(&mut state, ScenesAction::BendCollidingPaths);
+
+            let mut errors = Vec::new();
+            check_eq!(errors, state.transition_path_error, None::<TransitionPathError>);
+            check_eq!(errors, state.unresolved_collision_pairs, 1);
+            check_eq!(
+                errors,
+                unresolved_collisions_message("en", 1),
+                "1 pair(s) of dancers still come too close because they start or end too close together."
+            );
+            assert_no_errors(errors);
+        });
+    });
+
+    let report = crate::scenes::run_suite(&suite);
+    assert!(report.is_success());
+}
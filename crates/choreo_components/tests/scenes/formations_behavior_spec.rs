@@ -0,0 +1,402 @@
+use choreo_models::FloorModel;
+
+use super::actions::ScenesAction;
+use super::build_dancer;
+use super::build_position;
+use super::choreography_with_scenes;
+use super::create_state;
+use super::formations::FORMATION_TEMPLATES_KEY;
+use super::formations::FormationChoice;
+use super::formations::FormationKind;
+use super::formations::FormationParameters;
+use super::formations::FormationTemplate;
+use super::formations::arrange_positions;
+use super::formations::formation_templates_json;
+use super::formations::generate_formation;
+use super::formations::parse_formation_templates;
+use super::formations::save_template;
+use super::formations::template_formation;
+use super::reducer::reduce;
+use super::scene_model;
+use super::state::ScenesState;
+use super::ui::formation_choice_for_label;
+use super::ui::formation_label_index;
+use super::ui::formation_labels;
+use crate::scenes::Report;
+
+macro_rules! check_eq {
+    ($errors:expr, $left:expr, $right:expr) => {
+        if $left != $right {
+            $errors.push(format!(
+                "{} != {} (left = {:?}, right = {:?})",
+                stringify!($left),
+                stringify!($right),
+                $left,
+                $right
+            ));
+        }
+    };
+}
+
+macro_rules! check {
+    ($errors:expr, $condition:expr) => {
+        let passed: bool = $condition;
+        if !passed {
+            $errors.push(format!("condition failed: {}", stringify!($condition)));
+        }
+    };
+}
+
+fn assert_no_errors(errors: Vec<String>) {
+    assert!(
+        errors.is_empty(),
+        "Assertion failures:\n{}",
+        errors.join("\n")
+    );
+}
+
+fn floor(size: i32) -> FloorModel {
+    FloorModel {
+        size_front: size,
+        size_back: size,
+        size_left: size,
+        size_right: size,
+    }
+}
+
+fn parameters(count: usize, spacing_steps: u32) -> FormationParameters {
+    FormationParameters {
+        count,
+        spacing_steps,
+    }
+}
+
+fn spot_positions(state: &ScenesState) -> Vec<(f64, f64)> {
+    state
+        .selected_scene
+        .as_ref()
+        .map(|scene| {
+            scene
+                .positions
+                .iter()
+                .map(|position| (position.x, position.y))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Two scenes; the first is selected and has a dancer at (1, 2) followed by an empty position.
+fn load_state() -> ScenesState {
+    let mut state = create_state();
+    let mut dancer_position = build_position(1.0, 2.0);
+    dancer_position.dancer = Some(build_dancer(1, "Alex"));
+    dancer_position.movement1_x = Some(3.0);
+    dancer_position.movement1_y = Some(3.0);
+    let mut choreography = choreography_with_scenes(
+        "Test",
+        vec![
+            scene_model(
+                1,
+                "Opening",
+                Some("0"),
+                vec![dancer_position, build_position(-1.0, 0.0)],
+            ),
+            scene_model(2, "Finale", Some("8"), Vec::new()),
+        ],
+    );
+    choreography.floor = floor(5);
+    reduce(
+        &mut state,
+        ScenesAction::LoadScenes {
+            choreography: Box::new(choreography),
+        },
+    );
+    state.grid_resolution = 2;
+    state
+}
+
+#[test]
+fn formations_behavior_spec() {
+    let suite = rspec::describe("formations", (), |spec| {
+        spec.it(
+            "spaces a line by whole grid steps around the floor center",
+            |_| {
+                let spots =
+                    generate_formation(FormationKind::Line, parameters(4, 2), &floor(10), 2);
+
+                let mut errors = Vec::new();
+                check_eq!(
+                    errors,
+                    spots,
+                    vec![(-1.5, 0.0), (-0.5, 0.0), (0.5, 0.0), (1.5, 0.0)]
+                );
+                assert_no_errors(errors);
+            },
+        );
+
+        spec.it("puts the tip of a wedge at the front", |_| {
+            let spots = generate_formation(FormationKind::Wedge, parameters(5, 1), &floor(10), 1);
+
+            let mut errors = Vec::new();
+            check_eq!(
+                errors,
+                spots,
+                vec![
+                    (0.0, 1.0),
+                    (-1.0, 0.0),
+                    (1.0, 0.0),
+                    (-2.0, -1.0),
+                    (2.0, -1.0)
+                ]
+            );
+            assert_no_errors(errors);
+        });
+
+        spec.it(
+            "stands every spot of a circle equally far from its center",
+            |_| {
+                let spots =
+                    generate_formation(FormationKind::Circle, parameters(4, 2), &floor(10), 1000);
+
+                let mut errors = Vec::new();
+                check_eq!(errors, spots.len(), 4);
+                let radii = spots.iter().map(|(x, y)| x.hypot(*y)).collect::<Vec<_>>();
+                check!(
+                    errors,
+                    radii.iter().all(|radius| (radius - radii[0]).abs() < 0.01)
+                );
+                check!(errors, spots[0].1 > 0.0);
+                assert_no_errors(errors);
+            },
+        );
+
+        spec.it(
+            "lays out windows and checkerboards in staggered rows",
+            |_| {
+                let windows =
+                    generate_formation(FormationKind::Windows, parameters(4, 2), &floor(10), 1);
+                let checkerboard = generate_formation(
+                    FormationKind::Checkerboard,
+                    parameters(4, 1),
+                    &floor(10),
+                    1,
+                );
+                let double_line =
+                    generate_formation(FormationKind::DoubleLine, parameters(4, 1), &floor(10), 1);
+                let diagonal =
+                    generate_formation(FormationKind::Diagonal, parameters(3, 1), &floor(10), 1);
+
+                let mut errors = Vec::new();
+                check_eq!(
+                    errors,
+                    windows,
+                    vec![(-2.0, 1.0), (0.0, 1.0), (-1.0, -1.0), (1.0, -1.0)]
+                );
+                check_eq!(
+                    errors,
+                    checkerboard,
+                    vec![(-2.0, 0.0), (0.0, 0.0), (-1.0, -1.0), (1.0, -1.0)]
+                );
+                check_eq!(
+                    errors,
+                    double_line,
+                    vec![(-1.0, 0.0), (0.0, 0.0), (-1.0, -1.0), (0.0, -1.0)]
+                );
+                check_eq!(errors, diagonal, vec![(-1.0, 1.0), (0.0, 0.0), (1.0, -1.0)]);
+                assert_no_errors(errors);
+            },
+        );
+
+        spec.it("shrinks a formation that is wider than the floor", |_| {
+            let spots = generate_formation(FormationKind::Line, parameters(9, 4), &floor(2), 1);
+
+            let mut errors = Vec::new();
+            check_eq!(errors, spots.len(), 9);
+            check!(
+                errors,
+                spots
+                    .iter()
+                    .all(|(x, y)| (-2.0..=2.0).contains(x) && *y == 0.0)
+            );
+            check_eq!(errors, spots.first().map(|spot| spot.0), Some(-2.0));
+            check_eq!(errors, spots.last().map(|spot| spot.0), Some(2.0));
+            assert_no_errors(errors);
+        });
+
+        spec.it("moves dancers onto the spots in order", |_| {
+            let state = load_state();
+            let mut scene = state.choreography.scenes[0].clone();
+
+            arrange_positions(&mut scene, &[(0.0, 0.0), (1.0, 0.0), (2.0, 0.0)]);
+
+            let mut errors = Vec::new();
+            check_eq!(errors, scene.positions.len(), 3);
+            check_eq!(
+                errors,
+                scene.positions[0]
+                    .dancer
+                    .as_ref()
+                    .map(|dancer| dancer.name.as_str()),
+                Some("Alex")
+            );
+            check_eq!(errors, scene.positions[0].movement1_x, None::<f64>);
+            check!(errors, scene.positions[2].dancer.is_none());
+
+            arrange_positions(&mut scene, &[(4.0, 4.0)]);
+
+            check_eq!(errors, scene.positions.len(), 1);
+            check_eq!(errors, scene.positions[0].x, 4.0);
+            assert_no_errors(errors);
+        });
+
+        spec.it("applies the chosen formation to the selected scene", |_| {
+            let mut state = load_state();
+            reduce(
+                &mut state,
+                ScenesAction::UpdateFormationParameters {
+                    parameters: parameters(3, 2),
+                },
+            );
+
+            reduce(&mut state, ScenesAction::ApplyFormation);
+
+            let mut errors = Vec::new();
+            check_eq!(
+                errors,
+                spot_positions(&state),
+                vec![(-1.0, 0.0), (0.0, 0.0), (1.0, 0.0)]
+            );
+            check_eq!(
+                errors,
+                state.choreography.scenes[0]
+                    .positions
+                    .iter()
+                    .map(|position| position.x)
+                    .collect::<Vec<_>>(),
+                vec![-1.0, 0.0, 1.0]
+            );
+            check!(errors, state.redraw_floor_requested);
+            assert_no_errors(errors);
+        });
+
+        spec.it(
+            "saves the selected scene as a template and reapplies it",
+            |_| {
+                let mut state = load_state();
+
+                let mut errors = Vec::new();
+                check!(errors, state.can_apply_formation);
+                check!(errors, state.can_save_formation_template);
+
+                reduce(&mut state, ScenesAction::SaveFormationTemplate);
+
+                check_eq!(
+                    errors,
+                    state.formation_templates,
+                    vec![FormationTemplate {
+                        name: "Opening".to_string(),
+                        spots: vec![[1.0, 1.0], [-1.0, -1.0]],
+                    }]
+                );
+                check_eq!(errors, state.formation_choice, FormationChoice::Template(0));
+
+                reduce(
+                    &mut state,
+                    ScenesAction::SelectFormation {
+                        choice: FormationChoice::Generated(FormationKind::Line),
+                    },
+                );
+                reduce(&mut state, ScenesAction::ApplyFormation);
+                reduce(
+                    &mut state,
+                    ScenesAction::SelectFormation {
+                        choice: FormationChoice::Template(0),
+                    },
+                );
+                reduce(&mut state, ScenesAction::ApplyFormation);
+
+                check_eq!(
+                    errors,
+                    spot_positions(&state),
+                    vec![(1.0, 1.0), (-1.0, -1.0)]
+                );
+
+                reduce(&mut state, ScenesAction::SelectScene { index: 1 });
+
+                check!(errors, state.can_apply_formation);
+                check!(errors, !state.can_save_formation_template);
+                assert_no_errors(errors);
+            },
+        );
+
+        spec.it(
+            "replaces a template saved again under the same name",
+            |_| {
+                let mut templates = Vec::new();
+                let first = FormationTemplate {
+                    name: "Block".to_string(),
+                    spots: vec![[0.0, 0.0]],
+                };
+                let second = FormationTemplate {
+                    name: "Block".to_string(),
+                    spots: vec![[1.0, 0.0], [-1.0, 0.0]],
+                };
+                let other = FormationTemplate {
+                    name: "Pair".to_string(),
+                    spots: vec![[0.0, 1.0]],
+                };
+
+                let mut errors = Vec::new();
+                check_eq!(errors, save_template(&mut templates, first.clone()), 0);
+                check_eq!(errors, save_template(&mut templates, other.clone()), 1);
+                check_eq!(errors, save_template(&mut templates, second.clone()), 0);
+                check_eq!(errors, templates, vec![second.clone(), other.clone()]);
+                check_eq!(
+                    errors,
+                    template_formation(&second, &floor(4), 1),
+                    vec![(1.0, 0.0), (-1.0, 0.0)]
+                );
+                check_eq!(
+                    errors,
+                    parse_formation_templates(&formation_templates_json(&templates)),
+                    templates
+                );
+                check!(errors, parse_formation_templates("not json").is_empty());
+                check_eq!(errors, FORMATION_TEMPLATES_KEY, "formation_templates");
+                assert_no_errors(errors);
+            },
+        );
+
+        spec.it(
+            "lists generated formations before the saved templates",
+            |_| {
+                let mut state = load_state();
+                reduce(&mut state, ScenesAction::SaveFormationTemplate);
+
+                let labels = formation_labels(&state, "en");
+
+                let mut errors = Vec::new();
+                check_eq!(errors, labels.len(), FormationKind::ALL.len() + 1);
+                check_eq!(errors, labels[0], "Line");
+                check_eq!(errors, labels[3], "Circle");
+                check_eq!(errors, labels[7], "Opening");
+                for index in 0..labels.len() {
+                    check_eq!(
+                        errors,
+                        formation_label_index(formation_choice_for_label(index)),
+                        index
+                    );
+                }
+                check_eq!(
+                    errors,
+                    formation_choice_for_label(7),
+                    FormationChoice::Template(0)
+                );
+                assert_no_errors(errors);
+            },
+        );
+    });
+
+    let report = crate::scenes::run_suite(&suite);
+    assert!(report.is_success());
+}
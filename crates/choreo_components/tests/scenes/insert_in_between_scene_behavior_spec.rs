@@ -0,0 +1,263 @@
+use choreo_models::PositionModel;
+
+use super::actions::ScenesAction;
+use super::auto_assign::AutoAssignError;
+use super::auto_assign::in_between_timing;
+use super::build_dancer;
+use super::choreography_with_scenes;
+use super::create_state;
+use super::dancer_names;
+use super::dancer_position;
+use super::formations::FormationChoice;
+use super::formations::FormationTemplate;
+use super::reducer::reduce;
+use super::scene_model;
+use super::state::ScenesState;
+use crate::scenes::Report;
+
+macro_rules! check_eq {
+    ($errors:expr, $left:expr, $right:expr) => {
+        if $left != $right {
+            $errors.push(format!(
+                "{} != {} (left = {:?}, right = {:?})",
+                stringify!($left),
+                stringify!($right),
+                $left,
+                $right
+            ));
+        }
+    };
+}
+
+fn assert_no_errors(errors: Vec<String>) {
+    assert!(
+        errors.is_empty(),
+        "Assertion failures:\n{}",
+        errors.join("\n")
+    );
+}
+
+fn load_two_scenes(
+    start: (Option<&str>, Vec<PositionModel>),
+    end: (Option<&str>, Vec<PositionModel>),
+) -> ScenesState {
+    let mut state = create_state();
+    let choreography = choreography_with_scenes(
+        "Test",
+        vec![
+            scene_model(1, "Start", start.0, start.1),
+            scene_model(2, "End", end.0, end.1),
+        ],
+    );
+    reduce(
+        &mut state,
+        ScenesAction::LoadScenes {
+            choreography: Box::new(choreography),
+        },
+    );
+    state
+}
+
+#[test]
+fn insert_in_between_scene_behavior_spec() {
+    let suite = rspec::describe("insert in-between scene", (), |spec| {
+        spec.it(
+            "assigns dancers to the spots closest to their paths at the midpoint",
+            |_| {
+                let a = build_dancer(1, "A");
+                let b = build_dancer(2, "B");
+                let mut state = load_two_scenes(
+                    (
+                        None,
+                        vec![dancer_position(&a, 0.0, 0.0), dancer_position(&b, 2.0, 0.0)],
+                    ),
+                    (
+                        None,
+                        vec![
+                            dancer_position(&a, 10.0, 0.0),
+                            dancer_position(&b, -10.0, 0.0),
+                        ],
+                    ),
+                );
+
+                reduce(
+                    &mut state,
+                    ScenesAction::InsertInBetweenScene { formation: None },
+                );
+
+                let mut errors = Vec::new();
+                let names = state
+                    .choreography
+                    .scenes
+                    .iter()
+                    .map(|scene| scene.name.clone())
+                    .collect::<Vec<_>>();
+                check_eq!(errors, names, vec!["Start", "New Scene", "End"]);
+                let inserted = &state.choreography.scenes[1].positions;
+                check_eq!(
+                    errors,
+                    dancer_names(inserted),
+                    vec![Some("B".to_string()), Some("A".to_string())]
+                );
+                check_eq!(errors, (inserted[0].x, inserted[1].x), (0.0, 2.0));
+                check_eq!(
+                    errors,
+                    state
+                        .selected_scene
+                        .as_ref()
+                        .map(|scene| scene.name.clone()),
+                    Some("New Scene".to_string())
+                );
+                check_eq!(errors, state.scenes.len(), 3);
+                check_eq!(errors, state.redraw_floor_requested, true);
+                check_eq!(errors, state.auto_assign_error, None::<AutoAssignError>);
+                assert_no_errors(errors);
+            },
+        );
+
+        spec.it(
+            "places the dancers on the spots of a saved template",
+            |_| {
+                let a = build_dancer(1, "A");
+                let b = build_dancer(2, "B");
+                let mut state = load_two_scenes(
+                    (
+                        None,
+                        vec![dancer_position(&a, 0.0, 0.0), dancer_position(&b, 2.0, 0.0)],
+                    ),
+                    (
+                        None,
+                        vec![
+                            dancer_position(&a, 10.0, 0.0),
+                            dancer_position(&b, -10.0, 0.0),
+                        ],
+                    ),
+                );
+                state.formation_templates = vec![FormationTemplate {
+                    name: "Pair".to_string(),
+                    spots: vec![[1.0, 0.0], [-1.0, 0.0]],
+                }];
+
+                reduce(
+                    &mut state,
+                    ScenesAction::InsertInBetweenScene {
+                        formation: Some(FormationChoice::Template(0)),
+                    },
+                );
+
+                let mut errors = Vec::new();
+                let inserted = &state.choreography.scenes[1].positions;
+                check_eq!(
+                    errors,
+                    dancer_names(inserted),
+                    vec![Some("A".to_string()), Some("B".to_string())]
+                );
+                check_eq!(
+                    errors,
+                    inserted
+                        .iter()
+                        .map(|position| (position.x, position.y))
+                        .collect::<Vec<_>>(),
+                    vec![(1.0, 0.0), (-1.0, 0.0)]
+                );
+                check_eq!(errors, state.choreography.scenes[0].positions[0].x, 0.0);
+                assert_no_errors(errors);
+            },
+        );
+
+        spec.it("ignores a template that is not saved", |_| {
+            let a = build_dancer(1, "A");
+            let mut state = load_two_scenes(
+                (None, vec![dancer_position(&a, 0.0, 0.0)]),
+                (None, vec![dancer_position(&a, 1.0, 0.0)]),
+            );
+
+            reduce(
+                &mut state,
+                ScenesAction::InsertInBetweenScene {
+                    formation: Some(FormationChoice::Template(3)),
+                },
+            );
+
+            let mut errors = Vec::new();
+            check_eq!(errors, state.choreography.scenes.len(), 2);
+            assert_no_errors(errors);
+        });
+
+        spec.it("places the new scene halfway between timestamps", |_| {
+            let a = build_dancer(1, "A");
+            let mut state = load_two_scenes(
+                (Some("10"), vec![dancer_position(&a, 0.0, 0.0)]),
+                (Some("20"), vec![dancer_position(&a, 4.0, 0.0)]),
+            );
+
+            reduce(
+                &mut state,
+                ScenesAction::InsertInBetweenScene { formation: None },
+            );
+
+            let mut errors = Vec::new();
+            check_eq!(
+                errors,
+                state.choreography.scenes[1].timestamp.as_deref(),
+                Some("15")
+            );
+            check_eq!(
+                errors,
+                state
+                    .selected_scene
+                    .as_ref()
+                    .and_then(|scene| scene.timestamp),
+                Some(15.0)
+            );
+            assert_no_errors(errors);
+        });
+
+        spec.it("reports a missing next scene for the last scene", |_| {
+            let a = build_dancer(1, "A");
+            let mut state = load_two_scenes(
+                (None, vec![dancer_position(&a, 0.0, 0.0)]),
+                (None, vec![dancer_position(&a, 1.0, 0.0)]),
+            );
+            reduce(&mut state, ScenesAction::SelectScene { index: 1 });
+
+            reduce(
+                &mut state,
+                ScenesAction::InsertInBetweenScene { formation: None },
+            );
+
+            let mut errors = Vec::new();
+            check_eq!(
+                errors,
+                state.auto_assign_error,
+                Some(AutoAssignError::NoNextScene)
+            );
+            check_eq!(errors, state.choreography.scenes.len(), 2);
+            check_eq!(errors, state.can_insert_in_between_scene, false);
+            assert_no_errors(errors);
+        });
+
+        spec.it("uses the requested time only inside the transition", |_| {
+            let mut errors = Vec::new();
+            check_eq!(
+                errors,
+                in_between_timing(Some(10.0), Some(20.0), Some(12.0)),
+                (0.2, Some(12.0))
+            );
+            check_eq!(
+                errors,
+                in_between_timing(Some(10.0), Some(20.0), Some(25.0)),
+                (0.5, Some(15.0))
+            );
+            check_eq!(
+                errors,
+                in_between_timing(None, Some(20.0), Some(12.0)),
+                (0.5, None::<f64>)
+            );
+            assert_no_errors(errors);
+        });
+    });
+
+    let report = crate::scenes::run_suite(&suite);
+    assert!(report.is_success());
+}
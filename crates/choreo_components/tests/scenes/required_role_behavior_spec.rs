@@ -0,0 +1,268 @@
+use std::rc::Rc;
+
+use choreo_algorithms::AlgorithmError;
+use choreo_master_mobile_json::Color;
+use choreo_master_mobile_json::DancerId;
+use choreo_models::DancerModel;
+use choreo_models::PositionModel;
+use choreo_models::RoleModel;
+
+use super::actions::ScenesAction;
+use super::auto_assign::AutoAssignError;
+use super::build_position;
+use super::choreography_with_scenes;
+use super::create_state;
+use super::dancer_names;
+use super::dancer_position;
+use super::reducer::reduce;
+use super::scene_model;
+use super::state::ScenesState;
+use super::translations::auto_assign_error_message;
+use super::ui::required_role_action;
+use super::ui::required_role_labels;
+use crate::scenes::Report;
+
+macro_rules! check_eq {
+    ($errors:expr, $left:expr, $right:expr) => {
+        if $left != $right {
+            $errors.push(format!(
+                "{} != {} (left = {:?}, right = {:?})",
+                stringify!($left),
+                stringify!($right),
+                $left,
+                $right
+            ));
+        }
+    };
+}
+
+fn assert_no_errors(errors: Vec<String>) {
+    assert!(
+        errors.is_empty(),
+        "Assertion failures:\n{}",
+        errors.join("\n")
+    );
+}
+
+fn build_role(name: &str) -> Rc<RoleModel> {
+    Rc::new(RoleModel {
+        z_index: 0,
+        name: name.to_string(),
+        color: Color::transparent(),
+    })
+}
+
+fn build_dancer(dancer_id: i32, name: &str, role: &Rc<RoleModel>) -> Rc<DancerModel> {
+    Rc::new(DancerModel {
+        dancer_id: DancerId(dancer_id),
+        role: role.clone(),
+        name: name.to_string(),
+        shortcut: name.to_string(),
+        color: Color::transparent(),
+        icon: None,
+    })
+}
+
+fn spot_for(role: &Rc<RoleModel>, x: f64, y: f64) -> PositionModel {
+    PositionModel {
+        required_role: Some(role.clone()),
+        ..build_position(x, y)
+    }
+}
+
+fn load_and_select_second(
+    roles: Vec<Rc<RoleModel>>,
+    dancers: Vec<Rc<DancerModel>>,
+    previous: Vec<PositionModel>,
+    current: Vec<PositionModel>,
+) -> ScenesState {
+    let mut state = create_state();
+    let mut choreography = choreography_with_scenes(
+        "Test",
+        vec![
+            scene_model(1, "First", None, previous),
+            scene_model(2, "Second", None, current),
+        ],
+    );
+    choreography.roles = roles;
+    choreography.dancers = dancers;
+    reduce(
+        &mut state,
+        ScenesAction::LoadScenes {
+            choreography: Box::new(choreography),
+        },
+    );
+    reduce(&mut state, ScenesAction::SelectScene { index: 1 });
+    state
+}
+
+#[test]
+fn required_role_behavior_spec() {
+    let suite = rspec::describe("required position roles", (), |spec| {
+        spec.it("tags the selected positions with a role", |_| {
+            let lead = build_role("Lead");
+            let follow = build_role("Follow");
+            let mut state = load_and_select_second(
+                vec![lead.clone(), follow.clone()],
+                Vec::new(),
+                vec![build_position(0.0, 0.0), build_position(1.0, 0.0)],
+                vec![build_position(0.0, 1.0), build_position(1.0, 1.0)],
+            );
+            state.selected_position_indices = vec![1];
+
+            let action = required_role_action(&state, 2);
+            reduce(&mut state, action);
+
+            let mut errors = Vec::new();
+            let positions = &state.choreography.scenes[1].positions;
+            check_eq!(errors, positions[0].required_role.is_none(), true);
+            check_eq!(
+                errors,
+                positions[1]
+                    .required_role
+                    .as_ref()
+                    .is_some_and(|role| Rc::ptr_eq(role, &follow)),
+                true
+            );
+            check_eq!(
+                errors,
+                state
+                    .selected_scene
+                    .as_ref()
+                    .and_then(|scene| scene.positions[1].required_role.clone())
+                    .map(|role| role.name.clone()),
+                Some("Follow".to_string())
+            );
+            check_eq!(errors, state.selected_required_role, Some(1));
+            check_eq!(errors, state.can_set_required_role, true);
+            check_eq!(errors, state.redraw_floor_requested, true);
+            check_eq!(
+                errors,
+                required_role_labels(&state, "Any role"),
+                vec![
+                    "Any role".to_string(),
+                    "Lead".to_string(),
+                    "Follow".to_string()
+                ]
+            );
+            assert_no_errors(errors);
+        });
+
+        spec.it("clears the role when any role is chosen", |_| {
+            let lead = build_role("Lead");
+            let mut state = load_and_select_second(
+                vec![lead.clone()],
+                Vec::new(),
+                vec![build_position(0.0, 0.0)],
+                vec![spot_for(&lead, 0.0, 1.0)],
+            );
+            state.selected_position_indices = vec![0];
+
+            let action = required_role_action(&state, 0);
+            reduce(&mut state, action);
+
+            let mut errors = Vec::new();
+            check_eq!(
+                errors,
+                state.choreography.scenes[1].positions[0]
+                    .required_role
+                    .is_none(),
+                true
+            );
+            check_eq!(errors, state.selected_required_role, None::<usize>);
+            assert_no_errors(errors);
+        });
+
+        spec.it(
+            "places dancers only on positions that allow their role",
+            |_| {
+                let lead = build_role("Lead");
+                let follow = build_role("Follow");
+                let a = build_dancer(1, "A", &lead);
+                let b = build_dancer(2, "B", &follow);
+                let mut state = load_and_select_second(
+                    vec![lead.clone(), follow.clone()],
+                    vec![a.clone(), b.clone()],
+                    vec![
+                        dancer_position(&a, 0.0, 0.0),
+                        dancer_position(&b, 10.0, 0.0),
+                    ],
+                    vec![spot_for(&follow, 0.0, 1.0), spot_for(&lead, 10.0, 1.0)],
+                );
+
+                reduce(&mut state, ScenesAction::AutoAssignDancers);
+
+                let mut errors = Vec::new();
+                check_eq!(errors, state.auto_assign_error, None::<AutoAssignError>);
+                check_eq!(
+                    errors,
+                    dancer_names(&state.choreography.scenes[1].positions),
+                    vec![Some("B".to_string()), Some("A".to_string())]
+                );
+                assert_no_errors(errors);
+            },
+        );
+
+        spec.it(
+            "reports when no assignment satisfies the required roles",
+            |_| {
+                let lead = build_role("Lead");
+                let follow = build_role("Follow");
+                let a = build_dancer(1, "A", &lead);
+                let b = build_dancer(2, "B", &lead);
+                let mut state = load_and_select_second(
+                    vec![lead.clone(), follow.clone()],
+                    vec![a.clone(), b.clone()],
+                    vec![
+                        dancer_position(&a, 0.0, 0.0),
+                        dancer_position(&b, 10.0, 0.0),
+                    ],
+                    vec![spot_for(&follow, 0.0, 1.0), build_position(10.0, 1.0)],
+                );
+
+                reduce(&mut state, ScenesAction::AutoAssignDancers);
+
+                let mut errors = Vec::new();
+                check_eq!(
+                    errors,
+                    state.auto_assign_error,
+                    Some(AutoAssignError::NoRoleCompatibleAssignment)
+                );
+                check_eq!(
+                    errors,
+                    dancer_names(&state.choreography.scenes[1].positions),
+                    vec![None::<String>, None::<String>]
+                );
+                check_eq!(
+                    errors,
+                    auto_assign_error_message("en", &AutoAssignError::NoRoleCompatibleAssignment),
+                    "No assignment places every dancer on a position that allows their role."
+                );
+                assert_no_errors(errors);
+            },
+        );
+
+        spec.it(
+            "keeps the generic failure when no role rules out a pairing",
+            |_| {
+                let failure = AlgorithmError::NoPerfectAssignment("No augmenting path.");
+
+                let mut errors = Vec::new();
+                check_eq!(
+                    errors,
+                    AutoAssignError::from_algorithm(failure.clone(), false),
+                    AutoAssignError::Algorithm(failure.clone())
+                );
+                check_eq!(
+                    errors,
+                    AutoAssignError::from_algorithm(failure.clone(), true),
+                    AutoAssignError::NoRoleCompatibleAssignment
+                );
+                assert_no_errors(errors);
+            },
+        );
+    });
+
+    let report = crate::scenes::run_suite(&suite);
+    assert!(report.is_success());
+}
@@ -3,6 +3,8 @@ use super::choreography_with_scenes;
 use super::create_state;
 use super::reducer::reduce;
 use super::scene_model;
+use super::translations::save_choreo_error_message;
+use choreo_master_mobile_json::ChoreoJsonError;
 use std::fs;
 
 #[test]
@@ -85,6 +87,7 @@ fn save_choreo_with_no_last_opened_file_does_not_enable_can_save() {
     reduce(&mut state, ScenesAction::SaveChoreography);
 
     assert!(!state.can_save_choreo);
+    assert!(state.can_save_choreo_as);
 }
 
 #[test]
@@ -138,3 +141,31 @@ fn save_choreo_requires_existing_file_for_enablement_parity() {
     let _ = fs::remove_file(existing_path);
     let _ = fs::remove_dir(temp_root);
 }
+
+#[test]
+fn save_choreo_as_request_follows_enablement() {
+    let mut state = create_state();
+    reduce(&mut state, ScenesAction::RequestSaveChoreographyAs);
+    let requested_without_choreography = state.request_save_choreo_as;
+
+    reduce(
+        &mut state,
+        ScenesAction::LoadScenes {
+            choreography: Box::new(choreography_with_scenes("Draft", vec![])),
+        },
+    );
+    reduce(&mut state, ScenesAction::RequestSaveChoreographyAs);
+
+    assert!(!requested_without_choreography);
+    assert!(state.request_save_choreo_as);
+}
+
+#[test]
+fn save_choreo_error_message_includes_path_and_error_details() {
+    let error = ChoreoJsonError::MissingField("Scenes");
+
+    assert_eq!(
+        save_choreo_error_message("en", "/tmp/show.choreo", &error),
+        "Could not save the choreography to /tmp/show.choreo: missing field Scenes"
+    );
+}
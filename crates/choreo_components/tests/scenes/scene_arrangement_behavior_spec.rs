@@ -0,0 +1,309 @@
+use std::rc::Rc;
+
+use choreo_master_mobile_json::MusicalTimeline;
+use choreo_master_mobile_json::SceneId;
+
+use super::actions::ScenesAction;
+use super::arrangement::extend_selection;
+use super::arrangement::remove_scenes;
+use super::arrangement::toggle_selection;
+use super::build_dancer;
+use super::build_position;
+use super::choreography_with_scenes;
+use super::create_state;
+use super::reducer::reduce;
+use super::scene_model;
+use super::state::ScenesState;
+use super::ui::move_scene_action;
+use super::ui::scene_click_action;
+use super::ui::scene_time_shift_step_seconds;
+use crate::scenes::Report;
+
+macro_rules! check_eq {
+    ($errors:expr, $left:expr, $right:expr) => {
+        if $left != $right {
+            $errors.push(format!(
+                "{} != {} (left = {:?}, right = {:?})",
+                stringify!($left),
+                stringify!($right),
+                $left,
+                $right
+            ));
+        }
+    };
+}
+
+fn assert_no_errors(errors: Vec<String>) {
+    assert!(
+        errors.is_empty(),
+        "Assertion failures:\n{}",
+        errors.join("\n")
+    );
+}
+
+fn scene_names(state: &ScenesState) -> Vec<String> {
+    state
+        .scenes
+        .iter()
+        .map(|scene| scene.name.clone())
+        .collect()
+}
+
+fn selected_names(state: &ScenesState) -> Vec<String> {
+    state
+        .scenes
+        .iter()
+        .filter(|scene| scene.is_selected)
+        .map(|scene| scene.name.clone())
+        .collect()
+}
+
+fn load_state() -> ScenesState {
+    let mut state = create_state();
+    let mut dancer_position = build_position(1.0, 2.0);
+    dancer_position.dancer = Some(build_dancer(1, "Alex"));
+    reduce(
+        &mut state,
+        ScenesAction::LoadScenes {
+            choreography: Box::new(choreography_with_scenes(
+                "Test",
+                vec![
+                    scene_model(1, "Intro", Some("4"), vec![build_position(0.0, 0.0)]),
+                    scene_model(2, "Chorus", Some("10"), vec![dancer_position]),
+                    scene_model(3, "Bridge", None, Vec::new()),
+                    scene_model(4, "Outro", Some("20"), Vec::new()),
+                ],
+            )),
+        },
+    );
+    state
+}
+
+#[test]
+fn scene_arrangement_behavior_spec() {
+    let suite = rspec::describe("scene arrangement", (), |spec| {
+        spec.it("moves a dragged scene to where it is dropped", |_| {
+            let mut state = load_state();
+
+            reduce(&mut state, ScenesAction::MoveScene { from: 0, to: 2 });
+
+            let mut errors = Vec::new();
+            check_eq!(
+                errors,
+                scene_names(&state),
+                vec!["Chorus", "Bridge", "Intro", "Outro"]
+            );
+            check_eq!(
+                errors,
+                state
+                    .choreography
+                    .scenes
+                    .iter()
+                    .map(|scene| scene.name.as_str())
+                    .collect::<Vec<_>>(),
+                vec!["Chorus", "Bridge", "Intro", "Outro"]
+            );
+            check_eq!(
+                errors,
+                state
+                    .selected_scene
+                    .as_ref()
+                    .map(|scene| scene.name.as_str()),
+                Some("Intro")
+            );
+            assert_no_errors(errors);
+        });
+
+        spec.it("does not reorder a list filtered by a search", |_| {
+            let mut state = load_state();
+            reduce(&mut state, ScenesAction::UpdateSearchText("o".to_string()));
+
+            reduce(&mut state, ScenesAction::MoveScene { from: 0, to: 2 });
+
+            let mut errors = Vec::new();
+            check_eq!(errors, state.can_reorder_scenes, false);
+            check_eq!(
+                errors,
+                scene_names(&state),
+                vec!["Intro", "Chorus", "Bridge", "Outro"]
+            );
+            assert_no_errors(errors);
+        });
+
+        spec.it(
+            "duplicates the selected scene with its own copy of the positions",
+            |_| {
+                let mut state = load_state();
+                reduce(&mut state, ScenesAction::SelectScene { index: 1 });
+
+                reduce(&mut state, ScenesAction::DuplicateScene);
+                reduce(&mut state, ScenesAction::SelectScene { index: 1 });
+                reduce(&mut state, ScenesAction::DuplicateScene);
+
+                let mut errors = Vec::new();
+                check_eq!(
+                    errors,
+                    scene_names(&state),
+                    vec!["Intro", "Chorus", "Chorus 3", "Chorus 2", "Bridge", "Outro"]
+                );
+                let original = &state.choreography.scenes[1];
+                let copy = &state.choreography.scenes[3];
+                check_eq!(errors, copy.scene_id, SceneId(5));
+                check_eq!(errors, copy.timestamp, None::<String>);
+                check_eq!(errors, copy.positions, original.positions);
+                check_eq!(
+                    errors,
+                    Rc::ptr_eq(
+                        copy.positions[0]
+                            .dancer
+                            .as_ref()
+                            .expect("copy has a dancer"),
+                        original.positions[0]
+                            .dancer
+                            .as_ref()
+                            .expect("original has a dancer"),
+                    ),
+                    false
+                );
+                check_eq!(
+                    errors,
+                    state
+                        .selected_scene
+                        .as_ref()
+                        .map(|scene| scene.name.as_str()),
+                    Some("Chorus 3")
+                );
+                assert_no_errors(errors);
+            },
+        );
+
+        spec.it("picks scenes together with Ctrl and Shift clicks", |_| {
+            let mut state = load_state();
+
+            reduce(&mut state, scene_click_action(2, true, false));
+
+            let mut errors = Vec::new();
+            check_eq!(errors, selected_names(&state), vec!["Intro", "Bridge"]);
+            check_eq!(
+                errors,
+                state
+                    .selected_scene
+                    .as_ref()
+                    .map(|scene| scene.name.as_str()),
+                Some("Bridge")
+            );
+
+            reduce(&mut state, scene_click_action(0, false, true));
+
+            check_eq!(
+                errors,
+                selected_names(&state),
+                vec!["Intro", "Chorus", "Bridge"]
+            );
+            check_eq!(errors, state.selected_scene_ids.len(), 3);
+
+            reduce(&mut state, scene_click_action(3, false, false));
+
+            check_eq!(errors, selected_names(&state), vec!["Outro"]);
+            check_eq!(errors, state.selected_scene_ids.len(), 0);
+            assert_no_errors(errors);
+        });
+
+        spec.it("shifts the timestamps of every picked scene", |_| {
+            let mut state = load_state();
+            reduce(&mut state, ScenesAction::ExtendSceneSelection { index: 2 });
+
+            reduce(
+                &mut state,
+                ScenesAction::ShiftSceneTimestamps { seconds: -6.0 },
+            );
+
+            let mut errors = Vec::new();
+            check_eq!(
+                errors,
+                state
+                    .choreography
+                    .scenes
+                    .iter()
+                    .map(|scene| scene.timestamp.as_deref())
+                    .collect::<Vec<_>>(),
+                vec![Some("0"), Some("4"), None, Some("20")]
+            );
+            check_eq!(
+                errors,
+                state
+                    .scenes
+                    .iter()
+                    .map(|scene| scene.timestamp)
+                    .collect::<Vec<_>>(),
+                vec![Some(0.0), Some(4.0), None, Some(20.0)]
+            );
+            check_eq!(
+                errors,
+                selected_names(&state),
+                vec!["Intro", "Chorus", "Bridge"]
+            );
+            check_eq!(errors, state.can_shift_scene_timestamps, true);
+
+            reduce(&mut state, ScenesAction::SelectScene { index: 2 });
+
+            check_eq!(errors, state.can_shift_scene_timestamps, false);
+            assert_no_errors(errors);
+        });
+
+        spec.it(
+            "shifts by one count when the choreography has a musical timeline",
+            |_| {
+                let mut state = load_state();
+
+                let mut errors = Vec::new();
+                check_eq!(errors, scene_time_shift_step_seconds(&state), 1.0);
+
+                state.musical_timeline = Some(MusicalTimeline {
+                    bpm: 120.0,
+                    offset_seconds: 0.0,
+                    beats_per_bar: 4,
+                    beat_unit: 4,
+                });
+
+                check_eq!(errors, scene_time_shift_step_seconds(&state), 0.5);
+                assert_no_errors(errors);
+            },
+        );
+
+        spec.it("maps drops and selections to scene indices", |_| {
+            let mut errors = Vec::new();
+            check_eq!(
+                errors,
+                move_scene_action(0, 2, true),
+                Some(ScenesAction::MoveScene { from: 0, to: 2 })
+            );
+            check_eq!(
+                errors,
+                move_scene_action(3, 1, false),
+                Some(ScenesAction::MoveScene { from: 3, to: 1 })
+            );
+            check_eq!(errors, move_scene_action(1, 1, true), None::<ScenesAction>);
+            check_eq!(errors, move_scene_action(1, 2, false), None::<ScenesAction>);
+            check_eq!(errors, toggle_selection(&[1], 3), vec![1, 3]);
+            check_eq!(errors, toggle_selection(&[1, 3], 1), vec![3]);
+            check_eq!(errors, toggle_selection(&[3], 3), vec![3]);
+            check_eq!(errors, extend_selection(4, 2), vec![2, 3, 4]);
+
+            let mut scenes = load_state().choreography.scenes;
+            check_eq!(errors, remove_scenes(&mut scenes, &[3, 0, 9, 0]), 2);
+            check_eq!(
+                errors,
+                scenes
+                    .iter()
+                    .map(|scene| scene.name.as_str())
+                    .collect::<Vec<_>>(),
+                vec!["Chorus", "Bridge"]
+            );
+            assert_no_errors(errors);
+        });
+    });
+
+    let report = crate::scenes::run_suite(&suite);
+    assert!(report.is_success());
+}
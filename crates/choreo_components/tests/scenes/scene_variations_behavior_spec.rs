@@ -0,0 +1,333 @@
+use choreo_master_mobile_json::export;
+use choreo_master_mobile_json::import;
+use choreo_models::ChoreographyModelMapper;
+use choreo_models::SceneModel;
+
+use super::actions::ScenesAction;
+use super::build_position;
+use super::choreography_with_scenes;
+use super::create_state;
+use super::reducer::reduce;
+use super::scene_model;
+use super::state::ScenesState;
+use super::translations::variation_error_message;
+use super::ui::compare_scene_variation_action;
+use super::ui::compare_scene_variation_labels;
+use super::ui::scene_variation_labels;
+use super::ui::switch_scene_variation_action;
+use super::variations::VariationError;
+use super::variations::comparison_scene;
+use super::variations::switch_variation;
+use super::variations::variation_anchor;
+use crate::scenes::Report;
+
+macro_rules! check_eq {
+    ($errors:expr, $left:expr, $right:expr) => {
+        if $left != $right {
+            $errors.push(format!(
+                "{} != {} (left = {:?}, right = {:?})",
+                stringify!($left),
+                stringify!($right),
+                $left,
+                $right
+            ));
+        }
+    };
+}
+
+fn assert_no_errors(errors: Vec<String>) {
+    assert!(
+        errors.is_empty(),
+        "Assertion failures:\n{}",
+        errors.join("\n")
+    );
+}
+
+fn scene_names(state: &ScenesState) -> Vec<String> {
+    state
+        .scenes
+        .iter()
+        .map(|scene| scene.name.clone())
+        .collect()
+}
+
+fn load_and_select(index: usize) -> ScenesState {
+    let mut state = create_state();
+    reduce(
+        &mut state,
+        ScenesAction::LoadScenes {
+            choreography: Box::new(choreography_with_scenes(
+                "Test",
+                vec![
+                    scene_model(1, "Intro", None, vec![build_position(-2.0, 0.0)]),
+                    scene_model(2, "Chorus", None, vec![build_position(0.0, 0.0)]),
+                    scene_model(3, "Outro", None, vec![build_position(2.0, 0.0)]),
+                ],
+            )),
+        },
+    );
+    reduce(&mut state, ScenesAction::SelectScene { index });
+    state
+}
+
+#[test]
+fn scene_variations_behavior_spec() {
+    let suite = rspec::describe("scene variations", (), |spec| {
+        spec.it("branches a variation from the selected scene", |_| {
+            let mut state = load_and_select(1);
+
+            reduce(&mut state, ScenesAction::BranchSceneVariation);
+
+            let mut errors = Vec::new();
+            check_eq!(
+                errors,
+                scene_names(&state),
+                vec!["Intro", "Chorus", "Chorus (2)", "Outro"]
+            );
+            check_eq!(errors, state.scenes[2].variation_depth, 1);
+            check_eq!(errors, state.scenes[2].scene_id.0, 4);
+            check_eq!(errors, state.scenes[2].positions[0].x, 0.0);
+            check_eq!(
+                errors,
+                state
+                    .selected_scene
+                    .as_ref()
+                    .map(|scene| scene.name.as_str()),
+                Some("Chorus (2)")
+            );
+            check_eq!(
+                errors,
+                state.scene_variations,
+                vec![Some("Chorus (2)".to_string()), None]
+            );
+            check_eq!(errors, state.can_promote_scene_variation, true);
+            assert_no_errors(errors);
+        });
+
+        spec.it("numbers further branches and keeps every variation", |_| {
+            let mut state = load_and_select(1);
+            reduce(&mut state, ScenesAction::BranchSceneVariation);
+            reduce(&mut state, ScenesAction::SelectScene { index: 1 });
+
+            reduce(&mut state, ScenesAction::BranchSceneVariation);
+
+            let mut errors = Vec::new();
+            check_eq!(
+                errors,
+                scene_names(&state),
+                vec!["Intro", "Chorus", "Chorus (3)", "Outro"]
+            );
+            check_eq!(
+                errors,
+                state.scene_variations,
+                vec![
+                    Some("Chorus (3)".to_string()),
+                    None,
+                    Some("Chorus (2)".to_string())
+                ]
+            );
+            check_eq!(errors, state.scenes[2].scene_id.0, 5);
+            assert_no_errors(errors);
+        });
+
+        spec.it("switches to a variation set aside", |_| {
+            let mut state = load_and_select(1);
+            reduce(&mut state, ScenesAction::BranchSceneVariation);
+
+            reduce(
+                &mut state,
+                ScenesAction::SwitchSceneVariation { variation: 0 },
+            );
+
+            let mut errors = Vec::new();
+            check_eq!(
+                errors,
+                scene_names(&state),
+                vec!["Intro", "Chorus", "Outro"]
+            );
+            check_eq!(
+                errors,
+                state
+                    .selected_scene
+                    .as_ref()
+                    .map(|scene| scene.name.as_str()),
+                Some("Chorus")
+            );
+            check_eq!(
+                errors,
+                state.scene_variations,
+                vec![None, Some("Chorus (2)".to_string())]
+            );
+
+            reduce(
+                &mut state,
+                ScenesAction::SwitchSceneVariation { variation: 3 },
+            );
+
+            check_eq!(
+                errors,
+                scene_names(&state),
+                vec!["Intro", "Chorus", "Outro"]
+            );
+            check_eq!(
+                errors,
+                state.variation_error,
+                Some(VariationError::UnknownVariation(3))
+            );
+            check_eq!(
+                errors,
+                variation_error_message("en", &VariationError::UnknownVariation(3)),
+                "There is no variation 4."
+            );
+            assert_no_errors(errors);
+        });
+
+        spec.it("promotes the active variation to the main line", |_| {
+            let mut state = load_and_select(1);
+            reduce(&mut state, ScenesAction::BranchSceneVariation);
+            reduce(
+                &mut state,
+                ScenesAction::CompareSceneVariation { variation: Some(0) },
+            );
+
+            reduce(&mut state, ScenesAction::PromoteSceneVariation);
+
+            let mut errors = Vec::new();
+            check_eq!(
+                errors,
+                scene_names(&state),
+                vec!["Intro", "Chorus", "Chorus (2)", "Outro"]
+            );
+            check_eq!(
+                errors,
+                state
+                    .choreography
+                    .scenes
+                    .iter()
+                    .map(|scene| scene.variation_depth)
+                    .collect::<Vec<_>>(),
+                vec![0, 0, 0, 0]
+            );
+            check_eq!(errors, state.choreography.scenes[1].variations.len(), 0);
+            check_eq!(errors, state.scene_variations.len(), 0);
+            check_eq!(errors, state.compared_scene_variation, None::<usize>);
+            check_eq!(errors, state.can_promote_scene_variation, false);
+            assert_no_errors(errors);
+        });
+
+        spec.it(
+            "compares a scene with the same place in another variation",
+            |_| {
+                let mut state = load_and_select(1);
+                reduce(&mut state, ScenesAction::BranchSceneVariation);
+                reduce(&mut state, ScenesAction::SelectScene { index: 1 });
+                reduce(&mut state, ScenesAction::BranchSceneVariation);
+                let scenes = &state.choreography.scenes;
+
+                let mut errors = Vec::new();
+                check_eq!(errors, variation_anchor(scenes, 2), Some(1));
+                check_eq!(errors, variation_anchor(scenes, 0), None::<usize>);
+                check_eq!(
+                    errors,
+                    comparison_scene(scenes, 1, 1, 2).map(|scene| scene.name.as_str()),
+                    Some("Chorus (2)")
+                );
+                check_eq!(
+                    errors,
+                    comparison_scene(scenes, 1, 0, 2).map(|scene| scene.name.as_str()),
+                    Some("Chorus")
+                );
+                check_eq!(
+                    errors,
+                    comparison_scene(scenes, 1, 2, 2).map(|scene| scene.name.as_str()),
+                    None::<&str>
+                );
+                assert_no_errors(errors);
+            },
+        );
+
+        spec.it(
+            "labels the variations and maps the dropdowns to actions",
+            |_| {
+                let mut state = load_and_select(1);
+                reduce(&mut state, ScenesAction::BranchSceneVariation);
+
+                let mut errors = Vec::new();
+                check_eq!(
+                    errors,
+                    scene_variation_labels(&state, "Without variation"),
+                    vec!["Chorus (2)", "Without variation"]
+                );
+                check_eq!(
+                    errors,
+                    compare_scene_variation_labels(&state, "Nothing", "Without variation"),
+                    vec!["Nothing", "Without variation"]
+                );
+                check_eq!(
+                    errors,
+                    switch_scene_variation_action(0),
+                    None::<ScenesAction>
+                );
+                check_eq!(
+                    errors,
+                    switch_scene_variation_action(1),
+                    Some(ScenesAction::SwitchSceneVariation { variation: 0 })
+                );
+                check_eq!(
+                    errors,
+                    compare_scene_variation_action(0),
+                    ScenesAction::CompareSceneVariation { variation: None }
+                );
+                check_eq!(
+                    errors,
+                    compare_scene_variation_action(1),
+                    ScenesAction::CompareSceneVariation { variation: Some(0) }
+                );
+                assert_no_errors(errors);
+            },
+        );
+    });
+
+    let report = crate::scenes::run_suite(&suite);
+    assert!(report.is_success());
+}
+
+fn model_names(scenes: &[SceneModel]) -> Vec<String> {
+    scenes.iter().map(|scene| scene.name.clone()).collect()
+}
+
+#[test]
+fn switched_variations_survive_saving_and_loading_a_choreo_file() {
+    let json = include_str!("../../../choreo_master_mobile_json/tests/data/Variations.choreo");
+    let mapper = ChoreographyModelMapper;
+    let mut choreography = mapper.map_to_model(&import(json).expect("fixture should import"));
+
+    switch_variation(&mut choreography.scenes, 1, 0).expect("the chorus has a variation");
+    let exported = export(&mapper.map_to_json(&choreography)).expect("export should succeed");
+    let reloaded = mapper.map_to_model(&import(&exported).expect("re-import should succeed"));
+
+    let mut errors = Vec::new();
+    check_eq!(
+        errors,
+        model_names(&reloaded.scenes),
+        vec!["Opening", "Chorus", "Chorus (2)", "Lift", "Finale"]
+    );
+    let anchor = &reloaded.scenes[1];
+    check_eq!(
+        errors,
+        anchor
+            .variations
+            .iter()
+            .map(|variation| model_names(variation))
+            .collect::<Vec<_>>(),
+        vec![vec!["Chorus (3)".to_string()]]
+    );
+    check_eq!(
+        errors,
+        model_names(&anchor.current_variation),
+        vec!["Chorus (3)"]
+    );
+    check_eq!(errors, reloaded.scenes[3].variation_depth, 1);
+
+    assert_no_errors(errors);
+}
@@ -3,6 +3,7 @@ use super::create_state;
 use super::ui::navigate_dancers_icon;
 use super::ui::navigate_settings_icon;
 use super::ui::open_choreography_icon;
+use super::ui::save_choreography_as_icon;
 use super::ui::save_choreography_icon;
 use super::ui::scene_add_after_icon;
 use super::ui::scene_add_before_icon;
@@ -48,6 +49,7 @@ fn scene_pane_action_flow_matches_original_controls_and_caps() {
     let mut state = create_state();
     state.can_delete_scene = true;
     state.can_save_choreo = true;
+    state.can_save_choreo_as = true;
     state.can_navigate_to_settings = true;
     state.can_navigate_to_dancer_settings = true;
 
@@ -66,6 +68,7 @@ fn scene_pane_action_flow_matches_original_controls_and_caps() {
             ScenesAction::OpenDeleteSceneDialog,
             ScenesAction::RequestOpenChoreography,
             ScenesAction::RequestSaveChoreography,
+            ScenesAction::RequestSaveChoreographyAs,
             ScenesAction::NavigateToSettings,
             ScenesAction::NavigateToDancerSettings,
         ]
@@ -112,6 +115,11 @@ fn scene_toolbar_icons_match_original_slint_catalog() {
     check_eq!(errors, scene_delete_icon().slint_name, "Delete");
     check_eq!(errors, open_choreography_icon().slint_name, "FolderOpen");
     check_eq!(errors, save_choreography_icon().slint_name, "ContentSave");
+    check_eq!(
+        errors,
+        save_choreography_as_icon().slint_name,
+        "ContentSaveEdit"
+    );
     check_eq!(errors, navigate_settings_icon().slint_name, "Cog");
     check_eq!(errors, navigate_dancers_icon().slint_name, "AccountGroup");
 
@@ -4,7 +4,7 @@ mod delete_scene_dialog;
 mod scene_list_item;
 
 mod time {
-    pub use choreo_components::time::format_seconds;
+    pub use choreo_components::time::counts;
 }
 
 pub use choreo_components::i18n;
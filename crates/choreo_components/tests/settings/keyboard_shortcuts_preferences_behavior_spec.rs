@@ -0,0 +1,253 @@
+use std::collections::BTreeMap;
+
+use egui::Key;
+use egui::KeyboardShortcut;
+use egui::Modifiers;
+
+use crate::settings::Report;
+use crate::settings::actions::SettingsAction;
+use crate::settings::keyboard_shortcuts::KEYBOARD_SHORTCUTS_KEY;
+use crate::settings::keyboard_shortcuts::KeyboardShortcuts;
+use crate::settings::keyboard_shortcuts::ShortcutCommand;
+use crate::settings::keyboard_shortcuts::parse_shortcut;
+use crate::settings::keyboard_shortcuts::shortcut_text;
+use crate::settings::reducer::reduce;
+use crate::settings::state::SettingsState;
+use crate::settings::ui::captured_shortcut_action;
+
+macro_rules! check_eq {
+    ($errors:expr, $left:expr, $right:expr) => {
+        if $left != $right {
+            $errors.push(format!(
+                "{} != {} (left = {:?}, right = {:?})",
+                stringify!($left),
+                stringify!($right),
+                $left,
+                $right
+            ));
+        }
+    };
+}
+
+macro_rules! check {
+    ($errors:expr, $condition:expr) => {
+        let passed: bool = $condition;
+        if !passed {
+            $errors.push(format!("condition failed: {}", stringify!($condition)));
+        }
+    };
+}
+
+fn assert_no_errors(errors: Vec<String>) {
+    assert!(
+        errors.is_empty(),
+        "Assertion failures:\n{}",
+        errors.join("\n")
+    );
+}
+
+fn plain(key: Key) -> KeyboardShortcut {
+    KeyboardShortcut::new(Modifiers::NONE, key)
+}
+
+/// The settings action a single key press produces while waiting for a shortcut.
+fn capture(command: ShortcutCommand, key: Key, modifiers: Modifiers) -> Option<SettingsAction> {
+    let context = egui::Context::default();
+    let mut action = None;
+    let _ = context.run(
+        egui::RawInput {
+            events: vec![egui::Event::Key {
+                key,
+                physical_key: None,
+                pressed: true,
+                repeat: false,
+                modifiers,
+            }],
+            modifiers,
+            ..egui::RawInput::default()
+        },
+        |context| {
+            action = captured_shortcut_action(context, command);
+        },
+    );
+    action
+}
+
+#[test]
+fn keyboard_shortcuts_preferences_behavior_spec() {
+    let suite = rspec::describe(
+        "keyboard shortcuts preferences reducer behavior",
+        (),
+        |spec| {
+            spec.it(
+                "binds arrows, numbers and the usual keys by default",
+                |_| {
+                    let shortcuts = KeyboardShortcuts::default();
+
+                    let mut errors = Vec::new();
+
+                    check_eq!(
+                        errors,
+                        shortcuts.command_for(Key::ArrowLeft, Modifiers::NONE),
+                        Some(ShortcutCommand::NudgeLeft)
+                    );
+                    check_eq!(
+                        errors,
+                        shortcuts.command_for(Key::ArrowUp, Modifiers::SHIFT),
+                        Some(ShortcutCommand::NudgeFront)
+                    );
+                    check_eq!(
+                        errors,
+                        shortcuts.command_for(Key::A, Modifiers::COMMAND),
+                        Some(ShortcutCommand::SelectAll)
+                    );
+                    check_eq!(
+                        errors,
+                        shortcuts.command_for(Key::A, Modifiers::NONE),
+                        None::<ShortcutCommand>
+                    );
+                    check_eq!(
+                        errors,
+                        shortcuts.command_for(Key::Space, Modifiers::SHIFT),
+                        None::<ShortcutCommand>
+                    );
+                    check_eq!(
+                        errors,
+                        shortcuts.command_for(Key::Num9, Modifiers::NONE),
+                        Some(ShortcutCommand::BendPathMode)
+                    );
+                    check_eq!(errors, ShortcutCommand::BendPathMode.mode_index(), Some(8));
+
+                    assert_no_errors(errors);
+                },
+            );
+
+            spec.it(
+                "swaps shortcuts when a remap takes one already in use",
+                |_| {
+                    let mut state = SettingsState::default();
+                    reduce(
+                        &mut state,
+                        SettingsAction::CaptureShortcut {
+                            command: Some(ShortcutCommand::NextScene),
+                        },
+                    );
+                    reduce(
+                        &mut state,
+                        SettingsAction::RemapShortcut {
+                            command: ShortcutCommand::NextScene,
+                            shortcut: plain(Key::PageUp),
+                        },
+                    );
+
+                    let mut errors = Vec::new();
+
+                    check_eq!(errors, state.capturing_shortcut, None::<ShortcutCommand>);
+                    check_eq!(
+                        errors,
+                        state
+                            .keyboard_shortcuts
+                            .shortcut(ShortcutCommand::NextScene),
+                        plain(Key::PageUp)
+                    );
+                    check_eq!(
+                        errors,
+                        state
+                            .keyboard_shortcuts
+                            .shortcut(ShortcutCommand::PreviousScene),
+                        plain(Key::PageDown)
+                    );
+                    check!(
+                        errors,
+                        state.preferences.contains_key(KEYBOARD_SHORTCUTS_KEY)
+                    );
+
+                    reduce(&mut state, SettingsAction::ResetShortcuts);
+
+                    check!(errors, state.keyboard_shortcuts.is_default());
+                    check!(
+                        errors,
+                        !state.preferences.contains_key(KEYBOARD_SHORTCUTS_KEY)
+                    );
+
+                    assert_no_errors(errors);
+                },
+            );
+
+            spec.it(
+                "loads remapped shortcuts and keeps defaults for the rest",
+                |_| {
+                    let mut remapped = KeyboardShortcuts::default();
+                    remapped.remap(
+                        ShortcutCommand::TogglePlayback,
+                        KeyboardShortcut::new(Modifiers::COMMAND.plus(Modifiers::SHIFT), Key::P),
+                    );
+                    let stored = remapped.to_preference();
+
+                    let mut state = SettingsState::default();
+                    let mut preferences = BTreeMap::new();
+                    preferences.insert(KEYBOARD_SHORTCUTS_KEY.to_string(), stored);
+                    reduce(
+                        &mut state,
+                        SettingsAction::LoadFromPreferences {
+                            entries: preferences,
+                        },
+                    );
+
+                    let mut errors = Vec::new();
+
+                    check_eq!(errors, state.keyboard_shortcuts, remapped);
+                    check_eq!(
+                        errors,
+                        shortcut_text(
+                            state
+                                .keyboard_shortcuts
+                                .shortcut(ShortcutCommand::TogglePlayback)
+                        ),
+                        "Ctrl+Shift+P"
+                    );
+                    check_eq!(
+                        errors,
+                        KeyboardShortcuts::from_preference(r#"{"select_all":"Alt+Q","jump":"J"}"#)
+                            .shortcut(ShortcutCommand::SelectAll),
+                        KeyboardShortcut::new(Modifiers::ALT, Key::Q)
+                    );
+                    check!(
+                        errors,
+                        KeyboardShortcuts::from_preference("not json").is_default()
+                    );
+                    check_eq!(
+                        errors,
+                        parse_shortcut("Ctrl+Nope"),
+                        None::<KeyboardShortcut>
+                    );
+
+                    assert_no_errors(errors);
+                },
+            );
+
+            spec.it("takes the next key press as the new shortcut", |_| {
+                let mut errors = Vec::new();
+
+                check_eq!(
+                    errors,
+                    capture(ShortcutCommand::Delete, Key::X, Modifiers::ALT),
+                    Some(SettingsAction::RemapShortcut {
+                        command: ShortcutCommand::Delete,
+                        shortcut: KeyboardShortcut::new(Modifiers::ALT, Key::X),
+                    })
+                );
+                check_eq!(
+                    errors,
+                    capture(ShortcutCommand::Delete, Key::Escape, Modifiers::NONE),
+                    Some(SettingsAction::CaptureShortcut { command: None })
+                );
+
+                assert_no_errors(errors);
+            });
+        },
+    );
+
+    let report = crate::settings::run_suite(&suite);
+    assert!(report.is_success());
+}
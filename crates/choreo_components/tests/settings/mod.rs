@@ -6,6 +6,7 @@ use rspec::Logger;
 use rspec::Runner;
 
 pub use choreo_components::settings::actions;
+pub use choreo_components::settings::keyboard_shortcuts;
 pub use choreo_components::settings::provider;
 pub use choreo_components::settings::reducer;
 pub use choreo_components::settings::state;
@@ -31,8 +32,10 @@ where
 
 pub mod audio_backend_preferences_behavior_spec;
 pub mod color_preferences_behavior_spec;
+pub mod keyboard_shortcuts_preferences_behavior_spec;
 pub mod load_settings_preferences_behavior_spec;
 pub mod material_theme_application_spec;
+pub mod playback_speed_preferences_behavior_spec;
 pub mod provider_pipeline_spec;
 pub mod switch_dark_light_mode_behavior_spec;
 pub mod ui_smoke_spec;
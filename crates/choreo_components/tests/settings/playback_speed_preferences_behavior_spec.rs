@@ -0,0 +1,127 @@
+use std::collections::BTreeMap;
+
+use crate::settings::Report;
+use crate::settings::actions::SettingsAction;
+use crate::settings::reducer::reduce;
+use crate::settings::state::MAXIMUM_PLAYBACK_SPEED_KEY;
+use crate::settings::state::MINIMUM_PLAYBACK_SPEED_KEY;
+use crate::settings::state::SettingsState;
+
+macro_rules! check_eq {
+    ($errors:expr, $left:expr, $right:expr) => {
+        if $left != $right {
+            $errors.push(format!(
+                "{} != {} (left = {:?}, right = {:?})",
+                stringify!($left),
+                stringify!($right),
+                $left,
+                $right
+            ));
+        }
+    };
+}
+
+fn assert_no_errors(errors: Vec<String>) {
+    assert!(
+        errors.is_empty(),
+        "Assertion failures:\n{}",
+        errors.join("\n")
+    );
+}
+
+#[test]
+fn playback_speed_preferences_behavior_spec() {
+    let suite = rspec::describe("playback speed preferences reducer behavior", (), |spec| {
+        spec.it(
+            "defaults to the full half to one and a half speed range",
+            |_| {
+                let state = SettingsState::default();
+
+                let mut errors = Vec::new();
+
+                check_eq!(errors, state.minimum_playback_speed_percent, 50);
+                check_eq!(errors, state.maximum_playback_speed_percent, 150);
+
+                assert_no_errors(errors);
+            },
+        );
+
+        spec.it("stores the selected range ends in preferences", |_| {
+            let mut state = SettingsState::default();
+            reduce(
+                &mut state,
+                SettingsAction::UpdateMinimumPlaybackSpeed { percent: 70 },
+            );
+            reduce(
+                &mut state,
+                SettingsAction::UpdateMaximumPlaybackSpeed { percent: 120 },
+            );
+
+            let mut errors = Vec::new();
+
+            check_eq!(errors, state.minimum_playback_speed_percent, 70);
+            check_eq!(errors, state.maximum_playback_speed_percent, 120);
+            check_eq!(
+                errors,
+                state
+                    .preferences
+                    .get(MINIMUM_PLAYBACK_SPEED_KEY)
+                    .map(String::as_str),
+                Some("70")
+            );
+            check_eq!(
+                errors,
+                state
+                    .preferences
+                    .get(MAXIMUM_PLAYBACK_SPEED_KEY)
+                    .map(String::as_str),
+                Some("120")
+            );
+
+            assert_no_errors(errors);
+        });
+
+        spec.it("ignores speeds outside the offered choices", |_| {
+            let mut state = SettingsState::default();
+            reduce(
+                &mut state,
+                SettingsAction::UpdateMinimumPlaybackSpeed { percent: 20 },
+            );
+            reduce(
+                &mut state,
+                SettingsAction::UpdateMaximumPlaybackSpeed { percent: 300 },
+            );
+
+            let mut errors = Vec::new();
+
+            check_eq!(errors, state.minimum_playback_speed_percent, 50);
+            check_eq!(errors, state.maximum_playback_speed_percent, 150);
+            check_eq!(errors, state.preferences.len(), 0);
+
+            assert_no_errors(errors);
+        });
+
+        spec.it("loads the range and falls back for unknown values", |_| {
+            let mut state = SettingsState::default();
+            let mut preferences = BTreeMap::new();
+            preferences.insert(MINIMUM_PLAYBACK_SPEED_KEY.to_string(), "80".to_string());
+            preferences.insert(MAXIMUM_PLAYBACK_SPEED_KEY.to_string(), "fast".to_string());
+            reduce(
+                &mut state,
+                SettingsAction::LoadFromPreferences {
+                    entries: preferences,
+                },
+            );
+
+            let mut errors = Vec::new();
+
+            check_eq!(errors, state.minimum_playback_speed_percent, 80);
+            check_eq!(errors, state.maximum_playback_speed_percent, 150);
+
+            assert_no_errors(errors);
+        });
+    });
+
+    let report = crate::settings::run_suite(&suite);
+    assert!(report.is_success());
+}
@@ -207,6 +207,8 @@ fn settings_card_headers_follow_requested_order() {
     if shows_audio_backend_card() {
         expected.push("Audio backend".to_string());
     }
+    expected.push("Playback speed".to_string());
+    expected.push("Keyboard shortcuts".to_string());
 
     assert_eq!(visible_settings_card_headers(&state, &strings), expected);
 }
@@ -0,0 +1,69 @@
+use std::rc::Rc;
+
+use choreo_master_mobile_json::Color;
+use choreo_master_mobile_json::DancerId;
+use choreo_master_mobile_json::SceneId;
+use choreo_models::DancerModel;
+use choreo_models::PositionModel;
+use choreo_models::RoleModel;
+use choreo_models::SceneModel;
+
+pub fn build_dancer(dancer_id: i32, name: &str) -> Rc<DancerModel> {
+    Rc::new(DancerModel {
+        dancer_id: DancerId(dancer_id),
+        role: Rc::new(RoleModel {
+            z_index: 0,
+            name: "Role".to_string(),
+            color: Color::transparent(),
+        }),
+        name: name.to_string(),
+        shortcut: name.to_string(),
+        color: Color::transparent(),
+        icon: None,
+    })
+}
+
+pub fn build_position(x: f64, y: f64) -> PositionModel {
+    PositionModel {
+        dancer: None,
+        required_role: None,
+        orientation: None,
+        x,
+        y,
+        curve1_x: None,
+        curve1_y: None,
+        curve2_x: None,
+        curve2_y: None,
+        movement1_x: None,
+        movement1_y: None,
+        movement2_x: None,
+        movement2_y: None,
+    }
+}
+
+pub fn dancer_position(dancer: &Rc<DancerModel>, x: f64, y: f64) -> PositionModel {
+    PositionModel {
+        dancer: Some(Rc::clone(dancer)),
+        ..build_position(x, y)
+    }
+}
+
+pub fn scene_model(
+    scene_id: i32,
+    name: &str,
+    timestamp: Option<&str>,
+    positions: Vec<PositionModel>,
+) -> SceneModel {
+    SceneModel {
+        scene_id: SceneId(scene_id),
+        positions,
+        name: name.to_string(),
+        text: None,
+        fixed_positions: false,
+        timestamp: timestamp.map(str::to_string),
+        variation_depth: 0,
+        variations: Vec::new(),
+        current_variation: Vec::new(),
+        color: Color::transparent(),
+    }
+}
@@ -0,0 +1,115 @@
+use choreo_master_mobile_json::MusicalTimeline;
+
+use super::time_component::counts::CountPosition;
+use super::time_component::counts::MAXIMUM_BPM;
+use super::time_component::counts::MINIMUM_BPM;
+use super::time_component::counts::TIME_SIGNATURE_OPTIONS;
+use super::time_component::counts::count_position_for_seconds;
+use super::time_component::counts::counts_per_eight;
+use super::time_component::counts::format_scene_time;
+use super::time_component::counts::sanitize_musical_timeline;
+use super::time_component::counts::seconds_for_count_position;
+use super::time_component::counts::seconds_per_count;
+use super::time_component::counts::time_signature_text;
+
+fn waltz() -> MusicalTimeline {
+    MusicalTimeline {
+        bpm: 90.0,
+        offset_seconds: 0.0,
+        beats_per_bar: 3,
+        beat_unit: 4,
+    }
+}
+
+#[test]
+fn counts_per_eight_spans_two_bars() {
+    assert_eq!(counts_per_eight(&MusicalTimeline::default()), 8);
+    assert_eq!(counts_per_eight(&waltz()), 6);
+}
+
+#[test]
+fn counts_per_eight_keeps_eight_counts_in_compound_meters() {
+    let six_eight = MusicalTimeline {
+        beats_per_bar: 6,
+        beat_unit: 8,
+        ..MusicalTimeline::default()
+    };
+
+    assert_eq!(counts_per_eight(&six_eight), 8);
+    assert_eq!(
+        count_position_for_seconds(&six_eight, 8.0 * seconds_per_count(&six_eight)),
+        CountPosition { eight: 2, count: 1 }
+    );
+    assert_eq!(
+        format_scene_time(7.0 * seconds_per_count(&six_eight), Some(&six_eight)),
+        "1·8"
+    );
+}
+
+#[test]
+fn seconds_for_count_position_adds_the_first_count_offset() {
+    let timeline = MusicalTimeline {
+        offset_seconds: 0.5,
+        ..MusicalTimeline::default()
+    };
+
+    super::assert_close(seconds_per_count(&timeline), 0.5, 0.000_001);
+    super::assert_close(
+        seconds_for_count_position(&timeline, CountPosition { eight: 2, count: 3 }),
+        5.5,
+        0.000_001,
+    );
+}
+
+#[test]
+fn count_position_for_seconds_rounds_to_the_nearest_count() {
+    // Six counts per eight in 3/4: count 7 is the first count of the second eight.
+    assert_eq!(
+        count_position_for_seconds(&waltz(), 6.0 * 60.0 / 90.0 + 0.1),
+        CountPosition { eight: 2, count: 1 }
+    );
+    assert_eq!(
+        count_position_for_seconds(&waltz(), -4.0),
+        CountPosition { eight: 1, count: 1 }
+    );
+}
+
+#[test]
+fn format_scene_time_uses_counts_only_with_a_timeline() {
+    assert_eq!(format_scene_time(10.0, Some(&waltz())), "3·4");
+    assert_eq!(format_scene_time(12.25, None), "12.25");
+}
+
+#[test]
+fn sanitize_musical_timeline_clamps_to_usable_values() {
+    let sanitized = sanitize_musical_timeline(MusicalTimeline {
+        bpm: 1000.0,
+        offset_seconds: f64::NAN,
+        beats_per_bar: 0,
+        beat_unit: 64,
+    });
+
+    assert_eq!(sanitized.bpm, MAXIMUM_BPM);
+    assert_eq!(sanitized.offset_seconds, 0.0);
+    assert_eq!(sanitized.beats_per_bar, 1);
+    assert_eq!(sanitized.beat_unit, 32);
+    assert_eq!(
+        sanitize_musical_timeline(MusicalTimeline {
+            bpm: 0.0,
+            ..MusicalTimeline::default()
+        })
+        .bpm,
+        MINIMUM_BPM
+    );
+}
+
+#[test]
+fn time_signature_options_are_labelled_as_fractions() {
+    let labels: Vec<String> = TIME_SIGNATURE_OPTIONS
+        .iter()
+        .map(|(beats_per_bar, beat_unit)| time_signature_text(*beats_per_bar, *beat_unit))
+        .collect();
+
+    assert_eq!(labels.first().map(String::as_str), Some("2/4"));
+    assert!(labels.contains(&"3/4".to_string()));
+}
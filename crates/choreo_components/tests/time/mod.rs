@@ -1,3 +1,4 @@
+pub mod counts_spec;
 pub mod time_mod_spec;
 
 #[path = "../../src/time/mod.rs"]
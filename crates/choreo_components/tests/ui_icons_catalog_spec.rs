@@ -47,6 +47,14 @@ fn key_component_icon_mappings_match_expected_tokens() {
     check_eq!(errors, nav_close.token, "close");
     check_eq!(errors, nav_close.slint_name, "Close");
 
+    let nav_undo = icons::icon(UiIconKey::NavUndo);
+    check_eq!(errors, nav_undo.token, "undo");
+    check_eq!(errors, nav_undo.slint_name, "Undo");
+
+    let nav_redo = icons::icon(UiIconKey::NavRedo);
+    check_eq!(errors, nav_redo.token, "redo");
+    check_eq!(errors, nav_redo.slint_name, "Redo");
+
     let audio_link = icons::icon(UiIconKey::AudioLink);
     check_eq!(errors, audio_link.token, "link");
     check_eq!(errors, audio_link.slint_name, "Link");
@@ -63,10 +71,54 @@ fn key_component_icon_mappings_match_expected_tokens() {
     check_eq!(errors, scenes_delete.token, "delete");
     check_eq!(errors, scenes_delete.slint_name, "Delete");
 
+    let scenes_auto_assign = icons::icon(UiIconKey::ScenesAutoAssignDancers);
+    check_eq!(errors, scenes_auto_assign.token, "assignment_ind");
+    check_eq!(errors, scenes_auto_assign.slint_name, "AccountArrowRight");
+
+    let scenes_in_between = icons::icon(UiIconKey::ScenesInsertInBetween);
+    check_eq!(errors, scenes_in_between.token, "transition");
+    check_eq!(errors, scenes_in_between.slint_name, "Transition");
+
+    let scenes_bend_paths = icons::icon(UiIconKey::ScenesBendCollidingPaths);
+    check_eq!(errors, scenes_bend_paths.token, "alt_route");
+    check_eq!(errors, scenes_bend_paths.slint_name, "Routes");
+
+    let scenes_branch = icons::icon(UiIconKey::ScenesBranchVariation);
+    check_eq!(errors, scenes_branch.token, "fork_right");
+    check_eq!(errors, scenes_branch.slint_name, "SourceBranchPlus");
+
+    let scenes_promote = icons::icon(UiIconKey::ScenesPromoteVariation);
+    check_eq!(errors, scenes_promote.token, "merge");
+    check_eq!(errors, scenes_promote.slint_name, "SourceMerge");
+
+    let scenes_duplicate = icons::icon(UiIconKey::ScenesDuplicate);
+    check_eq!(errors, scenes_duplicate.token, "content_copy");
+    check_eq!(errors, scenes_duplicate.slint_name, "ContentDuplicate");
+
+    let scenes_shift_earlier = icons::icon(UiIconKey::ScenesShiftEarlier);
+    check_eq!(errors, scenes_shift_earlier.token, "fast_rewind");
+    check_eq!(errors, scenes_shift_earlier.slint_name, "Rewind");
+
+    let scenes_shift_later = icons::icon(UiIconKey::ScenesShiftLater);
+    check_eq!(errors, scenes_shift_later.token, "fast_forward");
+    check_eq!(errors, scenes_shift_later.slint_name, "FastForward");
+
+    let scenes_apply_formation = icons::icon(UiIconKey::ScenesApplyFormation);
+    check_eq!(errors, scenes_apply_formation.token, "scatter_plot");
+    check_eq!(errors, scenes_apply_formation.slint_name, "ScatterPlot");
+
+    let scenes_save_template = icons::icon(UiIconKey::ScenesSaveFormationTemplate);
+    check_eq!(errors, scenes_save_template.token, "bookmark_add");
+    check_eq!(errors, scenes_save_template.slint_name, "BookmarkPlus");
+
     let scenes_open = icons::icon(UiIconKey::ScenesOpenChoreography);
     check_eq!(errors, scenes_open.token, "folder_open");
     check_eq!(errors, scenes_open.slint_name, "FolderOpen");
 
+    let scenes_save_as = icons::icon(UiIconKey::ScenesSaveChoreographyAs);
+    check_eq!(errors, scenes_save_as.token, "save_as");
+    check_eq!(errors, scenes_save_as.slint_name, "ContentSaveEdit");
+
     let scenes_dancers = icons::icon(UiIconKey::ScenesNavigateDancers);
     check_eq!(errors, scenes_dancers.token, "groups");
     check_eq!(errors, scenes_dancers.slint_name, "AccountGroup");
@@ -94,6 +146,8 @@ fn component_icon_svgs_resolve_from_slint_catalog_assets() {
         UiIconKey::NavOpen,
         UiIconKey::NavClose,
         UiIconKey::NavSettings,
+        UiIconKey::NavUndo,
+        UiIconKey::NavRedo,
         UiIconKey::AudioOpenPanel,
         UiIconKey::AudioPlay,
         UiIconKey::AudioPause,
@@ -101,8 +155,19 @@ fn component_icon_svgs_resolve_from_slint_catalog_assets() {
         UiIconKey::ScenesAddBefore,
         UiIconKey::ScenesAddAfter,
         UiIconKey::ScenesDelete,
+        UiIconKey::ScenesAutoAssignDancers,
+        UiIconKey::ScenesInsertInBetween,
+        UiIconKey::ScenesBendCollidingPaths,
+        UiIconKey::ScenesBranchVariation,
+        UiIconKey::ScenesPromoteVariation,
+        UiIconKey::ScenesDuplicate,
+        UiIconKey::ScenesShiftEarlier,
+        UiIconKey::ScenesShiftLater,
+        UiIconKey::ScenesApplyFormation,
+        UiIconKey::ScenesSaveFormationTemplate,
         UiIconKey::ScenesOpenChoreography,
         UiIconKey::ScenesSaveChoreography,
+        UiIconKey::ScenesSaveChoreographyAs,
         UiIconKey::ScenesNavigateSettings,
         UiIconKey::ScenesNavigateDancers,
         UiIconKey::SettingsNavigateBack,
@@ -4,6 +4,7 @@ pub mod dancer;
 pub mod floor;
 pub mod front_position;
 pub mod ids;
+pub mod musical_timeline;
 pub mod position;
 pub mod role;
 pub mod scene;
@@ -15,6 +16,7 @@ pub use dancer::Dancer;
 pub use floor::Floor;
 pub use front_position::FrontPosition;
 pub use ids::{DancerId, SceneId};
+pub use musical_timeline::MusicalTimeline;
 pub use position::Position;
 pub use role::Role;
 pub use scene::Scene;
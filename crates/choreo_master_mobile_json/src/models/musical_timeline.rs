@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+/// Tempo and meter that let scenes be placed on counts instead of seconds.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct MusicalTimeline {
+    #[serde(rename = "Bpm")]
+    pub bpm: f64,
+    /// Seconds into the music where the first count falls.
+    #[serde(rename = "OffsetSeconds")]
+    pub offset_seconds: f64,
+    #[serde(rename = "BeatsPerBar")]
+    pub beats_per_bar: u32,
+    #[serde(rename = "BeatUnit")]
+    pub beat_unit: u32,
+}
+
+impl Default for MusicalTimeline {
+    fn default() -> Self {
+        Self {
+            bpm: 120.0,
+            offset_seconds: 0.0,
+            beats_per_bar: 4,
+            beat_unit: 4,
+        }
+    }
+}
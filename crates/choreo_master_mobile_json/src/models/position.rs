@@ -1,11 +1,13 @@
 use serde::{Deserialize, Serialize};
 
-use super::Dancer;
+use super::{Dancer, Role};
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct Position {
     #[serde(rename = "Dancer", skip_serializing, skip_deserializing)]
     pub dancer: Option<Dancer>,
+    #[serde(rename = "RequiredRole", skip_serializing, skip_deserializing)]
+    pub required_role: Option<Role>,
     #[serde(rename = "O", skip_serializing_if = "Option::is_none")]
     pub orientation: Option<f64>,
     #[serde(rename = "X")]
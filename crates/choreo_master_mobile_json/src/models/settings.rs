@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use super::{Color, FrontPosition};
+use super::{Color, FrontPosition, MusicalTimeline};
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Settings {
@@ -30,6 +30,12 @@ pub struct Settings {
     pub music_path_absolute: Option<String>,
     #[serde(rename = "MusicPathRelative")]
     pub music_path_relative: Option<String>,
+    #[serde(
+        rename = "MusicalTimeline",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub musical_timeline: Option<MusicalTimeline>,
 }
 
 fn default_snap_to_grid() -> bool {
@@ -52,6 +58,7 @@ impl Default for Settings {
             show_timestamps: false,
             music_path_absolute: None,
             music_path_relative: None,
+            musical_timeline: None,
         }
     }
 }
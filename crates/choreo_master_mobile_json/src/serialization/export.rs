@@ -44,7 +44,7 @@ fn to_value(choreography: &Choreography) -> Result<Value, ChoreoJsonError> {
 
     let roles_value = export_roles(&choreography.roles)?;
     let (dancers_value, dancer_ids) = export_dancers(&choreography.dancers, &choreography.roles)?;
-    let scenes_value = export_scenes(&choreography.scenes, &dancer_ids)?;
+    let scenes_value = export_scenes(&choreography.scenes, &dancer_ids, &choreography.roles)?;
 
     root.insert("Roles".to_string(), roles_value);
     root.insert("Dancers".to_string(), dancers_value);
@@ -113,7 +113,7 @@ fn export_dancers(
         } else {
             (index + 1) as i32
         };
-        let role_id = role_id_for_dancer(roles, dancer).unwrap_or_else(|| "1".to_string());
+        let role_id = role_id(roles, &dancer.role).unwrap_or_else(|| "1".to_string());
 
         let mut map = Map::new();
         map.insert("$id".to_string(), Value::String(id.to_string()));
@@ -134,16 +134,17 @@ fn export_dancers(
     Ok((Value::Array(list), ids))
 }
 
-fn role_id_for_dancer(roles: &[Role], dancer: &Dancer) -> Option<String> {
+fn role_id(roles: &[Role], role: &Role) -> Option<String> {
     roles
         .iter()
-        .position(|role| role == &dancer.role)
+        .position(|candidate| candidate == role)
         .map(|index| (index + 1).to_string())
 }
 
 fn export_scenes(
     scenes: &[Scene],
     dancer_ids: &HashMap<DancerId, String>,
+    roles: &[Role],
 ) -> Result<Value, ChoreoJsonError> {
     let mut list = Vec::with_capacity(scenes.len());
     for (index, scene) in scenes.iter().enumerate() {
@@ -152,7 +153,7 @@ fn export_scenes(
         } else {
             (index + 1) as i32
         };
-        list.push(export_scene(scene, id, dancer_ids)?);
+        list.push(export_scene(scene, id, dancer_ids, roles)?);
     }
     Ok(Value::Array(list))
 }
@@ -161,13 +162,14 @@ fn export_scene(
     scene: &Scene,
     id: i32,
     dancer_ids: &HashMap<DancerId, String>,
+    roles: &[Role],
 ) -> Result<Value, ChoreoJsonError> {
     let mut map = Map::new();
     map.insert("$id".to_string(), Value::String(id.to_string()));
     if let Some(positions) = &scene.positions {
         map.insert(
             "Positions".to_string(),
-            export_positions(positions, dancer_ids)?,
+            export_positions(positions, dancer_ids, roles)?,
         );
     }
     map.insert("Name".to_string(), Value::String(scene.name.clone()));
@@ -188,13 +190,13 @@ fn export_scene(
     if let Some(variations) = &scene.variations {
         map.insert(
             "Variations".to_string(),
-            export_scene_variations(variations, dancer_ids)?,
+            export_scene_variations(variations, dancer_ids, roles)?,
         );
     }
     if let Some(current) = &scene.current_variation {
         map.insert(
             "CurrentVariation".to_string(),
-            export_scene_list(current, dancer_ids)?,
+            export_scene_list(current, dancer_ids, roles)?,
         );
     }
     map.insert("Color".to_string(), Value::String(scene.color.to_hex()));
@@ -205,6 +207,7 @@ fn export_scene(
 fn export_positions(
     positions: &[Position],
     dancer_ids: &HashMap<DancerId, String>,
+    roles: &[Role],
 ) -> Result<Value, ChoreoJsonError> {
     let mut list = Vec::with_capacity(positions.len());
     for position in positions {
@@ -217,6 +220,16 @@ fn export_positions(
         {
             map.insert("Dancer".to_string(), Value::Object(ref_map("$ref", ref_id)));
         }
+        if let Some(role_id) = position
+            .required_role
+            .as_ref()
+            .and_then(|role| role_id(roles, role))
+        {
+            map.insert(
+                "RequiredRole".to_string(),
+                Value::Object(ref_map("$ref", &role_id)),
+            );
+        }
         list.push(Value::Object(map));
     }
     Ok(Value::Array(list))
@@ -225,10 +238,11 @@ fn export_positions(
 fn export_scene_variations(
     variations: &[Vec<Scene>],
     dancer_ids: &HashMap<DancerId, String>,
+    roles: &[Role],
 ) -> Result<Value, ChoreoJsonError> {
     let mut list = Vec::with_capacity(variations.len());
     for variation in variations {
-        list.push(export_scene_list(variation, dancer_ids)?);
+        list.push(export_scene_list(variation, dancer_ids, roles)?);
     }
     Ok(Value::Array(list))
 }
@@ -236,6 +250,7 @@ fn export_scene_variations(
 fn export_scene_list(
     scenes: &[Scene],
     dancer_ids: &HashMap<DancerId, String>,
+    roles: &[Role],
 ) -> Result<Value, ChoreoJsonError> {
     let mut list = Vec::with_capacity(scenes.len());
     for (index, scene) in scenes.iter().enumerate() {
@@ -244,7 +259,7 @@ fn export_scene_list(
         } else {
             (index + 1) as i32
         };
-        list.push(export_scene(scene, id, dancer_ids)?);
+        list.push(export_scene(scene, id, dancer_ids, roles)?);
     }
     Ok(Value::Array(list))
 }
@@ -57,7 +57,7 @@ fn from_value(value: &Value) -> Result<Choreography, ChoreoJsonError> {
 
     let (roles, role_ids) = parse_roles(root.get("Roles"))?;
     let (dancers, dancer_ids) = parse_dancers(root.get("Dancers"), &role_ids)?;
-    let scenes = parse_scenes(root.get("Scenes"), &dancer_ids)?;
+    let scenes = parse_scenes(root.get("Scenes"), &dancer_ids, &role_ids)?;
 
     Ok(Choreography {
         comment,
@@ -163,6 +163,7 @@ fn parse_role_ref(
 fn parse_scenes(
     value: Option<&Value>,
     dancers_by_id: &HashMap<String, Dancer>,
+    roles_by_id: &HashMap<String, Role>,
 ) -> Result<Vec<Scene>, ChoreoJsonError> {
     let list = value
         .and_then(Value::as_array)
@@ -170,7 +171,7 @@ fn parse_scenes(
 
     let mut scenes = Vec::with_capacity(list.len());
     for item in list {
-        scenes.push(parse_scene(item, dancers_by_id)?);
+        scenes.push(parse_scene(item, dancers_by_id, roles_by_id)?);
     }
     Ok(scenes)
 }
@@ -178,6 +179,7 @@ fn parse_scenes(
 fn parse_scene(
     value: &Value,
     dancers_by_id: &HashMap<String, Dancer>,
+    roles_by_id: &HashMap<String, Role>,
 ) -> Result<Scene, ChoreoJsonError> {
     let obj = value
         .as_object()
@@ -195,14 +197,22 @@ fn parse_scene(
     }
 
     if let Some(positions_value) = obj.get("Positions") {
-        scene.positions = Some(parse_positions(positions_value, dancers_by_id)?);
+        scene.positions = Some(parse_positions(
+            positions_value,
+            dancers_by_id,
+            roles_by_id,
+        )?);
     }
 
     if let Some(variations) = obj.get("Variations") {
-        scene.variations = Some(parse_scene_variations(variations, dancers_by_id)?);
+        scene.variations = Some(parse_scene_variations(
+            variations,
+            dancers_by_id,
+            roles_by_id,
+        )?);
     }
     if let Some(current) = obj.get("CurrentVariation") {
-        scene.current_variation = Some(parse_scene_list(current, dancers_by_id)?);
+        scene.current_variation = Some(parse_scene_list(current, dancers_by_id, roles_by_id)?);
     }
 
     Ok(scene)
@@ -211,6 +221,7 @@ fn parse_scene(
 fn parse_positions(
     value: &Value,
     dancers_by_id: &HashMap<String, Dancer>,
+    roles_by_id: &HashMap<String, Role>,
 ) -> Result<Vec<Position>, ChoreoJsonError> {
     let list = value
         .as_array()
@@ -222,6 +233,9 @@ fn parse_positions(
             .ok_or(ChoreoJsonError::ExpectedObject("Position"))?;
         let mut position: Position = serde_json::from_value(Value::Object(obj.clone()))?;
         position.dancer = parse_dancer_ref(obj.get("Dancer"), dancers_by_id)?;
+        if let Some(role_value) = obj.get("RequiredRole") {
+            position.required_role = Some(parse_role_ref(Some(role_value), roles_by_id)?);
+        }
         positions.push(position);
     }
     Ok(positions)
@@ -266,13 +280,14 @@ fn parse_dancer_ref(
 fn parse_scene_variations(
     value: &Value,
     dancers_by_id: &HashMap<String, Dancer>,
+    roles_by_id: &HashMap<String, Role>,
 ) -> Result<Vec<Vec<Scene>>, ChoreoJsonError> {
     let outer = value
         .as_array()
         .ok_or(ChoreoJsonError::ExpectedArray("Variations"))?;
     let mut variations = Vec::with_capacity(outer.len());
     for item in outer {
-        variations.push(parse_scene_list(item, dancers_by_id)?);
+        variations.push(parse_scene_list(item, dancers_by_id, roles_by_id)?);
     }
     Ok(variations)
 }
@@ -280,13 +295,14 @@ fn parse_scene_variations(
 fn parse_scene_list(
     value: &Value,
     dancers_by_id: &HashMap<String, Dancer>,
+    roles_by_id: &HashMap<String, Role>,
 ) -> Result<Vec<Scene>, ChoreoJsonError> {
     let list = value
         .as_array()
         .ok_or(ChoreoJsonError::ExpectedArray("SceneList"))?;
     let mut scenes = Vec::with_capacity(list.len());
     for item in list {
-        scenes.push(parse_scene(item, dancers_by_id)?);
+        scenes.push(parse_scene(item, dancers_by_id, roles_by_id)?);
     }
     Ok(scenes)
 }
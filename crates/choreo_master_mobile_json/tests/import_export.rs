@@ -1,4 +1,4 @@
-use choreo_master_mobile_json::{export, import};
+use choreo_master_mobile_json::{MusicalTimeline, export, import};
 
 #[test]
 fn import_sample_choreo() {
@@ -24,3 +24,85 @@ fn export_round_trip() {
     assert_eq!(round_trip.dancers.len(), choreography.dancers.len());
     assert_eq!(round_trip.scenes.len(), choreography.scenes.len());
 }
+
+#[test]
+fn export_round_trip_keeps_required_role() {
+    let json = include_str!("data/Test.choreo");
+    let mut choreography = import(json).expect("import should succeed");
+    let role = choreography.roles[0].clone();
+    choreography.scenes[0]
+        .positions
+        .as_mut()
+        .expect("positions")[0]
+        .required_role = Some(role.clone());
+
+    let exported = export(&choreography).expect("export should succeed");
+    let round_trip = import(&exported).expect("re-import should succeed");
+
+    let positions = round_trip.scenes[0].positions.as_ref().expect("positions");
+    assert_eq!(positions[0].required_role.as_ref(), Some(&role));
+    assert!(positions[1].required_role.is_none());
+}
+
+#[test]
+fn export_round_trip_keeps_musical_timeline() {
+    let json = include_str!("data/Test.choreo");
+    let mut choreography = import(json).expect("import should succeed");
+    assert_eq!(choreography.settings.musical_timeline, None);
+    let timeline = MusicalTimeline {
+        bpm: 128.0,
+        offset_seconds: 0.35,
+        beats_per_bar: 3,
+        beat_unit: 4,
+    };
+    choreography.settings.musical_timeline = Some(timeline);
+
+    let exported = export(&choreography).expect("export should succeed");
+    let round_trip = import(&exported).expect("re-import should succeed");
+
+    assert_eq!(round_trip.settings.musical_timeline, Some(timeline));
+}
+
+#[test]
+fn export_round_trip_keeps_scene_variations() {
+    let json = include_str!("data/Variations.choreo");
+    let choreography = import(json).expect("import should succeed");
+
+    let names = |scenes: &[choreo_master_mobile_json::Scene]| {
+        scenes
+            .iter()
+            .map(|scene| scene.name.clone())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        names(&choreography.scenes),
+        ["Opening", "Chorus", "Chorus (3)", "Finale"]
+    );
+    let anchor = &choreography.scenes[1];
+    let variations = anchor.variations.as_ref().expect("variations");
+    assert_eq!(variations.len(), 1);
+    assert_eq!(names(&variations[0]), ["Chorus (2)", "Lift"]);
+    assert_eq!(variations[0][1].variation_depth, 1);
+    let lift_positions = variations[0][1].positions.as_ref().expect("positions");
+    assert_eq!(
+        lift_positions[1]
+            .dancer
+            .as_ref()
+            .map(|dancer| dancer.name.as_str()),
+        Some("Ben")
+    );
+    assert_eq!(
+        names(
+            anchor
+                .current_variation
+                .as_ref()
+                .expect("current variation")
+        ),
+        ["Chorus (3)"]
+    );
+
+    let exported = export(&choreography).expect("export should succeed");
+    let round_trip = import(&exported).expect("re-import should succeed");
+
+    assert_eq!(round_trip.scenes, choreography.scenes);
+}
@@ -40,7 +40,7 @@ impl ChoreographyModelMapper {
         let mut scenes = source
             .scenes
             .iter()
-            .map(|scene| map_scene_to_model(scene, &dancers, &dancers_by_id))
+            .map(|scene| map_scene_to_model(scene, &roles, &dancers, &dancers_by_id))
             .collect::<Vec<_>>();
         normalize_scene_ids(&mut scenes);
 
@@ -224,6 +224,7 @@ fn map_settings_to_model(source: &Settings) -> SettingsModel {
         show_timestamps: source.show_timestamps,
         music_path_absolute: source.music_path_absolute.clone(),
         music_path_relative: source.music_path_relative.clone(),
+        musical_timeline: source.musical_timeline,
     }
 }
 
@@ -242,6 +243,7 @@ fn map_settings_from_model(source: &SettingsModel) -> Settings {
         show_timestamps: source.show_timestamps,
         music_path_absolute: source.music_path_absolute.clone(),
         music_path_relative: source.music_path_relative.clone(),
+        musical_timeline: source.musical_timeline,
     }
 }
 
@@ -303,6 +305,7 @@ fn map_dancer_from_model(source: &DancerModel, role: Role) -> Dancer {
 
 fn map_scene_to_model(
     source: &Scene,
+    roles: &[Rc<RoleModel>],
     dancers: &[Rc<DancerModel>],
     dancers_by_id: &HashMap<DancerId, Rc<DancerModel>>,
 ) -> SceneModel {
@@ -310,7 +313,7 @@ fn map_scene_to_model(
     if let Some(source_positions) = &source.positions {
         positions = source_positions
             .iter()
-            .map(|position| map_position_to_model(position, dancers, dancers_by_id))
+            .map(|position| map_position_to_model(position, roles, dancers, dancers_by_id))
             .collect();
     }
 
@@ -322,9 +325,15 @@ fn map_scene_to_model(
         fixed_positions: source.fixed_positions,
         timestamp: source.timestamp.clone(),
         variation_depth: source.variation_depth,
-        variations: map_scene_variations_to_model(&source.variations, dancers, dancers_by_id),
+        variations: map_scene_variations_to_model(
+            &source.variations,
+            roles,
+            dancers,
+            dancers_by_id,
+        ),
         current_variation: map_scene_list_to_model(
             &source.current_variation,
+            roles,
             dancers,
             dancers_by_id,
         ),
@@ -361,6 +370,7 @@ fn map_scene_from_model(source: &SceneModel, dancer_map: &HashMap<usize, Dancer>
 
 fn map_position_to_model(
     source: &Position,
+    roles: &[Rc<RoleModel>],
     dancers: &[Rc<DancerModel>],
     dancers_by_id: &HashMap<DancerId, Rc<DancerModel>>,
 ) -> PositionModel {
@@ -375,8 +385,13 @@ fn map_position_to_model(
         }
     });
 
+    let required_role = source.required_role.as_ref().map(|role| {
+        find_role_model(roles, role).unwrap_or_else(|| Rc::new(map_role_to_model(role)))
+    });
+
     PositionModel {
         dancer,
+        required_role,
         orientation: source.orientation,
         x: source.x,
         y: source.y,
@@ -423,6 +438,7 @@ fn map_position_from_model(
 
     Position {
         dancer,
+        required_role: source.required_role.as_deref().map(map_role_from_model),
         orientation: source.orientation,
         x: source.x,
         y: source.y,
@@ -439,6 +455,7 @@ fn map_position_from_model(
 
 fn map_scene_variations_to_model(
     variations: &Option<Vec<Vec<Scene>>>,
+    roles: &[Rc<RoleModel>],
     dancers: &[Rc<DancerModel>],
     dancers_by_id: &HashMap<DancerId, Rc<DancerModel>>,
 ) -> Vec<Vec<SceneModel>> {
@@ -450,7 +467,7 @@ fn map_scene_variations_to_model(
         .iter()
         .map(|list| {
             list.iter()
-                .map(|scene| map_scene_to_model(scene, dancers, dancers_by_id))
+                .map(|scene| map_scene_to_model(scene, roles, dancers, dancers_by_id))
                 .collect()
         })
         .collect()
@@ -478,6 +495,7 @@ fn map_scene_variations_from_model(
 
 fn map_scene_list_to_model(
     scenes: &Option<Vec<Scene>>,
+    roles: &[Rc<RoleModel>],
     dancers: &[Rc<DancerModel>],
     dancers_by_id: &HashMap<DancerId, Rc<DancerModel>>,
 ) -> Vec<SceneModel> {
@@ -487,7 +505,7 @@ fn map_scene_list_to_model(
 
     scenes
         .iter()
-        .map(|scene| map_scene_to_model(scene, dancers, dancers_by_id))
+        .map(|scene| map_scene_to_model(scene, roles, dancers, dancers_by_id))
         .collect()
 }
 
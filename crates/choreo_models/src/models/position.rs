@@ -8,6 +8,7 @@ use crate::models::role::RoleModel;
 #[derive(Debug, Clone, PartialEq)]
 pub struct PositionModel {
     pub dancer: Option<Rc<DancerModel>>,
+    pub required_role: Option<Rc<RoleModel>>,
     pub orientation: Option<f64>,
     pub x: f64,
     pub y: f64,
@@ -39,6 +40,10 @@ impl PositionModel {
     ) -> Self {
         Self {
             dancer: crate::mapping::clone_dancer(source.dancer.as_ref(), dancer_map, role_map),
+            required_role: source
+                .required_role
+                .as_ref()
+                .map(|role| crate::mapping::clone_role(role, role_map)),
             orientation: source.orientation,
             x: source.x,
             y: source.y,
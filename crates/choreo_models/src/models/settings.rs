@@ -1,4 +1,4 @@
-use choreo_master_mobile_json::{Color, FrontPosition};
+use choreo_master_mobile_json::{Color, FrontPosition, MusicalTimeline};
 
 use crate::clone_mode::CloneMode;
 
@@ -17,6 +17,7 @@ pub struct SettingsModel {
     pub show_timestamps: bool,
     pub music_path_absolute: Option<String>,
     pub music_path_relative: Option<String>,
+    pub musical_timeline: Option<MusicalTimeline>,
 }
 
 impl SettingsModel {
@@ -41,6 +42,7 @@ impl Default for SettingsModel {
             show_timestamps: false,
             music_path_absolute: None,
             music_path_relative: None,
+            musical_timeline: None,
         }
     }
 }
@@ -1,5 +1,6 @@
 use choreo_master_mobile_json::{
-    Choreography, Dancer, DancerId, Floor, FrontPosition, Position, Role, Scene, SceneId, Settings,
+    Choreography, Dancer, DancerId, Floor, FrontPosition, MusicalTimeline, Position, Role, Scene,
+    SceneId, Settings,
 };
 use choreo_models::{
     ChoreographyModel, ChoreographyModelMapper, Colors, DancerModel, FloorModel, PositionModel,
@@ -91,6 +92,11 @@ fn should_map_json_choreography_to_model_when_invoked() {
         result.settings.music_path_relative.as_deref(),
         Some("track.mp3")
     );
+    check_eq!(
+        errors,
+        result.settings.musical_timeline,
+        Some(waltz_timeline())
+    );
     check_eq!(errors, result.floor.size_front, 10);
     check_eq!(errors, result.floor.size_back, 11);
     check_eq!(errors, result.floor.size_left, 12);
@@ -149,6 +155,22 @@ fn should_map_json_choreography_to_model_when_invoked() {
     check_eq!(errors, result.scenes[0].positions[0].movement1_y, Some(0.6));
     check_eq!(errors, result.scenes[0].positions[0].movement2_x, Some(0.7));
     check_eq!(errors, result.scenes[0].positions[0].movement2_y, Some(0.8));
+    check!(
+        errors,
+        result.scenes[0].positions[0].required_role.is_none(),
+        "scene 0 position 0 should not require a role"
+    );
+    check!(
+        errors,
+        Rc::ptr_eq(
+            result.scenes[0].positions[1]
+                .required_role
+                .as_ref()
+                .expect("required role"),
+            &result.roles[1]
+        ),
+        "scene 0 position 1 required role should reference role 1"
+    );
     check_eq!(errors, result.scenes[0].variations.len(), 1);
     check_eq!(errors, result.scenes[0].variations[0].len(), 1);
     check_eq!(
@@ -231,6 +253,11 @@ fn should_map_model_choreography_to_json_when_invoked() {
         result.settings.music_path_relative.as_deref(),
         Some("track.mp3")
     );
+    check_eq!(
+        errors,
+        result.settings.musical_timeline,
+        Some(waltz_timeline())
+    );
     check_eq!(errors, result.floor.size_front, 10);
     check_eq!(errors, result.floor.size_back, 11);
     check_eq!(errors, result.floor.size_left, 12);
@@ -316,6 +343,14 @@ fn should_map_model_choreography_to_json_when_invoked() {
         result.scenes[0].positions.as_ref().expect("positions")[0].movement2_y,
         Some(0.8)
     );
+    check_eq!(
+        errors,
+        result.scenes[0].positions.as_ref().expect("positions")[1]
+            .required_role
+            .as_ref()
+            .map(|role| role.name.as_str()),
+        Some("Follow")
+    );
     check_eq!(
         errors,
         result.scenes[0]
@@ -467,6 +502,7 @@ fn build_json_choreography() -> Choreography {
             show_timestamps: false,
             music_path_absolute: Some("C:\\music\\track.mp3".to_string()),
             music_path_relative: Some("track.mp3".to_string()),
+            musical_timeline: Some(waltz_timeline()),
         },
         floor: Floor {
             size_front: 10,
@@ -492,6 +528,7 @@ fn build_json_scene(dancer_a: &Dancer, dancer_b: &Dancer) -> Scene {
         positions: Some(vec![
             Position {
                 dancer: Some(dancer_a.clone()),
+                required_role: None,
                 orientation: Some(90.0),
                 x: 1.25,
                 y: 2.5,
@@ -506,6 +543,7 @@ fn build_json_scene(dancer_a: &Dancer, dancer_b: &Dancer) -> Scene {
             },
             Position {
                 dancer: Some(dancer_b.clone()),
+                required_role: Some(dancer_b.role.clone()),
                 orientation: None,
                 x: 4.0,
                 y: 5.0,
@@ -612,6 +650,7 @@ fn build_model_choreography() -> ChoreographyModel {
             show_timestamps: false,
             music_path_absolute: Some("C:\\music\\track.mp3".to_string()),
             music_path_relative: Some("track.mp3".to_string()),
+            musical_timeline: Some(waltz_timeline()),
         },
         floor: FloorModel {
             size_front: 10,
@@ -641,6 +680,7 @@ fn build_model_scene(dancer_a: Rc<DancerModel>, dancer_b: Rc<DancerModel>) -> Sc
 
     scene.positions.push(PositionModel {
         dancer: Some(dancer_a),
+        required_role: None,
         orientation: Some(90.0),
         x: 1.25,
         y: 2.5,
@@ -655,6 +695,7 @@ fn build_model_scene(dancer_a: Rc<DancerModel>, dancer_b: Rc<DancerModel>) -> Sc
     });
 
     scene.positions.push(PositionModel {
+        required_role: Some(dancer_b.role.clone()),
         dancer: Some(dancer_b),
         orientation: None,
         x: 4.0,
@@ -727,3 +768,12 @@ fn collect_scene_ids(scenes: &[SceneModel], seen: &mut HashSet<i32>) {
         collect_scene_ids(&scene.current_variation, seen);
     }
 }
+
+fn waltz_timeline() -> MusicalTimeline {
+    MusicalTimeline {
+        bpm: 90.0,
+        offset_seconds: 1.25,
+        beats_per_bar: 3,
+        beat_unit: 4,
+    }
+}
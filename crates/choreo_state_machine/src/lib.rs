@@ -47,6 +47,9 @@ pub enum StateKind {
     ScaleAroundDancerSelectionEndState,
     ScaleAroundDancerDragStartState,
     ScaleAroundDancerDragEndState,
+    BendPathState,
+    BendPathSelectionState,
+    BendPathDragState,
 }
 
 impl StateKind {
@@ -89,6 +92,10 @@ impl StateKind {
                     | StateKind::ScaleAroundDancerDragStartState
                     | StateKind::ScaleAroundDancerDragEndState
             ),
+            StateKind::BendPathState => matches!(
+                other,
+                StateKind::BendPathSelectionState | StateKind::BendPathDragState
+            ),
             _ => false,
         }
     }
@@ -97,6 +104,12 @@ impl StateKind {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum TriggerKind {
     ApplicationTrigger,
+    BendPathCompletedTrigger,
+    BendPathDragCompletedTrigger,
+    BendPathDragStartedTrigger,
+    BendPathSelectionCompletedTrigger,
+    BendPathSelectionStartedTrigger,
+    BendPathStartedTrigger,
     MovePositionsCompletedTrigger,
     MovePositionsDragCompletedTrigger,
     MovePositionsDragStartedTrigger,
@@ -226,8 +239,32 @@ state_struct!(
     ScaleAroundDancerDragEndState,
     StateKind::ScaleAroundDancerDragEndState
 );
+state_struct!(BendPathState, StateKind::BendPathState);
+state_struct!(BendPathSelectionState, StateKind::BendPathSelectionState);
+state_struct!(BendPathDragState, StateKind::BendPathDragState);
 
 trigger_struct!(ApplicationTriggerBase, TriggerKind::ApplicationTrigger);
+trigger_struct!(
+    BendPathCompletedTrigger,
+    TriggerKind::BendPathCompletedTrigger
+);
+trigger_struct!(
+    BendPathDragCompletedTrigger,
+    TriggerKind::BendPathDragCompletedTrigger
+);
+trigger_struct!(
+    BendPathDragStartedTrigger,
+    TriggerKind::BendPathDragStartedTrigger
+);
+trigger_struct!(
+    BendPathSelectionCompletedTrigger,
+    TriggerKind::BendPathSelectionCompletedTrigger
+);
+trigger_struct!(
+    BendPathSelectionStartedTrigger,
+    TriggerKind::BendPathSelectionStartedTrigger
+);
+trigger_struct!(BendPathStartedTrigger, TriggerKind::BendPathStartedTrigger);
 trigger_struct!(
     MovePositionsCompletedTrigger,
     TriggerKind::MovePositionsCompletedTrigger
@@ -1145,6 +1182,56 @@ pub fn default_transitions() -> Vec<StateTransition> {
             TriggerKind::PanStartedTrigger,
             StateKind::PlacePositionsPanState,
         ),
+        transition(
+            StateKind::ViewSceneState,
+            TriggerKind::BendPathStartedTrigger,
+            StateKind::BendPathState,
+        ),
+        transition(
+            StateKind::ViewScenePanState,
+            TriggerKind::BendPathStartedTrigger,
+            StateKind::BendPathState,
+        ),
+        transition(
+            StateKind::ViewSceneZoomState,
+            TriggerKind::BendPathStartedTrigger,
+            StateKind::BendPathState,
+        ),
+        transition(
+            StateKind::BendPathState,
+            TriggerKind::BendPathCompletedTrigger,
+            StateKind::ViewSceneState,
+        ),
+        transition(
+            StateKind::BendPathSelectionState,
+            TriggerKind::BendPathCompletedTrigger,
+            StateKind::ViewSceneState,
+        ),
+        transition(
+            StateKind::BendPathDragState,
+            TriggerKind::BendPathCompletedTrigger,
+            StateKind::ViewSceneState,
+        ),
+        transition(
+            StateKind::BendPathState,
+            TriggerKind::BendPathSelectionCompletedTrigger,
+            StateKind::BendPathSelectionState,
+        ),
+        transition(
+            StateKind::BendPathSelectionState,
+            TriggerKind::BendPathSelectionStartedTrigger,
+            StateKind::BendPathState,
+        ),
+        transition(
+            StateKind::BendPathSelectionState,
+            TriggerKind::BendPathDragStartedTrigger,
+            StateKind::BendPathDragState,
+        ),
+        transition(
+            StateKind::BendPathDragState,
+            TriggerKind::BendPathDragCompletedTrigger,
+            StateKind::BendPathSelectionState,
+        ),
     ];
 
     transitions
@@ -1198,5 +1285,8 @@ fn create_state(kind: StateKind) -> Box<dyn ApplicationState> {
         }
         StateKind::ScaleAroundDancerDragStartState => Box::new(ScaleAroundDancerDragStartState),
         StateKind::ScaleAroundDancerDragEndState => Box::new(ScaleAroundDancerDragEndState),
+        StateKind::BendPathState => Box::new(BendPathState),
+        StateKind::BendPathSelectionState => Box::new(BendPathSelectionState),
+        StateKind::BendPathDragState => Box::new(BendPathDragState),
     }
 }
@@ -0,0 +1,228 @@
+use choreo_state_machine::{
+    ApplicationStateMachine, ApplicationTrigger, BendPathCompletedTrigger,
+    BendPathDragCompletedTrigger, BendPathDragStartedTrigger, BendPathSelectionCompletedTrigger,
+    BendPathSelectionStartedTrigger, BendPathStartedTrigger, GlobalStateModel,
+    MovePositionsStartedTrigger, PanStartedTrigger, StateKind,
+};
+use rspec::report::Report;
+use rspec::{ConfigurationBuilder, Logger, Runner};
+use std::io;
+use std::sync::Arc;
+
+macro_rules! check_eq {
+    ($errors:expr, $left:expr, $right:expr) => {
+        if $left != $right {
+            $errors.push(format!(
+                "{} != {} (left = {:?}, right = {:?})",
+                stringify!($left),
+                stringify!($right),
+                $left,
+                $right
+            ));
+        }
+    };
+}
+
+macro_rules! check {
+    ($errors:expr, $condition:expr) => {
+        if !$condition {
+            $errors.push(format!("condition failed: {}", stringify!($condition)));
+        }
+    };
+}
+
+#[derive(Debug)]
+struct TestGlobalState;
+
+impl GlobalStateModel for TestGlobalState {}
+
+fn run_suite<T>(suite: &rspec::block::Suite<T>) -> rspec::report::SuiteReport
+where
+    T: Clone + Send + Sync + std::fmt::Debug,
+{
+    let configuration = ConfigurationBuilder::default()
+        .exit_on_failure(false)
+        .build()
+        .expect("rspec configuration should build");
+    let logger = Arc::new(Logger::new(io::stdout()));
+    let runner = Runner::new(configuration, vec![logger]);
+    runner.run(suite)
+}
+
+fn create_state_machine() -> ApplicationStateMachine {
+    ApplicationStateMachine::with_default_transitions(Box::new(TestGlobalState))
+}
+
+fn apply_all(state_machine: &mut ApplicationStateMachine, triggers: &[&dyn ApplicationTrigger]) {
+    for trigger in triggers {
+        assert!(
+            state_machine.try_apply(*trigger),
+            "{trigger:?} should apply"
+        );
+    }
+}
+
+#[test]
+fn bend_path_transitions_spec() {
+    let suite = rspec::describe("bend path transitions", (), |spec| {
+        spec.it(
+            "enters bend path from the scene view and leaves it again",
+            |_| {
+                let mut state_machine = create_state_machine();
+                let mut errors = Vec::new();
+
+                check!(errors, state_machine.try_apply(&BendPathStartedTrigger));
+                check_eq!(
+                    errors,
+                    state_machine.state().kind(),
+                    StateKind::BendPathState
+                );
+
+                check!(errors, state_machine.try_apply(&BendPathCompletedTrigger));
+                check_eq!(
+                    errors,
+                    state_machine.state().kind(),
+                    StateKind::ViewSceneState
+                );
+
+                check!(errors, state_machine.try_apply(&PanStartedTrigger));
+                check!(errors, state_machine.try_apply(&BendPathStartedTrigger));
+                check_eq!(
+                    errors,
+                    state_machine.state().kind(),
+                    StateKind::BendPathState
+                );
+
+                assert!(
+                    errors.is_empty(),
+                    "Assertion failures:\n{}",
+                    errors.join("\n")
+                );
+            },
+        );
+
+        spec.it("selects, drags and deselects a path", |_| {
+            let mut state_machine = create_state_machine();
+            let mut errors = Vec::new();
+
+            apply_all(
+                &mut state_machine,
+                &[&BendPathStartedTrigger, &BendPathSelectionCompletedTrigger],
+            );
+            check_eq!(
+                errors,
+                state_machine.state().kind(),
+                StateKind::BendPathSelectionState
+            );
+
+            check!(errors, state_machine.try_apply(&BendPathDragStartedTrigger));
+            check_eq!(
+                errors,
+                state_machine.state().kind(),
+                StateKind::BendPathDragState
+            );
+
+            check!(
+                errors,
+                state_machine.try_apply(&BendPathDragCompletedTrigger)
+            );
+            check_eq!(
+                errors,
+                state_machine.state().kind(),
+                StateKind::BendPathSelectionState
+            );
+
+            check!(
+                errors,
+                state_machine.try_apply(&BendPathSelectionStartedTrigger)
+            );
+            check_eq!(
+                errors,
+                state_machine.state().kind(),
+                StateKind::BendPathState
+            );
+
+            assert!(
+                errors.is_empty(),
+                "Assertion failures:\n{}",
+                errors.join("\n")
+            );
+        });
+
+        spec.it("leaves bend path from a selection or a drag", |_| {
+            let mut errors = Vec::new();
+
+            let mut selecting = create_state_machine();
+            apply_all(
+                &mut selecting,
+                &[&BendPathStartedTrigger, &BendPathSelectionCompletedTrigger],
+            );
+            check!(errors, selecting.try_apply(&BendPathCompletedTrigger));
+            check_eq!(errors, selecting.state().kind(), StateKind::ViewSceneState);
+
+            let mut dragging = create_state_machine();
+            apply_all(
+                &mut dragging,
+                &[
+                    &BendPathStartedTrigger,
+                    &BendPathSelectionCompletedTrigger,
+                    &BendPathDragStartedTrigger,
+                ],
+            );
+            check!(errors, dragging.try_apply(&BendPathCompletedTrigger));
+            check_eq!(errors, dragging.state().kind(), StateKind::ViewSceneState);
+
+            assert!(
+                errors.is_empty(),
+                "Assertion failures:\n{}",
+                errors.join("\n")
+            );
+        });
+
+        spec.it("rejects triggers that do not fit the current state", |_| {
+            let mut state_machine = create_state_machine();
+            let mut errors = Vec::new();
+
+            check!(
+                errors,
+                !state_machine.try_apply(&BendPathSelectionCompletedTrigger)
+            );
+            check!(errors, !state_machine.try_apply(&BendPathCompletedTrigger));
+            check_eq!(
+                errors,
+                state_machine.state().kind(),
+                StateKind::ViewSceneState
+            );
+
+            check!(errors, state_machine.try_apply(&BendPathStartedTrigger));
+            check!(
+                errors,
+                !state_machine.try_apply(&BendPathDragStartedTrigger)
+            );
+            check!(
+                errors,
+                !state_machine.try_apply(&BendPathDragCompletedTrigger)
+            );
+            check!(errors, !state_machine.try_apply(&BendPathStartedTrigger));
+            check_eq!(
+                errors,
+                state_machine.state().kind(),
+                StateKind::BendPathState
+            );
+
+            let mut moving = create_state_machine();
+            check!(errors, moving.try_apply(&MovePositionsStartedTrigger));
+            check!(errors, !moving.try_apply(&BendPathStartedTrigger));
+            check_eq!(errors, moving.state().kind(), StateKind::MovePositionsState);
+
+            assert!(
+                errors.is_empty(),
+                "Assertion failures:\n{}",
+                errors.join("\n")
+            );
+        });
+    });
+
+    let report = run_suite(&suite);
+    assert!(report.is_success());
+}
@@ -20,6 +20,8 @@ pub enum TopBarIcon {
     Home,
     Image,
     Audio,
+    Undo,
+    Redo,
 }
 
 #[must_use]
@@ -29,6 +31,8 @@ pub const fn icon_uri(icon: TopBarIcon) -> &'static str {
         TopBarIcon::Home => "bytes://top_bar/home.svg",
         TopBarIcon::Image => "bytes://top_bar/image.svg",
         TopBarIcon::Audio => "bytes://top_bar/audio.svg",
+        TopBarIcon::Undo => "bytes://top_bar/undo.svg",
+        TopBarIcon::Redo => "bytes://top_bar/redo.svg",
     }
 }
 
@@ -38,6 +42,8 @@ pub fn icon_image(icon: TopBarIcon) -> Image<'static> {
         TopBarIcon::Home => Image::new(egui::include_image!("../../assets/icons/Home.svg")),
         TopBarIcon::Image => Image::new(egui::include_image!("../../assets/icons/Svg.svg")),
         TopBarIcon::Audio => Image::new(egui::include_image!("../../assets/icons/PlayCircle.svg")),
+        TopBarIcon::Undo => Image::new(egui::include_image!("../../assets/icons/Undo.svg")),
+        TopBarIcon::Redo => Image::new(egui::include_image!("../../assets/icons/Redo.svg")),
     }
 }
 
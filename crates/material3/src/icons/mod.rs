@@ -30510,6 +30510,8 @@ pub enum UiIconKey {
     NavOpen,
     NavClose,
     NavSettings,
+    NavUndo,
+    NavRedo,
     AudioOpenPanel,
     AudioPlay,
     AudioPause,
@@ -30517,8 +30519,19 @@ pub enum UiIconKey {
     ScenesAddBefore,
     ScenesAddAfter,
     ScenesDelete,
+    ScenesAutoAssignDancers,
+    ScenesInsertInBetween,
+    ScenesBendCollidingPaths,
+    ScenesBranchVariation,
+    ScenesPromoteVariation,
+    ScenesDuplicate,
+    ScenesShiftEarlier,
+    ScenesShiftLater,
+    ScenesApplyFormation,
+    ScenesSaveFormationTemplate,
     ScenesOpenChoreography,
     ScenesSaveChoreography,
+    ScenesSaveChoreographyAs,
     ScenesNavigateSettings,
     ScenesNavigateDancers,
     SettingsNavigateBack,
@@ -30557,6 +30570,8 @@ impl UiIconRegistry {
             UiIconKey::NavOpen => ui_icon_spec("menu", "Menu"),
             UiIconKey::NavClose => ui_icon_spec("close", "Close"),
             UiIconKey::NavSettings => ui_icon_spec("edit", "Pen"),
+            UiIconKey::NavUndo => ui_icon_spec("undo", "Undo"),
+            UiIconKey::NavRedo => ui_icon_spec("redo", "Redo"),
             UiIconKey::AudioOpenPanel => ui_icon_spec("play_circle", "PlayCircle"),
             UiIconKey::AudioPlay => ui_icon_spec("play_arrow", "Play"),
             UiIconKey::AudioPause => ui_icon_spec("pause", "Pause"),
@@ -30564,8 +30579,21 @@ impl UiIconRegistry {
             UiIconKey::ScenesAddBefore => ui_icon_spec("add_row_above", "TableRowPlusBefore"),
             UiIconKey::ScenesAddAfter => ui_icon_spec("add_row_below", "TableRowPlusAfter"),
             UiIconKey::ScenesDelete => ui_icon_spec("delete", "Delete"),
+            UiIconKey::ScenesAutoAssignDancers => {
+                ui_icon_spec("assignment_ind", "AccountArrowRight")
+            }
+            UiIconKey::ScenesInsertInBetween => ui_icon_spec("transition", "Transition"),
+            UiIconKey::ScenesBendCollidingPaths => ui_icon_spec("alt_route", "Routes"),
+            UiIconKey::ScenesBranchVariation => ui_icon_spec("fork_right", "SourceBranchPlus"),
+            UiIconKey::ScenesPromoteVariation => ui_icon_spec("merge", "SourceMerge"),
+            UiIconKey::ScenesDuplicate => ui_icon_spec("content_copy", "ContentDuplicate"),
+            UiIconKey::ScenesShiftEarlier => ui_icon_spec("fast_rewind", "Rewind"),
+            UiIconKey::ScenesShiftLater => ui_icon_spec("fast_forward", "FastForward"),
+            UiIconKey::ScenesApplyFormation => ui_icon_spec("scatter_plot", "ScatterPlot"),
+            UiIconKey::ScenesSaveFormationTemplate => ui_icon_spec("bookmark_add", "BookmarkPlus"),
             UiIconKey::ScenesOpenChoreography => ui_icon_spec("folder_open", "FolderOpen"),
             UiIconKey::ScenesSaveChoreography => ui_icon_spec("save", "ContentSave"),
+            UiIconKey::ScenesSaveChoreographyAs => ui_icon_spec("save_as", "ContentSaveEdit"),
             UiIconKey::ScenesNavigateSettings => ui_icon_spec("settings", "Cog"),
             UiIconKey::ScenesNavigateDancers => ui_icon_spec("groups", "AccountGroup"),
             UiIconKey::SettingsNavigateBack => ui_icon_spec("arrow_back", "ArrowLeft"),
@@ -30584,6 +30612,8 @@ impl UiIconRegistry {
             "Menu" => UiIconKey::NavOpen,
             "Close" => UiIconKey::NavClose,
             "Pen" => UiIconKey::NavSettings,
+            "Undo" => UiIconKey::NavUndo,
+            "Redo" => UiIconKey::NavRedo,
             "PlayCircle" => UiIconKey::AudioOpenPanel,
             "Play" => UiIconKey::AudioPlay,
             "Pause" => UiIconKey::AudioPause,
@@ -30591,8 +30621,19 @@ impl UiIconRegistry {
             "TableRowPlusBefore" => UiIconKey::ScenesAddBefore,
             "TableRowPlusAfter" => UiIconKey::ScenesAddAfter,
             "Delete" => UiIconKey::ScenesDelete,
+            "AccountArrowRight" => UiIconKey::ScenesAutoAssignDancers,
+            "Transition" => UiIconKey::ScenesInsertInBetween,
+            "Routes" => UiIconKey::ScenesBendCollidingPaths,
+            "SourceBranchPlus" => UiIconKey::ScenesBranchVariation,
+            "SourceMerge" => UiIconKey::ScenesPromoteVariation,
+            "ContentDuplicate" => UiIconKey::ScenesDuplicate,
+            "Rewind" => UiIconKey::ScenesShiftEarlier,
+            "FastForward" => UiIconKey::ScenesShiftLater,
+            "ScatterPlot" => UiIconKey::ScenesApplyFormation,
+            "BookmarkPlus" => UiIconKey::ScenesSaveFormationTemplate,
             "FolderOpen" => UiIconKey::ScenesOpenChoreography,
             "ContentSave" => UiIconKey::ScenesSaveChoreography,
+            "ContentSaveEdit" => UiIconKey::ScenesSaveChoreographyAs,
             "Cog" => UiIconKey::ScenesNavigateSettings,
             "AccountGroup" => UiIconKey::ScenesNavigateDancers,
             "ArrowLeft" => UiIconKey::SettingsNavigateBack,
@@ -30710,6 +30751,8 @@ mod tests {
             (UiIconKey::NavOpen, "menu", "Menu", "Menu"),
             (UiIconKey::NavClose, "close", "Close", "Close"),
             (UiIconKey::NavSettings, "edit", "Pen", "Pen"),
+            (UiIconKey::NavUndo, "undo", "Undo", "Undo"),
+            (UiIconKey::NavRedo, "redo", "Redo", "Redo"),
             (
                 UiIconKey::AudioOpenPanel,
                 "play_circle",
@@ -30732,6 +30775,66 @@ mod tests {
                 "TableRowPlusAfter",
             ),
             (UiIconKey::ScenesDelete, "delete", "Delete", "Delete"),
+            (
+                UiIconKey::ScenesAutoAssignDancers,
+                "assignment_ind",
+                "AccountArrowRight",
+                "AccountArrowRight",
+            ),
+            (
+                UiIconKey::ScenesInsertInBetween,
+                "transition",
+                "Transition",
+                "Transition",
+            ),
+            (
+                UiIconKey::ScenesBendCollidingPaths,
+                "alt_route",
+                "Routes",
+                "Routes",
+            ),
+            (
+                UiIconKey::ScenesBranchVariation,
+                "fork_right",
+                "SourceBranchPlus",
+                "SourceBranchPlus",
+            ),
+            (
+                UiIconKey::ScenesPromoteVariation,
+                "merge",
+                "SourceMerge",
+                "SourceMerge",
+            ),
+            (
+                UiIconKey::ScenesDuplicate,
+                "content_copy",
+                "ContentDuplicate",
+                "ContentDuplicate",
+            ),
+            (
+                UiIconKey::ScenesShiftEarlier,
+                "fast_rewind",
+                "Rewind",
+                "Rewind",
+            ),
+            (
+                UiIconKey::ScenesShiftLater,
+                "fast_forward",
+                "FastForward",
+                "FastForward",
+            ),
+            (
+                UiIconKey::ScenesApplyFormation,
+                "scatter_plot",
+                "ScatterPlot",
+                "ScatterPlot",
+            ),
+            (
+                UiIconKey::ScenesSaveFormationTemplate,
+                "bookmark_add",
+                "BookmarkPlus",
+                "BookmarkPlus",
+            ),
             (
                 UiIconKey::ScenesOpenChoreography,
                 "folder_open",
@@ -30744,6 +30847,12 @@ mod tests {
                 "ContentSave",
                 "ContentSave",
             ),
+            (
+                UiIconKey::ScenesSaveChoreographyAs,
+                "save_as",
+                "ContentSaveEdit",
+                "ContentSaveEdit",
+            ),
             (UiIconKey::ScenesNavigateSettings, "settings", "Cog", "Cog"),
             (
                 UiIconKey::ScenesNavigateDancers,